@@ -29,11 +29,36 @@ impl Client {
         A: Into<String>,
         C: Into<Option<crate::auth::Credentials>>,
     {
-        let http = reqwest::Client::builder().build()?;
+        let config = crate::utils::Config {
+            host: Some(host.into()),
+            ..Default::default()
+        };
+        Self::new_with_config(agent, credentials, config)
+    }
+
+    /// Create a new Client struct with the given `Config`, for example to
+    /// point it at a GitHub Enterprise Server or tune the underlying HTTP client.
+    pub fn new_with_config<A, C>(
+        agent: A,
+        credentials: C,
+        config: crate::utils::Config,
+    ) -> Result<Self>
+    where
+        A: Into<String>,
+        C: Into<Option<crate::auth::Credentials>>,
+    {
+        let http = config.http_client()?;
+        let host = config.host.unwrap_or_else(|| DEFAULT_HOST.to_string());
         let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
                 let client = reqwest_middleware::ClientBuilder::new(http)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware::default())
+                    // Record OpenTelemetry metrics when the `otel` feature is enabled.
+                    .with(crate::utils::MetricsMiddleware::default())
+                    // Parse the Link and rate limit headers of every response once.
+                    .with(crate::utils::PaginationMetadataMiddleware)
+                    // Wait while the rate limit reported by earlier responses is exhausted.
+                    .with(crate::utils::RateLimitStatusMiddleware::default())
                     // Retry failed requests.
                     .with(
                         reqwest_conditional_middleware::ConditionalMiddleware::new(
@@ -226,11 +251,8 @@ impl Client {
         let (remaining, reset, etag) = crate::utils::get_header_values(response.headers());
 
         let status = response.status();
-        let link = response
-            .headers()
-            .get(http::header::LINK)
-            .and_then(|l| l.to_str().ok())
-            .and_then(|l| l.parse().ok());
+        let rate_limit = crate::utils::RateLimitStatus::from_headers(response.headers());
+        let link = crate::utils::link_from_headers(response.headers());
 
         let response_body = response.bytes().await?;
 
@@ -280,26 +302,53 @@ impl Client {
                     unreachable!("this should not be reachable without the httpcache feature enabled")
                 }
         } else {
+            let error = crate::utils::StatusError::new(status, rate_limit, &response_body);
             let error = match (remaining, reset) {
-                (Some(remaining), Some(reset)) if remaining == 0 => {
+                (Some(0), Some(reset)) => {
                     let now = std::time::SystemTime::now()
                         .duration_since(std::time::UNIX_EPOCH)
                         .unwrap()
                         .as_secs();
-                    anyhow!("rate limit exceeded, will reset in {} seconds", u64::from(reset) - now)
+                    Error::from(error).context(format!("rate limit exceeded, will reset in {} seconds", u64::from(reset).saturating_sub(now)))
                 },
-                _ => {
-                    if response_body.is_empty() {
-                        anyhow!("code: {}, empty response", status)
-                    } else {
-                        anyhow!("code: {}, error: {:?}", status, String::from_utf8_lossy(&response_body),)
-                    }
-                }
+                _ => error.into(),
             };
             Err(error)
         }
     }
 
+    /// Send a request with the client's credentials and `headers` added, and
+    /// return the raw response, whatever its status.
+    async fn request_raw_with_headers(
+        &self,
+        method: http::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+        headers: http::HeaderMap,
+    ) -> Result<reqwest::Response> {
+        let (url, auth) = self
+            .url_and_auth(uri, crate::auth::AuthenticationConstraint::Unconstrained)
+            .await?;
+
+        let mut req = self.client.request(method, url);
+        req = req.header(http::header::USER_AGENT, &*self.agent);
+        req = req.header(
+            http::header::ACCEPT,
+            &*format!(
+                "{}",
+                hyperx::header::qitem::<mime::Mime>(From::from(crate::utils::MediaType::Json))
+            ),
+        );
+        req = req.headers(headers);
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+        if let Some(body) = body {
+            req = req.body(body);
+        }
+        Ok(req.send().await?)
+    }
+
     async fn request_entity<D>(
         &self,
         method: http::Method,
@@ -461,20 +510,353 @@ impl Client {
         D: serde::de::DeserializeOwned + 'static + Send,
     {
         let mut global_items = Vec::new();
+        // Servers may send relative targets, which resolve against the page they came from.
+        let mut page_url = reqwest::Url::parse(&(self.host.clone() + uri))?;
+        let mut visited = std::collections::HashSet::new();
+        visited.insert(page_url.clone());
         let (new_link, mut items) = self.get_pages(uri).await?;
         let mut link = new_link;
         while !items.is_empty() {
             global_items.append(&mut items);
             // We need to get the next link.
             if let Some(url) = link.as_ref().and_then(|l| crate::utils::next_link(l)) {
-                let url = reqwest::Url::parse(&url)?;
+                let url = page_url.join(&url)?;
+                if !visited.insert(url.clone()) {
+                    return Err(crate::utils::PaginationCycle { url: url.to_string() }.into());
+                }
                 let (new_link, new_items) = self.get_pages_url(&url).await?;
                 link = new_link;
                 items = new_items;
+                page_url = url;
             }
         }
 
         Ok(global_items)
+    }
+
+    /// Stream every page of a listing, following `rel="next"` links.
+    ///
+    /// The stream ends after the first page without a next link. Request errors,
+    /// error statuses and next links that cannot be parsed are yielded as items
+    /// and end the stream.
+    pub fn pages<'a>(&'a self, uri: &str) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        self.pages_from(reqwest::Url::parse(&(self.host.clone() + uri)).map_err(Error::from))
+    }
+
+    /// Stream every page of a listing, following links with the relation `rel`
+    /// rather than `next`, for example `prev` to walk history backwards. Registered
+    /// relations have their own variant, such as `RelationType::NextArchive` for
+    /// `next-archive`, and any other relation is `RelationType::ExtRelType`.
+    pub fn pages_by_rel<'a>(&'a self, uri: &str, rel: hyperx::header::RelationType) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        self.pages_from_rel(reqwest::Url::parse(&(self.host.clone() + uri)).map_err(Error::from), rel, None, None)
+    }
+
+    /// Stream the pages after `response`, following its `rel="next"` link.
+    ///
+    /// Use it to carry on from a first page fetched some other way. The stream
+    /// is empty if `response` has no next link.
+    pub fn pages_after<'a>(&'a self, response: &reqwest::Response) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        use futures::StreamExt;
+
+        let first = response.url().clone();
+        let next = crate::utils::ResponseLinkExt::next_link(response)
+            .map(|next| reqwest::Url::parse(&next).map_err(Error::from));
+        futures::stream::iter(next).flat_map(move |url| {
+            self.pages_from_rel(url, hyperx::header::RelationType::Next, None, Some(first.clone()))
+        })
+    }
+
+    /// Stream the pages of a listing that changed since an earlier walk, following
+    /// `rel="next"` links.
+    ///
+    /// The `ETag` and next link of each page are kept in `etags`, and the tag is
+    /// sent back as `If-None-Match` the next time that page is fetched. Pages the
+    /// server answers with `304 Not Modified` are skipped, and the walk carries on
+    /// from the next link stored for them, so only the pages that changed are
+    /// yielded.
+    pub fn pages_if_changed<'a>(&'a self, uri: &str, etags: &'a dyn crate::utils::EtagStore) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        self.pages_from_rel(
+            reqwest::Url::parse(&(self.host.clone() + uri)).map_err(Error::from),
+            hyperx::header::RelationType::Next,
+            Some(etags),
+            None,
+        )
+    }
+
+    fn pages_from<'a>(&'a self, url: Result<reqwest::Url>) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        self.pages_from_rel(url, hyperx::header::RelationType::Next, None, None)
+    }
+
+    /// Follow `rel` links from `url`. `after` is a page already fetched before
+    /// the walk, so a link back to it is a cycle too.
+    fn pages_from_rel<'a>(&'a self, url: Result<reqwest::Url>, rel: hyperx::header::RelationType, etags: Option<&'a dyn crate::utils::EtagStore>, after: Option<reqwest::Url>) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        let visited: std::collections::HashSet<_> = after.into_iter().collect();
+        futures::stream::unfold((Some(url), visited), move |(url, mut visited)| {
+            let rel = rel.clone();
+            async move {
+                let mut url = url?;
+                loop {
+                    let page = match url {
+                        Ok(page) => page,
+                        Err(e) => return Some((Err(e), (None, visited))),
+                    };
+                    if !visited.insert(page.clone()) {
+                        let cycle = crate::utils::PaginationCycle { url: page.to_string() };
+                        return Some((Err(cycle.into()), (None, visited)));
+                    }
+                    let etag = match etags {
+                        Some(etags) => etags.get(page.as_str()).await,
+                        None => None,
+                    };
+                    let mut headers = reqwest::header::HeaderMap::new();
+                    if let Some(etag) = etag.and_then(|etag| reqwest::header::HeaderValue::from_str(&etag).ok()) {
+                        headers.insert(reqwest::header::IF_NONE_MATCH, etag);
+                    }
+                    let response = match self.get_page_with_headers(&page, headers).await {
+                        Ok(response) => response,
+                        Err(e) => return Some((Err(e), (None, visited))),
+                    };
+                    // The page is the same as on the last walk, so skip it and carry on
+                    // from the next link it had then.
+                    if response.status() == http::StatusCode::NOT_MODIFIED {
+                        let next = match etags {
+                            Some(etags) => etags.get_next(page.as_str()).await,
+                            None => None,
+                        };
+                        match next {
+                            Some(next) => {
+                                url = reqwest::Url::parse(&next).map_err(Error::from);
+                                continue;
+                            }
+                            None => return None,
+                        }
+                    }
+                    // A non-empty Link header none of whose links parse is malformed rather than
+                    // the end of the listing, so the error follows the page.
+                    let links = crate::utils::ResponseLinkExt::links(&response);
+                    let malformed = links.is_empty()
+                        && response
+                            .headers()
+                            .get_all(http::header::LINK)
+                            .iter()
+                            .any(|value| value.as_bytes().iter().any(|b| !b.is_ascii_whitespace()));
+                    let next = if malformed {
+                        Some(Err(anyhow!("malformed Link header from {}", page)))
+                    } else {
+                        links
+                            .into_iter()
+                            .find_map(|(rels, target)| rels.contains(&rel).then_some(target))
+                            .map(|next| reqwest::Url::parse(&next).map_err(Error::from))
+                    };
+                    if let (Some(etags), Some(etag)) = (etags, response.headers().get(http::header::ETAG)) {
+                        if let Ok(etag) = etag.to_str() {
+                            etags.set(page.as_str(), etag.to_string()).await;
+                            let next_link = next.as_ref().and_then(|next| next.as_ref().ok());
+                            etags.set_next(page.as_str(), next_link.map(reqwest::Url::to_string)).await;
+                        }
+                    }
+                    return Some((Ok(response), (next, visited)));
+                }
+            }
+        })
+    }
+
+    /// Fetch a single page, failing on error statuses.
+    async fn get_page(&self, url: &reqwest::Url) -> Result<reqwest::Response> {
+        self.get_page_with_headers(url, reqwest::header::HeaderMap::new()).await
+    }
+
+    /// Fetch a single page with `headers` added to the request, failing on error statuses.
+    async fn get_page_with_headers(&self, url: &reqwest::Url, headers: reqwest::header::HeaderMap) -> Result<reqwest::Response> {
+        let mut waits = 0;
+        loop {
+            let response = self
+                .request_raw_with_headers(http::Method::GET, url.as_str(), None, headers.clone())
+                .await?;
+            // Wait out the rate limit rather than ending the walk.
+            let limit = crate::utils::RateLimitStatus::from_response(&response);
+            if waits < crate::utils::MAX_RATE_LIMIT_WAITS
+                && limit.is_limited(response.status())
+                && limit.time_until_reset().is_some()
+            {
+                waits += 1;
+                crate::utils::wait_for(&limit).await;
+                continue;
+            }
+            let status = response.status();
+            if status.is_client_error() || status.is_server_error() {
+                let body = response.bytes().await?;
+                return Err(crate::utils::StatusError::new(status, limit, &body).into());
+            }
+            return Ok(response);
+        }
+    }
+
+    /// Fetch every page of a page-numbered listing concurrently.
+    ///
+    /// The first page is fetched on its own to learn the `last` link, then the
+    /// remaining pages are requested with up to `concurrency` in flight and
+    /// yielded in order. Listings without page numbers fall back to following
+    /// next links one at a time.
+    pub fn pages_parallel<'a>(&'a self, uri: &str, concurrency: usize) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        use futures::StreamExt;
+
+        self.pages_parallel_indexed(uri, concurrency, false)
+            .map(|page| page.map(|(_, page)| page).map_err(|(_, e)| e))
+    }
+
+    /// Like `pages_parallel`, but yields each page as soon as it arrives rather
+    /// than in order, along with its index in the listing, the first page being 0.
+    ///
+    /// A slow page then does not hold back the pages after it. A failed page is
+    /// yielded as an error along with its index, so it can be fetched again.
+    pub fn pages_parallel_unordered<'a>(&'a self, uri: &str, concurrency: usize) -> impl futures::Stream<Item = std::result::Result<(usize, reqwest::Response), (usize, Error)>> + 'a {
+        self.pages_parallel_indexed(uri, concurrency, true)
+    }
+
+    fn pages_parallel_indexed<'a>(&'a self, uri: &str, concurrency: usize, unordered: bool) -> impl futures::Stream<Item = std::result::Result<(usize, reqwest::Response), (usize, Error)>> + 'a {
+        use futures::StreamExt;
+
+        let url = reqwest::Url::parse(&(self.host.clone() + uri));
+        futures::stream::once(async move {
+            let first = match url {
+                Ok(url) => self.get_page(&url).await,
+                Err(e) => Err(e.into()),
+            };
+            let first = match first {
+                Ok(first) => first,
+                Err(e) => return futures::stream::once(async { Err((0, e)) }).boxed(),
+            };
+            let links = crate::utils::ResponseLinkExt::pagination_links(&first);
+            let next_page = links
+                .next
+                .as_deref()
+                .and_then(|next| reqwest::Url::parse(next).ok())
+                .and_then(|next| crate::utils::PageQuery::from_url(&next).page);
+            let first_url = first.url().clone();
+            let first = futures::stream::once(async { Ok((0, first)) });
+
+            match (links.page_urls(), next_page) {
+                (Some(urls), Some(next_page)) => {
+                    let rest = urls
+                        .into_iter()
+                        .filter(move |url| crate::utils::PageQuery::from_url(url).page >= Some(next_page))
+                        .enumerate()
+                        .map(move |(i, url)| async move {
+                            self.get_page(&url).await.map(|page| (i + 1, page)).map_err(|e| (i + 1, e))
+                        });
+                    let rest = futures::stream::iter(rest);
+                    if unordered {
+                        first.chain(rest.buffer_unordered(concurrency.max(1))).boxed()
+                    } else {
+                        first.chain(rest.buffered(concurrency.max(1))).boxed()
+                    }
+                }
+                _ => match links.next {
+                    Some(next) => first
+                        .chain(
+                            self.pages_from_rel(
+                                reqwest::Url::parse(&next).map_err(Error::from),
+                                hyperx::header::RelationType::Next,
+                                None,
+                                Some(first_url),
+                            )
+                            .enumerate()
+                            .map(|(i, page)| page.map(|page| (i + 1, page)).map_err(|e| (i + 1, e))),
+                        )
+                        .boxed(),
+                    None => first.boxed(),
+                },
+            }
+        })
+        .flatten()
+    }
+
+    /// Like `pages`, but keeps requesting pages ahead of the consumer.
+    ///
+    /// At most `prefetch` pages are fetched before they are read, which hides the
+    /// latency of each request; with 0 each page is only fetched once it is asked
+    /// for. Pages are still yielded in order.
+    pub fn pages_prefetch(&self, uri: &str, prefetch: usize) -> impl futures::Stream<Item = Result<reqwest::Response>> {
+        use futures::StreamExt;
+
+        let client = self.clone();
+        let uri = uri.to_string();
+        // The fetching task is spawned on the first poll, so the stream can be
+        // built outside a runtime.
+        futures::stream::once(async move {
+            // Each fetch takes a permit: `prefetch` to begin with, plus one each
+            // time the consumer asks for a page.
+            let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(prefetch));
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+            let fetch_permits = permits.clone();
+            tokio::spawn(async move {
+                let pages = client.pages(&uri);
+                futures::pin_mut!(pages);
+                loop {
+                    tokio::select! {
+                        _ = tx.closed() => break,
+                        permit = fetch_permits.acquire() => match permit {
+                            Ok(permit) => permit.forget(),
+                            Err(_) => break,
+                        },
+                    }
+                    let page = match pages.next().await {
+                        Some(page) => page,
+                        None => break,
+                    };
+                    if tx.send(page).is_err() {
+                        break;
+                    }
+                }
+            });
+            futures::stream::unfold((rx, permits), |(mut rx, permits)| async move {
+                permits.add_permits(1);
+                rx.recv().await.map(|page| (page, (rx, permits)))
+            })
+        })
+        .flatten()
+    }
+
+    /// Stream the items of every page of a listing whose pages are JSON arrays.
+    pub fn items<'a, D>(&'a self, uri: &str) -> impl futures::Stream<Item = Result<D>> + 'a
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.items_at(uri, "")
+    }
+
+    /// Stream the items of every page of a listing, reading each page's items
+    /// from the array at the JSON pointer `pointer`, for example `/data`.
+    ///
+    /// A page that fails to fetch or does not hold an array there is yielded as
+    /// an error and ends the stream.
+    pub fn items_at<'a, D>(&'a self, uri: &str, pointer: &'a str) -> impl futures::Stream<Item = Result<D>> + 'a
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        use futures::{StreamExt, TryStreamExt};
+
+        self.pages(uri)
+            .and_then(move |response| async move {
+                let mut page: serde_json::Value = response.json().await?;
+                let items = page
+                    .pointer_mut(pointer)
+                    .map(serde_json::Value::take)
+                    .ok_or_else(|| anyhow!("`{}` not found in page", pointer))?;
+                Ok::<_, Error>(serde_json::from_value::<Vec<D>>(items)?)
+            })
+            .scan(false, |failed, page| {
+                let page = if *failed { None } else { *failed = page.is_err(); Some(page) };
+                futures::future::ready(page)
+            })
+            .map_ok(|items| futures::stream::iter(items.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    /// Query the GitHub GraphQL API.
+    pub fn graphql(&self) -> graphql::Graphql {
+        graphql::Graphql::new(self.clone())
     }"#;
 
 pub fn generate_client_generic_token(
@@ -486,9 +868,11 @@ pub fn generate_client_generic_token(
     let mut new_from_env = basic_new_from_env(proper_name, add_post_header);
     if proper_name.starts_with("Google") {
         new_from_env = GOOGLE_NEW_FROM_ENV_TEMPLATE.to_string();
+    } else if proper_name == "Zoom" {
+        new_from_env.push_str(ZOOM_SERVER_TO_SERVER_TEMPLATE);
     }
 
-    let add_post_header_struct = if add_post_header.is_empty() {
+    let mut add_post_header_struct = if add_post_header.is_empty() {
         "".to_string()
     } else {
         format!("{}: String,", to_snake_case(add_post_header))
@@ -506,7 +890,7 @@ pub fn generate_client_generic_token(
         "P: ToString,".to_string()
     };
 
-    let add_post_header_fn = if add_post_header.is_empty() {
+    let mut add_post_header_fn = if add_post_header.is_empty() {
         "".to_string()
     } else {
         format!(
@@ -522,13 +906,39 @@ pub fn generate_client_generic_token(
         "".to_string()
     };
 
+    let add_post_header_call = if add_post_header.is_empty() {
+        "".to_string()
+    } else {
+        format!("{},", to_snake_case(add_post_header))
+    };
+
+    let mut access_token_struct = ACCESS_TOKEN_STRUCT_TEMPLATE.to_string();
+    if proper_name == "Zoom" {
+        // The account ID is only set for server-to-server OAuth apps.
+        add_post_header_struct.push_str("account_id: String,");
+        add_post_header_fn.push_str("account_id: String::new(),");
+        access_token_struct.push_str(ACCESS_TOKEN_SCOPES_TEMPLATE);
+    }
+
     let consent_pattern = if proper_name.starts_with("Google") {
         "{}?client_id={}&access_type=offline&response_type=code&redirect_uri={}&state={}"
     } else {
         "{}?client_id={}&response_type=code&redirect_uri={}&state={}"
     };
 
-    let token_auth_template = get_token_auth_template(consent_pattern);
+    let mut token_auth_template = get_token_auth_template(consent_pattern);
+    if proper_name == "Zoom" {
+        // Server-to-server apps have no refresh token, they request a new access token instead.
+        token_auth_template = token_auth_template.replace(
+            "pub async fn refresh_access_token(&self) -> Result<AccessToken> {\n",
+            r#"pub async fn refresh_access_token(&self) -> Result<AccessToken> {
+    if !self.account_id.is_empty() {
+        return self.get_server_to_server_access_token().await;
+    }
+
+"#,
+        );
+    }
 
     format!(
         r#"use std::sync::Arc;
@@ -580,6 +990,36 @@ impl Client {{
         refresh_token: Q,
         {}
     ) -> Self
+    where
+        I: ToString,
+        K: ToString,
+        R: ToString,
+        T: ToString,
+        Q: ToString,
+        {}
+    {{
+        Client::new_with_config(
+            client_id,
+            client_secret,
+            redirect_uri,
+            token,
+            refresh_token,
+            {}
+            crate::utils::Config::default(),
+        )
+    }}
+
+    /// Create a new Client struct with the given `Config`, for example to
+    /// override the host or tune the underlying HTTP client.
+    pub fn new_with_config<I, K, R, T, Q{}>(
+        client_id: I,
+        client_secret: K,
+        redirect_uri: R,
+        token: T,
+        refresh_token: Q,
+        {}
+        config: crate::utils::Config,
+    ) -> Self
     where
         I: ToString,
         K: ToString,
@@ -590,12 +1030,18 @@ impl Client {{
     {{
         // Retry up to 3 times with increasing intervals between attempts.
         let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest::Client::builder().build();
+        let client = config.http_client();
         match client {{
             Ok(c) => {{
                 let client = reqwest_middleware::ClientBuilder::new(c)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware::default())
+                    // Record OpenTelemetry metrics when the `otel` feature is enabled.
+                    .with(crate::utils::MetricsMiddleware::default())
+                    // Parse the Link and rate limit headers of every response once.
+                    .with(crate::utils::PaginationMetadataMiddleware)
+                    // Wait while the rate limit reported by earlier responses is exhausted.
+                    .with(crate::utils::RateLimitStatusMiddleware::default())
                     // Retry failed requests.
                     .with(
                         reqwest_conditional_middleware::ConditionalMiddleware::new(
@@ -606,7 +1052,7 @@ impl Client {{
                     .build();
 
                 Client {{
-                    host: DEFAULT_HOST.to_string(),
+                    host: config.host.unwrap_or_else(|| DEFAULT_HOST.to_string()),
                     client_id: client_id.to_string(),
                     client_secret: client_secret.to_string(),
                     redirect_uri: redirect_uri.to_string(),
@@ -700,7 +1146,11 @@ impl Client {{
         token_endpoint.trim_start_matches("https://"),
         user_consent_endpoint.trim_start_matches("https://"),
         add_post_header_struct,
-        ACCESS_TOKEN_STRUCT_TEMPLATE,
+        access_token_struct,
+        add_post_header_type,
+        add_post_header_args,
+        add_post_header_args_where,
+        add_post_header_call,
         add_post_header_type,
         add_post_header_args,
         add_post_header_args_where,
@@ -773,6 +1223,104 @@ where
     )
 }
 
+const ZOOM_SERVER_TO_SERVER_TEMPLATE: &str = r#"
+
+/// Create a new Client struct for a server-to-server OAuth app.
+///
+/// Server-to-server apps authenticate with the account credentials grant, so no
+/// user consent, redirect URI or refresh token is needed. An access token is
+/// requested before the first request and again whenever it expires.
+pub fn new_server_to_server<A, I, K>(account_id: A, client_id: I, client_secret: K) -> Self
+where
+    A: ToString,
+    I: ToString,
+    K: ToString,
+{
+    let mut c = Client::new(client_id, client_secret, "", "", "");
+    c.account_id = account_id.to_string();
+    c.auto_refresh = true;
+    // Mark the empty token as expired so the first request fetches one.
+    c.token = Arc::new(RwLock::new(InnerToken {
+        access_token: String::new(),
+        refresh_token: String::new(),
+        expires_at: Some(Instant::now()),
+    }));
+    c
+}
+
+/// Create a new Client struct for a server-to-server OAuth app from the
+/// `ZOOM_ACCOUNT_ID`, `ZOOM_CLIENT_ID` and `ZOOM_CLIENT_SECRET` environment variables.
+pub fn new_server_to_server_from_env() -> Self {
+    let account_id = env::var("ZOOM_ACCOUNT_ID").expect("must set ZOOM_ACCOUNT_ID");
+    let client_id = env::var("ZOOM_CLIENT_ID").expect("must set ZOOM_CLIENT_ID");
+    let client_secret = env::var("ZOOM_CLIENT_SECRET").expect("must set ZOOM_CLIENT_SECRET");
+
+    Client::new_server_to_server(account_id, client_id, client_secret)
+}
+
+/// Get an access token for a server-to-server OAuth app with the account credentials grant.
+///
+/// The token is cached by the client until it expires. The scopes granted to the
+/// app are returned in the token's `scope`.
+pub async fn get_server_to_server_access_token(&self) -> Result<AccessToken> {
+    if self.account_id.is_empty() {
+        return Err(anyhow!("account id cannot be empty"));
+    }
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.append(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_static("application/json"),
+    );
+
+    let params = [
+        ("grant_type", "account_credentials"),
+        ("account_id", &self.account_id),
+    ];
+    let client = reqwest::Client::new();
+    let resp = client
+        .post(TOKEN_ENDPOINT)
+        .headers(headers)
+        .form(&params)
+        .basic_auth(&self.client_id, Some(&self.client_secret))
+        .send()
+        .await?;
+
+    let s = resp.status();
+    if !s.is_success() {
+        return Err(anyhow!(
+            "requesting server-to-server access token failed: code: {}, error: {:?}",
+            s,
+            resp.text().await.unwrap_or_default()
+        ));
+    }
+
+    // Unwrap the response.
+    let t: AccessToken = resp.json().await?;
+
+    *self.token.write().await = InnerToken {
+        access_token: t.access_token.clone(),
+        refresh_token: String::new(),
+        expires_at: Self::compute_expires_at(t.expires_in),
+    };
+
+    Ok(t)
+}"#;
+
+const ACCESS_TOKEN_SCOPES_TEMPLATE: &str = r#"
+
+impl AccessToken {
+    /// Returns the scopes granted to the token.
+    pub fn scopes(&self) -> Vec<&str> {
+        self.scope.split_whitespace().collect()
+    }
+
+    /// Returns true if the token was granted `scope`.
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scope.split_whitespace().any(|s| s == scope)
+    }
+}"#;
+
 const GOOGLE_NEW_FROM_ENV_TEMPLATE: &str = r#"
 /// Create a new Client struct from environment variables. It
 /// takes a type that can convert into
@@ -798,6 +1346,12 @@ where
             let client = reqwest_middleware::ClientBuilder::new(c)
                 // Trace HTTP requests. See the tracing crate to make use of these traces.
                 .with(reqwest_tracing::TracingMiddleware::default())
+                // Record OpenTelemetry metrics when the `otel` feature is enabled.
+                .with(crate::utils::MetricsMiddleware::default())
+                // Parse the Link and rate limit headers of every response once.
+                .with(crate::utils::PaginationMetadataMiddleware)
+                // Wait while the rate limit reported by earlier responses is exhausted.
+                .with(crate::utils::RateLimitStatusMiddleware::default())
                 // Retry failed requests.
                 .with(
                     reqwest_conditional_middleware::ConditionalMiddleware::new(
@@ -827,6 +1381,18 @@ where
 "#;
 
 pub fn generate_client_generic_api_key(proper_name: &str, add_post_header: &str) -> String {
+    let new_with_config = if proper_name == "Okta" {
+        OKTA_NEW_WITH_CONFIG_TEMPLATE
+    } else {
+        NEW_WITH_CONFIG_TEMPLATE
+    };
+
+    let new_with_rate_limit = if proper_name == "Okta" {
+        OKTA_NEW_WITH_RATE_LIMIT_TEMPLATE
+    } else {
+        ""
+    };
+
     format!(
         r#"use std::env;
 
@@ -849,33 +1415,10 @@ impl Client {{
     where
         T: ToString,
     {{
-        let client = reqwest::Client::builder().build();
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        match client {{
-            Ok(c) => {{
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
-                    .build();
-
-                Client {{
-                    host: DEFAULT_HOST.to_string(),
-                    token: token.to_string(),
-
-                    client,
-                }}
-            }}
-            Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
-        }}
+        Client::new_with_config(token, crate::utils::Config::default())
     }}
 
+{}
     /// Override the default host for the client.
     pub fn with_host<H>(&self, host: H) -> Self
     where
@@ -900,14 +1443,131 @@ impl Client {{
             token,
         )
     }}
-
+{}
     {}"#,
+        new_with_config,
         proper_name.to_uppercase().replace('.', ""),
         proper_name.to_uppercase().replace('.', ""),
+        new_with_rate_limit,
         get_shared_functions(proper_name, add_post_header)
     )
 }
 
+const OKTA_NEW_WITH_CONFIG_TEMPLATE: &str = r#"
+    /// Create a new Client struct with the given `Config`, for example to
+    /// override the host or tune the underlying HTTP client.
+    pub fn new_with_config<T>(
+        token: T,
+        config: crate::utils::Config,
+    ) -> Self
+    where
+        T: ToString,
+    {
+        Client::new_with_config_and_rate_limit(token, config, crate::utils::RateLimitMiddleware::default())
+    }
+"#;
+
+const OKTA_NEW_WITH_RATE_LIMIT_TEMPLATE: &str = r#"
+    /// Create a new Client struct that keeps its rate limit state in `rate_limit`.
+    ///
+    /// Clients created with clones of the same `RateLimitMiddleware`, or with
+    /// middlewares using the same `RateLimitStore`, share their rate limit budget
+    /// instead of each exhausting it on their own.
+    pub fn new_with_rate_limit<T>(token: T, rate_limit: crate::utils::RateLimitMiddleware) -> Self
+    where
+        T: ToString,
+    {
+        Client::new_with_config_and_rate_limit(token, crate::utils::Config::default(), rate_limit)
+    }
+
+    /// Create a new Client struct with the given `Config` that keeps its rate
+    /// limit state in `rate_limit`, see `new_with_config` and `new_with_rate_limit`.
+    pub fn new_with_config_and_rate_limit<T>(
+        token: T,
+        config: crate::utils::Config,
+        rate_limit: crate::utils::RateLimitMiddleware,
+    ) -> Self
+    where
+        T: ToString,
+    {
+        let client = config.http_client();
+        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        match client {
+            Ok(c) => {
+                let client = reqwest_middleware::ClientBuilder::new(c)
+                    // Trace HTTP requests. See the tracing crate to make use of these traces.
+                    .with(reqwest_tracing::TracingMiddleware::default())
+                    // Record OpenTelemetry metrics when the `otel` feature is enabled.
+                    .with(crate::utils::MetricsMiddleware::default())
+                    // Parse the Link and rate limit headers of every response once.
+                    .with(crate::utils::PaginationMetadataMiddleware)
+                    // Retry failed requests.
+                    .with(
+                        reqwest_conditional_middleware::ConditionalMiddleware::new(
+                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
+                            |req: &reqwest::Request| req.try_clone().is_some()
+                        )
+                    )
+                    // Wait for and retry requests that hit the rate limit.
+                    .with(rate_limit)
+                    .build();
+
+                Client {
+                    host: config.host.unwrap_or_else(|| DEFAULT_HOST.to_string()),
+                    token: token.to_string(),
+
+                    client,
+                }
+            }
+            Err(e) => panic!("creating reqwest client failed: {:?}", e),
+        }
+    }
+"#;
+
+const NEW_WITH_CONFIG_TEMPLATE: &str = r#"
+    /// Create a new Client struct with the given `Config`, for example to
+    /// override the host or tune the underlying HTTP client.
+    pub fn new_with_config<T>(
+        token: T,
+        config: crate::utils::Config,
+    ) -> Self
+    where
+        T: ToString,
+    {
+        let client = config.http_client();
+        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        match client {
+            Ok(c) => {
+                let client = reqwest_middleware::ClientBuilder::new(c)
+                    // Trace HTTP requests. See the tracing crate to make use of these traces.
+                    .with(reqwest_tracing::TracingMiddleware::default())
+                    // Record OpenTelemetry metrics when the `otel` feature is enabled.
+                    .with(crate::utils::MetricsMiddleware::default())
+                    // Parse the Link and rate limit headers of every response once.
+                    .with(crate::utils::PaginationMetadataMiddleware)
+                    // Wait while the rate limit reported by earlier responses is exhausted.
+                    .with(crate::utils::RateLimitStatusMiddleware::default())
+                    // Retry failed requests.
+                    .with(
+                        reqwest_conditional_middleware::ConditionalMiddleware::new(
+                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
+                            |req: &reqwest::Request| req.try_clone().is_some()
+                        )
+                    )
+                    .build();
+
+                Client {
+                    host: config.host.unwrap_or_else(|| DEFAULT_HOST.to_string()),
+                    token: token.to_string(),
+
+                    client,
+                }
+            }
+            Err(e) => panic!("creating reqwest client failed: {:?}", e),
+        }
+    }
+"#;
+
 fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
     let post_header_args = if !add_post_header.is_empty() {
         format!(
@@ -945,7 +1605,7 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
         get_shared_raw_functions_without_refresh(&bearer, &post_header_args)
     };
 
-    format!(
+    let mut shared = format!(
         r#"
 {}
 
@@ -961,6 +1621,7 @@ async fn request<Out>(
     let response = self.request_raw(method, uri, body).await?;
 
     let status = response.status();
+    let rate_limit = crate::utils::RateLimitStatus::from_headers(response.headers());
 
     let response_body = response.bytes().await?;
 
@@ -973,17 +1634,9 @@ async fn request<Out>(
         }};
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = crate::utils::StatusError::new(status, rate_limit, &response_body);
 
-        Err(error)
+        Err(error.into())
     }}
 }}
 
@@ -999,11 +1652,8 @@ where
     let response = self.request_raw(method, uri, body).await?;
 
     let status = response.status();
-    let link = response
-        .headers()
-        .get(http::header::LINK)
-        .and_then(|l| l.to_str().ok())
-        .and_then(|l| l.parse().ok());
+    let rate_limit = crate::utils::RateLimitStatus::from_headers(response.headers());
+    let link = crate::utils::link_from_headers(response.headers());
 
     let response_body = response.bytes().await?;
 
@@ -1017,16 +1667,8 @@ where
         }};
         parsed_response.map(|out| (link, out)).map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
-        Err(error)
+        let error = crate::utils::StatusError::new(status, rate_limit, &response_body);
+        Err(error.into())
     }}
 }}
 
@@ -1040,7 +1682,7 @@ async fn post_form<Out>(
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if reqwest::Url::parse(uri).is_ok() {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1066,6 +1708,7 @@ async fn post_form<Out>(
     let response = req.send().await?;
 
     let status = response.status();
+    let rate_limit = crate::utils::RateLimitStatus::from_headers(response.headers());
 
     let response_body = response.bytes().await?;
 
@@ -1081,17 +1724,9 @@ async fn post_form<Out>(
         }};
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = crate::utils::StatusError::new(status, rate_limit, &response_body);
 
-        Err(error)
+        Err(error.into())
     }}
 }}
 
@@ -1106,7 +1741,7 @@ async fn request_with_accept_mime<Out>(
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if reqwest::Url::parse(uri).is_ok() {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1130,6 +1765,7 @@ async fn request_with_accept_mime<Out>(
     let response = req.send().await?;
 
     let status = response.status();
+    let rate_limit = crate::utils::RateLimitStatus::from_headers(response.headers());
 
     let response_body = response.bytes().await?;
 
@@ -1145,17 +1781,9 @@ async fn request_with_accept_mime<Out>(
         }};
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = crate::utils::StatusError::new(status, rate_limit, &response_body);
 
-        Err(error)
+        Err(error.into())
     }}
 }}
 
@@ -1171,7 +1799,7 @@ async fn request_with_mime<Out>(
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if reqwest::Url::parse(uri).is_ok() {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1214,6 +1842,7 @@ async fn request_with_mime<Out>(
     let response = req.send().await?;
 
     let status = response.status();
+    let rate_limit = crate::utils::RateLimitStatus::from_headers(response.headers());
 
     let response_body = response.bytes().await?;
 
@@ -1226,17 +1855,9 @@ async fn request_with_mime<Out>(
         }};
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        let error = crate::utils::StatusError::new(status, rate_limit, &response_body);
 
-        Err(error)
+        Err(error.into())
     }}
 }}
 
@@ -1286,22 +1907,350 @@ where
     D: serde::de::DeserializeOwned + 'static + Send,
 {{
     let mut global_items = Vec::new();
+    // Servers may send relative targets, which resolve against the page they came from.
+    let mut page_url = reqwest::Url::parse(&(self.host.clone() + uri))?;
+    let mut visited = std::collections::HashSet::new();
+    visited.insert(page_url.clone());
     let (new_link, mut items) = self.get_pages(uri).await?;
     let mut link = new_link;
     while !items.is_empty() {{
         global_items.append(&mut items);
         // We need to get the next link.
         if let Some(url) = link.as_ref().and_then(crate::utils::next_link) {{
-            let url = reqwest::Url::parse(&url)?;
+            let url = page_url.join(&url)?;
+            if !visited.insert(url.clone()) {{
+                return Err(crate::utils::PaginationCycle {{ url: url.to_string() }}.into());
+            }}
             let (new_link, new_items) = self.get_pages_url(&url).await?;
             link = new_link;
             items = new_items;
+            page_url = url;
         }}
     }}
 
     Ok(global_items)
 }}
 
+/// Stream every page of a listing, following `rel="next"` links.
+///
+/// The stream ends after the first page without a next link. Request errors,
+/// error statuses and next links that cannot be parsed are yielded as items
+/// and end the stream.
+pub fn pages<'a>(&'a self, uri: &str) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {{
+    self.pages_from(reqwest::Url::parse(&(self.host.clone() + uri)).map_err(Error::from))
+}}
+
+/// Stream every page of a listing, following links with the relation `rel`
+/// rather than `next`, for example `prev` to walk history backwards. Registered
+/// relations have their own variant, such as `RelationType::NextArchive` for
+/// `next-archive`, and any other relation is `RelationType::ExtRelType`.
+pub fn pages_by_rel<'a>(&'a self, uri: &str, rel: hyperx::header::RelationType) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {{
+    self.pages_from_rel(reqwest::Url::parse(&(self.host.clone() + uri)).map_err(Error::from), rel, None, None)
+}}
+
+/// Stream the pages after `response`, following its `rel="next"` link.
+///
+/// Use it to carry on from a first page fetched some other way. The stream
+/// is empty if `response` has no next link.
+pub fn pages_after<'a>(&'a self, response: &reqwest::Response) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {{
+    use futures::StreamExt;
+
+    let first = response.url().clone();
+    let next = crate::utils::ResponseLinkExt::next_link(response)
+        .map(|next| reqwest::Url::parse(&next).map_err(Error::from));
+    futures::stream::iter(next).flat_map(move |url| {{
+        self.pages_from_rel(url, hyperx::header::RelationType::Next, None, Some(first.clone()))
+    }})
+}}
+
+/// Stream the pages of a listing that changed since an earlier walk, following
+/// `rel="next"` links.
+///
+/// The `ETag` and next link of each page are kept in `etags`, and the tag is
+/// sent back as `If-None-Match` the next time that page is fetched. Pages the
+/// server answers with `304 Not Modified` are skipped, and the walk carries on
+/// from the next link stored for them, so only the pages that changed are
+/// yielded.
+pub fn pages_if_changed<'a>(&'a self, uri: &str, etags: &'a dyn crate::utils::EtagStore) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {{
+    self.pages_from_rel(
+        reqwest::Url::parse(&(self.host.clone() + uri)).map_err(Error::from),
+        hyperx::header::RelationType::Next,
+        Some(etags),
+        None,
+    )
+}}
+
+fn pages_from<'a>(&'a self, url: Result<reqwest::Url>) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {{
+    self.pages_from_rel(url, hyperx::header::RelationType::Next, None, None)
+}}
+
+/// Follow `rel` links from `url`. `after` is a page already fetched before
+/// the walk, so a link back to it is a cycle too.
+fn pages_from_rel<'a>(&'a self, url: Result<reqwest::Url>, rel: hyperx::header::RelationType, etags: Option<&'a dyn crate::utils::EtagStore>, after: Option<reqwest::Url>) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {{
+    let visited: std::collections::HashSet<_> = after.into_iter().collect();
+    futures::stream::unfold((Some(url), visited), move |(url, mut visited)| {{
+        let rel = rel.clone();
+        async move {{
+            let mut url = url?;
+            loop {{
+                let page = match url {{
+                    Ok(page) => page,
+                    Err(e) => return Some((Err(e), (None, visited))),
+                }};
+                if !visited.insert(page.clone()) {{
+                    let cycle = crate::utils::PaginationCycle {{ url: page.to_string() }};
+                    return Some((Err(cycle.into()), (None, visited)));
+                }}
+                let etag = match etags {{
+                    Some(etags) => etags.get(page.as_str()).await,
+                    None => None,
+                }};
+                let mut headers = reqwest::header::HeaderMap::new();
+                if let Some(etag) = etag.and_then(|etag| reqwest::header::HeaderValue::from_str(&etag).ok()) {{
+                    headers.insert(reqwest::header::IF_NONE_MATCH, etag);
+                }}
+                let response = match self.get_page_with_headers(&page, headers).await {{
+                    Ok(response) => response,
+                    Err(e) => return Some((Err(e), (None, visited))),
+                }};
+                // The page is the same as on the last walk, so skip it and carry on
+                // from the next link it had then.
+                if response.status() == http::StatusCode::NOT_MODIFIED {{
+                    let next = match etags {{
+                        Some(etags) => etags.get_next(page.as_str()).await,
+                        None => None,
+                    }};
+                    match next {{
+                        Some(next) => {{
+                            url = reqwest::Url::parse(&next).map_err(Error::from);
+                            continue;
+                        }}
+                        None => return None,
+                    }}
+                }}
+                // A non-empty Link header none of whose links parse is malformed rather than
+                // the end of the listing, so the error follows the page.
+                let links = crate::utils::ResponseLinkExt::links(&response);
+                let malformed = links.is_empty()
+                    && response
+                        .headers()
+                        .get_all(http::header::LINK)
+                        .iter()
+                        .any(|value| value.as_bytes().iter().any(|b| !b.is_ascii_whitespace()));
+                let next = if malformed {{
+                    Some(Err(anyhow!("malformed Link header from {{}}", page)))
+                }} else {{
+                    links
+                        .into_iter()
+                        .find_map(|(rels, target)| rels.contains(&rel).then_some(target))
+                        .map(|next| reqwest::Url::parse(&next).map_err(Error::from))
+                }};
+                if let (Some(etags), Some(etag)) = (etags, response.headers().get(http::header::ETAG)) {{
+                    if let Ok(etag) = etag.to_str() {{
+                        etags.set(page.as_str(), etag.to_string()).await;
+                        let next_link = next.as_ref().and_then(|next| next.as_ref().ok());
+                        etags.set_next(page.as_str(), next_link.map(reqwest::Url::to_string)).await;
+                    }}
+                }}
+                return Some((Ok(response), (next, visited)));
+            }}
+        }}
+    }})
+}}
+
+/// Fetch a single page, failing on error statuses.
+async fn get_page(&self, url: &reqwest::Url) -> Result<reqwest::Response> {{
+    self.get_page_with_headers(url, reqwest::header::HeaderMap::new()).await
+}}
+
+/// Fetch a single page with `headers` added to the request, failing on error statuses.
+async fn get_page_with_headers(&self, url: &reqwest::Url, headers: reqwest::header::HeaderMap) -> Result<reqwest::Response> {{
+    let mut waits = 0;
+    loop {{
+        let response = self
+            .request_raw_with_headers(http::Method::GET, url.as_str(), None, headers.clone())
+            .await?;
+        // Wait out the rate limit rather than ending the walk.
+        let limit = crate::utils::RateLimitStatus::from_response(&response);
+        if waits < crate::utils::MAX_RATE_LIMIT_WAITS
+            && limit.is_limited(response.status())
+            && limit.time_until_reset().is_some()
+        {{
+            waits += 1;
+            crate::utils::wait_for(&limit).await;
+            continue;
+        }}
+        let status = response.status();
+        if status.is_client_error() || status.is_server_error() {{
+            let body = response.bytes().await?;
+            return Err(crate::utils::StatusError::new(status, limit, &body).into());
+        }}
+        return Ok(response);
+    }}
+}}
+
+/// Fetch every page of a page-numbered listing concurrently.
+///
+/// The first page is fetched on its own to learn the `last` link, then the
+/// remaining pages are requested with up to `concurrency` in flight and
+/// yielded in order. Listings without page numbers fall back to following
+/// next links one at a time.
+pub fn pages_parallel<'a>(&'a self, uri: &str, concurrency: usize) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {{
+    use futures::StreamExt;
+
+    self.pages_parallel_indexed(uri, concurrency, false)
+        .map(|page| page.map(|(_, page)| page).map_err(|(_, e)| e))
+}}
+
+/// Like `pages_parallel`, but yields each page as soon as it arrives rather
+/// than in order, along with its index in the listing, the first page being 0.
+///
+/// A slow page then does not hold back the pages after it. A failed page is
+/// yielded as an error along with its index, so it can be fetched again.
+pub fn pages_parallel_unordered<'a>(&'a self, uri: &str, concurrency: usize) -> impl futures::Stream<Item = std::result::Result<(usize, reqwest::Response), (usize, Error)>> + 'a {{
+    self.pages_parallel_indexed(uri, concurrency, true)
+}}
+
+fn pages_parallel_indexed<'a>(&'a self, uri: &str, concurrency: usize, unordered: bool) -> impl futures::Stream<Item = std::result::Result<(usize, reqwest::Response), (usize, Error)>> + 'a {{
+    use futures::StreamExt;
+
+    let url = reqwest::Url::parse(&(self.host.clone() + uri));
+    futures::stream::once(async move {{
+        let first = match url {{
+            Ok(url) => self.get_page(&url).await,
+            Err(e) => Err(e.into()),
+        }};
+        let first = match first {{
+            Ok(first) => first,
+            Err(e) => return futures::stream::once(async {{ Err((0, e)) }}).boxed(),
+        }};
+        let links = crate::utils::ResponseLinkExt::pagination_links(&first);
+        let next_page = links
+            .next
+            .as_deref()
+            .and_then(|next| reqwest::Url::parse(next).ok())
+            .and_then(|next| crate::utils::PageQuery::from_url(&next).page);
+        let first_url = first.url().clone();
+        let first = futures::stream::once(async {{ Ok((0, first)) }});
+
+        match (links.page_urls(), next_page) {{
+            (Some(urls), Some(next_page)) => {{
+                let rest = urls
+                    .into_iter()
+                    .filter(move |url| crate::utils::PageQuery::from_url(url).page >= Some(next_page))
+                    .enumerate()
+                    .map(move |(i, url)| async move {{
+                        self.get_page(&url).await.map(|page| (i + 1, page)).map_err(|e| (i + 1, e))
+                    }});
+                let rest = futures::stream::iter(rest);
+                if unordered {{
+                    first.chain(rest.buffer_unordered(concurrency.max(1))).boxed()
+                }} else {{
+                    first.chain(rest.buffered(concurrency.max(1))).boxed()
+                }}
+            }}
+            _ => match links.next {{
+                Some(next) => first
+                    .chain(
+                        self.pages_from_rel(
+                            reqwest::Url::parse(&next).map_err(Error::from),
+                            hyperx::header::RelationType::Next,
+                            None,
+                            Some(first_url),
+                        )
+                        .enumerate()
+                        .map(|(i, page)| page.map(|page| (i + 1, page)).map_err(|e| (i + 1, e))),
+                    )
+                    .boxed(),
+                None => first.boxed(),
+            }},
+        }}
+    }})
+    .flatten()
+}}
+
+/// Like `pages`, but keeps requesting pages ahead of the consumer.
+///
+/// At most `prefetch` pages are fetched before they are read, which hides the
+/// latency of each request; with 0 each page is only fetched once it is asked
+/// for. Pages are still yielded in order.
+pub fn pages_prefetch(&self, uri: &str, prefetch: usize) -> impl futures::Stream<Item = Result<reqwest::Response>> {{
+    use futures::StreamExt;
+
+    let client = self.clone();
+    let uri = uri.to_string();
+    // The fetching task is spawned on the first poll, so the stream can be
+    // built outside a runtime.
+    futures::stream::once(async move {{
+        // Each fetch takes a permit: `prefetch` to begin with, plus one each
+        // time the consumer asks for a page.
+        let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(prefetch));
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let fetch_permits = permits.clone();
+        tokio::spawn(async move {{
+            let pages = client.pages(&uri);
+            futures::pin_mut!(pages);
+            loop {{
+                tokio::select! {{
+                    _ = tx.closed() => break,
+                    permit = fetch_permits.acquire() => match permit {{
+                        Ok(permit) => permit.forget(),
+                        Err(_) => break,
+                    }},
+                }}
+                let page = match pages.next().await {{
+                    Some(page) => page,
+                    None => break,
+                }};
+                if tx.send(page).is_err() {{
+                    break;
+                }}
+            }}
+        }});
+        futures::stream::unfold((rx, permits), |(mut rx, permits)| async move {{
+            permits.add_permits(1);
+            rx.recv().await.map(|page| (page, (rx, permits)))
+        }})
+    }})
+    .flatten()
+}}
+
+/// Stream the items of every page of a listing whose pages are JSON arrays.
+pub fn items<'a, D>(&'a self, uri: &str) -> impl futures::Stream<Item = Result<D>> + 'a
+where
+    D: serde::de::DeserializeOwned + 'static + Send,
+{{
+    self.items_at(uri, "")
+}}
+
+/// Stream the items of every page of a listing, reading each page's items
+/// from the array at the JSON pointer `pointer`, for example `/data`.
+///
+/// A page that fails to fetch or does not hold an array there is yielded as
+/// an error and ends the stream.
+pub fn items_at<'a, D>(&'a self, uri: &str, pointer: &'a str) -> impl futures::Stream<Item = Result<D>> + 'a
+where
+    D: serde::de::DeserializeOwned + 'static + Send,
+{{
+    use futures::{{StreamExt, TryStreamExt}};
+
+    self.pages(uri)
+        .and_then(move |response| async move {{
+            let mut page: serde_json::Value = response.json().await?;
+            let items = page
+                .pointer_mut(pointer)
+                .map(serde_json::Value::take)
+                .ok_or_else(|| anyhow!("`{{}}` not found in page", pointer))?;
+            Ok::<_, Error>(serde_json::from_value::<Vec<D>>(items)?)
+        }})
+        .scan(false, |failed, page| {{
+            let page = if *failed {{ None }} else {{ *failed = page.is_err(); Some(page) }};
+            futures::future::ready(page)
+        }})
+        .map_ok(|items| futures::stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+}}
+
 #[allow(dead_code)]
 async fn get_pages<D>(&self, uri: &str) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
 where
@@ -1374,9 +2323,151 @@ where
     ).await
 }}"#,
         raw_request
-    )
+    );
+
+    if proper_name == "Slack" {
+        shared.push_str(SLACK_CURSOR_TEMPLATE);
+    } else if proper_name.starts_with("Google") {
+        shared.push_str(GOOGLE_PAGE_TOKEN_TEMPLATE);
+    } else if proper_name == "Stripe" {
+        shared.push_str(STRIPE_STARTING_AFTER_TEMPLATE);
+    }
+
+    shared
 }
 
+const GOOGLE_PAGE_TOKEN_TEMPLATE: &str = r#"
+
+/// Stream the items of every page of a list method paginated by page token.
+///
+/// `uri` is the path along with its query, for example
+/// `/files?pageSize=100`, and `field` names the array each page holds its
+/// items in, for example `files`. The `pageToken` argument is filled in from
+/// each page's `nextPageToken` until a page comes back without one. A page
+/// handing back the token it was requested with also ends the stream.
+pub fn items_by_page_token<'a, D>(&'a self, uri: &str, field: &'a str) -> impl futures::Stream<Item = Result<D>> + 'a
+where
+    D: serde::de::DeserializeOwned + 'static + Send,
+{
+    use futures::TryStreamExt;
+
+    let url = reqwest::Url::parse(&(self.host.clone() + uri));
+    futures::stream::try_unfold(Some(url), move |url| async move {
+        let mut url = match url {
+            Some(url) => url?,
+            None => return Ok(None),
+        };
+        let mut page: serde_json::Value = self.get_page(&url).await?.json().await?;
+        // Google leaves out the array entirely when a page is empty.
+        let items: Vec<D> = match page.get_mut(field).map(serde_json::Value::take) {
+            Some(items) => serde_json::from_value(items)?,
+            None => Vec::new(),
+        };
+
+        let previous = url
+            .query_pairs()
+            .find(|(k, _)| k == "pageToken")
+            .map(|(_, v)| v.into_owned());
+        let next = match page.get("nextPageToken").and_then(|t| t.as_str()) {
+            Some(token) if !token.is_empty() && previous.as_deref() != Some(token) => {
+                crate::utils::set_query_param(&mut url, "pageToken", token);
+                Some(Ok(url))
+            }
+            _ => None,
+        };
+        Ok::<_, Error>(Some((futures::stream::iter(items.into_iter().map(Ok)), next)))
+    })
+    .try_flatten()
+}"#;
+
+const STRIPE_STARTING_AFTER_TEMPLATE: &str = r#"
+
+/// Stream the items of every page of a list method.
+///
+/// `uri` is the path along with its query, for example
+/// `/v1/customers?limit=100`. Stripe pages hold their items in `data` and set
+/// `has_more` while there are more to come; the next page starts after the
+/// object whose ID `id` returns for the last item of the current page.
+pub fn items_starting_after<'a, D, F>(&'a self, uri: &str, id: F) -> impl futures::Stream<Item = Result<D>> + 'a
+where
+    D: serde::de::DeserializeOwned + 'static + Send,
+    F: Fn(&D) -> String + 'a,
+{
+    use futures::TryStreamExt;
+
+    let id = std::sync::Arc::new(id);
+    let url = reqwest::Url::parse(&(self.host.clone() + uri));
+    futures::stream::try_unfold(Some(url), move |url| {
+        let id = id.clone();
+        async move {
+            let mut url = match url {
+                Some(url) => url?,
+                None => return Ok(None),
+            };
+            let mut page: serde_json::Value = self.get_page(&url).await?.json().await?;
+            let items = page
+                .get_mut("data")
+                .map(serde_json::Value::take)
+                .ok_or_else(|| anyhow!("`data` not found in page"))?;
+            let items: Vec<D> = serde_json::from_value(items)?;
+
+            let next = match items.last() {
+                Some(last) if page["has_more"] == true => {
+                    crate::utils::set_query_param(&mut url, "starting_after", &(*id)(last));
+                    Some(Ok(url))
+                }
+                _ => None,
+            };
+            Ok::<_, Error>(Some((futures::stream::iter(items.into_iter().map(Ok)), next)))
+        }
+    })
+    .try_flatten()
+}"#;
+
+const SLACK_CURSOR_TEMPLATE: &str = r#"
+
+/// Stream the items of every page of a cursor-paginated Web API method.
+///
+/// `uri` is the method along with its query, for example
+/// `/conversations.list?limit=200`, and `field` names the array each page
+/// holds its items in, for example `channels`. The `cursor` argument is
+/// filled in from each page's `response_metadata.next_cursor` until Slack
+/// returns an empty one. Rate limited calls wait out their `Retry-After`.
+pub fn items_by_cursor<'a, D>(&'a self, uri: &str, field: &'a str) -> impl futures::Stream<Item = Result<D>> + 'a
+where
+    D: serde::de::DeserializeOwned + 'static + Send,
+{
+    use futures::TryStreamExt;
+
+    let url = reqwest::Url::parse(&(self.host.clone() + uri));
+    futures::stream::try_unfold(Some(url), move |url| async move {
+        let mut url = match url {
+            Some(url) => url?,
+            None => return Ok(None),
+        };
+        let mut page: serde_json::Value = self.get_page(&url).await?.json().await?;
+        if page["ok"] == false {
+            return Err(anyhow!("slack error: {}", page["error"]));
+        }
+        let items = page
+            .get_mut(field)
+            .map(serde_json::Value::take)
+            .ok_or_else(|| anyhow!("`{}` not found in page", field))?;
+        let items: Vec<D> = serde_json::from_value(items)?;
+
+        // Slack marks the last page with an empty cursor rather than none.
+        let next = match page.pointer("/response_metadata/next_cursor").and_then(|c| c.as_str()) {
+            Some(cursor) if !cursor.is_empty() => {
+                crate::utils::set_query_param(&mut url, "cursor", cursor);
+                Some(Ok(url))
+            }
+            _ => None,
+        };
+        Ok::<_, Error>(Some((futures::stream::iter(items.into_iter().map(Ok)), next)))
+    })
+    .try_flatten()
+}"#;
+
 fn get_shared_raw_functions_without_refresh(bearer: &str, post_header_args: &str) -> String {
     format!(
         r#"
@@ -1396,7 +2487,18 @@ async fn request_raw(
     body: Option<reqwest::Body>,
 ) -> Result<reqwest::Response>
 {{
-    let u = if uri.starts_with("https://") {{
+    self.request_raw_with_headers(method, uri, body, reqwest::header::HeaderMap::new()).await
+}}
+
+async fn request_raw_with_headers(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+    headers: reqwest::header::HeaderMap,
+) -> Result<reqwest::Response>
+{{
+    let u = if reqwest::Url::parse(uri).is_ok() {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1414,6 +2516,7 @@ async fn request_raw(
         reqwest::header::HeaderValue::from_static("application/json"),
     );
     {}
+    req = req.headers(headers);
     if let Some(auth_str) = auth {{
         req = req.header(http::header::AUTHORIZATION, &*auth_str);
     }}
@@ -1446,7 +2549,7 @@ async fn make_request(
     uri: &str,
     body: Option<reqwest::Body>,
 ) -> Result<reqwest::Request> {{
-    let u = if uri.starts_with("https://") {{
+    let u = if reqwest::Url::parse(uri).is_ok() {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1479,12 +2582,7 @@ async fn make_request(
     Ok(req.build()?)
 }}
 
-async fn request_raw(
-    &self,
-    method: reqwest::Method,
-    uri: &str,
-    body: Option<reqwest::Body>,
-) -> Result<reqwest::Response> {{
+async fn refresh_if_expired(&self) -> Result<()> {{
     if self.auto_refresh {{
         let expired = self.is_expired().await;
 
@@ -1512,7 +2610,29 @@ async fn request_raw(
         }}
     }}
 
-    let req = self.make_request(&method, uri, body).await?;
+    Ok(())
+}}
+
+async fn request_raw(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+) -> Result<reqwest::Response> {{
+    self.request_raw_with_headers(method, uri, body, reqwest::header::HeaderMap::new()).await
+}}
+
+async fn request_raw_with_headers(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+    headers: reqwest::header::HeaderMap,
+) -> Result<reqwest::Response> {{
+    self.refresh_if_expired().await?;
+
+    let mut req = self.make_request(&method, uri, body).await?;
+    req.headers_mut().extend(headers);
     let resp = self.client.execute(req).await?;
 
     Ok(resp)
@@ -1699,13 +2819,35 @@ impl Client {{
         K: ToString,
         T: ToString,
     {{
-        let client = reqwest::Client::builder().build();
+        Client::new_with_config(client_id, client_secret, token, crate::utils::Config::default())
+    }}
+
+    /// Create a new Client struct with the given `Config`, for example to
+    /// override the host or tune the underlying HTTP client.
+    pub fn new_with_config<I, K, T>(
+        client_id: I,
+        client_secret: K,
+        token: T,
+        config: crate::utils::Config,
+    ) -> Self
+    where
+        I: ToString,
+        K: ToString,
+        T: ToString,
+    {{
+        let client = config.http_client();
         let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
         match client {{
             Ok(c) => {{
                 let client = reqwest_middleware::ClientBuilder::new(c)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware::default())
+                    // Record OpenTelemetry metrics when the `otel` feature is enabled.
+                    .with(crate::utils::MetricsMiddleware::default())
+                    // Parse the Link and rate limit headers of every response once.
+                    .with(crate::utils::PaginationMetadataMiddleware)
+                    // Wait while the rate limit reported by earlier responses is exhausted.
+                    .with(crate::utils::RateLimitStatusMiddleware::default())
                     // Retry failed requests.
                     .with(
                         reqwest_conditional_middleware::ConditionalMiddleware::new(
@@ -1716,7 +2858,7 @@ impl Client {{
                     .build();
 
                 Client {{
-                    host: DEFAULT_HOST.to_string(),
+                    host: config.host.unwrap_or_else(|| DEFAULT_HOST.to_string()),
                     client_id: client_id.to_string(),
                     client_secret: client_secret.to_string(),
                     token: token.to_string(),
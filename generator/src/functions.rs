@@ -831,7 +831,7 @@ fn get_fn_inner(
                 }}
 
                 if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?startng_after={{}}", url, page), {}).await?;
+                    resp = self.client.{}(&format!("{{}}?starting_after={{}}", url, page), {}).await?;
                 }} else {{
                     resp = self.client.{}(&format!("{{}}&starting_after={{}}", url, page), {}).await?;
                 }}
@@ -2171,20 +2171,22 @@ fn render_param(
         enums.push(e.to_string());
     }
 
+    enums.retain(|e| !struct_name(e).is_empty());
+
     if !description.is_empty() {
         a("/**");
         a(&format!("* {}", description.replace('\n', "\n*   ")));
         a("*/");
     }
 
-    a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
+    // Serialize and Deserialize are implemented below so values added by the
+    // provider after this client was generated end up in `UnknownValue`, a name
+    // no documented value maps to.
+    a("#[derive(PartialEq, Debug, Clone, JsonSchema)]");
+    a("#[non_exhaustive]");
 
     a(&format!("pub enum {} {{", sn));
     for e in &enums {
-        if struct_name(e).is_empty() {
-            // TODO: do something for empty(?)
-            continue;
-        }
         a(&format!(r#"#[serde(rename = "{}")]"#, e));
         a(&format!("{},", struct_name(e)));
     }
@@ -2194,20 +2196,17 @@ fn render_param(
     }
 
     // Let's add the wildcard.
-    a("#[serde(other)]");
-    a("FallthroughString");
+    a("/// A value that was not known when this client was generated.");
+    a("#[serde(skip)]");
+    a("UnknownValue(String),");
 
     a("}");
     a("");
 
     a(&format!("impl std::fmt::Display for {} {{", sn));
     a(r#"fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {"#);
-    a(r#"match &*self {"#);
+    a(r#"match self {"#);
     for e in &enums {
-        if struct_name(e).is_empty() {
-            // TODO: do something for empty(?)
-            continue;
-        }
         a(&format!(r#"{}::{} => "{}","#, sn, struct_name(e), e));
     }
     if !required && default.is_none() {
@@ -2215,7 +2214,7 @@ fn render_param(
     }
 
     // Let's add the display format for the wildcard.
-    a(&format!(r#"{}::FallthroughString => "*","#, sn));
+    a(&format!(r#"{}::UnknownValue(s) => s.as_str(),"#, sn));
 
     a("}");
     a(".fmt(f)");
@@ -2223,6 +2222,45 @@ fn render_param(
     a("}");
     a("");
 
+    a(&format!("impl std::str::FromStr for {} {{", sn));
+    a("type Err = std::convert::Infallible;");
+    a("");
+    a("fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {");
+    a("Ok(match s {");
+    for e in &enums {
+        a(&format!(r#""{}" => {}::{},"#, e, sn, struct_name(e)));
+    }
+    if !required && default.is_none() {
+        a(&format!(r#""" => {}::Noop,"#, sn));
+    }
+    a(&format!("_ => {}::UnknownValue(s.to_string()),", sn));
+    a("})");
+    a("}");
+    a("}");
+    a("");
+
+    a(&format!("impl Serialize for {} {{", sn));
+    a("fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>");
+    a("where");
+    a("S: serde::Serializer,");
+    a("{");
+    a("serializer.collect_str(self)");
+    a("}");
+    a("}");
+    a("");
+
+    a(&format!("impl<'de> Deserialize<'de> for {} {{", sn));
+    a("fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>");
+    a("where");
+    a("D: serde::Deserializer<'de>,");
+    a("{");
+    a("String::deserialize(deserializer)?");
+    a(".parse()");
+    a(".map_err(serde::de::Error::custom)");
+    a("}");
+    a("}");
+    a("");
+
     // Add a default for the enum if it is not required.
     if !required || default.is_some() {
         a(&format!("impl Default for {} {{", sn));
@@ -2287,6 +2325,7 @@ fn gen(
     a("");
     if proper_name == "GitHub" {
         a("pub mod auth;");
+        a("pub mod graphql;");
         a(r#"#[cfg(feature = "httpcache")]"#);
         a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]"#);
         a("pub mod http_cache;");
@@ -2298,6 +2337,9 @@ fn gen(
     {
         a("pub mod traits;");
     }
+    if proper_name == "GitHub" || proper_name == "Stripe" {
+        a("pub mod webhooks;");
+    }
     a("#[cfg(test)]");
     a("mod tests;");
     // Hopefully there is never a "tag" named after these reserved libs.
@@ -3206,7 +3248,6 @@ fn main() -> Result<()> {
             if proper_name != "GitHub" {
                 uuid_lib = r#"
 bytes = { version = "1", features = ["serde"] }
-async-trait = "^0.1.51"
 uuid = { version = "1.1", features = ["serde", "v4"] }"#
                     .to_string();
             }
@@ -3218,6 +3259,39 @@ yup-oauth2 = "^5""#
                     .to_string();
             }
 
+            if proper_name == "Google Drive" {
+                // Used to verify resumable uploads.
+                yup_oauth2_lib.push_str(
+                    r#"
+md5 = "0.7""#,
+                );
+            }
+
+            let mut extra_lib = "".to_string();
+            let mut reqwest_features = "";
+            if proper_name == "Okta" {
+                extra_lib = r#"
+redis = { version = "0.21", default-features = false, features = ["tokio-comp", "connection-manager"], optional = true }"#
+                    .to_string();
+            } else if proper_name == "SendGrid" {
+                // Attachments are streamed into the request body.
+                reqwest_features = r#", "stream""#;
+                extra_lib = r#"
+base64 = "^0.13""#
+                    .to_string();
+            }
+
+            let mut hmac_lib = "";
+            let mut sha2_lib = "";
+            if proper_name == "GitHub" || proper_name == "Stripe" {
+                // Used to verify webhook signatures.
+                hmac_lib = r#"
+hex = "0.4"
+hmac = "0.12""#;
+                sha2_lib = r#"
+sha2 = "0.10""#;
+            }
+
             let mut toml = root.clone();
             toml.push("Cargo.toml");
             let tomlout = format!(
@@ -3236,22 +3310,27 @@ default = ["rustls-tls", "reqwest-tracing/opentelemetry_0_17"]
 # enable etag-based http_cache functionality
 httpcache = ["dirs"]
 native-tls = ["reqwest/default-tls", "openssl"]
+# record OpenTelemetry metrics for every request
+otel = ["opentelemetry", "reqwest-tracing/opentelemetry_0_17"]
 rustls-tls = ["reqwest/rustls-tls", "ring", "pem"]
 
 [dependencies]
 anyhow = "1"
 async-recursion = "^1.0"
+async-trait = "^0.1.51"
 chrono = {{ version = "0.4", default-features = false, features = ["serde"] }}
 dirs = {{ version = "^3.0.2", optional = true }}
+futures = "0.3"{}
 http = "^0.2.4"
 hyperx = "1"
 jsonwebtoken = "8"
 log = {{ version = "^0.4", features = ["serde"] }}
 mime = "0.3"
 openssl = {{ version = "0.10", default-features = false, optional = true }}
+opentelemetry = {{ version = "0.17", default-features = false, features = ["metrics"], optional = true }}
 pem = {{ version = "1.1.0",  default-features = false, optional = true }}
 percent-encoding = "2.1"
-reqwest = {{ version = "0.11.11", default-features = false, features = ["json", "multipart"] }}
+reqwest = {{ version = "0.11.11", default-features = false, features = ["json", "multipart"{}] }}
 reqwest-conditional-middleware = "0.1.0"
 reqwest-middleware = "0.1.5"
 reqwest-retry = "0.1.4"
@@ -3260,8 +3339,9 @@ ring = {{ version = "0.16", default-features = false, optional = true }}
 schemars = {{ version = "0.8", features = ["bytes", "chrono", "url", "uuid1"] }}
 serde = {{ version = "1", features = ["derive"] }}
 serde_json = "1"
-serde_urlencoded = "^0.7"
-url = {{ version = "2", features = ["serde"] }}{}{}
+serde_urlencoded = "^0.7"{}
+task-local-extensions = "0.1.1"
+url = {{ version = "2", features = ["serde"] }}{}{}{}
 tokio = {{ version = "1.20.1", features = ["full"] }}
 
 [dev-dependencies]
@@ -3273,7 +3353,17 @@ nom_pem = "4"
 all-features = true
 rustdoc-args = ["--cfg", "docsrs"]
 "#,
-                name, description, version, name, output_dir, uuid_lib, yup_oauth2_lib
+                name,
+                description,
+                version,
+                name,
+                output_dir,
+                hmac_lib,
+                reqwest_features,
+                sha2_lib,
+                uuid_lib,
+                yup_oauth2_lib,
+                extra_lib
             );
             save(&toml, tomlout.as_str())?;
 
@@ -633,6 +633,12 @@ pub fn generate_docs_generic_token(
         String::new()
     };
 
+    let server_to_server = if proper_name == "Zoom" {
+        ZOOM_SERVER_TO_SERVER_DOCS
+    } else {
+        ""
+    };
+
     format!(
         r#"{}
 //!
@@ -705,7 +711,7 @@ pub fn generate_docs_generic_token(
 //!     access_token = {}.refresh_access_token().await.unwrap();
 //! }}
 //! ```
-//!"#,
+//!{}"#,
         info,
         name.replace('_', "-").to_lowercase(),
         version,
@@ -724,9 +730,39 @@ pub fn generate_docs_generic_token(
         proper_name.to_lowercase(),
         proper_name.to_lowercase(),
         proper_name.to_lowercase(),
+        server_to_server,
     )
 }
 
+const ZOOM_SERVER_TO_SERVER_DOCS: &str = r#"
+//! ## Server-to-server OAuth
+//!
+//! Server-to-server OAuth apps use the account credentials grant instead. The
+//! client requests an access token before its first request and again whenever
+//! the token expires, so there is no consent or refresh flow to drive.
+//!
+//! ```
+//! use zoom_api::Client;
+//!
+//! async fn do_call() {
+//!     let zoom = Client::new_server_to_server(
+//!         String::from("account-id"),
+//!         String::from("client-id"),
+//!         String::from("client-secret"),
+//!     );
+//!
+//!     // Or read `ZOOM_ACCOUNT_ID`, `ZOOM_CLIENT_ID` and `ZOOM_CLIENT_SECRET`
+//!     // from the environment.
+//!     let zoom = Client::new_server_to_server_from_env();
+//!
+//!     // Fetching a token up front is optional, but lets you check the
+//!     // scopes granted to the app.
+//!     let access_token = zoom.get_server_to_server_access_token().await.unwrap();
+//!     assert!(access_token.has_scope("meeting:read:admin"));
+//! }
+//! ```
+//!"#;
+
 pub fn generate_docs_generic_api_key(
     api: &openapiv3::OpenAPI,
     name: &str,
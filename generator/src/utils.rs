@@ -2,19 +2,673 @@ const TEMPLATE: &str = r#"use std::{fmt, str::FromStr};
 
 use serde::de::{self, Visitor};
 
+/// Return the target of the `rel="next"` member of a `Link` header.
 pub fn next_link(l: &hyperx::header::Link) -> Option<String> {
-    l.values().iter().find_map(|value| {
-        value.rel().and_then(|rels| {
-            if rels
+    link_with_rel(l, &hyperx::header::RelationType::Next)
+}
+
+/// Return the target of the first member of a `Link` header with the given relation,
+/// for example `prev`, `last` or an extension relation.
+pub fn link_with_rel(
+    l: &hyperx::header::Link,
+    rel: &hyperx::header::RelationType,
+) -> Option<String> {
+    links(l)
+        .into_iter()
+        .find_map(|(rels, target)| rels.contains(rel).then_some(target))
+}
+
+/// Return the targets of every member of a `Link` header with any of the
+/// given relations, in header order.
+pub fn links_with_rel(
+    l: &hyperx::header::Link,
+    rels: &[hyperx::header::RelationType],
+) -> Vec<String> {
+    links(l)
+        .into_iter()
+        .filter(|(link_rels, _)| link_rels.iter().any(|rel| rels.contains(rel)))
+        .map(|(_, target)| target)
+        .collect()
+}
+
+/// Return every member of a `Link` header as its relations and target.
+pub fn links(l: &hyperx::header::Link) -> Vec<(Vec<hyperx::header::RelationType>, String)> {
+    l.values()
+        .iter()
+        .map(|value| {
+            (
+                value.rel().map(|rels| rels.to_vec()).unwrap_or_default(),
+                value.link().to_string(),
+            )
+        })
+        .collect()
+}
+
+/// The navigation links of a paginated response.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaginationLinks {
+    pub next: Option<String>,
+    pub prev: Option<String>,
+    pub first: Option<String>,
+    pub last: Option<String>,
+}
+
+impl PaginationLinks {
+    /// Collect the `next`, `prev`, `first` and `last` links in one pass.
+    ///
+    /// If a relation appears more than once, the first target wins.
+    pub fn from_link(l: &hyperx::header::Link) -> Self {
+        use hyperx::header::RelationType;
+
+        let mut links = PaginationLinks::default();
+        for value in l.values() {
+            for rel in value.rel().unwrap_or_default() {
+                let slot = match rel {
+                    RelationType::Next => &mut links.next,
+                    RelationType::Prev | RelationType::Previous => &mut links.prev,
+                    RelationType::First => &mut links.first,
+                    RelationType::Last => &mut links.last,
+                    _ => continue,
+                };
+                if slot.is_none() {
+                    *slot = Some(value.link().to_string());
+                }
+            }
+        }
+        links
+    }
+
+    /// Collect the navigation links from every `Link` header in `headers`.
+    pub fn from_headers(headers: &http::HeaderMap) -> Self {
+        link_from_headers(headers)
+            .map(|l| Self::from_link(&l))
+            .unwrap_or_default()
+    }
+
+    /// The page number of the `last` link, for page-numbered APIs such as GitHub.
+    pub fn last_page(&self) -> Option<u64> {
+        page_number(self.last.as_deref()?)
+    }
+
+    /// The number of pages left to fetch after the current one.
+    pub fn remaining_pages(&self) -> Option<u64> {
+        let last = self.last_page()?;
+        match self.next.as_deref() {
+            Some(next) => Some(last.saturating_sub(page_number(next)?) + 1),
+            None => Some(0),
+        }
+    }
+
+    /// Estimate the total number of items from the `last` link.
+    ///
+    /// This is an upper bound, since the last page may not be full. Returns
+    /// `None` if the estimate does not fit in a `u64`.
+    pub fn estimated_total(&self, per_page: u64) -> Option<u64> {
+        self.last_page()?.checked_mul(per_page)
+    }
+
+    /// Every page URL from `first` to `last`, for page-numbered APIs.
+    ///
+    /// The URLs are built from the `last` link, so its other query parameters
+    /// are kept. Without a `first` link, pages start at 1.
+    pub fn page_urls(&self) -> Option<Vec<reqwest::Url>> {
+        let last = reqwest::Url::parse(self.last.as_deref()?).ok()?;
+        let last_page = PageQuery::from_url(&last).page?;
+        let first_page = self.first.as_deref().and_then(page_number).unwrap_or(1);
+        Some(
+            (first_page..=last_page)
+                .map(|page| {
+                    let mut url = last.clone();
+                    set_query_param(&mut url, "page", &page.to_string());
+                    url
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Read the links from the `Link` headers of a response.
+///
+/// Relative targets are resolved against the URL the response came from.
+pub trait ResponseLinkExt {
+    /// Every link in the response, as its relations and target.
+    fn links(&self) -> Vec<(Vec<hyperx::header::RelationType>, String)>;
+
+    /// The target of the `rel="next"` link, if any.
+    fn next_link(&self) -> Option<String>;
+
+    /// The `next`, `prev`, `first` and `last` links of the response.
+    fn pagination_links(&self) -> PaginationLinks;
+}
+
+impl ResponseLinkExt for reqwest::Response {
+    fn links(&self) -> Vec<(Vec<hyperx::header::RelationType>, String)> {
+        if let Some(metadata) = self.extensions().get::<PaginationMetadata>() {
+            return metadata.links.clone();
+        }
+        link_from_headers(self.headers())
+            .map(|l| links(&l))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(rels, target)| (rels, resolve_link(self.url(), target)))
+            .collect()
+    }
+
+    fn next_link(&self) -> Option<String> {
+        self.pagination_links().next
+    }
+
+    fn pagination_links(&self) -> PaginationLinks {
+        if let Some(metadata) = self.extensions().get::<PaginationMetadata>() {
+            return metadata.pagination.clone();
+        }
+        let links = PaginationLinks::from_headers(self.headers());
+        let resolve = |target: Option<String>| target.map(|t| resolve_link(self.url(), t));
+        PaginationLinks {
+            next: resolve(links.next),
+            prev: resolve(links.prev),
+            first: resolve(links.first),
+            last: resolve(links.last),
+        }
+    }
+}
+
+fn resolve_link(base: &reqwest::Url, target: String) -> String {
+    base.join(&target).map(String::from).unwrap_or(target)
+}
+
+/// The well-known pagination parameters in the query of a page URL.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageQuery {
+    pub page: Option<u64>,
+    pub per_page: Option<u64>,
+    pub cursor: Option<String>,
+    pub after: Option<String>,
+    pub since: Option<String>,
+}
+
+impl PageQuery {
+    /// Read the pagination parameters from the query of `url`.
+    pub fn from_url(url: &reqwest::Url) -> Self {
+        let mut query = PageQuery::default();
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "page" => query.page = value.parse().ok(),
+                "per_page" => query.per_page = value.parse().ok(),
+                "cursor" => query.cursor = Some(value.into_owned()),
+                "after" => query.after = Some(value.into_owned()),
+                "since" => query.since = Some(value.into_owned()),
+                _ => {}
+            }
+        }
+        query
+    }
+}
+
+fn page_number(url: &str) -> Option<u64> {
+    PageQuery::from_url(&reqwest::Url::parse(url).ok()?).page
+}
+
+/// Set the query parameter `key` of `url` to `value`, replacing any values
+/// it already had, for example to raise `per_page` on a next link.
+pub fn set_query_param(url: &mut reqwest::Url, key: &str, value: &str) {
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(k, _)| k != key)
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .append_pair(key, value);
+}
+
+/// The rate limit reported by the headers of a response.
+///
+/// GitHub's `X-RateLimit-*`, Okta's `X-Rate-Limit-*`, the IETF draft
+/// `RateLimit-*` fields and `Retry-After` are all understood.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// The number of requests allowed in the current window.
+    pub limit: Option<u64>,
+    /// The number of requests left in the current window.
+    pub remaining: Option<u64>,
+    /// When the current window resets.
+    pub reset: Option<std::time::SystemTime>,
+    /// How long the server asked us to wait before retrying.
+    pub retry_after: Option<std::time::Duration>,
+}
+
+impl RateLimitStatus {
+    /// Read the rate limit of `response`, from its `PaginationMetadata` if it
+    /// has been parsed already.
+    pub fn from_response(response: &reqwest::Response) -> Self {
+        match response.extensions().get::<PaginationMetadata>() {
+            Some(metadata) => metadata.rate_limit,
+            None => Self::from_headers(response.headers()),
+        }
+    }
+
+    /// Read the rate limit from `headers`.
+    pub fn from_headers(headers: &http::HeaderMap) -> Self {
+        let number = |names: &[&str]| {
+            names
                 .iter()
-                .any(|rel| rel == &hyperx::header::RelationType::Next)
-            {
-                Some(value.link().into())
-            } else {
+                .find_map(|name| headers.get(*name))
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+        let now = std::time::SystemTime::now();
+
+        // The vendor headers report the reset as seconds since the epoch, the
+        // IETF draft as seconds from now.
+        let reset = number(&["x-ratelimit-reset", "x-rate-limit-reset"])
+            .map(|secs| std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs))
+            .or_else(|| {
+                number(&["ratelimit-reset"]).map(|secs| now + std::time::Duration::from_secs(secs))
+            });
+        let retry_after = headers
+            .get(http::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| parse_retry_after(v, now));
+
+        RateLimitStatus {
+            limit: number(&["x-ratelimit-limit", "x-rate-limit-limit", "ratelimit-limit"]),
+            remaining: number(&[
+                "x-ratelimit-remaining",
+                "x-rate-limit-remaining",
+                "ratelimit-remaining",
+            ]),
+            reset,
+            retry_after,
+        }
+    }
+
+    /// How long until requests may be made again, if the response said.
+    ///
+    /// `Retry-After` takes precedence over the reset of the window.
+    pub fn time_until_reset(&self) -> Option<std::time::Duration> {
+        if self.retry_after.is_some() {
+            return self.retry_after;
+        }
+        self.reset.map(|reset| {
+            reset
+                .duration_since(std::time::SystemTime::now())
+                .unwrap_or_default()
+        })
+    }
+
+    /// When requests may be made again, if the response said.
+    ///
+    /// Unlike `time_until_reset` this does not shrink as time passes, so it is
+    /// what to keep when the status is remembered for later requests.
+    pub fn reset_at(&self) -> Option<std::time::SystemTime> {
+        match self.retry_after {
+            Some(retry_after) => Some(std::time::SystemTime::now() + retry_after),
+            None => self.reset,
+        }
+    }
+
+    /// How long to wait before sending another request, if the window is exhausted.
+    pub fn wait_time(&self) -> Option<std::time::Duration> {
+        if self.remaining != Some(0) {
+            return None;
+        }
+        self.time_until_reset().filter(|wait| !wait.is_zero())
+    }
+
+    /// Whether a response with `status` was rejected for exceeding the rate limit.
+    pub fn is_limited(&self, status: http::StatusCode) -> bool {
+        status == http::StatusCode::TOO_MANY_REQUESTS
+            || (status == http::StatusCode::FORBIDDEN
+                && (self.remaining == Some(0) || self.retry_after.is_some()))
+    }
+}
+
+/// Parse a `Retry-After` value, which is either delay-seconds or an HTTP date.
+fn parse_retry_after(value: &str, now: std::time::SystemTime) -> Option<std::time::Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(std::time::Duration::from_secs(secs));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let at = std::time::UNIX_EPOCH + std::time::Duration::from_secs(date.timestamp().max(0) as u64);
+    Some(at.duration_since(now).unwrap_or_default())
+}
+
+/// Returned when a paginated listing links back to a page that was already
+/// fetched, which would otherwise make the walk go on forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaginationCycle {
+    /// The URL that was seen twice.
+    pub url: String,
+}
+
+impl fmt::Display for PaginationCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "pagination cycle: {} was already fetched", self.url)
+    }
+}
+
+impl std::error::Error for PaginationCycle {}
+
+/// Where the `ETag` of each page is kept between walks of a listing, so
+/// `Client::pages_if_changed` can skip the pages that have not changed.
+#[async_trait::async_trait]
+pub trait EtagStore: fmt::Debug + Send + Sync {
+    /// Return the last `ETag` seen for a page URL.
+    async fn get(&self, url: &str) -> Option<String>;
+
+    /// Record the `ETag` returned for a page URL.
+    async fn set(&self, url: &str, etag: String);
+
+    /// Return the `rel="next"` link a page URL had when its `ETag` was recorded,
+    /// so the walk can carry on past the page once it is not modified. Without
+    /// it the walk ends at the first page that is not modified.
+    async fn get_next(&self, _url: &str) -> Option<String> {
+        None
+    }
+
+    /// Record the `rel="next"` link of a page URL, `None` for the last page.
+    async fn set_next(&self, _url: &str, _next: Option<String>) {}
+}
+
+/// An `EtagStore` kept in memory. Clones share state.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryEtagStore {
+    etags: std::sync::Arc<std::sync::Mutex<std::collections::HashMap<String, String>>>,
+    next: std::sync::Arc<std::sync::Mutex<std::collections::HashMap<String, String>>>,
+}
+
+#[async_trait::async_trait]
+impl EtagStore for InMemoryEtagStore {
+    async fn get(&self, url: &str) -> Option<String> {
+        self.etags.lock().unwrap().get(url).cloned()
+    }
+
+    async fn set(&self, url: &str, etag: String) {
+        self.etags.lock().unwrap().insert(url.to_string(), etag);
+    }
+
+    async fn get_next(&self, url: &str) -> Option<String> {
+        self.next.lock().unwrap().get(url).cloned()
+    }
+
+    async fn set_next(&self, url: &str, next: Option<String>) {
+        let mut links = self.next.lock().unwrap();
+        match next {
+            Some(next) => links.insert(url.to_string(), next),
+            None => links.remove(url),
+        };
+    }
+}
+
+/// Returned when a request is answered with an error status.
+///
+/// Downcast the error of a failed request to it to inspect the status, or pass
+/// the error to `is_retryable`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusError {
+    /// The status of the response.
+    pub status: http::StatusCode,
+    /// The rate limit reported by the response.
+    pub rate_limit: RateLimitStatus,
+    /// The body of the response, decoded lossily as UTF-8.
+    pub body: String,
+}
+
+impl StatusError {
+    /// Describe a response with `status`, `rate_limit` and `body`.
+    pub fn new(status: http::StatusCode, rate_limit: RateLimitStatus, body: &[u8]) -> Self {
+        StatusError {
+            status,
+            rate_limit,
+            body: String::from_utf8_lossy(body).into_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.body.is_empty() {
+            write!(f, "code: {}, empty response", self.status)
+        } else {
+            write!(f, "code: {}, error: {:?}", self.status, self.body)
+        }
+    }
+}
+
+impl std::error::Error for StatusError {}
+
+/// Returns true if a failed request or page fetch is worth retrying.
+///
+/// Timeouts, connection failures, rate limits and server errors are
+/// retryable, whether the status came as a `StatusError` or from reqwest. Other statuses, malformed bodies and `PaginationCycle` are not,
+/// since fetching the same page again would fail the same way.
+pub fn is_retryable(err: &anyhow::Error) -> bool {
+    if let Some(e) = err.downcast_ref::<StatusError>() {
+        return e.rate_limit.is_limited(e.status) || e.status.is_server_error();
+    }
+    let err = match err.downcast_ref::<reqwest_middleware::Error>() {
+        Some(reqwest_middleware::Error::Reqwest(e)) => Some(e),
+        Some(reqwest_middleware::Error::Middleware(e)) => return is_retryable(e),
+        None => err.downcast_ref::<reqwest::Error>(),
+    };
+    match err {
+        Some(e) => match e.status() {
+            Some(status) => {
+                status == http::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+            }
+            None => e.is_timeout() || e.is_connect(),
+        },
+        None => false,
+    }
+}
+
+/// How many times `Client::pages` waits out a rate limit on the same page
+/// before giving up.
+pub const MAX_RATE_LIMIT_WAITS: u32 = 3;
+
+/// Sleep until the rate limit described by `status` resets.
+///
+/// Returns straight away if the response did not say when that is.
+pub async fn wait_for(status: &RateLimitStatus) {
+    if let Some(wait) = status.time_until_reset() {
+        log::debug!("rate limited, waiting {:?}", wait);
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// Parse every `Link` header in `headers` into a single `Link`.
+///
+/// The field may be sent more than once, so all of its values are merged in
+/// order. Values that are not UTF-8 are decoded as latin-1, see
+/// `decode_header_value`.
+pub fn link_from_headers(headers: &http::HeaderMap) -> Option<hyperx::header::Link> {
+    let values: Vec<std::borrow::Cow<'_, str>> = headers
+        .get_all(http::header::LINK)
+        .iter()
+        .map(|v| decode_header_value(v.as_bytes()))
+        .collect();
+    link_from_values(values.iter().map(|v| v.as_ref()))
+}
+
+/// Parse a `Link` field value from raw header bytes.
+pub fn link_from_bytes(value: &[u8]) -> Option<hyperx::header::Link> {
+    let value = decode_header_value(value);
+    link_from_values(std::iter::once(value.as_ref()))
+}
+
+/// Decode a header value that is not guaranteed to be UTF-8.
+///
+/// RFC 9110 allows obs-text octets in field values; when the bytes are not
+/// valid UTF-8 they are read as latin-1 rather than rejected.
+pub fn decode_header_value(bytes: &[u8]) -> std::borrow::Cow<'_, str> {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => std::borrow::Cow::Borrowed(s),
+        Err(_) => std::borrow::Cow::Owned(bytes.iter().map(|&b| b as char).collect()),
+    }
+}
+
+/// Parse several `Link` field values into a single `Link`, keeping their order.
+///
+/// Malformed members are skipped, see `parse_link_lossy`.
+pub fn link_from_values<'a, I>(values: I) -> Option<hyperx::header::Link>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let values: Vec<hyperx::header::LinkValue> =
+        values.into_iter().flat_map(parse_link_lossy).collect();
+
+    if values.is_empty() {
+        None
+    } else {
+        Some(hyperx::header::Link::new(values))
+    }
+}
+
+/// Parse a `Link` field value, skipping malformed members rather than failing
+/// the whole value, the way browsers treat the header.
+///
+/// Each skipped member is logged as a warning.
+pub fn parse_link_lossy(value: &str) -> Vec<hyperx::header::LinkValue> {
+    let value = unfold_header_value(value);
+    if let Ok(link) = value.parse::<hyperx::header::Link>() {
+        return link.values().iter().map(split_rel_lists).collect();
+    }
+
+    split_link_members(&value)
+        .into_iter()
+        .filter_map(|member| match member.parse::<hyperx::header::Link>() {
+            Ok(link) => Some(link.values().iter().map(split_rel_lists).collect::<Vec<_>>()),
+            Err(e) => {
+                log::warn!("skipping malformed link `{}`: {}", member, e);
                 None
             }
         })
-    })
+        .flatten()
+        .collect()
+}
+
+/// Split `rel` and `rev` lists on runs of whitespace.
+///
+/// hyperx splits them on single spaces only, so `rel="next\tprev"` or
+/// `rel="next  prev"` would otherwise come back as extension relations that
+/// never match `next`. RFC 8288 separates relation types by 1*( SP / HTAB ).
+fn split_rel_lists(value: &hyperx::header::LinkValue) -> hyperx::header::LinkValue {
+    use hyperx::header::{LinkValue, RelationType};
+
+    fn is_unsplit(rels: Option<&[RelationType]>) -> bool {
+        rels.unwrap_or_default().iter().any(|rel| {
+            matches!(rel, RelationType::ExtRelType(s) if s.is_empty() || s.contains(&[' ', '\t'][..]))
+        })
+    }
+
+    fn split(rels: Option<&[RelationType]>) -> Vec<RelationType> {
+        rels.unwrap_or_default()
+            .iter()
+            .flat_map(|rel| match rel {
+                RelationType::ExtRelType(s) => s
+                    .split(&[' ', '\t'][..])
+                    .filter(|t| !t.is_empty())
+                    .map(|t| t.parse().unwrap_or_else(|_| RelationType::ExtRelType(t.to_string())))
+                    .collect(),
+                rel => vec![rel.clone()],
+            })
+            .collect()
+    }
+
+    if !is_unsplit(value.rel()) && !is_unsplit(value.rev()) {
+        return value.clone();
+    }
+
+    let mut out = LinkValue::new(value.link().to_string());
+    for rel in split(value.rel()) {
+        out = out.push_rel(rel);
+    }
+    for rev in split(value.rev()) {
+        out = out.push_rev(rev);
+    }
+    if let Some(anchor) = value.anchor() {
+        out = out.set_anchor(anchor);
+    }
+    for tag in value.href_lang().unwrap_or_default() {
+        out = out.push_href_lang(tag.clone());
+    }
+    for desc in value.media_desc().unwrap_or_default() {
+        out = out.push_media_desc(desc.clone());
+    }
+    if let Some(title) = value.title() {
+        out = out.set_title(title);
+    }
+    if let Some(title_star) = value.title_star() {
+        out = out.set_title_star(title_star);
+    }
+    if let Some(media_type) = value.media_type() {
+        out = out.set_media_type(media_type.clone());
+    }
+    out
+}
+
+/// Replace each obs-fold (CRLF followed by spaces or tabs) that legacy
+/// proxies may leave in a field value with a single space, as RFC 7230
+/// section 3.2.4 asks recipients to. Any other CR or LF is left alone.
+///
+/// An `http::HeaderValue` can never hold a CR or LF, so this only matters for
+/// values that come in as raw strings, e.g. through `parse_link_lossy`.
+pub(crate) fn unfold_header_value(value: &str) -> std::borrow::Cow<'_, str> {
+    if !value.contains("\r\n") {
+        return std::borrow::Cow::Borrowed(value);
+    }
+
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(i) = rest.find("\r\n") {
+        let after = &rest[i + 2..];
+        let unfolded = after.trim_start_matches(&[' ', '\t'][..]);
+        if unfolded.len() == after.len() {
+            // Not a fold, keep the line break.
+            out.push_str(&rest[..i + 2]);
+        } else {
+            out.push_str(&rest[..i]);
+            out.push(' ');
+        }
+        rest = unfolded;
+    }
+    out.push_str(rest);
+
+    std::borrow::Cow::Owned(out)
+}
+
+/// Split a `Link` field value on the commas between its members, ignoring
+/// commas inside targets and quoted strings.
+fn split_link_members(value: &str) -> Vec<&str> {
+    let mut members = Vec::new();
+    let mut start = 0;
+    let mut in_target = false;
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for (i, c) in value.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match c {
+            '\\' if in_quotes => escaped = true,
+            '"' if !in_target => in_quotes = !in_quotes,
+            '<' if !in_quotes => in_target = true,
+            '>' if !in_quotes => in_target = false,
+            ',' if !in_target && !in_quotes => {
+                members.push(value[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    members.push(value[start..].trim());
+    members.retain(|m| !m.is_empty());
+    members
 }
 
 
@@ -643,6 +1297,270 @@ pub mod deserialize_null_vector {
         Ok(Default::default())
     }
 }
+
+/// Middleware that records the OpenTelemetry HTTP client metrics for every
+/// request when the `otel` feature is enabled. Without it, requests pass
+/// straight through.
+///
+/// Attribute names follow the OpenTelemetry HTTP semantic conventions.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "otel"), derive(Default))]
+pub struct MetricsMiddleware {
+    #[cfg(feature = "otel")]
+    duration: opentelemetry::metrics::ValueRecorder<f64>,
+    #[cfg(feature = "otel")]
+    requests: opentelemetry::metrics::Counter<u64>,
+}
+
+#[cfg(feature = "otel")]
+impl Default for MetricsMiddleware {
+    fn default() -> Self {
+        let meter = opentelemetry::global::meter(env!("CARGO_PKG_NAME"));
+
+        MetricsMiddleware {
+            duration: meter
+                .f64_value_recorder("http.client.duration")
+                .with_description("Measures the duration of outbound HTTP requests.")
+                .with_unit(opentelemetry::metrics::Unit::new("ms"))
+                .init(),
+            requests: meter
+                .u64_counter("http.client.requests")
+                .with_description("Counts outbound HTTP requests.")
+                .init(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for MetricsMiddleware {
+    #[cfg(feature = "otel")]
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        use opentelemetry::KeyValue;
+
+        let mut attributes = vec![
+            KeyValue::new("http.method", req.method().to_string()),
+            KeyValue::new("http.scheme", req.url().scheme().to_string()),
+        ];
+        if let Some(host) = req.url().host_str() {
+            attributes.push(KeyValue::new("net.peer.name", host.to_string()));
+        }
+
+        let start = std::time::Instant::now();
+        let resp = next.run(req, extensions).await;
+        match &resp {
+            Ok(resp) => attributes.push(KeyValue::new(
+                "http.status_code",
+                i64::from(resp.status().as_u16()),
+            )),
+            Err(_) => attributes.push(KeyValue::new("error", true)),
+        }
+
+        self.duration
+            .record(start.elapsed().as_secs_f64() * 1000.0, &attributes);
+        self.requests.add(1, &attributes);
+
+        resp
+    }
+
+    #[cfg(not(feature = "otel"))]
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        next.run(req, extensions).await
+    }
+}
+
+/// Holds back requests while the rate limit reported by earlier responses is
+/// exhausted, until it resets.
+///
+/// Limits are tracked per budget. A response naming its budget in an
+/// `X-RateLimit-Resource` header, as GitHub's `core`, `search` and `graphql`
+/// do, is kept under that name, and later requests to the same route, the
+/// host and first path segment, wait on it. Otherwise there is one budget per
+/// host. Clones of a client share this middleware, so concurrent requests made
+/// with the same client wait for the reset together instead of each tripping
+/// the limit.
+#[derive(Clone, Default)]
+pub struct RateLimitStatusMiddleware {
+    buckets: std::sync::Arc<std::sync::Mutex<RateLimitBuckets>>,
+}
+
+#[derive(Default)]
+struct RateLimitBuckets {
+    /// When each exhausted budget resets.
+    resets: std::collections::HashMap<String, std::time::Instant>,
+    /// The budget each route was last reported to count against.
+    routes: std::collections::HashMap<String, String>,
+}
+
+/// The route of a request, see `RateLimitStatusMiddleware`.
+fn rate_limit_route(url: &reqwest::Url) -> String {
+    let segment = url
+        .path_segments()
+        .and_then(|mut segments| segments.next())
+        .unwrap_or_default();
+    format!("{}/{}", url.origin().ascii_serialization(), segment)
+}
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for RateLimitStatusMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let origin = req.url().origin().ascii_serialization();
+        let route = rate_limit_route(req.url());
+        let (bucket, reset) = {
+            let buckets = self.buckets.lock().unwrap();
+            let bucket = buckets.routes.get(&route).cloned().unwrap_or_else(|| origin.clone());
+            let reset = buckets.resets.get(&bucket).copied();
+            (bucket, reset)
+        };
+        if let Some(reset) = reset {
+            log::debug!("rate limit for {} exhausted, waiting until {:?}", bucket, reset);
+            tokio::time::sleep_until(tokio::time::Instant::from_std(reset)).await;
+        }
+
+        let resp = next.run(req, extensions).await;
+        if let Ok(resp) = &resp {
+            let status = RateLimitStatus::from_headers(resp.headers());
+            if status.remaining.is_some() {
+                let bucket = match resp
+                    .headers()
+                    .get("x-ratelimit-resource")
+                    .and_then(|resource| resource.to_str().ok())
+                {
+                    Some(resource) => format!("{} {}", origin, resource),
+                    None => origin,
+                };
+                let mut buckets = self.buckets.lock().unwrap();
+                buckets.routes.insert(route, bucket.clone());
+                // Keep when the window resets, so waiting for it later does
+                // not start the delay over.
+                match status.wait_time() {
+                    Some(wait) => {
+                        buckets.resets.insert(bucket, std::time::Instant::now() + wait);
+                    }
+                    None => {
+                        buckets.resets.remove(&bucket);
+                    }
+                }
+            }
+        }
+        resp
+    }
+}
+
+/// The links and rate limit of a response, parsed from its headers by
+/// `PaginationMetadataMiddleware` and kept in the response's extensions.
+///
+/// `ResponseLinkExt` and `RateLimitStatus::from_response` read it from there
+/// rather than parsing the headers again.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PaginationMetadata {
+    /// Every link in the response, as its relations and target.
+    pub links: Vec<(Vec<hyperx::header::RelationType>, String)>,
+    /// The `next`, `prev`, `first` and `last` links of the response.
+    pub pagination: PaginationLinks,
+    /// The rate limit reported by the response.
+    pub rate_limit: RateLimitStatus,
+}
+
+impl PaginationMetadata {
+    /// Parse the `Link` and rate limit headers of `response`.
+    pub fn from_response(response: &reqwest::Response) -> Self {
+        PaginationMetadata {
+            links: ResponseLinkExt::links(response),
+            pagination: ResponseLinkExt::pagination_links(response),
+            rate_limit: RateLimitStatus::from_headers(response.headers()),
+        }
+    }
+}
+
+/// Parses the `Link` and rate limit headers of every response once and keeps
+/// them in the response's extensions as a `PaginationMetadata`, which
+/// `response.extensions().get::<PaginationMetadata>()` returns.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PaginationMetadataMiddleware;
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for PaginationMetadataMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let mut resp = next.run(req, extensions).await?;
+        let metadata = PaginationMetadata::from_response(&resp);
+        resp.extensions_mut().insert(metadata);
+        Ok(resp)
+    }
+}
+
+/// Options for the HTTP client underneath an API client.
+///
+/// Pass it to `Client::new_with_config` to point the client at another host,
+/// such as a self-hosted or enterprise instance, or to tune its connections.
+/// Options left as `None` use reqwest's defaults.
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    /// The base URL requests are sent to, instead of the default host.
+    pub host: Option<String>,
+    /// The `User-Agent` header sent with every request.
+    pub user_agent: Option<String>,
+    /// Headers sent with every request.
+    pub default_headers: reqwest::header::HeaderMap,
+    /// The timeout for a whole request, from connecting until the response body is read.
+    pub timeout: Option<std::time::Duration>,
+    /// The timeout for connecting to the host.
+    pub connect_timeout: Option<std::time::Duration>,
+    /// The maximum number of idle connections kept per host.
+    pub pool_max_idle_per_host: Option<usize>,
+    /// How long an idle connection is kept before it is closed.
+    pub pool_idle_timeout: Option<std::time::Duration>,
+    /// A ready-made client to send requests with, used instead of building
+    /// one from the options above. Useful to pick the TLS backend or root
+    /// certificates yourself, or to share a connection pool.
+    pub client: Option<reqwest::Client>,
+}
+
+impl Config {
+    /// Build the `reqwest::Client` described by this config.
+    pub fn http_client(&self) -> reqwest::Result<reqwest::Client> {
+        if let Some(client) = &self.client {
+            return Ok(client.clone());
+        }
+
+        let mut builder = reqwest::Client::builder().default_headers(self.default_headers.clone());
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(pool_idle_timeout);
+        }
+        builder.build()
+    }
+}
 "#;
 
 const GITHUB_TEMPLATE: &str = r#"//const X_GITHUB_REQUEST_ID: &str = "x-github-request-id";
@@ -726,10 +1644,363 @@ impl From<MediaType> for mime::Mime {
 
 "#;
 
+const OKTA_TEMPLATE: &str = r#"use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Okta rate limits by endpoint rather than per URL, so for example
+/// `/api/v1/users` and `/api/v1/users/{id}` share a bucket.
+fn rate_limit_bucket(method: &reqwest::Method, url: &reqwest::Url) -> String {
+    let path = url
+        .path_segments()
+        .map(|segments| segments.take(3).collect::<Vec<_>>().join("/"))
+        .unwrap_or_default();
+    format!("{} /{}", method, path)
+}
+
+/// Where the rate limit of each endpoint bucket is kept.
+///
+/// Clients that share a store share their rate limit budget. Use an
+/// `InMemoryRateLimitStore` to coordinate clients within a process, or a
+/// `RedisRateLimitStore` (behind the `redis` feature) to coordinate processes.
+#[async_trait::async_trait]
+pub trait RateLimitStore: Debug + Send + Sync {
+    /// Return the last rate limit seen for a bucket.
+    async fn get(&self, bucket: &str) -> Option<RateLimitStatus>;
+
+    /// Record the rate limit reported for a bucket.
+    async fn set(&self, bucket: &str, rate_limit: RateLimitStatus);
+
+    /// Take one request from a bucket's budget.
+    ///
+    /// Returns how long to wait if the bucket is exhausted. Otherwise the
+    /// remaining count is decremented so other clients see the request before
+    /// its response arrives. The default implementation is not atomic.
+    async fn acquire(&self, bucket: &str) -> Option<Duration> {
+        let mut rate_limit = self.get(bucket).await?;
+        if let Some(wait) = rate_limit.wait_time() {
+            return Some(wait);
+        }
+        if let Some(remaining) = rate_limit.remaining.as_mut() {
+            *remaining = remaining.saturating_sub(1);
+            self.set(bucket, rate_limit).await;
+        }
+        None
+    }
+}
+
+/// A `RateLimitStore` shared by the clients in a process. Clones share state.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryRateLimitStore {
+    buckets: Arc<Mutex<HashMap<String, RateLimitStatus>>>,
+}
+
+#[async_trait::async_trait]
+impl RateLimitStore for InMemoryRateLimitStore {
+    async fn get(&self, bucket: &str) -> Option<RateLimitStatus> {
+        self.buckets.lock().unwrap().get(bucket).copied()
+    }
+
+    async fn set(&self, bucket: &str, rate_limit: RateLimitStatus) {
+        self.buckets
+            .lock()
+            .unwrap()
+            .insert(bucket.to_string(), rate_limit);
+    }
+
+    async fn acquire(&self, bucket: &str) -> Option<Duration> {
+        let mut buckets = self.buckets.lock().unwrap();
+        let rate_limit = buckets.get_mut(bucket)?;
+        if let Some(wait) = rate_limit.wait_time() {
+            return Some(wait);
+        }
+        if let Some(remaining) = rate_limit.remaining.as_mut() {
+            *remaining = remaining.saturating_sub(1);
+        }
+        None
+    }
+}
+
+/// A `RateLimitStore` kept in Redis, so clients in different processes share a budget.
+///
+/// Each bucket is stored under `{prefix}:{bucket}` and expires when its window resets.
+#[cfg(feature = "redis")]
+#[derive(Clone)]
+pub struct RedisRateLimitStore {
+    conn: redis::aio::ConnectionManager,
+    prefix: String,
+}
+
+#[cfg(feature = "redis")]
+impl RedisRateLimitStore {
+    /// Connect to Redis, storing buckets under keys starting with `prefix`.
+    pub async fn new(client: redis::Client, prefix: &str) -> redis::RedisResult<Self> {
+        Ok(RedisRateLimitStore {
+            conn: redis::aio::ConnectionManager::new(client).await?,
+            prefix: prefix.to_string(),
+        })
+    }
+
+    fn key(&self, bucket: &str) -> String {
+        format!("{}:{}", self.prefix, bucket)
+    }
+}
+
+#[cfg(feature = "redis")]
+impl Debug for RedisRateLimitStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedisRateLimitStore")
+            .field("prefix", &self.prefix)
+            .finish()
+    }
+}
+
+#[cfg(feature = "redis")]
+#[async_trait::async_trait]
+impl RateLimitStore for RedisRateLimitStore {
+    async fn get(&self, bucket: &str) -> Option<RateLimitStatus> {
+        let mut conn = self.conn.clone();
+        let result: redis::RedisResult<(Option<u64>, Option<u64>, Option<u64>)> =
+            redis::cmd("HMGET")
+                .arg(self.key(bucket))
+                .arg(&["limit", "remaining", "reset"])
+                .query_async(&mut conn)
+                .await;
+        match result {
+            Ok((limit, remaining, reset)) => {
+                let rate_limit = RateLimitStatus {
+                    limit,
+                    remaining,
+                    reset: reset.map(|secs| std::time::UNIX_EPOCH + Duration::from_secs(secs)),
+                    retry_after: None,
+                };
+                if rate_limit == RateLimitStatus::default() {
+                    None
+                } else {
+                    Some(rate_limit)
+                }
+            }
+            Err(e) => {
+                // Fall back to the headers of the next response rather than failing the request.
+                log::warn!("reading rate limit for {} from redis failed: {}", bucket, e);
+                None
+            }
+        }
+    }
+
+    async fn set(&self, bucket: &str, rate_limit: RateLimitStatus) {
+        let key = self.key(bucket);
+        let mut pipe = redis::pipe();
+        pipe.atomic().del(&key).ignore();
+        // Keep the reset as seconds since the epoch, which every process agrees on.
+        let reset = rate_limit
+            .reset_at()
+            .and_then(|reset| reset.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|reset| reset.as_secs());
+        for (field, value) in [
+            ("limit", rate_limit.limit),
+            ("remaining", rate_limit.remaining),
+            ("reset", reset),
+        ] {
+            if let Some(value) = value {
+                pipe.hset(&key, field, value).ignore();
+            }
+        }
+        if let Some(reset) = reset {
+            pipe.expire_at(&key, reset as usize + 1).ignore();
+        }
+
+        let mut conn = self.conn.clone();
+        if let Err(e) = pipe.query_async::<_, ()>(&mut conn).await {
+            log::warn!("writing rate limit for {} to redis failed: {}", bucket, e);
+        }
+    }
+
+    async fn acquire(&self, bucket: &str) -> Option<Duration> {
+        // Check and take from the budget in one script, so two processes can't
+        // both take the last request of a window.
+        let mut conn = self.conn.clone();
+        let result: redis::RedisResult<i64> = redis::Script::new(REDIS_ACQUIRE_SCRIPT)
+            .key(self.key(bucket))
+            .invoke_async(&mut conn)
+            .await;
+        match result {
+            Ok(reset) if reset >= 0 => {
+                let reset = std::time::UNIX_EPOCH + Duration::from_secs(reset as u64);
+                reset
+                    .duration_since(std::time::SystemTime::now())
+                    .ok()
+                    .filter(|wait| !wait.is_zero())
+            }
+            Ok(_) => None,
+            Err(e) => {
+                log::warn!("acquiring rate limit for {} from redis failed: {}", bucket, e);
+                None
+            }
+        }
+    }
+}
+
+/// Takes one request from the bucket in `KEYS[1]`. Returns the reset, in
+/// seconds since the epoch, if the bucket is exhausted and -1 otherwise.
+#[cfg(feature = "redis")]
+const REDIS_ACQUIRE_SCRIPT: &str = r"
+local remaining = tonumber(redis.call('HGET', KEYS[1], 'remaining'))
+if remaining == nil then
+    return -1
+end
+if remaining > 0 then
+    redis.call('HINCRBY', KEYS[1], 'remaining', -1)
+    return -1
+end
+local reset = tonumber(redis.call('HGET', KEYS[1], 'reset'))
+if reset == nil then
+    return -1
+end
+return reset
+";
+
+/// Middleware that keeps requests within Okta's rate limits.
+///
+/// It tracks the `X-Rate-Limit-*` headers per endpoint bucket, waits for the
+/// window to reset once a bucket is exhausted, and retries requests that are
+/// rejected with `429 Too Many Requests`. Optionally it also caps the number
+/// of requests in flight to stay under Okta's concurrent request limit.
+/// See [this doc](https://developer.okta.com/docs/reference/rl-best-practices/) for more information.
+///
+/// Clones of the middleware share their rate limit state, so passing clones to
+/// `Client::new_with_rate_limit`, or to `Client::new_with_config_and_rate_limit`
+/// along with a `Config`, makes the clients coordinate their budget.
+#[derive(Clone, Debug)]
+pub struct RateLimitMiddleware {
+    store: Arc<dyn RateLimitStore>,
+    concurrency: Option<Arc<tokio::sync::Semaphore>>,
+    max_retries: u32,
+}
+
+impl Default for RateLimitMiddleware {
+    fn default() -> Self {
+        RateLimitMiddleware::new(3)
+    }
+}
+
+impl RateLimitMiddleware {
+    /// Create a new middleware that retries rate limited requests up to `max_retries` times.
+    pub fn new(max_retries: u32) -> Self {
+        RateLimitMiddleware {
+            store: Arc::new(InMemoryRateLimitStore::default()),
+            concurrency: None,
+            max_retries,
+        }
+    }
+
+    /// Keep the rate limit state in `store`, for example to share it between processes.
+    pub fn with_store<S>(mut self, store: S) -> Self
+    where
+        S: RateLimitStore + 'static,
+    {
+        self.store = Arc::new(store);
+        self
+    }
+
+    /// Limit the number of requests in flight at once.
+    pub fn with_max_concurrent(mut self, max_concurrent: usize) -> Self {
+        self.concurrency = Some(Arc::new(tokio::sync::Semaphore::new(max_concurrent)));
+        self
+    }
+
+    /// Return the last rate limit seen for the bucket of the given request.
+    pub async fn rate_limit(
+        &self,
+        method: &reqwest::Method,
+        url: &reqwest::Url,
+    ) -> Option<RateLimitStatus> {
+        self.store.get(&rate_limit_bucket(method, url)).await
+    }
+}
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for RateLimitMiddleware {
+    async fn handle(
+        &self,
+        mut req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let _permit = match &self.concurrency {
+            Some(semaphore) => Some(
+                semaphore
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .map_err(|e| reqwest_middleware::Error::Middleware(e.into()))?,
+            ),
+            None => None,
+        };
+
+        let bucket = rate_limit_bucket(req.method(), req.url());
+        let mut attempts = 0;
+        loop {
+            while let Some(wait) = self.store.acquire(&bucket).await {
+                log::debug!("rate limit for {} exhausted, waiting {:?}", bucket, wait);
+                tokio::time::sleep(wait).await;
+            }
+
+            let retry = if attempts < self.max_retries {
+                req.try_clone()
+            } else {
+                None
+            };
+
+            let resp = next.clone().run(req, extensions).await?;
+
+            let rate_limit = Some(RateLimitStatus::from_headers(resp.headers()))
+                .filter(|rl| *rl != RateLimitStatus::default());
+            if let Some(rate_limit) = rate_limit {
+                // Store when the window resets rather than how long that was
+                // from now, since the store is read by later requests.
+                let stored = RateLimitStatus {
+                    reset: rate_limit.reset_at(),
+                    retry_after: None,
+                    ..rate_limit
+                };
+                self.store.set(&bucket, stored).await;
+            }
+
+            if resp.status() != http::StatusCode::TOO_MANY_REQUESTS {
+                return Ok(resp);
+            }
+
+            match retry {
+                Some(r) => {
+                    // Wait at least a second, the concurrent request limit is
+                    // reported with the same status but no useful reset time.
+                    let wait = rate_limit
+                        .and_then(|rl| rl.time_until_reset())
+                        .unwrap_or_default()
+                        .max(Duration::from_secs(1));
+                    log::info!("rate limited on {}, retrying in {:?}", bucket, wait);
+                    tokio::time::sleep(wait).await;
+
+                    req = r;
+                    attempts += 1;
+                }
+                None => return Ok(resp),
+            }
+        }
+    }
+}
+"#;
+
 pub fn generate_utils(proper_name: &str) -> String {
     let mut optional = String::new();
     if proper_name == "GitHub" {
         optional = GITHUB_TEMPLATE.to_string();
+    } else if proper_name == "Okta" {
+        optional = OKTA_TEMPLATE.to_string();
     }
 
     format!("{}\n{}", optional, TEMPLATE)
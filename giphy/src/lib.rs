@@ -117,7 +117,16 @@ impl Client {
     where
         T: ToString,
     {
-        let client = reqwest::Client::builder().build();
+        Client::new_with_config(token, crate::utils::Config::default())
+    }
+
+    /// Create a new Client struct with the given `Config`, for example to
+    /// override the host or tune the underlying HTTP client.
+    pub fn new_with_config<T>(token: T, config: crate::utils::Config) -> Self
+    where
+        T: ToString,
+    {
+        let client = config.http_client();
         let retry_policy =
             reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
         match client {
@@ -125,6 +134,12 @@ impl Client {
                 let client = reqwest_middleware::ClientBuilder::new(c)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware::default())
+                    // Record OpenTelemetry metrics when the `otel` feature is enabled.
+                    .with(crate::utils::MetricsMiddleware::default())
+                    // Parse the Link and rate limit headers of every response once.
+                    .with(crate::utils::PaginationMetadataMiddleware)
+                    // Wait while the rate limit reported by earlier responses is exhausted.
+                    .with(crate::utils::RateLimitStatusMiddleware::default())
                     // Retry failed requests.
                     .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
                         reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
@@ -133,7 +148,7 @@ impl Client {
                     .build();
 
                 Client {
-                    host: DEFAULT_HOST.to_string(),
+                    host: config.host.unwrap_or_else(|| DEFAULT_HOST.to_string()),
                     token: token.to_string(),
 
                     client,
@@ -177,7 +192,18 @@ impl Client {
         uri: &str,
         body: Option<reqwest::Body>,
     ) -> Result<reqwest::Response> {
-        let u = if uri.starts_with("https://") {
+        self.request_raw_with_headers(method, uri, body, reqwest::header::HeaderMap::new())
+            .await
+    }
+
+    async fn request_raw_with_headers(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+        headers: reqwest::header::HeaderMap,
+    ) -> Result<reqwest::Response> {
+        let u = if reqwest::Url::parse(uri).is_ok() {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -195,6 +221,7 @@ impl Client {
             reqwest::header::HeaderValue::from_static("application/json"),
         );
 
+        req = req.headers(headers);
         if let Some(auth_str) = auth {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
         }
@@ -216,6 +243,7 @@ impl Client {
         let response = self.request_raw(method, uri, body).await?;
 
         let status = response.status();
+        let rate_limit = crate::utils::RateLimitStatus::from_headers(response.headers());
 
         let response_body = response.bytes().await?;
 
@@ -230,17 +258,9 @@ impl Client {
             };
             parsed_response.map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = crate::utils::StatusError::new(status, rate_limit, &response_body);
 
-            Err(error)
+            Err(error.into())
         }
     }
 
@@ -256,11 +276,8 @@ impl Client {
         let response = self.request_raw(method, uri, body).await?;
 
         let status = response.status();
-        let link = response
-            .headers()
-            .get(http::header::LINK)
-            .and_then(|l| l.to_str().ok())
-            .and_then(|l| l.parse().ok());
+        let rate_limit = crate::utils::RateLimitStatus::from_headers(response.headers());
+        let link = crate::utils::link_from_headers(response.headers());
 
         let response_body = response.bytes().await?;
 
@@ -276,16 +293,8 @@ impl Client {
             };
             parsed_response.map(|out| (link, out)).map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-            Err(error)
+            let error = crate::utils::StatusError::new(status, rate_limit, &response_body);
+            Err(error.into())
         }
     }
 
@@ -295,7 +304,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let u = if uri.starts_with("https://") {
+        let u = if reqwest::Url::parse(uri).is_ok() {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -321,6 +330,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let rate_limit = crate::utils::RateLimitStatus::from_headers(response.headers());
 
         let response_body = response.bytes().await?;
 
@@ -340,17 +350,9 @@ impl Client {
             };
             parsed_response.map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = crate::utils::StatusError::new(status, rate_limit, &response_body);
 
-            Err(error)
+            Err(error.into())
         }
     }
 
@@ -365,7 +367,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let u = if uri.starts_with("https://") {
+        let u = if reqwest::Url::parse(uri).is_ok() {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -389,6 +391,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let rate_limit = crate::utils::RateLimitStatus::from_headers(response.headers());
 
         let response_body = response.bytes().await?;
 
@@ -408,17 +411,9 @@ impl Client {
             };
             parsed_response.map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = crate::utils::StatusError::new(status, rate_limit, &response_body);
 
-            Err(error)
+            Err(error.into())
         }
     }
 
@@ -434,7 +429,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let u = if uri.starts_with("https://") {
+        let u = if reqwest::Url::parse(uri).is_ok() {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -478,6 +473,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let rate_limit = crate::utils::RateLimitStatus::from_headers(response.headers());
 
         let response_body = response.bytes().await?;
 
@@ -492,17 +488,9 @@ impl Client {
             };
             parsed_response.map_err(Error::from)
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            let error = crate::utils::StatusError::new(status, rate_limit, &response_body);
 
-            Err(error)
+            Err(error.into())
         }
     }
 
@@ -544,22 +532,436 @@ impl Client {
         D: serde::de::DeserializeOwned + 'static + Send,
     {
         let mut global_items = Vec::new();
+        // Servers may send relative targets, which resolve against the page they came from.
+        let mut page_url = reqwest::Url::parse(&(self.host.clone() + uri))?;
+        let mut visited = std::collections::HashSet::new();
+        visited.insert(page_url.clone());
         let (new_link, mut items) = self.get_pages(uri).await?;
         let mut link = new_link;
         while !items.is_empty() {
             global_items.append(&mut items);
             // We need to get the next link.
             if let Some(url) = link.as_ref().and_then(crate::utils::next_link) {
-                let url = reqwest::Url::parse(&url)?;
+                let url = page_url.join(&url)?;
+                if !visited.insert(url.clone()) {
+                    return Err(crate::utils::PaginationCycle {
+                        url: url.to_string(),
+                    }
+                    .into());
+                }
                 let (new_link, new_items) = self.get_pages_url(&url).await?;
                 link = new_link;
                 items = new_items;
+                page_url = url;
             }
         }
 
         Ok(global_items)
     }
 
+    /// Stream every page of a listing, following `rel="next"` links.
+    ///
+    /// The stream ends after the first page without a next link. Request errors,
+    /// error statuses and next links that cannot be parsed are yielded as items
+    /// and end the stream.
+    pub fn pages<'a>(
+        &'a self,
+        uri: &str,
+    ) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        self.pages_from(reqwest::Url::parse(&(self.host.clone() + uri)).map_err(Error::from))
+    }
+
+    /// Stream every page of a listing, following links with the relation `rel`
+    /// rather than `next`, for example `prev` to walk history backwards. Registered
+    /// relations have their own variant, such as `RelationType::NextArchive` for
+    /// `next-archive`, and any other relation is `RelationType::ExtRelType`.
+    pub fn pages_by_rel<'a>(
+        &'a self,
+        uri: &str,
+        rel: hyperx::header::RelationType,
+    ) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        self.pages_from_rel(
+            reqwest::Url::parse(&(self.host.clone() + uri)).map_err(Error::from),
+            rel,
+            None,
+            None,
+        )
+    }
+
+    /// Stream the pages after `response`, following its `rel="next"` link.
+    ///
+    /// Use it to carry on from a first page fetched some other way. The stream
+    /// is empty if `response` has no next link.
+    pub fn pages_after<'a>(
+        &'a self,
+        response: &reqwest::Response,
+    ) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        use futures::StreamExt;
+
+        let first = response.url().clone();
+        let next = crate::utils::ResponseLinkExt::next_link(response)
+            .map(|next| reqwest::Url::parse(&next).map_err(Error::from));
+        futures::stream::iter(next).flat_map(move |url| {
+            self.pages_from_rel(
+                url,
+                hyperx::header::RelationType::Next,
+                None,
+                Some(first.clone()),
+            )
+        })
+    }
+
+    /// Stream the pages of a listing that changed since an earlier walk, following
+    /// `rel="next"` links.
+    ///
+    /// The `ETag` and next link of each page are kept in `etags`, and the tag is
+    /// sent back as `If-None-Match` the next time that page is fetched. Pages the
+    /// server answers with `304 Not Modified` are skipped, and the walk carries on
+    /// from the next link stored for them, so only the pages that changed are
+    /// yielded.
+    pub fn pages_if_changed<'a>(
+        &'a self,
+        uri: &str,
+        etags: &'a dyn crate::utils::EtagStore,
+    ) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        self.pages_from_rel(
+            reqwest::Url::parse(&(self.host.clone() + uri)).map_err(Error::from),
+            hyperx::header::RelationType::Next,
+            Some(etags),
+            None,
+        )
+    }
+
+    fn pages_from<'a>(
+        &'a self,
+        url: Result<reqwest::Url>,
+    ) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        self.pages_from_rel(url, hyperx::header::RelationType::Next, None, None)
+    }
+
+    /// Follow `rel` links from `url`. `after` is a page already fetched before
+    /// the walk, so a link back to it is a cycle too.
+    fn pages_from_rel<'a>(
+        &'a self,
+        url: Result<reqwest::Url>,
+        rel: hyperx::header::RelationType,
+        etags: Option<&'a dyn crate::utils::EtagStore>,
+        after: Option<reqwest::Url>,
+    ) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        let visited: std::collections::HashSet<_> = after.into_iter().collect();
+        futures::stream::unfold((Some(url), visited), move |(url, mut visited)| {
+            let rel = rel.clone();
+            async move {
+                let mut url = url?;
+                loop {
+                    let page = match url {
+                        Ok(page) => page,
+                        Err(e) => return Some((Err(e), (None, visited))),
+                    };
+                    if !visited.insert(page.clone()) {
+                        let cycle = crate::utils::PaginationCycle {
+                            url: page.to_string(),
+                        };
+                        return Some((Err(cycle.into()), (None, visited)));
+                    }
+                    let etag = match etags {
+                        Some(etags) => etags.get(page.as_str()).await,
+                        None => None,
+                    };
+                    let mut headers = reqwest::header::HeaderMap::new();
+                    if let Some(etag) =
+                        etag.and_then(|etag| reqwest::header::HeaderValue::from_str(&etag).ok())
+                    {
+                        headers.insert(reqwest::header::IF_NONE_MATCH, etag);
+                    }
+                    let response = match self.get_page_with_headers(&page, headers).await {
+                        Ok(response) => response,
+                        Err(e) => return Some((Err(e), (None, visited))),
+                    };
+                    // The page is the same as on the last walk, so skip it and carry on
+                    // from the next link it had then.
+                    if response.status() == http::StatusCode::NOT_MODIFIED {
+                        let next = match etags {
+                            Some(etags) => etags.get_next(page.as_str()).await,
+                            None => None,
+                        };
+                        match next {
+                            Some(next) => {
+                                url = reqwest::Url::parse(&next).map_err(Error::from);
+                                continue;
+                            }
+                            None => return None,
+                        }
+                    }
+                    // A non-empty Link header none of whose links parse is malformed rather than
+                    // the end of the listing, so the error follows the page.
+                    let links = crate::utils::ResponseLinkExt::links(&response);
+                    let malformed = links.is_empty()
+                        && response
+                            .headers()
+                            .get_all(http::header::LINK)
+                            .iter()
+                            .any(|value| value.as_bytes().iter().any(|b| !b.is_ascii_whitespace()));
+                    let next = if malformed {
+                        Some(Err(anyhow!("malformed Link header from {}", page)))
+                    } else {
+                        links
+                            .into_iter()
+                            .find_map(|(rels, target)| rels.contains(&rel).then_some(target))
+                            .map(|next| reqwest::Url::parse(&next).map_err(Error::from))
+                    };
+                    if let (Some(etags), Some(etag)) =
+                        (etags, response.headers().get(http::header::ETAG))
+                    {
+                        if let Ok(etag) = etag.to_str() {
+                            etags.set(page.as_str(), etag.to_string()).await;
+                            let next_link = next.as_ref().and_then(|next| next.as_ref().ok());
+                            etags
+                                .set_next(page.as_str(), next_link.map(reqwest::Url::to_string))
+                                .await;
+                        }
+                    }
+                    return Some((Ok(response), (next, visited)));
+                }
+            }
+        })
+    }
+
+    /// Fetch a single page, failing on error statuses.
+    async fn get_page(&self, url: &reqwest::Url) -> Result<reqwest::Response> {
+        self.get_page_with_headers(url, reqwest::header::HeaderMap::new())
+            .await
+    }
+
+    /// Fetch a single page with `headers` added to the request, failing on error statuses.
+    async fn get_page_with_headers(
+        &self,
+        url: &reqwest::Url,
+        headers: reqwest::header::HeaderMap,
+    ) -> Result<reqwest::Response> {
+        let mut waits = 0;
+        loop {
+            let response = self
+                .request_raw_with_headers(http::Method::GET, url.as_str(), None, headers.clone())
+                .await?;
+            // Wait out the rate limit rather than ending the walk.
+            let limit = crate::utils::RateLimitStatus::from_response(&response);
+            if waits < crate::utils::MAX_RATE_LIMIT_WAITS
+                && limit.is_limited(response.status())
+                && limit.time_until_reset().is_some()
+            {
+                waits += 1;
+                crate::utils::wait_for(&limit).await;
+                continue;
+            }
+            let status = response.status();
+            if status.is_client_error() || status.is_server_error() {
+                let body = response.bytes().await?;
+                return Err(crate::utils::StatusError::new(status, limit, &body).into());
+            }
+            return Ok(response);
+        }
+    }
+
+    /// Fetch every page of a page-numbered listing concurrently.
+    ///
+    /// The first page is fetched on its own to learn the `last` link, then the
+    /// remaining pages are requested with up to `concurrency` in flight and
+    /// yielded in order. Listings without page numbers fall back to following
+    /// next links one at a time.
+    pub fn pages_parallel<'a>(
+        &'a self,
+        uri: &str,
+        concurrency: usize,
+    ) -> impl futures::Stream<Item = Result<reqwest::Response>> + 'a {
+        use futures::StreamExt;
+
+        self.pages_parallel_indexed(uri, concurrency, false)
+            .map(|page| page.map(|(_, page)| page).map_err(|(_, e)| e))
+    }
+
+    /// Like `pages_parallel`, but yields each page as soon as it arrives rather
+    /// than in order, along with its index in the listing, the first page being 0.
+    ///
+    /// A slow page then does not hold back the pages after it. A failed page is
+    /// yielded as an error along with its index, so it can be fetched again.
+    pub fn pages_parallel_unordered<'a>(
+        &'a self,
+        uri: &str,
+        concurrency: usize,
+    ) -> impl futures::Stream<Item = std::result::Result<(usize, reqwest::Response), (usize, Error)>> + 'a
+    {
+        self.pages_parallel_indexed(uri, concurrency, true)
+    }
+
+    fn pages_parallel_indexed<'a>(
+        &'a self,
+        uri: &str,
+        concurrency: usize,
+        unordered: bool,
+    ) -> impl futures::Stream<Item = std::result::Result<(usize, reqwest::Response), (usize, Error)>> + 'a
+    {
+        use futures::StreamExt;
+
+        let url = reqwest::Url::parse(&(self.host.clone() + uri));
+        futures::stream::once(async move {
+            let first = match url {
+                Ok(url) => self.get_page(&url).await,
+                Err(e) => Err(e.into()),
+            };
+            let first = match first {
+                Ok(first) => first,
+                Err(e) => return futures::stream::once(async { Err((0, e)) }).boxed(),
+            };
+            let links = crate::utils::ResponseLinkExt::pagination_links(&first);
+            let next_page = links
+                .next
+                .as_deref()
+                .and_then(|next| reqwest::Url::parse(next).ok())
+                .and_then(|next| crate::utils::PageQuery::from_url(&next).page);
+            let first_url = first.url().clone();
+            let first = futures::stream::once(async { Ok((0, first)) });
+
+            match (links.page_urls(), next_page) {
+                (Some(urls), Some(next_page)) => {
+                    let rest = urls
+                        .into_iter()
+                        .filter(move |url| {
+                            crate::utils::PageQuery::from_url(url).page >= Some(next_page)
+                        })
+                        .enumerate()
+                        .map(move |(i, url)| async move {
+                            self.get_page(&url)
+                                .await
+                                .map(|page| (i + 1, page))
+                                .map_err(|e| (i + 1, e))
+                        });
+                    let rest = futures::stream::iter(rest);
+                    if unordered {
+                        first
+                            .chain(rest.buffer_unordered(concurrency.max(1)))
+                            .boxed()
+                    } else {
+                        first.chain(rest.buffered(concurrency.max(1))).boxed()
+                    }
+                }
+                _ => match links.next {
+                    Some(next) => first
+                        .chain(
+                            self.pages_from_rel(
+                                reqwest::Url::parse(&next).map_err(Error::from),
+                                hyperx::header::RelationType::Next,
+                                None,
+                                Some(first_url),
+                            )
+                            .enumerate()
+                            .map(|(i, page)| {
+                                page.map(|page| (i + 1, page)).map_err(|e| (i + 1, e))
+                            }),
+                        )
+                        .boxed(),
+                    None => first.boxed(),
+                },
+            }
+        })
+        .flatten()
+    }
+
+    /// Like `pages`, but keeps requesting pages ahead of the consumer.
+    ///
+    /// At most `prefetch` pages are fetched before they are read, which hides the
+    /// latency of each request; with 0 each page is only fetched once it is asked
+    /// for. Pages are still yielded in order.
+    pub fn pages_prefetch(
+        &self,
+        uri: &str,
+        prefetch: usize,
+    ) -> impl futures::Stream<Item = Result<reqwest::Response>> {
+        use futures::StreamExt;
+
+        let client = self.clone();
+        let uri = uri.to_string();
+        // The fetching task is spawned on the first poll, so the stream can be
+        // built outside a runtime.
+        futures::stream::once(async move {
+            // Each fetch takes a permit: `prefetch` to begin with, plus one each
+            // time the consumer asks for a page.
+            let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(prefetch));
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+            let fetch_permits = permits.clone();
+            tokio::spawn(async move {
+                let pages = client.pages(&uri);
+                futures::pin_mut!(pages);
+                loop {
+                    tokio::select! {
+                        _ = tx.closed() => break,
+                        permit = fetch_permits.acquire() => match permit {
+                            Ok(permit) => permit.forget(),
+                            Err(_) => break,
+                        },
+                    }
+                    let page = match pages.next().await {
+                        Some(page) => page,
+                        None => break,
+                    };
+                    if tx.send(page).is_err() {
+                        break;
+                    }
+                }
+            });
+            futures::stream::unfold((rx, permits), |(mut rx, permits)| async move {
+                permits.add_permits(1);
+                rx.recv().await.map(|page| (page, (rx, permits)))
+            })
+        })
+        .flatten()
+    }
+
+    /// Stream the items of every page of a listing whose pages are JSON arrays.
+    pub fn items<'a, D>(&'a self, uri: &str) -> impl futures::Stream<Item = Result<D>> + 'a
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.items_at(uri, "")
+    }
+
+    /// Stream the items of every page of a listing, reading each page's items
+    /// from the array at the JSON pointer `pointer`, for example `/data`.
+    ///
+    /// A page that fails to fetch or does not hold an array there is yielded as
+    /// an error and ends the stream.
+    pub fn items_at<'a, D>(
+        &'a self,
+        uri: &str,
+        pointer: &'a str,
+    ) -> impl futures::Stream<Item = Result<D>> + 'a
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        use futures::{StreamExt, TryStreamExt};
+
+        self.pages(uri)
+            .and_then(move |response| async move {
+                let mut page: serde_json::Value = response.json().await?;
+                let items = page
+                    .pointer_mut(pointer)
+                    .map(serde_json::Value::take)
+                    .ok_or_else(|| anyhow!("`{}` not found in page", pointer))?;
+                Ok::<_, Error>(serde_json::from_value::<Vec<D>>(items)?)
+            })
+            .scan(false, |failed, page| {
+                let page = if *failed {
+                    None
+                } else {
+                    *failed = page.is_err();
+                    Some(page)
+                };
+                futures::future::ready(page)
+            })
+            .map_ok(|items| futures::stream::iter(items.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
     #[allow(dead_code)]
     async fn get_pages<D>(&self, uri: &str) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
     where
@@ -126,24 +126,57 @@ pub struct Images {
 /**
 * Type of the gif. By default, this is almost always gif
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Type {
     #[serde(rename = "gif")]
     Gif,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Type::Gif => "gif",
-            Type::FallthroughString => "*",
+            Type::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "gif" => Type::Gif,
+            _ => Type::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Gif
@@ -0,0 +1,316 @@
+//! For performing queries against the GitHub GraphQL API.
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::Client;
+
+pub struct Graphql {
+    pub client: Client,
+    cost: std::sync::Mutex<QueryCost>,
+}
+
+impl Graphql {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Graphql {
+            client,
+            cost: Default::default(),
+        }
+    }
+
+    /// Returns the rate limit cost of the queries run through this handle so
+    /// far, counting the queries that select the `rateLimit` object.
+    pub fn cost(&self) -> QueryCost {
+        self.cost.lock().unwrap().clone()
+    }
+
+    /// Returns the GraphQL endpoint for the host the client was created with.
+    ///
+    /// GitHub Enterprise Server serves REST from `/api/v3` and GraphQL from
+    /// `/api/graphql`, while github.com serves GraphQL from `/graphql`.
+    pub fn endpoint(&self) -> String {
+        let host = self.client.host.trim_end_matches('/');
+        match host.strip_suffix("/api/v3") {
+            Some(base) => format!("{}/api/graphql", base),
+            None => format!("{}/graphql", host),
+        }
+    }
+
+    /// Execute a query and return the full response, including any errors.
+    ///
+    /// If the query selects `rateLimit`, its cost is added to `cost`.
+    pub async fn execute<V, T>(&self, query: &str, variables: V) -> Result<Response<T>>
+    where
+        V: Serialize,
+        T: DeserializeOwned + 'static + Send,
+    {
+        let body = Request { query, variables };
+        let response: Response<serde_json::Value> = self
+            .client
+            .request_entity(
+                http::Method::POST,
+                &self.endpoint(),
+                Some(reqwest::Body::from(serde_json::to_vec(&body)?)),
+                crate::utils::MediaType::Json,
+                crate::auth::AuthenticationConstraint::Unconstrained,
+            )
+            .await?;
+
+        let rate_limit = response
+            .data
+            .as_ref()
+            .and_then(|data| data.get("rateLimit"))
+            .and_then(|rate_limit| RateLimit::deserialize(rate_limit).ok());
+        if let Some(rate_limit) = rate_limit {
+            let mut cost = self.cost.lock().unwrap();
+            cost.total += rate_limit.cost;
+            cost.last = Some(rate_limit);
+        }
+
+        Ok(Response {
+            data: response.data.map(serde_json::from_value).transpose()?,
+            errors: response.errors,
+        })
+    }
+
+    /// Execute a query and return its data.
+    ///
+    /// If GitHub returns any errors, they are returned as an `Errors` value
+    /// which can be recovered with `anyhow::Error::downcast_ref`.
+    pub async fn query<V, T>(&self, query: &str, variables: V) -> Result<T>
+    where
+        V: Serialize,
+        T: DeserializeOwned + 'static + Send,
+    {
+        self.execute(query, variables).await?.into_result()
+    }
+
+    /// Walk every page of a Relay connection and return all of its nodes.
+    ///
+    /// The query must accept an `$after: String` variable and select
+    /// `nodes` and `pageInfo { hasNextPage endCursor }` on the connection.
+    /// `connection` is a JSON pointer to the connection within `data`,
+    /// for example `/repository/issues`.
+    ///
+    /// If GitHub returns an `endCursor` it has already returned, a `CursorCycle`
+    /// is returned rather than walking the connection forever.
+    pub async fn query_all<N>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+        connection: &str,
+    ) -> Result<Vec<N>>
+    where
+        N: DeserializeOwned,
+    {
+        let mut variables = match variables {
+            serde_json::Value::Object(o) => o,
+            serde_json::Value::Null => Default::default(),
+            _ => return Err(anyhow!("graphql variables must be a JSON object")),
+        };
+
+        let mut nodes = Vec::new();
+        let mut cursors = std::collections::HashSet::new();
+        loop {
+            let mut data: serde_json::Value = self.query(query, &variables).await?;
+            let conn = data
+                .pointer_mut(connection)
+                .map(serde_json::Value::take)
+                .ok_or_else(|| anyhow!("connection `{}` not found in response", connection))?;
+            let mut conn: Connection<N> = serde_json::from_value(conn)?;
+            nodes.append(&mut conn.nodes);
+
+            match conn.page_info.next_cursor() {
+                Some(cursor) => {
+                    if !cursors.insert(cursor.to_string()) {
+                        return Err(CursorCycle {
+                            cursor: cursor.to_string(),
+                        }
+                        .into());
+                    }
+                    variables.insert("after".to_string(), cursor.into());
+                }
+                None => break,
+            }
+        }
+
+        Ok(nodes)
+    }
+}
+
+#[derive(Serialize)]
+struct Request<'a, V> {
+    query: &'a str,
+    variables: V,
+}
+
+/// The body of a GraphQL response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Response<T> {
+    pub data: Option<T>,
+    #[serde(default)]
+    pub errors: Vec<Error>,
+}
+
+impl<T> Response<T> {
+    /// Convert the response into its data, failing if GitHub returned errors.
+    pub fn into_result(self) -> Result<T> {
+        if !self.errors.is_empty() {
+            return Err(Errors(self.errors).into());
+        }
+        self.data
+            .ok_or_else(|| anyhow!("graphql response contained no data"))
+    }
+}
+
+/// A single error returned by the GraphQL API.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Error {
+    pub message: String,
+    /// The error type, for example `NOT_FOUND` or `RATE_LIMITED`.
+    #[serde(default, rename = "type")]
+    pub type_: Option<String>,
+    #[serde(default)]
+    pub path: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub locations: Vec<Location>,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(type_) = &self.type_ {
+            write!(f, "{}: ", type_)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+/// The position in the query an error refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct Location {
+    pub line: u32,
+    pub column: u32,
+}
+
+/// The errors returned alongside a GraphQL response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Errors(pub Vec<Error>);
+
+impl Errors {
+    /// Returns true if any of the errors was caused by the rate limit.
+    pub fn is_rate_limited(&self) -> bool {
+        self.0
+            .iter()
+            .any(|e| e.type_.as_deref() == Some("RATE_LIMITED"))
+    }
+}
+
+impl fmt::Display for Errors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graphql errors: ")?;
+        for (i, e) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", e)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Errors {}
+
+/// Returned when a connection hands back an `endCursor` that was already
+/// followed, which would otherwise make `query_all` go on forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CursorCycle {
+    /// The cursor that was seen twice.
+    pub cursor: String,
+}
+
+impl fmt::Display for CursorCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cursor cycle: {} was already followed", self.cursor)
+    }
+}
+
+impl std::error::Error for CursorCycle {}
+
+/// The rate limit cost of the queries run through a `Graphql` handle.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryCost {
+    /// The sum of the `cost` of every query that selected `rateLimit`.
+    pub total: i64,
+    /// The `rateLimit` object of the latest such query, with the points
+    /// `remaining` and when they reset.
+    pub last: Option<RateLimit>,
+}
+
+/// The `rateLimit` object, which reports the cost of a query.
+///
+/// Select it in a query with
+/// `rateLimit { cost limit nodeCount remaining resetAt used }`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimit {
+    #[serde(default)]
+    pub cost: i64,
+    #[serde(default)]
+    pub limit: i64,
+    #[serde(default)]
+    pub node_count: i64,
+    #[serde(default)]
+    pub remaining: i64,
+    #[serde(default)]
+    pub reset_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    pub used: i64,
+}
+
+/// Query data along with the optional `rateLimit` object selected next to it.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WithRateLimit<T> {
+    #[serde(default, rename = "rateLimit")]
+    pub rate_limit: Option<RateLimit>,
+    #[serde(flatten)]
+    pub data: T,
+}
+
+/// The `pageInfo` object of a Relay connection.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+    #[serde(default)]
+    pub has_next_page: bool,
+    #[serde(default)]
+    pub has_previous_page: bool,
+    #[serde(default)]
+    pub start_cursor: Option<String>,
+    #[serde(default)]
+    pub end_cursor: Option<String>,
+}
+
+impl PageInfo {
+    /// Returns the cursor to pass as `after` to fetch the next page, if any.
+    pub fn next_cursor(&self) -> Option<&str> {
+        if self.has_next_page {
+            self.end_cursor.as_deref()
+        } else {
+            None
+        }
+    }
+}
+
+/// A Relay connection selected with `nodes` and `pageInfo`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Connection<N> {
+    #[serde(default = "Vec::new")]
+    pub nodes: Vec<N>,
+    #[serde(default)]
+    pub page_info: PageInfo,
+    #[serde(default)]
+    pub total_count: Option<i64>,
+}
@@ -1 +1,1205 @@
+const GRAPHQL_ISSUES: &str = r#"{
+  "data": {
+    "rateLimit": {
+      "cost": 1,
+      "limit": 5000,
+      "nodeCount": 100,
+      "remaining": 4999,
+      "resetAt": "2021-08-15T01:52:41Z",
+      "used": 1
+    },
+    "repository": {
+      "issues": {
+        "totalCount": 2,
+        "nodes": [
+          { "number": 1, "title": "First" },
+          { "number": 2, "title": "Second" }
+        ],
+        "pageInfo": {
+          "hasNextPage": true,
+          "hasPreviousPage": false,
+          "startCursor": "Y3Vyc29yOjE=",
+          "endCursor": "Y3Vyc29yOjI="
+        }
+      }
+    }
+  }
+}"#;
 
+const GRAPHQL_ERRORS: &str = r#"{
+  "data": null,
+  "errors": [
+    {
+      "type": "NOT_FOUND",
+      "path": ["repository"],
+      "locations": [{ "line": 2, "column": 3 }],
+      "message": "Could not resolve to a Repository with the name 'octocat/nope'."
+    }
+  ]
+}"#;
+
+#[derive(serde::Deserialize, Debug)]
+struct Issue {
+    number: i64,
+    title: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Repository {
+    issues: crate::graphql::Connection<Issue>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct IssuesQuery {
+    repository: Repository,
+}
+
+#[test]
+fn test_deserialize_graphql_connection() {
+    let deserialized: crate::graphql::Response<crate::graphql::WithRateLimit<IssuesQuery>> =
+        serde_json::from_str(GRAPHQL_ISSUES).unwrap();
+    assert!(deserialized.errors.is_empty());
+
+    let data = deserialized.into_result().unwrap();
+    let rate_limit = data.rate_limit.unwrap();
+    assert_eq!(rate_limit.cost, 1);
+    assert_eq!(rate_limit.remaining, 4999);
+
+    let issues = data.data.repository.issues;
+    assert_eq!(issues.total_count, Some(2));
+    assert_eq!(issues.nodes.len(), 2);
+    assert_eq!(issues.nodes[0].number, 1);
+    assert_eq!(issues.nodes[0].title, "First");
+    assert_eq!(issues.nodes[1].number, 2);
+    assert_eq!(issues.nodes[1].title, "Second");
+    assert!(!issues.page_info.has_previous_page);
+    assert_eq!(
+        issues.page_info.start_cursor.as_deref(),
+        Some("Y3Vyc29yOjE=")
+    );
+    assert_eq!(issues.page_info.next_cursor(), Some("Y3Vyc29yOjI="));
+}
+
+#[test]
+fn test_deserialize_graphql_connection_without_nodes() {
+    let issues: crate::graphql::Connection<Issue> =
+        serde_json::from_str(r#"{ "pageInfo": { "hasNextPage": false } }"#).unwrap();
+
+    assert!(issues.nodes.is_empty());
+    assert_eq!(issues.page_info.next_cursor(), None);
+}
+
+#[test]
+fn test_deserialize_graphql_errors() {
+    let deserialized: crate::graphql::Response<IssuesQuery> =
+        serde_json::from_str(GRAPHQL_ERRORS).unwrap();
+
+    let err = deserialized.into_result().unwrap_err();
+    let errors = err.downcast_ref::<crate::graphql::Errors>().unwrap();
+    assert!(!errors.is_rate_limited());
+    assert_eq!(errors.0[0].type_.as_deref(), Some("NOT_FOUND"));
+    assert_eq!(
+        errors.0[0].locations[0],
+        crate::graphql::Location { line: 2, column: 3 }
+    );
+}
+
+#[test]
+fn test_deserialize_unknown_enum_value() {
+    let known: crate::types::ImportStatus = serde_json::from_str(r#""auth_failed""#).unwrap();
+    assert_eq!(known, crate::types::ImportStatus::AuthFailed);
+
+    // Values added after the client was generated are kept rather than rejected.
+    let unknown: crate::types::ImportStatus = serde_json::from_str(r#""queued""#).unwrap();
+    assert_eq!(
+        unknown,
+        crate::types::ImportStatus::UnknownValue("queued".to_string())
+    );
+    assert_eq!(serde_json::to_string(&unknown).unwrap(), r#""queued""#);
+
+    // The documented "unknown" status keeps its own variant.
+    let unknown: crate::types::ImportStatus = serde_json::from_str(r#""unknown""#).unwrap();
+    assert_eq!(unknown, crate::types::ImportStatus::Unknown);
+    assert_eq!(unknown.to_string(), "unknown");
+}
+
+#[tokio::test]
+async fn test_graphql_query_all_detects_cursor_cycle() {
+    // Every page hands back the same cursor.
+    let base = mock_server(|_| mock_response(200, &[], GRAPHQL_ISSUES)).await;
+    let github = mock_client(&base);
+    let graphql = github.graphql();
+
+    let err = graphql
+        .query_all::<serde_json::Value>(
+            "query($after: String) { ... }",
+            serde_json::Value::Null,
+            "/repository/issues",
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<crate::graphql::CursorCycle>(),
+        Some(&crate::graphql::CursorCycle {
+            cursor: "Y3Vyc29yOjI=".to_string(),
+        })
+    );
+
+    // The cost of both queries is counted.
+    let cost = graphql.cost();
+    assert_eq!(cost.total, 2);
+    assert_eq!(cost.last.unwrap().remaining, 4999);
+}
+
+#[test]
+fn test_new_with_config_enterprise_host() {
+    let config = crate::utils::Config {
+        host: Some("https://github.example.com/api/v3".to_string()),
+        timeout: Some(std::time::Duration::from_secs(30)),
+        ..Default::default()
+    };
+    let github =
+        crate::Client::new_with_config("agent", None::<crate::auth::Credentials>, config).unwrap();
+
+    assert_eq!(
+        github.graphql().endpoint(),
+        "https://github.example.com/api/graphql"
+    );
+}
+
+#[test]
+fn test_link_with_rel() {
+    let link: hyperx::header::Link = r#"<https://api.github.com/repositories/1/issues?page=2>; rel="next", <https://api.github.com/repositories/1/issues?page=5>; rel="last""#
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        crate::utils::next_link(&link).as_deref(),
+        Some("https://api.github.com/repositories/1/issues?page=2")
+    );
+    assert_eq!(
+        crate::utils::link_with_rel(&link, &hyperx::header::RelationType::Last).as_deref(),
+        Some("https://api.github.com/repositories/1/issues?page=5")
+    );
+    assert_eq!(
+        crate::utils::link_with_rel(&link, &hyperx::header::RelationType::Prev),
+        None
+    );
+    assert_eq!(crate::utils::links(&link).len(), 2);
+    assert_eq!(
+        crate::utils::links_with_rel(
+            &link,
+            &[
+                hyperx::header::RelationType::Next,
+                hyperx::header::RelationType::Last
+            ]
+        ),
+        vec![
+            "https://api.github.com/repositories/1/issues?page=2",
+            "https://api.github.com/repositories/1/issues?page=5",
+        ]
+    );
+}
+
+#[test]
+fn test_pagination_links() {
+    let link: hyperx::header::Link = r#"<https://api.github.com/user/repos?page=3>; rel="next", <https://api.github.com/user/repos?page=50>; rel="last", <https://api.github.com/user/repos?page=1>; rel="first", <https://api.github.com/user/repos?page=1>; rel="prev""#
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        crate::utils::PaginationLinks::from_link(&link),
+        crate::utils::PaginationLinks {
+            next: Some("https://api.github.com/user/repos?page=3".to_string()),
+            prev: Some("https://api.github.com/user/repos?page=1".to_string()),
+            first: Some("https://api.github.com/user/repos?page=1".to_string()),
+            last: Some("https://api.github.com/user/repos?page=50".to_string()),
+        }
+    );
+    assert_eq!(
+        crate::utils::PaginationLinks::from_headers(&http::HeaderMap::new()),
+        Default::default()
+    );
+}
+
+#[test]
+fn test_pagination_links_estimates() {
+    let links = crate::utils::PaginationLinks {
+        next: Some("https://api.github.com/user/repos?per_page=30&page=3".to_string()),
+        last: Some("https://api.github.com/user/repos?per_page=30&page=50".to_string()),
+        ..Default::default()
+    };
+
+    assert_eq!(links.last_page(), Some(50));
+    assert_eq!(links.remaining_pages(), Some(48));
+    assert_eq!(links.estimated_total(30), Some(1500));
+    assert_eq!(links.estimated_total(u64::MAX), None);
+    assert_eq!(
+        crate::utils::PaginationLinks::default().remaining_pages(),
+        None
+    );
+}
+
+#[test]
+fn test_pagination_links_page_urls() {
+    let links = crate::utils::PaginationLinks {
+        first: Some("https://api.github.com/user/repos?per_page=30&page=1".to_string()),
+        last: Some("https://api.github.com/user/repos?per_page=30&page=3".to_string()),
+        ..Default::default()
+    };
+
+    let urls: Vec<String> = links
+        .page_urls()
+        .unwrap()
+        .into_iter()
+        .map(String::from)
+        .collect();
+    assert_eq!(
+        urls,
+        vec![
+            "https://api.github.com/user/repos?per_page=30&page=1",
+            "https://api.github.com/user/repos?per_page=30&page=2",
+            "https://api.github.com/user/repos?per_page=30&page=3",
+        ]
+    );
+}
+
+#[test]
+fn test_page_query() {
+    let mut url = reqwest::Url::parse(
+        "https://api.github.com/repositories/1/issues?state=open&per_page=30&page=2",
+    )
+    .unwrap();
+
+    let query = crate::utils::PageQuery::from_url(&url);
+    assert_eq!(query.page, Some(2));
+    assert_eq!(query.per_page, Some(30));
+    assert_eq!(query.cursor, None);
+
+    crate::utils::set_query_param(&mut url, "per_page", "100");
+    assert_eq!(
+        url.as_str(),
+        "https://api.github.com/repositories/1/issues?state=open&page=2&per_page=100"
+    );
+}
+
+#[test]
+fn test_rate_limit_status() {
+    let mut headers = http::HeaderMap::new();
+    headers.insert("x-ratelimit-limit", http::HeaderValue::from_static("5000"));
+    headers.insert("x-ratelimit-remaining", http::HeaderValue::from_static("0"));
+    headers.insert(
+        "x-ratelimit-reset",
+        http::HeaderValue::from_static("1372700873"),
+    );
+
+    let status = crate::utils::RateLimitStatus::from_headers(&headers);
+    assert_eq!(status.limit, Some(5000));
+    assert_eq!(status.remaining, Some(0));
+    assert_eq!(
+        status.reset,
+        Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(1372700873))
+    );
+    // The reset is in the past.
+    assert_eq!(status.time_until_reset(), Some(std::time::Duration::ZERO));
+
+    headers.insert(
+        http::header::RETRY_AFTER,
+        http::HeaderValue::from_static("60"),
+    );
+    let status = crate::utils::RateLimitStatus::from_headers(&headers);
+    assert_eq!(
+        status.time_until_reset(),
+        Some(std::time::Duration::from_secs(60))
+    );
+}
+
+#[test]
+fn test_link_from_headers_merges_values() {
+    let mut headers = http::HeaderMap::new();
+    headers.append(
+        http::header::LINK,
+        http::HeaderValue::from_static(r#"<https://api.github.com/user/repos?page=1>; rel="prev""#),
+    );
+    headers.append(
+        http::header::LINK,
+        http::HeaderValue::from_static(r#"<https://api.github.com/user/repos?page=3>; rel="next""#),
+    );
+
+    let link = crate::utils::link_from_headers(&headers).unwrap();
+    assert_eq!(crate::utils::links(&link).len(), 2);
+    assert_eq!(
+        crate::utils::next_link(&link).as_deref(),
+        Some("https://api.github.com/user/repos?page=3")
+    );
+
+    assert!(crate::utils::link_from_headers(&http::HeaderMap::new()).is_none());
+}
+
+#[test]
+fn test_parse_link_lossy_obs_fold() {
+    let values = crate::utils::parse_link_lossy(
+        "<https://api.github.com/user/repos?page=3>;\r\n rel=\"next\",\r\n <https://api.github.com/user/repos?page=9>; rel=\"last\"",
+    );
+
+    assert_eq!(values.len(), 2);
+    assert_eq!(values[0].link(), "https://api.github.com/user/repos?page=3");
+    assert_eq!(values[1].link(), "https://api.github.com/user/repos?page=9");
+}
+
+#[test]
+fn test_unfold_header_value() {
+    use crate::utils::unfold_header_value;
+
+    assert_eq!(unfold_header_value("a; b"), "a; b");
+    assert_eq!(unfold_header_value("a;\r\n b"), "a; b");
+    assert_eq!(unfold_header_value("a;\r\n\t \tb,\r\n c"), "a; b, c");
+
+    // Line breaks that are not folds are kept as they are.
+    assert_eq!(unfold_header_value("a;\r\nb"), "a;\r\nb");
+    assert_eq!(unfold_header_value("a;\n b"), "a;\n b");
+    assert_eq!(unfold_header_value("a;\r b"), "a;\r b");
+}
+
+#[test]
+fn test_parse_link_lossy_whitespace_rel_list() {
+    use hyperx::header::RelationType;
+
+    let values = crate::utils::parse_link_lossy(
+        "<https://api.github.com/user/repos?page=3>; rel=\"next\tpreconnect\"; title=\"t\", <https://api.github.com/user/repos?page=1>; rel=\"prev  first\"",
+    );
+
+    assert_eq!(values.len(), 2);
+    assert_eq!(
+        values[0].rel(),
+        Some(
+            &[
+                RelationType::Next,
+                RelationType::ExtRelType("preconnect".to_string())
+            ][..]
+        )
+    );
+    assert_eq!(values[0].title(), Some("t"));
+    assert_eq!(
+        values[1].rel(),
+        Some(&[RelationType::Prev, RelationType::First][..])
+    );
+
+    let link = crate::utils::link_from_values(vec![
+        "<https://api.github.com/user/repos?page=3>; rel=\"next\tpreconnect\"",
+    ])
+    .unwrap();
+    assert_eq!(
+        crate::utils::next_link(&link).as_deref(),
+        Some("https://api.github.com/user/repos?page=3")
+    );
+}
+
+#[test]
+fn test_link_from_bytes_latin1() {
+    let link = crate::utils::link_from_bytes(
+        b"<https://api.github.com/user/repos?page=3>; rel=\"next\"; title=\"caf\xe9\"",
+    )
+    .unwrap();
+
+    assert_eq!(
+        crate::utils::next_link(&link).as_deref(),
+        Some("https://api.github.com/user/repos?page=3")
+    );
+    assert_eq!(link.values()[0].title(), Some("caf\u{e9}"));
+}
+
+#[test]
+fn test_link_from_values_keeps_order() {
+    let link = crate::utils::link_from_values(vec![
+        r#"<https://api.github.com/user/repos?page=3>; rel="next""#,
+        "not a link",
+        r#"<https://api.github.com/user/repos?page=9>; rel="last""#,
+    ])
+    .unwrap();
+
+    let links = crate::utils::links(&link);
+    assert_eq!(links.len(), 2);
+    assert_eq!(links[0].1, "https://api.github.com/user/repos?page=3");
+    assert_eq!(links[1].1, "https://api.github.com/user/repos?page=9");
+}
+
+#[test]
+fn test_parse_link_lossy_skips_malformed_members() {
+    let values = crate::utils::parse_link_lossy(
+        r#"<https://api.github.com/user/repos?page=3>; rel="next", garbled; rel=, <https://api.github.com/user/repos?page=9>; rel="last"; title="a, b""#,
+    );
+
+    assert_eq!(values.len(), 2);
+    assert_eq!(values[0].link(), "https://api.github.com/user/repos?page=3");
+    assert_eq!(values[1].link(), "https://api.github.com/user/repos?page=9");
+}
+
+#[test]
+fn test_verify_webhook_signature() {
+    let secret = b"It's a Secret to Everybody";
+    let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+    crate::webhooks::verify(secret, b"Hello, World!", signature).unwrap();
+    assert!(crate::webhooks::verify(secret, b"Hello, World?", signature).is_err());
+    assert!(crate::webhooks::verify(b"wrong secret", b"Hello, World!", signature).is_err());
+    assert!(crate::webhooks::verify(secret, b"Hello, World!", &signature[7..]).is_err());
+}
+
+#[test]
+fn test_is_retryable() {
+    let cycle = anyhow::Error::new(crate::utils::PaginationCycle {
+        url: "https://api.github.com/user/repos?page=2".to_string(),
+    });
+    assert!(!crate::utils::is_retryable(&cycle));
+
+    let parse = anyhow::Error::new(serde_json::from_str::<Vec<u8>>("{").unwrap_err());
+    assert!(!crate::utils::is_retryable(&parse));
+}
+
+/// Serve HTTP on a local port, answering every request with the response
+/// `respond` builds from its target. Returns the base URL of the server.
+async fn mock_server<F>(respond: F) -> String
+where
+    F: Fn(&str) -> String + Send + Sync + 'static,
+{
+    mock_server_with_head(move |head| respond(head.split(' ').nth(1).unwrap_or_default())).await
+}
+
+/// Like `mock_server`, but `respond` is given the whole request line and headers.
+async fn mock_server_with_head<F>(respond: F) -> String
+where
+    F: Fn(&str) -> String + Send + Sync + 'static,
+{
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let respond = std::sync::Arc::new(respond);
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let respond = respond.clone();
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut chunk = [0; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match socket.read(&mut chunk).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(n) => request.extend_from_slice(&chunk[..n]),
+                    }
+                }
+                let head = String::from_utf8_lossy(&request);
+                let _ = socket.write_all(respond(&head).as_bytes()).await;
+            });
+        }
+    });
+    base
+}
+
+/// Build a response for `mock_server` to send.
+fn mock_response(status: u16, headers: &[(&str, &str)], body: &str) -> String {
+    let mut resp = format!(
+        "HTTP/1.1 {} Mock\r\ncontent-length: {}\r\nconnection: close\r\n",
+        status,
+        body.len()
+    );
+    for (name, value) in headers {
+        resp.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    resp.push_str("\r\n");
+    resp.push_str(body);
+    resp
+}
+
+/// A client that sends its requests to `base`.
+fn mock_client(base: &str) -> crate::Client {
+    let config = crate::utils::Config {
+        host: Some(base.to_string()),
+        ..Default::default()
+    };
+    crate::Client::new_with_config("agent", None::<crate::auth::Credentials>, config).unwrap()
+}
+
+#[tokio::test]
+async fn test_rate_limit_status_middleware_waits_per_resource() {
+    let base = mock_server(|target| {
+        let (resource, remaining) = if target.starts_with("/search/") {
+            ("search", "0")
+        } else {
+            ("core", "10")
+        };
+        mock_response(
+            200,
+            &[
+                ("x-ratelimit-resource", resource),
+                ("x-ratelimit-remaining", remaining),
+                ("ratelimit-reset", "1"),
+            ],
+            "[]",
+        )
+    })
+    .await;
+    let github = mock_client(&base);
+    let search = format!("{}/search/code", base);
+    let repos = format!("{}/repos/o/r", base);
+    let short = std::time::Duration::from_millis(500);
+
+    let start = std::time::Instant::now();
+    github
+        .request_raw_with_headers(http::Method::GET, &search, None, http::HeaderMap::new())
+        .await
+        .unwrap();
+
+    // The exhausted search budget does not hold back other resources.
+    github
+        .request_raw_with_headers(http::Method::GET, &repos, None, http::HeaderMap::new())
+        .await
+        .unwrap();
+    assert!(start.elapsed() < short);
+
+    // Other search endpoints share the search budget and wait until its window resets.
+    github
+        .request_raw_with_headers(
+            http::Method::GET,
+            &format!("{}/search/issues", base),
+            None,
+            http::HeaderMap::new(),
+        )
+        .await
+        .unwrap();
+    assert!(start.elapsed() >= std::time::Duration::from_millis(900));
+
+    // The reset is remembered as a point in time, so once it has passed the
+    // next request goes out straight away.
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    let start = std::time::Instant::now();
+    github
+        .request_raw_with_headers(http::Method::GET, &search, None, http::HeaderMap::new())
+        .await
+        .unwrap();
+    assert!(start.elapsed() < short);
+}
+
+#[tokio::test]
+async fn test_pages_yields_unparsable_links() {
+    use futures::StreamExt;
+
+    let base = mock_server(|target| {
+        let link = match target {
+            "/bad-target" => r#"<http://[>; rel="next""#,
+            "/bad-header" => "not a link",
+            _ => " ",
+        };
+        mock_response(200, &[("link", link)], "[]")
+    })
+    .await;
+    let github = mock_client(&base);
+
+    // The page is yielded, then the error instead of quietly ending the listing.
+    for uri in ["/bad-target", "/bad-header"] {
+        let pages: Vec<_> = github.pages(uri).collect().await;
+        assert_eq!(pages.len(), 2, "{}", uri);
+        assert!(pages[0].is_ok());
+        assert!(pages[1].is_err());
+    }
+
+    // An empty Link header is just a last page.
+    let pages: Vec<_> = github.pages("/empty").collect().await;
+    assert_eq!(pages.len(), 1);
+    assert!(pages[0].is_ok());
+}
+
+#[tokio::test]
+async fn test_pages_follows_next_links() {
+    use futures::StreamExt;
+
+    let base = mock_server(|target| {
+        let link = match target {
+            "/items?page=1" => r#"</items?page=2>; rel="next""#,
+            "/items?page=2" => r#"</items?page=3>; rel="next", </items?page=1>; rel="prev""#,
+            _ => r#"</items?page=2>; rel="prev""#,
+        };
+        mock_response(200, &[("link", link)], "[]")
+    })
+    .await;
+    let github = mock_client(&base);
+
+    let pages: Vec<_> = github.pages("/items?page=1").collect().await;
+    let urls: Vec<_> = pages
+        .into_iter()
+        .map(|page| page.unwrap().url().to_string())
+        .collect();
+    assert_eq!(
+        urls,
+        vec![
+            format!("{}/items?page=1", base),
+            format!("{}/items?page=2", base),
+            format!("{}/items?page=3", base),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_pages_detects_cycle() {
+    use futures::StreamExt;
+
+    let base = mock_server(|target| {
+        let link = if target == "/a" {
+            r#"</b>; rel="next""#
+        } else {
+            r#"</a>; rel="next""#
+        };
+        mock_response(200, &[("link", link)], "[1]")
+    })
+    .await;
+    let github = mock_client(&base);
+
+    let pages: Vec<_> = github.pages("/a").collect().await;
+    assert_eq!(pages.len(), 3);
+    assert!(pages[0].is_ok() && pages[1].is_ok());
+    let cycle = pages[2]
+        .as_ref()
+        .unwrap_err()
+        .downcast_ref::<crate::utils::PaginationCycle>()
+        .unwrap();
+    assert_eq!(cycle.url, format!("{}/a", base));
+
+    // The `_all` methods stop on the same cycle.
+    let err = github.unfold::<u64>("/a").await.unwrap_err();
+    assert!(err
+        .downcast_ref::<crate::utils::PaginationCycle>()
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_pages_waits_out_rate_limit() {
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let requests = std::sync::Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let base = mock_server(move |target| {
+        let n = counter.fetch_add(1, Ordering::SeqCst);
+        if target == "/limited" || n == 0 {
+            mock_response(403, &[("retry-after", "0")], "{}")
+        } else {
+            mock_response(200, &[], "[]")
+        }
+    })
+    .await;
+    let github = mock_client(&base);
+
+    // The first response is rate limited, the page is fetched again after the wait.
+    let pages: Vec<_> = github.pages("/items").collect().await;
+    assert_eq!(pages.len(), 1);
+    assert!(pages[0].is_ok());
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    // A page that stays rate limited is given up on after MAX_RATE_LIMIT_WAITS.
+    requests.store(0, Ordering::SeqCst);
+    let pages: Vec<_> = github.pages("/limited").collect().await;
+    assert_eq!(pages.len(), 1);
+    assert!(pages[0].is_err());
+    assert_eq!(
+        requests.load(Ordering::SeqCst),
+        crate::utils::MAX_RATE_LIMIT_WAITS as usize + 1
+    );
+}
+
+#[tokio::test]
+async fn test_pages_by_rel_follows_other_relations() {
+    use futures::StreamExt;
+    use hyperx::header::RelationType;
+
+    let base = mock_server(|target| {
+        let link = match target {
+            "/p3" => r#"</p2>; rel="prev", </p4>; rel="next""#,
+            "/p2" => r#"</p1>; rel="prev", </p3>; rel="next""#,
+            "/archive" => r#"</archive/2>; rel="next-archive", </p1>; rel="next""#,
+            "/archive/2" => r#"</archive/3>; rel="https://example.com/rel/older""#,
+            _ => r#"</p2>; rel="next""#,
+        };
+        mock_response(200, &[("link", link)], "[]")
+    })
+    .await;
+    let github = mock_client(&base);
+    let paths = |pages: Vec<anyhow::Result<reqwest::Response>>| {
+        pages
+            .into_iter()
+            .map(|page| page.unwrap().url().path().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    let pages = github
+        .pages_by_rel("/p3", RelationType::Prev)
+        .collect()
+        .await;
+    assert_eq!(paths(pages), vec!["/p3", "/p2", "/p1"]);
+
+    let pages = github
+        .pages_by_rel("/archive", RelationType::NextArchive)
+        .collect()
+        .await;
+    assert_eq!(paths(pages), vec!["/archive", "/archive/2"]);
+
+    let older = RelationType::ExtRelType("https://example.com/rel/older".to_string());
+    let pages = github.pages_by_rel("/archive/2", older).collect().await;
+    assert_eq!(paths(pages), vec!["/archive/2", "/archive/3"]);
+}
+
+#[tokio::test]
+async fn test_items_across_pages() {
+    use futures::StreamExt;
+
+    let base = mock_server(|target| match target {
+        "/numbers" => mock_response(200, &[("link", r#"</numbers/2>; rel="next""#)], "[1, 2]"),
+        "/numbers/2" => mock_response(200, &[], "[3]"),
+        "/wrapped" => mock_response(
+            200,
+            &[("link", r#"</wrapped/2>; rel="next""#)],
+            r#"{"data": [1, 2]}"#,
+        ),
+        "/wrapped/2" => mock_response(200, &[], r#"{"data": [3]}"#),
+        _ => mock_response(200, &[], r#"{"data": 1}"#),
+    })
+    .await;
+    let github = mock_client(&base);
+
+    let items: Vec<u64> = github
+        .items::<u64>("/numbers")
+        .map(|item| item.unwrap())
+        .collect()
+        .await;
+    assert_eq!(items, vec![1, 2, 3]);
+
+    let items: Vec<u64> = github
+        .items_at::<u64>("/wrapped", "/data")
+        .map(|item| item.unwrap())
+        .collect()
+        .await;
+    assert_eq!(items, vec![1, 2, 3]);
+
+    // A page without the pointer, or where it is not an array, fails.
+    let missing: Vec<anyhow::Result<u64>> = github.items_at("/wrapped", "/items").collect().await;
+    assert_eq!(missing.len(), 1);
+    assert!(missing[0]
+        .as_ref()
+        .unwrap_err()
+        .to_string()
+        .contains("/items"));
+
+    let scalar: Vec<anyhow::Result<u64>> = github.items_at("/scalar", "/data").collect().await;
+    assert_eq!(scalar.len(), 1);
+    assert!(scalar[0].is_err());
+}
+
+#[tokio::test]
+async fn test_pages_prefetch() {
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let requests = std::sync::Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let base = mock_server(move |target| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        let page: u64 = target.trim_start_matches("/p").parse().unwrap();
+        if page < 4 {
+            let link = format!(r#"</p{}>; rel="next""#, page + 1);
+            mock_response(200, &[("link", &link)], "[]")
+        } else {
+            mock_response(404, &[], "{}")
+        }
+    })
+    .await;
+    let github = mock_client(&base);
+
+    let pages = github.pages_prefetch("/p1", 2);
+    futures::pin_mut!(pages);
+    let first = pages.next().await.unwrap().unwrap();
+    assert_eq!(first.url().path(), "/p1");
+
+    // Later pages are requested before they are read, but no more than `prefetch`.
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+    // They still come out in order, ending with the failed page.
+    let rest: Vec<_> = pages.collect().await;
+    assert_eq!(rest.len(), 3);
+    assert_eq!(rest[0].as_ref().unwrap().url().path(), "/p2");
+    assert_eq!(rest[1].as_ref().unwrap().url().path(), "/p3");
+    assert!(rest[2].is_err());
+
+    // With no prefetch each page is only requested once it is asked for.
+    requests.store(0, Ordering::SeqCst);
+    let pages = github.pages_prefetch("/p1", 0);
+    futures::pin_mut!(pages);
+    pages.next().await.unwrap().unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_pages_prefetch_outside_runtime() {
+    use futures::StreamExt;
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let base = runtime.block_on(mock_server(|_| mock_response(200, &[], "[]")));
+    let github = mock_client(&base);
+
+    // Nothing is spawned until the stream is polled.
+    let pages = github.pages_prefetch("/p1", 2);
+    let pages: Vec<_> = runtime.block_on(pages.collect());
+    assert_eq!(pages.len(), 1);
+    assert!(pages[0].is_ok());
+}
+
+#[tokio::test]
+async fn test_pagination_cycle_on_self_link() {
+    use futures::StreamExt;
+
+    let base = mock_server(|_| {
+        // Relative and absolute spellings of the same page are one URL.
+        mock_response(200, &[("link", r#"<../repo/items>; rel="next""#)], "[1]")
+    })
+    .await;
+    let github = mock_client(&base);
+
+    let pages: Vec<_> = github.pages("/repo/items").collect().await;
+    assert_eq!(pages.len(), 2);
+    assert!(pages[0].is_ok());
+    let err = pages[1].as_ref().unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<crate::utils::PaginationCycle>(),
+        Some(&crate::utils::PaginationCycle {
+            url: format!("{}/repo/items", base),
+        })
+    );
+
+    let err = github.unfold::<u64>("/repo/items").await.unwrap_err();
+    assert!(err
+        .downcast_ref::<crate::utils::PaginationCycle>()
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_pages_parallel() {
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let requests = std::sync::Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let base = mock_server(move |target| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        let link = match target {
+            "/numbered" => r#"</numbered?page=2>; rel="next", </numbered?page=4>; rel="last""#,
+            "/numbered?page=4" => r#"</numbered?page=3>; rel="prev""#,
+            t if t.starts_with("/numbered?page=") => r#"</numbered?page=4>; rel="last""#,
+            "/cursor" => r#"</cursor?after=a>; rel="next""#,
+            "/looping" => r#"</looping?after=a>; rel="next""#,
+            "/looping?after=a" => r#"</looping>; rel="next""#,
+            "/failing" => r#"</failing?page=2>; rel="next", </failing?page=3>; rel="last""#,
+            "/failing?page=2" => return mock_response(404, &[], "{}"),
+            _ => return mock_response(200, &[], "[]"),
+        };
+        mock_response(200, &[("link", link)], "[]")
+    })
+    .await;
+    let github = mock_client(&base);
+    let query = |page: &reqwest::Response| page.url().query().unwrap_or_default().to_string();
+
+    // The first page is not fetched again, the rest come out in order.
+    let pages: Vec<_> = github.pages_parallel("/numbered", 3).collect().await;
+    let queries: Vec<_> = pages
+        .iter()
+        .map(|page| query(page.as_ref().unwrap()))
+        .collect();
+    assert_eq!(queries, vec!["", "page=2", "page=3", "page=4"]);
+    assert_eq!(requests.swap(0, Ordering::SeqCst), 4);
+
+    // Unordered pages carry their index in the listing.
+    let mut pages: Vec<_> = github
+        .pages_parallel_unordered("/numbered", 3)
+        .map(|page| page.unwrap())
+        .map(|(i, page)| (i, query(&page)))
+        .collect()
+        .await;
+    pages.sort();
+    assert_eq!(
+        pages,
+        vec![
+            (0, "".to_string()),
+            (1, "page=2".to_string()),
+            (2, "page=3".to_string()),
+            (3, "page=4".to_string()),
+        ]
+    );
+    assert_eq!(requests.swap(0, Ordering::SeqCst), 4);
+
+    // Without page numbers the next links are followed one at a time.
+    let pages: Vec<_> = github
+        .pages_parallel_unordered("/cursor", 3)
+        .map(|page| page.unwrap())
+        .map(|(i, page)| (i, query(&page)))
+        .collect()
+        .await;
+    assert_eq!(pages, vec![(0, "".to_string()), (1, "after=a".to_string())]);
+
+    // A failed page is yielded with its index.
+    let mut failed: Vec<_> = github
+        .pages_parallel_unordered("/failing", 3)
+        .filter_map(|page| async move { page.err() })
+        .map(|(i, _)| i)
+        .collect()
+        .await;
+    failed.sort();
+    assert_eq!(failed, vec![1]);
+
+    // A next link back to the first page is a cycle.
+    let pages: Vec<_> = github.pages_parallel("/looping", 3).collect().await;
+    assert_eq!(pages.len(), 3);
+    assert!(pages[2]
+        .as_ref()
+        .unwrap_err()
+        .downcast_ref::<crate::utils::PaginationCycle>()
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_pages_after() {
+    use futures::StreamExt;
+
+    let base = mock_server(|target| match target {
+        "/p1" => mock_response(200, &[("link", r#"</p2>; rel="next""#)], "[]"),
+        "/p2" => mock_response(200, &[("link", r#"</p3>; rel="next""#)], "[]"),
+        _ => mock_response(200, &[], "[]"),
+    })
+    .await;
+    let github = mock_client(&base);
+
+    let first = github
+        .request_raw_with_headers(
+            http::Method::GET,
+            &format!("{}/p1", base),
+            None,
+            http::HeaderMap::new(),
+        )
+        .await
+        .unwrap();
+    let paths: Vec<_> = github
+        .pages_after(&first)
+        .map(|page| page.unwrap().url().path().to_string())
+        .collect()
+        .await;
+    assert_eq!(paths, vec!["/p2", "/p3"]);
+
+    // The last page has nothing after it.
+    let last = github
+        .request_raw_with_headers(
+            http::Method::GET,
+            &format!("{}/p3", base),
+            None,
+            http::HeaderMap::new(),
+        )
+        .await
+        .unwrap();
+    let after: Vec<_> = github.pages_after(&last).collect().await;
+    assert!(after.is_empty());
+}
+
+#[tokio::test]
+async fn test_is_retryable_statuses() {
+    let status_error = |status: u16, rate_limit| {
+        anyhow::Error::new(crate::utils::StatusError::new(
+            http::StatusCode::from_u16(status).unwrap(),
+            rate_limit,
+            b"",
+        ))
+    };
+    let exhausted = crate::utils::RateLimitStatus {
+        remaining: Some(0),
+        ..Default::default()
+    };
+    assert!(crate::utils::is_retryable(&status_error(
+        429,
+        Default::default()
+    )));
+    assert!(crate::utils::is_retryable(&status_error(
+        503,
+        Default::default()
+    )));
+    assert!(crate::utils::is_retryable(&status_error(403, exhausted)));
+    assert!(!crate::utils::is_retryable(&status_error(
+        403,
+        Default::default()
+    )));
+    assert!(!crate::utils::is_retryable(&status_error(
+        404,
+        Default::default()
+    )));
+
+    // The request methods return the status as a StatusError.
+    let base = mock_server(|target| match target {
+        "/missing" => mock_response(404, &[], r#"{"message":"Not Found"}"#),
+        _ => mock_response(
+            403,
+            &[("x-ratelimit-remaining", "0"), ("x-ratelimit-reset", "0")],
+            "",
+        ),
+    })
+    .await;
+    let github = mock_client(&base);
+
+    let err = github.get_pages::<u64>("/missing").await.unwrap_err();
+    let status = err.downcast_ref::<crate::utils::StatusError>().unwrap();
+    assert_eq!(status.status, http::StatusCode::NOT_FOUND);
+    assert_eq!(status.body, r#"{"message":"Not Found"}"#);
+    assert!(!crate::utils::is_retryable(&err));
+
+    let err = github.get_pages::<u64>("/limited").await.unwrap_err();
+    assert!(err.to_string().starts_with("rate limit exceeded"));
+    assert!(crate::utils::is_retryable(&err));
+
+    // The page streams return the same StatusError.
+    use futures::StreamExt;
+    let pages: Vec<_> = github.pages("/missing").collect().await;
+    let err = pages[0].as_ref().unwrap_err();
+    let status = err.downcast_ref::<crate::utils::StatusError>().unwrap();
+    assert_eq!(status.status, http::StatusCode::NOT_FOUND);
+    assert_eq!(status.body, r#"{"message":"Not Found"}"#);
+
+    // Timeouts are retryable.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let err = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_millis(50))
+        .build()
+        .unwrap()
+        .get(url)
+        .send()
+        .await
+        .unwrap_err();
+    assert!(crate::utils::is_retryable(&err.into()));
+}
+
+#[tokio::test]
+async fn test_pages_if_changed() {
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    let requests = std::sync::Arc::new(AtomicUsize::new(0));
+    let changed = std::sync::Arc::new(AtomicBool::new(false));
+    let (counter, page2_changed) = (requests.clone(), changed.clone());
+    let base = mock_server_with_head(move |head| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        let target = head.split(' ').nth(1).unwrap_or_default();
+        let etag = match target {
+            "/items?page=1" => r#""p1""#,
+            _ if page2_changed.load(Ordering::SeqCst) => r#""p2b""#,
+            _ => r#""p2""#,
+        };
+        if head
+            .to_ascii_lowercase()
+            .contains(&format!("if-none-match: {}", etag))
+        {
+            return mock_response(304, &[("etag", etag)], "");
+        }
+        let link = if target == "/items?page=1" {
+            r#"</items?page=2>; rel="next""#
+        } else {
+            ""
+        };
+        mock_response(200, &[("etag", etag), ("link", link)], "[1]")
+    })
+    .await;
+    let github = mock_client(&base);
+    let etags = crate::utils::InMemoryEtagStore::default();
+
+    let pages: Vec<_> = github
+        .pages_if_changed("/items?page=1", &etags)
+        .collect()
+        .await;
+    assert_eq!(pages.len(), 2);
+    assert!(pages.iter().all(|page| page.is_ok()));
+    assert_eq!(
+        crate::utils::EtagStore::get(&etags, &format!("{}/items?page=2", base))
+            .await
+            .as_deref(),
+        Some(r#""p2""#)
+    );
+
+    // Nothing changed, so every page is skipped.
+    requests.store(0, Ordering::SeqCst);
+    let pages: Vec<_> = github
+        .pages_if_changed("/items?page=1", &etags)
+        .collect()
+        .await;
+    assert!(pages.is_empty());
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    // A changed page after an unchanged one is still reached.
+    changed.store(true, Ordering::SeqCst);
+    let pages: Vec<_> = github
+        .pages_if_changed("/items?page=1", &etags)
+        .collect()
+        .await;
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].as_ref().unwrap().url().query(), Some("page=2"));
+
+    // Pages the store has no tag for are fetched in full.
+    let pages: Vec<_> = github
+        .pages_if_changed("/items?page=2", &crate::utils::InMemoryEtagStore::default())
+        .collect()
+        .await;
+    assert_eq!(pages.len(), 1);
+}
+
+#[tokio::test]
+async fn test_pagination_metadata_middleware() {
+    let base = mock_server(|_| {
+        mock_response(
+            200,
+            &[
+                (
+                    "link",
+                    r#"</items?page=2>; rel="next", </items?page=5>; rel="last""#,
+                ),
+                ("x-ratelimit-remaining", "42"),
+            ],
+            "[]",
+        )
+    })
+    .await;
+    let github = mock_client(&base);
+
+    let response = github
+        .request_raw_with_headers(
+            http::Method::GET,
+            &format!("{}/items?page=1", base),
+            None,
+            http::HeaderMap::new(),
+        )
+        .await
+        .unwrap();
+    let metadata = response
+        .extensions()
+        .get::<crate::utils::PaginationMetadata>()
+        .unwrap();
+    assert_eq!(
+        metadata.pagination.next,
+        Some(format!("{}/items?page=2", base))
+    );
+    assert_eq!(
+        metadata.pagination.last,
+        Some(format!("{}/items?page=5", base))
+    );
+    assert_eq!(metadata.links.len(), 2);
+    assert_eq!(metadata.rate_limit.remaining, Some(42));
+    assert_eq!(
+        crate::utils::RateLimitStatus::from_response(&response).remaining,
+        Some(42)
+    );
+    assert_eq!(
+        crate::utils::ResponseLinkExt::next_link(&response),
+        Some(format!("{}/items?page=2", base))
+    );
+}
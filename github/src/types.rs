@@ -845,7 +845,8 @@ pub struct Enterprise {
 /**
 * The level of permission to grant the access token to retrieve Pages statuses, configuration, and builds, as well as create new builds. Can be one of: `read` or `write`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Pages {
     #[serde(rename = "read")]
     Read,
@@ -853,8 +854,9 @@ pub enum Pages {
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Pages {
@@ -863,12 +865,45 @@ impl std::fmt::Display for Pages {
             Pages::Read => "read",
             Pages::Write => "write",
             Pages::Noop => "",
-            Pages::FallthroughString => "*",
+            Pages::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Pages {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "read" => Pages::Read,
+            "write" => Pages::Write,
+            "" => Pages::Noop,
+            _ => Pages::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Pages {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pages {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Pages {
     fn default() -> Pages {
         Pages::Noop
@@ -883,7 +918,8 @@ impl Pages {
 /**
 * The level of permission to grant the access token to manage repository projects, columns, and cards. Can be one of: `read`, `write`, or `admin`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RepositoryProjects {
     #[serde(rename = "admin")]
     Admin,
@@ -893,8 +929,9 @@ pub enum RepositoryProjects {
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RepositoryProjects {
@@ -904,12 +941,46 @@ impl std::fmt::Display for RepositoryProjects {
             RepositoryProjects::Read => "read",
             RepositoryProjects::Write => "write",
             RepositoryProjects::Noop => "",
-            RepositoryProjects::FallthroughString => "*",
+            RepositoryProjects::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RepositoryProjects {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => RepositoryProjects::Admin,
+            "read" => RepositoryProjects::Read,
+            "write" => RepositoryProjects::Write,
+            "" => RepositoryProjects::Noop,
+            _ => RepositoryProjects::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RepositoryProjects {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RepositoryProjects {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RepositoryProjects {
     fn default() -> RepositoryProjects {
         RepositoryProjects::Noop
@@ -924,14 +995,16 @@ impl RepositoryProjects {
 /**
 * The level of permission to grant the access token for viewing an organization's plan. Can be one of: `read`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrganizationPlan {
     #[serde(rename = "read")]
     Read,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrganizationPlan {
@@ -939,12 +1012,44 @@ impl std::fmt::Display for OrganizationPlan {
         match self {
             OrganizationPlan::Read => "read",
             OrganizationPlan::Noop => "",
-            OrganizationPlan::FallthroughString => "*",
+            OrganizationPlan::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrganizationPlan {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "read" => OrganizationPlan::Read,
+            "" => OrganizationPlan::Noop,
+            _ => OrganizationPlan::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrganizationPlan {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrganizationPlan {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrganizationPlan {
     fn default() -> OrganizationPlan {
         OrganizationPlan::Noop
@@ -959,14 +1064,16 @@ impl OrganizationPlan {
 /**
 * The level of permission to grant the access token to update GitHub Actions workflow files. Can be one of: `write`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Workflows {
     #[serde(rename = "write")]
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Workflows {
@@ -974,12 +1081,44 @@ impl std::fmt::Display for Workflows {
         match self {
             Workflows::Write => "write",
             Workflows::Noop => "",
-            Workflows::FallthroughString => "*",
+            Workflows::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Workflows {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "write" => Workflows::Write,
+            "" => Workflows::Noop,
+            _ => Workflows::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Workflows {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Workflows {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Workflows {
     fn default() -> Workflows {
         Workflows::Noop
@@ -1173,7 +1312,8 @@ pub struct AccountAnyOf {
 /**
 * Describe whether all repositories have been selected or there's a selection involved
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RepositorySelection {
     #[serde(rename = "all")]
     All,
@@ -1181,8 +1321,9 @@ pub enum RepositorySelection {
     Selected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RepositorySelection {
@@ -1191,12 +1332,45 @@ impl std::fmt::Display for RepositorySelection {
             RepositorySelection::All => "all",
             RepositorySelection::Selected => "selected",
             RepositorySelection::Noop => "",
-            RepositorySelection::FallthroughString => "*",
+            RepositorySelection::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RepositorySelection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => RepositorySelection::All,
+            "selected" => RepositorySelection::Selected,
+            "" => RepositorySelection::Noop,
+            _ => RepositorySelection::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RepositorySelection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RepositorySelection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RepositorySelection {
     fn default() -> RepositorySelection {
         RepositorySelection::Noop
@@ -2819,7 +2993,8 @@ pub struct CodeOfConduct {
 /**
 * The policy that controls the repositories in the organization that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EnabledRepositories {
     #[serde(rename = "all")]
     All,
@@ -2829,8 +3004,9 @@ pub enum EnabledRepositories {
     Selected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EnabledRepositories {
@@ -2840,12 +3016,46 @@ impl std::fmt::Display for EnabledRepositories {
             EnabledRepositories::None => "none",
             EnabledRepositories::Selected => "selected",
             EnabledRepositories::Noop => "",
-            EnabledRepositories::FallthroughString => "*",
+            EnabledRepositories::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EnabledRepositories {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => EnabledRepositories::All,
+            "none" => EnabledRepositories::None,
+            "selected" => EnabledRepositories::Selected,
+            "" => EnabledRepositories::Noop,
+            _ => EnabledRepositories::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EnabledRepositories {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EnabledRepositories {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EnabledRepositories {
     fn default() -> EnabledRepositories {
         EnabledRepositories::Noop
@@ -2860,7 +3070,8 @@ impl EnabledRepositories {
 /**
 * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AllowedActions {
     #[serde(rename = "all")]
     All,
@@ -2870,8 +3081,9 @@ pub enum AllowedActions {
     Selected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AllowedActions {
@@ -2881,12 +3093,46 @@ impl std::fmt::Display for AllowedActions {
             AllowedActions::LocalOnly => "local_only",
             AllowedActions::Selected => "selected",
             AllowedActions::Noop => "",
-            AllowedActions::FallthroughString => "*",
+            AllowedActions::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AllowedActions {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => AllowedActions::All,
+            "local_only" => AllowedActions::LocalOnly,
+            "selected" => AllowedActions::Selected,
+            "" => AllowedActions::Noop,
+            _ => AllowedActions::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AllowedActions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AllowedActions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AllowedActions {
     fn default() -> AllowedActions {
         AllowedActions::Noop
@@ -3071,7 +3317,8 @@ pub struct RunnerGroupsEnterprise {
 /**
 * The type of label. Read-only labels are applied automatically when the runner is configured.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Type {
     #[serde(rename = "custom")]
     Custom,
@@ -3079,8 +3326,9 @@ pub enum Type {
     ReadOnly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Type {
@@ -3089,12 +3337,45 @@ impl std::fmt::Display for Type {
             Type::Custom => "custom",
             Type::ReadOnly => "read-only",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "custom" => Type::Custom,
+            "read-only" => Type::ReadOnly,
+            "" => Type::Noop,
+            _ => Type::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -3689,14 +3970,16 @@ pub struct Label {
 /**
 * The state of the milestone.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum State {
     #[serde(rename = "closed")]
     Closed,
     #[serde(rename = "open")]
     Open,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for State {
@@ -3704,12 +3987,44 @@ impl std::fmt::Display for State {
         match self {
             State::Closed => "closed",
             State::Open => "open",
-            State::FallthroughString => "*",
+            State::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for State {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "closed" => State::Closed,
+            "open" => State::Open,
+            _ => State::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for State {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for State {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for State {
     fn default() -> State {
         State::Open
@@ -3815,7 +4130,8 @@ pub struct Milestone {
 /**
 * How the author is associated with the repository.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AuthorAssociation {
     #[serde(rename = "COLLABORATOR")]
     Collaborator,
@@ -3835,8 +4151,9 @@ pub enum AuthorAssociation {
     Owner,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AuthorAssociation {
@@ -3851,12 +4168,51 @@ impl std::fmt::Display for AuthorAssociation {
             AuthorAssociation::None => "NONE",
             AuthorAssociation::Owner => "OWNER",
             AuthorAssociation::Noop => "",
-            AuthorAssociation::FallthroughString => "*",
+            AuthorAssociation::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AuthorAssociation {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COLLABORATOR" => AuthorAssociation::Collaborator,
+            "CONTRIBUTOR" => AuthorAssociation::Contributor,
+            "FIRST_TIMER" => AuthorAssociation::FirstTimer,
+            "FIRST_TIME_CONTRIBUTOR" => AuthorAssociation::FirstTimeContributor,
+            "MANNEQUIN" => AuthorAssociation::Mannequin,
+            "MEMBER" => AuthorAssociation::Member,
+            "NONE" => AuthorAssociation::None,
+            "OWNER" => AuthorAssociation::Owner,
+            "" => AuthorAssociation::Noop,
+            _ => AuthorAssociation::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AuthorAssociation {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthorAssociation {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AuthorAssociation {
     fn default() -> AuthorAssociation {
         AuthorAssociation::Noop
@@ -7231,7 +7587,8 @@ pub struct RunnerGroupsOrg {
 /**
 * Visibility of a secret
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Visibility {
     #[serde(rename = "all")]
     All,
@@ -7241,8 +7598,9 @@ pub enum Visibility {
     Selected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -7252,12 +7610,46 @@ impl std::fmt::Display for Visibility {
             Visibility::Private => "private",
             Visibility::Selected => "selected",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Visibility {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => Visibility::All,
+            "private" => Visibility::Private,
+            "selected" => Visibility::Selected,
+            "" => Visibility::Noop,
+            _ => Visibility::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Visibility {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Visibility {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Visibility {
     fn default() -> Visibility {
         Visibility::Noop
@@ -7598,7 +7990,8 @@ pub struct OrgHook {
 /**
 * The type of GitHub user that can comment, open issues, or create pull requests while the interaction limit is in effect. Can be one of: `existing_users`, `contributors_only`, `collaborators_only`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum InteractionGroup {
     #[serde(rename = "collaborators_only")]
     CollaboratorsOnly,
@@ -7608,8 +8001,9 @@ pub enum InteractionGroup {
     ExistingUsers,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for InteractionGroup {
@@ -7619,12 +8013,46 @@ impl std::fmt::Display for InteractionGroup {
             InteractionGroup::ContributorsOnly => "contributors_only",
             InteractionGroup::ExistingUsers => "existing_users",
             InteractionGroup::Noop => "",
-            InteractionGroup::FallthroughString => "*",
+            InteractionGroup::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for InteractionGroup {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "collaborators_only" => InteractionGroup::CollaboratorsOnly,
+            "contributors_only" => InteractionGroup::ContributorsOnly,
+            "existing_users" => InteractionGroup::ExistingUsers,
+            "" => InteractionGroup::Noop,
+            _ => InteractionGroup::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for InteractionGroup {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for InteractionGroup {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for InteractionGroup {
     fn default() -> InteractionGroup {
         InteractionGroup::Noop
@@ -7661,7 +8089,8 @@ pub struct InteractionLimits {
 /**
 * The duration of the interaction restriction. Can be one of: `one_day`, `three_days`, `one_week`, `one_month`, `six_months`. Default: `one_day`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum InteractionExpiry {
     #[serde(rename = "one_day")]
     OneDay,
@@ -7675,8 +8104,9 @@ pub enum InteractionExpiry {
     ThreeDays,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for InteractionExpiry {
@@ -7688,12 +8118,48 @@ impl std::fmt::Display for InteractionExpiry {
             InteractionExpiry::SixMonths => "six_months",
             InteractionExpiry::ThreeDays => "three_days",
             InteractionExpiry::Noop => "",
-            InteractionExpiry::FallthroughString => "*",
+            InteractionExpiry::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for InteractionExpiry {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "one_day" => InteractionExpiry::OneDay,
+            "one_month" => InteractionExpiry::OneMonth,
+            "one_week" => InteractionExpiry::OneWeek,
+            "six_months" => InteractionExpiry::SixMonths,
+            "three_days" => InteractionExpiry::ThreeDays,
+            "" => InteractionExpiry::Noop,
+            _ => InteractionExpiry::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for InteractionExpiry {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for InteractionExpiry {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for InteractionExpiry {
     fn default() -> InteractionExpiry {
         InteractionExpiry::Noop
@@ -7907,7 +8373,8 @@ pub struct Team {
 /**
 * The state of the member in the organization. The `pending` state indicates the user has not yet accepted an invitation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrgMembershipState {
     #[serde(rename = "active")]
     Active,
@@ -7915,8 +8382,9 @@ pub enum OrgMembershipState {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrgMembershipState {
@@ -7925,12 +8393,45 @@ impl std::fmt::Display for OrgMembershipState {
             OrgMembershipState::Active => "active",
             OrgMembershipState::Pending => "pending",
             OrgMembershipState::Noop => "",
-            OrgMembershipState::FallthroughString => "*",
+            OrgMembershipState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrgMembershipState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => OrgMembershipState::Active,
+            "pending" => OrgMembershipState::Pending,
+            "" => OrgMembershipState::Noop,
+            _ => OrgMembershipState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrgMembershipState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrgMembershipState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrgMembershipState {
     fn default() -> OrgMembershipState {
         OrgMembershipState::Noop
@@ -7945,7 +8446,8 @@ impl OrgMembershipState {
 /**
 * The user's membership type in the organization.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Role {
     #[serde(rename = "admin")]
     Admin,
@@ -7955,8 +8457,9 @@ pub enum Role {
     Member,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Role {
@@ -7966,12 +8469,46 @@ impl std::fmt::Display for Role {
             Role::BillingManager => "billing_manager",
             Role::Member => "member",
             Role::Noop => "",
-            Role::FallthroughString => "*",
+            Role::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Role {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => Role::Admin,
+            "billing_manager" => Role::BillingManager,
+            "member" => Role::Member,
+            "" => Role::Noop,
+            _ => Role::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Role {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Role {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Role {
     fn default() -> Role {
         Role::Noop
@@ -8111,7 +8648,8 @@ pub struct Migration {
     pub url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PackageType {
     #[serde(rename = "container")]
     Container,
@@ -8127,8 +8665,9 @@ pub enum PackageType {
     Rubygems,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PackageType {
@@ -8141,12 +8680,49 @@ impl std::fmt::Display for PackageType {
             PackageType::Nuget => "nuget",
             PackageType::Rubygems => "rubygems",
             PackageType::Noop => "",
-            PackageType::FallthroughString => "*",
+            PackageType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PackageType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "container" => PackageType::Container,
+            "docker" => PackageType::Docker,
+            "maven" => PackageType::Maven,
+            "npm" => PackageType::Npm,
+            "nuget" => PackageType::Nuget,
+            "rubygems" => PackageType::Rubygems,
+            "" => PackageType::Noop,
+            _ => PackageType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PackageType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PackageType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PackageType {
     fn default() -> PackageType {
         PackageType::Noop
@@ -8158,7 +8734,8 @@ impl PackageType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PackageVisibility {
     #[serde(rename = "private")]
     Private,
@@ -8166,8 +8743,9 @@ pub enum PackageVisibility {
     Public,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PackageVisibility {
@@ -8176,12 +8754,45 @@ impl std::fmt::Display for PackageVisibility {
             PackageVisibility::Private => "private",
             PackageVisibility::Public => "public",
             PackageVisibility::Noop => "",
-            PackageVisibility::FallthroughString => "*",
+            PackageVisibility::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PackageVisibility {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "private" => PackageVisibility::Private,
+            "public" => PackageVisibility::Public,
+            "" => PackageVisibility::Noop,
+            _ => PackageVisibility::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PackageVisibility {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PackageVisibility {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PackageVisibility {
     fn default() -> PackageVisibility {
         PackageVisibility::Noop
@@ -8357,7 +8968,8 @@ pub struct PackageVersion {
 /**
 * The baseline permission that all organization members have on this project. Only present if owner is an organization.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrganizationPermission {
     #[serde(rename = "admin")]
     Admin,
@@ -8369,8 +8981,9 @@ pub enum OrganizationPermission {
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrganizationPermission {
@@ -8381,12 +8994,47 @@ impl std::fmt::Display for OrganizationPermission {
             OrganizationPermission::Read => "read",
             OrganizationPermission::Write => "write",
             OrganizationPermission::Noop => "",
-            OrganizationPermission::FallthroughString => "*",
+            OrganizationPermission::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrganizationPermission {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => OrganizationPermission::Admin,
+            "none" => OrganizationPermission::None,
+            "read" => OrganizationPermission::Read,
+            "write" => OrganizationPermission::Write,
+            "" => OrganizationPermission::Noop,
+            _ => OrganizationPermission::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrganizationPermission {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrganizationPermission {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrganizationPermission {
     fn default() -> OrganizationPermission {
         OrganizationPermission::Noop
@@ -8541,7 +9189,8 @@ pub struct GroupMapping {
 /**
 * The level of privacy this team should have
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Privacy {
     #[serde(rename = "closed")]
     Closed,
@@ -8549,8 +9198,9 @@ pub enum Privacy {
     Secret,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Privacy {
@@ -8559,12 +9209,45 @@ impl std::fmt::Display for Privacy {
             Privacy::Closed => "closed",
             Privacy::Secret => "secret",
             Privacy::Noop => "",
-            Privacy::FallthroughString => "*",
+            Privacy::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Privacy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "closed" => Privacy::Closed,
+            "secret" => Privacy::Secret,
+            "" => Privacy::Noop,
+            _ => Privacy::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Privacy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Privacy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Privacy {
     fn default() -> Privacy {
         Privacy::Noop
@@ -8870,7 +9553,8 @@ pub struct TeamDiscussionComment {
 /**
 * The reaction to use
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Content {
     #[serde(rename = "+1")]
     PlusOne,
@@ -8890,8 +9574,9 @@ pub enum Content {
     Rocket,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Content {
@@ -8906,12 +9591,51 @@ impl std::fmt::Display for Content {
             Content::Laugh => "laugh",
             Content::Rocket => "rocket",
             Content::Noop => "",
-            Content::FallthroughString => "*",
+            Content::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Content {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "+1" => Content::PlusOne,
+            "-1" => Content::MinusOne,
+            "confused" => Content::Confused,
+            "eyes" => Content::Eyes,
+            "heart" => Content::Heart,
+            "hooray" => Content::Hooray,
+            "laugh" => Content::Laugh,
+            "rocket" => Content::Rocket,
+            "" => Content::Noop,
+            _ => Content::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Content {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Content {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Content {
     fn default() -> Content {
         Content::Noop
@@ -8956,14 +9680,16 @@ pub struct Reaction {
 /**
 * The role of the user in the team.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TeamMembershipRole {
     #[serde(rename = "maintainer")]
     Maintainer,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TeamMembershipRole {
@@ -8971,12 +9697,44 @@ impl std::fmt::Display for TeamMembershipRole {
         match self {
             TeamMembershipRole::Maintainer => "maintainer",
             TeamMembershipRole::Member => "member",
-            TeamMembershipRole::FallthroughString => "*",
+            TeamMembershipRole::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TeamMembershipRole {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "maintainer" => TeamMembershipRole::Maintainer,
+            "member" => TeamMembershipRole::Member,
+            _ => TeamMembershipRole::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TeamMembershipRole {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TeamMembershipRole {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TeamMembershipRole {
     fn default() -> TeamMembershipRole {
         TeamMembershipRole::Member
@@ -9898,7 +10656,8 @@ pub struct FullRepositoryPermissions {
     pub push: bool,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Status {
     #[serde(rename = "disabled")]
     Disabled,
@@ -9906,8 +10665,9 @@ pub enum Status {
     Enabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Status {
@@ -9916,12 +10676,45 @@ impl std::fmt::Display for Status {
             Status::Disabled => "disabled",
             Status::Enabled => "enabled",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "disabled" => Status::Disabled,
+            "enabled" => Status::Enabled,
+            "" => Status::Noop,
+            _ => Status::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -10567,7 +11360,8 @@ pub struct Artifact {
 /**
 * The phase of the lifecycle that the job is currently in.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum JobStatus {
     #[serde(rename = "completed")]
     Completed,
@@ -10577,8 +11371,9 @@ pub enum JobStatus {
     Queued,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for JobStatus {
@@ -10588,12 +11383,46 @@ impl std::fmt::Display for JobStatus {
             JobStatus::InProgress => "in_progress",
             JobStatus::Queued => "queued",
             JobStatus::Noop => "",
-            JobStatus::FallthroughString => "*",
+            JobStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for JobStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "completed" => JobStatus::Completed,
+            "in_progress" => JobStatus::InProgress,
+            "queued" => JobStatus::Queued,
+            "" => JobStatus::Noop,
+            _ => JobStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for JobStatus {
     fn default() -> JobStatus {
         JobStatus::Noop
@@ -11071,7 +11900,8 @@ pub struct Environments {
 /**
 * Whether deployment to the environment(s) was approved or rejected
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EnvironmentApprovalState {
     #[serde(rename = "approved")]
     Approved,
@@ -11079,8 +11909,9 @@ pub enum EnvironmentApprovalState {
     Rejected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EnvironmentApprovalState {
@@ -11089,14 +11920,47 @@ impl std::fmt::Display for EnvironmentApprovalState {
             EnvironmentApprovalState::Approved => "approved",
             EnvironmentApprovalState::Rejected => "rejected",
             EnvironmentApprovalState::Noop => "",
-            EnvironmentApprovalState::FallthroughString => "*",
+            EnvironmentApprovalState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
-impl Default for EnvironmentApprovalState {
-    fn default() -> EnvironmentApprovalState {
+impl std::str::FromStr for EnvironmentApprovalState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approved" => EnvironmentApprovalState::Approved,
+            "rejected" => EnvironmentApprovalState::Rejected,
+            "" => EnvironmentApprovalState::Noop,
+            _ => EnvironmentApprovalState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EnvironmentApprovalState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EnvironmentApprovalState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl Default for EnvironmentApprovalState {
+    fn default() -> EnvironmentApprovalState {
         EnvironmentApprovalState::Noop
     }
 }
@@ -11139,7 +12003,8 @@ pub struct EnvironmentApproval {
 /**
 * The type of reviewer. Must be one of: `User` or `Team`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeploymentReviewerType {
     #[serde(rename = "Team")]
     Team,
@@ -11147,8 +12012,9 @@ pub enum DeploymentReviewerType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeploymentReviewerType {
@@ -11157,12 +12023,45 @@ impl std::fmt::Display for DeploymentReviewerType {
             DeploymentReviewerType::Team => "Team",
             DeploymentReviewerType::User => "User",
             DeploymentReviewerType::Noop => "",
-            DeploymentReviewerType::FallthroughString => "*",
+            DeploymentReviewerType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeploymentReviewerType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Team" => DeploymentReviewerType::Team,
+            "User" => DeploymentReviewerType::User,
+            "" => DeploymentReviewerType::Noop,
+            _ => DeploymentReviewerType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeploymentReviewerType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeploymentReviewerType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeploymentReviewerType {
     fn default() -> DeploymentReviewerType {
         DeploymentReviewerType::Noop
@@ -11484,7 +12383,8 @@ pub struct ActionsSecret {
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WorkflowState {
     #[serde(rename = "active")]
     Active,
@@ -11498,8 +12398,9 @@ pub enum WorkflowState {
     DisabledManually,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WorkflowState {
@@ -11511,12 +12412,48 @@ impl std::fmt::Display for WorkflowState {
             WorkflowState::DisabledInactivity => "disabled_inactivity",
             WorkflowState::DisabledManually => "disabled_manually",
             WorkflowState::Noop => "",
-            WorkflowState::FallthroughString => "*",
+            WorkflowState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WorkflowState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => WorkflowState::Active,
+            "deleted" => WorkflowState::Deleted,
+            "disabled_fork" => WorkflowState::DisabledFork,
+            "disabled_inactivity" => WorkflowState::DisabledInactivity,
+            "disabled_manually" => WorkflowState::DisabledManually,
+            "" => WorkflowState::Noop,
+            _ => WorkflowState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WorkflowState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WorkflowState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WorkflowState {
     fn default() -> WorkflowState {
         WorkflowState::Noop
@@ -12814,7 +13751,8 @@ pub struct DeploymentSimple {
     pub url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Conclusion {
     #[serde(rename = "action_required")]
     ActionRequired,
@@ -12832,8 +13770,9 @@ pub enum Conclusion {
     TimedOut,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Conclusion {
@@ -12847,12 +13786,50 @@ impl std::fmt::Display for Conclusion {
             Conclusion::Success => "success",
             Conclusion::TimedOut => "timed_out",
             Conclusion::Noop => "",
-            Conclusion::FallthroughString => "*",
+            Conclusion::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Conclusion {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "action_required" => Conclusion::ActionRequired,
+            "cancelled" => Conclusion::Cancelled,
+            "failure" => Conclusion::Failure,
+            "neutral" => Conclusion::Neutral,
+            "skipped" => Conclusion::Skipped,
+            "success" => Conclusion::Success,
+            "timed_out" => Conclusion::TimedOut,
+            "" => Conclusion::Noop,
+            _ => Conclusion::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Conclusion {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Conclusion {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Conclusion {
     fn default() -> Conclusion {
         Conclusion::Noop
@@ -13198,7 +14175,8 @@ pub struct CheckSuitePreference {
 /**
 * State of a code scanning alert.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CodeScanningAlertState {
     #[serde(rename = "closed")]
     Closed,
@@ -13210,8 +14188,9 @@ pub enum CodeScanningAlertState {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertState {
@@ -13222,12 +14201,47 @@ impl std::fmt::Display for CodeScanningAlertState {
             CodeScanningAlertState::Fixed => "fixed",
             CodeScanningAlertState::Open => "open",
             CodeScanningAlertState::Noop => "",
-            CodeScanningAlertState::FallthroughString => "*",
+            CodeScanningAlertState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CodeScanningAlertState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "closed" => CodeScanningAlertState::Closed,
+            "dismissed" => CodeScanningAlertState::Dismissed,
+            "fixed" => CodeScanningAlertState::Fixed,
+            "open" => CodeScanningAlertState::Open,
+            "" => CodeScanningAlertState::Noop,
+            _ => CodeScanningAlertState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CodeScanningAlertState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CodeScanningAlertState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CodeScanningAlertState {
     fn default() -> CodeScanningAlertState {
         CodeScanningAlertState::Noop
@@ -13242,7 +14256,8 @@ impl CodeScanningAlertState {
 /**
 * **Required when the state is dismissed.** The reason for dismissing or closing the alert. Can be one of: `false positive`, `won't fix`, and `used in tests`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CodeScanningAlertDismissedReason {
     #[serde(rename = "false positive")]
     FalsePositive,
@@ -13252,8 +14267,9 @@ pub enum CodeScanningAlertDismissedReason {
     WonTFix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertDismissedReason {
@@ -13263,12 +14279,46 @@ impl std::fmt::Display for CodeScanningAlertDismissedReason {
             CodeScanningAlertDismissedReason::UsedInTests => "used in tests",
             CodeScanningAlertDismissedReason::WonTFix => "won't fix",
             CodeScanningAlertDismissedReason::Noop => "",
-            CodeScanningAlertDismissedReason::FallthroughString => "*",
+            CodeScanningAlertDismissedReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CodeScanningAlertDismissedReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "false positive" => CodeScanningAlertDismissedReason::FalsePositive,
+            "used in tests" => CodeScanningAlertDismissedReason::UsedInTests,
+            "won't fix" => CodeScanningAlertDismissedReason::WonTFix,
+            "" => CodeScanningAlertDismissedReason::Noop,
+            _ => CodeScanningAlertDismissedReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CodeScanningAlertDismissedReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CodeScanningAlertDismissedReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CodeScanningAlertDismissedReason {
     fn default() -> CodeScanningAlertDismissedReason {
         CodeScanningAlertDismissedReason::Noop
@@ -13283,7 +14333,8 @@ impl CodeScanningAlertDismissedReason {
 /**
 * The severity of the alert.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Severity {
     #[serde(rename = "error")]
     Error,
@@ -13295,8 +14346,9 @@ pub enum Severity {
     Warning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Severity {
@@ -13307,12 +14359,47 @@ impl std::fmt::Display for Severity {
             Severity::Note => "note",
             Severity::Warning => "warning",
             Severity::Noop => "",
-            Severity::FallthroughString => "*",
+            Severity::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Severity {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "error" => Severity::Error,
+            "none" => Severity::None,
+            "note" => Severity::Note,
+            "warning" => Severity::Warning,
+            "" => Severity::Noop,
+            _ => Severity::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Severity {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Severity {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Severity {
     fn default() -> Severity {
         Severity::Noop
@@ -13423,7 +14510,8 @@ pub struct CodeScanningAlertLocation {
 /**
 * A classification of the file. For example to identify it as generated.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CodeScanningAlertClassification {
     #[serde(rename = "generated")]
     Generated,
@@ -13435,8 +14523,9 @@ pub enum CodeScanningAlertClassification {
     Test,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertClassification {
@@ -13447,12 +14536,47 @@ impl std::fmt::Display for CodeScanningAlertClassification {
             CodeScanningAlertClassification::Source => "source",
             CodeScanningAlertClassification::Test => "test",
             CodeScanningAlertClassification::Noop => "",
-            CodeScanningAlertClassification::FallthroughString => "*",
+            CodeScanningAlertClassification::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CodeScanningAlertClassification {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "generated" => CodeScanningAlertClassification::Generated,
+            "library" => CodeScanningAlertClassification::Library,
+            "source" => CodeScanningAlertClassification::Source,
+            "test" => CodeScanningAlertClassification::Test,
+            "" => CodeScanningAlertClassification::Noop,
+            _ => CodeScanningAlertClassification::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CodeScanningAlertClassification {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CodeScanningAlertClassification {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CodeScanningAlertClassification {
     fn default() -> CodeScanningAlertClassification {
         CodeScanningAlertClassification::Noop
@@ -13595,7 +14719,8 @@ pub struct CodeScanningAlertItems {
 /**
 * The security severity of the alert.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SecuritySeverityLevel {
     #[serde(rename = "critical")]
     Critical,
@@ -13607,8 +14732,9 @@ pub enum SecuritySeverityLevel {
     Medium,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SecuritySeverityLevel {
@@ -13619,12 +14745,47 @@ impl std::fmt::Display for SecuritySeverityLevel {
             SecuritySeverityLevel::Low => "low",
             SecuritySeverityLevel::Medium => "medium",
             SecuritySeverityLevel::Noop => "",
-            SecuritySeverityLevel::FallthroughString => "*",
+            SecuritySeverityLevel::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SecuritySeverityLevel {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "critical" => SecuritySeverityLevel::Critical,
+            "high" => SecuritySeverityLevel::High,
+            "low" => SecuritySeverityLevel::Low,
+            "medium" => SecuritySeverityLevel::Medium,
+            "" => SecuritySeverityLevel::Noop,
+            _ => SecuritySeverityLevel::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SecuritySeverityLevel {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SecuritySeverityLevel {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SecuritySeverityLevel {
     fn default() -> SecuritySeverityLevel {
         SecuritySeverityLevel::Noop
@@ -13755,7 +14916,8 @@ pub struct CodeScanningAlert {
 /**
 * Sets the state of the code scanning alert. Can be one of `open` or `dismissed`. You must provide `dismissed_reason` when you set the state to `dismissed`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CodeScanningAlertSetState {
     #[serde(rename = "dismissed")]
     Dismissed,
@@ -13763,8 +14925,9 @@ pub enum CodeScanningAlertSetState {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertSetState {
@@ -13773,12 +14936,45 @@ impl std::fmt::Display for CodeScanningAlertSetState {
             CodeScanningAlertSetState::Dismissed => "dismissed",
             CodeScanningAlertSetState::Open => "open",
             CodeScanningAlertSetState::Noop => "",
-            CodeScanningAlertSetState::FallthroughString => "*",
+            CodeScanningAlertSetState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CodeScanningAlertSetState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "dismissed" => CodeScanningAlertSetState::Dismissed,
+            "open" => CodeScanningAlertSetState::Open,
+            "" => CodeScanningAlertSetState::Noop,
+            _ => CodeScanningAlertSetState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CodeScanningAlertSetState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CodeScanningAlertSetState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CodeScanningAlertSetState {
     fn default() -> CodeScanningAlertSetState {
         CodeScanningAlertSetState::Noop
@@ -13922,7 +15118,8 @@ pub struct CodeScanningSarifsReceipt {
 /**
 * `pending` files have not yet been processed, while `complete` means all results in the SARIF have been stored.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ProcessingStatus {
     #[serde(rename = "complete")]
     Complete,
@@ -13930,8 +15127,9 @@ pub enum ProcessingStatus {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ProcessingStatus {
@@ -13940,12 +15138,45 @@ impl std::fmt::Display for ProcessingStatus {
             ProcessingStatus::Complete => "complete",
             ProcessingStatus::Pending => "pending",
             ProcessingStatus::Noop => "",
-            ProcessingStatus::FallthroughString => "*",
+            ProcessingStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ProcessingStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "complete" => ProcessingStatus::Complete,
+            "pending" => ProcessingStatus::Pending,
+            "" => ProcessingStatus::Noop,
+            _ => ProcessingStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ProcessingStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProcessingStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ProcessingStatus {
     fn default() -> ProcessingStatus {
         ProcessingStatus::Noop
@@ -14105,7 +15336,8 @@ pub struct Collaborator {
 /**
 * The permission associated with the invitation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RepositoryInvitationPermissions {
     #[serde(rename = "admin")]
     Admin,
@@ -14119,8 +15351,9 @@ pub enum RepositoryInvitationPermissions {
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RepositoryInvitationPermissions {
@@ -14132,12 +15365,48 @@ impl std::fmt::Display for RepositoryInvitationPermissions {
             RepositoryInvitationPermissions::Triage => "triage",
             RepositoryInvitationPermissions::Write => "write",
             RepositoryInvitationPermissions::Noop => "",
-            RepositoryInvitationPermissions::FallthroughString => "*",
+            RepositoryInvitationPermissions::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RepositoryInvitationPermissions {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => RepositoryInvitationPermissions::Admin,
+            "maintain" => RepositoryInvitationPermissions::Maintain,
+            "read" => RepositoryInvitationPermissions::Read,
+            "triage" => RepositoryInvitationPermissions::Triage,
+            "write" => RepositoryInvitationPermissions::Write,
+            "" => RepositoryInvitationPermissions::Noop,
+            _ => RepositoryInvitationPermissions::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RepositoryInvitationPermissions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RepositoryInvitationPermissions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RepositoryInvitationPermissions {
     fn default() -> RepositoryInvitationPermissions {
         RepositoryInvitationPermissions::Noop
@@ -14324,7 +15593,8 @@ pub struct Link {
 /**
 * The merge method to use.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MergeMethod {
     #[serde(rename = "merge")]
     Merge,
@@ -14334,8 +15604,9 @@ pub enum MergeMethod {
     Squash,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MergeMethod {
@@ -14345,12 +15616,46 @@ impl std::fmt::Display for MergeMethod {
             MergeMethod::Rebase => "rebase",
             MergeMethod::Squash => "squash",
             MergeMethod::Noop => "",
-            MergeMethod::FallthroughString => "*",
+            MergeMethod::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MergeMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "merge" => MergeMethod::Merge,
+            "rebase" => MergeMethod::Rebase,
+            "squash" => MergeMethod::Squash,
+            "" => MergeMethod::Noop,
+            _ => MergeMethod::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MergeMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MergeMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MergeMethod {
     fn default() -> MergeMethod {
         MergeMethod::Noop
@@ -15024,7 +16329,8 @@ pub struct DiffEntry {
     pub status: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CommitComparisonStatus {
     #[serde(rename = "ahead")]
     Ahead,
@@ -15036,8 +16342,9 @@ pub enum CommitComparisonStatus {
     Identical,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CommitComparisonStatus {
@@ -15048,12 +16355,47 @@ impl std::fmt::Display for CommitComparisonStatus {
             CommitComparisonStatus::Diverged => "diverged",
             CommitComparisonStatus::Identical => "identical",
             CommitComparisonStatus::Noop => "",
-            CommitComparisonStatus::FallthroughString => "*",
+            CommitComparisonStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CommitComparisonStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ahead" => CommitComparisonStatus::Ahead,
+            "behind" => CommitComparisonStatus::Behind,
+            "diverged" => CommitComparisonStatus::Diverged,
+            "identical" => CommitComparisonStatus::Identical,
+            "" => CommitComparisonStatus::Noop,
+            _ => CommitComparisonStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CommitComparisonStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CommitComparisonStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CommitComparisonStatus {
     fn default() -> CommitComparisonStatus {
         CommitComparisonStatus::Noop
@@ -15842,7 +17184,8 @@ pub struct Contributor {
 /**
 * The state of the status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeploymentStatusState {
     #[serde(rename = "error")]
     Error,
@@ -15860,8 +17203,9 @@ pub enum DeploymentStatusState {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeploymentStatusState {
@@ -15875,12 +17219,50 @@ impl std::fmt::Display for DeploymentStatusState {
             DeploymentStatusState::Queued => "queued",
             DeploymentStatusState::Success => "success",
             DeploymentStatusState::Noop => "",
-            DeploymentStatusState::FallthroughString => "*",
+            DeploymentStatusState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeploymentStatusState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "error" => DeploymentStatusState::Error,
+            "failure" => DeploymentStatusState::Failure,
+            "in_progress" => DeploymentStatusState::InProgress,
+            "inactive" => DeploymentStatusState::Inactive,
+            "pending" => DeploymentStatusState::Pending,
+            "queued" => DeploymentStatusState::Queued,
+            "success" => DeploymentStatusState::Success,
+            "" => DeploymentStatusState::Noop,
+            _ => DeploymentStatusState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeploymentStatusState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeploymentStatusState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeploymentStatusState {
     fn default() -> DeploymentStatusState {
         DeploymentStatusState::Noop
@@ -16604,7 +17986,8 @@ pub struct Hook {
     pub url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ImportStatus {
     #[serde(rename = "auth")]
     Auth,
@@ -16640,8 +18023,9 @@ pub enum ImportStatus {
     WaitingToPush,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ImportStatus {
@@ -16664,12 +18048,59 @@ impl std::fmt::Display for ImportStatus {
             ImportStatus::Unknown => "unknown",
             ImportStatus::WaitingToPush => "waiting_to_push",
             ImportStatus::Noop => "",
-            ImportStatus::FallthroughString => "*",
+            ImportStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ImportStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "auth" => ImportStatus::Auth,
+            "auth_failed" => ImportStatus::AuthFailed,
+            "choose" => ImportStatus::Choose,
+            "complete" => ImportStatus::Complete,
+            "detecting" => ImportStatus::Detecting,
+            "detection_found_multiple" => ImportStatus::DetectionFoundMultiple,
+            "detection_found_nothing" => ImportStatus::DetectionFoundNothing,
+            "detection_needs_auth" => ImportStatus::DetectionNeedsAuth,
+            "error" => ImportStatus::Error,
+            "importing" => ImportStatus::Importing,
+            "mapping" => ImportStatus::Mapping,
+            "none" => ImportStatus::None,
+            "pushing" => ImportStatus::Pushing,
+            "setup" => ImportStatus::Setup,
+            "unknown" => ImportStatus::Unknown,
+            "waiting_to_push" => ImportStatus::WaitingToPush,
+            "" => ImportStatus::Noop,
+            _ => ImportStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ImportStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ImportStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ImportStatus {
     fn default() -> ImportStatus {
         ImportStatus::Noop
@@ -18332,14 +19763,16 @@ pub struct PullRequestReviewCommentLinks {
 /**
 * The side of the diff to which the comment applies. The side of the last line of the range for a multi-line comment
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Side {
     #[serde(rename = "LEFT")]
     Left,
     #[serde(rename = "RIGHT")]
     Right,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Side {
@@ -18347,12 +19780,44 @@ impl std::fmt::Display for Side {
         match self {
             Side::Left => "LEFT",
             Side::Right => "RIGHT",
-            Side::FallthroughString => "*",
+            Side::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Side {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "LEFT" => Side::Left,
+            "RIGHT" => Side::Right,
+            _ => Side::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Side {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Side {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Side {
     fn default() -> Side {
         Side::Right
@@ -18795,7 +20260,8 @@ pub struct PagesSourceHash {
     pub path: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PagesHttpsCertificateState {
     #[serde(rename = "approved")]
     Approved,
@@ -18823,8 +20289,9 @@ pub enum PagesHttpsCertificateState {
     Uploaded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PagesHttpsCertificateState {
@@ -18843,12 +20310,55 @@ impl std::fmt::Display for PagesHttpsCertificateState {
             PagesHttpsCertificateState::New => "new",
             PagesHttpsCertificateState::Uploaded => "uploaded",
             PagesHttpsCertificateState::Noop => "",
-            PagesHttpsCertificateState::FallthroughString => "*",
+            PagesHttpsCertificateState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PagesHttpsCertificateState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approved" => PagesHttpsCertificateState::Approved,
+            "authorization_created" => PagesHttpsCertificateState::AuthorizationCreated,
+            "authorization_pending" => PagesHttpsCertificateState::AuthorizationPending,
+            "authorization_revoked" => PagesHttpsCertificateState::AuthorizationRevoked,
+            "authorized" => PagesHttpsCertificateState::Authorized,
+            "bad_authz" => PagesHttpsCertificateState::BadAuthz,
+            "destroy_pending" => PagesHttpsCertificateState::DestroyPending,
+            "dns_changed" => PagesHttpsCertificateState::DnsChanged,
+            "errored" => PagesHttpsCertificateState::Errored,
+            "issued" => PagesHttpsCertificateState::Issued,
+            "new" => PagesHttpsCertificateState::New,
+            "uploaded" => PagesHttpsCertificateState::Uploaded,
+            "" => PagesHttpsCertificateState::Noop,
+            _ => PagesHttpsCertificateState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PagesHttpsCertificateState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PagesHttpsCertificateState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PagesHttpsCertificateState {
     fn default() -> PagesHttpsCertificateState {
         PagesHttpsCertificateState::Noop
@@ -18890,7 +20400,8 @@ pub struct PagesHttpsCertificate {
 /**
 * The status of the most recent build of the Page.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PageStatus {
     #[serde(rename = "building")]
     Building,
@@ -18900,8 +20411,9 @@ pub enum PageStatus {
     Errored,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PageStatus {
@@ -18911,12 +20423,46 @@ impl std::fmt::Display for PageStatus {
             PageStatus::Built => "built",
             PageStatus::Errored => "errored",
             PageStatus::Noop => "",
-            PageStatus::FallthroughString => "*",
+            PageStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PageStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "building" => PageStatus::Building,
+            "built" => PageStatus::Built,
+            "errored" => PageStatus::Errored,
+            "" => PageStatus::Noop,
+            _ => PageStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PageStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PageStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PageStatus {
     fn default() -> PageStatus {
         PageStatus::Noop
@@ -21027,7 +22573,8 @@ pub struct ReviewComment {
 /**
 * State of the release asset.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReleaseAssetState {
     #[serde(rename = "open")]
     Open,
@@ -21035,8 +22582,9 @@ pub enum ReleaseAssetState {
     Uploaded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReleaseAssetState {
@@ -21045,12 +22593,45 @@ impl std::fmt::Display for ReleaseAssetState {
             ReleaseAssetState::Open => "open",
             ReleaseAssetState::Uploaded => "uploaded",
             ReleaseAssetState::Noop => "",
-            ReleaseAssetState::FallthroughString => "*",
+            ReleaseAssetState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReleaseAssetState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "open" => ReleaseAssetState::Open,
+            "uploaded" => ReleaseAssetState::Uploaded,
+            "" => ReleaseAssetState::Noop,
+            _ => ReleaseAssetState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReleaseAssetState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReleaseAssetState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReleaseAssetState {
     fn default() -> ReleaseAssetState {
         ReleaseAssetState::Noop
@@ -21285,7 +22866,8 @@ pub struct Release {
 /**
 * Sets the state of the secret scanning alert. Can be either `open` or `resolved`. You must provide `resolution` when you set the state to `resolved`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SecretScanningAlertState {
     #[serde(rename = "open")]
     Open,
@@ -21293,8 +22875,9 @@ pub enum SecretScanningAlertState {
     Resolved,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SecretScanningAlertState {
@@ -21303,12 +22886,45 @@ impl std::fmt::Display for SecretScanningAlertState {
             SecretScanningAlertState::Open => "open",
             SecretScanningAlertState::Resolved => "resolved",
             SecretScanningAlertState::Noop => "",
-            SecretScanningAlertState::FallthroughString => "*",
+            SecretScanningAlertState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SecretScanningAlertState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "open" => SecretScanningAlertState::Open,
+            "resolved" => SecretScanningAlertState::Resolved,
+            "" => SecretScanningAlertState::Noop,
+            _ => SecretScanningAlertState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SecretScanningAlertState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretScanningAlertState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SecretScanningAlertState {
     fn default() -> SecretScanningAlertState {
         SecretScanningAlertState::Noop
@@ -21323,7 +22939,8 @@ impl SecretScanningAlertState {
 /**
 * **Required when the `state` is `resolved`.** The reason for resolving the alert. Can be one of `false_positive`, `wont_fix`, `revoked`, or `used_in_tests`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SecretScanningAlertResolution {
     #[serde(rename = "false_positive")]
     FalsePositive,
@@ -21335,8 +22952,9 @@ pub enum SecretScanningAlertResolution {
     WontFix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SecretScanningAlertResolution {
@@ -21347,12 +22965,47 @@ impl std::fmt::Display for SecretScanningAlertResolution {
             SecretScanningAlertResolution::UsedInTests => "used_in_tests",
             SecretScanningAlertResolution::WontFix => "wont_fix",
             SecretScanningAlertResolution::Noop => "",
-            SecretScanningAlertResolution::FallthroughString => "*",
+            SecretScanningAlertResolution::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SecretScanningAlertResolution {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "false_positive" => SecretScanningAlertResolution::FalsePositive,
+            "revoked" => SecretScanningAlertResolution::Revoked,
+            "used_in_tests" => SecretScanningAlertResolution::UsedInTests,
+            "wont_fix" => SecretScanningAlertResolution::WontFix,
+            "" => SecretScanningAlertResolution::Noop,
+            _ => SecretScanningAlertResolution::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SecretScanningAlertResolution {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretScanningAlertResolution {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SecretScanningAlertResolution {
     fn default() -> SecretScanningAlertResolution {
         SecretScanningAlertResolution::Noop
@@ -22086,7 +23739,8 @@ pub struct ScimUserMeta {
     pub resource_type: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Op {
     #[serde(rename = "add")]
     Add,
@@ -22096,8 +23750,9 @@ pub enum Op {
     Replace,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Op {
@@ -22107,12 +23762,46 @@ impl std::fmt::Display for Op {
             Op::Remove => "remove",
             Op::Replace => "replace",
             Op::Noop => "",
-            Op::FallthroughString => "*",
+            Op::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Op {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "add" => Op::Add,
+            "remove" => Op::Remove,
+            "replace" => Op::Replace,
+            "" => Op::Noop,
+            _ => Op::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Op {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Op {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Op {
     fn default() -> Op {
         Op::Noop
@@ -24381,7 +26070,8 @@ pub struct KeySimple {
 *   
 *   The default is `web`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Include {
     #[serde(rename = "all")]
     All,
@@ -24391,8 +26081,9 @@ pub enum Include {
     Web,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Include {
@@ -24402,13 +26093,47 @@ impl std::fmt::Display for Include {
             Include::Git => "git",
             Include::Web => "web",
             Include::Noop => "",
-            Include::FallthroughString => "*",
+            Include::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
-impl Default for Include {
+impl std::str::FromStr for Include {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => Include::All,
+            "git" => Include::Git,
+            "web" => Include::Web,
+            "" => Include::Noop,
+            _ => Include::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Include {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Include {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl Default for Include {
     fn default() -> Include {
         Include::Noop
     }
@@ -24424,7 +26149,8 @@ impl Include {
 *   
 *   The default is `desc`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Order {
     #[serde(rename = "asc")]
     Asc,
@@ -24432,8 +26158,9 @@ pub enum Order {
     Desc,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Order {
@@ -24442,12 +26169,45 @@ impl std::fmt::Display for Order {
             Order::Asc => "asc",
             Order::Desc => "desc",
             Order::Noop => "",
-            Order::FallthroughString => "*",
+            Order::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Order {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "asc" => Order::Asc,
+            "desc" => Order::Desc,
+            "" => Order::Noop,
+            _ => Order::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Order {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Order {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Order {
     fn default() -> Order {
         Order::Noop
@@ -24462,14 +26222,16 @@ impl Order {
 /**
 * One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Sort {
     #[serde(rename = "created")]
     Created,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Sort {
@@ -24477,12 +26239,44 @@ impl std::fmt::Display for Sort {
         match self {
             Sort::Created => "created",
             Sort::Updated => "updated",
-            Sort::FallthroughString => "*",
+            Sort::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Sort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "created" => Sort::Created,
+            "updated" => Sort::Updated,
+            _ => Sort::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Sort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Sort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Sort {
     fn default() -> Sort {
         Sort::Created
@@ -24492,7 +26286,8 @@ impl Default for Sort {
 /**
 * Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run)."
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WorkflowRunStatus {
     #[serde(rename = "action_required")]
     ActionRequired,
@@ -24522,8 +26317,9 @@ pub enum WorkflowRunStatus {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WorkflowRunStatus {
@@ -24543,12 +26339,56 @@ impl std::fmt::Display for WorkflowRunStatus {
             WorkflowRunStatus::TimedOut => "timed_out",
             WorkflowRunStatus::Waiting => "waiting",
             WorkflowRunStatus::Noop => "",
-            WorkflowRunStatus::FallthroughString => "*",
+            WorkflowRunStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WorkflowRunStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "action_required" => WorkflowRunStatus::ActionRequired,
+            "cancelled" => WorkflowRunStatus::Cancelled,
+            "completed" => WorkflowRunStatus::Completed,
+            "failure" => WorkflowRunStatus::Failure,
+            "in_progress" => WorkflowRunStatus::InProgress,
+            "neutral" => WorkflowRunStatus::Neutral,
+            "queued" => WorkflowRunStatus::Queued,
+            "requested" => WorkflowRunStatus::Requested,
+            "skipped" => WorkflowRunStatus::Skipped,
+            "stale" => WorkflowRunStatus::Stale,
+            "success" => WorkflowRunStatus::Success,
+            "timed_out" => WorkflowRunStatus::TimedOut,
+            "waiting" => WorkflowRunStatus::Waiting,
+            "" => WorkflowRunStatus::Noop,
+            _ => WorkflowRunStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WorkflowRunStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WorkflowRunStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WorkflowRunStatus {
     fn default() -> WorkflowRunStatus {
         WorkflowRunStatus::Noop
@@ -24617,14 +26457,16 @@ impl std::convert::From<TitleOneOf> for String {
 /**
 * Must be one of: `day`, `week`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Per {
     #[serde(rename = "day")]
     Day,
     #[serde(rename = "week")]
     Week,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Per {
@@ -24632,12 +26474,44 @@ impl std::fmt::Display for Per {
         match self {
             Per::Day => "day",
             Per::Week => "week",
-            Per::FallthroughString => "*",
+            Per::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Per {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "day" => Per::Day,
+            "week" => Per::Week,
+            _ => Per::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Per {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Per {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Per {
     fn default() -> Per {
         Per::Day
@@ -25397,14 +27271,16 @@ pub struct FilesAdditionalPropertiesData {
     pub content: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Public {
     #[serde(rename = "false")]
     False,
     #[serde(rename = "true")]
     True,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Public {
@@ -25412,12 +27288,44 @@ impl std::fmt::Display for Public {
         match self {
             Public::False => "false",
             Public::True => "true",
-            Public::FallthroughString => "*",
+            Public::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Public {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "false" => Public::False,
+            "true" => Public::True,
+            _ => Public::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Public {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Public {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Public {
     fn default() -> Public {
         Public::False
@@ -25552,7 +27460,8 @@ pub struct AppsListInstallationReposResponse {
 *   \* `subscribed`: Issues you're subscribed to updates for  
 *   \* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Filter {
     #[serde(rename = "all")]
     All,
@@ -25566,8 +27475,9 @@ pub enum Filter {
     Repos,
     #[serde(rename = "subscribed")]
     Subscribed,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Filter {
@@ -25579,12 +27489,48 @@ impl std::fmt::Display for Filter {
             Filter::Mentioned => "mentioned",
             Filter::Repos => "repos",
             Filter::Subscribed => "subscribed",
-            Filter::FallthroughString => "*",
+            Filter::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Filter {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => Filter::All,
+            "assigned" => Filter::Assigned,
+            "created" => Filter::Created,
+            "mentioned" => Filter::Mentioned,
+            "repos" => Filter::Repos,
+            "subscribed" => Filter::Subscribed,
+            _ => Filter::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Filter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Filter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Filter {
     fn default() -> Filter {
         Filter::Assigned
@@ -25594,7 +27540,8 @@ impl Default for Filter {
 /**
 * Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuesListState {
     #[serde(rename = "all")]
     All,
@@ -25602,8 +27549,9 @@ pub enum IssuesListState {
     Closed,
     #[serde(rename = "open")]
     Open,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuesListState {
@@ -25612,12 +27560,45 @@ impl std::fmt::Display for IssuesListState {
             IssuesListState::All => "all",
             IssuesListState::Closed => "closed",
             IssuesListState::Open => "open",
-            IssuesListState::FallthroughString => "*",
+            IssuesListState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuesListState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => IssuesListState::All,
+            "closed" => IssuesListState::Closed,
+            "open" => IssuesListState::Open,
+            _ => IssuesListState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuesListState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuesListState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuesListState {
     fn default() -> IssuesListState {
         IssuesListState::Open
@@ -25627,7 +27608,8 @@ impl Default for IssuesListState {
 /**
 * What to sort results by. Can be either `created`, `updated`, `comments`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuesListSort {
     #[serde(rename = "comments")]
     Comments,
@@ -25635,8 +27617,9 @@ pub enum IssuesListSort {
     Created,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuesListSort {
@@ -25645,12 +27628,45 @@ impl std::fmt::Display for IssuesListSort {
             IssuesListSort::Comments => "comments",
             IssuesListSort::Created => "created",
             IssuesListSort::Updated => "updated",
-            IssuesListSort::FallthroughString => "*",
+            IssuesListSort::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuesListSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "comments" => IssuesListSort::Comments,
+            "created" => IssuesListSort::Created,
+            "updated" => IssuesListSort::Updated,
+            _ => IssuesListSort::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuesListSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuesListSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuesListSort {
     fn default() -> IssuesListSort {
         IssuesListSort::Created
@@ -25660,14 +27676,16 @@ impl Default for IssuesListSort {
 /**
 * The rendering mode.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Mode {
     #[serde(rename = "gfm")]
     Gfm,
     #[serde(rename = "markdown")]
     Markdown,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Mode {
@@ -25675,12 +27693,44 @@ impl std::fmt::Display for Mode {
         match self {
             Mode::Gfm => "gfm",
             Mode::Markdown => "markdown",
-            Mode::FallthroughString => "*",
+            Mode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Mode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "gfm" => Mode::Gfm,
+            "markdown" => Mode::Markdown,
+            _ => Mode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Mode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Mode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Mode {
     fn default() -> Mode {
         Mode::Markdown
@@ -25733,7 +27783,8 @@ pub struct ActivitySetThreadSubscriptionRequest {
 *   \* `none` - only admin members can create repositories.  
 *   **Note:** This parameter is deprecated and will be removed in the future. Its return value ignores internal repositories. Using this parameter overrides values set in `members_can_create_repositories`. See the parameter deprecation notice in the operation description for details.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MembersAllowedRepositoryCreationType {
     #[serde(rename = "all")]
     All,
@@ -25743,8 +27794,9 @@ pub enum MembersAllowedRepositoryCreationType {
     Private,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MembersAllowedRepositoryCreationType {
@@ -25754,12 +27806,46 @@ impl std::fmt::Display for MembersAllowedRepositoryCreationType {
             MembersAllowedRepositoryCreationType::None => "none",
             MembersAllowedRepositoryCreationType::Private => "private",
             MembersAllowedRepositoryCreationType::Noop => "",
-            MembersAllowedRepositoryCreationType::FallthroughString => "*",
+            MembersAllowedRepositoryCreationType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MembersAllowedRepositoryCreationType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => MembersAllowedRepositoryCreationType::All,
+            "none" => MembersAllowedRepositoryCreationType::None,
+            "private" => MembersAllowedRepositoryCreationType::Private,
+            "" => MembersAllowedRepositoryCreationType::Noop,
+            _ => MembersAllowedRepositoryCreationType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MembersAllowedRepositoryCreationType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MembersAllowedRepositoryCreationType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MembersAllowedRepositoryCreationType {
     fn default() -> MembersAllowedRepositoryCreationType {
         MembersAllowedRepositoryCreationType::Noop
@@ -26263,7 +28349,8 @@ pub struct InteractionsGetRestrictionsResponseAnyOf {
 *   \* `direct_member` - Non-owner organization members with ability to see other members and join teams by invitation.  
 *   \* `billing_manager` - Non-owner organization members with ability to manage the billing settings of your organization.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrgsCreateInvitationRequestRole {
     #[serde(rename = "admin")]
     Admin,
@@ -26271,8 +28358,9 @@ pub enum OrgsCreateInvitationRequestRole {
     BillingManager,
     #[serde(rename = "direct_member")]
     DirectMember,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrgsCreateInvitationRequestRole {
@@ -26281,12 +28369,45 @@ impl std::fmt::Display for OrgsCreateInvitationRequestRole {
             OrgsCreateInvitationRequestRole::Admin => "admin",
             OrgsCreateInvitationRequestRole::BillingManager => "billing_manager",
             OrgsCreateInvitationRequestRole::DirectMember => "direct_member",
-            OrgsCreateInvitationRequestRole::FallthroughString => "*",
+            OrgsCreateInvitationRequestRole::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrgsCreateInvitationRequestRole {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => OrgsCreateInvitationRequestRole::Admin,
+            "billing_manager" => OrgsCreateInvitationRequestRole::BillingManager,
+            "direct_member" => OrgsCreateInvitationRequestRole::DirectMember,
+            _ => OrgsCreateInvitationRequestRole::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrgsCreateInvitationRequestRole {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrgsCreateInvitationRequestRole {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrgsCreateInvitationRequestRole {
     fn default() -> OrgsCreateInvitationRequestRole {
         OrgsCreateInvitationRequestRole::DirectMember
@@ -26331,14 +28452,16 @@ pub struct OrgsCreateInvitationRequest {
 *   \* `2fa_disabled` - Members without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled. Available for organization owners.  
 *   \* `all` - All members the authenticated user can see.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrgsListMembersFilter {
     #[serde(rename = "2fa_disabled")]
     TwoFaDisabled,
     #[serde(rename = "all")]
     All,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrgsListMembersFilter {
@@ -26346,12 +28469,44 @@ impl std::fmt::Display for OrgsListMembersFilter {
         match self {
             OrgsListMembersFilter::TwoFaDisabled => "2fa_disabled",
             OrgsListMembersFilter::All => "all",
-            OrgsListMembersFilter::FallthroughString => "*",
+            OrgsListMembersFilter::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrgsListMembersFilter {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "2fa_disabled" => OrgsListMembersFilter::TwoFaDisabled,
+            "all" => OrgsListMembersFilter::All,
+            _ => OrgsListMembersFilter::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrgsListMembersFilter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrgsListMembersFilter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrgsListMembersFilter {
     fn default() -> OrgsListMembersFilter {
         OrgsListMembersFilter::All
@@ -26364,7 +28519,8 @@ impl Default for OrgsListMembersFilter {
 *   \* `admin` - Organization owners.  
 *   \* `member` - Non-owner organization members.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrgsListMembersRole {
     #[serde(rename = "admin")]
     Admin,
@@ -26372,8 +28528,9 @@ pub enum OrgsListMembersRole {
     All,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrgsListMembersRole {
@@ -26382,12 +28539,45 @@ impl std::fmt::Display for OrgsListMembersRole {
             OrgsListMembersRole::Admin => "admin",
             OrgsListMembersRole::All => "all",
             OrgsListMembersRole::Member => "member",
-            OrgsListMembersRole::FallthroughString => "*",
+            OrgsListMembersRole::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrgsListMembersRole {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => OrgsListMembersRole::Admin,
+            "all" => OrgsListMembersRole::All,
+            "member" => OrgsListMembersRole::Member,
+            _ => OrgsListMembersRole::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrgsListMembersRole {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrgsListMembersRole {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrgsListMembersRole {
     fn default() -> OrgsListMembersRole {
         OrgsListMembersRole::All
@@ -26399,14 +28589,16 @@ impl Default for OrgsListMembersRole {
 *   \* `admin` - The user will become an owner of the organization.  
 *   \* `member` - The user will become a non-owner member of the organization.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrgsSetMembershipUserRequestRole {
     #[serde(rename = "admin")]
     Admin,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrgsSetMembershipUserRequestRole {
@@ -26414,12 +28606,44 @@ impl std::fmt::Display for OrgsSetMembershipUserRequestRole {
         match self {
             OrgsSetMembershipUserRequestRole::Admin => "admin",
             OrgsSetMembershipUserRequestRole::Member => "member",
-            OrgsSetMembershipUserRequestRole::FallthroughString => "*",
+            OrgsSetMembershipUserRequestRole::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrgsSetMembershipUserRequestRole {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => OrgsSetMembershipUserRequestRole::Admin,
+            "member" => OrgsSetMembershipUserRequestRole::Member,
+            _ => OrgsSetMembershipUserRequestRole::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrgsSetMembershipUserRequestRole {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrgsSetMembershipUserRequestRole {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrgsSetMembershipUserRequestRole {
     fn default() -> OrgsSetMembershipUserRequestRole {
         OrgsSetMembershipUserRequestRole::Member
@@ -26440,14 +28664,16 @@ pub struct OrgsSetMembershipUserRequest {
 /**
 * Allowed values that can be passed to the exclude param.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Exclude {
     #[serde(rename = "repositories")]
     Repositories,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Exclude {
@@ -26455,12 +28681,44 @@ impl std::fmt::Display for Exclude {
         match self {
             Exclude::Repositories => "repositories",
             Exclude::Noop => "",
-            Exclude::FallthroughString => "*",
+            Exclude::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Exclude {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "repositories" => Exclude::Repositories,
+            "" => Exclude::Noop,
+            _ => Exclude::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Exclude {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Exclude {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Exclude {
     fn default() -> Exclude {
         Exclude::Noop
@@ -26501,14 +28759,16 @@ pub struct MigrationsStartRequest {
 /**
 * The state of the package, either active or deleted.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PackagesGetAllPackageVersionsOwnedByOrgState {
     #[serde(rename = "active")]
     Active,
     #[serde(rename = "deleted")]
     Deleted,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PackagesGetAllPackageVersionsOwnedByOrgState {
@@ -26516,12 +28776,44 @@ impl std::fmt::Display for PackagesGetAllPackageVersionsOwnedByOrgState {
         match self {
             PackagesGetAllPackageVersionsOwnedByOrgState::Active => "active",
             PackagesGetAllPackageVersionsOwnedByOrgState::Deleted => "deleted",
-            PackagesGetAllPackageVersionsOwnedByOrgState::FallthroughString => "*",
+            PackagesGetAllPackageVersionsOwnedByOrgState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PackagesGetAllPackageVersionsOwnedByOrgState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => PackagesGetAllPackageVersionsOwnedByOrgState::Active,
+            "deleted" => PackagesGetAllPackageVersionsOwnedByOrgState::Deleted,
+            _ => PackagesGetAllPackageVersionsOwnedByOrgState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PackagesGetAllPackageVersionsOwnedByOrgState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PackagesGetAllPackageVersionsOwnedByOrgState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PackagesGetAllPackageVersionsOwnedByOrgState {
     fn default() -> PackagesGetAllPackageVersionsOwnedByOrgState {
         PackagesGetAllPackageVersionsOwnedByOrgState::Active
@@ -26547,7 +28839,8 @@ pub struct ProjectsCreateRequest {
 /**
 * Specifies the types of repositories you want returned. Can be one of `all`, `public`, `private`, `forks`, `sources`, `member`, `internal`. Note: For GitHub AE, can be one of `all`, `private`, `forks`, `sources`, `member`, `internal`. Default: `all`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `type` can also be `internal`. However, the `internal` value is not yet supported when a GitHub App calls this API with an installation access token.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReposListOrgType {
     #[serde(rename = "all")]
     All,
@@ -26565,8 +28858,9 @@ pub enum ReposListOrgType {
     Sources,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReposListOrgType {
@@ -26580,12 +28874,50 @@ impl std::fmt::Display for ReposListOrgType {
             ReposListOrgType::Public => "public",
             ReposListOrgType::Sources => "sources",
             ReposListOrgType::Noop => "",
-            ReposListOrgType::FallthroughString => "*",
+            ReposListOrgType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReposListOrgType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ReposListOrgType::All,
+            "forks" => ReposListOrgType::Forks,
+            "internal" => ReposListOrgType::Internal,
+            "member" => ReposListOrgType::Member,
+            "private" => ReposListOrgType::Private,
+            "public" => ReposListOrgType::Public,
+            "sources" => ReposListOrgType::Sources,
+            "" => ReposListOrgType::Noop,
+            _ => ReposListOrgType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReposListOrgType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposListOrgType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReposListOrgType {
     fn default() -> ReposListOrgType {
         ReposListOrgType::Noop
@@ -26600,7 +28932,8 @@ impl ReposListOrgType {
 /**
 * Can be one of `created`, `updated`, `pushed`, `full_name`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReposListOrgSort {
     #[serde(rename = "created")]
     Created,
@@ -26610,8 +28943,9 @@ pub enum ReposListOrgSort {
     Pushed,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReposListOrgSort {
@@ -26621,12 +28955,46 @@ impl std::fmt::Display for ReposListOrgSort {
             ReposListOrgSort::FullName => "full_name",
             ReposListOrgSort::Pushed => "pushed",
             ReposListOrgSort::Updated => "updated",
-            ReposListOrgSort::FallthroughString => "*",
+            ReposListOrgSort::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReposListOrgSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "created" => ReposListOrgSort::Created,
+            "full_name" => ReposListOrgSort::FullName,
+            "pushed" => ReposListOrgSort::Pushed,
+            "updated" => ReposListOrgSort::Updated,
+            _ => ReposListOrgSort::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReposListOrgSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposListOrgSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReposListOrgSort {
     fn default() -> ReposListOrgSort {
         ReposListOrgSort::Created
@@ -26637,7 +29005,8 @@ impl Default for ReposListOrgSort {
 * Can be `public` or `private`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `visibility` can also be `internal`. Note: For GitHub Enterprise Server and GitHub AE, this endpoint will only list repositories available to all users on the enterprise. For more information, see "[Creating an internal repository](https://help.github.com/en/github/creating-cloning-and-archiving-repositories/about-repository-visibility#about-internal-repositories)" in the GitHub Help documentation.  
 *   The `visibility` parameter overrides the `private` parameter when you use both parameters with the `nebula-preview` preview header.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReposCreateInOrgRequestVisibility {
     #[serde(rename = "internal")]
     Internal,
@@ -26649,8 +29018,9 @@ pub enum ReposCreateInOrgRequestVisibility {
     Visibility,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReposCreateInOrgRequestVisibility {
@@ -26661,12 +29031,47 @@ impl std::fmt::Display for ReposCreateInOrgRequestVisibility {
             ReposCreateInOrgRequestVisibility::Public => "public",
             ReposCreateInOrgRequestVisibility::Visibility => "visibility",
             ReposCreateInOrgRequestVisibility::Noop => "",
-            ReposCreateInOrgRequestVisibility::FallthroughString => "*",
+            ReposCreateInOrgRequestVisibility::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReposCreateInOrgRequestVisibility {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "internal" => ReposCreateInOrgRequestVisibility::Internal,
+            "private" => ReposCreateInOrgRequestVisibility::Private,
+            "public" => ReposCreateInOrgRequestVisibility::Public,
+            "visibility" => ReposCreateInOrgRequestVisibility::Visibility,
+            "" => ReposCreateInOrgRequestVisibility::Noop,
+            _ => ReposCreateInOrgRequestVisibility::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReposCreateInOrgRequestVisibility {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposCreateInOrgRequestVisibility {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReposCreateInOrgRequestVisibility {
     fn default() -> ReposCreateInOrgRequestVisibility {
         ReposCreateInOrgRequestVisibility::Noop
@@ -26752,7 +29157,8 @@ pub struct ReposCreateInOrgRequest {
 *   \* `push` - team members can pull and push, but not administer newly-added repositories.  
 *   \* `admin` - team members can pull, push and administer newly-added repositories.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Permission {
     #[serde(rename = "admin")]
     Admin,
@@ -26760,8 +29166,9 @@ pub enum Permission {
     Pull,
     #[serde(rename = "push")]
     Push,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Permission {
@@ -26770,12 +29177,45 @@ impl std::fmt::Display for Permission {
             Permission::Admin => "admin",
             Permission::Pull => "pull",
             Permission::Push => "push",
-            Permission::FallthroughString => "*",
+            Permission::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Permission {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => Permission::Admin,
+            "pull" => Permission::Pull,
+            "push" => Permission::Push,
+            _ => Permission::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Permission {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Permission {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Permission {
     fn default() -> Permission {
         Permission::Pull
@@ -26919,7 +29359,8 @@ pub struct ReactionsCreateIssueRequest {
 *   \* `maintainer` - team maintainers.  
 *   \* `all` - all members of the team.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TeamsListMembersInOrgRole {
     #[serde(rename = "all")]
     All,
@@ -26927,8 +29368,9 @@ pub enum TeamsListMembersInOrgRole {
     Maintainer,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TeamsListMembersInOrgRole {
@@ -26937,12 +29379,45 @@ impl std::fmt::Display for TeamsListMembersInOrgRole {
             TeamsListMembersInOrgRole::All => "all",
             TeamsListMembersInOrgRole::Maintainer => "maintainer",
             TeamsListMembersInOrgRole::Member => "member",
-            TeamsListMembersInOrgRole::FallthroughString => "*",
+            TeamsListMembersInOrgRole::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TeamsListMembersInOrgRole {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => TeamsListMembersInOrgRole::All,
+            "maintainer" => TeamsListMembersInOrgRole::Maintainer,
+            "member" => TeamsListMembersInOrgRole::Member,
+            _ => TeamsListMembersInOrgRole::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TeamsListMembersInOrgRole {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TeamsListMembersInOrgRole {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TeamsListMembersInOrgRole {
     fn default() -> TeamsListMembersInOrgRole {
         TeamsListMembersInOrgRole::All
@@ -26974,7 +29449,8 @@ pub struct ProjectsAddCollaboratorRequest {
 *     
 *   If no permission is specified, the team's `permission` attribute will be used to determine what permission to grant the team on this repository.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
     #[serde(rename = "admin")]
     Admin,
@@ -26988,8 +29464,9 @@ pub enum TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
     Triage,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
@@ -27001,12 +29478,48 @@ impl std::fmt::Display for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
             TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Push => "push",
             TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Triage => "triage",
             TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Noop => "",
-            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::FallthroughString => "*",
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Admin,
+            "maintain" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Maintain,
+            "pull" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Pull,
+            "push" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Push,
+            "triage" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Triage,
+            "" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Noop,
+            _ => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
     fn default() -> TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
         TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Noop
@@ -27232,7 +29745,8 @@ pub struct ProjectsUpdateColumnRequest {
 /**
 * Filters the project cards that are returned by the card's state. Can be one of `all`,`archived`, or `not_archived`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ArchivedState {
     #[serde(rename = "all")]
     All,
@@ -27240,8 +29754,9 @@ pub enum ArchivedState {
     Archived,
     #[serde(rename = "not_archived")]
     NotArchived,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ArchivedState {
@@ -27250,12 +29765,45 @@ impl std::fmt::Display for ArchivedState {
             ArchivedState::All => "all",
             ArchivedState::Archived => "archived",
             ArchivedState::NotArchived => "not_archived",
-            ArchivedState::FallthroughString => "*",
+            ArchivedState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ArchivedState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ArchivedState::All,
+            "archived" => ArchivedState::Archived,
+            "not_archived" => ArchivedState::NotArchived,
+            _ => ArchivedState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ArchivedState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ArchivedState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ArchivedState {
     fn default() -> ArchivedState {
         ArchivedState::NotArchived
@@ -27363,7 +29911,8 @@ pub struct ProjectsUpdateRequest {
 *   \* `direct`: Collaborators with permissions to a project, regardless of organization membership status.  
 *   \* `all`: All collaborators the authenticated user can see.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Affiliation {
     #[serde(rename = "all")]
     All,
@@ -27371,8 +29920,9 @@ pub enum Affiliation {
     Direct,
     #[serde(rename = "outside")]
     Outside,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Affiliation {
@@ -27381,12 +29931,45 @@ impl std::fmt::Display for Affiliation {
             Affiliation::All => "all",
             Affiliation::Direct => "direct",
             Affiliation::Outside => "outside",
-            Affiliation::FallthroughString => "*",
+            Affiliation::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Affiliation {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => Affiliation::All,
+            "direct" => Affiliation::Direct,
+            "outside" => Affiliation::Outside,
+            _ => Affiliation::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Affiliation {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Affiliation {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Affiliation {
     fn default() -> Affiliation {
         Affiliation::All
@@ -27528,14 +30111,16 @@ pub struct ActionsListWorkflowRunsResponse {
 *   \* `latest`: Returns jobs from the most recent execution of the workflow run.  
 *   \* `all`: Returns all jobs for a workflow run, including from old executions of the workflow run.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ActionsListJobsWorkflowRunFilter {
     #[serde(rename = "all")]
     All,
     #[serde(rename = "latest")]
     Latest,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ActionsListJobsWorkflowRunFilter {
@@ -27543,12 +30128,44 @@ impl std::fmt::Display for ActionsListJobsWorkflowRunFilter {
         match self {
             ActionsListJobsWorkflowRunFilter::All => "all",
             ActionsListJobsWorkflowRunFilter::Latest => "latest",
-            ActionsListJobsWorkflowRunFilter::FallthroughString => "*",
+            ActionsListJobsWorkflowRunFilter::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ActionsListJobsWorkflowRunFilter {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ActionsListJobsWorkflowRunFilter::All,
+            "latest" => ActionsListJobsWorkflowRunFilter::Latest,
+            _ => ActionsListJobsWorkflowRunFilter::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ActionsListJobsWorkflowRunFilter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ActionsListJobsWorkflowRunFilter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ActionsListJobsWorkflowRunFilter {
     fn default() -> ActionsListJobsWorkflowRunFilter {
         ActionsListJobsWorkflowRunFilter::Latest
@@ -28122,7 +30739,8 @@ pub struct ReposRenameBranchRequest {
 * **Required if you provide `completed_at` or a `status` of `completed`**. The final conclusion of the check. Can be one of `action_required`, `cancelled`, `failure`, `neutral`, `success`, `skipped`, `stale`, or `timed_out`. When the conclusion is `action_required`, additional details should be provided on the site specified by `details_url`.  
 *   **Note:** Providing `conclusion` will automatically set the `status` parameter to `completed`. You cannot change a check run conclusion to `stale`, only GitHub can set this.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ChecksCreateRequestConclusion {
     #[serde(rename = "action_required")]
     ActionRequired,
@@ -28142,8 +30760,9 @@ pub enum ChecksCreateRequestConclusion {
     TimedOut,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ChecksCreateRequestConclusion {
@@ -28158,12 +30777,51 @@ impl std::fmt::Display for ChecksCreateRequestConclusion {
             ChecksCreateRequestConclusion::Success => "success",
             ChecksCreateRequestConclusion::TimedOut => "timed_out",
             ChecksCreateRequestConclusion::Noop => "",
-            ChecksCreateRequestConclusion::FallthroughString => "*",
+            ChecksCreateRequestConclusion::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ChecksCreateRequestConclusion {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "action_required" => ChecksCreateRequestConclusion::ActionRequired,
+            "cancelled" => ChecksCreateRequestConclusion::Cancelled,
+            "failure" => ChecksCreateRequestConclusion::Failure,
+            "neutral" => ChecksCreateRequestConclusion::Neutral,
+            "skipped" => ChecksCreateRequestConclusion::Skipped,
+            "stale" => ChecksCreateRequestConclusion::Stale,
+            "success" => ChecksCreateRequestConclusion::Success,
+            "timed_out" => ChecksCreateRequestConclusion::TimedOut,
+            "" => ChecksCreateRequestConclusion::Noop,
+            _ => ChecksCreateRequestConclusion::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ChecksCreateRequestConclusion {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChecksCreateRequestConclusion {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ChecksCreateRequestConclusion {
     fn default() -> ChecksCreateRequestConclusion {
         ChecksCreateRequestConclusion::Noop
@@ -28178,7 +30836,8 @@ impl ChecksCreateRequestConclusion {
 /**
 * The level of the annotation. Can be one of `notice`, `warning`, or `failure`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AnnotationLevel {
     #[serde(rename = "failure")]
     Failure,
@@ -28188,8 +30847,9 @@ pub enum AnnotationLevel {
     Warning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AnnotationLevel {
@@ -28199,12 +30859,46 @@ impl std::fmt::Display for AnnotationLevel {
             AnnotationLevel::Notice => "notice",
             AnnotationLevel::Warning => "warning",
             AnnotationLevel::Noop => "",
-            AnnotationLevel::FallthroughString => "*",
+            AnnotationLevel::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AnnotationLevel {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "failure" => AnnotationLevel::Failure,
+            "notice" => AnnotationLevel::Notice,
+            "warning" => AnnotationLevel::Warning,
+            "" => AnnotationLevel::Noop,
+            _ => AnnotationLevel::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AnnotationLevel {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AnnotationLevel {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AnnotationLevel {
     fn default() -> AnnotationLevel {
         AnnotationLevel::Noop
@@ -28846,7 +31540,8 @@ pub struct ReposCreateDeploymentRequest {
 /**
 * Name for the target deployment environment, which can be changed when setting a deploy status. For example, `production`, `staging`, or `qa`. **Note:** This parameter requires you to use the [`application/vnd.github.flash-preview+json`](https://docs.github.com/rest/overview/api-previews#deployment-statuses) custom media type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReposCreateDeploymentStatusRequestEnvironment {
     #[serde(rename = "production")]
     Production,
@@ -28856,8 +31551,9 @@ pub enum ReposCreateDeploymentStatusRequestEnvironment {
     Staging,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReposCreateDeploymentStatusRequestEnvironment {
@@ -28867,12 +31563,46 @@ impl std::fmt::Display for ReposCreateDeploymentStatusRequestEnvironment {
             ReposCreateDeploymentStatusRequestEnvironment::Qa => "qa",
             ReposCreateDeploymentStatusRequestEnvironment::Staging => "staging",
             ReposCreateDeploymentStatusRequestEnvironment::Noop => "",
-            ReposCreateDeploymentStatusRequestEnvironment::FallthroughString => "*",
+            ReposCreateDeploymentStatusRequestEnvironment::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReposCreateDeploymentStatusRequestEnvironment {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "production" => ReposCreateDeploymentStatusRequestEnvironment::Production,
+            "qa" => ReposCreateDeploymentStatusRequestEnvironment::Qa,
+            "staging" => ReposCreateDeploymentStatusRequestEnvironment::Staging,
+            "" => ReposCreateDeploymentStatusRequestEnvironment::Noop,
+            _ => ReposCreateDeploymentStatusRequestEnvironment::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReposCreateDeploymentStatusRequestEnvironment {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposCreateDeploymentStatusRequestEnvironment {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReposCreateDeploymentStatusRequestEnvironment {
     fn default() -> ReposCreateDeploymentStatusRequestEnvironment {
         ReposCreateDeploymentStatusRequestEnvironment::Noop
@@ -28994,7 +31724,8 @@ pub struct ReposCreateUpdateEnvironmentRequest {
 /**
 * The sort order. Can be either `newest`, `oldest`, or `stargazers`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReposListForksSort {
     #[serde(rename = "newest")]
     Newest,
@@ -29004,8 +31735,9 @@ pub enum ReposListForksSort {
     Stargazers,
     #[serde(rename = "watchers")]
     Watchers,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReposListForksSort {
@@ -29015,12 +31747,46 @@ impl std::fmt::Display for ReposListForksSort {
             ReposListForksSort::Oldest => "oldest",
             ReposListForksSort::Stargazers => "stargazers",
             ReposListForksSort::Watchers => "watchers",
-            ReposListForksSort::FallthroughString => "*",
+            ReposListForksSort::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReposListForksSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "newest" => ReposListForksSort::Newest,
+            "oldest" => ReposListForksSort::Oldest,
+            "stargazers" => ReposListForksSort::Stargazers,
+            "watchers" => ReposListForksSort::Watchers,
+            _ => ReposListForksSort::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReposListForksSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposListForksSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReposListForksSort {
     fn default() -> ReposListForksSort {
         ReposListForksSort::Newest
@@ -29184,7 +31950,8 @@ pub struct GitUpdateRefRequest {
 /**
 * The type of the object we're tagging. Normally this is a `commit` but it can also be a `tree` or a `blob`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GitCreateTagRequestType {
     #[serde(rename = "blob")]
     Blob,
@@ -29194,8 +31961,9 @@ pub enum GitCreateTagRequestType {
     Tree,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GitCreateTagRequestType {
@@ -29205,12 +31973,46 @@ impl std::fmt::Display for GitCreateTagRequestType {
             GitCreateTagRequestType::Commit => "commit",
             GitCreateTagRequestType::Tree => "tree",
             GitCreateTagRequestType::Noop => "",
-            GitCreateTagRequestType::FallthroughString => "*",
+            GitCreateTagRequestType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GitCreateTagRequestType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "blob" => GitCreateTagRequestType::Blob,
+            "commit" => GitCreateTagRequestType::Commit,
+            "tree" => GitCreateTagRequestType::Tree,
+            "" => GitCreateTagRequestType::Noop,
+            _ => GitCreateTagRequestType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GitCreateTagRequestType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GitCreateTagRequestType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GitCreateTagRequestType {
     fn default() -> GitCreateTagRequestType {
         GitCreateTagRequestType::Noop
@@ -29287,7 +32089,8 @@ pub struct GitCreateTagRequest {
 /**
 * The file mode; one of `100644` for file (blob), `100755` for executable (blob), `040000` for subdirectory (tree), `160000` for submodule (commit), or `120000` for a blob that specifies the path of a symlink.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GitCreateTreeRequestMode {
     #[serde(rename = "040000")]
     SubdirectoryTree,
@@ -29301,8 +32104,9 @@ pub enum GitCreateTreeRequestMode {
     SubmoduleCommit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GitCreateTreeRequestMode {
@@ -29314,12 +32118,48 @@ impl std::fmt::Display for GitCreateTreeRequestMode {
             GitCreateTreeRequestMode::SymlinkPathBlob => "120000",
             GitCreateTreeRequestMode::SubmoduleCommit => "160000",
             GitCreateTreeRequestMode::Noop => "",
-            GitCreateTreeRequestMode::FallthroughString => "*",
+            GitCreateTreeRequestMode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GitCreateTreeRequestMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "040000" => GitCreateTreeRequestMode::SubdirectoryTree,
+            "100644" => GitCreateTreeRequestMode::FileBlob,
+            "100755" => GitCreateTreeRequestMode::ExecutableBlob,
+            "120000" => GitCreateTreeRequestMode::SymlinkPathBlob,
+            "160000" => GitCreateTreeRequestMode::SubmoduleCommit,
+            "" => GitCreateTreeRequestMode::Noop,
+            _ => GitCreateTreeRequestMode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GitCreateTreeRequestMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GitCreateTreeRequestMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GitCreateTreeRequestMode {
     fn default() -> GitCreateTreeRequestMode {
         GitCreateTreeRequestMode::Noop
@@ -29523,7 +32363,8 @@ pub struct ReposUpdateWebhookRequest {
 /**
 * The originating VCS type. Can be one of `subversion`, `git`, `mercurial`, or `tfvc`. Please be aware that without this parameter, the import job will take additional time to detect the VCS type before beginning the import. This detection step will be reflected in the response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Vcs {
     #[serde(rename = "git")]
     Git,
@@ -29535,8 +32376,9 @@ pub enum Vcs {
     Tfvc,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Vcs {
@@ -29547,12 +32389,47 @@ impl std::fmt::Display for Vcs {
             Vcs::Subversion => "subversion",
             Vcs::Tfvc => "tfvc",
             Vcs::Noop => "",
-            Vcs::FallthroughString => "*",
+            Vcs::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Vcs {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "git" => Vcs::Git,
+            "mercurial" => Vcs::Mercurial,
+            "subversion" => Vcs::Subversion,
+            "tfvc" => Vcs::Tfvc,
+            "" => Vcs::Noop,
+            _ => Vcs::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Vcs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Vcs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Vcs {
     fn default() -> Vcs {
         Vcs::Noop
@@ -29628,7 +32505,8 @@ pub struct MigrationsUpdateImportRequest {
 /**
 * Can be one of `opt_in` (large files will be stored using Git LFS) or `opt_out` (large files will be removed during the import).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UseLfs {
     #[serde(rename = "opt_in")]
     OptIn,
@@ -29636,8 +32514,9 @@ pub enum UseLfs {
     OptOut,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UseLfs {
@@ -29646,12 +32525,45 @@ impl std::fmt::Display for UseLfs {
             UseLfs::OptIn => "opt_in",
             UseLfs::OptOut => "opt_out",
             UseLfs::Noop => "",
-            UseLfs::FallthroughString => "*",
+            UseLfs::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UseLfs {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "opt_in" => UseLfs::OptIn,
+            "opt_out" => UseLfs::OptOut,
+            "" => UseLfs::Noop,
+            _ => UseLfs::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UseLfs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UseLfs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UseLfs {
     fn default() -> UseLfs {
         UseLfs::Noop
@@ -29996,7 +32908,8 @@ pub struct IssuesSetLabelsRequestAnyOf {
 *   \* `resolved`  
 *   \* `spam`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LockReason {
     #[serde(rename = "off-topic")]
     OffTopic,
@@ -30008,8 +32921,9 @@ pub enum LockReason {
     TooHeated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LockReason {
@@ -30020,12 +32934,47 @@ impl std::fmt::Display for LockReason {
             LockReason::Spam => "spam",
             LockReason::TooHeated => "too heated",
             LockReason::Noop => "",
-            LockReason::FallthroughString => "*",
+            LockReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LockReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "off-topic" => LockReason::OffTopic,
+            "resolved" => LockReason::Resolved,
+            "spam" => LockReason::Spam,
+            "too heated" => LockReason::TooHeated,
+            "" => LockReason::Noop,
+            _ => LockReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LockReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LockReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LockReason {
     fn default() -> LockReason {
         LockReason::Noop
@@ -30130,14 +33079,16 @@ pub struct ReposMergeRequest {
 /**
 * What to sort results by. Either `due_on` or `completeness`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuesListMilestonesSort {
     #[serde(rename = "completeness")]
     Completeness,
     #[serde(rename = "due_on")]
     DueOn,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuesListMilestonesSort {
@@ -30145,12 +33096,44 @@ impl std::fmt::Display for IssuesListMilestonesSort {
         match self {
             IssuesListMilestonesSort::Completeness => "completeness",
             IssuesListMilestonesSort::DueOn => "due_on",
-            IssuesListMilestonesSort::FallthroughString => "*",
+            IssuesListMilestonesSort::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuesListMilestonesSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "completeness" => IssuesListMilestonesSort::Completeness,
+            "due_on" => IssuesListMilestonesSort::DueOn,
+            _ => IssuesListMilestonesSort::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuesListMilestonesSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuesListMilestonesSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuesListMilestonesSort {
     fn default() -> IssuesListMilestonesSort {
         IssuesListMilestonesSort::DueOn
@@ -30213,14 +33196,16 @@ pub struct PullsUpdateBranchResponse {
 /**
 * The repository directory that includes the source files for the Pages site. Allowed paths are `/` or `/docs`. Default: `/`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Path {
     #[serde(rename = "/")]
     Root,
     #[serde(rename = "/docs")]
     Docs,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Path {
@@ -30228,12 +33213,44 @@ impl std::fmt::Display for Path {
         match self {
             Path::Root => "/",
             Path::Docs => "/docs",
-            Path::FallthroughString => "*",
+            Path::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Path {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "/" => Path::Root,
+            "/docs" => Path::Docs,
+            _ => Path::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Path {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Path {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Path {
     fn default() -> Path {
         Path::Root
@@ -30269,7 +33286,8 @@ pub struct ReposCreatePagesSiteRequest {
 /**
 * Update the source for the repository. Must include the branch name, and may optionally specify the subdirectory `/docs`. Possible values are `"gh-pages"`, `"master"`, and `"master /docs"`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SourceData {
     #[serde(rename = "gh-pages")]
     GhPages,
@@ -30279,8 +33297,9 @@ pub enum SourceData {
     MasterDocs,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SourceData {
@@ -30290,12 +33309,46 @@ impl std::fmt::Display for SourceData {
             SourceData::Master => "master",
             SourceData::MasterDocs => "master /docs",
             SourceData::Noop => "",
-            SourceData::FallthroughString => "*",
+            SourceData::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SourceData {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "gh-pages" => SourceData::GhPages,
+            "master" => SourceData::Master,
+            "master /docs" => SourceData::MasterDocs,
+            "" => SourceData::Noop,
+            _ => SourceData::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SourceData {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceData {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SourceData {
     fn default() -> SourceData {
         SourceData::Noop
@@ -30361,7 +33414,8 @@ pub struct ReposUpdateInformationAboutPagesSiteRequest {
 /**
 * What to sort results by. Can be either `created`, `updated`, `popularity` (comment count) or `long-running` (age, filtering by pulls updated in the last month).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PullsListSort {
     #[serde(rename = "created")]
     Created,
@@ -30371,8 +33425,9 @@ pub enum PullsListSort {
     Popularity,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PullsListSort {
@@ -30382,12 +33437,46 @@ impl std::fmt::Display for PullsListSort {
             PullsListSort::LongRunning => "long-running",
             PullsListSort::Popularity => "popularity",
             PullsListSort::Updated => "updated",
-            PullsListSort::FallthroughString => "*",
+            PullsListSort::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PullsListSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "created" => PullsListSort::Created,
+            "long-running" => PullsListSort::LongRunning,
+            "popularity" => PullsListSort::Popularity,
+            "updated" => PullsListSort::Updated,
+            _ => PullsListSort::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PullsListSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PullsListSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PullsListSort {
     fn default() -> PullsListSort {
         PullsListSort::Created
@@ -30432,7 +33521,8 @@ pub struct PullsCreateRequest {
     pub title: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PullsListReviewCommentsRepoSort {
     #[serde(rename = "created")]
     Created,
@@ -30442,8 +33532,9 @@ pub enum PullsListReviewCommentsRepoSort {
     Updated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PullsListReviewCommentsRepoSort {
@@ -30453,12 +33544,46 @@ impl std::fmt::Display for PullsListReviewCommentsRepoSort {
             PullsListReviewCommentsRepoSort::CreatedAt => "created_at",
             PullsListReviewCommentsRepoSort::Updated => "updated",
             PullsListReviewCommentsRepoSort::Noop => "",
-            PullsListReviewCommentsRepoSort::FallthroughString => "*",
+            PullsListReviewCommentsRepoSort::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PullsListReviewCommentsRepoSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "created" => PullsListReviewCommentsRepoSort::Created,
+            "created_at" => PullsListReviewCommentsRepoSort::CreatedAt,
+            "updated" => PullsListReviewCommentsRepoSort::Updated,
+            "" => PullsListReviewCommentsRepoSort::Noop,
+            _ => PullsListReviewCommentsRepoSort::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PullsListReviewCommentsRepoSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PullsListReviewCommentsRepoSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PullsListReviewCommentsRepoSort {
     fn default() -> PullsListReviewCommentsRepoSort {
         PullsListReviewCommentsRepoSort::Noop
@@ -30502,7 +33627,8 @@ pub struct PullsUpdateRequest {
 /**
 * **Required when using multi-line comments**. To create multi-line comments, you must use the `comfort-fade` preview header. The `start_side` is the starting side of the diff that the comment applies to. Can be `LEFT` or `RIGHT`. To learn more about multi-line comments, see "[Commenting on a pull request](https://help.github.com/en/articles/commenting-on-a-pull-request#adding-line-comments-to-a-pull-request)" in the GitHub Help documentation. See `side` in this table for additional context.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PullsCreateReviewCommentRequestStartSide {
     #[serde(rename = "LEFT")]
     Left,
@@ -30512,8 +33638,9 @@ pub enum PullsCreateReviewCommentRequestStartSide {
     Side,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PullsCreateReviewCommentRequestStartSide {
@@ -30523,12 +33650,46 @@ impl std::fmt::Display for PullsCreateReviewCommentRequestStartSide {
             PullsCreateReviewCommentRequestStartSide::Right => "RIGHT",
             PullsCreateReviewCommentRequestStartSide::Side => "side",
             PullsCreateReviewCommentRequestStartSide::Noop => "",
-            PullsCreateReviewCommentRequestStartSide::FallthroughString => "*",
+            PullsCreateReviewCommentRequestStartSide::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PullsCreateReviewCommentRequestStartSide {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "LEFT" => PullsCreateReviewCommentRequestStartSide::Left,
+            "RIGHT" => PullsCreateReviewCommentRequestStartSide::Right,
+            "side" => PullsCreateReviewCommentRequestStartSide::Side,
+            "" => PullsCreateReviewCommentRequestStartSide::Noop,
+            _ => PullsCreateReviewCommentRequestStartSide::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PullsCreateReviewCommentRequestStartSide {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PullsCreateReviewCommentRequestStartSide {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PullsCreateReviewCommentRequestStartSide {
     fn default() -> PullsCreateReviewCommentRequestStartSide {
         PullsCreateReviewCommentRequestStartSide::Noop
@@ -30667,7 +33828,8 @@ pub struct PullsRemoveRequestedReviewersRequest {
 /**
 * The review action you want to perform. The review actions include: `APPROVE`, `REQUEST_CHANGES`, or `COMMENT`. By leaving this blank, you set the review action state to `PENDING`, which means you will need to [submit the pull request review](https://docs.github.com/rest/reference/pulls#submit-a-review-for-a-pull-request) when you are ready.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PullsCreateReviewRequestEvent {
     #[serde(rename = "APPROVE")]
     Approve,
@@ -30677,8 +33839,9 @@ pub enum PullsCreateReviewRequestEvent {
     RequestChanges,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PullsCreateReviewRequestEvent {
@@ -30688,12 +33851,46 @@ impl std::fmt::Display for PullsCreateReviewRequestEvent {
             PullsCreateReviewRequestEvent::Comment => "COMMENT",
             PullsCreateReviewRequestEvent::RequestChanges => "REQUEST_CHANGES",
             PullsCreateReviewRequestEvent::Noop => "",
-            PullsCreateReviewRequestEvent::FallthroughString => "*",
+            PullsCreateReviewRequestEvent::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PullsCreateReviewRequestEvent {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "APPROVE" => PullsCreateReviewRequestEvent::Approve,
+            "COMMENT" => PullsCreateReviewRequestEvent::Comment,
+            "REQUEST_CHANGES" => PullsCreateReviewRequestEvent::RequestChanges,
+            "" => PullsCreateReviewRequestEvent::Noop,
+            _ => PullsCreateReviewRequestEvent::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PullsCreateReviewRequestEvent {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PullsCreateReviewRequestEvent {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PullsCreateReviewRequestEvent {
     fn default() -> PullsCreateReviewRequestEvent {
         PullsCreateReviewRequestEvent::Noop
@@ -30888,7 +34085,8 @@ pub struct ReposUpdateReleaseAssetRequest {
 /**
 * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the release.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReactionsCreateReleaseRequestContent {
     #[serde(rename = "+1")]
     PlusOne,
@@ -30904,8 +34102,9 @@ pub enum ReactionsCreateReleaseRequestContent {
     Rocket,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReactionsCreateReleaseRequestContent {
@@ -30918,12 +34117,49 @@ impl std::fmt::Display for ReactionsCreateReleaseRequestContent {
             ReactionsCreateReleaseRequestContent::Laugh => "laugh",
             ReactionsCreateReleaseRequestContent::Rocket => "rocket",
             ReactionsCreateReleaseRequestContent::Noop => "",
-            ReactionsCreateReleaseRequestContent::FallthroughString => "*",
+            ReactionsCreateReleaseRequestContent::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReactionsCreateReleaseRequestContent {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "+1" => ReactionsCreateReleaseRequestContent::PlusOne,
+            "eyes" => ReactionsCreateReleaseRequestContent::Eyes,
+            "heart" => ReactionsCreateReleaseRequestContent::Heart,
+            "hooray" => ReactionsCreateReleaseRequestContent::Hooray,
+            "laugh" => ReactionsCreateReleaseRequestContent::Laugh,
+            "rocket" => ReactionsCreateReleaseRequestContent::Rocket,
+            "" => ReactionsCreateReleaseRequestContent::Noop,
+            _ => ReactionsCreateReleaseRequestContent::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReactionsCreateReleaseRequestContent {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReactionsCreateReleaseRequestContent {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReactionsCreateReleaseRequestContent {
     fn default() -> ReactionsCreateReleaseRequestContent {
         ReactionsCreateReleaseRequestContent::Noop
@@ -30977,7 +34213,8 @@ pub struct ActivityListStargazersRepoResponseAnyOf {
 /**
 * The state of the status. Can be one of `error`, `failure`, `pending`, or `success`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReposCreateCommitStatusRequestState {
     #[serde(rename = "error")]
     Error,
@@ -30989,8 +34226,9 @@ pub enum ReposCreateCommitStatusRequestState {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReposCreateCommitStatusRequestState {
@@ -31001,12 +34239,47 @@ impl std::fmt::Display for ReposCreateCommitStatusRequestState {
             ReposCreateCommitStatusRequestState::Pending => "pending",
             ReposCreateCommitStatusRequestState::Success => "success",
             ReposCreateCommitStatusRequestState::Noop => "",
-            ReposCreateCommitStatusRequestState::FallthroughString => "*",
+            ReposCreateCommitStatusRequestState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReposCreateCommitStatusRequestState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "error" => ReposCreateCommitStatusRequestState::Error,
+            "failure" => ReposCreateCommitStatusRequestState::Failure,
+            "pending" => ReposCreateCommitStatusRequestState::Pending,
+            "success" => ReposCreateCommitStatusRequestState::Success,
+            "" => ReposCreateCommitStatusRequestState::Noop,
+            _ => ReposCreateCommitStatusRequestState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReposCreateCommitStatusRequestState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposCreateCommitStatusRequestState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReposCreateCommitStatusRequestState {
     fn default() -> ReposCreateCommitStatusRequestState {
         ReposCreateCommitStatusRequestState::Noop
@@ -31127,7 +34400,8 @@ pub struct EnterpriseAdminProvisionInviteGroupRequest {
     pub schemas: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
     #[serde(rename = "Add")]
     Add,
@@ -31137,8 +34411,9 @@ pub enum EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
     Replace,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
@@ -31148,12 +34423,48 @@ impl std::fmt::Display for EnterpriseAdminUpdateAttributeGroupRequestOperationsO
             EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Remove => "Remove",
             EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Replace => "Replace",
             EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop => "",
-            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::FallthroughString => "*",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Add" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Add,
+            "Remove" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Remove,
+            "Replace" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Replace,
+            "" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop,
+            _ => {
+                EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::UnknownValue(s.to_string())
+            }
+        })
+    }
+}
+
+impl Serialize for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
     fn default() -> EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
         EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop
@@ -31491,14 +34802,16 @@ pub struct ScimUpdateAttributeUserRequest {
 /**
 * Sorts the results of your query. Can only be `indexed`, which indicates how recently a file has been indexed by the GitHub search infrastructure. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SearchCodeSort {
     #[serde(rename = "indexed")]
     Indexed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SearchCodeSort {
@@ -31506,12 +34819,44 @@ impl std::fmt::Display for SearchCodeSort {
         match self {
             SearchCodeSort::Indexed => "indexed",
             SearchCodeSort::Noop => "",
-            SearchCodeSort::FallthroughString => "*",
+            SearchCodeSort::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SearchCodeSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "indexed" => SearchCodeSort::Indexed,
+            "" => SearchCodeSort::Noop,
+            _ => SearchCodeSort::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SearchCodeSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchCodeSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SearchCodeSort {
     fn default() -> SearchCodeSort {
         SearchCodeSort::Noop
@@ -31547,7 +34892,8 @@ pub struct SearchCodeResponse {
 /**
 * Sorts the results of your query by `author-date` or `committer-date`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SearchCommitsSort {
     #[serde(rename = "author-date")]
     AuthorDate,
@@ -31555,8 +34901,9 @@ pub enum SearchCommitsSort {
     CommitterDate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SearchCommitsSort {
@@ -31565,12 +34912,45 @@ impl std::fmt::Display for SearchCommitsSort {
             SearchCommitsSort::AuthorDate => "author-date",
             SearchCommitsSort::CommitterDate => "committer-date",
             SearchCommitsSort::Noop => "",
-            SearchCommitsSort::FallthroughString => "*",
+            SearchCommitsSort::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SearchCommitsSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "author-date" => SearchCommitsSort::AuthorDate,
+            "committer-date" => SearchCommitsSort::CommitterDate,
+            "" => SearchCommitsSort::Noop,
+            _ => SearchCommitsSort::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SearchCommitsSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchCommitsSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SearchCommitsSort {
     fn default() -> SearchCommitsSort {
         SearchCommitsSort::Noop
@@ -31606,7 +34986,8 @@ pub struct SearchCommitsResponse {
 /**
 * Sorts the results of your query by the number of `comments`, `reactions`, `reactions-+1`, `reactions--1`, `reactions-smile`, `reactions-thinking_face`, `reactions-heart`, `reactions-tada`, or `interactions`. You can also sort results by how recently the items were `created` or `updated`, Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SearchIssuesPullRequestsSort {
     #[serde(rename = "comments")]
     Comments,
@@ -31632,8 +35013,9 @@ pub enum SearchIssuesPullRequestsSort {
     Updated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SearchIssuesPullRequestsSort {
@@ -31651,12 +35033,54 @@ impl std::fmt::Display for SearchIssuesPullRequestsSort {
             SearchIssuesPullRequestsSort::ReactionsThinkingFace => "reactions-thinking_face",
             SearchIssuesPullRequestsSort::Updated => "updated",
             SearchIssuesPullRequestsSort::Noop => "",
-            SearchIssuesPullRequestsSort::FallthroughString => "*",
+            SearchIssuesPullRequestsSort::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SearchIssuesPullRequestsSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "comments" => SearchIssuesPullRequestsSort::Comments,
+            "created" => SearchIssuesPullRequestsSort::Created,
+            "interactions" => SearchIssuesPullRequestsSort::Interactions,
+            "reactions" => SearchIssuesPullRequestsSort::Reactions,
+            "reactions-+1" => SearchIssuesPullRequestsSort::ReactionsPlusOne,
+            "reactions--1" => SearchIssuesPullRequestsSort::ReactionsMinusOne,
+            "reactions-heart" => SearchIssuesPullRequestsSort::ReactionsHeart,
+            "reactions-smile" => SearchIssuesPullRequestsSort::ReactionsSmile,
+            "reactions-tada" => SearchIssuesPullRequestsSort::ReactionsTada,
+            "reactions-thinking_face" => SearchIssuesPullRequestsSort::ReactionsThinkingFace,
+            "updated" => SearchIssuesPullRequestsSort::Updated,
+            "" => SearchIssuesPullRequestsSort::Noop,
+            _ => SearchIssuesPullRequestsSort::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SearchIssuesPullRequestsSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchIssuesPullRequestsSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SearchIssuesPullRequestsSort {
     fn default() -> SearchIssuesPullRequestsSort {
         SearchIssuesPullRequestsSort::Noop
@@ -31713,7 +35137,8 @@ pub struct SearchLabelsResponse {
 /**
 * Sorts the results of your query by number of `stars`, `forks`, or `help-wanted-issues` or how recently the items were `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SearchReposSort {
     #[serde(rename = "forks")]
     Forks,
@@ -31725,8 +35150,9 @@ pub enum SearchReposSort {
     Updated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SearchReposSort {
@@ -31737,12 +35163,47 @@ impl std::fmt::Display for SearchReposSort {
             SearchReposSort::Stars => "stars",
             SearchReposSort::Updated => "updated",
             SearchReposSort::Noop => "",
-            SearchReposSort::FallthroughString => "*",
+            SearchReposSort::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SearchReposSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "forks" => SearchReposSort::Forks,
+            "help-wanted-issues" => SearchReposSort::HelpWantedIssues,
+            "stars" => SearchReposSort::Stars,
+            "updated" => SearchReposSort::Updated,
+            "" => SearchReposSort::Noop,
+            _ => SearchReposSort::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SearchReposSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchReposSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SearchReposSort {
     fn default() -> SearchReposSort {
         SearchReposSort::Noop
@@ -31799,7 +35260,8 @@ pub struct SearchTopicsResponse {
 /**
 * Sorts the results of your query by number of `followers` or `repositories`, or when the person `joined` GitHub. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SearchUsersSort {
     #[serde(rename = "followers")]
     Followers,
@@ -31809,8 +35271,9 @@ pub enum SearchUsersSort {
     Repositories,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SearchUsersSort {
@@ -31820,12 +35283,46 @@ impl std::fmt::Display for SearchUsersSort {
             SearchUsersSort::Joined => "joined",
             SearchUsersSort::Repositories => "repositories",
             SearchUsersSort::Noop => "",
-            SearchUsersSort::FallthroughString => "*",
+            SearchUsersSort::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SearchUsersSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "followers" => SearchUsersSort::Followers,
+            "joined" => SearchUsersSort::Joined,
+            "repositories" => SearchUsersSort::Repositories,
+            "" => SearchUsersSort::Noop,
+            _ => SearchUsersSort::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SearchUsersSort {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchUsersSort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SearchUsersSort {
     fn default() -> SearchUsersSort {
         SearchUsersSort::Noop
@@ -32140,14 +35637,16 @@ pub struct UsersCreatePublicSshKeyAuthenticatedRequest {
 /**
 * The state that the membership should be in. Only `"active"` will be accepted.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrgsUpdateMembershipRequestState {
     #[serde(rename = "active")]
     Active,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrgsUpdateMembershipRequestState {
@@ -32155,12 +35654,44 @@ impl std::fmt::Display for OrgsUpdateMembershipRequestState {
         match self {
             OrgsUpdateMembershipRequestState::Active => "active",
             OrgsUpdateMembershipRequestState::Noop => "",
-            OrgsUpdateMembershipRequestState::FallthroughString => "*",
+            OrgsUpdateMembershipRequestState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrgsUpdateMembershipRequestState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => OrgsUpdateMembershipRequestState::Active,
+            "" => OrgsUpdateMembershipRequestState::Noop,
+            _ => OrgsUpdateMembershipRequestState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrgsUpdateMembershipRequestState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrgsUpdateMembershipRequestState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrgsUpdateMembershipRequestState {
     fn default() -> OrgsUpdateMembershipRequestState {
         OrgsUpdateMembershipRequestState::Noop
@@ -32187,7 +35718,8 @@ pub struct OrgsUpdateMembershipRequest {
 /**
 * Can be one of `all`, `public`, or `private`. Note: For GitHub AE, can be one of `all`, `internal`, or `private`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReposListVisibility {
     #[serde(rename = "all")]
     All,
@@ -32195,8 +35727,9 @@ pub enum ReposListVisibility {
     Private,
     #[serde(rename = "public")]
     Public,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReposListVisibility {
@@ -32205,12 +35738,45 @@ impl std::fmt::Display for ReposListVisibility {
             ReposListVisibility::All => "all",
             ReposListVisibility::Private => "private",
             ReposListVisibility::Public => "public",
-            ReposListVisibility::FallthroughString => "*",
+            ReposListVisibility::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReposListVisibility {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ReposListVisibility::All,
+            "private" => ReposListVisibility::Private,
+            "public" => ReposListVisibility::Public,
+            _ => ReposListVisibility::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReposListVisibility {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposListVisibility {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReposListVisibility {
     fn default() -> ReposListVisibility {
         ReposListVisibility::All
@@ -32222,7 +35788,8 @@ impl Default for ReposListVisibility {
 *     
 *   Will cause a `422` error if used in the same request as **visibility** or **affiliation**. Will cause a `422` error if used in the same request as **visibility** or **affiliation**.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReposListType {
     #[serde(rename = "all")]
     All,
@@ -32234,8 +35801,9 @@ pub enum ReposListType {
     Private,
     #[serde(rename = "public")]
     Public,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReposListType {
@@ -32246,12 +35814,47 @@ impl std::fmt::Display for ReposListType {
             ReposListType::Owner => "owner",
             ReposListType::Private => "private",
             ReposListType::Public => "public",
-            ReposListType::FallthroughString => "*",
+            ReposListType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReposListType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ReposListType::All,
+            "member" => ReposListType::Member,
+            "owner" => ReposListType::Owner,
+            "private" => ReposListType::Private,
+            "public" => ReposListType::Public,
+            _ => ReposListType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReposListType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposListType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReposListType {
     fn default() -> ReposListType {
         ReposListType::All
@@ -32325,7 +35928,8 @@ pub struct ReposCreateRequest {
 /**
 * Identifies which additional information you'd like to receive about the person's hovercard. Can be `organization`, `repository`, `issue`, `pull_request`. **Required** when using `subject_id`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SubjectType {
     #[serde(rename = "issue")]
     Issue,
@@ -32337,8 +35941,9 @@ pub enum SubjectType {
     Repository,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SubjectType {
@@ -32349,12 +35954,47 @@ impl std::fmt::Display for SubjectType {
             SubjectType::PullRequest => "pull_request",
             SubjectType::Repository => "repository",
             SubjectType::Noop => "",
-            SubjectType::FallthroughString => "*",
+            SubjectType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SubjectType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "issue" => SubjectType::Issue,
+            "organization" => SubjectType::Organization,
+            "pull_request" => SubjectType::PullRequest,
+            "repository" => SubjectType::Repository,
+            "" => SubjectType::Noop,
+            _ => SubjectType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SubjectType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SubjectType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SubjectType {
     fn default() -> SubjectType {
         SubjectType::Noop
@@ -32369,7 +36009,8 @@ impl SubjectType {
 /**
 * Can be one of `all`, `owner`, `member`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReposListUserType {
     #[serde(rename = "all")]
     All,
@@ -32377,8 +36018,9 @@ pub enum ReposListUserType {
     Member,
     #[serde(rename = "owner")]
     Owner,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReposListUserType {
@@ -32387,12 +36029,45 @@ impl std::fmt::Display for ReposListUserType {
             ReposListUserType::All => "all",
             ReposListUserType::Member => "member",
             ReposListUserType::Owner => "owner",
-            ReposListUserType::FallthroughString => "*",
+            ReposListUserType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReposListUserType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ReposListUserType::All,
+            "member" => ReposListUserType::Member,
+            "owner" => ReposListUserType::Owner,
+            _ => ReposListUserType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReposListUserType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReposListUserType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReposListUserType {
     fn default() -> ReposListUserType {
         ReposListUserType::Owner
@@ -0,0 +1,33 @@
+//! For verifying the signatures GitHub sends with webhook deliveries.
+use anyhow::{anyhow, Result};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// The header GitHub sends the HMAC-SHA256 signature of a delivery in.
+pub const SIGNATURE_HEADER: &str = "x-hub-signature-256";
+
+/// Verify the `X-Hub-Signature-256` header of a webhook delivery.
+///
+/// `signature` is the header value, for example `sha256=757107ea...`, and
+/// `body` is the raw request body, before any parsing. The comparison is
+/// constant-time.
+pub fn verify(secret: &[u8], body: &[u8], signature: &str) -> Result<()> {
+    let digest = signature
+        .strip_prefix("sha256=")
+        .ok_or_else(|| anyhow!("webhook signature is missing the `sha256=` prefix"))?;
+    let expected = hex::decode(digest)?;
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    mac.verify_slice(&expected)
+        .map_err(|_| anyhow!("webhook signature does not match"))
+}
+
+/// Verify a webhook delivery using the signature in its headers.
+pub fn verify_headers(secret: &[u8], body: &[u8], headers: &http::HeaderMap) -> Result<()> {
+    let signature = headers
+        .get(SIGNATURE_HEADER)
+        .ok_or_else(|| anyhow!("missing `{}` header", SIGNATURE_HEADER))?
+        .to_str()?;
+    verify(secret, body, signature)
+}
@@ -185,7 +185,8 @@ pub struct Asps {
 /**
 * Message severity
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Severity {
     #[serde(rename = "SEVERITY_ERROR")]
     SeverityError,
@@ -197,8 +198,9 @@ pub enum Severity {
     SeverityWarning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Severity {
@@ -209,12 +211,47 @@ impl std::fmt::Display for Severity {
             Severity::SeverityUnspecified => "SEVERITY_UNSPECIFIED",
             Severity::SeverityWarning => "SEVERITY_WARNING",
             Severity::Noop => "",
-            Severity::FallthroughString => "*",
+            Severity::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Severity {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SEVERITY_ERROR" => Severity::SeverityError,
+            "SEVERITY_INFO" => Severity::SeverityInfo,
+            "SEVERITY_UNSPECIFIED" => Severity::SeverityUnspecified,
+            "SEVERITY_WARNING" => Severity::SeverityWarning,
+            "" => Severity::Noop,
+            _ => Severity::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Severity {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Severity {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Severity {
     fn default() -> Severity {
         Severity::Noop
@@ -1697,7 +1734,8 @@ pub struct CustomerPostalAddress {
 /**
 * Indicates the command state.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum State {
     #[serde(rename = "ACKED_BY_CLIENT")]
     AckedByClient,
@@ -1715,8 +1753,9 @@ pub enum State {
     StateUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for State {
@@ -1730,12 +1769,50 @@ impl std::fmt::Display for State {
             State::SentToClient => "SENT_TO_CLIENT",
             State::StateUnspecified => "STATE_UNSPECIFIED",
             State::Noop => "",
-            State::FallthroughString => "*",
+            State::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for State {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACKED_BY_CLIENT" => State::AckedByClient,
+            "CANCELLED" => State::Cancelled,
+            "EXECUTED_BY_CLIENT" => State::ExecutedByClient,
+            "EXPIRED" => State::Expired,
+            "PENDING" => State::Pending,
+            "SENT_TO_CLIENT" => State::SentToClient,
+            "STATE_UNSPECIFIED" => State::StateUnspecified,
+            "" => State::Noop,
+            _ => State::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for State {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for State {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for State {
     fn default() -> State {
         State::Noop
@@ -1750,7 +1827,8 @@ impl State {
 /**
 * The type of the command.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Type {
     #[serde(rename = "COMMAND_TYPE_UNSPECIFIED")]
     CommandTypeUnspecified,
@@ -1766,8 +1844,9 @@ pub enum Type {
     WipeUsers,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Type {
@@ -1780,12 +1859,49 @@ impl std::fmt::Display for Type {
             Type::TakeAScreenshot => "TAKE_A_SCREENSHOT",
             Type::WipeUsers => "WIPE_USERS",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COMMAND_TYPE_UNSPECIFIED" => Type::CommandTypeUnspecified,
+            "REBOOT" => Type::Reboot,
+            "REMOTE_POWERWASH" => Type::RemotePowerwash,
+            "SET_VOLUME" => Type::SetVolume,
+            "TAKE_A_SCREENSHOT" => Type::TakeAScreenshot,
+            "WIPE_USERS" => Type::WipeUsers,
+            "" => Type::Noop,
+            _ => Type::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -1863,7 +1979,8 @@ pub struct DirectoryChromeosdevicesCommand {
 /**
 * The result of the command.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Result {
     #[serde(rename = "COMMAND_RESULT_TYPE_UNSPECIFIED")]
     CommandResultTypeUnspecified,
@@ -1875,8 +1992,9 @@ pub enum Result {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Result {
@@ -1887,12 +2005,47 @@ impl std::fmt::Display for Result {
             Result::Ignored => "IGNORED",
             Result::Success => "SUCCESS",
             Result::Noop => "",
-            Result::FallthroughString => "*",
+            Result::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Result {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COMMAND_RESULT_TYPE_UNSPECIFIED" => Result::CommandResultTypeUnspecified,
+            "FAILURE" => Result::Failure,
+            "IGNORED" => Result::Ignored,
+            "SUCCESS" => Result::Success,
+            "" => Result::Noop,
+            _ => Result::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Result {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Result {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Result {
     fn default() -> Result {
         Result::Noop
@@ -2138,7 +2291,8 @@ pub struct Empty {}
 /**
 * Canonical code for why the update failed to apply.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ErrorCode {
     #[serde(rename = "ABORTED")]
     Aborted,
@@ -2176,8 +2330,9 @@ pub enum ErrorCode {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -2201,12 +2356,60 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::Unimplemented => "UNIMPLEMENTED",
             ErrorCode::Unknown => "UNKNOWN",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ErrorCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ABORTED" => ErrorCode::Aborted,
+            "ALREADY_EXISTS" => ErrorCode::AlreadyExists,
+            "CANCELLED" => ErrorCode::Cancelled,
+            "DATA_LOSS" => ErrorCode::DataLoss,
+            "DEADLINE_EXCEEDED" => ErrorCode::DeadlineExceeded,
+            "FAILED_PRECONDITION" => ErrorCode::FailedPrecondition,
+            "INTERNAL" => ErrorCode::Internal,
+            "INVALID_ARGUMENT" => ErrorCode::InvalidArgument,
+            "NOT_FOUND" => ErrorCode::NotFound,
+            "OK" => ErrorCode::Ok,
+            "OUT_OF_RANGE" => ErrorCode::OutOfRange,
+            "PERMISSION_DENIED" => ErrorCode::PermissionDenied,
+            "RESOURCE_EXHAUSTED" => ErrorCode::ResourceExhausted,
+            "UNAUTHENTICATED" => ErrorCode::Unauthenticated,
+            "UNAVAILABLE" => ErrorCode::Unavailable,
+            "UNIMPLEMENTED" => ErrorCode::Unimplemented,
+            "UNKNOWN" => ErrorCode::Unknown,
+            "" => ErrorCode::Noop,
+            _ => ErrorCode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ErrorCode {
     fn default() -> ErrorCode {
         ErrorCode::Noop
@@ -5470,7 +5673,8 @@ pub struct VerificationCodes {
 /**
 * V1 error format.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Xgafv {
     #[serde(rename = "1")]
     One,
@@ -5478,8 +5682,9 @@ pub enum Xgafv {
     Two,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -5488,12 +5693,45 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Xgafv {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "1" => Xgafv::One,
+            "2" => Xgafv::Two,
+            "" => Xgafv::Noop,
+            _ => Xgafv::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Xgafv {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Xgafv {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Xgafv {
     fn default() -> Xgafv {
         Xgafv::Noop
@@ -5508,7 +5746,8 @@ impl Xgafv {
 /**
 * Data format for response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -5518,8 +5757,9 @@ pub enum Alt {
     Proto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -5529,12 +5769,46 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Alt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "json" => Alt::Json,
+            "media" => Alt::Media,
+            "proto" => Alt::Proto,
+            "" => Alt::Noop,
+            _ => Alt::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Alt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Alt {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
@@ -5549,7 +5823,8 @@ impl Alt {
 /**
 * Device property to use for sorting results.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrderBy {
     #[serde(rename = "annotatedLocation")]
     AnnotatedLocation,
@@ -5567,8 +5842,9 @@ pub enum OrderBy {
     SupportEndDate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrderBy {
@@ -5582,12 +5858,50 @@ impl std::fmt::Display for OrderBy {
             OrderBy::Status => "status",
             OrderBy::SupportEndDate => "supportEndDate",
             OrderBy::Noop => "",
-            OrderBy::FallthroughString => "*",
+            OrderBy::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrderBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "annotatedLocation" => OrderBy::AnnotatedLocation,
+            "annotatedUser" => OrderBy::AnnotatedUser,
+            "lastSync" => OrderBy::LastSync,
+            "notes" => OrderBy::Notes,
+            "serialNumber" => OrderBy::SerialNumber,
+            "status" => OrderBy::Status,
+            "supportEndDate" => OrderBy::SupportEndDate,
+            "" => OrderBy::Noop,
+            _ => OrderBy::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrderBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrderBy {
     fn default() -> OrderBy {
         OrderBy::Noop
@@ -5602,7 +5916,8 @@ impl OrderBy {
 /**
 * Restrict information returned to a set of selected fields.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Projection {
     #[serde(rename = "BASIC")]
     Basic,
@@ -5610,8 +5925,9 @@ pub enum Projection {
     Full,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Projection {
@@ -5620,12 +5936,45 @@ impl std::fmt::Display for Projection {
             Projection::Basic => "BASIC",
             Projection::Full => "FULL",
             Projection::Noop => "",
-            Projection::FallthroughString => "*",
+            Projection::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Projection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BASIC" => Projection::Basic,
+            "FULL" => Projection::Full,
+            "" => Projection::Noop,
+            _ => Projection::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Projection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Projection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Projection {
     fn default() -> Projection {
         Projection::Noop
@@ -5640,7 +5989,8 @@ impl Projection {
 /**
 * Whether to return results in ascending or descending order. Must be used with the `orderBy` parameter.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SortOrder {
     #[serde(rename = "ASCENDING")]
     Ascending,
@@ -5648,8 +5998,9 @@ pub enum SortOrder {
     Descending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -5658,12 +6009,45 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Ascending => "ASCENDING",
             SortOrder::Descending => "DESCENDING",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SortOrder {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ASCENDING" => SortOrder::Ascending,
+            "DESCENDING" => SortOrder::Descending,
+            "" => SortOrder::Noop,
+            _ => SortOrder::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SortOrder {
     fn default() -> SortOrder {
         SortOrder::Noop
@@ -5678,7 +6062,8 @@ impl SortOrder {
 /**
 * Device property to use for sorting results.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DirectoryMobiledevicesListOrderBy {
     #[serde(rename = "deviceId")]
     DeviceId,
@@ -5698,8 +6083,9 @@ pub enum DirectoryMobiledevicesListOrderBy {
     Type,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DirectoryMobiledevicesListOrderBy {
@@ -5714,12 +6100,51 @@ impl std::fmt::Display for DirectoryMobiledevicesListOrderBy {
             DirectoryMobiledevicesListOrderBy::Status => "status",
             DirectoryMobiledevicesListOrderBy::Type => "type",
             DirectoryMobiledevicesListOrderBy::Noop => "",
-            DirectoryMobiledevicesListOrderBy::FallthroughString => "*",
+            DirectoryMobiledevicesListOrderBy::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DirectoryMobiledevicesListOrderBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "deviceId" => DirectoryMobiledevicesListOrderBy::DeviceId,
+            "email" => DirectoryMobiledevicesListOrderBy::Email,
+            "lastSync" => DirectoryMobiledevicesListOrderBy::LastSync,
+            "model" => DirectoryMobiledevicesListOrderBy::Model,
+            "name" => DirectoryMobiledevicesListOrderBy::Name,
+            "os" => DirectoryMobiledevicesListOrderBy::Os,
+            "status" => DirectoryMobiledevicesListOrderBy::Status,
+            "type" => DirectoryMobiledevicesListOrderBy::Type,
+            "" => DirectoryMobiledevicesListOrderBy::Noop,
+            _ => DirectoryMobiledevicesListOrderBy::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DirectoryMobiledevicesListOrderBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DirectoryMobiledevicesListOrderBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DirectoryMobiledevicesListOrderBy {
     fn default() -> DirectoryMobiledevicesListOrderBy {
         DirectoryMobiledevicesListOrderBy::Noop
@@ -5734,7 +6159,8 @@ impl DirectoryMobiledevicesListOrderBy {
 /**
 * Whether to return all sub-organizations or just immediate children.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DirectoryOrgunitsListType {
     #[serde(rename = "all")]
     All,
@@ -5742,8 +6168,9 @@ pub enum DirectoryOrgunitsListType {
     Children,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DirectoryOrgunitsListType {
@@ -5752,12 +6179,45 @@ impl std::fmt::Display for DirectoryOrgunitsListType {
             DirectoryOrgunitsListType::All => "all",
             DirectoryOrgunitsListType::Children => "children",
             DirectoryOrgunitsListType::Noop => "",
-            DirectoryOrgunitsListType::FallthroughString => "*",
+            DirectoryOrgunitsListType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DirectoryOrgunitsListType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => DirectoryOrgunitsListType::All,
+            "children" => DirectoryOrgunitsListType::Children,
+            "" => DirectoryOrgunitsListType::Noop,
+            _ => DirectoryOrgunitsListType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DirectoryOrgunitsListType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DirectoryOrgunitsListType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DirectoryOrgunitsListType {
     fn default() -> DirectoryOrgunitsListType {
         DirectoryOrgunitsListType::Noop
@@ -5772,7 +6232,8 @@ impl DirectoryOrgunitsListType {
 /**
 * Source from which Building.coordinates are derived.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CoordinatesSource {
     #[serde(rename = "CLIENT_SPECIFIED")]
     ClientSpecified,
@@ -5782,8 +6243,9 @@ pub enum CoordinatesSource {
     SourceUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CoordinatesSource {
@@ -5793,12 +6255,46 @@ impl std::fmt::Display for CoordinatesSource {
             CoordinatesSource::ResolvedFromAddress => "RESOLVED_FROM_ADDRESS",
             CoordinatesSource::SourceUnspecified => "SOURCE_UNSPECIFIED",
             CoordinatesSource::Noop => "",
-            CoordinatesSource::FallthroughString => "*",
+            CoordinatesSource::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CoordinatesSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CLIENT_SPECIFIED" => CoordinatesSource::ClientSpecified,
+            "RESOLVED_FROM_ADDRESS" => CoordinatesSource::ResolvedFromAddress,
+            "SOURCE_UNSPECIFIED" => CoordinatesSource::SourceUnspecified,
+            "" => CoordinatesSource::Noop,
+            _ => CoordinatesSource::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CoordinatesSource {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CoordinatesSource {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CoordinatesSource {
     fn default() -> CoordinatesSource {
         CoordinatesSource::Noop
@@ -5813,14 +6309,16 @@ impl CoordinatesSource {
 /**
 * Column to use for sorting results
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DirectoryGroupsListOrderBy {
     #[serde(rename = "email")]
     Email,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DirectoryGroupsListOrderBy {
@@ -5828,12 +6326,44 @@ impl std::fmt::Display for DirectoryGroupsListOrderBy {
         match self {
             DirectoryGroupsListOrderBy::Email => "email",
             DirectoryGroupsListOrderBy::Noop => "",
-            DirectoryGroupsListOrderBy::FallthroughString => "*",
+            DirectoryGroupsListOrderBy::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DirectoryGroupsListOrderBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "email" => DirectoryGroupsListOrderBy::Email,
+            "" => DirectoryGroupsListOrderBy::Noop,
+            _ => DirectoryGroupsListOrderBy::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DirectoryGroupsListOrderBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DirectoryGroupsListOrderBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DirectoryGroupsListOrderBy {
     fn default() -> DirectoryGroupsListOrderBy {
         DirectoryGroupsListOrderBy::Noop
@@ -5848,7 +6378,8 @@ impl DirectoryGroupsListOrderBy {
 /**
 * Event on which subscription is intended (if subscribing)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Event {
     #[serde(rename = "add")]
     Add,
@@ -5862,8 +6393,9 @@ pub enum Event {
     Update,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Event {
@@ -5875,12 +6407,48 @@ impl std::fmt::Display for Event {
             Event::Undelete => "undelete",
             Event::Update => "update",
             Event::Noop => "",
-            Event::FallthroughString => "*",
+            Event::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Event {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "add" => Event::Add,
+            "delete" => Event::Delete,
+            "makeAdmin" => Event::MakeAdmin,
+            "undelete" => Event::Undelete,
+            "update" => Event::Update,
+            "" => Event::Noop,
+            _ => Event::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Event {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Event {
     fn default() -> Event {
         Event::Noop
@@ -5895,7 +6463,8 @@ impl Event {
 /**
 * Property to use for sorting results.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DirectoryUsersListOrderBy {
     #[serde(rename = "email")]
     Email,
@@ -5905,8 +6474,9 @@ pub enum DirectoryUsersListOrderBy {
     GivenName,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DirectoryUsersListOrderBy {
@@ -5916,12 +6486,46 @@ impl std::fmt::Display for DirectoryUsersListOrderBy {
             DirectoryUsersListOrderBy::FamilyName => "familyName",
             DirectoryUsersListOrderBy::GivenName => "givenName",
             DirectoryUsersListOrderBy::Noop => "",
-            DirectoryUsersListOrderBy::FallthroughString => "*",
+            DirectoryUsersListOrderBy::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DirectoryUsersListOrderBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "email" => DirectoryUsersListOrderBy::Email,
+            "familyName" => DirectoryUsersListOrderBy::FamilyName,
+            "givenName" => DirectoryUsersListOrderBy::GivenName,
+            "" => DirectoryUsersListOrderBy::Noop,
+            _ => DirectoryUsersListOrderBy::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DirectoryUsersListOrderBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DirectoryUsersListOrderBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DirectoryUsersListOrderBy {
     fn default() -> DirectoryUsersListOrderBy {
         DirectoryUsersListOrderBy::Noop
@@ -5936,7 +6540,8 @@ impl DirectoryUsersListOrderBy {
 /**
 * What subset of fields to fetch for this user.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DirectoryUsersListProjection {
     #[serde(rename = "basic")]
     Basic,
@@ -5946,8 +6551,9 @@ pub enum DirectoryUsersListProjection {
     Full,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DirectoryUsersListProjection {
@@ -5957,12 +6563,46 @@ impl std::fmt::Display for DirectoryUsersListProjection {
             DirectoryUsersListProjection::Custom => "custom",
             DirectoryUsersListProjection::Full => "full",
             DirectoryUsersListProjection::Noop => "",
-            DirectoryUsersListProjection::FallthroughString => "*",
+            DirectoryUsersListProjection::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DirectoryUsersListProjection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "basic" => DirectoryUsersListProjection::Basic,
+            "custom" => DirectoryUsersListProjection::Custom,
+            "full" => DirectoryUsersListProjection::Full,
+            "" => DirectoryUsersListProjection::Noop,
+            _ => DirectoryUsersListProjection::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DirectoryUsersListProjection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DirectoryUsersListProjection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DirectoryUsersListProjection {
     fn default() -> DirectoryUsersListProjection {
         DirectoryUsersListProjection::Noop
@@ -5977,7 +6617,8 @@ impl DirectoryUsersListProjection {
 /**
 * Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ViewType {
     #[serde(rename = "admin_view")]
     AdminView,
@@ -5985,8 +6626,9 @@ pub enum ViewType {
     DomainPublic,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ViewType {
@@ -5995,12 +6637,45 @@ impl std::fmt::Display for ViewType {
             ViewType::AdminView => "admin_view",
             ViewType::DomainPublic => "domain_public",
             ViewType::Noop => "",
-            ViewType::FallthroughString => "*",
+            ViewType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ViewType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin_view" => ViewType::AdminView,
+            "domain_public" => ViewType::DomainPublic,
+            "" => ViewType::Noop,
+            _ => ViewType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ViewType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ViewType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ViewType {
     fn default() -> ViewType {
         ViewType::Noop
@@ -6015,7 +6690,8 @@ impl ViewType {
 /**
 * Events to watch for.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DirectoryUsersAliasesListEvent {
     #[serde(rename = "add")]
     Add,
@@ -6023,8 +6699,9 @@ pub enum DirectoryUsersAliasesListEvent {
     Delete,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DirectoryUsersAliasesListEvent {
@@ -6033,12 +6710,45 @@ impl std::fmt::Display for DirectoryUsersAliasesListEvent {
             DirectoryUsersAliasesListEvent::Add => "add",
             DirectoryUsersAliasesListEvent::Delete => "delete",
             DirectoryUsersAliasesListEvent::Noop => "",
-            DirectoryUsersAliasesListEvent::FallthroughString => "*",
+            DirectoryUsersAliasesListEvent::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DirectoryUsersAliasesListEvent {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "add" => DirectoryUsersAliasesListEvent::Add,
+            "delete" => DirectoryUsersAliasesListEvent::Delete,
+            "" => DirectoryUsersAliasesListEvent::Noop,
+            _ => DirectoryUsersAliasesListEvent::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DirectoryUsersAliasesListEvent {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DirectoryUsersAliasesListEvent {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DirectoryUsersAliasesListEvent {
     fn default() -> DirectoryUsersAliasesListEvent {
         DirectoryUsersAliasesListEvent::Noop
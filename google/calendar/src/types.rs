@@ -2062,14 +2062,16 @@ pub struct TimePeriod {
 /**
 * Data format for the response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -2077,12 +2079,44 @@ impl std::fmt::Display for Alt {
         match self {
             Alt::Json => "json",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Alt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "json" => Alt::Json,
+            "" => Alt::Noop,
+            _ => Alt::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Alt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Alt {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
@@ -2097,7 +2131,8 @@ impl Alt {
 /**
 * The order of the events returned in the result. Optional. The default is an unspecified, stable order.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrderBy {
     #[serde(rename = "startTime")]
     StartTime,
@@ -2105,8 +2140,9 @@ pub enum OrderBy {
     Updated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrderBy {
@@ -2115,12 +2151,45 @@ impl std::fmt::Display for OrderBy {
             OrderBy::StartTime => "startTime",
             OrderBy::Updated => "updated",
             OrderBy::Noop => "",
-            OrderBy::FallthroughString => "*",
+            OrderBy::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrderBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "startTime" => OrderBy::StartTime,
+            "updated" => OrderBy::Updated,
+            "" => OrderBy::Noop,
+            _ => OrderBy::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrderBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrderBy {
     fn default() -> OrderBy {
         OrderBy::Noop
@@ -2135,7 +2204,8 @@ impl OrderBy {
 /**
 * Whether to send notifications about the creation of the new event. Note that some emails might still be sent. The default is false.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SendUpdates {
     #[serde(rename = "all")]
     All,
@@ -2145,8 +2215,9 @@ pub enum SendUpdates {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SendUpdates {
@@ -2156,12 +2227,46 @@ impl std::fmt::Display for SendUpdates {
             SendUpdates::ExternalOnly => "externalOnly",
             SendUpdates::None => "none",
             SendUpdates::Noop => "",
-            SendUpdates::FallthroughString => "*",
+            SendUpdates::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SendUpdates {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => SendUpdates::All,
+            "externalOnly" => SendUpdates::ExternalOnly,
+            "none" => SendUpdates::None,
+            "" => SendUpdates::Noop,
+            _ => SendUpdates::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SendUpdates {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SendUpdates {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SendUpdates {
     fn default() -> SendUpdates {
         SendUpdates::Noop
@@ -2176,7 +2281,8 @@ impl SendUpdates {
 /**
 * The minimum access role for the user in the returned entries. Optional. The default is no restriction.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MinAccessRole {
     #[serde(rename = "freeBusyReader")]
     FreeBusyReader,
@@ -2188,8 +2294,9 @@ pub enum MinAccessRole {
     Writer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MinAccessRole {
@@ -2200,12 +2307,47 @@ impl std::fmt::Display for MinAccessRole {
             MinAccessRole::Reader => "reader",
             MinAccessRole::Writer => "writer",
             MinAccessRole::Noop => "",
-            MinAccessRole::FallthroughString => "*",
+            MinAccessRole::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MinAccessRole {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "freeBusyReader" => MinAccessRole::FreeBusyReader,
+            "owner" => MinAccessRole::Owner,
+            "reader" => MinAccessRole::Reader,
+            "writer" => MinAccessRole::Writer,
+            "" => MinAccessRole::Noop,
+            _ => MinAccessRole::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MinAccessRole {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MinAccessRole {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MinAccessRole {
     fn default() -> MinAccessRole {
         MinAccessRole::Noop
@@ -29,7 +29,8 @@ pub struct AuditConfig {
 /**
 * The log type that this config enables.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LogType {
     #[serde(rename = "ADMIN_READ")]
     AdminRead,
@@ -41,8 +42,9 @@ pub enum LogType {
     LogTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LogType {
@@ -53,12 +55,47 @@ impl std::fmt::Display for LogType {
             LogType::DataWrite => "DATA_WRITE",
             LogType::LogTypeUnspecified => "LOG_TYPE_UNSPECIFIED",
             LogType::Noop => "",
-            LogType::FallthroughString => "*",
+            LogType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LogType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ADMIN_READ" => LogType::AdminRead,
+            "DATA_READ" => LogType::DataRead,
+            "DATA_WRITE" => LogType::DataWrite,
+            "LOG_TYPE_UNSPECIFIED" => LogType::LogTypeUnspecified,
+            "" => LogType::Noop,
+            _ => LogType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LogType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LogType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LogType {
     fn default() -> LogType {
         LogType::Noop
@@ -121,7 +158,8 @@ pub struct Binding {
 /**
 * The type of this operation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OperationType {
     #[serde(rename = "CREATE")]
     Create,
@@ -131,8 +169,9 @@ pub enum OperationType {
     OperationTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OperationType {
@@ -142,12 +181,46 @@ impl std::fmt::Display for OperationType {
             OperationType::Move => "MOVE",
             OperationType::OperationTypeUnspecified => "OPERATION_TYPE_UNSPECIFIED",
             OperationType::Noop => "",
-            OperationType::FallthroughString => "*",
+            OperationType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OperationType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CREATE" => OperationType::Create,
+            "MOVE" => OperationType::Move,
+            "OPERATION_TYPE_UNSPECIFIED" => OperationType::OperationTypeUnspecified,
+            "" => OperationType::Noop,
+            _ => OperationType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OperationType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OperationType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OperationType {
     fn default() -> OperationType {
         OperationType::Noop
@@ -306,7 +379,8 @@ pub struct Expr {
 /**
 * Output only. The lifecycle state of the folder. Updates to the lifecycle_state must be performed via DeleteFolder and UndeleteFolder.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LifecycleState {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -316,8 +390,9 @@ pub enum LifecycleState {
     LifecycleStateUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LifecycleState {
@@ -327,12 +402,46 @@ impl std::fmt::Display for LifecycleState {
             LifecycleState::DeleteRequested => "DELETE_REQUESTED",
             LifecycleState::LifecycleStateUnspecified => "LIFECYCLE_STATE_UNSPECIFIED",
             LifecycleState::Noop => "",
-            LifecycleState::FallthroughString => "*",
+            LifecycleState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LifecycleState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => LifecycleState::Active,
+            "DELETE_REQUESTED" => LifecycleState::DeleteRequested,
+            "LIFECYCLE_STATE_UNSPECIFIED" => LifecycleState::LifecycleStateUnspecified,
+            "" => LifecycleState::Noop,
+            _ => LifecycleState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LifecycleState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LifecycleState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LifecycleState {
     fn default() -> LifecycleState {
         LifecycleState::Noop
@@ -399,7 +508,8 @@ pub struct Folder {
 /**
 * The type of operation error experienced.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ErrorMessageId {
     #[serde(rename = "ACTIVE_FOLDER_HEIGHT_VIOLATION")]
     ActiveFolderHeightViolation,
@@ -423,8 +533,9 @@ pub enum ErrorMessageId {
     ResourceDeletedViolation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ErrorMessageId {
@@ -443,12 +554,55 @@ impl std::fmt::Display for ErrorMessageId {
             ErrorMessageId::ParentDeletedViolation => "PARENT_DELETED_VIOLATION",
             ErrorMessageId::ResourceDeletedViolation => "RESOURCE_DELETED_VIOLATION",
             ErrorMessageId::Noop => "",
-            ErrorMessageId::FallthroughString => "*",
+            ErrorMessageId::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ErrorMessageId {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE_FOLDER_HEIGHT_VIOLATION" => ErrorMessageId::ActiveFolderHeightViolation,
+            "CYCLE_INTRODUCED_VIOLATION" => ErrorMessageId::CycleIntroducedViolation,
+            "DELETED_FOLDER_HEIGHT_VIOLATION" => ErrorMessageId::DeletedFolderHeightViolation,
+            "ERROR_TYPE_UNSPECIFIED" => ErrorMessageId::ErrorTypeUnspecified,
+            "FOLDER_BEING_MOVED_VIOLATION" => ErrorMessageId::FolderBeingMovedViolation,
+            "FOLDER_NAME_UNIQUENESS_VIOLATION" => ErrorMessageId::FolderNameUniquenessViolation,
+            "FOLDER_TO_DELETE_NON_EMPTY_VIOLATION" => {
+                ErrorMessageId::FolderToDeleteNonEmptyViolation
+            }
+            "MAX_CHILD_FOLDERS_VIOLATION" => ErrorMessageId::MaxChildFoldersViolation,
+            "PARENT_DELETED_VIOLATION" => ErrorMessageId::ParentDeletedViolation,
+            "RESOURCE_DELETED_VIOLATION" => ErrorMessageId::ResourceDeletedViolation,
+            "" => ErrorMessageId::Noop,
+            _ => ErrorMessageId::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ErrorMessageId {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorMessageId {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ErrorMessageId {
     fn default() -> ErrorMessageId {
         ErrorMessageId::Noop
@@ -789,7 +943,8 @@ pub struct TestIamPermissionsResponse {
 /**
 * V1 error format.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Xgafv {
     #[serde(rename = "1")]
     One,
@@ -797,8 +952,9 @@ pub enum Xgafv {
     Two,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -807,12 +963,45 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Xgafv {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "1" => Xgafv::One,
+            "2" => Xgafv::Two,
+            "" => Xgafv::Noop,
+            _ => Xgafv::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Xgafv {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Xgafv {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Xgafv {
     fn default() -> Xgafv {
         Xgafv::Noop
@@ -827,7 +1016,8 @@ impl Xgafv {
 /**
 * Data format for response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -837,8 +1027,9 @@ pub enum Alt {
     Proto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -848,12 +1039,46 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Alt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "json" => Alt::Json,
+            "media" => Alt::Media,
+            "proto" => Alt::Proto,
+            "" => Alt::Noop,
+            _ => Alt::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Alt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Alt {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
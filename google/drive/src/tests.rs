@@ -1 +1,62 @@
+fn range_headers(range: &str) -> reqwest::header::HeaderMap {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        reqwest::header::RANGE,
+        reqwest::header::HeaderValue::from_str(range).unwrap(),
+    );
+    headers
+}
 
+#[test]
+fn test_received_bytes() {
+    assert_eq!(
+        crate::traits::received_bytes(&reqwest::header::HeaderMap::new()),
+        0
+    );
+    assert_eq!(
+        crate::traits::received_bytes(&range_headers("bytes=0-0")),
+        1
+    );
+    assert_eq!(
+        crate::traits::received_bytes(&range_headers("bytes=0-262143")),
+        262144
+    );
+    assert_eq!(crate::traits::received_bytes(&range_headers("bytes=0-")), 0);
+    assert_eq!(crate::traits::received_bytes(&range_headers("garbage")), 0);
+}
+
+#[test]
+fn test_verify_upload() {
+    let contents = b"hello world";
+    let file = crate::types::File {
+        id: "abc".to_string(),
+        size: contents.len() as i64,
+        md_5_checksum: format!("{:x}", md5::compute(contents)),
+        ..Default::default()
+    };
+    assert_eq!(
+        crate::traits::verify_upload(file.clone(), contents).unwrap(),
+        file
+    );
+
+    // Nothing to check against.
+    let unknown = crate::types::File {
+        id: "abc".to_string(),
+        ..Default::default()
+    };
+    assert!(crate::traits::verify_upload(unknown, contents).is_ok());
+
+    let short = crate::types::File {
+        size: 5,
+        ..file.clone()
+    };
+    let err = crate::traits::verify_upload(short, contents).unwrap_err();
+    assert!(err.to_string().contains("has size 5"), "{}", err);
+
+    let corrupt = crate::types::File {
+        md_5_checksum: format!("{:x}", md5::compute(b"hello")),
+        ..file
+    };
+    let err = crate::traits::verify_upload(corrupt, contents).unwrap_err();
+    assert!(err.to_string().contains("checksum"), "{}", err);
+}
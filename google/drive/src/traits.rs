@@ -239,25 +239,14 @@ impl FileOps for crate::files::Files {
             // Create the file.
         }
 
-        // Build the request to get the URL upload location if we need to create the file.
-        let resp = self
-            .client
-            .request_raw(
-                method,
-                &uri,
-                Some(reqwest::Body::from(serde_json::to_vec(&f)?)),
-            )
-            .await?;
+        // Ask for the checksum and size so the upload can be verified.
+        uri += "&fields=kind,id,name,mimeType,md5Checksum,size";
 
-        // Get the "Location" header.
-        let location = match resp.headers().get("Location") {
-            Some(location) => location.to_str()?,
-            None => anyhow::bail!("No Location header"),
-        };
-
-        // Now upload the file to that location.
-        self.client
-            .request_with_mime(reqwest::Method::PUT, location, contents, mime_type)
+        // Start the upload session, then upload the file to its location.
+        let location = self
+            .start_upload(method, &uri, &f, mime_type, contents.len() as u64)
+            .await?;
+        self.upload(&location, contents, RESUMABLE_UPLOAD_CHUNK_SIZE)
             .await
     }
 
@@ -376,6 +365,279 @@ impl FileOps for crate::files::Files {
     }
 }
 
+/// Every chunk of a resumable upload, except the last, must be a multiple of 256 KiB.
+const RESUMABLE_UPLOAD_CHUNK_MULTIPLE: usize = 256 * 1024;
+/// The chunk size used by `FileOps::create_or_update`.
+pub const RESUMABLE_UPLOAD_CHUNK_SIZE: usize = 32 * RESUMABLE_UPLOAD_CHUNK_MULTIPLE;
+/// How many times in a row an upload is resumed without progress before giving up.
+const RESUMABLE_UPLOAD_MAX_RESUMES: u32 = 5;
+
+/// How much of a resumable upload the server has received.
+#[derive(Clone, Debug)]
+pub enum UploadStatus {
+    /// The upload is incomplete and the server has received this many bytes.
+    Incomplete(u64),
+    /// The upload is complete.
+    Complete(Box<crate::types::File>),
+}
+
+/// The outcome of sending a single chunk.
+enum ChunkResult {
+    Status(UploadStatus),
+    /// The chunk failed in a way that can be resumed, e.g. a dropped connection.
+    Interrupted(anyhow::Error),
+}
+
+/// Perform uploads with the resumable upload protocol.
+/// See [this doc](https://developers.google.com/drive/api/guides/manage-uploads#resumable) for more information.
+#[async_trait::async_trait]
+pub trait UploadOps {
+    /// Start a resumable upload session and return the session URI.
+    ///
+    /// `uri` must be an upload endpoint with `uploadType=resumable`, `file` is the
+    /// metadata sent with the request and `size` is the length of the contents
+    /// to upload.
+    async fn start_upload(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        file: &crate::types::File,
+        mime_type: &str,
+        size: u64,
+    ) -> Result<String>;
+
+    /// Ask the server how much of an upload of `size` bytes it has received.
+    async fn upload_status(&self, session_uri: &str, size: u64) -> Result<UploadStatus>;
+
+    /// Upload `contents` to a session in chunks of `chunk_size` bytes.
+    ///
+    /// If a chunk fails because the connection dropped or the server errored,
+    /// the upload is resumed from the last byte the server received, waiting
+    /// 1s, 2s, 4s, ... between failures in a row. Once the upload is complete
+    /// the checksum and size the server reports, if any, are compared against
+    /// `contents`.
+    async fn upload(
+        &self,
+        session_uri: &str,
+        contents: &[u8],
+        chunk_size: usize,
+    ) -> Result<crate::types::File>;
+}
+
+#[async_trait::async_trait]
+impl UploadOps for crate::files::Files {
+    /// Start a resumable upload session and return the session URI.
+    async fn start_upload(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        file: &crate::types::File,
+        mime_type: &str,
+        size: u64,
+    ) -> Result<String> {
+        self.client.refresh_if_expired().await?;
+        let mut req = self
+            .client
+            .make_request(
+                &method,
+                uri,
+                Some(reqwest::Body::from(serde_json::to_vec(file)?)),
+            )
+            .await?;
+        req.headers_mut().insert(
+            reqwest::header::HeaderName::from_static("x-upload-content-type"),
+            reqwest::header::HeaderValue::from_str(mime_type)?,
+        );
+        req.headers_mut().insert(
+            reqwest::header::HeaderName::from_static("x-upload-content-length"),
+            reqwest::header::HeaderValue::from(size),
+        );
+
+        let resp = self.client.client.execute(req).await?;
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let rate_limit = crate::utils::RateLimitStatus::from_headers(resp.headers());
+            let body = resp.bytes().await.unwrap_or_default();
+            return Err(crate::utils::StatusError::new(status, rate_limit, &body).into());
+        }
+
+        // Get the "Location" header.
+        match resp.headers().get(reqwest::header::LOCATION) {
+            Some(location) => Ok(location.to_str()?.to_string()),
+            None => anyhow::bail!("No Location header"),
+        }
+    }
+
+    /// Ask the server how much of an upload of `size` bytes it has received.
+    async fn upload_status(&self, session_uri: &str, size: u64) -> Result<UploadStatus> {
+        match self
+            .put_chunk(session_uri, &[], &format!("bytes */{}", size))
+            .await?
+        {
+            ChunkResult::Status(status) => Ok(status),
+            ChunkResult::Interrupted(e) => Err(e),
+        }
+    }
+
+    /// Upload `contents` to a session in chunks of `chunk_size` bytes.
+    async fn upload(
+        &self,
+        session_uri: &str,
+        contents: &[u8],
+        chunk_size: usize,
+    ) -> Result<crate::types::File> {
+        let total = contents.len();
+        let chunk_size = std::cmp::max(chunk_size / RESUMABLE_UPLOAD_CHUNK_MULTIPLE, 1)
+            * RESUMABLE_UPLOAD_CHUNK_MULTIPLE;
+
+        let mut offset = 0;
+        let mut resumes = 0;
+        let mut interrupted = false;
+        loop {
+            let result = if interrupted {
+                // Find out where to resume from.
+                self.put_chunk(session_uri, &[], &format!("bytes */{}", total))
+                    .await?
+            } else {
+                let end = std::cmp::min(offset + chunk_size, total);
+                let range = if total == 0 {
+                    "bytes */0".to_string()
+                } else {
+                    format!("bytes {}-{}/{}", offset, end - 1, total)
+                };
+                self.put_chunk(session_uri, &contents[offset..end], &range)
+                    .await?
+            };
+
+            match result {
+                ChunkResult::Status(UploadStatus::Complete(file)) => {
+                    return verify_upload(*file, contents);
+                }
+                ChunkResult::Status(UploadStatus::Incomplete(received)) => {
+                    let received = std::cmp::min(received as usize, total);
+                    if !interrupted && received <= offset {
+                        // The server did not take any of the chunk, count it as a failed
+                        // attempt so a session that never advances can't loop forever.
+                        if resumes >= RESUMABLE_UPLOAD_MAX_RESUMES {
+                            anyhow::bail!("upload to {} stalled at byte {}", session_uri, offset);
+                        }
+                        log::info!("upload did not advance past byte {}, retrying", offset);
+                        tokio::time::sleep(std::time::Duration::from_secs(1 << resumes)).await;
+                        resumes += 1;
+                    } else if received > offset {
+                        // Progress was made, so only consecutive failures count.
+                        resumes = 0;
+                    }
+                    offset = received;
+                    interrupted = false;
+                }
+                ChunkResult::Interrupted(e) => {
+                    if resumes >= RESUMABLE_UPLOAD_MAX_RESUMES {
+                        return Err(e);
+                    }
+                    log::info!("upload interrupted, resuming: {}", e);
+                    tokio::time::sleep(std::time::Duration::from_secs(1 << resumes)).await;
+                    resumes += 1;
+                    interrupted = true;
+                }
+            }
+        }
+    }
+}
+
+impl crate::files::Files {
+    /// Send one chunk of an upload with the given `Content-Range`.
+    async fn put_chunk(
+        &self,
+        session_uri: &str,
+        chunk: &[u8],
+        content_range: &str,
+    ) -> Result<ChunkResult> {
+        self.client.refresh_if_expired().await?;
+        let body = bytes::Bytes::copy_from_slice(chunk);
+        let mut req = self
+            .client
+            .make_request(&reqwest::Method::PUT, session_uri, Some(body.into()))
+            .await?;
+        req.headers_mut().insert(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_static("application/octet-stream"),
+        );
+        req.headers_mut().insert(
+            reqwest::header::CONTENT_RANGE,
+            reqwest::header::HeaderValue::from_str(content_range)?,
+        );
+
+        let resp = match self.client.client.execute(req).await {
+            Ok(resp) => resp,
+            Err(e) => return Ok(ChunkResult::Interrupted(e.into())),
+        };
+
+        let status = resp.status();
+        match status.as_u16() {
+            200 | 201 => Ok(ChunkResult::Status(UploadStatus::Complete(
+                resp.json().await?,
+            ))),
+            // Google uses 308 to mean "Resume Incomplete".
+            308 => Ok(ChunkResult::Status(UploadStatus::Incomplete(
+                received_bytes(resp.headers()),
+            ))),
+            404 | 410 => Err(anyhow!("upload session {} has expired", session_uri)),
+            _ => {
+                let rate_limit = crate::utils::RateLimitStatus::from_headers(resp.headers());
+                let body = resp.bytes().await.unwrap_or_default();
+                let error = crate::utils::StatusError::new(status, rate_limit, &body).into();
+                if crate::utils::is_retryable(&error) {
+                    Ok(ChunkResult::Interrupted(error))
+                } else {
+                    Err(error)
+                }
+            }
+        }
+    }
+}
+
+/// Parse the `Range: bytes=0-N` header of a 308 response into the number of
+/// bytes received. No header means nothing has been received yet.
+pub(crate) fn received_bytes(headers: &reqwest::header::HeaderMap) -> u64 {
+    headers
+        .get(reqwest::header::RANGE)
+        .and_then(|range| range.to_str().ok())
+        .and_then(|range| range.trim_start_matches("bytes=").split('-').nth(1))
+        .and_then(|end| end.parse::<u64>().ok())
+        .map(|end| end + 1)
+        .unwrap_or(0)
+}
+
+/// Check the uploaded file against what we sent, if the server told us its checksum and size.
+pub(crate) fn verify_upload(
+    file: crate::types::File,
+    contents: &[u8],
+) -> Result<crate::types::File> {
+    if file.size != 0 && file.size as usize != contents.len() {
+        anyhow::bail!(
+            "uploaded file {} has size {}, expected {}",
+            file.id,
+            file.size,
+            contents.len()
+        );
+    }
+
+    if !file.md_5_checksum.is_empty() {
+        let checksum = format!("{:x}", md5::compute(contents));
+        if file.md_5_checksum != checksum {
+            anyhow::bail!(
+                "uploaded file {} has checksum {}, expected {}",
+                file.id,
+                file.md_5_checksum,
+                checksum
+            );
+        }
+    }
+
+    Ok(file)
+}
+
 #[async_trait::async_trait]
 pub trait DriveOps {
     /// Get a drive by it's name.
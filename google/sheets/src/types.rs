@@ -283,7 +283,8 @@ pub struct AppendCellsRequest {
 /**
 * Whether rows or columns should be appended.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Dimension {
     #[serde(rename = "COLUMNS")]
     Columns,
@@ -293,8 +294,9 @@ pub enum Dimension {
     Rows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Dimension {
@@ -304,12 +306,46 @@ impl std::fmt::Display for Dimension {
             Dimension::DimensionUnspecified => "DIMENSION_UNSPECIFIED",
             Dimension::Rows => "ROWS",
             Dimension::Noop => "",
-            Dimension::FallthroughString => "*",
+            Dimension::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Dimension {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COLUMNS" => Dimension::Columns,
+            "DIMENSION_UNSPECIFIED" => Dimension::DimensionUnspecified,
+            "ROWS" => Dimension::Rows,
+            "" => Dimension::Noop,
+            _ => Dimension::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Dimension {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Dimension {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Dimension {
     fn default() -> Dimension {
         Dimension::Noop
@@ -545,7 +581,8 @@ pub struct BandingProperties {
 /**
 * The comparison type of key value with baseline value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ComparisonType {
     #[serde(rename = "ABSOLUTE_DIFFERENCE")]
     AbsoluteDifference,
@@ -555,8 +592,9 @@ pub enum ComparisonType {
     PercentageDifference,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ComparisonType {
@@ -566,12 +604,46 @@ impl std::fmt::Display for ComparisonType {
             ComparisonType::ComparisonTypeUndefined => "COMPARISON_TYPE_UNDEFINED",
             ComparisonType::PercentageDifference => "PERCENTAGE_DIFFERENCE",
             ComparisonType::Noop => "",
-            ComparisonType::FallthroughString => "*",
+            ComparisonType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ComparisonType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ABSOLUTE_DIFFERENCE" => ComparisonType::AbsoluteDifference,
+            "COMPARISON_TYPE_UNDEFINED" => ComparisonType::ComparisonTypeUndefined,
+            "PERCENTAGE_DIFFERENCE" => ComparisonType::PercentageDifference,
+            "" => ComparisonType::Noop,
+            _ => ComparisonType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ComparisonType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ComparisonType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ComparisonType {
     fn default() -> ComparisonType {
         ComparisonType::Noop
@@ -659,7 +731,8 @@ pub struct BaselineValueFormat {
 /**
 * The position of this axis.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Position {
     #[serde(rename = "BASIC_CHART_AXIS_POSITION_UNSPECIFIED")]
     BasicChartAxisPositionUnspecified,
@@ -671,8 +744,9 @@ pub enum Position {
     RightAxis,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Position {
@@ -683,12 +757,47 @@ impl std::fmt::Display for Position {
             Position::LeftAxis => "LEFT_AXIS",
             Position::RightAxis => "RIGHT_AXIS",
             Position::Noop => "",
-            Position::FallthroughString => "*",
+            Position::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Position {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BASIC_CHART_AXIS_POSITION_UNSPECIFIED" => Position::BasicChartAxisPositionUnspecified,
+            "BOTTOM_AXIS" => Position::BottomAxis,
+            "LEFT_AXIS" => Position::LeftAxis,
+            "RIGHT_AXIS" => Position::RightAxis,
+            "" => Position::Noop,
+            _ => Position::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Position {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Position {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Position {
     fn default() -> Position {
         Position::Noop
@@ -763,7 +872,8 @@ pub struct BasicChartDomain {
 /**
 * The type of this series. Valid only if the chartType is COMBO. Different types will change the way the series is visualized. Only LINE, AREA, and COLUMN are supported.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Type {
     #[serde(rename = "AREA")]
     Area,
@@ -783,8 +893,9 @@ pub enum Type {
     SteppedArea,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Type {
@@ -799,12 +910,51 @@ impl std::fmt::Display for Type {
             Type::Scatter => "SCATTER",
             Type::SteppedArea => "STEPPED_AREA",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AREA" => Type::Area,
+            "BAR" => Type::Bar,
+            "BASIC_CHART_TYPE_UNSPECIFIED" => Type::BasicChartTypeUnspecified,
+            "COLUMN" => Type::Column,
+            "COMBO" => Type::Combo,
+            "LINE" => Type::Line,
+            "SCATTER" => Type::Scatter,
+            "STEPPED_AREA" => Type::SteppedArea,
+            "" => Type::Noop,
+            _ => Type::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -886,7 +1036,8 @@ pub struct BasicChartSeries {
 /**
 * The behavior of tooltips and data highlighting when hovering on data and chart area.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CompareMode {
     #[serde(rename = "BASIC_CHART_COMPARE_MODE_UNSPECIFIED")]
     BasicChartCompareModeUnspecified,
@@ -896,8 +1047,9 @@ pub enum CompareMode {
     Datum,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CompareMode {
@@ -907,12 +1059,46 @@ impl std::fmt::Display for CompareMode {
             CompareMode::Category => "CATEGORY",
             CompareMode::Datum => "DATUM",
             CompareMode::Noop => "",
-            CompareMode::FallthroughString => "*",
+            CompareMode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CompareMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BASIC_CHART_COMPARE_MODE_UNSPECIFIED" => CompareMode::BasicChartCompareModeUnspecified,
+            "CATEGORY" => CompareMode::Category,
+            "DATUM" => CompareMode::Datum,
+            "" => CompareMode::Noop,
+            _ => CompareMode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CompareMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompareMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CompareMode {
     fn default() -> CompareMode {
         CompareMode::Noop
@@ -927,7 +1113,8 @@ impl CompareMode {
 /**
 * The position of the chart legend.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LegendPosition {
     #[serde(rename = "BASIC_CHART_LEGEND_POSITION_UNSPECIFIED")]
     BasicChartLegendPositionUnspecified,
@@ -943,8 +1130,9 @@ pub enum LegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LegendPosition {
@@ -959,12 +1147,51 @@ impl std::fmt::Display for LegendPosition {
             LegendPosition::RightLegend => "RIGHT_LEGEND",
             LegendPosition::TopLegend => "TOP_LEGEND",
             LegendPosition::Noop => "",
-            LegendPosition::FallthroughString => "*",
+            LegendPosition::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LegendPosition {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BASIC_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                LegendPosition::BasicChartLegendPositionUnspecified
+            }
+            "BOTTOM_LEGEND" => LegendPosition::BottomLegend,
+            "LEFT_LEGEND" => LegendPosition::LeftLegend,
+            "NO_LEGEND" => LegendPosition::NoLegend,
+            "RIGHT_LEGEND" => LegendPosition::RightLegend,
+            "TOP_LEGEND" => LegendPosition::TopLegend,
+            "" => LegendPosition::Noop,
+            _ => LegendPosition::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LegendPosition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LegendPosition {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LegendPosition {
     fn default() -> LegendPosition {
         LegendPosition::Noop
@@ -979,7 +1206,8 @@ impl LegendPosition {
 /**
 * The stacked type for charts that support vertical stacking. Applies to Area, Bar, Column, Combo, and Stepped Area charts.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum StackedType {
     #[serde(rename = "BASIC_CHART_STACKED_TYPE_UNSPECIFIED")]
     BasicChartStackedTypeUnspecified,
@@ -991,8 +1219,9 @@ pub enum StackedType {
     Stacked,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for StackedType {
@@ -1003,12 +1232,47 @@ impl std::fmt::Display for StackedType {
             StackedType::PercentStacked => "PERCENT_STACKED",
             StackedType::Stacked => "STACKED",
             StackedType::Noop => "",
-            StackedType::FallthroughString => "*",
+            StackedType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for StackedType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BASIC_CHART_STACKED_TYPE_UNSPECIFIED" => StackedType::BasicChartStackedTypeUnspecified,
+            "NOT_STACKED" => StackedType::NotStacked,
+            "PERCENT_STACKED" => StackedType::PercentStacked,
+            "STACKED" => StackedType::Stacked,
+            "" => StackedType::Noop,
+            _ => StackedType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for StackedType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for StackedType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for StackedType {
     fn default() -> StackedType {
         StackedType::Noop
@@ -1284,7 +1548,8 @@ pub struct BatchClearValuesResponse {
 /**
 * How dates, times, and durations should be represented in the output. This is ignored if value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DateTimeRenderOption {
     #[serde(rename = "FORMATTED_STRING")]
     FormattedString,
@@ -1292,8 +1557,9 @@ pub enum DateTimeRenderOption {
     SerialNumber,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DateTimeRenderOption {
@@ -1302,12 +1568,45 @@ impl std::fmt::Display for DateTimeRenderOption {
             DateTimeRenderOption::FormattedString => "FORMATTED_STRING",
             DateTimeRenderOption::SerialNumber => "SERIAL_NUMBER",
             DateTimeRenderOption::Noop => "",
-            DateTimeRenderOption::FallthroughString => "*",
+            DateTimeRenderOption::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DateTimeRenderOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "FORMATTED_STRING" => DateTimeRenderOption::FormattedString,
+            "SERIAL_NUMBER" => DateTimeRenderOption::SerialNumber,
+            "" => DateTimeRenderOption::Noop,
+            _ => DateTimeRenderOption::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DateTimeRenderOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateTimeRenderOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DateTimeRenderOption {
     fn default() -> DateTimeRenderOption {
         DateTimeRenderOption::Noop
@@ -1322,7 +1621,8 @@ impl DateTimeRenderOption {
 /**
 * How values should be represented in the output. The default render option is FORMATTED_VALUE.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ValueRenderOption {
     #[serde(rename = "FORMATTED_VALUE")]
     FormattedValue,
@@ -1332,8 +1632,9 @@ pub enum ValueRenderOption {
     UnformattedValue,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ValueRenderOption {
@@ -1343,12 +1644,46 @@ impl std::fmt::Display for ValueRenderOption {
             ValueRenderOption::Formula => "FORMULA",
             ValueRenderOption::UnformattedValue => "UNFORMATTED_VALUE",
             ValueRenderOption::Noop => "",
-            ValueRenderOption::FallthroughString => "*",
+            ValueRenderOption::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ValueRenderOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "FORMATTED_VALUE" => ValueRenderOption::FormattedValue,
+            "FORMULA" => ValueRenderOption::Formula,
+            "UNFORMATTED_VALUE" => ValueRenderOption::UnformattedValue,
+            "" => ValueRenderOption::Noop,
+            _ => ValueRenderOption::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ValueRenderOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ValueRenderOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ValueRenderOption {
     fn default() -> ValueRenderOption {
         ValueRenderOption::Noop
@@ -1530,7 +1865,8 @@ pub struct BatchUpdateSpreadsheetResponse {
 /**
 * How the input data should be interpreted.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ValueInputOption {
     #[serde(rename = "INPUT_VALUE_OPTION_UNSPECIFIED")]
     InputValueOptionUnspecified,
@@ -1540,8 +1876,9 @@ pub enum ValueInputOption {
     UserEntered,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ValueInputOption {
@@ -1551,12 +1888,46 @@ impl std::fmt::Display for ValueInputOption {
             ValueInputOption::Raw => "RAW",
             ValueInputOption::UserEntered => "USER_ENTERED",
             ValueInputOption::Noop => "",
-            ValueInputOption::FallthroughString => "*",
+            ValueInputOption::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ValueInputOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "INPUT_VALUE_OPTION_UNSPECIFIED" => ValueInputOption::InputValueOptionUnspecified,
+            "RAW" => ValueInputOption::Raw,
+            "USER_ENTERED" => ValueInputOption::UserEntered,
+            "" => ValueInputOption::Noop,
+            _ => ValueInputOption::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ValueInputOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ValueInputOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ValueInputOption {
     fn default() -> ValueInputOption {
         ValueInputOption::Noop
@@ -1874,7 +2245,8 @@ pub struct BigQueryTableSpec {
 /**
 * The type of condition.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BooleanConditionType {
     #[serde(rename = "BLANK")]
     Blank,
@@ -1942,8 +2314,9 @@ pub enum BooleanConditionType {
     TextStartsWith,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BooleanConditionType {
@@ -1982,12 +2355,75 @@ impl std::fmt::Display for BooleanConditionType {
             BooleanConditionType::TextNotEq => "TEXT_NOT_EQ",
             BooleanConditionType::TextStartsWith => "TEXT_STARTS_WITH",
             BooleanConditionType::Noop => "",
-            BooleanConditionType::FallthroughString => "*",
+            BooleanConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BooleanConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BLANK" => BooleanConditionType::Blank,
+            "BOOLEAN" => BooleanConditionType::Boolean,
+            "CONDITION_TYPE_UNSPECIFIED" => BooleanConditionType::ConditionTypeUnspecified,
+            "CUSTOM_FORMULA" => BooleanConditionType::CustomFormula,
+            "DATE_AFTER" => BooleanConditionType::DateAfter,
+            "DATE_BEFORE" => BooleanConditionType::DateBefore,
+            "DATE_BETWEEN" => BooleanConditionType::DateBetween,
+            "DATE_EQ" => BooleanConditionType::DateEq,
+            "DATE_IS_VALID" => BooleanConditionType::DateIsValid,
+            "DATE_NOT_BETWEEN" => BooleanConditionType::DateNotBetween,
+            "DATE_NOT_EQ" => BooleanConditionType::DateNotEq,
+            "DATE_ON_OR_AFTER" => BooleanConditionType::DateOnOrAfter,
+            "DATE_ON_OR_BEFORE" => BooleanConditionType::DateOnOrBefore,
+            "NOT_BLANK" => BooleanConditionType::NotBlank,
+            "NUMBER_BETWEEN" => BooleanConditionType::NumberBetween,
+            "NUMBER_EQ" => BooleanConditionType::NumberEq,
+            "NUMBER_GREATER" => BooleanConditionType::NumberGreater,
+            "NUMBER_GREATER_THAN_EQ" => BooleanConditionType::NumberGreaterThanEq,
+            "NUMBER_LESS" => BooleanConditionType::NumberLess,
+            "NUMBER_LESS_THAN_EQ" => BooleanConditionType::NumberLessThanEq,
+            "NUMBER_NOT_BETWEEN" => BooleanConditionType::NumberNotBetween,
+            "NUMBER_NOT_EQ" => BooleanConditionType::NumberNotEq,
+            "ONE_OF_LIST" => BooleanConditionType::OneOfList,
+            "ONE_OF_RANGE" => BooleanConditionType::OneOfRange,
+            "TEXT_CONTAINS" => BooleanConditionType::TextContains,
+            "TEXT_ENDS_WITH" => BooleanConditionType::TextEndsWith,
+            "TEXT_EQ" => BooleanConditionType::TextEq,
+            "TEXT_IS_EMAIL" => BooleanConditionType::TextIsEmail,
+            "TEXT_IS_URL" => BooleanConditionType::TextIsUrl,
+            "TEXT_NOT_CONTAINS" => BooleanConditionType::TextNotContains,
+            "TEXT_NOT_EQ" => BooleanConditionType::TextNotEq,
+            "TEXT_STARTS_WITH" => BooleanConditionType::TextStartsWith,
+            "" => BooleanConditionType::Noop,
+            _ => BooleanConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BooleanConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BooleanConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BooleanConditionType {
     fn default() -> BooleanConditionType {
         BooleanConditionType::Noop
@@ -2036,7 +2472,8 @@ pub struct BooleanRule {
 /**
 * The style of the border.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Style {
     #[serde(rename = "DASHED")]
     Dashed,
@@ -2056,8 +2493,9 @@ pub enum Style {
     StyleUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Style {
@@ -2072,12 +2510,51 @@ impl std::fmt::Display for Style {
             Style::SolidThick => "SOLID_THICK",
             Style::StyleUnspecified => "STYLE_UNSPECIFIED",
             Style::Noop => "",
-            Style::FallthroughString => "*",
+            Style::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Style {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DASHED" => Style::Dashed,
+            "DOTTED" => Style::Dotted,
+            "DOUBLE" => Style::Double,
+            "NONE" => Style::None,
+            "SOLID" => Style::Solid,
+            "SOLID_MEDIUM" => Style::SolidMedium,
+            "SOLID_THICK" => Style::SolidThick,
+            "STYLE_UNSPECIFIED" => Style::StyleUnspecified,
+            "" => Style::Noop,
+            _ => Style::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Style {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Style {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Style {
     fn default() -> Style {
         Style::Noop
@@ -2150,7 +2627,8 @@ pub struct Borders {
 /**
 * Where the legend of the chart should be drawn.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BubbleChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -2168,8 +2646,9 @@ pub enum BubbleChartSpecLegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BubbleChartSpecLegendPosition {
@@ -2185,12 +2664,52 @@ impl std::fmt::Display for BubbleChartSpecLegendPosition {
             BubbleChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             BubbleChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             BubbleChartSpecLegendPosition::Noop => "",
-            BubbleChartSpecLegendPosition::FallthroughString => "*",
+            BubbleChartSpecLegendPosition::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BubbleChartSpecLegendPosition {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BOTTOM_LEGEND" => BubbleChartSpecLegendPosition::BottomLegend,
+            "BUBBLE_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                BubbleChartSpecLegendPosition::BubbleChartLegendPositionUnspecified
+            }
+            "INSIDE_LEGEND" => BubbleChartSpecLegendPosition::InsideLegend,
+            "LEFT_LEGEND" => BubbleChartSpecLegendPosition::LeftLegend,
+            "NO_LEGEND" => BubbleChartSpecLegendPosition::NoLegend,
+            "RIGHT_LEGEND" => BubbleChartSpecLegendPosition::RightLegend,
+            "TOP_LEGEND" => BubbleChartSpecLegendPosition::TopLegend,
+            "" => BubbleChartSpecLegendPosition::Noop,
+            _ => BubbleChartSpecLegendPosition::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BubbleChartSpecLegendPosition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BubbleChartSpecLegendPosition {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BubbleChartSpecLegendPosition {
     fn default() -> BubbleChartSpecLegendPosition {
         BubbleChartSpecLegendPosition::Noop
@@ -2508,7 +3027,8 @@ pub struct CellData {
 /**
 * The horizontal alignment of the value in the cell.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum HorizontalAlignment {
     #[serde(rename = "CENTER")]
     Center,
@@ -2520,8 +3040,9 @@ pub enum HorizontalAlignment {
     Right,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for HorizontalAlignment {
@@ -2532,12 +3053,47 @@ impl std::fmt::Display for HorizontalAlignment {
             HorizontalAlignment::Left => "LEFT",
             HorizontalAlignment::Right => "RIGHT",
             HorizontalAlignment::Noop => "",
-            HorizontalAlignment::FallthroughString => "*",
+            HorizontalAlignment::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for HorizontalAlignment {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CENTER" => HorizontalAlignment::Center,
+            "HORIZONTAL_ALIGN_UNSPECIFIED" => HorizontalAlignment::HorizontalAlignUnspecified,
+            "LEFT" => HorizontalAlignment::Left,
+            "RIGHT" => HorizontalAlignment::Right,
+            "" => HorizontalAlignment::Noop,
+            _ => HorizontalAlignment::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for HorizontalAlignment {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for HorizontalAlignment {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for HorizontalAlignment {
     fn default() -> HorizontalAlignment {
         HorizontalAlignment::Noop
@@ -2552,7 +3108,8 @@ impl HorizontalAlignment {
 /**
 * How a hyperlink, if it exists, should be displayed in the cell.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum HyperlinkDisplayType {
     #[serde(rename = "HYPERLINK_DISPLAY_TYPE_UNSPECIFIED")]
     HyperlinkDisplayTypeUnspecified,
@@ -2562,8 +3119,9 @@ pub enum HyperlinkDisplayType {
     PlainText,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for HyperlinkDisplayType {
@@ -2575,12 +3133,48 @@ impl std::fmt::Display for HyperlinkDisplayType {
             HyperlinkDisplayType::Linked => "LINKED",
             HyperlinkDisplayType::PlainText => "PLAIN_TEXT",
             HyperlinkDisplayType::Noop => "",
-            HyperlinkDisplayType::FallthroughString => "*",
+            HyperlinkDisplayType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for HyperlinkDisplayType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "HYPERLINK_DISPLAY_TYPE_UNSPECIFIED" => {
+                HyperlinkDisplayType::HyperlinkDisplayTypeUnspecified
+            }
+            "LINKED" => HyperlinkDisplayType::Linked,
+            "PLAIN_TEXT" => HyperlinkDisplayType::PlainText,
+            "" => HyperlinkDisplayType::Noop,
+            _ => HyperlinkDisplayType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for HyperlinkDisplayType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for HyperlinkDisplayType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for HyperlinkDisplayType {
     fn default() -> HyperlinkDisplayType {
         HyperlinkDisplayType::Noop
@@ -2595,7 +3189,8 @@ impl HyperlinkDisplayType {
 /**
 * The direction of the text in the cell.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TextDirection {
     #[serde(rename = "LEFT_TO_RIGHT")]
     LeftToRight,
@@ -2605,8 +3200,9 @@ pub enum TextDirection {
     TextDirectionUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TextDirection {
@@ -2616,12 +3212,46 @@ impl std::fmt::Display for TextDirection {
             TextDirection::RightToLeft => "RIGHT_TO_LEFT",
             TextDirection::TextDirectionUnspecified => "TEXT_DIRECTION_UNSPECIFIED",
             TextDirection::Noop => "",
-            TextDirection::FallthroughString => "*",
+            TextDirection::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TextDirection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "LEFT_TO_RIGHT" => TextDirection::LeftToRight,
+            "RIGHT_TO_LEFT" => TextDirection::RightToLeft,
+            "TEXT_DIRECTION_UNSPECIFIED" => TextDirection::TextDirectionUnspecified,
+            "" => TextDirection::Noop,
+            _ => TextDirection::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TextDirection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TextDirection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TextDirection {
     fn default() -> TextDirection {
         TextDirection::Noop
@@ -2636,7 +3266,8 @@ impl TextDirection {
 /**
 * The vertical alignment of the value in the cell.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum VerticalAlignment {
     #[serde(rename = "BOTTOM")]
     Bottom,
@@ -2648,8 +3279,9 @@ pub enum VerticalAlignment {
     VerticalAlignUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for VerticalAlignment {
@@ -2660,12 +3292,47 @@ impl std::fmt::Display for VerticalAlignment {
             VerticalAlignment::Top => "TOP",
             VerticalAlignment::VerticalAlignUnspecified => "VERTICAL_ALIGN_UNSPECIFIED",
             VerticalAlignment::Noop => "",
-            VerticalAlignment::FallthroughString => "*",
+            VerticalAlignment::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for VerticalAlignment {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BOTTOM" => VerticalAlignment::Bottom,
+            "MIDDLE" => VerticalAlignment::Middle,
+            "TOP" => VerticalAlignment::Top,
+            "VERTICAL_ALIGN_UNSPECIFIED" => VerticalAlignment::VerticalAlignUnspecified,
+            "" => VerticalAlignment::Noop,
+            _ => VerticalAlignment::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for VerticalAlignment {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for VerticalAlignment {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for VerticalAlignment {
     fn default() -> VerticalAlignment {
         VerticalAlignment::Noop
@@ -2680,7 +3347,8 @@ impl VerticalAlignment {
 /**
 * The wrap strategy for the value in the cell.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WrapStrategy {
     #[serde(rename = "CLIP")]
     Clip,
@@ -2694,8 +3362,9 @@ pub enum WrapStrategy {
     WrapStrategyUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WrapStrategy {
@@ -2707,12 +3376,48 @@ impl std::fmt::Display for WrapStrategy {
             WrapStrategy::Wrap => "WRAP",
             WrapStrategy::WrapStrategyUnspecified => "WRAP_STRATEGY_UNSPECIFIED",
             WrapStrategy::Noop => "",
-            WrapStrategy::FallthroughString => "*",
+            WrapStrategy::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WrapStrategy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CLIP" => WrapStrategy::Clip,
+            "LEGACY_WRAP" => WrapStrategy::LegacyWrap,
+            "OVERFLOW_CELL" => WrapStrategy::OverflowCell,
+            "WRAP" => WrapStrategy::Wrap,
+            "WRAP_STRATEGY_UNSPECIFIED" => WrapStrategy::WrapStrategyUnspecified,
+            "" => WrapStrategy::Noop,
+            _ => WrapStrategy::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WrapStrategy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WrapStrategy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WrapStrategy {
     fn default() -> WrapStrategy {
         WrapStrategy::Noop
@@ -2832,7 +3537,8 @@ pub struct CellFormat {
 /**
 * The view window's mode.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ViewWindowMode {
     #[serde(rename = "DEFAULT_VIEW_WINDOW_MODE")]
     DefaultViewWindowMode,
@@ -2844,8 +3550,9 @@ pub enum ViewWindowMode {
     ViewWindowModeUnsupported,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ViewWindowMode {
@@ -2856,12 +3563,47 @@ impl std::fmt::Display for ViewWindowMode {
             ViewWindowMode::Pretty => "PRETTY",
             ViewWindowMode::ViewWindowModeUnsupported => "VIEW_WINDOW_MODE_UNSUPPORTED",
             ViewWindowMode::Noop => "",
-            ViewWindowMode::FallthroughString => "*",
+            ViewWindowMode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ViewWindowMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DEFAULT_VIEW_WINDOW_MODE" => ViewWindowMode::DefaultViewWindowMode,
+            "EXPLICIT" => ViewWindowMode::Explicit,
+            "PRETTY" => ViewWindowMode::Pretty,
+            "VIEW_WINDOW_MODE_UNSUPPORTED" => ViewWindowMode::ViewWindowModeUnsupported,
+            "" => ViewWindowMode::Noop,
+            _ => ViewWindowMode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ViewWindowMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ViewWindowMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ViewWindowMode {
     fn default() -> ViewWindowMode {
         ViewWindowMode::Noop
@@ -2933,7 +3675,8 @@ pub struct ChartCustomNumberFormatOptions {
 /**
 * The aggregation type for the series of a data source chart. Only supported for data source charts.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AggregateType {
     #[serde(rename = "AVERAGE")]
     Average,
@@ -2951,8 +3694,9 @@ pub enum AggregateType {
     Sum,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AggregateType {
@@ -2966,12 +3710,50 @@ impl std::fmt::Display for AggregateType {
             AggregateType::Min => "MIN",
             AggregateType::Sum => "SUM",
             AggregateType::Noop => "",
-            AggregateType::FallthroughString => "*",
+            AggregateType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AggregateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AVERAGE" => AggregateType::Average,
+            "CHART_AGGREGATE_TYPE_UNSPECIFIED" => AggregateType::ChartAggregateTypeUnspecified,
+            "COUNT" => AggregateType::Count,
+            "MAX" => AggregateType::Max,
+            "MEDIAN" => AggregateType::Median,
+            "MIN" => AggregateType::Min,
+            "SUM" => AggregateType::Sum,
+            "" => AggregateType::Noop,
+            _ => AggregateType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AggregateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AggregateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AggregateType {
     fn default() -> AggregateType {
         AggregateType::Noop
@@ -3023,7 +3805,8 @@ pub struct ChartData {
 /**
 * The type of date-time grouping to apply.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ChartDateTimeRuleType {
     #[serde(rename = "CHART_DATE_TIME_RULE_TYPE_UNSPECIFIED")]
     ChartDateTimeRuleTypeUnspecified,
@@ -3059,8 +3842,9 @@ pub enum ChartDateTimeRuleType {
     YearQuarter,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ChartDateTimeRuleType {
@@ -3085,12 +3869,61 @@ impl std::fmt::Display for ChartDateTimeRuleType {
             ChartDateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
             ChartDateTimeRuleType::YearQuarter => "YEAR_QUARTER",
             ChartDateTimeRuleType::Noop => "",
-            ChartDateTimeRuleType::FallthroughString => "*",
+            ChartDateTimeRuleType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ChartDateTimeRuleType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CHART_DATE_TIME_RULE_TYPE_UNSPECIFIED" => {
+                ChartDateTimeRuleType::ChartDateTimeRuleTypeUnspecified
+            }
+            "DAY_MONTH" => ChartDateTimeRuleType::DayMonth,
+            "DAY_OF_MONTH" => ChartDateTimeRuleType::DayOfMonth,
+            "DAY_OF_WEEK" => ChartDateTimeRuleType::DayOfWeek,
+            "DAY_OF_YEAR" => ChartDateTimeRuleType::DayOfYear,
+            "HOUR" => ChartDateTimeRuleType::Hour,
+            "HOUR_MINUTE" => ChartDateTimeRuleType::HourMinute,
+            "HOUR_MINUTE_AMPM" => ChartDateTimeRuleType::HourMinuteAmpm,
+            "MINUTE" => ChartDateTimeRuleType::Minute,
+            "MONTH" => ChartDateTimeRuleType::Month,
+            "QUARTER" => ChartDateTimeRuleType::Quarter,
+            "SECOND" => ChartDateTimeRuleType::Second,
+            "YEAR" => ChartDateTimeRuleType::Year,
+            "YEAR_MONTH" => ChartDateTimeRuleType::YearMonth,
+            "YEAR_MONTH_DAY" => ChartDateTimeRuleType::YearMonthDay,
+            "YEAR_QUARTER" => ChartDateTimeRuleType::YearQuarter,
+            "" => ChartDateTimeRuleType::Noop,
+            _ => ChartDateTimeRuleType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ChartDateTimeRuleType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChartDateTimeRuleType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ChartDateTimeRuleType {
     fn default() -> ChartDateTimeRuleType {
         ChartDateTimeRuleType::Noop
@@ -3187,7 +4020,8 @@ pub struct ChartSourceRange {
 /**
 * Determines how the charts will use hidden rows or columns.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum HiddenDimensionStrategy {
     #[serde(rename = "CHART_HIDDEN_DIMENSION_STRATEGY_UNSPECIFIED")]
     ChartHiddenDimensionStrategyUnspecified,
@@ -3201,8 +4035,9 @@ pub enum HiddenDimensionStrategy {
     SkipHiddenRowsAndColumns,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for HiddenDimensionStrategy {
@@ -3216,12 +4051,50 @@ impl std::fmt::Display for HiddenDimensionStrategy {
             HiddenDimensionStrategy::SkipHiddenRows => "SKIP_HIDDEN_ROWS",
             HiddenDimensionStrategy::SkipHiddenRowsAndColumns => "SKIP_HIDDEN_ROWS_AND_COLUMNS",
             HiddenDimensionStrategy::Noop => "",
-            HiddenDimensionStrategy::FallthroughString => "*",
+            HiddenDimensionStrategy::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for HiddenDimensionStrategy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CHART_HIDDEN_DIMENSION_STRATEGY_UNSPECIFIED" => {
+                HiddenDimensionStrategy::ChartHiddenDimensionStrategyUnspecified
+            }
+            "SHOW_ALL" => HiddenDimensionStrategy::ShowAll,
+            "SKIP_HIDDEN_COLUMNS" => HiddenDimensionStrategy::SkipHiddenColumns,
+            "SKIP_HIDDEN_ROWS" => HiddenDimensionStrategy::SkipHiddenRows,
+            "SKIP_HIDDEN_ROWS_AND_COLUMNS" => HiddenDimensionStrategy::SkipHiddenRowsAndColumns,
+            "" => HiddenDimensionStrategy::Noop,
+            _ => HiddenDimensionStrategy::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for HiddenDimensionStrategy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for HiddenDimensionStrategy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for HiddenDimensionStrategy {
     fn default() -> HiddenDimensionStrategy {
         HiddenDimensionStrategy::Noop
@@ -3537,7 +4410,8 @@ pub struct Color {
 /**
 * The type of the spreadsheet theme color.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ColorType {
     #[serde(rename = "ACCENT1")]
     Accent1,
@@ -3561,8 +4435,9 @@ pub enum ColorType {
     ThemeColorTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ColorType {
@@ -3579,12 +4454,53 @@ impl std::fmt::Display for ColorType {
             ColorType::Text => "TEXT",
             ColorType::ThemeColorTypeUnspecified => "THEME_COLOR_TYPE_UNSPECIFIED",
             ColorType::Noop => "",
-            ColorType::FallthroughString => "*",
+            ColorType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ColorType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACCENT1" => ColorType::Accent1,
+            "ACCENT2" => ColorType::Accent2,
+            "ACCENT3" => ColorType::Accent3,
+            "ACCENT4" => ColorType::Accent4,
+            "ACCENT5" => ColorType::Accent5,
+            "ACCENT6" => ColorType::Accent6,
+            "BACKGROUND" => ColorType::Background,
+            "LINK" => ColorType::Link,
+            "TEXT" => ColorType::Text,
+            "THEME_COLOR_TYPE_UNSPECIFIED" => ColorType::ThemeColorTypeUnspecified,
+            "" => ColorType::Noop,
+            _ => ColorType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ColorType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ColorType {
     fn default() -> ColorType {
         ColorType::Noop
@@ -3618,7 +4534,8 @@ pub struct ColorStyle {
 /**
 * A relative date (based on the current date). Valid only if the type is DATE_BEFORE, DATE_AFTER, DATE_ON_OR_BEFORE or DATE_ON_OR_AFTER. Relative dates are not supported in data validation. They are supported only in conditional formatting and conditional filters.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RelativeDate {
     #[serde(rename = "PAST_MONTH")]
     PastMonth,
@@ -3636,8 +4553,9 @@ pub enum RelativeDate {
     Yesterday,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RelativeDate {
@@ -3651,12 +4569,50 @@ impl std::fmt::Display for RelativeDate {
             RelativeDate::Tomorrow => "TOMORROW",
             RelativeDate::Yesterday => "YESTERDAY",
             RelativeDate::Noop => "",
-            RelativeDate::FallthroughString => "*",
+            RelativeDate::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RelativeDate {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "PAST_MONTH" => RelativeDate::PastMonth,
+            "PAST_WEEK" => RelativeDate::PastWeek,
+            "PAST_YEAR" => RelativeDate::PastYear,
+            "RELATIVE_DATE_UNSPECIFIED" => RelativeDate::RelativeDateUnspecified,
+            "TODAY" => RelativeDate::Today,
+            "TOMORROW" => RelativeDate::Tomorrow,
+            "YESTERDAY" => RelativeDate::Yesterday,
+            "" => RelativeDate::Noop,
+            _ => RelativeDate::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RelativeDate {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RelativeDate {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RelativeDate {
     fn default() -> RelativeDate {
         RelativeDate::Noop
@@ -3727,7 +4683,8 @@ pub struct ConditionalFormatRule {
 /**
 * How that data should be oriented when pasting.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PasteOrientation {
     #[serde(rename = "NORMAL")]
     Normal,
@@ -3735,8 +4692,9 @@ pub enum PasteOrientation {
     Transpose,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PasteOrientation {
@@ -3745,12 +4703,45 @@ impl std::fmt::Display for PasteOrientation {
             PasteOrientation::Normal => "NORMAL",
             PasteOrientation::Transpose => "TRANSPOSE",
             PasteOrientation::Noop => "",
-            PasteOrientation::FallthroughString => "*",
+            PasteOrientation::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PasteOrientation {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "NORMAL" => PasteOrientation::Normal,
+            "TRANSPOSE" => PasteOrientation::Transpose,
+            "" => PasteOrientation::Noop,
+            _ => PasteOrientation::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PasteOrientation {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PasteOrientation {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PasteOrientation {
     fn default() -> PasteOrientation {
         PasteOrientation::Noop
@@ -3765,7 +4756,8 @@ impl PasteOrientation {
 /**
 * What kind of data to paste.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PasteType {
     #[serde(rename = "PASTE_CONDITIONAL_FORMATTING")]
     PasteConditionalFormatting,
@@ -3783,8 +4775,9 @@ pub enum PasteType {
     PasteValues,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PasteType {
@@ -3798,12 +4791,50 @@ impl std::fmt::Display for PasteType {
             PasteType::PasteNoBorders => "PASTE_NO_BORDERS",
             PasteType::PasteValues => "PASTE_VALUES",
             PasteType::Noop => "",
-            PasteType::FallthroughString => "*",
+            PasteType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PasteType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "PASTE_CONDITIONAL_FORMATTING" => PasteType::PasteConditionalFormatting,
+            "PASTE_DATA_VALIDATION" => PasteType::PasteDataValidation,
+            "PASTE_FORMAT" => PasteType::PasteFormat,
+            "PASTE_FORMULA" => PasteType::PasteFormula,
+            "PASTE_NORMAL" => PasteType::PasteNormal,
+            "PASTE_NO_BORDERS" => PasteType::PasteNoBorders,
+            "PASTE_VALUES" => PasteType::PasteValues,
+            "" => PasteType::Noop,
+            _ => PasteType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PasteType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PasteType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PasteType {
     fn default() -> PasteType {
         PasteType::Noop
@@ -3910,7 +4941,8 @@ pub struct CutPasteRequest {
 /**
 * The error code.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ErrorCode {
     #[serde(rename = "CONCURRENT_QUERY")]
     ConcurrentQuery,
@@ -3950,8 +4982,9 @@ pub enum ErrorCode {
     UnsupportedDataType,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -3976,12 +5009,61 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::TooManyRows => "TOO_MANY_ROWS",
             ErrorCode::UnsupportedDataType => "UNSUPPORTED_DATA_TYPE",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ErrorCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CONCURRENT_QUERY" => ErrorCode::ConcurrentQuery,
+            "DATA_EXECUTION_ERROR_CODE_UNSPECIFIED" => ErrorCode::DataExecutionErrorCodeUnspecified,
+            "DATA_NOT_FOUND" => ErrorCode::DataNotFound,
+            "DUPLICATE_COLUMN_NAMES" => ErrorCode::DuplicateColumnNames,
+            "ENGINE" => ErrorCode::Engine,
+            "INTERRUPTED" => ErrorCode::Interrupted,
+            "MISSING_COLUMN_ALIAS" => ErrorCode::MissingColumnAlias,
+            "OBJECT_IN_ERROR_STATE" => ErrorCode::ObjectInErrorState,
+            "OBJECT_NOT_FOUND" => ErrorCode::ObjectNotFound,
+            "OBJECT_SPEC_INVALID" => ErrorCode::ObjectSpecInvalid,
+            "OTHER" => ErrorCode::Other,
+            "PARAMETER_INVALID" => ErrorCode::ParameterInvalid,
+            "PERMISSION_DENIED" => ErrorCode::PermissionDenied,
+            "TIMED_OUT" => ErrorCode::TimedOut,
+            "TOO_MANY_CELLS" => ErrorCode::TooManyCells,
+            "TOO_MANY_CHARS_PER_CELL" => ErrorCode::TooManyCharsPerCell,
+            "TOO_MANY_ROWS" => ErrorCode::TooManyRows,
+            "UNSUPPORTED_DATA_TYPE" => ErrorCode::UnsupportedDataType,
+            "" => ErrorCode::Noop,
+            _ => ErrorCode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ErrorCode {
     fn default() -> ErrorCode {
         ErrorCode::Noop
@@ -3996,7 +5078,8 @@ impl ErrorCode {
 /**
 * The state of the data execution.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum State {
     #[serde(rename = "DATA_EXECUTION_STATE_UNSPECIFIED")]
     DataExecutionStateUnspecified,
@@ -4010,8 +5093,9 @@ pub enum State {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for State {
@@ -4023,12 +5107,48 @@ impl std::fmt::Display for State {
             State::Running => "RUNNING",
             State::Succeeded => "SUCCEEDED",
             State::Noop => "",
-            State::FallthroughString => "*",
+            State::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for State {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DATA_EXECUTION_STATE_UNSPECIFIED" => State::DataExecutionStateUnspecified,
+            "FAILED" => State::Failed,
+            "NOT_STARTED" => State::NotStarted,
+            "RUNNING" => State::Running,
+            "SUCCEEDED" => State::Succeeded,
+            "" => State::Noop,
+            _ => State::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for State {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for State {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for State {
     fn default() -> State {
         State::Noop
@@ -4139,7 +5259,8 @@ pub struct DataFilterValueRange {
 /**
 * The placement of the data label relative to the labeled data.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Placement {
     #[serde(rename = "ABOVE")]
     Above,
@@ -4161,8 +5282,9 @@ pub enum Placement {
     Right,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Placement {
@@ -4178,12 +5300,52 @@ impl std::fmt::Display for Placement {
             Placement::OutsideEnd => "OUTSIDE_END",
             Placement::Right => "RIGHT",
             Placement::Noop => "",
-            Placement::FallthroughString => "*",
+            Placement::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Placement {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ABOVE" => Placement::Above,
+            "BELOW" => Placement::Below,
+            "CENTER" => Placement::Center,
+            "DATA_LABEL_PLACEMENT_UNSPECIFIED" => Placement::DataLabelPlacementUnspecified,
+            "INSIDE_BASE" => Placement::InsideBase,
+            "INSIDE_END" => Placement::InsideEnd,
+            "LEFT" => Placement::Left,
+            "OUTSIDE_END" => Placement::OutsideEnd,
+            "RIGHT" => Placement::Right,
+            "" => Placement::Noop,
+            _ => Placement::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Placement {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Placement {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Placement {
     fn default() -> Placement {
         Placement::Noop
@@ -4198,7 +5360,8 @@ impl Placement {
 /**
 * The type of the data label.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DataLabelType {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -4210,8 +5373,9 @@ pub enum DataLabelType {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DataLabelType {
@@ -4222,12 +5386,47 @@ impl std::fmt::Display for DataLabelType {
             DataLabelType::DataLabelTypeUnspecified => "DATA_LABEL_TYPE_UNSPECIFIED",
             DataLabelType::None => "NONE",
             DataLabelType::Noop => "",
-            DataLabelType::FallthroughString => "*",
+            DataLabelType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DataLabelType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CUSTOM" => DataLabelType::Custom,
+            "DATA" => DataLabelType::Data,
+            "DATA_LABEL_TYPE_UNSPECIFIED" => DataLabelType::DataLabelTypeUnspecified,
+            "NONE" => DataLabelType::None,
+            "" => DataLabelType::Noop,
+            _ => DataLabelType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DataLabelType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DataLabelType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DataLabelType {
     fn default() -> DataLabelType {
         DataLabelType::Noop
@@ -4521,7 +5720,8 @@ pub struct DataSourceRefreshMonthlySchedule {
 /**
 * The scope of the refresh. Must be ALL_DATA_SOURCES.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RefreshScope {
     #[serde(rename = "ALL_DATA_SOURCES")]
     AllDataSources,
@@ -4529,8 +5729,9 @@ pub enum RefreshScope {
     DataSourceRefreshScopeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RefreshScope {
@@ -4541,12 +5742,47 @@ impl std::fmt::Display for RefreshScope {
                 "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED"
             }
             RefreshScope::Noop => "",
-            RefreshScope::FallthroughString => "*",
+            RefreshScope::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RefreshScope {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ALL_DATA_SOURCES" => RefreshScope::AllDataSources,
+            "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED" => {
+                RefreshScope::DataSourceRefreshScopeUnspecified
+            }
+            "" => RefreshScope::Noop,
+            _ => RefreshScope::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RefreshScope {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RefreshScope {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RefreshScope {
     fn default() -> RefreshScope {
         RefreshScope::Noop
@@ -4612,7 +5848,8 @@ pub struct DataSourceRefreshSchedule {
     pub weekly_schedule: Option<DataSourceRefreshWeeklySchedule>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DaysOfWeek {
     #[serde(rename = "DAY_OF_WEEK_UNSPECIFIED")]
     DayOfWeekUnspecified,
@@ -4632,8 +5869,9 @@ pub enum DaysOfWeek {
     Wednesday,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DaysOfWeek {
@@ -4648,12 +5886,51 @@ impl std::fmt::Display for DaysOfWeek {
             DaysOfWeek::Tuesday => "TUESDAY",
             DaysOfWeek::Wednesday => "WEDNESDAY",
             DaysOfWeek::Noop => "",
-            DaysOfWeek::FallthroughString => "*",
+            DaysOfWeek::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DaysOfWeek {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DAY_OF_WEEK_UNSPECIFIED" => DaysOfWeek::DayOfWeekUnspecified,
+            "FRIDAY" => DaysOfWeek::Friday,
+            "MONDAY" => DaysOfWeek::Monday,
+            "SATURDAY" => DaysOfWeek::Saturday,
+            "SUNDAY" => DaysOfWeek::Sunday,
+            "THURSDAY" => DaysOfWeek::Thursday,
+            "TUESDAY" => DaysOfWeek::Tuesday,
+            "WEDNESDAY" => DaysOfWeek::Wednesday,
+            "" => DaysOfWeek::Noop,
+            _ => DaysOfWeek::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DaysOfWeek {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DaysOfWeek {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DaysOfWeek {
     fn default() -> DaysOfWeek {
         DaysOfWeek::Noop
@@ -4765,7 +6042,8 @@ pub struct DataSourceSpec {
 /**
 * The type to select columns for the data source table. Defaults to SELECTED.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ColumnSelectionType {
     #[serde(rename = "DATA_SOURCE_TABLE_COLUMN_SELECTION_TYPE_UNSPECIFIED")]
     DataSourceTableColumnSelectionTypeUnspecified,
@@ -4775,8 +6053,9 @@ pub enum ColumnSelectionType {
     SyncAll,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ColumnSelectionType {
@@ -4788,12 +6067,48 @@ impl std::fmt::Display for ColumnSelectionType {
             ColumnSelectionType::Selected => "SELECTED",
             ColumnSelectionType::SyncAll => "SYNC_ALL",
             ColumnSelectionType::Noop => "",
-            ColumnSelectionType::FallthroughString => "*",
+            ColumnSelectionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ColumnSelectionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DATA_SOURCE_TABLE_COLUMN_SELECTION_TYPE_UNSPECIFIED" => {
+                ColumnSelectionType::DataSourceTableColumnSelectionTypeUnspecified
+            }
+            "SELECTED" => ColumnSelectionType::Selected,
+            "SYNC_ALL" => ColumnSelectionType::SyncAll,
+            "" => ColumnSelectionType::Noop,
+            _ => ColumnSelectionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ColumnSelectionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ColumnSelectionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ColumnSelectionType {
     fn default() -> ColumnSelectionType {
         ColumnSelectionType::Noop
@@ -4917,7 +6232,8 @@ pub struct DataValidationRule {
 /**
 * The type of date-time grouping to apply.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DateTimeRuleType {
     #[serde(rename = "DATE_TIME_RULE_TYPE_UNSPECIFIED")]
     DateTimeRuleTypeUnspecified,
@@ -4953,8 +6269,9 @@ pub enum DateTimeRuleType {
     YearQuarter,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DateTimeRuleType {
@@ -4977,12 +6294,59 @@ impl std::fmt::Display for DateTimeRuleType {
             DateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
             DateTimeRuleType::YearQuarter => "YEAR_QUARTER",
             DateTimeRuleType::Noop => "",
-            DateTimeRuleType::FallthroughString => "*",
+            DateTimeRuleType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DateTimeRuleType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DATE_TIME_RULE_TYPE_UNSPECIFIED" => DateTimeRuleType::DateTimeRuleTypeUnspecified,
+            "DAY_MONTH" => DateTimeRuleType::DayMonth,
+            "DAY_OF_MONTH" => DateTimeRuleType::DayOfMonth,
+            "DAY_OF_WEEK" => DateTimeRuleType::DayOfWeek,
+            "DAY_OF_YEAR" => DateTimeRuleType::DayOfYear,
+            "HOUR" => DateTimeRuleType::Hour,
+            "HOUR_MINUTE" => DateTimeRuleType::HourMinute,
+            "HOUR_MINUTE_AMPM" => DateTimeRuleType::HourMinuteAmpm,
+            "MINUTE" => DateTimeRuleType::Minute,
+            "MONTH" => DateTimeRuleType::Month,
+            "QUARTER" => DateTimeRuleType::Quarter,
+            "SECOND" => DateTimeRuleType::Second,
+            "YEAR" => DateTimeRuleType::Year,
+            "YEAR_MONTH" => DateTimeRuleType::YearMonth,
+            "YEAR_MONTH_DAY" => DateTimeRuleType::YearMonthDay,
+            "YEAR_QUARTER" => DateTimeRuleType::YearQuarter,
+            "" => DateTimeRuleType::Noop,
+            _ => DateTimeRuleType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DateTimeRuleType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateTimeRuleType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DateTimeRuleType {
     fn default() -> DateTimeRuleType {
         DateTimeRuleType::Noop
@@ -5264,7 +6628,8 @@ pub struct DeleteSheetRequest {
 /**
 * The metadata visibility. Developer metadata must always have a visibility specified.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Visibility {
     #[serde(rename = "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED")]
     DeveloperMetadataVisibilityUnspecified,
@@ -5274,8 +6639,9 @@ pub enum Visibility {
     Project,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -5287,12 +6653,48 @@ impl std::fmt::Display for Visibility {
             Visibility::Document => "DOCUMENT",
             Visibility::Project => "PROJECT",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Visibility {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED" => {
+                Visibility::DeveloperMetadataVisibilityUnspecified
+            }
+            "DOCUMENT" => Visibility::Document,
+            "PROJECT" => Visibility::Project,
+            "" => Visibility::Noop,
+            _ => Visibility::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Visibility {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Visibility {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Visibility {
     fn default() -> Visibility {
         Visibility::Noop
@@ -5352,7 +6754,8 @@ pub struct DeveloperMetadata {
 /**
 * The type of location this object represents. This field is read-only.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LocationType {
     #[serde(rename = "COLUMN")]
     Column,
@@ -5366,8 +6769,9 @@ pub enum LocationType {
     Spreadsheet,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LocationType {
@@ -5381,12 +6785,50 @@ impl std::fmt::Display for LocationType {
             LocationType::Sheet => "SHEET",
             LocationType::Spreadsheet => "SPREADSHEET",
             LocationType::Noop => "",
-            LocationType::FallthroughString => "*",
+            LocationType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LocationType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COLUMN" => LocationType::Column,
+            "DEVELOPER_METADATA_LOCATION_TYPE_UNSPECIFIED" => {
+                LocationType::DeveloperMetadataLocationTypeUnspecified
+            }
+            "ROW" => LocationType::Row,
+            "SHEET" => LocationType::Sheet,
+            "SPREADSHEET" => LocationType::Spreadsheet,
+            "" => LocationType::Noop,
+            _ => LocationType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LocationType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LocationType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LocationType {
     fn default() -> LocationType {
         LocationType::Noop
@@ -5442,7 +6884,8 @@ pub struct DeveloperMetadataLocation {
 /**
 * Determines how this lookup matches the location. If this field is specified as EXACT, only developer metadata associated on the exact location specified is matched. If this field is specified to INTERSECTING, developer metadata associated on intersecting locations is also matched. If left unspecified, this field assumes a default value of INTERSECTING. If this field is specified, a metadataLocation must also be specified.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LocationMatchingStrategy {
     #[serde(rename = "DEVELOPER_METADATA_LOCATION_MATCHING_STRATEGY_UNSPECIFIED")]
     DeveloperMetadataLocationMatchingStrategyUnspecified,
@@ -5452,8 +6895,9 @@ pub enum LocationMatchingStrategy {
     IntersectingLocation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LocationMatchingStrategy {
@@ -5465,12 +6909,48 @@ impl std::fmt::Display for LocationMatchingStrategy {
             LocationMatchingStrategy::ExactLocation => "EXACT_LOCATION",
             LocationMatchingStrategy::IntersectingLocation => "INTERSECTING_LOCATION",
             LocationMatchingStrategy::Noop => "",
-            LocationMatchingStrategy::FallthroughString => "*",
+            LocationMatchingStrategy::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LocationMatchingStrategy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DEVELOPER_METADATA_LOCATION_MATCHING_STRATEGY_UNSPECIFIED" => {
+                LocationMatchingStrategy::DeveloperMetadataLocationMatchingStrategyUnspecified
+            }
+            "EXACT_LOCATION" => LocationMatchingStrategy::ExactLocation,
+            "INTERSECTING_LOCATION" => LocationMatchingStrategy::IntersectingLocation,
+            "" => LocationMatchingStrategy::Noop,
+            _ => LocationMatchingStrategy::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LocationMatchingStrategy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LocationMatchingStrategy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LocationMatchingStrategy {
     fn default() -> LocationMatchingStrategy {
         LocationMatchingStrategy::Noop
@@ -5865,7 +7345,8 @@ pub struct EmbeddedObjectPosition {
 /**
 * The type of error.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ErrorValueType {
     #[serde(rename = "DIVIDE_BY_ZERO")]
     DivideByZero,
@@ -5889,8 +7370,9 @@ pub enum ErrorValueType {
     Value,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ErrorValueType {
@@ -5907,12 +7389,53 @@ impl std::fmt::Display for ErrorValueType {
             ErrorValueType::Ref => "REF",
             ErrorValueType::Value => "VALUE",
             ErrorValueType::Noop => "",
-            ErrorValueType::FallthroughString => "*",
+            ErrorValueType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ErrorValueType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DIVIDE_BY_ZERO" => ErrorValueType::DivideByZero,
+            "ERROR" => ErrorValueType::Error,
+            "ERROR_TYPE_UNSPECIFIED" => ErrorValueType::ErrorTypeUnspecified,
+            "LOADING" => ErrorValueType::Loading,
+            "NAME" => ErrorValueType::Name,
+            "NULL_VALUE" => ErrorValueType::NullValue,
+            "NUM" => ErrorValueType::Num,
+            "N_A" => ErrorValueType::NA,
+            "REF" => ErrorValueType::Ref,
+            "VALUE" => ErrorValueType::Value,
+            "" => ErrorValueType::Noop,
+            _ => ErrorValueType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ErrorValueType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorValueType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ErrorValueType {
     fn default() -> ErrorValueType {
         ErrorValueType::Noop
@@ -6543,7 +8066,8 @@ pub struct GridRange {
 /**
 * The position of the chart legend.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum HistogramChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -6561,8 +8085,9 @@ pub enum HistogramChartSpecLegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for HistogramChartSpecLegendPosition {
@@ -6578,12 +8103,52 @@ impl std::fmt::Display for HistogramChartSpecLegendPosition {
             HistogramChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             HistogramChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             HistogramChartSpecLegendPosition::Noop => "",
-            HistogramChartSpecLegendPosition::FallthroughString => "*",
+            HistogramChartSpecLegendPosition::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for HistogramChartSpecLegendPosition {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BOTTOM_LEGEND" => HistogramChartSpecLegendPosition::BottomLegend,
+            "HISTOGRAM_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                HistogramChartSpecLegendPosition::HistogramChartLegendPositionUnspecified
+            }
+            "INSIDE_LEGEND" => HistogramChartSpecLegendPosition::InsideLegend,
+            "LEFT_LEGEND" => HistogramChartSpecLegendPosition::LeftLegend,
+            "NO_LEGEND" => HistogramChartSpecLegendPosition::NoLegend,
+            "RIGHT_LEGEND" => HistogramChartSpecLegendPosition::RightLegend,
+            "TOP_LEGEND" => HistogramChartSpecLegendPosition::TopLegend,
+            "" => HistogramChartSpecLegendPosition::Noop,
+            _ => HistogramChartSpecLegendPosition::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for HistogramChartSpecLegendPosition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for HistogramChartSpecLegendPosition {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for HistogramChartSpecLegendPosition {
     fn default() -> HistogramChartSpecLegendPosition {
         HistogramChartSpecLegendPosition::Noop
@@ -6744,7 +8309,8 @@ pub struct InsertRangeRequest {
 /**
 * How the value should be interpreted.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum InterpolationPointType {
     #[serde(rename = "INTERPOLATION_POINT_TYPE_UNSPECIFIED")]
     InterpolationPointTypeUnspecified,
@@ -6760,8 +8326,9 @@ pub enum InterpolationPointType {
     Percentile,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for InterpolationPointType {
@@ -6776,12 +8343,51 @@ impl std::fmt::Display for InterpolationPointType {
             InterpolationPointType::Percent => "PERCENT",
             InterpolationPointType::Percentile => "PERCENTILE",
             InterpolationPointType::Noop => "",
-            InterpolationPointType::FallthroughString => "*",
+            InterpolationPointType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for InterpolationPointType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "INTERPOLATION_POINT_TYPE_UNSPECIFIED" => {
+                InterpolationPointType::InterpolationPointTypeUnspecified
+            }
+            "MAX" => InterpolationPointType::Max,
+            "MIN" => InterpolationPointType::Min,
+            "NUMBER" => InterpolationPointType::Number,
+            "PERCENT" => InterpolationPointType::Percent,
+            "PERCENTILE" => InterpolationPointType::Percentile,
+            "" => InterpolationPointType::Noop,
+            _ => InterpolationPointType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for InterpolationPointType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for InterpolationPointType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for InterpolationPointType {
     fn default() -> InterpolationPointType {
         InterpolationPointType::Noop
@@ -6898,7 +8504,8 @@ pub struct KeyValueFormat {
 /**
 * The dash type of the line.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LineStyleType {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -6920,8 +8527,9 @@ pub enum LineStyleType {
     Solid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LineStyleType {
@@ -6937,12 +8545,52 @@ impl std::fmt::Display for LineStyleType {
             LineStyleType::MediumDashedDotted => "MEDIUM_DASHED_DOTTED",
             LineStyleType::Solid => "SOLID",
             LineStyleType::Noop => "",
-            LineStyleType::FallthroughString => "*",
+            LineStyleType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LineStyleType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CUSTOM" => LineStyleType::Custom,
+            "DOTTED" => LineStyleType::Dotted,
+            "INVISIBLE" => LineStyleType::Invisible,
+            "LINE_DASH_TYPE_UNSPECIFIED" => LineStyleType::LineDashTypeUnspecified,
+            "LONG_DASHED" => LineStyleType::LongDashed,
+            "LONG_DASHED_DOTTED" => LineStyleType::LongDashedDotted,
+            "MEDIUM_DASHED" => LineStyleType::MediumDashed,
+            "MEDIUM_DASHED_DOTTED" => LineStyleType::MediumDashedDotted,
+            "SOLID" => LineStyleType::Solid,
+            "" => LineStyleType::Noop,
+            _ => LineStyleType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LineStyleType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LineStyleType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LineStyleType {
     fn default() -> LineStyleType {
         LineStyleType::Noop
@@ -7071,7 +8719,8 @@ pub struct MatchedValueRange {
 /**
 * How the cells should be merged.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MergeType {
     #[serde(rename = "MERGE_ALL")]
     MergeAll,
@@ -7081,8 +8730,9 @@ pub enum MergeType {
     MergeRows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MergeType {
@@ -7092,12 +8742,46 @@ impl std::fmt::Display for MergeType {
             MergeType::MergeColumns => "MERGE_COLUMNS",
             MergeType::MergeRows => "MERGE_ROWS",
             MergeType::Noop => "",
-            MergeType::FallthroughString => "*",
+            MergeType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MergeType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "MERGE_ALL" => MergeType::MergeAll,
+            "MERGE_COLUMNS" => MergeType::MergeColumns,
+            "MERGE_ROWS" => MergeType::MergeRows,
+            "" => MergeType::Noop,
+            _ => MergeType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MergeType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MergeType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MergeType {
     fn default() -> MergeType {
         MergeType::Noop
@@ -7176,7 +8860,8 @@ pub struct NamedRange {
 /**
 * The type of the number format. When writing, this field must be set.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum NumberFormatType {
     #[serde(rename = "CURRENCY")]
     Currency,
@@ -7198,8 +8883,9 @@ pub enum NumberFormatType {
     Time,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for NumberFormatType {
@@ -7215,12 +8901,52 @@ impl std::fmt::Display for NumberFormatType {
             NumberFormatType::Text => "TEXT",
             NumberFormatType::Time => "TIME",
             NumberFormatType::Noop => "",
-            NumberFormatType::FallthroughString => "*",
+            NumberFormatType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for NumberFormatType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CURRENCY" => NumberFormatType::Currency,
+            "DATE" => NumberFormatType::Date,
+            "DATE_TIME" => NumberFormatType::DateTime,
+            "NUMBER" => NumberFormatType::Number,
+            "NUMBER_FORMAT_TYPE_UNSPECIFIED" => NumberFormatType::NumberFormatTypeUnspecified,
+            "PERCENT" => NumberFormatType::Percent,
+            "SCIENTIFIC" => NumberFormatType::Scientific,
+            "TEXT" => NumberFormatType::Text,
+            "TIME" => NumberFormatType::Time,
+            "" => NumberFormatType::Noop,
+            _ => NumberFormatType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NumberFormatType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for NumberFormatType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for NumberFormatType {
     fn default() -> NumberFormatType {
         NumberFormatType::Noop
@@ -7254,7 +8980,8 @@ pub struct NumberFormat {
 /**
 * The size of the org chart nodes.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum NodeSize {
     #[serde(rename = "LARGE")]
     Large,
@@ -7266,8 +8993,9 @@ pub enum NodeSize {
     Small,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for NodeSize {
@@ -7278,12 +9006,47 @@ impl std::fmt::Display for NodeSize {
             NodeSize::OrgChartLabelSizeUnspecified => "ORG_CHART_LABEL_SIZE_UNSPECIFIED",
             NodeSize::Small => "SMALL",
             NodeSize::Noop => "",
-            NodeSize::FallthroughString => "*",
+            NodeSize::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for NodeSize {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "LARGE" => NodeSize::Large,
+            "MEDIUM" => NodeSize::Medium,
+            "ORG_CHART_LABEL_SIZE_UNSPECIFIED" => NodeSize::OrgChartLabelSizeUnspecified,
+            "SMALL" => NodeSize::Small,
+            "" => NodeSize::Noop,
+            _ => NodeSize::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NodeSize {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for NodeSize {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for NodeSize {
     fn default() -> NodeSize {
         NodeSize::Noop
@@ -7492,7 +9255,8 @@ pub struct PasteDataRequest {
 /**
 * Where the legend of the pie chart should be drawn.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PieChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -7510,8 +9274,9 @@ pub enum PieChartSpecLegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PieChartSpecLegendPosition {
@@ -7527,12 +9292,52 @@ impl std::fmt::Display for PieChartSpecLegendPosition {
             PieChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             PieChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             PieChartSpecLegendPosition::Noop => "",
-            PieChartSpecLegendPosition::FallthroughString => "*",
+            PieChartSpecLegendPosition::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PieChartSpecLegendPosition {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BOTTOM_LEGEND" => PieChartSpecLegendPosition::BottomLegend,
+            "LABELED_LEGEND" => PieChartSpecLegendPosition::LabeledLegend,
+            "LEFT_LEGEND" => PieChartSpecLegendPosition::LeftLegend,
+            "NO_LEGEND" => PieChartSpecLegendPosition::NoLegend,
+            "PIE_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                PieChartSpecLegendPosition::PieChartLegendPositionUnspecified
+            }
+            "RIGHT_LEGEND" => PieChartSpecLegendPosition::RightLegend,
+            "TOP_LEGEND" => PieChartSpecLegendPosition::TopLegend,
+            "" => PieChartSpecLegendPosition::Noop,
+            _ => PieChartSpecLegendPosition::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PieChartSpecLegendPosition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PieChartSpecLegendPosition {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PieChartSpecLegendPosition {
     fn default() -> PieChartSpecLegendPosition {
         PieChartSpecLegendPosition::Noop
@@ -7652,7 +9457,8 @@ pub struct PivotFilterSpec {
 /**
 * The order the values in this group should be sorted.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SortOrder {
     #[serde(rename = "ASCENDING")]
     Ascending,
@@ -7662,8 +9468,9 @@ pub enum SortOrder {
     SortOrderUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -7673,12 +9480,46 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Descending => "DESCENDING",
             SortOrder::SortOrderUnspecified => "SORT_ORDER_UNSPECIFIED",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SortOrder {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ASCENDING" => SortOrder::Ascending,
+            "DESCENDING" => SortOrder::Descending,
+            "SORT_ORDER_UNSPECIFIED" => SortOrder::SortOrderUnspecified,
+            "" => SortOrder::Noop,
+            _ => SortOrder::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SortOrder {
     fn default() -> SortOrder {
         SortOrder::Noop
@@ -7881,7 +9722,8 @@ pub struct PivotGroupValueMetadata {
 /**
 * Whether values should be listed horizontally (as columns) or vertically (as rows).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ValueLayout {
     #[serde(rename = "HORIZONTAL")]
     Horizontal,
@@ -7889,8 +9731,9 @@ pub enum ValueLayout {
     Vertical,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ValueLayout {
@@ -7899,12 +9742,45 @@ impl std::fmt::Display for ValueLayout {
             ValueLayout::Horizontal => "HORIZONTAL",
             ValueLayout::Vertical => "VERTICAL",
             ValueLayout::Noop => "",
-            ValueLayout::FallthroughString => "*",
+            ValueLayout::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ValueLayout {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "HORIZONTAL" => ValueLayout::Horizontal,
+            "VERTICAL" => ValueLayout::Vertical,
+            "" => ValueLayout::Noop,
+            _ => ValueLayout::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ValueLayout {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ValueLayout {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ValueLayout {
     fn default() -> ValueLayout {
         ValueLayout::Noop
@@ -7999,7 +9875,8 @@ pub struct PivotTable {
 /**
 * If specified, indicates that pivot values should be displayed as the result of a calculation with another pivot value. For example, if calculated_display_type is specified as PERCENT_OF_GRAND_TOTAL, all the pivot values are displayed as the percentage of the grand total. In the Sheets editor, this is referred to as "Show As" in the value section of a pivot table.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CalculatedDisplayType {
     #[serde(rename = "PERCENT_OF_COLUMN_TOTAL")]
     PercentOfColumnTotal,
@@ -8011,8 +9888,9 @@ pub enum CalculatedDisplayType {
     PivotValueCalculatedDisplayTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CalculatedDisplayType {
@@ -8025,12 +9903,49 @@ impl std::fmt::Display for CalculatedDisplayType {
                 "PIVOT_VALUE_CALCULATED_DISPLAY_TYPE_UNSPECIFIED"
             }
             CalculatedDisplayType::Noop => "",
-            CalculatedDisplayType::FallthroughString => "*",
+            CalculatedDisplayType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CalculatedDisplayType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "PERCENT_OF_COLUMN_TOTAL" => CalculatedDisplayType::PercentOfColumnTotal,
+            "PERCENT_OF_GRAND_TOTAL" => CalculatedDisplayType::PercentOfGrandTotal,
+            "PERCENT_OF_ROW_TOTAL" => CalculatedDisplayType::PercentOfRowTotal,
+            "PIVOT_VALUE_CALCULATED_DISPLAY_TYPE_UNSPECIFIED" => {
+                CalculatedDisplayType::PivotValueCalculatedDisplayTypeUnspecified
+            }
+            "" => CalculatedDisplayType::Noop,
+            _ => CalculatedDisplayType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CalculatedDisplayType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CalculatedDisplayType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CalculatedDisplayType {
     fn default() -> CalculatedDisplayType {
         CalculatedDisplayType::Noop
@@ -8045,7 +9960,8 @@ impl CalculatedDisplayType {
 /**
 * A function to summarize the value. If formula is set, the only supported values are SUM and CUSTOM. If sourceColumnOffset is set, then `CUSTOM` is not supported.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SummarizeFunction {
     #[serde(rename = "AVERAGE")]
     Average,
@@ -8079,8 +9995,9 @@ pub enum SummarizeFunction {
     Varp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SummarizeFunction {
@@ -8104,12 +10021,60 @@ impl std::fmt::Display for SummarizeFunction {
             SummarizeFunction::Var => "VAR",
             SummarizeFunction::Varp => "VARP",
             SummarizeFunction::Noop => "",
-            SummarizeFunction::FallthroughString => "*",
+            SummarizeFunction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SummarizeFunction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AVERAGE" => SummarizeFunction::Average,
+            "COUNT" => SummarizeFunction::Count,
+            "COUNTA" => SummarizeFunction::Counta,
+            "COUNTUNIQUE" => SummarizeFunction::Countunique,
+            "CUSTOM" => SummarizeFunction::Custom,
+            "MAX" => SummarizeFunction::Max,
+            "MEDIAN" => SummarizeFunction::Median,
+            "MIN" => SummarizeFunction::Min,
+            "PIVOT_STANDARD_VALUE_FUNCTION_UNSPECIFIED" => {
+                SummarizeFunction::PivotStandardValueFunctionUnspecified
+            }
+            "PRODUCT" => SummarizeFunction::Product,
+            "STDEV" => SummarizeFunction::Stdev,
+            "STDEVP" => SummarizeFunction::Stdevp,
+            "SUM" => SummarizeFunction::Sum,
+            "VAR" => SummarizeFunction::Var,
+            "VARP" => SummarizeFunction::Varp,
+            "" => SummarizeFunction::Noop,
+            _ => SummarizeFunction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SummarizeFunction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SummarizeFunction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SummarizeFunction {
     fn default() -> SummarizeFunction {
         SummarizeFunction::Noop
@@ -8184,7 +10149,8 @@ pub struct PivotValue {
 /**
 * The point shape. If empty or unspecified, a default shape is used.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Shape {
     #[serde(rename = "CIRCLE")]
     Circle,
@@ -8206,8 +10172,9 @@ pub enum Shape {
     XMark,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Shape {
@@ -8223,12 +10190,52 @@ impl std::fmt::Display for Shape {
             Shape::Triangle => "TRIANGLE",
             Shape::XMark => "X_MARK",
             Shape::Noop => "",
-            Shape::FallthroughString => "*",
+            Shape::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Shape {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CIRCLE" => Shape::Circle,
+            "DIAMOND" => Shape::Diamond,
+            "HEXAGON" => Shape::Hexagon,
+            "PENTAGON" => Shape::Pentagon,
+            "POINT_SHAPE_UNSPECIFIED" => Shape::PointShapeUnspecified,
+            "SQUARE" => Shape::Square,
+            "STAR" => Shape::Star,
+            "TRIANGLE" => Shape::Triangle,
+            "X_MARK" => Shape::XMark,
+            "" => Shape::Noop,
+            _ => Shape::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Shape {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Shape {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Shape {
     fn default() -> Shape {
         Shape::Noop
@@ -9203,7 +11210,8 @@ pub struct RowData {
 /**
 * The number format source used in the scorecard chart. This field is optional.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum NumberFormatSource {
     #[serde(rename = "CHART_NUMBER_FORMAT_SOURCE_UNDEFINED")]
     ChartNumberFormatSourceUndefined,
@@ -9213,8 +11221,9 @@ pub enum NumberFormatSource {
     FromData,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for NumberFormatSource {
@@ -9226,12 +11235,48 @@ impl std::fmt::Display for NumberFormatSource {
             NumberFormatSource::Custom => "CUSTOM",
             NumberFormatSource::FromData => "FROM_DATA",
             NumberFormatSource::Noop => "",
-            NumberFormatSource::FallthroughString => "*",
+            NumberFormatSource::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for NumberFormatSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CHART_NUMBER_FORMAT_SOURCE_UNDEFINED" => {
+                NumberFormatSource::ChartNumberFormatSourceUndefined
+            }
+            "CUSTOM" => NumberFormatSource::Custom,
+            "FROM_DATA" => NumberFormatSource::FromData,
+            "" => NumberFormatSource::Noop,
+            _ => NumberFormatSource::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NumberFormatSource {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for NumberFormatSource {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for NumberFormatSource {
     fn default() -> NumberFormatSource {
         NumberFormatSource::Noop
@@ -9504,7 +11549,8 @@ pub struct Sheet {
 /**
 * The type of sheet. Defaults to GRID. This field cannot be changed once set.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SheetType {
     #[serde(rename = "DATA_SOURCE")]
     DataSource,
@@ -9516,8 +11562,9 @@ pub enum SheetType {
     SheetTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SheetType {
@@ -9528,12 +11575,47 @@ impl std::fmt::Display for SheetType {
             SheetType::Object => "OBJECT",
             SheetType::SheetTypeUnspecified => "SHEET_TYPE_UNSPECIFIED",
             SheetType::Noop => "",
-            SheetType::FallthroughString => "*",
+            SheetType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SheetType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DATA_SOURCE" => SheetType::DataSource,
+            "GRID" => SheetType::Grid,
+            "OBJECT" => SheetType::Object,
+            "SHEET_TYPE_UNSPECIFIED" => SheetType::SheetTypeUnspecified,
+            "" => SheetType::Noop,
+            _ => SheetType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SheetType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SheetType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SheetType {
     fn default() -> SheetType {
         SheetType::Noop
@@ -9932,7 +12014,8 @@ pub struct Spreadsheet {
 /**
 * The amount of time to wait before volatile functions are recalculated.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AutoRecalc {
     #[serde(rename = "HOUR")]
     Hour,
@@ -9944,8 +12027,9 @@ pub enum AutoRecalc {
     RecalculationIntervalUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AutoRecalc {
@@ -9956,12 +12040,47 @@ impl std::fmt::Display for AutoRecalc {
             AutoRecalc::OnChange => "ON_CHANGE",
             AutoRecalc::RecalculationIntervalUnspecified => "RECALCULATION_INTERVAL_UNSPECIFIED",
             AutoRecalc::Noop => "",
-            AutoRecalc::FallthroughString => "*",
+            AutoRecalc::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AutoRecalc {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "HOUR" => AutoRecalc::Hour,
+            "MINUTE" => AutoRecalc::Minute,
+            "ON_CHANGE" => AutoRecalc::OnChange,
+            "RECALCULATION_INTERVAL_UNSPECIFIED" => AutoRecalc::RecalculationIntervalUnspecified,
+            "" => AutoRecalc::Noop,
+            _ => AutoRecalc::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AutoRecalc {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AutoRecalc {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AutoRecalc {
     fn default() -> AutoRecalc {
         AutoRecalc::Noop
@@ -10206,7 +12325,8 @@ pub struct TextRotation {
 /**
 * The delimiter type to use.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DelimiterType {
     #[serde(rename = "AUTODETECT")]
     Autodetect,
@@ -10224,8 +12344,9 @@ pub enum DelimiterType {
     Space,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DelimiterType {
@@ -10239,12 +12360,50 @@ impl std::fmt::Display for DelimiterType {
             DelimiterType::Semicolon => "SEMICOLON",
             DelimiterType::Space => "SPACE",
             DelimiterType::Noop => "",
-            DelimiterType::FallthroughString => "*",
+            DelimiterType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DelimiterType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AUTODETECT" => DelimiterType::Autodetect,
+            "COMMA" => DelimiterType::Comma,
+            "CUSTOM" => DelimiterType::Custom,
+            "DELIMITER_TYPE_UNSPECIFIED" => DelimiterType::DelimiterTypeUnspecified,
+            "PERIOD" => DelimiterType::Period,
+            "SEMICOLON" => DelimiterType::Semicolon,
+            "SPACE" => DelimiterType::Space,
+            "" => DelimiterType::Noop,
+            _ => DelimiterType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DelimiterType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DelimiterType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DelimiterType {
     fn default() -> DelimiterType {
         DelimiterType::Noop
@@ -11419,7 +13578,8 @@ pub struct WaterfallChartSeries {
 /**
 * The stacked type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WaterfallChartSpecStackedType {
     #[serde(rename = "SEQUENTIAL")]
     Sequential,
@@ -11429,8 +13589,9 @@ pub enum WaterfallChartSpecStackedType {
     WaterfallStackedTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WaterfallChartSpecStackedType {
@@ -11442,12 +13603,48 @@ impl std::fmt::Display for WaterfallChartSpecStackedType {
                 "WATERFALL_STACKED_TYPE_UNSPECIFIED"
             }
             WaterfallChartSpecStackedType::Noop => "",
-            WaterfallChartSpecStackedType::FallthroughString => "*",
+            WaterfallChartSpecStackedType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WaterfallChartSpecStackedType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SEQUENTIAL" => WaterfallChartSpecStackedType::Sequential,
+            "STACKED" => WaterfallChartSpecStackedType::Stacked,
+            "WATERFALL_STACKED_TYPE_UNSPECIFIED" => {
+                WaterfallChartSpecStackedType::WaterfallStackedTypeUnspecified
+            }
+            "" => WaterfallChartSpecStackedType::Noop,
+            _ => WaterfallChartSpecStackedType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WaterfallChartSpecStackedType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WaterfallChartSpecStackedType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WaterfallChartSpecStackedType {
     fn default() -> WaterfallChartSpecStackedType {
         WaterfallChartSpecStackedType::Noop
@@ -11526,7 +13723,8 @@ pub struct WaterfallChartSpec {
 /**
 * V1 error format.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Xgafv {
     #[serde(rename = "1")]
     One,
@@ -11534,8 +13732,9 @@ pub enum Xgafv {
     Two,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -11544,12 +13743,45 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Xgafv {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "1" => Xgafv::One,
+            "2" => Xgafv::Two,
+            "" => Xgafv::Noop,
+            _ => Xgafv::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Xgafv {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Xgafv {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Xgafv {
     fn default() -> Xgafv {
         Xgafv::Noop
@@ -11564,7 +13796,8 @@ impl Xgafv {
 /**
 * Data format for response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -11574,8 +13807,9 @@ pub enum Alt {
     Proto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -11585,12 +13819,46 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Alt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "json" => Alt::Json,
+            "media" => Alt::Media,
+            "proto" => Alt::Proto,
+            "" => Alt::Noop,
+            _ => Alt::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Alt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Alt {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
@@ -11605,7 +13873,8 @@ impl Alt {
 /**
 * How the input data should be inserted.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum InsertDataOption {
     #[serde(rename = "INSERT_ROWS")]
     InsertRows,
@@ -11613,8 +13882,9 @@ pub enum InsertDataOption {
     Overwrite,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for InsertDataOption {
@@ -11623,12 +13893,45 @@ impl std::fmt::Display for InsertDataOption {
             InsertDataOption::InsertRows => "INSERT_ROWS",
             InsertDataOption::Overwrite => "OVERWRITE",
             InsertDataOption::Noop => "",
-            InsertDataOption::FallthroughString => "*",
+            InsertDataOption::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for InsertDataOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "INSERT_ROWS" => InsertDataOption::InsertRows,
+            "OVERWRITE" => InsertDataOption::Overwrite,
+            "" => InsertDataOption::Noop,
+            _ => InsertDataOption::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for InsertDataOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for InsertDataOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for InsertDataOption {
     fn default() -> InsertDataOption {
         InsertDataOption::Noop
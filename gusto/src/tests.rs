@@ -135,9 +135,7 @@ fn test_deserialize_employee() {
             .unwrap()
             .clone()
             .to_string(),
-        // TODO: fix this better.
-        //"Commission Only Exempt".to_string()
-        "*".to_string()
+        "Commission Only Exempt".to_string()
     );
     assert_eq!(
         first_compensation.payment_unit.as_ref().unwrap().clone(),
@@ -591,7 +591,8 @@ pub struct Termination {
 /**
 * The unit accompanying the compensation rate. If the employee is an owner, rate should be 'Paycheck'.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentUnit {
     #[serde(rename = "Hour")]
     Hour,
@@ -605,8 +606,9 @@ pub enum PaymentUnit {
     Year,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentUnit {
@@ -618,12 +620,48 @@ impl std::fmt::Display for PaymentUnit {
             PaymentUnit::Week => "Week",
             PaymentUnit::Year => "Year",
             PaymentUnit::Noop => "",
-            PaymentUnit::FallthroughString => "*",
+            PaymentUnit::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentUnit {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Hour" => PaymentUnit::Hour,
+            "Month" => PaymentUnit::Month,
+            "Paycheck" => PaymentUnit::Paycheck,
+            "Week" => PaymentUnit::Week,
+            "Year" => PaymentUnit::Year,
+            "" => PaymentUnit::Noop,
+            _ => PaymentUnit::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentUnit {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentUnit {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentUnit {
     fn default() -> PaymentUnit {
         PaymentUnit::Noop
@@ -638,7 +676,8 @@ impl PaymentUnit {
 /**
 * The FLSA status for this compensation. Salaried ('Exempt') employees are paid a fixed salary every pay period. Salaried with overtime ('Salaried Nonexempt') employees are paid a fixed salary every pay period, and receive overtime pay when applicable. Hourly ('Nonexempt') employees are paid for the hours they work, and receive overtime pay when applicable. Owners ('Owner') are employees that own at least twenty percent of the company.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FlsaStatus {
     #[serde(rename = "Exempt")]
     Exempt,
@@ -650,8 +689,9 @@ pub enum FlsaStatus {
     SalariedNonexempt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FlsaStatus {
@@ -662,12 +702,47 @@ impl std::fmt::Display for FlsaStatus {
             FlsaStatus::Owner => "Owner",
             FlsaStatus::SalariedNonexempt => "Salaried Nonexempt",
             FlsaStatus::Noop => "",
-            FlsaStatus::FallthroughString => "*",
+            FlsaStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FlsaStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Exempt" => FlsaStatus::Exempt,
+            "Nonexempt" => FlsaStatus::Nonexempt,
+            "Owner" => FlsaStatus::Owner,
+            "Salaried Nonexempt" => FlsaStatus::SalariedNonexempt,
+            "" => FlsaStatus::Noop,
+            _ => FlsaStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FlsaStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FlsaStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FlsaStatus {
     fn default() -> FlsaStatus {
         FlsaStatus::Noop
@@ -951,7 +1026,8 @@ pub struct Admin {
 /**
 * The tax payer type of the company.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EntityType {
     #[serde(rename = "Association")]
     Association,
@@ -979,8 +1055,9 @@ pub enum EntityType {
     Trusteeship,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EntityType {
@@ -999,12 +1076,55 @@ impl std::fmt::Display for EntityType {
             EntityType::SoleProprietor => "Sole proprietor",
             EntityType::Trusteeship => "Trusteeship",
             EntityType::Noop => "",
-            EntityType::FallthroughString => "*",
+            EntityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EntityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Association" => EntityType::Association,
+            "C-Corporation" => EntityType::CCorporation,
+            "Co-ownership" => EntityType::CoOwnership,
+            "General partnership" => EntityType::GeneralPartnership,
+            "Joint venture" => EntityType::JointVenture,
+            "LLC" => EntityType::Llc,
+            "LLP" => EntityType::Llp,
+            "Limited partnership" => EntityType::LimitedPartnership,
+            "Non-Profit" => EntityType::NonProfit,
+            "S-Corporation" => EntityType::SCorporation,
+            "Sole proprietor" => EntityType::SoleProprietor,
+            "Trusteeship" => EntityType::Trusteeship,
+            "" => EntityType::Noop,
+            _ => EntityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EntityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EntityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EntityType {
     fn default() -> EntityType {
         EntityType::Noop
@@ -1019,7 +1139,8 @@ impl EntityType {
 /**
 * The Gusto product tier of the company.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Tier {
     #[serde(rename = "basic")]
     Basic,
@@ -1033,8 +1154,9 @@ pub enum Tier {
     Core,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Tier {
@@ -1046,12 +1168,48 @@ impl std::fmt::Display for Tier {
             Tier::ContractorOnly => "contractor_only",
             Tier::Core => "core",
             Tier::Noop => "",
-            Tier::FallthroughString => "*",
+            Tier::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Tier {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "basic" => Tier::Basic,
+            "complete" => Tier::Complete,
+            "concierge" => Tier::Concierge,
+            "contractor_only" => Tier::ContractorOnly,
+            "core" => Tier::Core,
+            "" => Tier::Noop,
+            _ => Tier::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Tier {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Tier {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Tier {
     fn default() -> Tier {
         Tier::Noop
@@ -1066,7 +1224,8 @@ impl Tier {
 /**
 * The status of the company in Gusto. "Approved" companies may run payroll with Gusto. "Not Approved" companies may not yet run payroll with Gusto. In order to run payroll, the company may need to complete onboarding or contact support. "Suspended" companies may not run payroll with Gusto. In order to unsuspend their account, the company must contact support.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CompanyStatus {
     #[serde(rename = "Approved")]
     Approved,
@@ -1076,8 +1235,9 @@ pub enum CompanyStatus {
     Suspended,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CompanyStatus {
@@ -1087,12 +1247,46 @@ impl std::fmt::Display for CompanyStatus {
             CompanyStatus::NotApproved => "Not Approved",
             CompanyStatus::Suspended => "Suspended",
             CompanyStatus::Noop => "",
-            CompanyStatus::FallthroughString => "*",
+            CompanyStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CompanyStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Approved" => CompanyStatus::Approved,
+            "Not Approved" => CompanyStatus::NotApproved,
+            "Suspended" => CompanyStatus::Suspended,
+            "" => CompanyStatus::Noop,
+            _ => CompanyStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CompanyStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompanyStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CompanyStatus {
     fn default() -> CompanyStatus {
         CompanyStatus::Noop
@@ -1398,7 +1592,8 @@ pub struct Company {
 /**
 * The contractor's wage type, either "Fixed" or "Hourly".
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WageType {
     #[serde(rename = "Fixed")]
     Fixed,
@@ -1406,8 +1601,9 @@ pub enum WageType {
     Hourly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WageType {
@@ -1416,12 +1612,45 @@ impl std::fmt::Display for WageType {
             WageType::Fixed => "Fixed",
             WageType::Hourly => "Hourly",
             WageType::Noop => "",
-            WageType::FallthroughString => "*",
+            WageType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WageType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Fixed" => WageType::Fixed,
+            "Hourly" => WageType::Hourly,
+            "" => WageType::Noop,
+            _ => WageType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WageType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WageType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WageType {
     fn default() -> WageType {
         WageType::Noop
@@ -1436,7 +1665,8 @@ impl WageType {
 /**
 * The contractor's type, either "Individual" or "Business".
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Type {
     #[serde(rename = "Business")]
     Business,
@@ -1444,8 +1674,9 @@ pub enum Type {
     Individual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Type {
@@ -1454,12 +1685,45 @@ impl std::fmt::Display for Type {
             Type::Business => "Business",
             Type::Individual => "Individual",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Business" => Type::Business,
+            "Individual" => Type::Individual,
+            "" => Type::Noop,
+            _ => Type::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -1651,7 +1915,8 @@ pub struct Contractor {
 /**
 * The payment method.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethod {
     #[serde(rename = "Check")]
     Check,
@@ -1663,8 +1928,9 @@ pub enum PaymentMethod {
     HistoricalPayment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethod {
@@ -1675,12 +1941,47 @@ impl std::fmt::Display for PaymentMethod {
             PaymentMethod::DirectDeposit => "Direct Deposit",
             PaymentMethod::HistoricalPayment => "Historical Payment",
             PaymentMethod::Noop => "",
-            PaymentMethod::FallthroughString => "*",
+            PaymentMethod::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Check" => PaymentMethod::Check,
+            "Correction Payment" => PaymentMethod::CorrectionPayment,
+            "Direct Deposit" => PaymentMethod::DirectDeposit,
+            "Historical Payment" => PaymentMethod::HistoricalPayment,
+            "" => PaymentMethod::Noop,
+            _ => PaymentMethod::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethod {
     fn default() -> PaymentMethod {
         PaymentMethod::Noop
@@ -1874,7 +2175,8 @@ pub struct ContractorPaymentSummary {
 /**
 * The status of the time off request.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Status {
     #[serde(rename = "approved")]
     Approved,
@@ -1884,8 +2186,9 @@ pub enum Status {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1895,12 +2198,46 @@ impl std::fmt::Display for Status {
             Status::Denied => "denied",
             Status::Pending => "pending",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approved" => Status::Approved,
+            "denied" => Status::Denied,
+            "pending" => Status::Pending,
+            "" => Status::Noop,
+            _ => Status::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -1915,7 +2252,8 @@ impl Status {
 /**
 * The type of time off request.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RequestType {
     #[serde(rename = "sick")]
     Sick,
@@ -1923,8 +2261,9 @@ pub enum RequestType {
     Vacation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RequestType {
@@ -1933,12 +2272,45 @@ impl std::fmt::Display for RequestType {
             RequestType::Sick => "sick",
             RequestType::Vacation => "vacation",
             RequestType::Noop => "",
-            RequestType::FallthroughString => "*",
+            RequestType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RequestType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "sick" => RequestType::Sick,
+            "vacation" => RequestType::Vacation,
+            "" => RequestType::Noop,
+            _ => RequestType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RequestType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RequestType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RequestType {
     fn default() -> RequestType {
         RequestType::Noop
@@ -2161,7 +2533,8 @@ pub struct CurrentUser {
     pub roles: Option<Roles>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Frequency {
     #[serde(rename = "Every other week")]
     EveryOtherWeek,
@@ -2173,8 +2546,9 @@ pub enum Frequency {
     TwicePerMonth,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Frequency {
@@ -2185,12 +2559,47 @@ impl std::fmt::Display for Frequency {
             Frequency::Monthly => "Monthly",
             Frequency::TwicePerMonth => "Twice per month",
             Frequency::Noop => "",
-            Frequency::FallthroughString => "*",
+            Frequency::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Frequency {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Every other week" => Frequency::EveryOtherWeek,
+            "Every week" => Frequency::EveryWeek,
+            "Monthly" => Frequency::Monthly,
+            "Twice per month" => Frequency::TwicePerMonth,
+            "" => Frequency::Noop,
+            _ => Frequency::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Frequency {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Frequency {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Frequency {
     fn default() -> Frequency {
         Frequency::Noop
@@ -2274,7 +2683,8 @@ pub struct PaySchedule {
 /**
 * Bank account type
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AccountType {
     #[serde(rename = "Checking")]
     Checking,
@@ -2282,8 +2692,9 @@ pub enum AccountType {
     Savings,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AccountType {
@@ -2292,12 +2703,45 @@ impl std::fmt::Display for AccountType {
             AccountType::Checking => "Checking",
             AccountType::Savings => "Savings",
             AccountType::Noop => "",
-            AccountType::FallthroughString => "*",
+            AccountType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AccountType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Checking" => AccountType::Checking,
+            "Savings" => AccountType::Savings,
+            "" => AccountType::Noop,
+            _ => AccountType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AccountType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AccountType {
     fn default() -> AccountType {
         AccountType::Noop
@@ -2316,7 +2760,8 @@ impl AccountType {
 *   'ready_for_verification' means the micro-deposits are completed and the verification process can begin by using the verify endpoint.
 *   'verified' means the bank account is verified.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum VerificationStatus {
     #[serde(rename = "awaiting_deposits")]
     AwaitingDeposits,
@@ -2326,8 +2771,9 @@ pub enum VerificationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for VerificationStatus {
@@ -2337,12 +2783,46 @@ impl std::fmt::Display for VerificationStatus {
             VerificationStatus::ReadyForVerification => "ready_for_verification",
             VerificationStatus::Verified => "verified",
             VerificationStatus::Noop => "",
-            VerificationStatus::FallthroughString => "*",
+            VerificationStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for VerificationStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "awaiting_deposits" => VerificationStatus::AwaitingDeposits,
+            "ready_for_verification" => VerificationStatus::ReadyForVerification,
+            "verified" => VerificationStatus::Verified,
+            "" => VerificationStatus::Noop,
+            _ => VerificationStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for VerificationStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for VerificationStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for VerificationStatus {
     fn default() -> VerificationStatus {
         VerificationStatus::Noop
@@ -2594,7 +3074,8 @@ pub struct EarningType {
 /**
 * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeductionReducesTaxableIncome {
     #[serde(rename = "does_not_reduce_taxable_income")]
     DoesNotReduceTaxableIncome,
@@ -2602,8 +3083,9 @@ pub enum DeductionReducesTaxableIncome {
     ReducesTaxableIncome,
     #[serde(rename = "unset")]
     Unset,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeductionReducesTaxableIncome {
@@ -2614,12 +3096,47 @@ impl std::fmt::Display for DeductionReducesTaxableIncome {
             }
             DeductionReducesTaxableIncome::ReducesTaxableIncome => "reduces_taxable_income",
             DeductionReducesTaxableIncome::Unset => "unset",
-            DeductionReducesTaxableIncome::FallthroughString => "*",
+            DeductionReducesTaxableIncome::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeductionReducesTaxableIncome {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "does_not_reduce_taxable_income" => {
+                DeductionReducesTaxableIncome::DoesNotReduceTaxableIncome
+            }
+            "reduces_taxable_income" => DeductionReducesTaxableIncome::ReducesTaxableIncome,
+            "unset" => DeductionReducesTaxableIncome::Unset,
+            _ => DeductionReducesTaxableIncome::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeductionReducesTaxableIncome {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeductionReducesTaxableIncome {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeductionReducesTaxableIncome {
     fn default() -> DeductionReducesTaxableIncome {
         DeductionReducesTaxableIncome::Unset
@@ -3070,7 +3587,8 @@ pub struct Totals {
 /**
 * The employee's compensation payment method. This value is only available for processed payrolls.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PayrollEmployeeCompensationsPaymentMethod {
     #[serde(rename = "Check")]
     Check,
@@ -3078,8 +3596,9 @@ pub enum PayrollEmployeeCompensationsPaymentMethod {
     DirectDeposit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PayrollEmployeeCompensationsPaymentMethod {
@@ -3088,12 +3607,45 @@ impl std::fmt::Display for PayrollEmployeeCompensationsPaymentMethod {
             PayrollEmployeeCompensationsPaymentMethod::Check => "Check",
             PayrollEmployeeCompensationsPaymentMethod::DirectDeposit => "Direct Deposit",
             PayrollEmployeeCompensationsPaymentMethod::Noop => "",
-            PayrollEmployeeCompensationsPaymentMethod::FallthroughString => "*",
+            PayrollEmployeeCompensationsPaymentMethod::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PayrollEmployeeCompensationsPaymentMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Check" => PayrollEmployeeCompensationsPaymentMethod::Check,
+            "Direct Deposit" => PayrollEmployeeCompensationsPaymentMethod::DirectDeposit,
+            "" => PayrollEmployeeCompensationsPaymentMethod::Noop,
+            _ => PayrollEmployeeCompensationsPaymentMethod::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PayrollEmployeeCompensationsPaymentMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PayrollEmployeeCompensationsPaymentMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PayrollEmployeeCompensationsPaymentMethod {
     fn default() -> PayrollEmployeeCompensationsPaymentMethod {
         PayrollEmployeeCompensationsPaymentMethod::Noop
@@ -3468,7 +4020,8 @@ pub struct PayrollData {
     pub version: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CustomFieldType {
     #[serde(rename = "currency")]
     Currency,
@@ -3482,8 +4035,9 @@ pub enum CustomFieldType {
     Text,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CustomFieldType {
@@ -3495,12 +4049,48 @@ impl std::fmt::Display for CustomFieldType {
             CustomFieldType::Radio => "radio",
             CustomFieldType::Text => "text",
             CustomFieldType::Noop => "",
-            CustomFieldType::FallthroughString => "*",
+            CustomFieldType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CustomFieldType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "currency" => CustomFieldType::Currency,
+            "date" => CustomFieldType::Date,
+            "number" => CustomFieldType::Number,
+            "radio" => CustomFieldType::Radio,
+            "text" => CustomFieldType::Text,
+            "" => CustomFieldType::Noop,
+            _ => CustomFieldType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CustomFieldType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomFieldType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CustomFieldType {
     fn default() -> CustomFieldType {
         CustomFieldType::Noop
@@ -3616,7 +4206,8 @@ pub struct CompanyCustomField {
     pub type_: CustomFieldType,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GustoPersonType {
     #[serde(rename = "Candidate")]
     Candidate,
@@ -3626,8 +4217,9 @@ pub enum GustoPersonType {
     Employee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GustoPersonType {
@@ -3637,12 +4229,46 @@ impl std::fmt::Display for GustoPersonType {
             GustoPersonType::Contractor => "Contractor",
             GustoPersonType::Employee => "Employee",
             GustoPersonType::Noop => "",
-            GustoPersonType::FallthroughString => "*",
+            GustoPersonType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GustoPersonType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Candidate" => GustoPersonType::Candidate,
+            "Contractor" => GustoPersonType::Contractor,
+            "Employee" => GustoPersonType::Employee,
+            "" => GustoPersonType::Noop,
+            _ => GustoPersonType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GustoPersonType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GustoPersonType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GustoPersonType {
     fn default() -> GustoPersonType {
         GustoPersonType::Noop
@@ -3870,14 +4496,16 @@ pub struct EarningTypeListResponse {
     pub default: Vec<EarningType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Include {
     #[serde(rename = "custom_fields")]
     CustomFields,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Include {
@@ -3885,12 +4513,44 @@ impl std::fmt::Display for Include {
         match self {
             Include::CustomFields => "custom_fields",
             Include::Noop => "",
-            Include::FallthroughString => "*",
+            Include::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Include {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "custom_fields" => Include::CustomFields,
+            "" => Include::Noop,
+            _ => Include::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Include {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Include {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Include {
     fn default() -> Include {
         Include::Noop
@@ -5065,7 +5725,8 @@ pub struct PutEmployeeBenefitRequest {
     pub version: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetCompanyPayrollsInclude {
     #[serde(rename = "benefits")]
     Benefits,
@@ -5075,8 +5736,9 @@ pub enum GetCompanyPayrollsInclude {
     Taxes,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetCompanyPayrollsInclude {
@@ -5086,12 +5748,46 @@ impl std::fmt::Display for GetCompanyPayrollsInclude {
             GetCompanyPayrollsInclude::Deductions => "deductions",
             GetCompanyPayrollsInclude::Taxes => "taxes",
             GetCompanyPayrollsInclude::Noop => "",
-            GetCompanyPayrollsInclude::FallthroughString => "*",
+            GetCompanyPayrollsInclude::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetCompanyPayrollsInclude {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "benefits" => GetCompanyPayrollsInclude::Benefits,
+            "deductions" => GetCompanyPayrollsInclude::Deductions,
+            "taxes" => GetCompanyPayrollsInclude::Taxes,
+            "" => GetCompanyPayrollsInclude::Noop,
+            _ => GetCompanyPayrollsInclude::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetCompanyPayrollsInclude {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetCompanyPayrollsInclude {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetCompanyPayrollsInclude {
     fn default() -> GetCompanyPayrollsInclude {
         GetCompanyPayrollsInclude::Noop
@@ -5103,7 +5799,8 @@ impl GetCompanyPayrollsInclude {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OffCycleReason {
     #[serde(rename = "Bonus")]
     Bonus,
@@ -5111,8 +5808,9 @@ pub enum OffCycleReason {
     Correction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OffCycleReason {
@@ -5121,12 +5819,45 @@ impl std::fmt::Display for OffCycleReason {
             OffCycleReason::Bonus => "Bonus",
             OffCycleReason::Correction => "Correction",
             OffCycleReason::Noop => "",
-            OffCycleReason::FallthroughString => "*",
+            OffCycleReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OffCycleReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Bonus" => OffCycleReason::Bonus,
+            "Correction" => OffCycleReason::Correction,
+            "" => OffCycleReason::Noop,
+            _ => OffCycleReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OffCycleReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OffCycleReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OffCycleReason {
     fn default() -> OffCycleReason {
         OffCycleReason::Noop
@@ -5594,7 +6325,8 @@ pub struct GetCompanyCustomFieldsResponse {
 /**
 * Must be "Employee" if send_offer is set to true.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OnboardingPersonType {
     #[serde(rename = "Contractor")]
     Contractor,
@@ -5602,8 +6334,9 @@ pub enum OnboardingPersonType {
     Employee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OnboardingPersonType {
@@ -5612,12 +6345,45 @@ impl std::fmt::Display for OnboardingPersonType {
             OnboardingPersonType::Contractor => "Contractor",
             OnboardingPersonType::Employee => "Employee",
             OnboardingPersonType::Noop => "",
-            OnboardingPersonType::FallthroughString => "*",
+            OnboardingPersonType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OnboardingPersonType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Contractor" => OnboardingPersonType::Contractor,
+            "Employee" => OnboardingPersonType::Employee,
+            "" => OnboardingPersonType::Noop,
+            _ => OnboardingPersonType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OnboardingPersonType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OnboardingPersonType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OnboardingPersonType {
     fn default() -> OnboardingPersonType {
         OnboardingPersonType::Noop
@@ -88,7 +88,8 @@ pub struct Interest {
 /**
 * Determines how this category’s interests appear on signup forms.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Type {
     #[serde(rename = "checkboxes")]
     Checkboxes,
@@ -100,8 +101,9 @@ pub enum Type {
     Radio,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Type {
@@ -112,12 +114,47 @@ impl std::fmt::Display for Type {
             Type::Hidden => "hidden",
             Type::Radio => "radio",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "checkboxes" => Type::Checkboxes,
+            "dropdown" => Type::Dropdown,
+            "hidden" => Type::Hidden,
+            "radio" => Type::Radio,
+            "" => Type::Noop,
+            _ => Type::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -366,7 +403,8 @@ pub struct ECommerceProductVariant {
 /**
 * The type of pricing plan the account is on.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PricingPlanType {
     #[serde(rename = "forever_free")]
     ForeverFree,
@@ -376,8 +414,9 @@ pub enum PricingPlanType {
     PayAsYouGo,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PricingPlanType {
@@ -387,12 +426,46 @@ impl std::fmt::Display for PricingPlanType {
             PricingPlanType::Monthly => "monthly",
             PricingPlanType::PayAsYouGo => "pay_as_you_go",
             PricingPlanType::Noop => "",
-            PricingPlanType::FallthroughString => "*",
+            PricingPlanType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PricingPlanType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "forever_free" => PricingPlanType::ForeverFree,
+            "monthly" => PricingPlanType::Monthly,
+            "pay_as_you_go" => PricingPlanType::PayAsYouGo,
+            "" => PricingPlanType::Noop,
+            _ => PricingPlanType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PricingPlanType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PricingPlanType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PricingPlanType {
     fn default() -> PricingPlanType {
         PricingPlanType::Noop
@@ -509,7 +582,8 @@ pub struct IndustryStats {
 /**
 * The HTTP method that should be used when accessing the URL defined in 'href'.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Method {
     #[serde(rename = "DELETE")]
     Delete,
@@ -527,8 +601,9 @@ pub enum Method {
     Put,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Method {
@@ -542,12 +617,50 @@ impl std::fmt::Display for Method {
             Method::Post => "POST",
             Method::Put => "PUT",
             Method::Noop => "",
-            Method::FallthroughString => "*",
+            Method::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Method {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DELETE" => Method::Delete,
+            "GET" => Method::Get,
+            "HEAD" => Method::Head,
+            "OPTIONS" => Method::Options,
+            "PATCH" => Method::Patch,
+            "POST" => Method::Post,
+            "PUT" => Method::Put,
+            "" => Method::Noop,
+            _ => Method::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Method {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Method {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Method {
     fn default() -> Method {
         Method::Noop
@@ -782,7 +895,8 @@ pub struct ApiRoot {
 /**
 * The type of activity
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetActivityFeedChimpChatterResponseType {
     #[serde(rename = "campaigns:facebook-likes")]
     CampaignsFacebookLikes,
@@ -798,8 +912,9 @@ pub enum GetActivityFeedChimpChatterResponseType {
     ListsUnsubscribes,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetActivityFeedChimpChatterResponseType {
@@ -816,12 +931,53 @@ impl std::fmt::Display for GetActivityFeedChimpChatterResponseType {
             GetActivityFeedChimpChatterResponseType::ListsProfileUpdates => "lists:profile-updates",
             GetActivityFeedChimpChatterResponseType::ListsUnsubscribes => "lists:unsubscribes",
             GetActivityFeedChimpChatterResponseType::Noop => "",
-            GetActivityFeedChimpChatterResponseType::FallthroughString => "*",
+            GetActivityFeedChimpChatterResponseType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetActivityFeedChimpChatterResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "campaigns:facebook-likes" => {
+                GetActivityFeedChimpChatterResponseType::CampaignsFacebookLikes
+            }
+            "campaigns:forward-to-friend" => {
+                GetActivityFeedChimpChatterResponseType::CampaignsForwardToFriend
+            }
+            "lists:imports" => GetActivityFeedChimpChatterResponseType::ListsImports,
+            "lists:new-subscriber" => GetActivityFeedChimpChatterResponseType::ListsNewSubscriber,
+            "lists:profile-updates" => GetActivityFeedChimpChatterResponseType::ListsProfileUpdates,
+            "lists:unsubscribes" => GetActivityFeedChimpChatterResponseType::ListsUnsubscribes,
+            "" => GetActivityFeedChimpChatterResponseType::Noop,
+            _ => GetActivityFeedChimpChatterResponseType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetActivityFeedChimpChatterResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetActivityFeedChimpChatterResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetActivityFeedChimpChatterResponseType {
     fn default() -> GetActivityFeedChimpChatterResponseType {
         GetActivityFeedChimpChatterResponseType::Noop
@@ -1017,7 +1173,8 @@ pub struct GetAuthorizedAppsResponse {
 /**
 * Restrict the results to automations with the specified status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Status {
     #[serde(rename = "paused")]
     Paused,
@@ -1027,8 +1184,9 @@ pub enum Status {
     Sending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1038,12 +1196,46 @@ impl std::fmt::Display for Status {
             Status::Save => "save",
             Status::Sending => "sending",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "paused" => Status::Paused,
+            "save" => Status::Save,
+            "sending" => Status::Sending,
+            "" => Status::Noop,
+            _ => Status::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -1058,7 +1250,8 @@ impl Status {
 /**
 * Segment match type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Match {
     #[serde(rename = "all")]
     All,
@@ -1066,8 +1259,9 @@ pub enum Match {
     Any,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Match {
@@ -1076,12 +1270,45 @@ impl std::fmt::Display for Match {
             Match::All => "all",
             Match::Any => "any",
             Match::Noop => "",
-            Match::FallthroughString => "*",
+            Match::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Match {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => Match::All,
+            "any" => Match::Any,
+            "" => Match::Noop,
+            _ => Match::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Match {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Match {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Match {
     fn default() -> Match {
         Match::Noop
@@ -1093,14 +1320,16 @@ impl Match {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ConditionType {
     #[serde(rename = "Aim")]
     Aim,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ConditionType {
@@ -1108,12 +1337,44 @@ impl std::fmt::Display for ConditionType {
         match self {
             ConditionType::Aim => "Aim",
             ConditionType::Noop => "",
-            ConditionType::FallthroughString => "*",
+            ConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Aim" => ConditionType::Aim,
+            "" => ConditionType::Noop,
+            _ => ConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ConditionType {
     fn default() -> ConditionType {
         ConditionType::Noop
@@ -1128,14 +1389,16 @@ impl ConditionType {
 /**
 * Segment by interaction with a specific campaign.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Field {
     #[serde(rename = "aim")]
     Aim,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Field {
@@ -1143,12 +1406,44 @@ impl std::fmt::Display for Field {
         match self {
             Field::Aim => "aim",
             Field::Noop => "",
-            Field::FallthroughString => "*",
+            Field::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Field {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "aim" => Field::Aim,
+            "" => Field::Noop,
+            _ => Field::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Field {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Field {
     fn default() -> Field {
         Field::Noop
@@ -1163,7 +1458,8 @@ impl Field {
 /**
 * The status of the member with regard to their campaign interaction. One of the following: opened, clicked, was sent, didn't open, didn't click, or was not sent.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Op {
     #[serde(rename = "click")]
     Click,
@@ -1179,8 +1475,9 @@ pub enum Op {
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Op {
@@ -1193,12 +1490,49 @@ impl std::fmt::Display for Op {
             Op::Open => "open",
             Op::Sent => "sent",
             Op::Noop => "",
-            Op::FallthroughString => "*",
+            Op::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Op {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "click" => Op::Click,
+            "noclick" => Op::Noclick,
+            "noopen" => Op::Noopen,
+            "nosent" => Op::Nosent,
+            "open" => Op::Open,
+            "sent" => Op::Sent,
+            "" => Op::Noop,
+            _ => Op::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Op {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Op {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Op {
     fn default() -> Op {
         Op::Noop
@@ -1239,14 +1573,16 @@ pub struct Conditions {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AutomationSegmentConditionType {
     #[serde(rename = "Automation")]
     Automation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AutomationSegmentConditionType {
@@ -1254,12 +1590,44 @@ impl std::fmt::Display for AutomationSegmentConditionType {
         match self {
             AutomationSegmentConditionType::Automation => "Automation",
             AutomationSegmentConditionType::Noop => "",
-            AutomationSegmentConditionType::FallthroughString => "*",
+            AutomationSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AutomationSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Automation" => AutomationSegmentConditionType::Automation,
+            "" => AutomationSegmentConditionType::Noop,
+            _ => AutomationSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AutomationSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AutomationSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AutomationSegmentConditionType {
     fn default() -> AutomationSegmentConditionType {
         AutomationSegmentConditionType::Noop
@@ -1274,14 +1642,16 @@ impl AutomationSegmentConditionType {
 /**
 * Segment by interaction with an Automation workflow.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SegmentField {
     #[serde(rename = "automation")]
     Automation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SegmentField {
@@ -1289,12 +1659,44 @@ impl std::fmt::Display for SegmentField {
         match self {
             SegmentField::Automation => "automation",
             SegmentField::Noop => "",
-            SegmentField::FallthroughString => "*",
+            SegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "automation" => SegmentField::Automation,
+            "" => SegmentField::Noop,
+            _ => SegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SegmentField {
     fn default() -> SegmentField {
         SegmentField::Noop
@@ -1309,7 +1711,8 @@ impl SegmentField {
 /**
 * The status of the member with regard to the automation workflow. One of the following: has started the workflow, has completed the workflow, has not started the workflow, or has not completed the workflow.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SegmentOperator {
     #[serde(rename = "completed")]
     Completed,
@@ -1321,8 +1724,9 @@ pub enum SegmentOperator {
     Started,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SegmentOperator {
@@ -1333,12 +1737,47 @@ impl std::fmt::Display for SegmentOperator {
             SegmentOperator::NotStarted => "not_started",
             SegmentOperator::Started => "started",
             SegmentOperator::Noop => "",
-            SegmentOperator::FallthroughString => "*",
+            SegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "completed" => SegmentOperator::Completed,
+            "not_completed" => SegmentOperator::NotCompleted,
+            "not_started" => SegmentOperator::NotStarted,
+            "started" => SegmentOperator::Started,
+            "" => SegmentOperator::Noop,
+            _ => SegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SegmentOperator {
     fn default() -> SegmentOperator {
         SegmentOperator::Noop
@@ -1379,14 +1818,16 @@ pub struct AutomationSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PollActivitySegmentConditionType {
     #[serde(rename = "CampaignPoll")]
     CampaignPoll,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentConditionType {
@@ -1394,12 +1835,44 @@ impl std::fmt::Display for PollActivitySegmentConditionType {
         match self {
             PollActivitySegmentConditionType::CampaignPoll => "CampaignPoll",
             PollActivitySegmentConditionType::Noop => "",
-            PollActivitySegmentConditionType::FallthroughString => "*",
+            PollActivitySegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PollActivitySegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CampaignPoll" => PollActivitySegmentConditionType::CampaignPoll,
+            "" => PollActivitySegmentConditionType::Noop,
+            _ => PollActivitySegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PollActivitySegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PollActivitySegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PollActivitySegmentConditionType {
     fn default() -> PollActivitySegmentConditionType {
         PollActivitySegmentConditionType::Noop
@@ -1414,14 +1887,16 @@ impl PollActivitySegmentConditionType {
 /**
 * Segment by poll activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PollActivitySegmentField {
     #[serde(rename = "poll")]
     Poll,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentField {
@@ -1429,12 +1904,44 @@ impl std::fmt::Display for PollActivitySegmentField {
         match self {
             PollActivitySegmentField::Poll => "poll",
             PollActivitySegmentField::Noop => "",
-            PollActivitySegmentField::FallthroughString => "*",
+            PollActivitySegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PollActivitySegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "poll" => PollActivitySegmentField::Poll,
+            "" => PollActivitySegmentField::Noop,
+            _ => PollActivitySegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PollActivitySegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PollActivitySegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PollActivitySegmentField {
     fn default() -> PollActivitySegmentField {
         PollActivitySegmentField::Noop
@@ -1449,7 +1956,8 @@ impl PollActivitySegmentField {
 /**
 * Members have/have not interacted with a specific poll in a Mailchimp email.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PollActivitySegmentOperator {
     #[serde(rename = "member")]
     Member,
@@ -1457,8 +1965,9 @@ pub enum PollActivitySegmentOperator {
     Notmember,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentOperator {
@@ -1467,12 +1976,45 @@ impl std::fmt::Display for PollActivitySegmentOperator {
             PollActivitySegmentOperator::Member => "member",
             PollActivitySegmentOperator::Notmember => "notmember",
             PollActivitySegmentOperator::Noop => "",
-            PollActivitySegmentOperator::FallthroughString => "*",
+            PollActivitySegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PollActivitySegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "member" => PollActivitySegmentOperator::Member,
+            "notmember" => PollActivitySegmentOperator::Notmember,
+            "" => PollActivitySegmentOperator::Noop,
+            _ => PollActivitySegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PollActivitySegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PollActivitySegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PollActivitySegmentOperator {
     fn default() -> PollActivitySegmentOperator {
         PollActivitySegmentOperator::Noop
@@ -1513,14 +2055,16 @@ pub struct PollActivitySegment {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ConversationSegmentConditionType {
     #[serde(rename = "Conversation")]
     Conversation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ConversationSegmentConditionType {
@@ -1528,12 +2072,44 @@ impl std::fmt::Display for ConversationSegmentConditionType {
         match self {
             ConversationSegmentConditionType::Conversation => "Conversation",
             ConversationSegmentConditionType::Noop => "",
-            ConversationSegmentConditionType::FallthroughString => "*",
+            ConversationSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ConversationSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Conversation" => ConversationSegmentConditionType::Conversation,
+            "" => ConversationSegmentConditionType::Noop,
+            _ => ConversationSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ConversationSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ConversationSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ConversationSegmentConditionType {
     fn default() -> ConversationSegmentConditionType {
         ConversationSegmentConditionType::Noop
@@ -1548,14 +2124,16 @@ impl ConversationSegmentConditionType {
 /**
 * Segment by interaction with a campaign via Conversations.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ConversationSegmentField {
     #[serde(rename = "conversation")]
     Conversation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ConversationSegmentField {
@@ -1563,12 +2141,44 @@ impl std::fmt::Display for ConversationSegmentField {
         match self {
             ConversationSegmentField::Conversation => "conversation",
             ConversationSegmentField::Noop => "",
-            ConversationSegmentField::FallthroughString => "*",
+            ConversationSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ConversationSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "conversation" => ConversationSegmentField::Conversation,
+            "" => ConversationSegmentField::Noop,
+            _ => ConversationSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ConversationSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ConversationSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ConversationSegmentField {
     fn default() -> ConversationSegmentField {
         ConversationSegmentField::Noop
@@ -1609,14 +2219,16 @@ pub struct ConversationSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DateSegmentConditionType {
     #[serde(rename = "Date")]
     Date,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DateSegmentConditionType {
@@ -1624,12 +2236,44 @@ impl std::fmt::Display for DateSegmentConditionType {
         match self {
             DateSegmentConditionType::Date => "Date",
             DateSegmentConditionType::Noop => "",
-            DateSegmentConditionType::FallthroughString => "*",
+            DateSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DateSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Date" => DateSegmentConditionType::Date,
+            "" => DateSegmentConditionType::Noop,
+            _ => DateSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DateSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DateSegmentConditionType {
     fn default() -> DateSegmentConditionType {
         DateSegmentConditionType::Noop
@@ -1644,7 +2288,8 @@ impl DateSegmentConditionType {
 /**
 * The type of date field to segment on: The opt-in time for a signup, the date the subscriber was last updated, or the date of their last ecomm purchase.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DateSegmentField {
     #[serde(rename = "ecomm_date")]
     EcommDate,
@@ -1654,8 +2299,9 @@ pub enum DateSegmentField {
     TimestampOpt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DateSegmentField {
@@ -1665,12 +2311,46 @@ impl std::fmt::Display for DateSegmentField {
             DateSegmentField::InfoChanged => "info_changed",
             DateSegmentField::TimestampOpt => "timestamp_opt",
             DateSegmentField::Noop => "",
-            DateSegmentField::FallthroughString => "*",
+            DateSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DateSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_date" => DateSegmentField::EcommDate,
+            "info_changed" => DateSegmentField::InfoChanged,
+            "timestamp_opt" => DateSegmentField::TimestampOpt,
+            "" => DateSegmentField::Noop,
+            _ => DateSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DateSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DateSegmentField {
     fn default() -> DateSegmentField {
         DateSegmentField::Noop
@@ -1685,7 +2365,8 @@ impl DateSegmentField {
 /**
 * When the event took place:  Before, after, is a specific date, is not a specific date, is blank, or is not blank.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DateSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -1705,8 +2386,9 @@ pub enum DateSegmentOperator {
     Within,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DateSegmentOperator {
@@ -1721,12 +2403,51 @@ impl std::fmt::Display for DateSegmentOperator {
             DateSegmentOperator::Notwithin => "notwithin",
             DateSegmentOperator::Within => "within",
             DateSegmentOperator::Noop => "",
-            DateSegmentOperator::FallthroughString => "*",
+            DateSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DateSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "blank" => DateSegmentOperator::Blank,
+            "blank_not" => DateSegmentOperator::BlankNot,
+            "greater" => DateSegmentOperator::Greater,
+            "is" => DateSegmentOperator::Is,
+            "less" => DateSegmentOperator::Less,
+            "not" => DateSegmentOperator::Not,
+            "notwithin" => DateSegmentOperator::Notwithin,
+            "within" => DateSegmentOperator::Within,
+            "" => DateSegmentOperator::Noop,
+            _ => DateSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DateSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DateSegmentOperator {
     fn default() -> DateSegmentOperator {
         DateSegmentOperator::Noop
@@ -1776,14 +2497,16 @@ pub struct DateSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EmailClientSegmentConditionType {
     #[serde(rename = "EmailClient")]
     EmailClient,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentConditionType {
@@ -1791,12 +2514,44 @@ impl std::fmt::Display for EmailClientSegmentConditionType {
         match self {
             EmailClientSegmentConditionType::EmailClient => "EmailClient",
             EmailClientSegmentConditionType::Noop => "",
-            EmailClientSegmentConditionType::FallthroughString => "*",
+            EmailClientSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EmailClientSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EmailClient" => EmailClientSegmentConditionType::EmailClient,
+            "" => EmailClientSegmentConditionType::Noop,
+            _ => EmailClientSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EmailClientSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailClientSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EmailClientSegmentConditionType {
     fn default() -> EmailClientSegmentConditionType {
         EmailClientSegmentConditionType::Noop
@@ -1811,14 +2566,16 @@ impl EmailClientSegmentConditionType {
 /**
 * Segment by use of a particular email client.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EmailClientSegmentField {
     #[serde(rename = "email_client")]
     EmailClient,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentField {
@@ -1826,12 +2583,44 @@ impl std::fmt::Display for EmailClientSegmentField {
         match self {
             EmailClientSegmentField::EmailClient => "email_client",
             EmailClientSegmentField::Noop => "",
-            EmailClientSegmentField::FallthroughString => "*",
+            EmailClientSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EmailClientSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "email_client" => EmailClientSegmentField::EmailClient,
+            "" => EmailClientSegmentField::Noop,
+            _ => EmailClientSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EmailClientSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailClientSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EmailClientSegmentField {
     fn default() -> EmailClientSegmentField {
         EmailClientSegmentField::Noop
@@ -1846,7 +2635,8 @@ impl EmailClientSegmentField {
 /**
 * The operation to determine whether we select clients that match the value, or clients that do not match the value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EmailClientSegmentOperator {
     #[serde(rename = "client_is")]
     ClientIs,
@@ -1854,8 +2644,9 @@ pub enum EmailClientSegmentOperator {
     ClientNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentOperator {
@@ -1864,12 +2655,45 @@ impl std::fmt::Display for EmailClientSegmentOperator {
             EmailClientSegmentOperator::ClientIs => "client_is",
             EmailClientSegmentOperator::ClientNot => "client_not",
             EmailClientSegmentOperator::Noop => "",
-            EmailClientSegmentOperator::FallthroughString => "*",
+            EmailClientSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EmailClientSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "client_is" => EmailClientSegmentOperator::ClientIs,
+            "client_not" => EmailClientSegmentOperator::ClientNot,
+            "" => EmailClientSegmentOperator::Noop,
+            _ => EmailClientSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EmailClientSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailClientSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EmailClientSegmentOperator {
     fn default() -> EmailClientSegmentOperator {
         EmailClientSegmentOperator::Noop
@@ -1910,14 +2734,16 @@ pub struct EmailClientSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LanguageSegmentConditionType {
     #[serde(rename = "Language")]
     Language,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LanguageSegmentConditionType {
@@ -1925,12 +2751,44 @@ impl std::fmt::Display for LanguageSegmentConditionType {
         match self {
             LanguageSegmentConditionType::Language => "Language",
             LanguageSegmentConditionType::Noop => "",
-            LanguageSegmentConditionType::FallthroughString => "*",
+            LanguageSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LanguageSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Language" => LanguageSegmentConditionType::Language,
+            "" => LanguageSegmentConditionType::Noop,
+            _ => LanguageSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LanguageSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LanguageSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LanguageSegmentConditionType {
     fn default() -> LanguageSegmentConditionType {
         LanguageSegmentConditionType::Noop
@@ -1945,14 +2803,16 @@ impl LanguageSegmentConditionType {
 /**
 * Segmenting based off of a subscriber's language.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LanguageSegmentField {
     #[serde(rename = "language")]
     Language,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LanguageSegmentField {
@@ -1960,12 +2820,44 @@ impl std::fmt::Display for LanguageSegmentField {
         match self {
             LanguageSegmentField::Language => "language",
             LanguageSegmentField::Noop => "",
-            LanguageSegmentField::FallthroughString => "*",
+            LanguageSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LanguageSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "language" => LanguageSegmentField::Language,
+            "" => LanguageSegmentField::Noop,
+            _ => LanguageSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LanguageSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LanguageSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LanguageSegmentField {
     fn default() -> LanguageSegmentField {
         LanguageSegmentField::Noop
@@ -1980,7 +2872,8 @@ impl LanguageSegmentField {
 /**
 * Whether the member's language is or is not set to a specific language.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LanguageSegmentOperator {
     #[serde(rename = "is")]
     Is,
@@ -1988,8 +2881,9 @@ pub enum LanguageSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LanguageSegmentOperator {
@@ -1998,12 +2892,45 @@ impl std::fmt::Display for LanguageSegmentOperator {
             LanguageSegmentOperator::Is => "is",
             LanguageSegmentOperator::Not => "not",
             LanguageSegmentOperator::Noop => "",
-            LanguageSegmentOperator::FallthroughString => "*",
+            LanguageSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LanguageSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "is" => LanguageSegmentOperator::Is,
+            "not" => LanguageSegmentOperator::Not,
+            "" => LanguageSegmentOperator::Noop,
+            _ => LanguageSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LanguageSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LanguageSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LanguageSegmentOperator {
     fn default() -> LanguageSegmentOperator {
         LanguageSegmentOperator::Noop
@@ -2044,14 +2971,16 @@ pub struct LanguageSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MemberRatingSegmentConditionType {
     #[serde(rename = "MemberRating")]
     MemberRating,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentConditionType {
@@ -2059,12 +2988,44 @@ impl std::fmt::Display for MemberRatingSegmentConditionType {
         match self {
             MemberRatingSegmentConditionType::MemberRating => "MemberRating",
             MemberRatingSegmentConditionType::Noop => "",
-            MemberRatingSegmentConditionType::FallthroughString => "*",
+            MemberRatingSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MemberRatingSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "MemberRating" => MemberRatingSegmentConditionType::MemberRating,
+            "" => MemberRatingSegmentConditionType::Noop,
+            _ => MemberRatingSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MemberRatingSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MemberRatingSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MemberRatingSegmentConditionType {
     fn default() -> MemberRatingSegmentConditionType {
         MemberRatingSegmentConditionType::Noop
@@ -2079,14 +3040,16 @@ impl MemberRatingSegmentConditionType {
 /**
 * Segment by member rating.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MemberRatingSegmentField {
     #[serde(rename = "rating")]
     Rating,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentField {
@@ -2094,12 +3057,44 @@ impl std::fmt::Display for MemberRatingSegmentField {
         match self {
             MemberRatingSegmentField::Rating => "rating",
             MemberRatingSegmentField::Noop => "",
-            MemberRatingSegmentField::FallthroughString => "*",
+            MemberRatingSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MemberRatingSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "rating" => MemberRatingSegmentField::Rating,
+            "" => MemberRatingSegmentField::Noop,
+            _ => MemberRatingSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MemberRatingSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MemberRatingSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MemberRatingSegmentField {
     fn default() -> MemberRatingSegmentField {
         MemberRatingSegmentField::Noop
@@ -2114,7 +3109,8 @@ impl MemberRatingSegmentField {
 /**
 * Members who have have a rating that is/not exactly a given number or members who have a rating greater/less than a given number.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MemberRatingSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -2126,8 +3122,9 @@ pub enum MemberRatingSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentOperator {
@@ -2138,12 +3135,47 @@ impl std::fmt::Display for MemberRatingSegmentOperator {
             MemberRatingSegmentOperator::Less => "less",
             MemberRatingSegmentOperator::Not => "not",
             MemberRatingSegmentOperator::Noop => "",
-            MemberRatingSegmentOperator::FallthroughString => "*",
+            MemberRatingSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MemberRatingSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "greater" => MemberRatingSegmentOperator::Greater,
+            "is" => MemberRatingSegmentOperator::Is,
+            "less" => MemberRatingSegmentOperator::Less,
+            "not" => MemberRatingSegmentOperator::Not,
+            "" => MemberRatingSegmentOperator::Noop,
+            _ => MemberRatingSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MemberRatingSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MemberRatingSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MemberRatingSegmentOperator {
     fn default() -> MemberRatingSegmentOperator {
         MemberRatingSegmentOperator::Noop
@@ -2184,14 +3216,16 @@ pub struct MemberRatingSegment {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SignupSourceSegmentType {
     #[serde(rename = "SignupSource")]
     SignupSource,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentType {
@@ -2199,12 +3233,44 @@ impl std::fmt::Display for SignupSourceSegmentType {
         match self {
             SignupSourceSegmentType::SignupSource => "SignupSource",
             SignupSourceSegmentType::Noop => "",
-            SignupSourceSegmentType::FallthroughString => "*",
+            SignupSourceSegmentType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SignupSourceSegmentType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SignupSource" => SignupSourceSegmentType::SignupSource,
+            "" => SignupSourceSegmentType::Noop,
+            _ => SignupSourceSegmentType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SignupSourceSegmentType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SignupSourceSegmentType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SignupSourceSegmentType {
     fn default() -> SignupSourceSegmentType {
         SignupSourceSegmentType::Noop
@@ -2216,14 +3282,16 @@ impl SignupSourceSegmentType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SignupSourceSegmentField {
     #[serde(rename = "source")]
     Source,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentField {
@@ -2231,12 +3299,44 @@ impl std::fmt::Display for SignupSourceSegmentField {
         match self {
             SignupSourceSegmentField::Source => "source",
             SignupSourceSegmentField::Noop => "",
-            SignupSourceSegmentField::FallthroughString => "*",
+            SignupSourceSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SignupSourceSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "source" => SignupSourceSegmentField::Source,
+            "" => SignupSourceSegmentField::Noop,
+            _ => SignupSourceSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SignupSourceSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SignupSourceSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SignupSourceSegmentField {
     fn default() -> SignupSourceSegmentField {
         SignupSourceSegmentField::Noop
@@ -2251,7 +3351,8 @@ impl SignupSourceSegmentField {
 /**
 * Whether the member's signup source was/was not a particular value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SignupSourceSegmentOperator {
     #[serde(rename = "source_is")]
     SourceIs,
@@ -2259,8 +3360,9 @@ pub enum SignupSourceSegmentOperator {
     SourceNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentOperator {
@@ -2269,12 +3371,45 @@ impl std::fmt::Display for SignupSourceSegmentOperator {
             SignupSourceSegmentOperator::SourceIs => "source_is",
             SignupSourceSegmentOperator::SourceNot => "source_not",
             SignupSourceSegmentOperator::Noop => "",
-            SignupSourceSegmentOperator::FallthroughString => "*",
+            SignupSourceSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SignupSourceSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "source_is" => SignupSourceSegmentOperator::SourceIs,
+            "source_not" => SignupSourceSegmentOperator::SourceNot,
+            "" => SignupSourceSegmentOperator::Noop,
+            _ => SignupSourceSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SignupSourceSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SignupSourceSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SignupSourceSegmentOperator {
     fn default() -> SignupSourceSegmentOperator {
         SignupSourceSegmentOperator::Noop
@@ -2309,14 +3444,16 @@ pub struct SignupSourceSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SurveyMonkeySegmentConditionType {
     #[serde(rename = "SurveyMonkey")]
     SurveyMonkey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SurveyMonkeySegmentConditionType {
@@ -2324,12 +3461,44 @@ impl std::fmt::Display for SurveyMonkeySegmentConditionType {
         match self {
             SurveyMonkeySegmentConditionType::SurveyMonkey => "SurveyMonkey",
             SurveyMonkeySegmentConditionType::Noop => "",
-            SurveyMonkeySegmentConditionType::FallthroughString => "*",
+            SurveyMonkeySegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SurveyMonkeySegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SurveyMonkey" => SurveyMonkeySegmentConditionType::SurveyMonkey,
+            "" => SurveyMonkeySegmentConditionType::Noop,
+            _ => SurveyMonkeySegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SurveyMonkeySegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SurveyMonkeySegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SurveyMonkeySegmentConditionType {
     fn default() -> SurveyMonkeySegmentConditionType {
         SurveyMonkeySegmentConditionType::Noop
@@ -2344,14 +3513,16 @@ impl SurveyMonkeySegmentConditionType {
 /**
 * Segment by interaction with a SurveyMonkey survey.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SurveyMonkeySegmentField {
     #[serde(rename = "survey_monkey")]
     SurveyMonkey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SurveyMonkeySegmentField {
@@ -2359,12 +3530,44 @@ impl std::fmt::Display for SurveyMonkeySegmentField {
         match self {
             SurveyMonkeySegmentField::SurveyMonkey => "survey_monkey",
             SurveyMonkeySegmentField::Noop => "",
-            SurveyMonkeySegmentField::FallthroughString => "*",
+            SurveyMonkeySegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SurveyMonkeySegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "survey_monkey" => SurveyMonkeySegmentField::SurveyMonkey,
+            "" => SurveyMonkeySegmentField::Noop,
+            _ => SurveyMonkeySegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SurveyMonkeySegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SurveyMonkeySegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SurveyMonkeySegmentField {
     fn default() -> SurveyMonkeySegmentField {
         SurveyMonkeySegmentField::Noop
@@ -2405,14 +3608,16 @@ pub struct SurveyMonkeySegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum VipSegmentConditionType {
     #[serde(rename = "VIP")]
     Vip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for VipSegmentConditionType {
@@ -2420,12 +3625,44 @@ impl std::fmt::Display for VipSegmentConditionType {
         match self {
             VipSegmentConditionType::Vip => "VIP",
             VipSegmentConditionType::Noop => "",
-            VipSegmentConditionType::FallthroughString => "*",
+            VipSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for VipSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "VIP" => VipSegmentConditionType::Vip,
+            "" => VipSegmentConditionType::Noop,
+            _ => VipSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for VipSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for VipSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for VipSegmentConditionType {
     fn default() -> VipSegmentConditionType {
         VipSegmentConditionType::Noop
@@ -2440,14 +3677,16 @@ impl VipSegmentConditionType {
 /**
 * Segment by VIP status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum VipSegmentField {
     #[serde(rename = "gmonkey")]
     Gmonkey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for VipSegmentField {
@@ -2455,12 +3694,44 @@ impl std::fmt::Display for VipSegmentField {
         match self {
             VipSegmentField::Gmonkey => "gmonkey",
             VipSegmentField::Noop => "",
-            VipSegmentField::FallthroughString => "*",
+            VipSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for VipSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "gmonkey" => VipSegmentField::Gmonkey,
+            "" => VipSegmentField::Noop,
+            _ => VipSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for VipSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for VipSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for VipSegmentField {
     fn default() -> VipSegmentField {
         VipSegmentField::Noop
@@ -2492,14 +3763,16 @@ pub struct VipSegment {
     pub op: PollActivitySegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum InterestsSegmentConditionType {
     #[serde(rename = "Interests")]
     Interests,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for InterestsSegmentConditionType {
@@ -2507,12 +3780,44 @@ impl std::fmt::Display for InterestsSegmentConditionType {
         match self {
             InterestsSegmentConditionType::Interests => "Interests",
             InterestsSegmentConditionType::Noop => "",
-            InterestsSegmentConditionType::FallthroughString => "*",
+            InterestsSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for InterestsSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Interests" => InterestsSegmentConditionType::Interests,
+            "" => InterestsSegmentConditionType::Noop,
+            _ => InterestsSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for InterestsSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for InterestsSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for InterestsSegmentConditionType {
     fn default() -> InterestsSegmentConditionType {
         InterestsSegmentConditionType::Noop
@@ -2527,7 +3832,8 @@ impl InterestsSegmentConditionType {
 /**
 * Whether the member is a part of one, all, or none of the groups.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum InterestsSegmentOperator {
     #[serde(rename = "interestcontains")]
     Interestcontains,
@@ -2537,8 +3843,9 @@ pub enum InterestsSegmentOperator {
     Interestnotcontains,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for InterestsSegmentOperator {
@@ -2548,12 +3855,46 @@ impl std::fmt::Display for InterestsSegmentOperator {
             InterestsSegmentOperator::Interestcontainsall => "interestcontainsall",
             InterestsSegmentOperator::Interestnotcontains => "interestnotcontains",
             InterestsSegmentOperator::Noop => "",
-            InterestsSegmentOperator::FallthroughString => "*",
+            InterestsSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for InterestsSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "interestcontains" => InterestsSegmentOperator::Interestcontains,
+            "interestcontainsall" => InterestsSegmentOperator::Interestcontainsall,
+            "interestnotcontains" => InterestsSegmentOperator::Interestnotcontains,
+            "" => InterestsSegmentOperator::Noop,
+            _ => InterestsSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for InterestsSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for InterestsSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for InterestsSegmentOperator {
     fn default() -> InterestsSegmentOperator {
         InterestsSegmentOperator::Noop
@@ -2598,14 +3939,16 @@ pub struct InterestsSegment {
     pub value: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommerceCategorySegmentConditionType {
     #[serde(rename = "EcommCategory")]
     EcommCategory,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentConditionType {
@@ -2613,12 +3956,44 @@ impl std::fmt::Display for EcommerceCategorySegmentConditionType {
         match self {
             EcommerceCategorySegmentConditionType::EcommCategory => "EcommCategory",
             EcommerceCategorySegmentConditionType::Noop => "",
-            EcommerceCategorySegmentConditionType::FallthroughString => "*",
+            EcommerceCategorySegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommerceCategorySegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EcommCategory" => EcommerceCategorySegmentConditionType::EcommCategory,
+            "" => EcommerceCategorySegmentConditionType::Noop,
+            _ => EcommerceCategorySegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommerceCategorySegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceCategorySegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommerceCategorySegmentConditionType {
     fn default() -> EcommerceCategorySegmentConditionType {
         EcommerceCategorySegmentConditionType::Noop
@@ -2633,7 +4008,8 @@ impl EcommerceCategorySegmentConditionType {
 /**
 * Segment by purchases in specific items or categories.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommerceCategorySegmentField {
     #[serde(rename = "ecomm_cat")]
     EcommCat,
@@ -2641,8 +4017,9 @@ pub enum EcommerceCategorySegmentField {
     EcommProd,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentField {
@@ -2651,12 +4028,45 @@ impl std::fmt::Display for EcommerceCategorySegmentField {
             EcommerceCategorySegmentField::EcommCat => "ecomm_cat",
             EcommerceCategorySegmentField::EcommProd => "ecomm_prod",
             EcommerceCategorySegmentField::Noop => "",
-            EcommerceCategorySegmentField::FallthroughString => "*",
+            EcommerceCategorySegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommerceCategorySegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_cat" => EcommerceCategorySegmentField::EcommCat,
+            "ecomm_prod" => EcommerceCategorySegmentField::EcommProd,
+            "" => EcommerceCategorySegmentField::Noop,
+            _ => EcommerceCategorySegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommerceCategorySegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceCategorySegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommerceCategorySegmentField {
     fn default() -> EcommerceCategorySegmentField {
         EcommerceCategorySegmentField::Noop
@@ -2671,7 +4081,8 @@ impl EcommerceCategorySegmentField {
 /**
 * A member who has purchased from a category/specific item that is/is not a specific name, where the category/item name contains/doesn't contain a specific phrase or string, or a category/item name that starts/ends with a string.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommerceCategorySegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -2687,8 +4098,9 @@ pub enum EcommerceCategorySegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentOperator {
@@ -2701,12 +4113,49 @@ impl std::fmt::Display for EcommerceCategorySegmentOperator {
             EcommerceCategorySegmentOperator::Notcontain => "notcontain",
             EcommerceCategorySegmentOperator::Starts => "starts",
             EcommerceCategorySegmentOperator::Noop => "",
-            EcommerceCategorySegmentOperator::FallthroughString => "*",
+            EcommerceCategorySegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommerceCategorySegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "contains" => EcommerceCategorySegmentOperator::Contains,
+            "ends" => EcommerceCategorySegmentOperator::Ends,
+            "is" => EcommerceCategorySegmentOperator::Is,
+            "not" => EcommerceCategorySegmentOperator::Not,
+            "notcontain" => EcommerceCategorySegmentOperator::Notcontain,
+            "starts" => EcommerceCategorySegmentOperator::Starts,
+            "" => EcommerceCategorySegmentOperator::Noop,
+            _ => EcommerceCategorySegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommerceCategorySegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceCategorySegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommerceCategorySegmentOperator {
     fn default() -> EcommerceCategorySegmentOperator {
         EcommerceCategorySegmentOperator::Noop
@@ -2747,14 +4196,16 @@ pub struct EcommerceCategorySegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommerceNumberSegmentConditionType {
     #[serde(rename = "EcommNumber")]
     EcommNumber,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommerceNumberSegmentConditionType {
@@ -2762,12 +4213,44 @@ impl std::fmt::Display for EcommerceNumberSegmentConditionType {
         match self {
             EcommerceNumberSegmentConditionType::EcommNumber => "EcommNumber",
             EcommerceNumberSegmentConditionType::Noop => "",
-            EcommerceNumberSegmentConditionType::FallthroughString => "*",
+            EcommerceNumberSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommerceNumberSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EcommNumber" => EcommerceNumberSegmentConditionType::EcommNumber,
+            "" => EcommerceNumberSegmentConditionType::Noop,
+            _ => EcommerceNumberSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommerceNumberSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceNumberSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommerceNumberSegmentConditionType {
     fn default() -> EcommerceNumberSegmentConditionType {
         EcommerceNumberSegmentConditionType::Noop
@@ -2782,7 +4265,8 @@ impl EcommerceNumberSegmentConditionType {
 /**
 * Segment by average spent total, number of orders, total number of products purchased, or average number of products per order.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommerceNumberSegmentField {
     #[serde(rename = "ecomm_avg_ord")]
     EcommAvgOrd,
@@ -2794,8 +4278,9 @@ pub enum EcommerceNumberSegmentField {
     EcommSpentAvg,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommerceNumberSegmentField {
@@ -2806,12 +4291,47 @@ impl std::fmt::Display for EcommerceNumberSegmentField {
             EcommerceNumberSegmentField::EcommProdAll => "ecomm_prod_all",
             EcommerceNumberSegmentField::EcommSpentAvg => "ecomm_spent_avg",
             EcommerceNumberSegmentField::Noop => "",
-            EcommerceNumberSegmentField::FallthroughString => "*",
+            EcommerceNumberSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommerceNumberSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_avg_ord" => EcommerceNumberSegmentField::EcommAvgOrd,
+            "ecomm_orders" => EcommerceNumberSegmentField::EcommOrders,
+            "ecomm_prod_all" => EcommerceNumberSegmentField::EcommProdAll,
+            "ecomm_spent_avg" => EcommerceNumberSegmentField::EcommSpentAvg,
+            "" => EcommerceNumberSegmentField::Noop,
+            _ => EcommerceNumberSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommerceNumberSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceNumberSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommerceNumberSegmentField {
     fn default() -> EcommerceNumberSegmentField {
         EcommerceNumberSegmentField::Noop
@@ -2852,14 +4372,16 @@ pub struct EcommerceNumberSegment {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommercePurchasedSegmentConditionType {
     #[serde(rename = "EcommPurchased")]
     EcommPurchased,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedSegmentConditionType {
@@ -2867,12 +4389,44 @@ impl std::fmt::Display for EcommercePurchasedSegmentConditionType {
         match self {
             EcommercePurchasedSegmentConditionType::EcommPurchased => "EcommPurchased",
             EcommercePurchasedSegmentConditionType::Noop => "",
-            EcommercePurchasedSegmentConditionType::FallthroughString => "*",
+            EcommercePurchasedSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommercePurchasedSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EcommPurchased" => EcommercePurchasedSegmentConditionType::EcommPurchased,
+            "" => EcommercePurchasedSegmentConditionType::Noop,
+            _ => EcommercePurchasedSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommercePurchasedSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommercePurchasedSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommercePurchasedSegmentConditionType {
     fn default() -> EcommercePurchasedSegmentConditionType {
         EcommercePurchasedSegmentConditionType::Noop
@@ -2887,14 +4441,16 @@ impl EcommercePurchasedSegmentConditionType {
 /**
 * Segment by whether someone has purchased anything.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommercePurchasedSegmentField {
     #[serde(rename = "ecomm_purchased")]
     EcommPurchased,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedSegmentField {
@@ -2902,12 +4458,44 @@ impl std::fmt::Display for EcommercePurchasedSegmentField {
         match self {
             EcommercePurchasedSegmentField::EcommPurchased => "ecomm_purchased",
             EcommercePurchasedSegmentField::Noop => "",
-            EcommercePurchasedSegmentField::FallthroughString => "*",
+            EcommercePurchasedSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommercePurchasedSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_purchased" => EcommercePurchasedSegmentField::EcommPurchased,
+            "" => EcommercePurchasedSegmentField::Noop,
+            _ => EcommercePurchasedSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommercePurchasedSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommercePurchasedSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommercePurchasedSegmentField {
     fn default() -> EcommercePurchasedSegmentField {
         EcommercePurchasedSegmentField::Noop
@@ -2939,14 +4527,16 @@ pub struct EcommercePurchasedSegment {
     pub op: Option<PollActivitySegmentOperator>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommerceSpentSegmentConditionType {
     #[serde(rename = "EcommSpent")]
     EcommSpent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentConditionType {
@@ -2954,12 +4544,44 @@ impl std::fmt::Display for EcommerceSpentSegmentConditionType {
         match self {
             EcommerceSpentSegmentConditionType::EcommSpent => "EcommSpent",
             EcommerceSpentSegmentConditionType::Noop => "",
-            EcommerceSpentSegmentConditionType::FallthroughString => "*",
+            EcommerceSpentSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommerceSpentSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EcommSpent" => EcommerceSpentSegmentConditionType::EcommSpent,
+            "" => EcommerceSpentSegmentConditionType::Noop,
+            _ => EcommerceSpentSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommerceSpentSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceSpentSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommerceSpentSegmentConditionType {
     fn default() -> EcommerceSpentSegmentConditionType {
         EcommerceSpentSegmentConditionType::Noop
@@ -2974,7 +4596,8 @@ impl EcommerceSpentSegmentConditionType {
 /**
 * Segment by amount spent on a single order or across all orders.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommerceSpentSegmentField {
     #[serde(rename = "ecomm_spent_all")]
     EcommSpentAll,
@@ -2982,8 +4605,9 @@ pub enum EcommerceSpentSegmentField {
     EcommSpentOne,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentField {
@@ -2992,12 +4616,45 @@ impl std::fmt::Display for EcommerceSpentSegmentField {
             EcommerceSpentSegmentField::EcommSpentAll => "ecomm_spent_all",
             EcommerceSpentSegmentField::EcommSpentOne => "ecomm_spent_one",
             EcommerceSpentSegmentField::Noop => "",
-            EcommerceSpentSegmentField::FallthroughString => "*",
+            EcommerceSpentSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommerceSpentSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_spent_all" => EcommerceSpentSegmentField::EcommSpentAll,
+            "ecomm_spent_one" => EcommerceSpentSegmentField::EcommSpentOne,
+            "" => EcommerceSpentSegmentField::Noop,
+            _ => EcommerceSpentSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommerceSpentSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceSpentSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommerceSpentSegmentField {
     fn default() -> EcommerceSpentSegmentField {
         EcommerceSpentSegmentField::Noop
@@ -3012,7 +4669,8 @@ impl EcommerceSpentSegmentField {
 /**
 * Members who have spent 'more' or 'less' than then specified value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommerceSpentSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -3020,8 +4678,9 @@ pub enum EcommerceSpentSegmentOperator {
     Less,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentOperator {
@@ -3030,12 +4689,45 @@ impl std::fmt::Display for EcommerceSpentSegmentOperator {
             EcommerceSpentSegmentOperator::Greater => "greater",
             EcommerceSpentSegmentOperator::Less => "less",
             EcommerceSpentSegmentOperator::Noop => "",
-            EcommerceSpentSegmentOperator::FallthroughString => "*",
+            EcommerceSpentSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommerceSpentSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "greater" => EcommerceSpentSegmentOperator::Greater,
+            "less" => EcommerceSpentSegmentOperator::Less,
+            "" => EcommerceSpentSegmentOperator::Noop,
+            _ => EcommerceSpentSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommerceSpentSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceSpentSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommerceSpentSegmentOperator {
     fn default() -> EcommerceSpentSegmentOperator {
         EcommerceSpentSegmentOperator::Noop
@@ -3076,14 +4768,16 @@ pub struct EcommerceSpentSegment {
     pub value: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommercePurchasedStoreSegmentConditionType {
     #[serde(rename = "EcommStore")]
     EcommStore,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedStoreSegmentConditionType {
@@ -3091,12 +4785,44 @@ impl std::fmt::Display for EcommercePurchasedStoreSegmentConditionType {
         match self {
             EcommercePurchasedStoreSegmentConditionType::EcommStore => "EcommStore",
             EcommercePurchasedStoreSegmentConditionType::Noop => "",
-            EcommercePurchasedStoreSegmentConditionType::FallthroughString => "*",
+            EcommercePurchasedStoreSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommercePurchasedStoreSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EcommStore" => EcommercePurchasedStoreSegmentConditionType::EcommStore,
+            "" => EcommercePurchasedStoreSegmentConditionType::Noop,
+            _ => EcommercePurchasedStoreSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommercePurchasedStoreSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommercePurchasedStoreSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommercePurchasedStoreSegmentConditionType {
     fn default() -> EcommercePurchasedStoreSegmentConditionType {
         EcommercePurchasedStoreSegmentConditionType::Noop
@@ -3111,14 +4837,16 @@ impl EcommercePurchasedStoreSegmentConditionType {
 /**
 * Segment by purchases from a specific store.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommercePurchasedStoreSegmentField {
     #[serde(rename = "ecomm_store")]
     EcommStore,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedStoreSegmentField {
@@ -3126,12 +4854,44 @@ impl std::fmt::Display for EcommercePurchasedStoreSegmentField {
         match self {
             EcommercePurchasedStoreSegmentField::EcommStore => "ecomm_store",
             EcommercePurchasedStoreSegmentField::Noop => "",
-            EcommercePurchasedStoreSegmentField::FallthroughString => "*",
+            EcommercePurchasedStoreSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommercePurchasedStoreSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_store" => EcommercePurchasedStoreSegmentField::EcommStore,
+            "" => EcommercePurchasedStoreSegmentField::Noop,
+            _ => EcommercePurchasedStoreSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommercePurchasedStoreSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommercePurchasedStoreSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommercePurchasedStoreSegmentField {
     fn default() -> EcommercePurchasedStoreSegmentField {
         EcommercePurchasedStoreSegmentField::Noop
@@ -3172,14 +4932,16 @@ pub struct EcommercePurchasedStoreSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GoalActivitySegmentConditionType {
     #[serde(rename = "GoalActivity")]
     GoalActivity,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentConditionType {
@@ -3187,12 +4949,44 @@ impl std::fmt::Display for GoalActivitySegmentConditionType {
         match self {
             GoalActivitySegmentConditionType::GoalActivity => "GoalActivity",
             GoalActivitySegmentConditionType::Noop => "",
-            GoalActivitySegmentConditionType::FallthroughString => "*",
+            GoalActivitySegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GoalActivitySegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "GoalActivity" => GoalActivitySegmentConditionType::GoalActivity,
+            "" => GoalActivitySegmentConditionType::Noop,
+            _ => GoalActivitySegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GoalActivitySegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GoalActivitySegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GoalActivitySegmentConditionType {
     fn default() -> GoalActivitySegmentConditionType {
         GoalActivitySegmentConditionType::Noop
@@ -3207,14 +5001,16 @@ impl GoalActivitySegmentConditionType {
 /**
 * Segment by Goal activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GoalActivitySegmentField {
     #[serde(rename = "goal")]
     Goal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentField {
@@ -3222,12 +5018,44 @@ impl std::fmt::Display for GoalActivitySegmentField {
         match self {
             GoalActivitySegmentField::Goal => "goal",
             GoalActivitySegmentField::Noop => "",
-            GoalActivitySegmentField::FallthroughString => "*",
+            GoalActivitySegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GoalActivitySegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "goal" => GoalActivitySegmentField::Goal,
+            "" => GoalActivitySegmentField::Noop,
+            _ => GoalActivitySegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GoalActivitySegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GoalActivitySegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GoalActivitySegmentField {
     fn default() -> GoalActivitySegmentField {
         GoalActivitySegmentField::Noop
@@ -3242,7 +5070,8 @@ impl GoalActivitySegmentField {
 /**
 * Whether the website URL is/not exactly, contains/doesn't contain, starts with/ends with a string.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GoalActivitySegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -3258,8 +5087,9 @@ pub enum GoalActivitySegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentOperator {
@@ -3272,12 +5102,49 @@ impl std::fmt::Display for GoalActivitySegmentOperator {
             GoalActivitySegmentOperator::Is => "is",
             GoalActivitySegmentOperator::Starts => "starts",
             GoalActivitySegmentOperator::Noop => "",
-            GoalActivitySegmentOperator::FallthroughString => "*",
+            GoalActivitySegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GoalActivitySegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "contains" => GoalActivitySegmentOperator::Contains,
+            "ends" => GoalActivitySegmentOperator::Ends,
+            "goal_not" => GoalActivitySegmentOperator::GoalNot,
+            "goal_notcontain" => GoalActivitySegmentOperator::GoalNotcontain,
+            "is" => GoalActivitySegmentOperator::Is,
+            "starts" => GoalActivitySegmentOperator::Starts,
+            "" => GoalActivitySegmentOperator::Noop,
+            _ => GoalActivitySegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GoalActivitySegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GoalActivitySegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GoalActivitySegmentOperator {
     fn default() -> GoalActivitySegmentOperator {
         GoalActivitySegmentOperator::Noop
@@ -3318,14 +5185,16 @@ pub struct GoalActivitySegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GoalTimestampSegmentConditionType {
     #[serde(rename = "GoalTimestamp")]
     GoalTimestamp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentConditionType {
@@ -3333,12 +5202,44 @@ impl std::fmt::Display for GoalTimestampSegmentConditionType {
         match self {
             GoalTimestampSegmentConditionType::GoalTimestamp => "GoalTimestamp",
             GoalTimestampSegmentConditionType::Noop => "",
-            GoalTimestampSegmentConditionType::FallthroughString => "*",
+            GoalTimestampSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GoalTimestampSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "GoalTimestamp" => GoalTimestampSegmentConditionType::GoalTimestamp,
+            "" => GoalTimestampSegmentConditionType::Noop,
+            _ => GoalTimestampSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GoalTimestampSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GoalTimestampSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GoalTimestampSegmentConditionType {
     fn default() -> GoalTimestampSegmentConditionType {
         GoalTimestampSegmentConditionType::Noop
@@ -3353,14 +5254,16 @@ impl GoalTimestampSegmentConditionType {
 /**
 * Segment by most recent interaction with a website.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GoalTimestampSegmentField {
     #[serde(rename = "goal_last_visited")]
     GoalLastVisited,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentField {
@@ -3368,12 +5271,44 @@ impl std::fmt::Display for GoalTimestampSegmentField {
         match self {
             GoalTimestampSegmentField::GoalLastVisited => "goal_last_visited",
             GoalTimestampSegmentField::Noop => "",
-            GoalTimestampSegmentField::FallthroughString => "*",
+            GoalTimestampSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GoalTimestampSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "goal_last_visited" => GoalTimestampSegmentField::GoalLastVisited,
+            "" => GoalTimestampSegmentField::Noop,
+            _ => GoalTimestampSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GoalTimestampSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GoalTimestampSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GoalTimestampSegmentField {
     fn default() -> GoalTimestampSegmentField {
         GoalTimestampSegmentField::Noop
@@ -3388,7 +5323,8 @@ impl GoalTimestampSegmentField {
 /**
 * Whether the website activity happened after, before, or at a given timestamp.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GoalTimestampSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -3398,8 +5334,9 @@ pub enum GoalTimestampSegmentOperator {
     Less,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentOperator {
@@ -3409,12 +5346,46 @@ impl std::fmt::Display for GoalTimestampSegmentOperator {
             GoalTimestampSegmentOperator::Is => "is",
             GoalTimestampSegmentOperator::Less => "less",
             GoalTimestampSegmentOperator::Noop => "",
-            GoalTimestampSegmentOperator::FallthroughString => "*",
+            GoalTimestampSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GoalTimestampSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "greater" => GoalTimestampSegmentOperator::Greater,
+            "is" => GoalTimestampSegmentOperator::Is,
+            "less" => GoalTimestampSegmentOperator::Less,
+            "" => GoalTimestampSegmentOperator::Noop,
+            _ => GoalTimestampSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GoalTimestampSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GoalTimestampSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GoalTimestampSegmentOperator {
     fn default() -> GoalTimestampSegmentOperator {
         GoalTimestampSegmentOperator::Noop
@@ -3455,14 +5426,16 @@ pub struct GoalTimestampSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SimilarSubscribersSegmentMemberConditionType {
     #[serde(rename = "FuzzySegment")]
     FuzzySegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberConditionType {
@@ -3470,12 +5443,44 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberConditionType {
         match self {
             SimilarSubscribersSegmentMemberConditionType::FuzzySegment => "FuzzySegment",
             SimilarSubscribersSegmentMemberConditionType::Noop => "",
-            SimilarSubscribersSegmentMemberConditionType::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SimilarSubscribersSegmentMemberConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "FuzzySegment" => SimilarSubscribersSegmentMemberConditionType::FuzzySegment,
+            "" => SimilarSubscribersSegmentMemberConditionType::Noop,
+            _ => SimilarSubscribersSegmentMemberConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SimilarSubscribersSegmentMemberConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SimilarSubscribersSegmentMemberConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SimilarSubscribersSegmentMemberConditionType {
     fn default() -> SimilarSubscribersSegmentMemberConditionType {
         SimilarSubscribersSegmentMemberConditionType::Noop
@@ -3490,14 +5495,16 @@ impl SimilarSubscribersSegmentMemberConditionType {
 /**
 * Segment by similar subscribers.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SimilarSubscribersSegmentMemberField {
     #[serde(rename = "fuzzy_segment")]
     FuzzySegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberField {
@@ -3505,12 +5512,44 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberField {
         match self {
             SimilarSubscribersSegmentMemberField::FuzzySegment => "fuzzy_segment",
             SimilarSubscribersSegmentMemberField::Noop => "",
-            SimilarSubscribersSegmentMemberField::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SimilarSubscribersSegmentMemberField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "fuzzy_segment" => SimilarSubscribersSegmentMemberField::FuzzySegment,
+            "" => SimilarSubscribersSegmentMemberField::Noop,
+            _ => SimilarSubscribersSegmentMemberField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SimilarSubscribersSegmentMemberField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SimilarSubscribersSegmentMemberField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SimilarSubscribersSegmentMemberField {
     fn default() -> SimilarSubscribersSegmentMemberField {
         SimilarSubscribersSegmentMemberField::Noop
@@ -3525,7 +5564,8 @@ impl SimilarSubscribersSegmentMemberField {
 /**
 * Members who are/are not apart of a 'similar subscribers' segment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SimilarSubscribersSegmentMemberOperator {
     #[serde(rename = "fuzzy_is")]
     FuzzyIs,
@@ -3533,8 +5573,9 @@ pub enum SimilarSubscribersSegmentMemberOperator {
     FuzzyNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberOperator {
@@ -3543,12 +5584,45 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberOperator {
             SimilarSubscribersSegmentMemberOperator::FuzzyIs => "fuzzy_is",
             SimilarSubscribersSegmentMemberOperator::FuzzyNot => "fuzzy_not",
             SimilarSubscribersSegmentMemberOperator::Noop => "",
-            SimilarSubscribersSegmentMemberOperator::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SimilarSubscribersSegmentMemberOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "fuzzy_is" => SimilarSubscribersSegmentMemberOperator::FuzzyIs,
+            "fuzzy_not" => SimilarSubscribersSegmentMemberOperator::FuzzyNot,
+            "" => SimilarSubscribersSegmentMemberOperator::Noop,
+            _ => SimilarSubscribersSegmentMemberOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SimilarSubscribersSegmentMemberOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SimilarSubscribersSegmentMemberOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SimilarSubscribersSegmentMemberOperator {
     fn default() -> SimilarSubscribersSegmentMemberOperator {
         SimilarSubscribersSegmentMemberOperator::Noop
@@ -3595,14 +5669,16 @@ pub struct SimilarSubscribersSegmentMember {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum StaticSegmentMemberConditionType {
     #[serde(rename = "StaticSegment")]
     StaticSegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberConditionType {
@@ -3610,12 +5686,44 @@ impl std::fmt::Display for StaticSegmentMemberConditionType {
         match self {
             StaticSegmentMemberConditionType::StaticSegment => "StaticSegment",
             StaticSegmentMemberConditionType::Noop => "",
-            StaticSegmentMemberConditionType::FallthroughString => "*",
+            StaticSegmentMemberConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for StaticSegmentMemberConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "StaticSegment" => StaticSegmentMemberConditionType::StaticSegment,
+            "" => StaticSegmentMemberConditionType::Noop,
+            _ => StaticSegmentMemberConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for StaticSegmentMemberConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for StaticSegmentMemberConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for StaticSegmentMemberConditionType {
     fn default() -> StaticSegmentMemberConditionType {
         StaticSegmentMemberConditionType::Noop
@@ -3630,14 +5738,16 @@ impl StaticSegmentMemberConditionType {
 /**
 * Segment by a given static segment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum StaticSegmentMemberField {
     #[serde(rename = "static_segment")]
     StaticSegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberField {
@@ -3645,12 +5755,44 @@ impl std::fmt::Display for StaticSegmentMemberField {
         match self {
             StaticSegmentMemberField::StaticSegment => "static_segment",
             StaticSegmentMemberField::Noop => "",
-            StaticSegmentMemberField::FallthroughString => "*",
+            StaticSegmentMemberField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for StaticSegmentMemberField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "static_segment" => StaticSegmentMemberField::StaticSegment,
+            "" => StaticSegmentMemberField::Noop,
+            _ => StaticSegmentMemberField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for StaticSegmentMemberField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for StaticSegmentMemberField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for StaticSegmentMemberField {
     fn default() -> StaticSegmentMemberField {
         StaticSegmentMemberField::Noop
@@ -3665,7 +5807,8 @@ impl StaticSegmentMemberField {
 /**
 * Members who are/are not apart of a static segment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum StaticSegmentMemberOperator {
     #[serde(rename = "static_is")]
     StaticIs,
@@ -3673,8 +5816,9 @@ pub enum StaticSegmentMemberOperator {
     StaticNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberOperator {
@@ -3683,12 +5827,45 @@ impl std::fmt::Display for StaticSegmentMemberOperator {
             StaticSegmentMemberOperator::StaticIs => "static_is",
             StaticSegmentMemberOperator::StaticNot => "static_not",
             StaticSegmentMemberOperator::Noop => "",
-            StaticSegmentMemberOperator::FallthroughString => "*",
+            StaticSegmentMemberOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for StaticSegmentMemberOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "static_is" => StaticSegmentMemberOperator::StaticIs,
+            "static_not" => StaticSegmentMemberOperator::StaticNot,
+            "" => StaticSegmentMemberOperator::Noop,
+            _ => StaticSegmentMemberOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for StaticSegmentMemberOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for StaticSegmentMemberOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for StaticSegmentMemberOperator {
     fn default() -> StaticSegmentMemberOperator {
         StaticSegmentMemberOperator::Noop
@@ -3729,14 +5906,16 @@ pub struct StaticSegmentMember {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoCountryState")]
     IpGeoCountryState,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentConditionType {
@@ -3744,12 +5923,44 @@ impl std::fmt::Display for LocationBasedSegmentConditionType {
         match self {
             LocationBasedSegmentConditionType::IpGeoCountryState => "IPGeoCountryState",
             LocationBasedSegmentConditionType::Noop => "",
-            LocationBasedSegmentConditionType::FallthroughString => "*",
+            LocationBasedSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LocationBasedSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "IPGeoCountryState" => LocationBasedSegmentConditionType::IpGeoCountryState,
+            "" => LocationBasedSegmentConditionType::Noop,
+            _ => LocationBasedSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LocationBasedSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LocationBasedSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LocationBasedSegmentConditionType {
     fn default() -> LocationBasedSegmentConditionType {
         LocationBasedSegmentConditionType::Noop
@@ -3764,14 +5975,16 @@ impl LocationBasedSegmentConditionType {
 /**
 * Segmenting subscribers who are within a specific location.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LocationBasedSegmentField {
     #[serde(rename = "ipgeo")]
     Ipgeo,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentField {
@@ -3779,12 +5992,44 @@ impl std::fmt::Display for LocationBasedSegmentField {
         match self {
             LocationBasedSegmentField::Ipgeo => "ipgeo",
             LocationBasedSegmentField::Noop => "",
-            LocationBasedSegmentField::FallthroughString => "*",
+            LocationBasedSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LocationBasedSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ipgeo" => LocationBasedSegmentField::Ipgeo,
+            "" => LocationBasedSegmentField::Noop,
+            _ => LocationBasedSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LocationBasedSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LocationBasedSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LocationBasedSegmentField {
     fn default() -> LocationBasedSegmentField {
         LocationBasedSegmentField::Noop
@@ -3799,7 +6044,8 @@ impl LocationBasedSegmentField {
 /**
 * Segment members who are within a specific country or US state.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LocationBasedSegmentOperator {
     #[serde(rename = "ipgeocountry")]
     Ipgeocountry,
@@ -3811,8 +6057,9 @@ pub enum LocationBasedSegmentOperator {
     Ipgeostate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentOperator {
@@ -3823,12 +6070,47 @@ impl std::fmt::Display for LocationBasedSegmentOperator {
             LocationBasedSegmentOperator::Ipgeonotstate => "ipgeonotstate",
             LocationBasedSegmentOperator::Ipgeostate => "ipgeostate",
             LocationBasedSegmentOperator::Noop => "",
-            LocationBasedSegmentOperator::FallthroughString => "*",
+            LocationBasedSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LocationBasedSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ipgeocountry" => LocationBasedSegmentOperator::Ipgeocountry,
+            "ipgeonotcountry" => LocationBasedSegmentOperator::Ipgeonotcountry,
+            "ipgeonotstate" => LocationBasedSegmentOperator::Ipgeonotstate,
+            "ipgeostate" => LocationBasedSegmentOperator::Ipgeostate,
+            "" => LocationBasedSegmentOperator::Noop,
+            _ => LocationBasedSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LocationBasedSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LocationBasedSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LocationBasedSegmentOperator {
     fn default() -> LocationBasedSegmentOperator {
         LocationBasedSegmentOperator::Noop
@@ -3869,14 +6151,16 @@ pub struct LocationBasedSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GeolocationSegmentConditionType {
     #[serde(rename = "IPGeoIn")]
     IpGeoIn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GeolocationSegmentConditionType {
@@ -3884,12 +6168,44 @@ impl std::fmt::Display for GeolocationSegmentConditionType {
         match self {
             GeolocationSegmentConditionType::IpGeoIn => "IPGeoIn",
             GeolocationSegmentConditionType::Noop => "",
-            GeolocationSegmentConditionType::FallthroughString => "*",
+            GeolocationSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GeolocationSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "IPGeoIn" => GeolocationSegmentConditionType::IpGeoIn,
+            "" => GeolocationSegmentConditionType::Noop,
+            _ => GeolocationSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GeolocationSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GeolocationSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GeolocationSegmentConditionType {
     fn default() -> GeolocationSegmentConditionType {
         GeolocationSegmentConditionType::Noop
@@ -3904,7 +6220,8 @@ impl GeolocationSegmentConditionType {
 /**
 * Segment members who are within a specific geographic region.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GeolocationSegmentOperator {
     #[serde(rename = "ipgeoin")]
     Ipgeoin,
@@ -3912,8 +6229,9 @@ pub enum GeolocationSegmentOperator {
     Ipgeonotin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GeolocationSegmentOperator {
@@ -3922,12 +6240,45 @@ impl std::fmt::Display for GeolocationSegmentOperator {
             GeolocationSegmentOperator::Ipgeoin => "ipgeoin",
             GeolocationSegmentOperator::Ipgeonotin => "ipgeonotin",
             GeolocationSegmentOperator::Noop => "",
-            GeolocationSegmentOperator::FallthroughString => "*",
+            GeolocationSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GeolocationSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ipgeoin" => GeolocationSegmentOperator::Ipgeoin,
+            "ipgeonotin" => GeolocationSegmentOperator::Ipgeonotin,
+            "" => GeolocationSegmentOperator::Noop,
+            _ => GeolocationSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GeolocationSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GeolocationSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GeolocationSegmentOperator {
     fn default() -> GeolocationSegmentOperator {
         GeolocationSegmentOperator::Noop
@@ -3995,14 +6346,16 @@ pub struct GeolocationSegment {
     pub value: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UsZipCodeSegmentConditionType {
     #[serde(rename = "IPGeoInZip")]
     IpGeoInZip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UsZipCodeSegmentConditionType {
@@ -4010,12 +6363,44 @@ impl std::fmt::Display for UsZipCodeSegmentConditionType {
         match self {
             UsZipCodeSegmentConditionType::IpGeoInZip => "IPGeoInZip",
             UsZipCodeSegmentConditionType::Noop => "",
-            UsZipCodeSegmentConditionType::FallthroughString => "*",
+            UsZipCodeSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UsZipCodeSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "IPGeoInZip" => UsZipCodeSegmentConditionType::IpGeoInZip,
+            "" => UsZipCodeSegmentConditionType::Noop,
+            _ => UsZipCodeSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UsZipCodeSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UsZipCodeSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UsZipCodeSegmentConditionType {
     fn default() -> UsZipCodeSegmentConditionType {
         UsZipCodeSegmentConditionType::Noop
@@ -4030,14 +6415,16 @@ impl UsZipCodeSegmentConditionType {
 /**
 * Segment members who are within a specific US zip code.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UsZipCodeSegmentOperator {
     #[serde(rename = "ipgeoinzip")]
     Ipgeoinzip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UsZipCodeSegmentOperator {
@@ -4045,12 +6432,44 @@ impl std::fmt::Display for UsZipCodeSegmentOperator {
         match self {
             UsZipCodeSegmentOperator::Ipgeoinzip => "ipgeoinzip",
             UsZipCodeSegmentOperator::Noop => "",
-            UsZipCodeSegmentOperator::FallthroughString => "*",
+            UsZipCodeSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UsZipCodeSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ipgeoinzip" => UsZipCodeSegmentOperator::Ipgeoinzip,
+            "" => UsZipCodeSegmentOperator::Noop,
+            _ => UsZipCodeSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UsZipCodeSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UsZipCodeSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UsZipCodeSegmentOperator {
     fn default() -> UsZipCodeSegmentOperator {
         UsZipCodeSegmentOperator::Noop
@@ -4100,14 +6519,16 @@ pub struct UsZipCodeSegment {
     pub value: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UnknownLocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoUnknown")]
     IpGeoUnknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UnknownLocationBasedSegmentConditionType {
@@ -4115,12 +6536,44 @@ impl std::fmt::Display for UnknownLocationBasedSegmentConditionType {
         match self {
             UnknownLocationBasedSegmentConditionType::IpGeoUnknown => "IPGeoUnknown",
             UnknownLocationBasedSegmentConditionType::Noop => "",
-            UnknownLocationBasedSegmentConditionType::FallthroughString => "*",
+            UnknownLocationBasedSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UnknownLocationBasedSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "IPGeoUnknown" => UnknownLocationBasedSegmentConditionType::IpGeoUnknown,
+            "" => UnknownLocationBasedSegmentConditionType::Noop,
+            _ => UnknownLocationBasedSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UnknownLocationBasedSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UnknownLocationBasedSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UnknownLocationBasedSegmentConditionType {
     fn default() -> UnknownLocationBasedSegmentConditionType {
         UnknownLocationBasedSegmentConditionType::Noop
@@ -4135,14 +6588,16 @@ impl UnknownLocationBasedSegmentConditionType {
 /**
 * Segment members for which location information is unknown.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UnknownLocationBasedSegmentOperator {
     #[serde(rename = "ipgeounknown")]
     Ipgeounknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UnknownLocationBasedSegmentOperator {
@@ -4150,12 +6605,44 @@ impl std::fmt::Display for UnknownLocationBasedSegmentOperator {
         match self {
             UnknownLocationBasedSegmentOperator::Ipgeounknown => "ipgeounknown",
             UnknownLocationBasedSegmentOperator::Noop => "",
-            UnknownLocationBasedSegmentOperator::FallthroughString => "*",
+            UnknownLocationBasedSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UnknownLocationBasedSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ipgeounknown" => UnknownLocationBasedSegmentOperator::Ipgeounknown,
+            "" => UnknownLocationBasedSegmentOperator::Noop,
+            _ => UnknownLocationBasedSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UnknownLocationBasedSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UnknownLocationBasedSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UnknownLocationBasedSegmentOperator {
     fn default() -> UnknownLocationBasedSegmentOperator {
         UnknownLocationBasedSegmentOperator::Noop
@@ -4190,14 +6677,16 @@ pub struct UnknownLocationBasedSegment {
     pub op: UnknownLocationBasedSegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ZipCodeLocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoZip")]
     IpGeoZip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ZipCodeLocationBasedSegmentConditionType {
@@ -4205,12 +6694,44 @@ impl std::fmt::Display for ZipCodeLocationBasedSegmentConditionType {
         match self {
             ZipCodeLocationBasedSegmentConditionType::IpGeoZip => "IPGeoZip",
             ZipCodeLocationBasedSegmentConditionType::Noop => "",
-            ZipCodeLocationBasedSegmentConditionType::FallthroughString => "*",
+            ZipCodeLocationBasedSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ZipCodeLocationBasedSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "IPGeoZip" => ZipCodeLocationBasedSegmentConditionType::IpGeoZip,
+            "" => ZipCodeLocationBasedSegmentConditionType::Noop,
+            _ => ZipCodeLocationBasedSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ZipCodeLocationBasedSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ZipCodeLocationBasedSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ZipCodeLocationBasedSegmentConditionType {
     fn default() -> ZipCodeLocationBasedSegmentConditionType {
         ZipCodeLocationBasedSegmentConditionType::Noop
@@ -4225,7 +6746,8 @@ impl ZipCodeLocationBasedSegmentConditionType {
 /**
 * Segment members who are/are not within a specific US zip code.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ZipCodeLocationBasedSegmentOperator {
     #[serde(rename = "ipgeoiszip")]
     Ipgeoiszip,
@@ -4233,8 +6755,9 @@ pub enum ZipCodeLocationBasedSegmentOperator {
     Ipgeonotzip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ZipCodeLocationBasedSegmentOperator {
@@ -4243,12 +6766,45 @@ impl std::fmt::Display for ZipCodeLocationBasedSegmentOperator {
             ZipCodeLocationBasedSegmentOperator::Ipgeoiszip => "ipgeoiszip",
             ZipCodeLocationBasedSegmentOperator::Ipgeonotzip => "ipgeonotzip",
             ZipCodeLocationBasedSegmentOperator::Noop => "",
-            ZipCodeLocationBasedSegmentOperator::FallthroughString => "*",
+            ZipCodeLocationBasedSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ZipCodeLocationBasedSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ipgeoiszip" => ZipCodeLocationBasedSegmentOperator::Ipgeoiszip,
+            "ipgeonotzip" => ZipCodeLocationBasedSegmentOperator::Ipgeonotzip,
+            "" => ZipCodeLocationBasedSegmentOperator::Noop,
+            _ => ZipCodeLocationBasedSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ZipCodeLocationBasedSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ZipCodeLocationBasedSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ZipCodeLocationBasedSegmentOperator {
     fn default() -> ZipCodeLocationBasedSegmentOperator {
         ZipCodeLocationBasedSegmentOperator::Noop
@@ -4292,14 +6848,16 @@ pub struct ZipCodeLocationBasedSegment {
     pub value: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SocialProfilesAgeSegmentConditionType {
     #[serde(rename = "SocialAge")]
     SocialAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SocialProfilesAgeSegmentConditionType {
@@ -4307,12 +6865,44 @@ impl std::fmt::Display for SocialProfilesAgeSegmentConditionType {
         match self {
             SocialProfilesAgeSegmentConditionType::SocialAge => "SocialAge",
             SocialProfilesAgeSegmentConditionType::Noop => "",
-            SocialProfilesAgeSegmentConditionType::FallthroughString => "*",
+            SocialProfilesAgeSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SocialProfilesAgeSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SocialAge" => SocialProfilesAgeSegmentConditionType::SocialAge,
+            "" => SocialProfilesAgeSegmentConditionType::Noop,
+            _ => SocialProfilesAgeSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SocialProfilesAgeSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesAgeSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SocialProfilesAgeSegmentConditionType {
     fn default() -> SocialProfilesAgeSegmentConditionType {
         SocialProfilesAgeSegmentConditionType::Noop
@@ -4327,14 +6917,16 @@ impl SocialProfilesAgeSegmentConditionType {
 /**
 * Segment by age ranges in Social Profiles data.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SocialProfilesAgeSegmentField {
     #[serde(rename = "social_age")]
     SocialAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SocialProfilesAgeSegmentField {
@@ -4342,12 +6934,44 @@ impl std::fmt::Display for SocialProfilesAgeSegmentField {
         match self {
             SocialProfilesAgeSegmentField::SocialAge => "social_age",
             SocialProfilesAgeSegmentField::Noop => "",
-            SocialProfilesAgeSegmentField::FallthroughString => "*",
+            SocialProfilesAgeSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SocialProfilesAgeSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "social_age" => SocialProfilesAgeSegmentField::SocialAge,
+            "" => SocialProfilesAgeSegmentField::Noop,
+            _ => SocialProfilesAgeSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SocialProfilesAgeSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesAgeSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SocialProfilesAgeSegmentField {
     fn default() -> SocialProfilesAgeSegmentField {
         SocialProfilesAgeSegmentField::Noop
@@ -4362,7 +6986,8 @@ impl SocialProfilesAgeSegmentField {
 /**
 * The age range to segment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Value {
     #[serde(rename = "18-24")]
     OneThousandEightHundredAndTwentyFour,
@@ -4374,8 +6999,9 @@ pub enum Value {
     FiftyFive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Value {
@@ -4386,12 +7012,47 @@ impl std::fmt::Display for Value {
             Value::ThreeThousandFiveHundredAndFiftyFour => "35-54",
             Value::FiftyFive => "55+",
             Value::Noop => "",
-            Value::FallthroughString => "*",
+            Value::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Value {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "18-24" => Value::OneThousandEightHundredAndTwentyFour,
+            "25-34" => Value::TwoThousandFiveHundredAndThirtyFour,
+            "35-54" => Value::ThreeThousandFiveHundredAndFiftyFour,
+            "55+" => Value::FiftyFive,
+            "" => Value::Noop,
+            _ => Value::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Value {
     fn default() -> Value {
         Value::Noop
@@ -4431,14 +7092,16 @@ pub struct SocialProfilesAgeSegment {
     pub value: Value,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SocialProfilesGenderSegmentConditionType {
     #[serde(rename = "SocialGender")]
     SocialGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentConditionType {
@@ -4446,12 +7109,44 @@ impl std::fmt::Display for SocialProfilesGenderSegmentConditionType {
         match self {
             SocialProfilesGenderSegmentConditionType::SocialGender => "SocialGender",
             SocialProfilesGenderSegmentConditionType::Noop => "",
-            SocialProfilesGenderSegmentConditionType::FallthroughString => "*",
+            SocialProfilesGenderSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SocialProfilesGenderSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SocialGender" => SocialProfilesGenderSegmentConditionType::SocialGender,
+            "" => SocialProfilesGenderSegmentConditionType::Noop,
+            _ => SocialProfilesGenderSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SocialProfilesGenderSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesGenderSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SocialProfilesGenderSegmentConditionType {
     fn default() -> SocialProfilesGenderSegmentConditionType {
         SocialProfilesGenderSegmentConditionType::Noop
@@ -4466,14 +7161,16 @@ impl SocialProfilesGenderSegmentConditionType {
 /**
 * Segment by listed gender in Social Profiles data.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SocialProfilesGenderSegmentField {
     #[serde(rename = "social_gender")]
     SocialGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentField {
@@ -4481,12 +7178,44 @@ impl std::fmt::Display for SocialProfilesGenderSegmentField {
         match self {
             SocialProfilesGenderSegmentField::SocialGender => "social_gender",
             SocialProfilesGenderSegmentField::Noop => "",
-            SocialProfilesGenderSegmentField::FallthroughString => "*",
+            SocialProfilesGenderSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SocialProfilesGenderSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "social_gender" => SocialProfilesGenderSegmentField::SocialGender,
+            "" => SocialProfilesGenderSegmentField::Noop,
+            _ => SocialProfilesGenderSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SocialProfilesGenderSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesGenderSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SocialProfilesGenderSegmentField {
     fn default() -> SocialProfilesGenderSegmentField {
         SocialProfilesGenderSegmentField::Noop
@@ -4501,7 +7230,8 @@ impl SocialProfilesGenderSegmentField {
 /**
 * The Social Profiles gender to segment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SocialProfilesGenderSegmentOperator {
     #[serde(rename = "female")]
     Female,
@@ -4509,8 +7239,9 @@ pub enum SocialProfilesGenderSegmentOperator {
     Male,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentOperator {
@@ -4519,12 +7250,45 @@ impl std::fmt::Display for SocialProfilesGenderSegmentOperator {
             SocialProfilesGenderSegmentOperator::Female => "female",
             SocialProfilesGenderSegmentOperator::Male => "male",
             SocialProfilesGenderSegmentOperator::Noop => "",
-            SocialProfilesGenderSegmentOperator::FallthroughString => "*",
+            SocialProfilesGenderSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SocialProfilesGenderSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "female" => SocialProfilesGenderSegmentOperator::Female,
+            "male" => SocialProfilesGenderSegmentOperator::Male,
+            "" => SocialProfilesGenderSegmentOperator::Noop,
+            _ => SocialProfilesGenderSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SocialProfilesGenderSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesGenderSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SocialProfilesGenderSegmentOperator {
     fn default() -> SocialProfilesGenderSegmentOperator {
         SocialProfilesGenderSegmentOperator::Noop
@@ -4567,14 +7331,16 @@ pub struct SocialProfilesGenderSegment {
     pub value: SocialProfilesGenderSegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SocialProfilesInfluenceSegmentConditionType {
     #[serde(rename = "SocialInfluence")]
     SocialInfluence,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SocialProfilesInfluenceSegmentConditionType {
@@ -4582,12 +7348,44 @@ impl std::fmt::Display for SocialProfilesInfluenceSegmentConditionType {
         match self {
             SocialProfilesInfluenceSegmentConditionType::SocialInfluence => "SocialInfluence",
             SocialProfilesInfluenceSegmentConditionType::Noop => "",
-            SocialProfilesInfluenceSegmentConditionType::FallthroughString => "*",
+            SocialProfilesInfluenceSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SocialProfilesInfluenceSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SocialInfluence" => SocialProfilesInfluenceSegmentConditionType::SocialInfluence,
+            "" => SocialProfilesInfluenceSegmentConditionType::Noop,
+            _ => SocialProfilesInfluenceSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SocialProfilesInfluenceSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesInfluenceSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SocialProfilesInfluenceSegmentConditionType {
     fn default() -> SocialProfilesInfluenceSegmentConditionType {
         SocialProfilesInfluenceSegmentConditionType::Noop
@@ -4602,14 +7400,16 @@ impl SocialProfilesInfluenceSegmentConditionType {
 /**
 * Segment by influence rating in Social Profiles data.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SocialProfilesInfluenceSegmentField {
     #[serde(rename = "social_influence")]
     SocialInfluence,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SocialProfilesInfluenceSegmentField {
@@ -4617,12 +7417,44 @@ impl std::fmt::Display for SocialProfilesInfluenceSegmentField {
         match self {
             SocialProfilesInfluenceSegmentField::SocialInfluence => "social_influence",
             SocialProfilesInfluenceSegmentField::Noop => "",
-            SocialProfilesInfluenceSegmentField::FallthroughString => "*",
+            SocialProfilesInfluenceSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SocialProfilesInfluenceSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "social_influence" => SocialProfilesInfluenceSegmentField::SocialInfluence,
+            "" => SocialProfilesInfluenceSegmentField::Noop,
+            _ => SocialProfilesInfluenceSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SocialProfilesInfluenceSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesInfluenceSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SocialProfilesInfluenceSegmentField {
     fn default() -> SocialProfilesInfluenceSegmentField {
         SocialProfilesInfluenceSegmentField::Noop
@@ -4666,14 +7498,16 @@ pub struct SocialProfilesInfluenceSegment {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SocialProfilesNetworkSegmentConditionType {
     #[serde(rename = "SocialNetworkMember")]
     SocialNetworkMember,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentConditionType {
@@ -4681,12 +7515,44 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentConditionType {
         match self {
             SocialProfilesNetworkSegmentConditionType::SocialNetworkMember => "SocialNetworkMember",
             SocialProfilesNetworkSegmentConditionType::Noop => "",
-            SocialProfilesNetworkSegmentConditionType::FallthroughString => "*",
+            SocialProfilesNetworkSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SocialProfilesNetworkSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SocialNetworkMember" => SocialProfilesNetworkSegmentConditionType::SocialNetworkMember,
+            "" => SocialProfilesNetworkSegmentConditionType::Noop,
+            _ => SocialProfilesNetworkSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SocialProfilesNetworkSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesNetworkSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SocialProfilesNetworkSegmentConditionType {
     fn default() -> SocialProfilesNetworkSegmentConditionType {
         SocialProfilesNetworkSegmentConditionType::Noop
@@ -4701,14 +7567,16 @@ impl SocialProfilesNetworkSegmentConditionType {
 /**
 * Segment by social network in Social Profiles data.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SocialProfilesNetworkSegmentField {
     #[serde(rename = "social_network")]
     SocialNetwork,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentField {
@@ -4716,12 +7584,44 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentField {
         match self {
             SocialProfilesNetworkSegmentField::SocialNetwork => "social_network",
             SocialProfilesNetworkSegmentField::Noop => "",
-            SocialProfilesNetworkSegmentField::FallthroughString => "*",
+            SocialProfilesNetworkSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SocialProfilesNetworkSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "social_network" => SocialProfilesNetworkSegmentField::SocialNetwork,
+            "" => SocialProfilesNetworkSegmentField::Noop,
+            _ => SocialProfilesNetworkSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SocialProfilesNetworkSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesNetworkSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SocialProfilesNetworkSegmentField {
     fn default() -> SocialProfilesNetworkSegmentField {
         SocialProfilesNetworkSegmentField::Noop
@@ -4736,7 +7636,8 @@ impl SocialProfilesNetworkSegmentField {
 /**
 * The social network to segment against.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SocialProfilesNetworkSegmentOperator {
     #[serde(rename = "facebook")]
     Facebook,
@@ -4762,8 +7663,9 @@ pub enum SocialProfilesNetworkSegmentOperator {
     Youtube,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentOperator {
@@ -4781,12 +7683,54 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentOperator {
             SocialProfilesNetworkSegmentOperator::Yelp => "yelp",
             SocialProfilesNetworkSegmentOperator::Youtube => "youtube",
             SocialProfilesNetworkSegmentOperator::Noop => "",
-            SocialProfilesNetworkSegmentOperator::FallthroughString => "*",
+            SocialProfilesNetworkSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SocialProfilesNetworkSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "facebook" => SocialProfilesNetworkSegmentOperator::Facebook,
+            "flickr" => SocialProfilesNetworkSegmentOperator::Flickr,
+            "foursquare" => SocialProfilesNetworkSegmentOperator::Foursquare,
+            "lastfm" => SocialProfilesNetworkSegmentOperator::Lastfm,
+            "linkedin" => SocialProfilesNetworkSegmentOperator::Linkedin,
+            "myspace" => SocialProfilesNetworkSegmentOperator::Myspace,
+            "quora" => SocialProfilesNetworkSegmentOperator::Quora,
+            "twitter" => SocialProfilesNetworkSegmentOperator::Twitter,
+            "vimeo" => SocialProfilesNetworkSegmentOperator::Vimeo,
+            "yelp" => SocialProfilesNetworkSegmentOperator::Yelp,
+            "youtube" => SocialProfilesNetworkSegmentOperator::Youtube,
+            "" => SocialProfilesNetworkSegmentOperator::Noop,
+            _ => SocialProfilesNetworkSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SocialProfilesNetworkSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesNetworkSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SocialProfilesNetworkSegmentOperator {
     fn default() -> SocialProfilesNetworkSegmentOperator {
         SocialProfilesNetworkSegmentOperator::Noop
@@ -4829,14 +7773,16 @@ pub struct SocialProfilesNetworkSegment {
     pub value: SocialProfilesNetworkSegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SocialProfilesNetworkFollowSegmentConditionType {
     #[serde(rename = "SocialNetworkFollow")]
     SocialNetworkFollow,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentConditionType {
@@ -4846,12 +7792,46 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentConditionType {
                 "SocialNetworkFollow"
             }
             SocialProfilesNetworkFollowSegmentConditionType::Noop => "",
-            SocialProfilesNetworkFollowSegmentConditionType::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SocialProfilesNetworkFollowSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SocialNetworkFollow" => {
+                SocialProfilesNetworkFollowSegmentConditionType::SocialNetworkFollow
+            }
+            "" => SocialProfilesNetworkFollowSegmentConditionType::Noop,
+            _ => SocialProfilesNetworkFollowSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SocialProfilesNetworkFollowSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesNetworkFollowSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SocialProfilesNetworkFollowSegmentConditionType {
     fn default() -> SocialProfilesNetworkFollowSegmentConditionType {
         SocialProfilesNetworkFollowSegmentConditionType::Noop
@@ -4866,7 +7846,8 @@ impl SocialProfilesNetworkFollowSegmentConditionType {
 /**
 * Members who are/not following a linked account on a given social network.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SocialProfilesNetworkFollowSegmentOperator {
     #[serde(rename = "follow")]
     Follow,
@@ -4874,8 +7855,9 @@ pub enum SocialProfilesNetworkFollowSegmentOperator {
     Notfollow,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperator {
@@ -4884,12 +7866,45 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperator {
             SocialProfilesNetworkFollowSegmentOperator::Follow => "follow",
             SocialProfilesNetworkFollowSegmentOperator::Notfollow => "notfollow",
             SocialProfilesNetworkFollowSegmentOperator::Noop => "",
-            SocialProfilesNetworkFollowSegmentOperator::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SocialProfilesNetworkFollowSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "follow" => SocialProfilesNetworkFollowSegmentOperator::Follow,
+            "notfollow" => SocialProfilesNetworkFollowSegmentOperator::Notfollow,
+            "" => SocialProfilesNetworkFollowSegmentOperator::Noop,
+            _ => SocialProfilesNetworkFollowSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SocialProfilesNetworkFollowSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesNetworkFollowSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SocialProfilesNetworkFollowSegmentOperator {
     fn default() -> SocialProfilesNetworkFollowSegmentOperator {
         SocialProfilesNetworkFollowSegmentOperator::Noop
@@ -4904,14 +7919,16 @@ impl SocialProfilesNetworkFollowSegmentOperator {
 /**
 * The social network to segment against.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SocialProfilesNetworkFollowSegmentOperatorData {
     #[serde(rename = "twitter_follow")]
     TwitterFollow,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperatorData {
@@ -4919,12 +7936,44 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperatorData {
         match self {
             SocialProfilesNetworkFollowSegmentOperatorData::TwitterFollow => "twitter_follow",
             SocialProfilesNetworkFollowSegmentOperatorData::Noop => "",
-            SocialProfilesNetworkFollowSegmentOperatorData::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentOperatorData::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SocialProfilesNetworkFollowSegmentOperatorData {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "twitter_follow" => SocialProfilesNetworkFollowSegmentOperatorData::TwitterFollow,
+            "" => SocialProfilesNetworkFollowSegmentOperatorData::Noop,
+            _ => SocialProfilesNetworkFollowSegmentOperatorData::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SocialProfilesNetworkFollowSegmentOperatorData {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialProfilesNetworkFollowSegmentOperatorData {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SocialProfilesNetworkFollowSegmentOperatorData {
     fn default() -> SocialProfilesNetworkFollowSegmentOperatorData {
         SocialProfilesNetworkFollowSegmentOperatorData::Noop
@@ -4970,14 +8019,16 @@ pub struct SocialProfilesNetworkFollowSegment {
     pub value: SocialProfilesNetworkFollowSegmentOperatorData,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AddressMergeFieldSegmentConditionType {
     #[serde(rename = "AddressMerge")]
     AddressMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AddressMergeFieldSegmentConditionType {
@@ -4985,12 +8036,44 @@ impl std::fmt::Display for AddressMergeFieldSegmentConditionType {
         match self {
             AddressMergeFieldSegmentConditionType::AddressMerge => "AddressMerge",
             AddressMergeFieldSegmentConditionType::Noop => "",
-            AddressMergeFieldSegmentConditionType::FallthroughString => "*",
+            AddressMergeFieldSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AddressMergeFieldSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AddressMerge" => AddressMergeFieldSegmentConditionType::AddressMerge,
+            "" => AddressMergeFieldSegmentConditionType::Noop,
+            _ => AddressMergeFieldSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AddressMergeFieldSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AddressMergeFieldSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AddressMergeFieldSegmentConditionType {
     fn default() -> AddressMergeFieldSegmentConditionType {
         AddressMergeFieldSegmentConditionType::Noop
@@ -5005,7 +8088,8 @@ impl AddressMergeFieldSegmentConditionType {
 /**
 * Whether the member's address merge field contains/does not contain a value or is/is not blank.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AddressMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5017,8 +8101,9 @@ pub enum AddressMergeFieldSegmentOperator {
     Notcontain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AddressMergeFieldSegmentOperator {
@@ -5029,12 +8114,47 @@ impl std::fmt::Display for AddressMergeFieldSegmentOperator {
             AddressMergeFieldSegmentOperator::Contains => "contains",
             AddressMergeFieldSegmentOperator::Notcontain => "notcontain",
             AddressMergeFieldSegmentOperator::Noop => "",
-            AddressMergeFieldSegmentOperator::FallthroughString => "*",
+            AddressMergeFieldSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AddressMergeFieldSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "blank" => AddressMergeFieldSegmentOperator::Blank,
+            "blank_not" => AddressMergeFieldSegmentOperator::BlankNot,
+            "contains" => AddressMergeFieldSegmentOperator::Contains,
+            "notcontain" => AddressMergeFieldSegmentOperator::Notcontain,
+            "" => AddressMergeFieldSegmentOperator::Noop,
+            _ => AddressMergeFieldSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AddressMergeFieldSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AddressMergeFieldSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AddressMergeFieldSegmentOperator {
     fn default() -> AddressMergeFieldSegmentOperator {
         AddressMergeFieldSegmentOperator::Noop
@@ -5082,14 +8202,16 @@ pub struct AddressMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AddressZipMergeFieldSegmentConditionType {
     #[serde(rename = "ZipMerge")]
     ZipMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AddressZipMergeFieldSegmentConditionType {
@@ -5097,12 +8219,44 @@ impl std::fmt::Display for AddressZipMergeFieldSegmentConditionType {
         match self {
             AddressZipMergeFieldSegmentConditionType::ZipMerge => "ZipMerge",
             AddressZipMergeFieldSegmentConditionType::Noop => "",
-            AddressZipMergeFieldSegmentConditionType::FallthroughString => "*",
+            AddressZipMergeFieldSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AddressZipMergeFieldSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ZipMerge" => AddressZipMergeFieldSegmentConditionType::ZipMerge,
+            "" => AddressZipMergeFieldSegmentConditionType::Noop,
+            _ => AddressZipMergeFieldSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AddressZipMergeFieldSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AddressZipMergeFieldSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AddressZipMergeFieldSegmentConditionType {
     fn default() -> AddressZipMergeFieldSegmentConditionType {
         AddressZipMergeFieldSegmentConditionType::Noop
@@ -5117,14 +8271,16 @@ impl AddressZipMergeFieldSegmentConditionType {
 /**
 * Whether the member's address merge field is within a given distance from a city or zip.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AddressZipMergeFieldSegmentOperator {
     #[serde(rename = "geoin")]
     Geoin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AddressZipMergeFieldSegmentOperator {
@@ -5132,12 +8288,44 @@ impl std::fmt::Display for AddressZipMergeFieldSegmentOperator {
         match self {
             AddressZipMergeFieldSegmentOperator::Geoin => "geoin",
             AddressZipMergeFieldSegmentOperator::Noop => "",
-            AddressZipMergeFieldSegmentOperator::FallthroughString => "*",
+            AddressZipMergeFieldSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AddressZipMergeFieldSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "geoin" => AddressZipMergeFieldSegmentOperator::Geoin,
+            "" => AddressZipMergeFieldSegmentOperator::Noop,
+            _ => AddressZipMergeFieldSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AddressZipMergeFieldSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AddressZipMergeFieldSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AddressZipMergeFieldSegmentOperator {
     fn default() -> AddressZipMergeFieldSegmentOperator {
         AddressZipMergeFieldSegmentOperator::Noop
@@ -5194,14 +8382,16 @@ pub struct AddressZipMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BirthdayMergeFieldSegmentConditionType {
     #[serde(rename = "BirthdayMerge")]
     BirthdayMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BirthdayMergeFieldSegmentConditionType {
@@ -5209,12 +8399,44 @@ impl std::fmt::Display for BirthdayMergeFieldSegmentConditionType {
         match self {
             BirthdayMergeFieldSegmentConditionType::BirthdayMerge => "BirthdayMerge",
             BirthdayMergeFieldSegmentConditionType::Noop => "",
-            BirthdayMergeFieldSegmentConditionType::FallthroughString => "*",
+            BirthdayMergeFieldSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BirthdayMergeFieldSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BirthdayMerge" => BirthdayMergeFieldSegmentConditionType::BirthdayMerge,
+            "" => BirthdayMergeFieldSegmentConditionType::Noop,
+            _ => BirthdayMergeFieldSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BirthdayMergeFieldSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BirthdayMergeFieldSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BirthdayMergeFieldSegmentConditionType {
     fn default() -> BirthdayMergeFieldSegmentConditionType {
         BirthdayMergeFieldSegmentConditionType::Noop
@@ -5229,7 +8451,8 @@ impl BirthdayMergeFieldSegmentConditionType {
 /**
 * Whether the member's birthday merge information is/is not a certain date or is/is not blank.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BirthdayMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5241,8 +8464,9 @@ pub enum BirthdayMergeFieldSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BirthdayMergeFieldSegmentOperator {
@@ -5253,12 +8477,47 @@ impl std::fmt::Display for BirthdayMergeFieldSegmentOperator {
             BirthdayMergeFieldSegmentOperator::Is => "is",
             BirthdayMergeFieldSegmentOperator::Not => "not",
             BirthdayMergeFieldSegmentOperator::Noop => "",
-            BirthdayMergeFieldSegmentOperator::FallthroughString => "*",
+            BirthdayMergeFieldSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BirthdayMergeFieldSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "blank" => BirthdayMergeFieldSegmentOperator::Blank,
+            "blank_not" => BirthdayMergeFieldSegmentOperator::BlankNot,
+            "is" => BirthdayMergeFieldSegmentOperator::Is,
+            "not" => BirthdayMergeFieldSegmentOperator::Not,
+            "" => BirthdayMergeFieldSegmentOperator::Noop,
+            _ => BirthdayMergeFieldSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BirthdayMergeFieldSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BirthdayMergeFieldSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BirthdayMergeFieldSegmentOperator {
     fn default() -> BirthdayMergeFieldSegmentOperator {
         BirthdayMergeFieldSegmentOperator::Noop
@@ -5306,14 +8565,16 @@ pub struct BirthdayMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DateMergeFieldSegmentConditionType {
     #[serde(rename = "DateMerge")]
     DateMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DateMergeFieldSegmentConditionType {
@@ -5321,12 +8582,44 @@ impl std::fmt::Display for DateMergeFieldSegmentConditionType {
         match self {
             DateMergeFieldSegmentConditionType::DateMerge => "DateMerge",
             DateMergeFieldSegmentConditionType::Noop => "",
-            DateMergeFieldSegmentConditionType::FallthroughString => "*",
+            DateMergeFieldSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DateMergeFieldSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DateMerge" => DateMergeFieldSegmentConditionType::DateMerge,
+            "" => DateMergeFieldSegmentConditionType::Noop,
+            _ => DateMergeFieldSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DateMergeFieldSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateMergeFieldSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DateMergeFieldSegmentConditionType {
     fn default() -> DateMergeFieldSegmentConditionType {
         DateMergeFieldSegmentConditionType::Noop
@@ -5341,7 +8634,8 @@ impl DateMergeFieldSegmentConditionType {
 /**
 * Whether the member's merge information is/is not, is greater/less than a value or is/is not blank.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DateMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5357,8 +8651,9 @@ pub enum DateMergeFieldSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DateMergeFieldSegmentOperator {
@@ -5371,12 +8666,49 @@ impl std::fmt::Display for DateMergeFieldSegmentOperator {
             DateMergeFieldSegmentOperator::Less => "less",
             DateMergeFieldSegmentOperator::Not => "not",
             DateMergeFieldSegmentOperator::Noop => "",
-            DateMergeFieldSegmentOperator::FallthroughString => "*",
+            DateMergeFieldSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DateMergeFieldSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "blank" => DateMergeFieldSegmentOperator::Blank,
+            "blank_not" => DateMergeFieldSegmentOperator::BlankNot,
+            "greater" => DateMergeFieldSegmentOperator::Greater,
+            "is" => DateMergeFieldSegmentOperator::Is,
+            "less" => DateMergeFieldSegmentOperator::Less,
+            "not" => DateMergeFieldSegmentOperator::Not,
+            "" => DateMergeFieldSegmentOperator::Noop,
+            _ => DateMergeFieldSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DateMergeFieldSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateMergeFieldSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DateMergeFieldSegmentOperator {
     fn default() -> DateMergeFieldSegmentOperator {
         DateMergeFieldSegmentOperator::Noop
@@ -5424,14 +8756,16 @@ pub struct DateMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DropdownRadioMergeFieldSegmentConditionType {
     #[serde(rename = "SelectMerge")]
     SelectMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DropdownRadioMergeFieldSegmentConditionType {
@@ -5439,12 +8773,44 @@ impl std::fmt::Display for DropdownRadioMergeFieldSegmentConditionType {
         match self {
             DropdownRadioMergeFieldSegmentConditionType::SelectMerge => "SelectMerge",
             DropdownRadioMergeFieldSegmentConditionType::Noop => "",
-            DropdownRadioMergeFieldSegmentConditionType::FallthroughString => "*",
+            DropdownRadioMergeFieldSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DropdownRadioMergeFieldSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SelectMerge" => DropdownRadioMergeFieldSegmentConditionType::SelectMerge,
+            "" => DropdownRadioMergeFieldSegmentConditionType::Noop,
+            _ => DropdownRadioMergeFieldSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DropdownRadioMergeFieldSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DropdownRadioMergeFieldSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DropdownRadioMergeFieldSegmentConditionType {
     fn default() -> DropdownRadioMergeFieldSegmentConditionType {
         DropdownRadioMergeFieldSegmentConditionType::Noop
@@ -5459,7 +8825,8 @@ impl DropdownRadioMergeFieldSegmentConditionType {
 /**
 * Whether the member's merge information is/is not a value or is/is not blank.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DropdownRadioMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5475,8 +8842,9 @@ pub enum DropdownRadioMergeFieldSegmentOperator {
     Notcontain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DropdownRadioMergeFieldSegmentOperator {
@@ -5489,12 +8857,49 @@ impl std::fmt::Display for DropdownRadioMergeFieldSegmentOperator {
             DropdownRadioMergeFieldSegmentOperator::Not => "not",
             DropdownRadioMergeFieldSegmentOperator::Notcontain => "notcontain",
             DropdownRadioMergeFieldSegmentOperator::Noop => "",
-            DropdownRadioMergeFieldSegmentOperator::FallthroughString => "*",
+            DropdownRadioMergeFieldSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DropdownRadioMergeFieldSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "blank" => DropdownRadioMergeFieldSegmentOperator::Blank,
+            "blank_not" => DropdownRadioMergeFieldSegmentOperator::BlankNot,
+            "contains" => DropdownRadioMergeFieldSegmentOperator::Contains,
+            "is" => DropdownRadioMergeFieldSegmentOperator::Is,
+            "not" => DropdownRadioMergeFieldSegmentOperator::Not,
+            "notcontain" => DropdownRadioMergeFieldSegmentOperator::Notcontain,
+            "" => DropdownRadioMergeFieldSegmentOperator::Noop,
+            _ => DropdownRadioMergeFieldSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DropdownRadioMergeFieldSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DropdownRadioMergeFieldSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DropdownRadioMergeFieldSegmentOperator {
     fn default() -> DropdownRadioMergeFieldSegmentOperator {
         DropdownRadioMergeFieldSegmentOperator::Noop
@@ -5542,14 +8947,16 @@ pub struct DropdownRadioMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TextNumberMergeFieldSegmentConditionType {
     #[serde(rename = "TextMerge")]
     TextMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TextNumberMergeFieldSegmentConditionType {
@@ -5557,12 +8964,44 @@ impl std::fmt::Display for TextNumberMergeFieldSegmentConditionType {
         match self {
             TextNumberMergeFieldSegmentConditionType::TextMerge => "TextMerge",
             TextNumberMergeFieldSegmentConditionType::Noop => "",
-            TextNumberMergeFieldSegmentConditionType::FallthroughString => "*",
+            TextNumberMergeFieldSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TextNumberMergeFieldSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "TextMerge" => TextNumberMergeFieldSegmentConditionType::TextMerge,
+            "" => TextNumberMergeFieldSegmentConditionType::Noop,
+            _ => TextNumberMergeFieldSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TextNumberMergeFieldSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TextNumberMergeFieldSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TextNumberMergeFieldSegmentConditionType {
     fn default() -> TextNumberMergeFieldSegmentConditionType {
         TextNumberMergeFieldSegmentConditionType::Noop
@@ -5577,7 +9016,8 @@ impl TextNumberMergeFieldSegmentConditionType {
 /**
 * Whether the member's merge information is/is not, contains/does not contain, starts/ends with, or is greater/less than a value
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TextNumberMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5601,8 +9041,9 @@ pub enum TextNumberMergeFieldSegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TextNumberMergeFieldSegmentOperator {
@@ -5619,12 +9060,53 @@ impl std::fmt::Display for TextNumberMergeFieldSegmentOperator {
             TextNumberMergeFieldSegmentOperator::Notcontain => "notcontain",
             TextNumberMergeFieldSegmentOperator::Starts => "starts",
             TextNumberMergeFieldSegmentOperator::Noop => "",
-            TextNumberMergeFieldSegmentOperator::FallthroughString => "*",
+            TextNumberMergeFieldSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TextNumberMergeFieldSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "blank" => TextNumberMergeFieldSegmentOperator::Blank,
+            "blank_not" => TextNumberMergeFieldSegmentOperator::BlankNot,
+            "contains" => TextNumberMergeFieldSegmentOperator::Contains,
+            "ends" => TextNumberMergeFieldSegmentOperator::Ends,
+            "greater" => TextNumberMergeFieldSegmentOperator::Greater,
+            "is" => TextNumberMergeFieldSegmentOperator::Is,
+            "less" => TextNumberMergeFieldSegmentOperator::Less,
+            "not" => TextNumberMergeFieldSegmentOperator::Not,
+            "notcontain" => TextNumberMergeFieldSegmentOperator::Notcontain,
+            "starts" => TextNumberMergeFieldSegmentOperator::Starts,
+            "" => TextNumberMergeFieldSegmentOperator::Noop,
+            _ => TextNumberMergeFieldSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TextNumberMergeFieldSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TextNumberMergeFieldSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TextNumberMergeFieldSegmentOperator {
     fn default() -> TextNumberMergeFieldSegmentOperator {
         TextNumberMergeFieldSegmentOperator::Noop
@@ -5672,14 +9154,16 @@ pub struct TextNumberMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EmailSegmentConditionType {
     #[serde(rename = "EmailAddress")]
     EmailAddress,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EmailSegmentConditionType {
@@ -5687,12 +9171,44 @@ impl std::fmt::Display for EmailSegmentConditionType {
         match self {
             EmailSegmentConditionType::EmailAddress => "EmailAddress",
             EmailSegmentConditionType::Noop => "",
-            EmailSegmentConditionType::FallthroughString => "*",
+            EmailSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EmailSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EmailAddress" => EmailSegmentConditionType::EmailAddress,
+            "" => EmailSegmentConditionType::Noop,
+            _ => EmailSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EmailSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EmailSegmentConditionType {
     fn default() -> EmailSegmentConditionType {
         EmailSegmentConditionType::Noop
@@ -5707,7 +9223,8 @@ impl EmailSegmentConditionType {
 /**
 * Segmenting based off of a subscriber's email address.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EmailSegmentField {
     #[serde(rename = "EMAIL")]
     Email,
@@ -5715,8 +9232,9 @@ pub enum EmailSegmentField {
     Merge0,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EmailSegmentField {
@@ -5725,12 +9243,45 @@ impl std::fmt::Display for EmailSegmentField {
             EmailSegmentField::Email => "EMAIL",
             EmailSegmentField::Merge0 => "merge0",
             EmailSegmentField::Noop => "",
-            EmailSegmentField::FallthroughString => "*",
+            EmailSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EmailSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EMAIL" => EmailSegmentField::Email,
+            "merge0" => EmailSegmentField::Merge0,
+            "" => EmailSegmentField::Noop,
+            _ => EmailSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EmailSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EmailSegmentField {
     fn default() -> EmailSegmentField {
         EmailSegmentField::Noop
@@ -5745,7 +9296,8 @@ impl EmailSegmentField {
 /**
 * Whether the email address is/not exactly, contains/doesn't contain, starts/ends with a string.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EmailSegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -5765,8 +9317,9 @@ pub enum EmailSegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EmailSegmentOperator {
@@ -5781,12 +9334,51 @@ impl std::fmt::Display for EmailSegmentOperator {
             EmailSegmentOperator::Notcontain => "notcontain",
             EmailSegmentOperator::Starts => "starts",
             EmailSegmentOperator::Noop => "",
-            EmailSegmentOperator::FallthroughString => "*",
+            EmailSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EmailSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "contains" => EmailSegmentOperator::Contains,
+            "ends" => EmailSegmentOperator::Ends,
+            "greater" => EmailSegmentOperator::Greater,
+            "is" => EmailSegmentOperator::Is,
+            "less" => EmailSegmentOperator::Less,
+            "not" => EmailSegmentOperator::Not,
+            "notcontain" => EmailSegmentOperator::Notcontain,
+            "starts" => EmailSegmentOperator::Starts,
+            "" => EmailSegmentOperator::Noop,
+            _ => EmailSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EmailSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EmailSegmentOperator {
     fn default() -> EmailSegmentOperator {
         EmailSegmentOperator::Noop
@@ -5827,14 +9419,16 @@ pub struct EmailSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PredictedGenderSegmentConditionType {
     #[serde(rename = "PredictedGender")]
     PredictedGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PredictedGenderSegmentConditionType {
@@ -5842,12 +9436,44 @@ impl std::fmt::Display for PredictedGenderSegmentConditionType {
         match self {
             PredictedGenderSegmentConditionType::PredictedGender => "PredictedGender",
             PredictedGenderSegmentConditionType::Noop => "",
-            PredictedGenderSegmentConditionType::FallthroughString => "*",
+            PredictedGenderSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PredictedGenderSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "PredictedGender" => PredictedGenderSegmentConditionType::PredictedGender,
+            "" => PredictedGenderSegmentConditionType::Noop,
+            _ => PredictedGenderSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PredictedGenderSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PredictedGenderSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PredictedGenderSegmentConditionType {
     fn default() -> PredictedGenderSegmentConditionType {
         PredictedGenderSegmentConditionType::Noop
@@ -5862,14 +9488,16 @@ impl PredictedGenderSegmentConditionType {
 /**
 * Segment by predicted gender.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PredictedGenderSegmentField {
     #[serde(rename = "predicted_gender")]
     PredictedGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PredictedGenderSegmentField {
@@ -5877,12 +9505,44 @@ impl std::fmt::Display for PredictedGenderSegmentField {
         match self {
             PredictedGenderSegmentField::PredictedGender => "predicted_gender",
             PredictedGenderSegmentField::Noop => "",
-            PredictedGenderSegmentField::FallthroughString => "*",
+            PredictedGenderSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PredictedGenderSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "predicted_gender" => PredictedGenderSegmentField::PredictedGender,
+            "" => PredictedGenderSegmentField::Noop,
+            _ => PredictedGenderSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PredictedGenderSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PredictedGenderSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PredictedGenderSegmentField {
     fn default() -> PredictedGenderSegmentField {
         PredictedGenderSegmentField::Noop
@@ -5922,14 +9582,16 @@ pub struct PredictedGenderSegment {
     pub value: SocialProfilesGenderSegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PredictedAgeSegmentConditionType {
     #[serde(rename = "PredictedAge")]
     PredictedAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentConditionType {
@@ -5937,12 +9599,44 @@ impl std::fmt::Display for PredictedAgeSegmentConditionType {
         match self {
             PredictedAgeSegmentConditionType::PredictedAge => "PredictedAge",
             PredictedAgeSegmentConditionType::Noop => "",
-            PredictedAgeSegmentConditionType::FallthroughString => "*",
+            PredictedAgeSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PredictedAgeSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "PredictedAge" => PredictedAgeSegmentConditionType::PredictedAge,
+            "" => PredictedAgeSegmentConditionType::Noop,
+            _ => PredictedAgeSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PredictedAgeSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PredictedAgeSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PredictedAgeSegmentConditionType {
     fn default() -> PredictedAgeSegmentConditionType {
         PredictedAgeSegmentConditionType::Noop
@@ -5957,14 +9651,16 @@ impl PredictedAgeSegmentConditionType {
 /**
 * Segment by predicted age.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PredictedAgeSegmentField {
     #[serde(rename = "predicted_age_range")]
     PredictedAgeRange,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentField {
@@ -5972,12 +9668,44 @@ impl std::fmt::Display for PredictedAgeSegmentField {
         match self {
             PredictedAgeSegmentField::PredictedAgeRange => "predicted_age_range",
             PredictedAgeSegmentField::Noop => "",
-            PredictedAgeSegmentField::FallthroughString => "*",
+            PredictedAgeSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PredictedAgeSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "predicted_age_range" => PredictedAgeSegmentField::PredictedAgeRange,
+            "" => PredictedAgeSegmentField::Noop,
+            _ => PredictedAgeSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PredictedAgeSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PredictedAgeSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PredictedAgeSegmentField {
     fn default() -> PredictedAgeSegmentField {
         PredictedAgeSegmentField::Noop
@@ -5992,14 +9720,16 @@ impl PredictedAgeSegmentField {
 /**
 * Members who are/not the exact criteria listed.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PredictedAgeSegmentOperator {
     #[serde(rename = "is")]
     Is,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentOperator {
@@ -6007,12 +9737,44 @@ impl std::fmt::Display for PredictedAgeSegmentOperator {
         match self {
             PredictedAgeSegmentOperator::Is => "is",
             PredictedAgeSegmentOperator::Noop => "",
-            PredictedAgeSegmentOperator::FallthroughString => "*",
+            PredictedAgeSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PredictedAgeSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "is" => PredictedAgeSegmentOperator::Is,
+            "" => PredictedAgeSegmentOperator::Noop,
+            _ => PredictedAgeSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PredictedAgeSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PredictedAgeSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PredictedAgeSegmentOperator {
     fn default() -> PredictedAgeSegmentOperator {
         PredictedAgeSegmentOperator::Noop
@@ -6027,7 +9789,8 @@ impl PredictedAgeSegmentOperator {
 /**
 * The predicted age to segment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PredictedAgeSegmentOperatorData {
     #[serde(rename = "18-24")]
     OneThousandEightHundredAndTwentyFour,
@@ -6043,8 +9806,9 @@ pub enum PredictedAgeSegmentOperatorData {
     SixtyFive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentOperatorData {
@@ -6057,12 +9821,49 @@ impl std::fmt::Display for PredictedAgeSegmentOperatorData {
             PredictedAgeSegmentOperatorData::FiveThousandFiveHundredAndSixtyFour => "55-64",
             PredictedAgeSegmentOperatorData::SixtyFive => "65+",
             PredictedAgeSegmentOperatorData::Noop => "",
-            PredictedAgeSegmentOperatorData::FallthroughString => "*",
+            PredictedAgeSegmentOperatorData::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PredictedAgeSegmentOperatorData {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "18-24" => PredictedAgeSegmentOperatorData::OneThousandEightHundredAndTwentyFour,
+            "25-34" => PredictedAgeSegmentOperatorData::TwoThousandFiveHundredAndThirtyFour,
+            "35-44" => PredictedAgeSegmentOperatorData::ThreeThousandFiveHundredAndFortyFour,
+            "45-54" => PredictedAgeSegmentOperatorData::FourThousandFiveHundredAndFiftyFour,
+            "55-64" => PredictedAgeSegmentOperatorData::FiveThousandFiveHundredAndSixtyFour,
+            "65+" => PredictedAgeSegmentOperatorData::SixtyFive,
+            "" => PredictedAgeSegmentOperatorData::Noop,
+            _ => PredictedAgeSegmentOperatorData::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PredictedAgeSegmentOperatorData {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PredictedAgeSegmentOperatorData {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PredictedAgeSegmentOperatorData {
     fn default() -> PredictedAgeSegmentOperatorData {
         PredictedAgeSegmentOperatorData::Noop
@@ -6102,14 +9903,16 @@ pub struct PredictedAgeSegment {
     pub value: PredictedAgeSegmentOperatorData,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum NewSubscribersPrebuiltSegmentConditionType {
     #[serde(rename = "NewSubscribers")]
     NewSubscribers,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentConditionType {
@@ -6117,12 +9920,44 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentConditionType {
         match self {
             NewSubscribersPrebuiltSegmentConditionType::NewSubscribers => "NewSubscribers",
             NewSubscribersPrebuiltSegmentConditionType::Noop => "",
-            NewSubscribersPrebuiltSegmentConditionType::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for NewSubscribersPrebuiltSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "NewSubscribers" => NewSubscribersPrebuiltSegmentConditionType::NewSubscribers,
+            "" => NewSubscribersPrebuiltSegmentConditionType::Noop,
+            _ => NewSubscribersPrebuiltSegmentConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NewSubscribersPrebuiltSegmentConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for NewSubscribersPrebuiltSegmentConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for NewSubscribersPrebuiltSegmentConditionType {
     fn default() -> NewSubscribersPrebuiltSegmentConditionType {
         NewSubscribersPrebuiltSegmentConditionType::Noop
@@ -6137,14 +9972,16 @@ impl NewSubscribersPrebuiltSegmentConditionType {
 /**
 * Segment by when people subscribed.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum NewSubscribersPrebuiltSegmentField {
     #[serde(rename = "timestamp_opt")]
     TimestampOpt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentField {
@@ -6152,12 +9989,44 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentField {
         match self {
             NewSubscribersPrebuiltSegmentField::TimestampOpt => "timestamp_opt",
             NewSubscribersPrebuiltSegmentField::Noop => "",
-            NewSubscribersPrebuiltSegmentField::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for NewSubscribersPrebuiltSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "timestamp_opt" => NewSubscribersPrebuiltSegmentField::TimestampOpt,
+            "" => NewSubscribersPrebuiltSegmentField::Noop,
+            _ => NewSubscribersPrebuiltSegmentField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NewSubscribersPrebuiltSegmentField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for NewSubscribersPrebuiltSegmentField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for NewSubscribersPrebuiltSegmentField {
     fn default() -> NewSubscribersPrebuiltSegmentField {
         NewSubscribersPrebuiltSegmentField::Noop
@@ -6172,14 +10041,16 @@ impl NewSubscribersPrebuiltSegmentField {
 /**
 * Whe the event took place, namely within a time frame.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum NewSubscribersPrebuiltSegmentOperator {
     #[serde(rename = "date_within")]
     DateWithin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentOperator {
@@ -6187,12 +10058,44 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentOperator {
         match self {
             NewSubscribersPrebuiltSegmentOperator::DateWithin => "date_within",
             NewSubscribersPrebuiltSegmentOperator::Noop => "",
-            NewSubscribersPrebuiltSegmentOperator::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentOperator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for NewSubscribersPrebuiltSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "date_within" => NewSubscribersPrebuiltSegmentOperator::DateWithin,
+            "" => NewSubscribersPrebuiltSegmentOperator::Noop,
+            _ => NewSubscribersPrebuiltSegmentOperator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NewSubscribersPrebuiltSegmentOperator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for NewSubscribersPrebuiltSegmentOperator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for NewSubscribersPrebuiltSegmentOperator {
     fn default() -> NewSubscribersPrebuiltSegmentOperator {
         NewSubscribersPrebuiltSegmentOperator::Noop
@@ -6996,7 +10899,8 @@ pub struct Tracking {
 /**
 * The type of Automation workflow.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WorkflowType {
     #[serde(rename = "abandonedBrowse")]
     AbandonedBrowse,
@@ -7034,8 +10938,9 @@ pub enum WorkflowType {
     WelcomeSeries,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WorkflowType {
@@ -7059,12 +10964,60 @@ impl std::fmt::Display for WorkflowType {
             WorkflowType::VisitUrl => "visitUrl",
             WorkflowType::WelcomeSeries => "welcomeSeries",
             WorkflowType::Noop => "",
-            WorkflowType::FallthroughString => "*",
+            WorkflowType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WorkflowType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "abandonedBrowse" => WorkflowType::AbandonedBrowse,
+            "abandonedCart" => WorkflowType::AbandonedCart,
+            "api" => WorkflowType::Api,
+            "bestCustomers" => WorkflowType::BestCustomers,
+            "categoryFollowup" => WorkflowType::CategoryFollowup,
+            "dateAdded" => WorkflowType::DateAdded,
+            "emailFollowup" => WorkflowType::EmailFollowup,
+            "emailSeries" => WorkflowType::EmailSeries,
+            "groupAdd" => WorkflowType::GroupAdd,
+            "groupRemove" => WorkflowType::GroupRemove,
+            "mandrill" => WorkflowType::Mandrill,
+            "productFollowup" => WorkflowType::ProductFollowup,
+            "purchaseFollowup" => WorkflowType::PurchaseFollowup,
+            "recurringEvent" => WorkflowType::RecurringEvent,
+            "specialEvent" => WorkflowType::SpecialEvent,
+            "visitUrl" => WorkflowType::VisitUrl,
+            "welcomeSeries" => WorkflowType::WelcomeSeries,
+            "" => WorkflowType::Noop,
+            _ => WorkflowType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WorkflowType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WorkflowType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WorkflowType {
     fn default() -> WorkflowType {
         WorkflowType::Noop
@@ -7076,7 +11029,8 @@ impl WorkflowType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Days {
     #[serde(rename = "friday")]
     Friday,
@@ -7094,8 +11048,9 @@ pub enum Days {
     Wednesday,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Days {
@@ -7109,12 +11064,50 @@ impl std::fmt::Display for Days {
             Days::Tuesday => "tuesday",
             Days::Wednesday => "wednesday",
             Days::Noop => "",
-            Days::FallthroughString => "*",
+            Days::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Days {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "friday" => Days::Friday,
+            "monday" => Days::Monday,
+            "saturday" => Days::Saturday,
+            "sunday" => Days::Sunday,
+            "thursday" => Days::Thursday,
+            "tuesday" => Days::Tuesday,
+            "wednesday" => Days::Wednesday,
+            "" => Days::Noop,
+            _ => Days::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Days {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Days {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Days {
     fn default() -> Days {
         Days::Noop
@@ -7129,7 +11122,8 @@ impl Days {
 /**
 * When to send the Automation email.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EmailSendTimeSettings {
     #[serde(rename = "send_asap")]
     SendAsap,
@@ -7139,8 +11133,9 @@ pub enum EmailSendTimeSettings {
     SendBetween,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EmailSendTimeSettings {
@@ -7150,12 +11145,46 @@ impl std::fmt::Display for EmailSendTimeSettings {
             EmailSendTimeSettings::SendAt => "send_at",
             EmailSendTimeSettings::SendBetween => "send_between",
             EmailSendTimeSettings::Noop => "",
-            EmailSendTimeSettings::FallthroughString => "*",
+            EmailSendTimeSettings::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EmailSendTimeSettings {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "send_asap" => EmailSendTimeSettings::SendAsap,
+            "send_at" => EmailSendTimeSettings::SendAt,
+            "send_between" => EmailSendTimeSettings::SendBetween,
+            "" => EmailSendTimeSettings::Noop,
+            _ => EmailSendTimeSettings::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EmailSendTimeSettings {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailSendTimeSettings {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EmailSendTimeSettings {
     fn default() -> EmailSendTimeSettings {
         EmailSendTimeSettings::Noop
@@ -7489,7 +11518,8 @@ pub struct AutomationWorkflow {
 /**
 * The type of delay for an Automation email.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DelayType {
     #[serde(rename = "day")]
     Day,
@@ -7501,8 +11531,9 @@ pub enum DelayType {
     Week,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DelayType {
@@ -7513,12 +11544,47 @@ impl std::fmt::Display for DelayType {
             DelayType::Now => "now",
             DelayType::Week => "week",
             DelayType::Noop => "",
-            DelayType::FallthroughString => "*",
+            DelayType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DelayType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "day" => DelayType::Day,
+            "hour" => DelayType::Hour,
+            "now" => DelayType::Now,
+            "week" => DelayType::Week,
+            "" => DelayType::Noop,
+            _ => DelayType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DelayType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DelayType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DelayType {
     fn default() -> DelayType {
         DelayType::Noop
@@ -7533,7 +11599,8 @@ impl DelayType {
 /**
 * Whether the delay settings describe before or after the delay action of an Automation email.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Direction {
     #[serde(rename = "after")]
     After,
@@ -7541,8 +11608,9 @@ pub enum Direction {
     Before,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Direction {
@@ -7551,12 +11619,45 @@ impl std::fmt::Display for Direction {
             Direction::After => "after",
             Direction::Before => "before",
             Direction::Noop => "",
-            Direction::FallthroughString => "*",
+            Direction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Direction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "after" => Direction::After,
+            "before" => Direction::Before,
+            "" => Direction::Noop,
+            _ => Direction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Direction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Direction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Direction {
     fn default() -> Direction {
         Direction::Noop
@@ -7571,7 +11672,8 @@ impl Direction {
 /**
 * The action that triggers the delay of an Automation email.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Action {
     #[serde(rename = "annual")]
     Annual,
@@ -7641,8 +11743,9 @@ pub enum Action {
     TagAdd,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Action {
@@ -7682,12 +11785,76 @@ impl std::fmt::Display for Action {
             Action::Signup => "signup",
             Action::TagAdd => "tag_add",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Action {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "annual" => Action::Annual,
+            "api" => Action::Api,
+            "birthday" => Action::Birthday,
+            "campaign_sent" => Action::CampaignSent,
+            "campaign_specific_clicked" => Action::CampaignSpecificClicked,
+            "clicked_email" => Action::ClickedEmail,
+            "date" => Action::Date,
+            "date_added" => Action::DateAdded,
+            "ecomm_abandoned_cart" => Action::EcommAbandonedCart,
+            "ecomm_bought_any" => Action::EcommBoughtAny,
+            "ecomm_bought_category" => Action::EcommBoughtCategory,
+            "ecomm_bought_product" => Action::EcommBoughtProduct,
+            "ecomm_not_bought_any" => Action::EcommNotBoughtAny,
+            "goal" => Action::Goal,
+            "group_add" => Action::GroupAdd,
+            "group_remove" => Action::GroupRemove,
+            "mandrill_any" => Action::MandrillAny,
+            "mandrill_clicked" => Action::MandrillClicked,
+            "mandrill_opened" => Action::MandrillOpened,
+            "mandrill_sent" => Action::MandrillSent,
+            "manual" => Action::Manual,
+            "merge_changed" => Action::MergeChanged,
+            "not_clicked_email" => Action::NotClickedEmail,
+            "not_opened_email" => Action::NotOpenedEmail,
+            "opened_email" => Action::OpenedEmail,
+            "previous_campaign_clicked_any" => Action::PreviousCampaignClickedAny,
+            "previous_campaign_not_clicked_any" => Action::PreviousCampaignNotClickedAny,
+            "previous_campaign_not_opened" => Action::PreviousCampaignNotOpened,
+            "previous_campaign_opened" => Action::PreviousCampaignOpened,
+            "previous_campaign_sent" => Action::PreviousCampaignSent,
+            "previous_campaign_specific_clicked" => Action::PreviousCampaignSpecificClicked,
+            "signup" => Action::Signup,
+            "tag_add" => Action::TagAdd,
+            "" => Action::Noop,
+            _ => Action::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Action {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Action {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Action {
     fn default() -> Action {
         Action::Noop
@@ -8365,14 +12532,16 @@ pub struct UpdateInformationAboutASpecificWorkflowEmailCampaignSettings {
 /**
 * Whether the delay settings describe before or after the delay action of an automation email.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DelayDirection {
     #[serde(rename = "after")]
     After,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DelayDirection {
@@ -8380,12 +12549,44 @@ impl std::fmt::Display for DelayDirection {
         match self {
             DelayDirection::After => "after",
             DelayDirection::Noop => "",
-            DelayDirection::FallthroughString => "*",
+            DelayDirection::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DelayDirection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "after" => DelayDirection::After,
+            "" => DelayDirection::Noop,
+            _ => DelayDirection::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DelayDirection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DelayDirection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DelayDirection {
     fn default() -> DelayDirection {
         DelayDirection::Noop
@@ -8400,7 +12601,8 @@ impl DelayDirection {
 /**
 * The action that triggers the delay of an automation emails.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DelayAction {
     #[serde(rename = "ecomm_abandoned_browse")]
     EcommAbandonedBrowse,
@@ -8410,8 +12612,9 @@ pub enum DelayAction {
     Signup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DelayAction {
@@ -8421,12 +12624,46 @@ impl std::fmt::Display for DelayAction {
             DelayAction::EcommAbandonedCart => "ecomm_abandoned_cart",
             DelayAction::Signup => "signup",
             DelayAction::Noop => "",
-            DelayAction::FallthroughString => "*",
+            DelayAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DelayAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_abandoned_browse" => DelayAction::EcommAbandonedBrowse,
+            "ecomm_abandoned_cart" => DelayAction::EcommAbandonedCart,
+            "signup" => DelayAction::Signup,
+            "" => DelayAction::Noop,
+            _ => DelayAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DelayAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DelayAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DelayAction {
     fn default() -> DelayAction {
         DelayAction::Noop
@@ -8775,7 +13012,8 @@ pub struct RemovedSubscribers {
 /**
 * The status of the batch call. [Learn more](https://mailchimp.com/developer/marketing/guides/run-async-requests-batch-endpoint/#check-the-status-of-a-batch-operation) about the batch operation status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BatchOperationsStatus {
     #[serde(rename = "finalizing")]
     Finalizing,
@@ -8789,8 +13027,9 @@ pub enum BatchOperationsStatus {
     Started,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BatchOperationsStatus {
@@ -8802,12 +13041,48 @@ impl std::fmt::Display for BatchOperationsStatus {
             BatchOperationsStatus::Preprocessing => "preprocessing",
             BatchOperationsStatus::Started => "started",
             BatchOperationsStatus::Noop => "",
-            BatchOperationsStatus::FallthroughString => "*",
+            BatchOperationsStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BatchOperationsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "finalizing" => BatchOperationsStatus::Finalizing,
+            "finished" => BatchOperationsStatus::Finished,
+            "pending" => BatchOperationsStatus::Pending,
+            "preprocessing" => BatchOperationsStatus::Preprocessing,
+            "started" => BatchOperationsStatus::Started,
+            "" => BatchOperationsStatus::Noop,
+            _ => BatchOperationsStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BatchOperationsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BatchOperationsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BatchOperationsStatus {
     fn default() -> BatchOperationsStatus {
         BatchOperationsStatus::Noop
@@ -8938,7 +13213,8 @@ pub struct BatchOperations {
 /**
 * The HTTP method to use for the operation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum HttpMethod {
     #[serde(rename = "DELETE")]
     Delete,
@@ -8952,8 +13228,9 @@ pub enum HttpMethod {
     Put,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for HttpMethod {
@@ -8965,12 +13242,48 @@ impl std::fmt::Display for HttpMethod {
             HttpMethod::Post => "POST",
             HttpMethod::Put => "PUT",
             HttpMethod::Noop => "",
-            HttpMethod::FallthroughString => "*",
+            HttpMethod::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for HttpMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DELETE" => HttpMethod::Delete,
+            "GET" => HttpMethod::Get,
+            "PATCH" => HttpMethod::Patch,
+            "POST" => HttpMethod::Post,
+            "PUT" => HttpMethod::Put,
+            "" => HttpMethod::Noop,
+            _ => HttpMethod::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for HttpMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for HttpMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for HttpMethod {
     fn default() -> HttpMethod {
         HttpMethod::Noop
@@ -9264,7 +13577,8 @@ pub struct CampaignFolders {
 /**
 * There are four types of [campaigns](https://mailchimp.com/help/getting-started-with-campaigns/) you can create in Mailchimp. A/B Split campaigns have been deprecated and variate campaigns should be used instead.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CampaignType {
     #[serde(rename = "absplit")]
     Absplit,
@@ -9278,8 +13592,9 @@ pub enum CampaignType {
     Variate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CampaignType {
@@ -9291,12 +13606,48 @@ impl std::fmt::Display for CampaignType {
             CampaignType::Rss => "rss",
             CampaignType::Variate => "variate",
             CampaignType::Noop => "",
-            CampaignType::FallthroughString => "*",
+            CampaignType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CampaignType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "absplit" => CampaignType::Absplit,
+            "plaintext" => CampaignType::Plaintext,
+            "regular" => CampaignType::Regular,
+            "rss" => CampaignType::Rss,
+            "variate" => CampaignType::Variate,
+            "" => CampaignType::Noop,
+            _ => CampaignType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CampaignType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CampaignType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CampaignType {
     fn default() -> CampaignType {
         CampaignType::Noop
@@ -9311,7 +13662,8 @@ impl CampaignType {
 /**
 * The status of the campaign.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetCampaignsStatus {
     #[serde(rename = "paused")]
     Paused,
@@ -9325,8 +13677,9 @@ pub enum GetCampaignsStatus {
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetCampaignsStatus {
@@ -9338,12 +13691,48 @@ impl std::fmt::Display for GetCampaignsStatus {
             GetCampaignsStatus::Sending => "sending",
             GetCampaignsStatus::Sent => "sent",
             GetCampaignsStatus::Noop => "",
-            GetCampaignsStatus::FallthroughString => "*",
+            GetCampaignsStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetCampaignsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "paused" => GetCampaignsStatus::Paused,
+            "save" => GetCampaignsStatus::Save,
+            "schedule" => GetCampaignsStatus::Schedule,
+            "sending" => GetCampaignsStatus::Sending,
+            "sent" => GetCampaignsStatus::Sent,
+            "" => GetCampaignsStatus::Noop,
+            _ => GetCampaignsStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetCampaignsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetCampaignsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetCampaignsStatus {
     fn default() -> GetCampaignsStatus {
         GetCampaignsStatus::Noop
@@ -9358,7 +13747,8 @@ impl GetCampaignsStatus {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SortField {
     #[serde(rename = "create_time")]
     CreateTime,
@@ -9366,8 +13756,9 @@ pub enum SortField {
     SendTime,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SortField {
@@ -9376,12 +13767,45 @@ impl std::fmt::Display for SortField {
             SortField::CreateTime => "create_time",
             SortField::SendTime => "send_time",
             SortField::Noop => "",
-            SortField::FallthroughString => "*",
+            SortField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "create_time" => SortField::CreateTime,
+            "send_time" => SortField::SendTime,
+            "" => SortField::Noop,
+            _ => SortField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SortField {
     fn default() -> SortField {
         SortField::Noop
@@ -9396,7 +13820,8 @@ impl SortField {
 /**
 * Determines the order direction for sorted results.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SortDir {
     #[serde(rename = "ASC")]
     Asc,
@@ -9404,8 +13829,9 @@ pub enum SortDir {
     Desc,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SortDir {
@@ -9414,12 +13840,45 @@ impl std::fmt::Display for SortDir {
             SortDir::Asc => "ASC",
             SortDir::Desc => "DESC",
             SortDir::Noop => "",
-            SortDir::FallthroughString => "*",
+            SortDir::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SortDir {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ASC" => SortDir::Asc,
+            "DESC" => SortDir::Desc,
+            "" => SortDir::Noop,
+            _ => SortDir::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SortDir {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SortDir {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SortDir {
     fn default() -> SortDir {
         SortDir::Noop
@@ -9434,7 +13893,8 @@ impl SortDir {
 /**
 * The current status of the campaign.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CampaignStatus {
     #[serde(rename = "archived")]
     Archived,
@@ -9454,8 +13914,9 @@ pub enum CampaignStatus {
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CampaignStatus {
@@ -9470,12 +13931,51 @@ impl std::fmt::Display for CampaignStatus {
             CampaignStatus::Sending => "sending",
             CampaignStatus::Sent => "sent",
             CampaignStatus::Noop => "",
-            CampaignStatus::FallthroughString => "*",
+            CampaignStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CampaignStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "archived" => CampaignStatus::Archived,
+            "canceled" => CampaignStatus::Canceled,
+            "canceling" => CampaignStatus::Canceling,
+            "paused" => CampaignStatus::Paused,
+            "save" => CampaignStatus::Save,
+            "schedule" => CampaignStatus::Schedule,
+            "sending" => CampaignStatus::Sending,
+            "sent" => CampaignStatus::Sent,
+            "" => CampaignStatus::Noop,
+            _ => CampaignStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CampaignStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CampaignStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CampaignStatus {
     fn default() -> CampaignStatus {
         CampaignStatus::Noop
@@ -9490,7 +13990,8 @@ impl CampaignStatus {
 /**
 * How the campaign's content is put together.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ContentType {
     #[serde(rename = "html")]
     Html,
@@ -9502,8 +14003,9 @@ pub enum ContentType {
     Url,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ContentType {
@@ -9514,12 +14016,47 @@ impl std::fmt::Display for ContentType {
             ContentType::Template => "template",
             ContentType::Url => "url",
             ContentType::Noop => "",
-            ContentType::FallthroughString => "*",
+            ContentType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ContentType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "html" => ContentType::Html,
+            "multichannel" => ContentType::Multichannel,
+            "template" => ContentType::Template,
+            "url" => ContentType::Url,
+            "" => ContentType::Noop,
+            _ => ContentType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ContentType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ContentType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ContentType {
     fn default() -> ContentType {
         ContentType::Noop
@@ -9684,7 +14221,8 @@ pub struct GetCampaignsResponseCampaignSettings {
 /**
 * The combination that performs the best. This may be determined automatically by click rate, open rate, or total revenue -- or you may choose manually based on the reporting data you find the most valuable. For Multivariate Campaigns testing send_time, winner_criteria is ignored. For Multivariate Campaigns with 'manual' as the winner_criteria, the winner must be chosen in the Mailchimp web application.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WinnerCriteria {
     #[serde(rename = "clicks")]
     Clicks,
@@ -9696,8 +14234,9 @@ pub enum WinnerCriteria {
     TotalRevenue,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WinnerCriteria {
@@ -9708,12 +14247,47 @@ impl std::fmt::Display for WinnerCriteria {
             WinnerCriteria::Opens => "opens",
             WinnerCriteria::TotalRevenue => "total_revenue",
             WinnerCriteria::Noop => "",
-            WinnerCriteria::FallthroughString => "*",
+            WinnerCriteria::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WinnerCriteria {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "clicks" => WinnerCriteria::Clicks,
+            "manual" => WinnerCriteria::Manual,
+            "opens" => WinnerCriteria::Opens,
+            "total_revenue" => WinnerCriteria::TotalRevenue,
+            "" => WinnerCriteria::Noop,
+            _ => WinnerCriteria::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WinnerCriteria {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WinnerCriteria {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WinnerCriteria {
     fn default() -> WinnerCriteria {
         WinnerCriteria::Noop
@@ -9895,7 +14469,8 @@ pub struct ABTestOptions {
 /**
 * The frequency of the RSS Campaign.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Frequency {
     #[serde(rename = "daily")]
     Daily,
@@ -9905,8 +14480,9 @@ pub enum Frequency {
     Weekly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Frequency {
@@ -9916,12 +14492,46 @@ impl std::fmt::Display for Frequency {
             Frequency::Monthly => "monthly",
             Frequency::Weekly => "weekly",
             Frequency::Noop => "",
-            Frequency::FallthroughString => "*",
+            Frequency::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Frequency {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "daily" => Frequency::Daily,
+            "monthly" => Frequency::Monthly,
+            "weekly" => Frequency::Weekly,
+            "" => Frequency::Noop,
+            _ => Frequency::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Frequency {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Frequency {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Frequency {
     fn default() -> Frequency {
         Frequency::Noop
@@ -10071,7 +14681,8 @@ pub struct RssOpts {
 /**
 * The type of AB split to run.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SplitTest {
     #[serde(rename = "from_name")]
     FromName,
@@ -10081,8 +14692,9 @@ pub enum SplitTest {
     Subject,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SplitTest {
@@ -10092,12 +14704,46 @@ impl std::fmt::Display for SplitTest {
             SplitTest::Schedule => "schedule",
             SplitTest::Subject => "subject",
             SplitTest::Noop => "",
-            SplitTest::FallthroughString => "*",
+            SplitTest::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SplitTest {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "from_name" => SplitTest::FromName,
+            "schedule" => SplitTest::Schedule,
+            "subject" => SplitTest::Subject,
+            "" => SplitTest::Noop,
+            _ => SplitTest::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SplitTest {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SplitTest {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SplitTest {
     fn default() -> SplitTest {
         SplitTest::Noop
@@ -10112,7 +14758,8 @@ impl SplitTest {
 /**
 * How we should evaluate a winner. Based on 'opens', 'clicks', or 'manual'.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PickWinner {
     #[serde(rename = "clicks")]
     Clicks,
@@ -10122,8 +14769,9 @@ pub enum PickWinner {
     Opens,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PickWinner {
@@ -10133,12 +14781,46 @@ impl std::fmt::Display for PickWinner {
             PickWinner::Manual => "manual",
             PickWinner::Opens => "opens",
             PickWinner::Noop => "",
-            PickWinner::FallthroughString => "*",
+            PickWinner::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PickWinner {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "clicks" => PickWinner::Clicks,
+            "manual" => PickWinner::Manual,
+            "opens" => PickWinner::Opens,
+            "" => PickWinner::Noop,
+            _ => PickWinner::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PickWinner {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PickWinner {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PickWinner {
     fn default() -> PickWinner {
         PickWinner::Noop
@@ -10153,7 +14835,8 @@ impl PickWinner {
 /**
 * How unit of time for measuring the winner ('hours' or 'days'). This cannot be changed after a campaign is sent.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WaitTime {
     #[serde(rename = "days")]
     Days,
@@ -10161,8 +14844,9 @@ pub enum WaitTime {
     Hours,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WaitTime {
@@ -10171,12 +14855,45 @@ impl std::fmt::Display for WaitTime {
             WaitTime::Days => "days",
             WaitTime::Hours => "hours",
             WaitTime::Noop => "",
-            WaitTime::FallthroughString => "*",
+            WaitTime::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WaitTime {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "days" => WaitTime::Days,
+            "hours" => WaitTime::Hours,
+            "" => WaitTime::Noop,
+            _ => WaitTime::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WaitTime {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WaitTime {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WaitTime {
     fn default() -> WaitTime {
         WaitTime::Noop
@@ -10406,7 +15123,8 @@ pub struct GetCampaignsResponseCampaignReportSummary {
 /**
 * The current state of a campaign delivery.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CampaignDeliveryStatus {
     #[serde(rename = "canceled")]
     Canceled,
@@ -10418,8 +15136,9 @@ pub enum CampaignDeliveryStatus {
     Delivering,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CampaignDeliveryStatus {
@@ -10430,12 +15149,47 @@ impl std::fmt::Display for CampaignDeliveryStatus {
             CampaignDeliveryStatus::Delivered => "delivered",
             CampaignDeliveryStatus::Delivering => "delivering",
             CampaignDeliveryStatus::Noop => "",
-            CampaignDeliveryStatus::FallthroughString => "*",
+            CampaignDeliveryStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CampaignDeliveryStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "canceled" => CampaignDeliveryStatus::Canceled,
+            "canceling" => CampaignDeliveryStatus::Canceling,
+            "delivered" => CampaignDeliveryStatus::Delivered,
+            "delivering" => CampaignDeliveryStatus::Delivering,
+            "" => CampaignDeliveryStatus::Noop,
+            _ => CampaignDeliveryStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CampaignDeliveryStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CampaignDeliveryStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CampaignDeliveryStatus {
     fn default() -> CampaignDeliveryStatus {
         CampaignDeliveryStatus::Noop
@@ -10967,7 +15721,8 @@ pub struct RssOptions {
 /**
 * How the campaign's content is put together. The old drag and drop editor uses 'template' while the new editor uses 'multichannel'. Defaults to template.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CreatedCampaignContentType {
     #[serde(rename = "multichannel")]
     Multichannel,
@@ -10975,8 +15730,9 @@ pub enum CreatedCampaignContentType {
     Template,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CreatedCampaignContentType {
@@ -10985,12 +15741,45 @@ impl std::fmt::Display for CreatedCampaignContentType {
             CreatedCampaignContentType::Multichannel => "multichannel",
             CreatedCampaignContentType::Template => "template",
             CreatedCampaignContentType::Noop => "",
-            CreatedCampaignContentType::FallthroughString => "*",
+            CreatedCampaignContentType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CreatedCampaignContentType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "multichannel" => CreatedCampaignContentType::Multichannel,
+            "template" => CreatedCampaignContentType::Template,
+            "" => CreatedCampaignContentType::Noop,
+            _ => CreatedCampaignContentType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CreatedCampaignContentType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CreatedCampaignContentType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CreatedCampaignContentType {
     fn default() -> CreatedCampaignContentType {
         CreatedCampaignContentType::Noop
@@ -11742,7 +16531,8 @@ pub struct PostCampaignsActionsScheduleRequest {
 /**
 * Choose the type of test email to send.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SendType {
     #[serde(rename = "html")]
     Html,
@@ -11750,8 +16540,9 @@ pub enum SendType {
     Plaintext,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SendType {
@@ -11760,12 +16551,45 @@ impl std::fmt::Display for SendType {
             SendType::Html => "html",
             SendType::Plaintext => "plaintext",
             SendType::Noop => "",
-            SendType::FallthroughString => "*",
+            SendType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SendType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "html" => SendType::Html,
+            "plaintext" => SendType::Plaintext,
+            "" => SendType::Noop,
+            _ => SendType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SendType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SendType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SendType {
     fn default() -> SendType {
         SendType::Noop
@@ -11899,7 +16723,8 @@ pub struct Template {
 /**
 * The type of encoded file. Defaults to zip.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ArchiveType {
     #[serde(rename = "tar")]
     Tar,
@@ -11915,8 +16740,9 @@ pub enum ArchiveType {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ArchiveType {
@@ -11929,12 +16755,49 @@ impl std::fmt::Display for ArchiveType {
             ArchiveType::Tgz => "tgz",
             ArchiveType::Zip => "zip",
             ArchiveType::Noop => "",
-            ArchiveType::FallthroughString => "*",
+            ArchiveType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ArchiveType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "tar" => ArchiveType::Tar,
+            "tar.bz2" => ArchiveType::TarBz2,
+            "tar.gz" => ArchiveType::TarGz,
+            "tbz" => ArchiveType::Tbz,
+            "tgz" => ArchiveType::Tgz,
+            "zip" => ArchiveType::Zip,
+            "" => ArchiveType::Noop,
+            _ => ArchiveType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ArchiveType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ArchiveType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ArchiveType {
     fn default() -> ArchiveType {
         ArchiveType::Noop
@@ -12088,7 +16951,8 @@ pub struct CampaignContentData {
 /**
 * The source of the feedback.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Source {
     #[serde(rename = "android")]
     Android,
@@ -12104,8 +16968,9 @@ pub enum Source {
     Web,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Source {
@@ -12118,12 +16983,49 @@ impl std::fmt::Display for Source {
             Source::Sms => "sms",
             Source::Web => "web",
             Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Source {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "android" => Source::Android,
+            "api" => Source::Api,
+            "email" => Source::Email,
+            "ios" => Source::Ios,
+            "sms" => Source::Sms,
+            "web" => Source::Web,
+            "" => Source::Noop,
+            _ => Source::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Source {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Source {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Source {
     fn default() -> Source {
         Source::Noop
@@ -12442,7 +17344,8 @@ pub struct CampaignFeedbackDataType {
 /**
 * The item type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SendChecklistItemsType {
     #[serde(rename = "error")]
     Error,
@@ -12452,8 +17355,9 @@ pub enum SendChecklistItemsType {
     Warning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SendChecklistItemsType {
@@ -12463,12 +17367,46 @@ impl std::fmt::Display for SendChecklistItemsType {
             SendChecklistItemsType::Success => "success",
             SendChecklistItemsType::Warning => "warning",
             SendChecklistItemsType::Noop => "",
-            SendChecklistItemsType::FallthroughString => "*",
+            SendChecklistItemsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SendChecklistItemsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "error" => SendChecklistItemsType::Error,
+            "success" => SendChecklistItemsType::Success,
+            "warning" => SendChecklistItemsType::Warning,
+            "" => SendChecklistItemsType::Noop,
+            _ => SendChecklistItemsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SendChecklistItemsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SendChecklistItemsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SendChecklistItemsType {
     fn default() -> SendChecklistItemsType {
         SendChecklistItemsType::Noop
@@ -12704,7 +17642,8 @@ pub struct ConnectedSite {
 /**
 * Whether a conversation message has been marked as read.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IsRead {
     #[serde(rename = "false")]
     False,
@@ -12712,8 +17651,9 @@ pub enum IsRead {
     True,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IsRead {
@@ -12722,12 +17662,45 @@ impl std::fmt::Display for IsRead {
             IsRead::False => "false",
             IsRead::True => "true",
             IsRead::Noop => "",
-            IsRead::FallthroughString => "*",
+            IsRead::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IsRead {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "false" => IsRead::False,
+            "true" => IsRead::True,
+            "" => IsRead::Noop,
+            _ => IsRead::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IsRead {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IsRead {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IsRead {
     fn default() -> IsRead {
         IsRead::Noop
@@ -13062,14 +18035,16 @@ pub struct CollectionOfConversationMessages {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetFileManagerFilesSortField {
     #[serde(rename = "added_date")]
     AddedDate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetFileManagerFilesSortField {
@@ -13077,12 +18052,44 @@ impl std::fmt::Display for GetFileManagerFilesSortField {
         match self {
             GetFileManagerFilesSortField::AddedDate => "added_date",
             GetFileManagerFilesSortField::Noop => "",
-            GetFileManagerFilesSortField::FallthroughString => "*",
+            GetFileManagerFilesSortField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetFileManagerFilesSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "added_date" => GetFileManagerFilesSortField::AddedDate,
+            "" => GetFileManagerFilesSortField::Noop,
+            _ => GetFileManagerFilesSortField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetFileManagerFilesSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetFileManagerFilesSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetFileManagerFilesSortField {
     fn default() -> GetFileManagerFilesSortField {
         GetFileManagerFilesSortField::Noop
@@ -13097,7 +18104,8 @@ impl GetFileManagerFilesSortField {
 /**
 * The type of file in the File Manager.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FileType {
     #[serde(rename = "file")]
     File,
@@ -13105,8 +18113,9 @@ pub enum FileType {
     Image,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -13115,12 +18124,45 @@ impl std::fmt::Display for FileType {
             FileType::File => "file",
             FileType::Image => "image",
             FileType::Noop => "",
-            FileType::FallthroughString => "*",
+            FileType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FileType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "file" => FileType::File,
+            "image" => FileType::Image,
+            "" => FileType::Noop,
+            _ => FileType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FileType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FileType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FileType {
     fn default() -> FileType {
         FileType::Noop
@@ -13435,14 +18477,16 @@ pub struct FileManagerFolders {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetListsSortField {
     #[serde(rename = "date_created")]
     DateCreated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetListsSortField {
@@ -13450,12 +18494,44 @@ impl std::fmt::Display for GetListsSortField {
         match self {
             GetListsSortField::DateCreated => "date_created",
             GetListsSortField::Noop => "",
-            GetListsSortField::FallthroughString => "*",
+            GetListsSortField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetListsSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "date_created" => GetListsSortField::DateCreated,
+            "" => GetListsSortField::Noop,
+            _ => GetListsSortField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetListsSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetListsSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetListsSortField {
     fn default() -> GetListsSortField {
         GetListsSortField::Noop
@@ -13590,7 +18666,8 @@ pub struct CampaignDefaults {
 /**
 * Legacy - visibility settings are no longer used
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Visibility {
     #[serde(rename = "prv")]
     Prv,
@@ -13598,8 +18675,9 @@ pub enum Visibility {
     Pub,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -13608,12 +18686,45 @@ impl std::fmt::Display for Visibility {
             Visibility::Prv => "prv",
             Visibility::Pub => "pub",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Visibility {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "prv" => Visibility::Prv,
+            "pub" => Visibility::Pub,
+            "" => Visibility::Noop,
+            _ => Visibility::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Visibility {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Visibility {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Visibility {
     fn default() -> Visibility {
         Visibility::Noop
@@ -14241,7 +19352,8 @@ pub struct SubscriberList {
 /**
 * Subscriber's current status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MembersSubscribeUnsubscribeFromAListInBatchStatus {
     #[serde(rename = "cleaned")]
     Cleaned,
@@ -14253,8 +19365,9 @@ pub enum MembersSubscribeUnsubscribeFromAListInBatchStatus {
     Unsubscribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MembersSubscribeUnsubscribeFromAListInBatchStatus {
@@ -14265,12 +19378,47 @@ impl std::fmt::Display for MembersSubscribeUnsubscribeFromAListInBatchStatus {
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Subscribed => "subscribed",
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Unsubscribed => "unsubscribed",
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop => "",
-            MembersSubscribeUnsubscribeFromAListInBatchStatus::FallthroughString => "*",
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MembersSubscribeUnsubscribeFromAListInBatchStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cleaned" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Cleaned,
+            "pending" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Pending,
+            "subscribed" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Subscribed,
+            "unsubscribed" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Unsubscribed,
+            "" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop,
+            _ => MembersSubscribeUnsubscribeFromAListInBatchStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MembersSubscribeUnsubscribeFromAListInBatchStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MembersSubscribeUnsubscribeFromAListInBatchStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MembersSubscribeUnsubscribeFromAListInBatchStatus {
     fn default() -> MembersSubscribeUnsubscribeFromAListInBatchStatus {
         MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop
@@ -14432,7 +19580,8 @@ pub struct MembersSubscribeUnsubscribeFromAListInBatch {
 /**
 * Subscriber's status. This value is required only if the email address is not already present on the list.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum StatusIfNew {
     #[serde(rename = "cleaned")]
     Cleaned,
@@ -14446,8 +19595,9 @@ pub enum StatusIfNew {
     Unsubscribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for StatusIfNew {
@@ -14459,12 +19609,48 @@ impl std::fmt::Display for StatusIfNew {
             StatusIfNew::Transactional => "transactional",
             StatusIfNew::Unsubscribed => "unsubscribed",
             StatusIfNew::Noop => "",
-            StatusIfNew::FallthroughString => "*",
+            StatusIfNew::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for StatusIfNew {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cleaned" => StatusIfNew::Cleaned,
+            "pending" => StatusIfNew::Pending,
+            "subscribed" => StatusIfNew::Subscribed,
+            "transactional" => StatusIfNew::Transactional,
+            "unsubscribed" => StatusIfNew::Unsubscribed,
+            "" => StatusIfNew::Noop,
+            _ => StatusIfNew::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for StatusIfNew {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for StatusIfNew {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for StatusIfNew {
     fn default() -> StatusIfNew {
         StatusIfNew::Noop
@@ -14815,7 +20001,8 @@ pub struct NewMembers {
 /**
 * A unique code that identifies this specifc error.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ErrorCode {
     #[serde(rename = "ERROR_CONTACT_EXISTS")]
     ErrorContactExists,
@@ -14823,8 +20010,9 @@ pub enum ErrorCode {
     ErrorGeneric,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -14833,12 +20021,45 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::ErrorContactExists => "ERROR_CONTACT_EXISTS",
             ErrorCode::ErrorGeneric => "ERROR_GENERIC",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ErrorCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ERROR_CONTACT_EXISTS" => ErrorCode::ErrorContactExists,
+            "ERROR_GENERIC" => ErrorCode::ErrorGeneric,
+            "" => ErrorCode::Noop,
+            _ => ErrorCode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ErrorCode {
     fn default() -> ErrorCode {
         ErrorCode::Noop
@@ -15447,14 +20668,16 @@ pub struct EmailClients {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetListsGrowthHistorySortField {
     #[serde(rename = "month")]
     Month,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetListsGrowthHistorySortField {
@@ -15462,12 +20685,44 @@ impl std::fmt::Display for GetListsGrowthHistorySortField {
         match self {
             GetListsGrowthHistorySortField::Month => "month",
             GetListsGrowthHistorySortField::Noop => "",
-            GetListsGrowthHistorySortField::FallthroughString => "*",
+            GetListsGrowthHistorySortField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetListsGrowthHistorySortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "month" => GetListsGrowthHistorySortField::Month,
+            "" => GetListsGrowthHistorySortField::Noop,
+            _ => GetListsGrowthHistorySortField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetListsGrowthHistorySortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetListsGrowthHistorySortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetListsGrowthHistorySortField {
     fn default() -> GetListsGrowthHistorySortField {
         GetListsGrowthHistorySortField::Noop
@@ -15865,7 +21120,8 @@ pub struct InterestsData {
 /**
 * The type of segment. Static segments are now known as tags. Learn more about [tags](https://mailchimp.com/help/getting-started-tags?utm_source=mc-api&utm_medium=docs&utm_campaign=apidocs).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CollectionOfSegmentsType {
     #[serde(rename = "fuzzy")]
     Fuzzy,
@@ -15875,8 +21131,9 @@ pub enum CollectionOfSegmentsType {
     Static,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CollectionOfSegmentsType {
@@ -15886,12 +21143,46 @@ impl std::fmt::Display for CollectionOfSegmentsType {
             CollectionOfSegmentsType::Saved => "saved",
             CollectionOfSegmentsType::Static => "static",
             CollectionOfSegmentsType::Noop => "",
-            CollectionOfSegmentsType::FallthroughString => "*",
+            CollectionOfSegmentsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CollectionOfSegmentsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "fuzzy" => CollectionOfSegmentsType::Fuzzy,
+            "saved" => CollectionOfSegmentsType::Saved,
+            "static" => CollectionOfSegmentsType::Static,
+            "" => CollectionOfSegmentsType::Noop,
+            _ => CollectionOfSegmentsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CollectionOfSegmentsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CollectionOfSegmentsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CollectionOfSegmentsType {
     fn default() -> CollectionOfSegmentsType {
         CollectionOfSegmentsType::Noop
@@ -16483,7 +21774,8 @@ pub struct TagSearchResults {
 /**
 * The subscriber's status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetListsMembersStatus {
     #[serde(rename = "archived")]
     Archived,
@@ -16499,8 +21791,9 @@ pub enum GetListsMembersStatus {
     Unsubscribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetListsMembersStatus {
@@ -16513,12 +21806,49 @@ impl std::fmt::Display for GetListsMembersStatus {
             GetListsMembersStatus::Transactional => "transactional",
             GetListsMembersStatus::Unsubscribed => "unsubscribed",
             GetListsMembersStatus::Noop => "",
-            GetListsMembersStatus::FallthroughString => "*",
+            GetListsMembersStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetListsMembersStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "archived" => GetListsMembersStatus::Archived,
+            "cleaned" => GetListsMembersStatus::Cleaned,
+            "pending" => GetListsMembersStatus::Pending,
+            "subscribed" => GetListsMembersStatus::Subscribed,
+            "transactional" => GetListsMembersStatus::Transactional,
+            "unsubscribed" => GetListsMembersStatus::Unsubscribed,
+            "" => GetListsMembersStatus::Noop,
+            _ => GetListsMembersStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetListsMembersStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetListsMembersStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetListsMembersStatus {
     fn default() -> GetListsMembersStatus {
         GetListsMembersStatus::Noop
@@ -16533,7 +21863,8 @@ impl GetListsMembersStatus {
 /**
 * Used to filter list members by interests. Must be accompanied by interest_category_id and interest_ids. "any" will match a member with any of the interest supplied, "all" will only match members with every interest supplied, and "none" will match members without any of the interest supplied.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum InterestMatch {
     #[serde(rename = "all")]
     All,
@@ -16543,8 +21874,9 @@ pub enum InterestMatch {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for InterestMatch {
@@ -16554,12 +21886,46 @@ impl std::fmt::Display for InterestMatch {
             InterestMatch::Any => "any",
             InterestMatch::None => "none",
             InterestMatch::Noop => "",
-            InterestMatch::FallthroughString => "*",
+            InterestMatch::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for InterestMatch {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => InterestMatch::All,
+            "any" => InterestMatch::Any,
+            "none" => InterestMatch::None,
+            "" => InterestMatch::Noop,
+            _ => InterestMatch::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for InterestMatch {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for InterestMatch {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for InterestMatch {
     fn default() -> InterestMatch {
         InterestMatch::Noop
@@ -16574,7 +21940,8 @@ impl InterestMatch {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetListsMembersSortField {
     #[serde(rename = "last_changed")]
     LastChanged,
@@ -16584,8 +21951,9 @@ pub enum GetListsMembersSortField {
     TimestampSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetListsMembersSortField {
@@ -16595,12 +21963,46 @@ impl std::fmt::Display for GetListsMembersSortField {
             GetListsMembersSortField::TimestampOpt => "timestamp_opt",
             GetListsMembersSortField::TimestampSignup => "timestamp_signup",
             GetListsMembersSortField::Noop => "",
-            GetListsMembersSortField::FallthroughString => "*",
+            GetListsMembersSortField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetListsMembersSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "last_changed" => GetListsMembersSortField::LastChanged,
+            "timestamp_opt" => GetListsMembersSortField::TimestampOpt,
+            "timestamp_signup" => GetListsMembersSortField::TimestampSignup,
+            "" => GetListsMembersSortField::Noop,
+            _ => GetListsMembersSortField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetListsMembersSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetListsMembersSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetListsMembersSortField {
     fn default() -> GetListsMembersSortField {
         GetListsMembersSortField::Noop
@@ -17464,14 +22866,16 @@ pub struct MemberActivityEvents {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ActivityType {
     #[serde(rename = "open")]
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ActivityType {
@@ -17479,12 +22883,44 @@ impl std::fmt::Display for ActivityType {
         match self {
             ActivityType::Open => "open",
             ActivityType::Noop => "",
-            ActivityType::FallthroughString => "*",
+            ActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "open" => ActivityType::Open,
+            "" => ActivityType::Noop,
+            _ => ActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ActivityType {
     fn default() -> ActivityType {
         ActivityType::Noop
@@ -17536,14 +22972,16 @@ pub struct EmailOpens {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EmailClicksActivityType {
     #[serde(rename = "click")]
     Click,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EmailClicksActivityType {
@@ -17551,12 +22989,44 @@ impl std::fmt::Display for EmailClicksActivityType {
         match self {
             EmailClicksActivityType::Click => "click",
             EmailClicksActivityType::Noop => "",
-            EmailClicksActivityType::FallthroughString => "*",
+            EmailClicksActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EmailClicksActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "click" => EmailClicksActivityType::Click,
+            "" => EmailClicksActivityType::Noop,
+            _ => EmailClicksActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EmailClicksActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailClicksActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EmailClicksActivityType {
     fn default() -> EmailClicksActivityType {
         EmailClicksActivityType::Noop
@@ -17617,14 +23087,16 @@ pub struct EmailClicks {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EmailBouncedActivityType {
     #[serde(rename = "bounce")]
     Bounce,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EmailBouncedActivityType {
@@ -17632,12 +23104,44 @@ impl std::fmt::Display for EmailBouncedActivityType {
         match self {
             EmailBouncedActivityType::Bounce => "bounce",
             EmailBouncedActivityType::Noop => "",
-            EmailBouncedActivityType::FallthroughString => "*",
+            EmailBouncedActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EmailBouncedActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "bounce" => EmailBouncedActivityType::Bounce,
+            "" => EmailBouncedActivityType::Noop,
+            _ => EmailBouncedActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EmailBouncedActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailBouncedActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EmailBouncedActivityType {
     fn default() -> EmailBouncedActivityType {
         EmailBouncedActivityType::Noop
@@ -17652,7 +23156,8 @@ impl EmailBouncedActivityType {
 /**
 * The type of bounce.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BounceType {
     #[serde(rename = "hard")]
     Hard,
@@ -17660,8 +23165,9 @@ pub enum BounceType {
     Soft,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BounceType {
@@ -17670,12 +23176,45 @@ impl std::fmt::Display for BounceType {
             BounceType::Hard => "hard",
             BounceType::Soft => "soft",
             BounceType::Noop => "",
-            BounceType::FallthroughString => "*",
+            BounceType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BounceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "hard" => BounceType::Hard,
+            "soft" => BounceType::Soft,
+            "" => BounceType::Noop,
+            _ => BounceType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BounceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BounceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BounceType {
     fn default() -> BounceType {
         BounceType::Noop
@@ -17740,14 +23279,16 @@ pub struct EmailBounced {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ListUnsubscribedActivityType {
     #[serde(rename = "unsub")]
     Unsub,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ListUnsubscribedActivityType {
@@ -17755,12 +23296,44 @@ impl std::fmt::Display for ListUnsubscribedActivityType {
         match self {
             ListUnsubscribedActivityType::Unsub => "unsub",
             ListUnsubscribedActivityType::Noop => "",
-            ListUnsubscribedActivityType::FallthroughString => "*",
+            ListUnsubscribedActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ListUnsubscribedActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "unsub" => ListUnsubscribedActivityType::Unsub,
+            "" => ListUnsubscribedActivityType::Noop,
+            _ => ListUnsubscribedActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ListUnsubscribedActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListUnsubscribedActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ListUnsubscribedActivityType {
     fn default() -> ListUnsubscribedActivityType {
         ListUnsubscribedActivityType::Noop
@@ -17829,14 +23402,16 @@ pub struct ListUnsubscribed {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EmailSentActivityType {
     #[serde(rename = "sent")]
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EmailSentActivityType {
@@ -17844,12 +23419,44 @@ impl std::fmt::Display for EmailSentActivityType {
         match self {
             EmailSentActivityType::Sent => "sent",
             EmailSentActivityType::Noop => "",
-            EmailSentActivityType::FallthroughString => "*",
+            EmailSentActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EmailSentActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "sent" => EmailSentActivityType::Sent,
+            "" => EmailSentActivityType::Noop,
+            _ => EmailSentActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EmailSentActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailSentActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EmailSentActivityType {
     fn default() -> EmailSentActivityType {
         EmailSentActivityType::Noop
@@ -17999,14 +23606,16 @@ pub struct EmailConversation {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum NoteActivityType {
     #[serde(rename = "note")]
     Note,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for NoteActivityType {
@@ -18014,12 +23623,44 @@ impl std::fmt::Display for NoteActivityType {
         match self {
             NoteActivityType::Note => "note",
             NoteActivityType::Noop => "",
-            NoteActivityType::FallthroughString => "*",
+            NoteActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for NoteActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "note" => NoteActivityType::Note,
+            "" => NoteActivityType::Noop,
+            _ => NoteActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NoteActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for NoteActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for NoteActivityType {
     fn default() -> NoteActivityType {
         NoteActivityType::Noop
@@ -18098,14 +23739,16 @@ pub struct Note {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MarketingPermissionActivityType {
     #[serde(rename = "marketing_permission")]
     MarketingPermission,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MarketingPermissionActivityType {
@@ -18113,12 +23756,44 @@ impl std::fmt::Display for MarketingPermissionActivityType {
         match self {
             MarketingPermissionActivityType::MarketingPermission => "marketing_permission",
             MarketingPermissionActivityType::Noop => "",
-            MarketingPermissionActivityType::FallthroughString => "*",
+            MarketingPermissionActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MarketingPermissionActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "marketing_permission" => MarketingPermissionActivityType::MarketingPermission,
+            "" => MarketingPermissionActivityType::Noop,
+            _ => MarketingPermissionActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MarketingPermissionActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MarketingPermissionActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MarketingPermissionActivityType {
     fn default() -> MarketingPermissionActivityType {
         MarketingPermissionActivityType::Noop
@@ -18178,14 +23853,16 @@ pub struct MarketingPermissionData {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PostcardSentActivityType {
     #[serde(rename = "postcard_sent")]
     PostcardSent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PostcardSentActivityType {
@@ -18193,12 +23870,44 @@ impl std::fmt::Display for PostcardSentActivityType {
         match self {
             PostcardSentActivityType::PostcardSent => "postcard_sent",
             PostcardSentActivityType::Noop => "",
-            PostcardSentActivityType::FallthroughString => "*",
+            PostcardSentActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PostcardSentActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "postcard_sent" => PostcardSentActivityType::PostcardSent,
+            "" => PostcardSentActivityType::Noop,
+            _ => PostcardSentActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PostcardSentActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PostcardSentActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PostcardSentActivityType {
     fn default() -> PostcardSentActivityType {
         PostcardSentActivityType::Noop
@@ -18250,14 +23959,16 @@ pub struct PostcardSent {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SquatterSignupActivityType {
     #[serde(rename = "squatter_signup")]
     SquatterSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SquatterSignupActivityType {
@@ -18265,12 +23976,44 @@ impl std::fmt::Display for SquatterSignupActivityType {
         match self {
             SquatterSignupActivityType::SquatterSignup => "squatter_signup",
             SquatterSignupActivityType::Noop => "",
-            SquatterSignupActivityType::FallthroughString => "*",
+            SquatterSignupActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SquatterSignupActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "squatter_signup" => SquatterSignupActivityType::SquatterSignup,
+            "" => SquatterSignupActivityType::Noop,
+            _ => SquatterSignupActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SquatterSignupActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SquatterSignupActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SquatterSignupActivityType {
     fn default() -> SquatterSignupActivityType {
         SquatterSignupActivityType::Noop
@@ -18322,14 +24065,16 @@ pub struct SquatterSignup {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WebsiteSignupActivityType {
     #[serde(rename = "website_signup")]
     WebsiteSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WebsiteSignupActivityType {
@@ -18337,12 +24082,44 @@ impl std::fmt::Display for WebsiteSignupActivityType {
         match self {
             WebsiteSignupActivityType::WebsiteSignup => "website_signup",
             WebsiteSignupActivityType::Noop => "",
-            WebsiteSignupActivityType::FallthroughString => "*",
+            WebsiteSignupActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WebsiteSignupActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "website_signup" => WebsiteSignupActivityType::WebsiteSignup,
+            "" => WebsiteSignupActivityType::Noop,
+            _ => WebsiteSignupActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WebsiteSignupActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WebsiteSignupActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WebsiteSignupActivityType {
     fn default() -> WebsiteSignupActivityType {
         WebsiteSignupActivityType::Noop
@@ -18394,14 +24171,16 @@ pub struct WebsiteSignup {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LandingPageSignupActivityType {
     #[serde(rename = "landing_page_signup")]
     LandingPageSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LandingPageSignupActivityType {
@@ -18409,12 +24188,44 @@ impl std::fmt::Display for LandingPageSignupActivityType {
         match self {
             LandingPageSignupActivityType::LandingPageSignup => "landing_page_signup",
             LandingPageSignupActivityType::Noop => "",
-            LandingPageSignupActivityType::FallthroughString => "*",
+            LandingPageSignupActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LandingPageSignupActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "landing_page_signup" => LandingPageSignupActivityType::LandingPageSignup,
+            "" => LandingPageSignupActivityType::Noop,
+            _ => LandingPageSignupActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LandingPageSignupActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LandingPageSignupActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LandingPageSignupActivityType {
     fn default() -> LandingPageSignupActivityType {
         LandingPageSignupActivityType::Noop
@@ -18466,14 +24277,16 @@ pub struct LandingPageSignup {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommerceSignupActivityType {
     #[serde(rename = "ecommerce_signup")]
     EcommerceSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommerceSignupActivityType {
@@ -18481,12 +24294,44 @@ impl std::fmt::Display for EcommerceSignupActivityType {
         match self {
             EcommerceSignupActivityType::EcommerceSignup => "ecommerce_signup",
             EcommerceSignupActivityType::Noop => "",
-            EcommerceSignupActivityType::FallthroughString => "*",
+            EcommerceSignupActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommerceSignupActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ecommerce_signup" => EcommerceSignupActivityType::EcommerceSignup,
+            "" => EcommerceSignupActivityType::Noop,
+            _ => EcommerceSignupActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommerceSignupActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceSignupActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommerceSignupActivityType {
     fn default() -> EcommerceSignupActivityType {
         EcommerceSignupActivityType::Noop
@@ -18529,14 +24374,16 @@ pub struct EcommerceSignup {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GenericSignupActivityType {
     #[serde(rename = "generic_signup")]
     GenericSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GenericSignupActivityType {
@@ -18544,12 +24391,44 @@ impl std::fmt::Display for GenericSignupActivityType {
         match self {
             GenericSignupActivityType::GenericSignup => "generic_signup",
             GenericSignupActivityType::Noop => "",
-            GenericSignupActivityType::FallthroughString => "*",
+            GenericSignupActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GenericSignupActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "generic_signup" => GenericSignupActivityType::GenericSignup,
+            "" => GenericSignupActivityType::Noop,
+            _ => GenericSignupActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GenericSignupActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GenericSignupActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GenericSignupActivityType {
     fn default() -> GenericSignupActivityType {
         GenericSignupActivityType::Noop
@@ -18592,14 +24471,16 @@ pub struct GenericSignup {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EcommerceOrderActivityType {
     #[serde(rename = "order")]
     Order,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EcommerceOrderActivityType {
@@ -18607,12 +24488,44 @@ impl std::fmt::Display for EcommerceOrderActivityType {
         match self {
             EcommerceOrderActivityType::Order => "order",
             EcommerceOrderActivityType::Noop => "",
-            EcommerceOrderActivityType::FallthroughString => "*",
+            EcommerceOrderActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EcommerceOrderActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "order" => EcommerceOrderActivityType::Order,
+            "" => EcommerceOrderActivityType::Noop,
+            _ => EcommerceOrderActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EcommerceOrderActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EcommerceOrderActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EcommerceOrderActivityType {
     fn default() -> EcommerceOrderActivityType {
         EcommerceOrderActivityType::Noop
@@ -18814,14 +24727,16 @@ pub struct EcommerceOrder {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ContactActivityEventType {
     #[serde(rename = "event")]
     Event,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ContactActivityEventType {
@@ -18829,12 +24744,44 @@ impl std::fmt::Display for ContactActivityEventType {
         match self {
             ContactActivityEventType::Event => "event",
             ContactActivityEventType::Noop => "",
-            ContactActivityEventType::FallthroughString => "*",
+            ContactActivityEventType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ContactActivityEventType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "event" => ContactActivityEventType::Event,
+            "" => ContactActivityEventType::Noop,
+            _ => ContactActivityEventType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ContactActivityEventType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ContactActivityEventType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ContactActivityEventType {
     fn default() -> ContactActivityEventType {
         ContactActivityEventType::Noop
@@ -18886,14 +24833,16 @@ pub struct ContactActivityEvent {
 /**
 * The type of event activity.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SurveyResponseActivityType {
     #[serde(rename = "survey_response")]
     SurveyResponse,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SurveyResponseActivityType {
@@ -18901,12 +24850,44 @@ impl std::fmt::Display for SurveyResponseActivityType {
         match self {
             SurveyResponseActivityType::SurveyResponse => "survey_response",
             SurveyResponseActivityType::Noop => "",
-            SurveyResponseActivityType::FallthroughString => "*",
+            SurveyResponseActivityType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SurveyResponseActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "survey_response" => SurveyResponseActivityType::SurveyResponse,
+            "" => SurveyResponseActivityType::Noop,
+            _ => SurveyResponseActivityType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SurveyResponseActivityType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SurveyResponseActivityType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SurveyResponseActivityType {
     fn default() -> SurveyResponseActivityType {
         SurveyResponseActivityType::Noop
@@ -19249,7 +25230,8 @@ pub struct CollectionOfTags {
 /**
 * The status for the tag on the member, pass in active to add a tag or inactive to remove it.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MemberTagsTagStatus {
     #[serde(rename = "active")]
     Active,
@@ -19257,8 +25239,9 @@ pub enum MemberTagsTagStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MemberTagsTagStatus {
@@ -19267,12 +25250,45 @@ impl std::fmt::Display for MemberTagsTagStatus {
             MemberTagsTagStatus::Active => "active",
             MemberTagsTagStatus::Inactive => "inactive",
             MemberTagsTagStatus::Noop => "",
-            MemberTagsTagStatus::FallthroughString => "*",
+            MemberTagsTagStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MemberTagsTagStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => MemberTagsTagStatus::Active,
+            "inactive" => MemberTagsTagStatus::Inactive,
+            "" => MemberTagsTagStatus::Noop,
+            _ => MemberTagsTagStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MemberTagsTagStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MemberTagsTagStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MemberTagsTagStatus {
     fn default() -> MemberTagsTagStatus {
         MemberTagsTagStatus::Noop
@@ -19525,7 +25541,8 @@ pub struct CollectionOfMemberActivityEvents {
 /**
 * Returns notes sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetListsMembersNotesSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -19535,8 +25552,9 @@ pub enum GetListsMembersNotesSortField {
     UpdatedAt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetListsMembersNotesSortField {
@@ -19546,12 +25564,46 @@ impl std::fmt::Display for GetListsMembersNotesSortField {
             GetListsMembersNotesSortField::NoteId => "note_id",
             GetListsMembersNotesSortField::UpdatedAt => "updated_at",
             GetListsMembersNotesSortField::Noop => "",
-            GetListsMembersNotesSortField::FallthroughString => "*",
+            GetListsMembersNotesSortField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetListsMembersNotesSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "created_at" => GetListsMembersNotesSortField::CreatedAt,
+            "note_id" => GetListsMembersNotesSortField::NoteId,
+            "updated_at" => GetListsMembersNotesSortField::UpdatedAt,
+            "" => GetListsMembersNotesSortField::Noop,
+            _ => GetListsMembersNotesSortField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetListsMembersNotesSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetListsMembersNotesSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetListsMembersNotesSortField {
     fn default() -> GetListsMembersNotesSortField {
         GetListsMembersNotesSortField::Noop
@@ -19695,7 +25747,8 @@ pub struct CollectionOfNotes {
 /**
 * The [type](https://mailchimp.com/help/manage-audience-signup-form-fields/#Audience_field_types) for the merge field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MergeFieldType {
     #[serde(rename = "address")]
     Address,
@@ -19721,8 +25774,9 @@ pub enum MergeFieldType {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MergeFieldType {
@@ -19740,12 +25794,54 @@ impl std::fmt::Display for MergeFieldType {
             MergeFieldType::Url => "url",
             MergeFieldType::Zip => "zip",
             MergeFieldType::Noop => "",
-            MergeFieldType::FallthroughString => "*",
+            MergeFieldType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MergeFieldType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "address" => MergeFieldType::Address,
+            "birthday" => MergeFieldType::Birthday,
+            "date" => MergeFieldType::Date,
+            "dropdown" => MergeFieldType::Dropdown,
+            "imageurl" => MergeFieldType::Imageurl,
+            "number" => MergeFieldType::Number,
+            "phone" => MergeFieldType::Phone,
+            "radio" => MergeFieldType::Radio,
+            "text" => MergeFieldType::Text,
+            "url" => MergeFieldType::Url,
+            "zip" => MergeFieldType::Zip,
+            "" => MergeFieldType::Noop,
+            _ => MergeFieldType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MergeFieldType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MergeFieldType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MergeFieldType {
     fn default() -> MergeFieldType {
         MergeFieldType::Noop
@@ -20324,7 +26420,8 @@ pub struct ListWebhooksData {
 /**
 * Image alignment.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ImageAlign {
     #[serde(rename = "center")]
     Center,
@@ -20336,8 +26433,9 @@ pub enum ImageAlign {
     Right,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ImageAlign {
@@ -20348,12 +26446,47 @@ impl std::fmt::Display for ImageAlign {
             ImageAlign::None => "none",
             ImageAlign::Right => "right",
             ImageAlign::Noop => "",
-            ImageAlign::FallthroughString => "*",
+            ImageAlign::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ImageAlign {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "center" => ImageAlign::Center,
+            "left" => ImageAlign::Left,
+            "none" => ImageAlign::None,
+            "right" => ImageAlign::Right,
+            "" => ImageAlign::Noop,
+            _ => ImageAlign::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ImageAlign {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ImageAlign {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ImageAlign {
     fn default() -> ImageAlign {
         ImageAlign::Noop
@@ -20368,7 +26501,8 @@ impl ImageAlign {
 /**
 * Image border style.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ImageBorderStyle {
     #[serde(rename = "dashed")]
     Dashed,
@@ -20390,8 +26524,9 @@ pub enum ImageBorderStyle {
     Solid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ImageBorderStyle {
@@ -20407,12 +26542,52 @@ impl std::fmt::Display for ImageBorderStyle {
             ImageBorderStyle::Ridge => "ridge",
             ImageBorderStyle::Solid => "solid",
             ImageBorderStyle::Noop => "",
-            ImageBorderStyle::FallthroughString => "*",
+            ImageBorderStyle::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ImageBorderStyle {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "dashed" => ImageBorderStyle::Dashed,
+            "dotted" => ImageBorderStyle::Dotted,
+            "double" => ImageBorderStyle::Double,
+            "groove" => ImageBorderStyle::Groove,
+            "inset" => ImageBorderStyle::Inset,
+            "none" => ImageBorderStyle::None,
+            "outset" => ImageBorderStyle::Outset,
+            "ridge" => ImageBorderStyle::Ridge,
+            "solid" => ImageBorderStyle::Solid,
+            "" => ImageBorderStyle::Noop,
+            _ => ImageBorderStyle::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ImageBorderStyle {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ImageBorderStyle {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ImageBorderStyle {
     fn default() -> ImageBorderStyle {
         ImageBorderStyle::Noop
@@ -20427,7 +26602,8 @@ impl ImageBorderStyle {
 /**
 * Image link target.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ImageTarget {
     #[serde(rename = "_blank")]
     Blank,
@@ -20435,8 +26611,9 @@ pub enum ImageTarget {
     Null,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ImageTarget {
@@ -20445,12 +26622,45 @@ impl std::fmt::Display for ImageTarget {
             ImageTarget::Blank => "_blank",
             ImageTarget::Null => "null",
             ImageTarget::Noop => "",
-            ImageTarget::FallthroughString => "*",
+            ImageTarget::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ImageTarget {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "_blank" => ImageTarget::Blank,
+            "null" => ImageTarget::Null,
+            "" => ImageTarget::Noop,
+            _ => ImageTarget::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ImageTarget {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ImageTarget {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ImageTarget {
     fn default() -> ImageTarget {
         ImageTarget::Noop
@@ -20557,7 +26767,8 @@ pub struct Header {
 /**
 * The content section name.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Section {
     #[serde(rename = "signup_message")]
     SignupMessage,
@@ -20567,8 +26778,9 @@ pub enum Section {
     UnsubMessage,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Section {
@@ -20578,12 +26790,46 @@ impl std::fmt::Display for Section {
             Section::SignupThankYouTitle => "signup_thank_you_title",
             Section::UnsubMessage => "unsub_message",
             Section::Noop => "",
-            Section::FallthroughString => "*",
+            Section::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Section {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "signup_message" => Section::SignupMessage,
+            "signup_thank_you_title" => Section::SignupThankYouTitle,
+            "unsub_message" => Section::UnsubMessage,
+            "" => Section::Noop,
+            _ => Section::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Section {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Section {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Section {
     fn default() -> Section {
         Section::Noop
@@ -20617,7 +26863,8 @@ pub struct Contents {
 /**
 * A string that identifies the element selector.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Selector {
     #[serde(rename = "body_background")]
     BodyBackground,
@@ -20649,8 +26896,9 @@ pub enum Selector {
     PageOuterWrapper,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Selector {
@@ -20671,12 +26919,57 @@ impl std::fmt::Display for Selector {
             Selector::PageHeader => "page_header",
             Selector::PageOuterWrapper => "page_outer_wrapper",
             Selector::Noop => "",
-            Selector::FallthroughString => "*",
+            Selector::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Selector {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "body_background" => Selector::BodyBackground,
+            "body_link_style" => Selector::BodyLinkStyle,
+            "forms_buttons" => Selector::FormsButtons,
+            "forms_buttons_hovered" => Selector::FormsButtonsHovered,
+            "forms_errors" => Selector::FormsErrors,
+            "forms_field_label" => Selector::FormsFieldLabel,
+            "forms_field_text" => Selector::FormsFieldText,
+            "forms_help_text" => Selector::FormsHelpText,
+            "forms_required" => Selector::FormsRequired,
+            "forms_required_legend" => Selector::FormsRequiredLegend,
+            "monkey_rewards_badge" => Selector::MonkeyRewardsBadge,
+            "page_background" => Selector::PageBackground,
+            "page_header" => Selector::PageHeader,
+            "page_outer_wrapper" => Selector::PageOuterWrapper,
+            "" => Selector::Noop,
+            _ => Selector::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Selector {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Selector {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Selector {
     fn default() -> Selector {
         Selector::Noop
@@ -20929,7 +27222,8 @@ pub struct ListLocations {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetAllLandingPagesSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -20937,8 +27231,9 @@ pub enum GetAllLandingPagesSortField {
     UpdatedAt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetAllLandingPagesSortField {
@@ -20947,12 +27242,45 @@ impl std::fmt::Display for GetAllLandingPagesSortField {
             GetAllLandingPagesSortField::CreatedAt => "created_at",
             GetAllLandingPagesSortField::UpdatedAt => "updated_at",
             GetAllLandingPagesSortField::Noop => "",
-            GetAllLandingPagesSortField::FallthroughString => "*",
+            GetAllLandingPagesSortField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetAllLandingPagesSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "created_at" => GetAllLandingPagesSortField::CreatedAt,
+            "updated_at" => GetAllLandingPagesSortField::UpdatedAt,
+            "" => GetAllLandingPagesSortField::Noop,
+            _ => GetAllLandingPagesSortField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetAllLandingPagesSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAllLandingPagesSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetAllLandingPagesSortField {
     fn default() -> GetAllLandingPagesSortField {
         GetAllLandingPagesSortField::Noop
@@ -20967,7 +27295,8 @@ impl GetAllLandingPagesSortField {
 /**
 * The status of this landing page.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LandingPageStatus {
     #[serde(rename = "draft")]
     Draft,
@@ -20977,8 +27306,9 @@ pub enum LandingPageStatus {
     Unpublished,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LandingPageStatus {
@@ -20988,12 +27318,46 @@ impl std::fmt::Display for LandingPageStatus {
             LandingPageStatus::Published => "published",
             LandingPageStatus::Unpublished => "unpublished",
             LandingPageStatus::Noop => "",
-            LandingPageStatus::FallthroughString => "*",
+            LandingPageStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LandingPageStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "draft" => LandingPageStatus::Draft,
+            "published" => LandingPageStatus::Published,
+            "unpublished" => LandingPageStatus::Unpublished,
+            "" => LandingPageStatus::Noop,
+            _ => LandingPageStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LandingPageStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LandingPageStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LandingPageStatus {
     fn default() -> LandingPageStatus {
         LandingPageStatus::Noop
@@ -21213,7 +27577,8 @@ pub struct GetAllLandingPagesResponse {
 /**
 * The type of template the landing page has.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LandingPageTemplateType {
     #[serde(rename = "product")]
     Product,
@@ -21221,8 +27586,9 @@ pub enum LandingPageTemplateType {
     Signup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LandingPageTemplateType {
@@ -21231,12 +27597,45 @@ impl std::fmt::Display for LandingPageTemplateType {
             LandingPageTemplateType::Product => "product",
             LandingPageTemplateType::Signup => "signup",
             LandingPageTemplateType::Noop => "",
-            LandingPageTemplateType::FallthroughString => "*",
+            LandingPageTemplateType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LandingPageTemplateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "product" => LandingPageTemplateType::Product,
+            "signup" => LandingPageTemplateType::Signup,
+            "" => LandingPageTemplateType::Noop,
+            _ => LandingPageTemplateType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LandingPageTemplateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LandingPageTemplateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LandingPageTemplateType {
     fn default() -> LandingPageTemplateType {
         LandingPageTemplateType::Noop
@@ -22433,7 +28832,8 @@ pub struct AbuseComplaintsData {
 /**
 * The sentiment type for a feedback message.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AdviceType {
     #[serde(rename = "negative")]
     Negative,
@@ -22443,8 +28843,9 @@ pub enum AdviceType {
     Positive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AdviceType {
@@ -22454,12 +28855,46 @@ impl std::fmt::Display for AdviceType {
             AdviceType::Neutral => "neutral",
             AdviceType::Positive => "positive",
             AdviceType::Noop => "",
-            AdviceType::FallthroughString => "*",
+            AdviceType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AdviceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "negative" => AdviceType::Negative,
+            "neutral" => AdviceType::Neutral,
+            "positive" => AdviceType::Positive,
+            "" => AdviceType::Noop,
+            _ => AdviceType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AdviceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AdviceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AdviceType {
     fn default() -> AdviceType {
         AdviceType::Noop
@@ -24086,7 +30521,8 @@ pub struct UnsubscribesData {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetReportsEcommerceProductActivitySortField {
     #[serde(rename = "title")]
     Title,
@@ -24096,8 +30532,9 @@ pub enum GetReportsEcommerceProductActivitySortField {
     TotalRevenue,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetReportsEcommerceProductActivitySortField {
@@ -24107,12 +30544,46 @@ impl std::fmt::Display for GetReportsEcommerceProductActivitySortField {
             GetReportsEcommerceProductActivitySortField::TotalPurchased => "total_purchased",
             GetReportsEcommerceProductActivitySortField::TotalRevenue => "total_revenue",
             GetReportsEcommerceProductActivitySortField::Noop => "",
-            GetReportsEcommerceProductActivitySortField::FallthroughString => "*",
+            GetReportsEcommerceProductActivitySortField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetReportsEcommerceProductActivitySortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "title" => GetReportsEcommerceProductActivitySortField::Title,
+            "total_purchased" => GetReportsEcommerceProductActivitySortField::TotalPurchased,
+            "total_revenue" => GetReportsEcommerceProductActivitySortField::TotalRevenue,
+            "" => GetReportsEcommerceProductActivitySortField::Noop,
+            _ => GetReportsEcommerceProductActivitySortField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetReportsEcommerceProductActivitySortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetReportsEcommerceProductActivitySortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetReportsEcommerceProductActivitySortField {
     fn default() -> GetReportsEcommerceProductActivitySortField {
         GetReportsEcommerceProductActivitySortField::Noop
@@ -24236,7 +30707,8 @@ pub struct GetReportsEcommerceProductActivityResponse {
 /**
 * Returns user templates sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetTemplatesSortField {
     #[serde(rename = "date_created")]
     DateCreated,
@@ -24246,8 +30718,9 @@ pub enum GetTemplatesSortField {
     Name,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetTemplatesSortField {
@@ -24257,12 +30730,46 @@ impl std::fmt::Display for GetTemplatesSortField {
             GetTemplatesSortField::DateEdited => "date_edited",
             GetTemplatesSortField::Name => "name",
             GetTemplatesSortField::Noop => "",
-            GetTemplatesSortField::FallthroughString => "*",
+            GetTemplatesSortField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetTemplatesSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "date_created" => GetTemplatesSortField::DateCreated,
+            "date_edited" => GetTemplatesSortField::DateEdited,
+            "name" => GetTemplatesSortField::Name,
+            "" => GetTemplatesSortField::Noop,
+            _ => GetTemplatesSortField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetTemplatesSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetTemplatesSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetTemplatesSortField {
     fn default() -> GetTemplatesSortField {
         GetTemplatesSortField::Noop
@@ -24657,14 +31164,16 @@ pub struct Customer {
 /**
 * The Mailchimp tracking code for the order. Uses the 'mc_tc' parameter in E-Commerce tracking URLs.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TrackingCode {
     #[serde(rename = "prec")]
     Prec,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TrackingCode {
@@ -24672,12 +31181,44 @@ impl std::fmt::Display for TrackingCode {
         match self {
             TrackingCode::Prec => "prec",
             TrackingCode::Noop => "",
-            TrackingCode::FallthroughString => "*",
+            TrackingCode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TrackingCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "prec" => TrackingCode::Prec,
+            "" => TrackingCode::Noop,
+            _ => TrackingCode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TrackingCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TrackingCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TrackingCode {
     fn default() -> TrackingCode {
         TrackingCode::Noop
@@ -24940,7 +31481,8 @@ pub struct BillingAddress {
 /**
 * Type of discount. For free shipping set type to fixed
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrdersPromosType {
     #[serde(rename = "fixed")]
     Fixed,
@@ -24948,8 +31490,9 @@ pub enum OrdersPromosType {
     Percentage,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrdersPromosType {
@@ -24958,12 +31501,45 @@ impl std::fmt::Display for OrdersPromosType {
             OrdersPromosType::Fixed => "fixed",
             OrdersPromosType::Percentage => "percentage",
             OrdersPromosType::Noop => "",
-            OrdersPromosType::FallthroughString => "*",
+            OrdersPromosType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrdersPromosType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "fixed" => OrdersPromosType::Fixed,
+            "percentage" => OrdersPromosType::Percentage,
+            "" => OrdersPromosType::Noop,
+            _ => OrdersPromosType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrdersPromosType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrdersPromosType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrdersPromosType {
     fn default() -> OrdersPromosType {
         OrdersPromosType::Noop
@@ -26646,7 +33222,8 @@ pub struct ECommerceCustomerDataType {
 /**
 * The target that the discount applies to.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Target {
     #[serde(rename = "per_item")]
     PerItem,
@@ -26656,8 +33233,9 @@ pub enum Target {
     Total,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Target {
@@ -26667,12 +33245,46 @@ impl std::fmt::Display for Target {
             Target::Shipping => "shipping",
             Target::Total => "total",
             Target::Noop => "",
-            Target::FallthroughString => "*",
+            Target::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Target {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "per_item" => Target::PerItem,
+            "shipping" => Target::Shipping,
+            "total" => Target::Total,
+            "" => Target::Noop,
+            _ => Target::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Target {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Target {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Target {
     fn default() -> Target {
         Target::Noop
@@ -28751,7 +35363,8 @@ pub struct ApiHealthStatus {
 /**
 * Returns files sorted by the specified field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetAllFacebookAdsSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -28761,8 +35374,9 @@ pub enum GetAllFacebookAdsSortField {
     UpdatedAt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetAllFacebookAdsSortField {
@@ -28772,12 +35386,46 @@ impl std::fmt::Display for GetAllFacebookAdsSortField {
             GetAllFacebookAdsSortField::EndTime => "end_time",
             GetAllFacebookAdsSortField::UpdatedAt => "updated_at",
             GetAllFacebookAdsSortField::Noop => "",
-            GetAllFacebookAdsSortField::FallthroughString => "*",
+            GetAllFacebookAdsSortField::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetAllFacebookAdsSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "created_at" => GetAllFacebookAdsSortField::CreatedAt,
+            "end_time" => GetAllFacebookAdsSortField::EndTime,
+            "updated_at" => GetAllFacebookAdsSortField::UpdatedAt,
+            "" => GetAllFacebookAdsSortField::Noop,
+            _ => GetAllFacebookAdsSortField::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetAllFacebookAdsSortField {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAllFacebookAdsSortField {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetAllFacebookAdsSortField {
     fn default() -> GetAllFacebookAdsSortField {
         GetAllFacebookAdsSortField::Noop
@@ -28792,7 +35440,8 @@ impl GetAllFacebookAdsSortField {
 /**
 * Supported Campaign, Ad, Page type
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OutreachType {
     #[serde(rename = "absplit")]
     Absplit,
@@ -28824,8 +35473,9 @@ pub enum OutreachType {
     Website,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OutreachType {
@@ -28846,12 +35496,57 @@ impl std::fmt::Display for OutreachType {
             OutreachType::Variate => "variate",
             OutreachType::Website => "website",
             OutreachType::Noop => "",
-            OutreachType::FallthroughString => "*",
+            OutreachType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OutreachType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "absplit" => OutreachType::Absplit,
+            "automation" => OutreachType::Automation,
+            "autoresponder" => OutreachType::Autoresponder,
+            "facebook" => OutreachType::Facebook,
+            "google" => OutreachType::Google,
+            "page" => OutreachType::Page,
+            "plaintext" => OutreachType::Plaintext,
+            "reconfirm" => OutreachType::Reconfirm,
+            "regular" => OutreachType::Regular,
+            "rss" => OutreachType::Rss,
+            "survey" => OutreachType::Survey,
+            "transactional" => OutreachType::Transactional,
+            "variate" => OutreachType::Variate,
+            "website" => OutreachType::Website,
+            "" => OutreachType::Noop,
+            _ => OutreachType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OutreachType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OutreachType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OutreachType {
     fn default() -> OutreachType {
         OutreachType::Noop
@@ -28866,7 +35561,8 @@ impl OutreachType {
 /**
 * Campaign, Ad, or Page status
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OutreachStatus {
     #[serde(rename = "active")]
     Active,
@@ -28904,8 +35600,9 @@ pub enum OutreachStatus {
     Unpublished,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OutreachStatus {
@@ -28929,12 +35626,60 @@ impl std::fmt::Display for OutreachStatus {
             OutreachStatus::Somepaused => "somepaused",
             OutreachStatus::Unpublished => "unpublished",
             OutreachStatus::Noop => "",
-            OutreachStatus::FallthroughString => "*",
+            OutreachStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OutreachStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => OutreachStatus::Active,
+            "canceled" => OutreachStatus::Canceled,
+            "canceling" => OutreachStatus::Canceling,
+            "completed" => OutreachStatus::Completed,
+            "disconnected" => OutreachStatus::Disconnected,
+            "draft" => OutreachStatus::Draft,
+            "partialRejected" => OutreachStatus::PartialRejected,
+            "paused" => OutreachStatus::Paused,
+            "pending" => OutreachStatus::Pending,
+            "published" => OutreachStatus::Published,
+            "rejected" => OutreachStatus::Rejected,
+            "save" => OutreachStatus::Save,
+            "schedule" => OutreachStatus::Schedule,
+            "sending" => OutreachStatus::Sending,
+            "sent" => OutreachStatus::Sent,
+            "somepaused" => OutreachStatus::Somepaused,
+            "unpublished" => OutreachStatus::Unpublished,
+            "" => OutreachStatus::Noop,
+            _ => OutreachStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OutreachStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OutreachStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OutreachStatus {
     fn default() -> OutreachStatus {
         OutreachStatus::Noop
@@ -29433,7 +36178,8 @@ pub struct Site {
 /**
 * Type of the audience
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FacebookAdsAudienceType {
     #[serde(rename = "Custom Audience")]
     CustomAudience,
@@ -29443,8 +36189,9 @@ pub enum FacebookAdsAudienceType {
     LookalikeAudience,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FacebookAdsAudienceType {
@@ -29454,12 +36201,46 @@ impl std::fmt::Display for FacebookAdsAudienceType {
             FacebookAdsAudienceType::InterestBasedAudience => "Interest-based Audience",
             FacebookAdsAudienceType::LookalikeAudience => "Lookalike Audience",
             FacebookAdsAudienceType::Noop => "",
-            FacebookAdsAudienceType::FallthroughString => "*",
+            FacebookAdsAudienceType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FacebookAdsAudienceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Custom Audience" => FacebookAdsAudienceType::CustomAudience,
+            "Interest-based Audience" => FacebookAdsAudienceType::InterestBasedAudience,
+            "Lookalike Audience" => FacebookAdsAudienceType::LookalikeAudience,
+            "" => FacebookAdsAudienceType::Noop,
+            _ => FacebookAdsAudienceType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FacebookAdsAudienceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FacebookAdsAudienceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FacebookAdsAudienceType {
     fn default() -> FacebookAdsAudienceType {
         FacebookAdsAudienceType::Noop
@@ -29474,7 +36255,8 @@ impl FacebookAdsAudienceType {
 /**
 * List or Facebook based audience
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SourceType {
     #[serde(rename = "facebook")]
     Facebook,
@@ -29482,8 +36264,9 @@ pub enum SourceType {
     List,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SourceType {
@@ -29492,12 +36275,45 @@ impl std::fmt::Display for SourceType {
             SourceType::Facebook => "facebook",
             SourceType::List => "list",
             SourceType::Noop => "",
-            SourceType::FallthroughString => "*",
+            SourceType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SourceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "facebook" => SourceType::Facebook,
+            "list" => SourceType::List,
+            "" => SourceType::Noop,
+            _ => SourceType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SourceType {
     fn default() -> SourceType {
         SourceType::Noop
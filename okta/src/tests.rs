@@ -1 +1,224 @@
+#[test]
+fn test_rate_limit_from_headers() {
+    let reset = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 30;
 
+    let mut headers = http::header::HeaderMap::new();
+    headers.insert("x-rate-limit-limit", "600".parse().unwrap());
+    headers.insert("x-rate-limit-remaining", "0".parse().unwrap());
+    headers.insert("x-rate-limit-reset", reset.to_string().parse().unwrap());
+
+    let rate_limit = crate::utils::RateLimitStatus::from_headers(&headers);
+    assert_eq!(rate_limit.limit, Some(600));
+    assert_eq!(rate_limit.remaining, Some(0));
+    assert_eq!(
+        rate_limit.reset,
+        Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(reset))
+    );
+
+    let wait = rate_limit.wait_time().unwrap();
+    assert!(wait <= std::time::Duration::from_secs(30));
+    assert!(wait >= std::time::Duration::from_secs(29));
+
+    headers.insert("x-rate-limit-remaining", "599".parse().unwrap());
+    let rate_limit = crate::utils::RateLimitStatus::from_headers(&headers);
+    assert_eq!(rate_limit.wait_time(), None);
+
+    assert_eq!(
+        crate::utils::RateLimitStatus::from_headers(&http::header::HeaderMap::new()),
+        crate::utils::RateLimitStatus::default()
+    );
+}
+
+#[tokio::test]
+async fn test_in_memory_rate_limit_store_is_shared() {
+    use crate::utils::RateLimitStore;
+
+    let reset = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 30;
+
+    let store = crate::utils::InMemoryRateLimitStore::default();
+    let other = store.clone();
+    assert_eq!(store.acquire("GET /api/v1/users").await, None);
+
+    store
+        .set(
+            "GET /api/v1/users",
+            crate::utils::RateLimitStatus {
+                limit: Some(600),
+                remaining: Some(1),
+                reset: Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(reset)),
+                retry_after: None,
+            },
+        )
+        .await;
+
+    // The last request in the window is taken by one clone, leaving the other to wait.
+    assert_eq!(store.acquire("GET /api/v1/users").await, None);
+    assert!(other.acquire("GET /api/v1/users").await.is_some());
+    assert_eq!(
+        other.get("GET /api/v1/users").await.unwrap().remaining,
+        Some(0)
+    );
+}
+
+/// Needs a Redis server, at `REDIS_URL` or on localhost.
+#[cfg(feature = "redis")]
+#[tokio::test]
+#[ignore]
+async fn test_redis_rate_limit_store_acquire_is_atomic() {
+    use crate::utils::RateLimitStore;
+
+    let url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let prefix = format!("okta-test-{}", std::process::id());
+    let store =
+        crate::utils::RedisRateLimitStore::new(redis::Client::open(url.as_str()).unwrap(), &prefix)
+            .await
+            .unwrap();
+    let other =
+        crate::utils::RedisRateLimitStore::new(redis::Client::open(url.as_str()).unwrap(), &prefix)
+            .await
+            .unwrap();
+
+    let reset = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 30;
+    store
+        .set(
+            "GET /api/v1/users",
+            crate::utils::RateLimitStatus {
+                limit: Some(600),
+                remaining: Some(1),
+                reset: Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(reset)),
+                retry_after: None,
+            },
+        )
+        .await;
+
+    // Both stores race for the last request in the window, and only one gets it.
+    let (a, b) = futures::join!(
+        store.acquire("GET /api/v1/users"),
+        other.acquire("GET /api/v1/users")
+    );
+    assert!(a.is_none() != b.is_none(), "{:?} {:?}", a, b);
+    assert_eq!(
+        other.get("GET /api/v1/users").await.unwrap().remaining,
+        Some(0)
+    );
+}
+
+#[tokio::test]
+async fn test_new_with_config_and_rate_limit_shares_budget() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let reset = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 30;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut request = Vec::new();
+            let mut chunk = [0; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                match socket.read(&mut chunk).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => request.extend_from_slice(&chunk[..n]),
+                }
+            }
+            let resp = format!(
+                "HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n\
+                 x-rate-limit-limit: 600\r\nx-rate-limit-remaining: 0\r\n\
+                 x-rate-limit-reset: {}\r\n\r\n[]",
+                reset
+            );
+            let _ = socket.write_all(resp.as_bytes()).await;
+        }
+    });
+
+    let rate_limit = crate::utils::RateLimitMiddleware::default();
+    let config = crate::utils::Config {
+        host: Some(host.clone()),
+        ..Default::default()
+    };
+    let okta = crate::Client::new_with_config_and_rate_limit("token", config, rate_limit.clone());
+
+    // Relative paths are sent to the host from the config.
+    okta.request_raw(reqwest::Method::GET, "/api/v1/users", None)
+        .await
+        .unwrap();
+
+    // Clones of the middleware share the rate limit the response reported.
+    let url = format!("{}/api/v1/users/00u1", host)
+        .parse::<reqwest::Url>()
+        .unwrap();
+    let status = rate_limit
+        .rate_limit(&reqwest::Method::GET, &url)
+        .await
+        .unwrap();
+    assert_eq!(status.remaining, Some(0));
+    assert!(status.wait_time().is_some());
+}
+
+#[tokio::test]
+async fn test_pages_follows_absolute_links_on_http_host() {
+    use futures::StreamExt;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let base = host.clone();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut request = Vec::new();
+            let mut chunk = [0; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                match socket.read(&mut chunk).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => request.extend_from_slice(&chunk[..n]),
+                }
+            }
+            // Okta sends absolute next links.
+            let link = if String::from_utf8_lossy(&request).starts_with("GET /api/v1/users ") {
+                format!("link: <{}/api/v1/users?after=2>; rel=\"next\"\r\n", base)
+            } else {
+                String::new()
+            };
+            let resp = format!(
+                "HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n{}\r\n[]",
+                link
+            );
+            let _ = socket.write_all(resp.as_bytes()).await;
+        }
+    });
+
+    let config = crate::utils::Config {
+        host: Some(host.clone()),
+        ..Default::default()
+    };
+    let okta = crate::Client::new_with_config("token", config);
+
+    let pages: Vec<_> = okta.pages("/api/v1/users").collect().await;
+    let urls: Vec<_> = pages
+        .into_iter()
+        .map(|page| page.unwrap().url().to_string())
+        .collect();
+    assert_eq!(
+        urls,
+        vec![
+            format!("{}/api/v1/users", host),
+            format!("{}/api/v1/users?after=2", host),
+        ]
+    );
+}
@@ -56,7 +56,8 @@ pub struct ActivateFactorRequest {
     pub state_token: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Type {
     #[serde(rename = "APP")]
     App,
@@ -64,8 +65,9 @@ pub enum Type {
     AppType,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Type {
@@ -74,12 +76,45 @@ impl std::fmt::Display for Type {
             Type::App => "APP",
             Type::AppType => "APP_TYPE",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "APP" => Type::App,
+            "APP_TYPE" => Type::AppType,
+            "" => Type::Noop,
+            _ => Type::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -314,7 +349,8 @@ pub struct AppUserPasswordCredential {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Status {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -324,8 +360,9 @@ pub enum Status {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Status {
@@ -335,12 +372,46 @@ impl std::fmt::Display for Status {
             Status::Deleted => "DELETED",
             Status::Inactive => "INACTIVE",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => Status::Active,
+            "DELETED" => Status::Deleted,
+            "INACTIVE" => Status::Inactive,
+            "" => Status::Noop,
+            _ => Status::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -477,7 +548,8 @@ pub struct ApplicationCredentialsOAuthClient {
     pub token_endpoint_auth_method: Option<OAuthEndpointAuthenticationMethod>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ApplicationCredentialsScheme {
     #[serde(rename = "ADMIN_SETS_CREDENTIALS")]
     AdminSetsCredentials,
@@ -491,8 +563,9 @@ pub enum ApplicationCredentialsScheme {
     SharedUsernameAndPassword,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ApplicationCredentialsScheme {
@@ -506,12 +579,50 @@ impl std::fmt::Display for ApplicationCredentialsScheme {
                 "SHARED_USERNAME_AND_PASSWORD"
             }
             ApplicationCredentialsScheme::Noop => "",
-            ApplicationCredentialsScheme::FallthroughString => "*",
+            ApplicationCredentialsScheme::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ApplicationCredentialsScheme {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ADMIN_SETS_CREDENTIALS" => ApplicationCredentialsScheme::AdminSetsCredentials,
+            "EDIT_PASSWORD_ONLY" => ApplicationCredentialsScheme::EditPasswordOnly,
+            "EDIT_USERNAME_AND_PASSWORD" => ApplicationCredentialsScheme::EditUsernameAndPassword,
+            "EXTERNAL_PASSWORD_SYNC" => ApplicationCredentialsScheme::ExternalPasswordSync,
+            "SHARED_USERNAME_AND_PASSWORD" => {
+                ApplicationCredentialsScheme::SharedUsernameAndPassword
+            }
+            "" => ApplicationCredentialsScheme::Noop,
+            _ => ApplicationCredentialsScheme::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ApplicationCredentialsScheme {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ApplicationCredentialsScheme {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ApplicationCredentialsScheme {
     fn default() -> ApplicationCredentialsScheme {
         ApplicationCredentialsScheme::Noop
@@ -556,14 +667,16 @@ pub struct ApplicationCredentialsSigning {
     pub use_: Option<Use>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Use {
     #[serde(rename = "sig")]
     Sig,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Use {
@@ -571,12 +684,44 @@ impl std::fmt::Display for Use {
         match self {
             Use::Sig => "sig",
             Use::Noop => "",
-            Use::FallthroughString => "*",
+            Use::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Use {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "sig" => Use::Sig,
+            "" => Use::Noop,
+            _ => Use::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Use {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Use {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Use {
     fn default() -> Use {
         Use::Noop
@@ -719,7 +864,8 @@ pub struct ApplicationSettingsNotificationsVpnNetwork {
     pub include: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ApplicationSignOnMode {
     #[serde(rename = "AUTO_LOGIN")]
     AutoLogin,
@@ -741,8 +887,9 @@ pub enum ApplicationSignOnMode {
     WsFederation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ApplicationSignOnMode {
@@ -758,12 +905,52 @@ impl std::fmt::Display for ApplicationSignOnMode {
             ApplicationSignOnMode::SecurePasswordStore => "SECURE_PASSWORD_STORE",
             ApplicationSignOnMode::WsFederation => "WS_FEDERATION",
             ApplicationSignOnMode::Noop => "",
-            ApplicationSignOnMode::FallthroughString => "*",
+            ApplicationSignOnMode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ApplicationSignOnMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AUTO_LOGIN" => ApplicationSignOnMode::AutoLogin,
+            "BASIC_AUTH" => ApplicationSignOnMode::BasicAuth,
+            "BOOKMARK" => ApplicationSignOnMode::Bookmark,
+            "BROWSER_PLUGIN" => ApplicationSignOnMode::BrowserPlugin,
+            "OPENID_CONNECT" => ApplicationSignOnMode::OpenidConnect,
+            "SAML_1_1" => ApplicationSignOnMode::Saml1,
+            "SAML_2_0" => ApplicationSignOnMode::Saml20,
+            "SECURE_PASSWORD_STORE" => ApplicationSignOnMode::SecurePasswordStore,
+            "WS_FEDERATION" => ApplicationSignOnMode::WsFederation,
+            "" => ApplicationSignOnMode::Noop,
+            _ => ApplicationSignOnMode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ApplicationSignOnMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ApplicationSignOnMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ApplicationSignOnMode {
     fn default() -> ApplicationSignOnMode {
         ApplicationSignOnMode::Noop
@@ -826,7 +1013,8 @@ pub struct AuthenticationProvider {
     pub type_: Option<AuthenticationProviderType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AuthenticationProviderType {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -842,8 +1030,9 @@ pub enum AuthenticationProviderType {
     Social,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AuthenticationProviderType {
@@ -856,12 +1045,49 @@ impl std::fmt::Display for AuthenticationProviderType {
             AuthenticationProviderType::Okta => "OKTA",
             AuthenticationProviderType::Social => "SOCIAL",
             AuthenticationProviderType::Noop => "",
-            AuthenticationProviderType::FallthroughString => "*",
+            AuthenticationProviderType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AuthenticationProviderType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE_DIRECTORY" => AuthenticationProviderType::ActiveDirectory,
+            "FEDERATION" => AuthenticationProviderType::Federation,
+            "IMPORT" => AuthenticationProviderType::Import,
+            "LDAP" => AuthenticationProviderType::Ldap,
+            "OKTA" => AuthenticationProviderType::Okta,
+            "SOCIAL" => AuthenticationProviderType::Social,
+            "" => AuthenticationProviderType::Noop,
+            _ => AuthenticationProviderType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AuthenticationProviderType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthenticationProviderType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AuthenticationProviderType {
     fn default() -> AuthenticationProviderType {
         AuthenticationProviderType::Noop
@@ -873,7 +1099,8 @@ impl AuthenticationProviderType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuerMode {
     #[serde(rename = "CUSTOM_URL")]
     CustomUrl,
@@ -881,8 +1108,9 @@ pub enum IssuerMode {
     OrgUrl,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuerMode {
@@ -891,12 +1119,45 @@ impl std::fmt::Display for IssuerMode {
             IssuerMode::CustomUrl => "CUSTOM_URL",
             IssuerMode::OrgUrl => "ORG_URL",
             IssuerMode::Noop => "",
-            IssuerMode::FallthroughString => "*",
+            IssuerMode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuerMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CUSTOM_URL" => IssuerMode::CustomUrl,
+            "ORG_URL" => IssuerMode::OrgUrl,
+            "" => IssuerMode::Noop,
+            _ => IssuerMode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuerMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuerMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuerMode {
     fn default() -> IssuerMode {
         IssuerMode::Noop
@@ -908,7 +1169,8 @@ impl IssuerMode {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RoleStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -916,8 +1178,9 @@ pub enum RoleStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RoleStatus {
@@ -926,12 +1189,45 @@ impl std::fmt::Display for RoleStatus {
             RoleStatus::Active => "ACTIVE",
             RoleStatus::Inactive => "INACTIVE",
             RoleStatus::Noop => "",
-            RoleStatus::FallthroughString => "*",
+            RoleStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RoleStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => RoleStatus::Active,
+            "INACTIVE" => RoleStatus::Inactive,
+            "" => RoleStatus::Noop,
+            _ => RoleStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RoleStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RoleStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RoleStatus {
     fn default() -> RoleStatus {
         RoleStatus::Noop
@@ -1008,7 +1304,8 @@ pub struct AuthorizationServerCredentials {
     pub signing: Option<AuthorizationServerCredentialsSigningConfig>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AuthorizationServerCredentialsRotationMode {
     #[serde(rename = "AUTO")]
     Auto,
@@ -1016,8 +1313,9 @@ pub enum AuthorizationServerCredentialsRotationMode {
     Manual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AuthorizationServerCredentialsRotationMode {
@@ -1026,12 +1324,45 @@ impl std::fmt::Display for AuthorizationServerCredentialsRotationMode {
             AuthorizationServerCredentialsRotationMode::Auto => "AUTO",
             AuthorizationServerCredentialsRotationMode::Manual => "MANUAL",
             AuthorizationServerCredentialsRotationMode::Noop => "",
-            AuthorizationServerCredentialsRotationMode::FallthroughString => "*",
+            AuthorizationServerCredentialsRotationMode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AuthorizationServerCredentialsRotationMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AUTO" => AuthorizationServerCredentialsRotationMode::Auto,
+            "MANUAL" => AuthorizationServerCredentialsRotationMode::Manual,
+            "" => AuthorizationServerCredentialsRotationMode::Noop,
+            _ => AuthorizationServerCredentialsRotationMode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AuthorizationServerCredentialsRotationMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthorizationServerCredentialsRotationMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AuthorizationServerCredentialsRotationMode {
     fn default() -> AuthorizationServerCredentialsRotationMode {
         AuthorizationServerCredentialsRotationMode::Noop
@@ -1131,14 +1462,16 @@ pub struct AuthorizationServerPolicy {
     pub type_: Option<PolicyType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AuthorizationServerPolicyRuleType {
     #[serde(rename = "RESOURCE_ACCESS")]
     ResourceAccess,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AuthorizationServerPolicyRuleType {
@@ -1146,12 +1479,44 @@ impl std::fmt::Display for AuthorizationServerPolicyRuleType {
         match self {
             AuthorizationServerPolicyRuleType::ResourceAccess => "RESOURCE_ACCESS",
             AuthorizationServerPolicyRuleType::Noop => "",
-            AuthorizationServerPolicyRuleType::FallthroughString => "*",
+            AuthorizationServerPolicyRuleType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AuthorizationServerPolicyRuleType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "RESOURCE_ACCESS" => AuthorizationServerPolicyRuleType::ResourceAccess,
+            "" => AuthorizationServerPolicyRuleType::Noop,
+            _ => AuthorizationServerPolicyRuleType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AuthorizationServerPolicyRuleType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthorizationServerPolicyRuleType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AuthorizationServerPolicyRuleType {
     fn default() -> AuthorizationServerPolicyRuleType {
         AuthorizationServerPolicyRuleType::Noop
@@ -1655,7 +2020,8 @@ pub struct DnsRecord {
     pub values: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DnsRecordType {
     #[serde(rename = "CNAME")]
     Cname,
@@ -1663,8 +2029,9 @@ pub enum DnsRecordType {
     Txt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DnsRecordType {
@@ -1673,12 +2040,45 @@ impl std::fmt::Display for DnsRecordType {
             DnsRecordType::Cname => "CNAME",
             DnsRecordType::Txt => "TXT",
             DnsRecordType::Noop => "",
-            DnsRecordType::FallthroughString => "*",
+            DnsRecordType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DnsRecordType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CNAME" => DnsRecordType::Cname,
+            "TXT" => DnsRecordType::Txt,
+            "" => DnsRecordType::Noop,
+            _ => DnsRecordType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DnsRecordType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DnsRecordType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DnsRecordType {
     fn default() -> DnsRecordType {
         DnsRecordType::Noop
@@ -1690,7 +2090,8 @@ impl DnsRecordType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TrustLevel {
     #[serde(rename = "ANY")]
     Any,
@@ -1698,8 +2099,9 @@ pub enum TrustLevel {
     Trusted,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TrustLevel {
@@ -1708,12 +2110,45 @@ impl std::fmt::Display for TrustLevel {
             TrustLevel::Any => "ANY",
             TrustLevel::Trusted => "TRUSTED",
             TrustLevel::Noop => "",
-            TrustLevel::FallthroughString => "*",
+            TrustLevel::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TrustLevel {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANY" => TrustLevel::Any,
+            "TRUSTED" => TrustLevel::Trusted,
+            "" => TrustLevel::Noop,
+            _ => TrustLevel::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TrustLevel {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TrustLevel {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TrustLevel {
     fn default() -> TrustLevel {
         TrustLevel::Noop
@@ -1747,7 +2182,8 @@ pub struct DevicePolicyRuleCondition {
     pub trust_level: Option<TrustLevel>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SupportedMdmFrameworks {
     #[serde(rename = "AFW")]
     Afw,
@@ -1757,8 +2193,9 @@ pub enum SupportedMdmFrameworks {
     Safe,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SupportedMdmFrameworks {
@@ -1768,12 +2205,46 @@ impl std::fmt::Display for SupportedMdmFrameworks {
             SupportedMdmFrameworks::Native => "NATIVE",
             SupportedMdmFrameworks::Safe => "SAFE",
             SupportedMdmFrameworks::Noop => "",
-            SupportedMdmFrameworks::FallthroughString => "*",
+            SupportedMdmFrameworks::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SupportedMdmFrameworks {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AFW" => SupportedMdmFrameworks::Afw,
+            "NATIVE" => SupportedMdmFrameworks::Native,
+            "SAFE" => SupportedMdmFrameworks::Safe,
+            "" => SupportedMdmFrameworks::Noop,
+            _ => SupportedMdmFrameworks::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SupportedMdmFrameworks {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SupportedMdmFrameworks {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SupportedMdmFrameworks {
     fn default() -> SupportedMdmFrameworks {
         SupportedMdmFrameworks::Noop
@@ -1785,7 +2256,8 @@ impl SupportedMdmFrameworks {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Types {
     #[serde(rename = "ANDROID")]
     Android,
@@ -1797,8 +2269,9 @@ pub enum Types {
     Windows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Types {
@@ -1809,12 +2282,47 @@ impl std::fmt::Display for Types {
             Types::Osx => "OSX",
             Types::Windows => "WINDOWS",
             Types::Noop => "",
-            Types::FallthroughString => "*",
+            Types::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Types {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANDROID" => Types::Android,
+            "IOS" => Types::Ios,
+            "OSX" => Types::Osx,
+            "WINDOWS" => Types::Windows,
+            "" => Types::Noop,
+            _ => Types::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Types {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Types {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Types {
     fn default() -> Types {
         Types::Noop
@@ -1932,14 +2440,16 @@ pub struct DomainCertificateMetadata {
     pub subject: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DomainCertificateSourceType {
     #[serde(rename = "MANUAL")]
     Manual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DomainCertificateSourceType {
@@ -1947,12 +2457,44 @@ impl std::fmt::Display for DomainCertificateSourceType {
         match self {
             DomainCertificateSourceType::Manual => "MANUAL",
             DomainCertificateSourceType::Noop => "",
-            DomainCertificateSourceType::FallthroughString => "*",
+            DomainCertificateSourceType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DomainCertificateSourceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "MANUAL" => DomainCertificateSourceType::Manual,
+            "" => DomainCertificateSourceType::Noop,
+            _ => DomainCertificateSourceType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DomainCertificateSourceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DomainCertificateSourceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DomainCertificateSourceType {
     fn default() -> DomainCertificateSourceType {
         DomainCertificateSourceType::Noop
@@ -1964,14 +2506,16 @@ impl DomainCertificateSourceType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DomainCertificateType {
     #[serde(rename = "PEM")]
     Pem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DomainCertificateType {
@@ -1979,12 +2523,44 @@ impl std::fmt::Display for DomainCertificateType {
         match self {
             DomainCertificateType::Pem => "PEM",
             DomainCertificateType::Noop => "",
-            DomainCertificateType::FallthroughString => "*",
+            DomainCertificateType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DomainCertificateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "PEM" => DomainCertificateType::Pem,
+            "" => DomainCertificateType::Noop,
+            _ => DomainCertificateType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DomainCertificateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DomainCertificateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DomainCertificateType {
     fn default() -> DomainCertificateType {
         DomainCertificateType::Noop
@@ -2006,7 +2582,8 @@ pub struct DomainListResponse {
     pub domains: Vec<Domain>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DomainValidationStatus {
     #[serde(rename = "COMPLETED")]
     Completed,
@@ -2018,8 +2595,9 @@ pub enum DomainValidationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DomainValidationStatus {
@@ -2030,12 +2608,47 @@ impl std::fmt::Display for DomainValidationStatus {
             DomainValidationStatus::NotStarted => "NOT_STARTED",
             DomainValidationStatus::Verified => "VERIFIED",
             DomainValidationStatus::Noop => "",
-            DomainValidationStatus::FallthroughString => "*",
+            DomainValidationStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DomainValidationStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COMPLETED" => DomainValidationStatus::Completed,
+            "IN_PROGRESS" => DomainValidationStatus::InProgress,
+            "NOT_STARTED" => DomainValidationStatus::NotStarted,
+            "VERIFIED" => DomainValidationStatus::Verified,
+            "" => DomainValidationStatus::Noop,
+            _ => DomainValidationStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DomainValidationStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DomainValidationStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DomainValidationStatus {
     fn default() -> DomainValidationStatus {
         DomainValidationStatus::Noop
@@ -2079,7 +2692,8 @@ pub struct EmailUserFactorProfile {
     pub email: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EnabledStatus {
     #[serde(rename = "DISABLED")]
     Disabled,
@@ -2087,8 +2701,9 @@ pub enum EnabledStatus {
     Enabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EnabledStatus {
@@ -2097,12 +2712,45 @@ impl std::fmt::Display for EnabledStatus {
             EnabledStatus::Disabled => "DISABLED",
             EnabledStatus::Enabled => "ENABLED",
             EnabledStatus::Noop => "",
-            EnabledStatus::FallthroughString => "*",
+            EnabledStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EnabledStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DISABLED" => EnabledStatus::Disabled,
+            "ENABLED" => EnabledStatus::Enabled,
+            "" => EnabledStatus::Noop,
+            _ => EnabledStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EnabledStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EnabledStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EnabledStatus {
     fn default() -> EnabledStatus {
         EnabledStatus::Noop
@@ -2114,7 +2762,8 @@ impl EnabledStatus {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum VerificationStatus {
     #[serde(rename = "UNVERIFIED")]
     Unverified,
@@ -2122,8 +2771,9 @@ pub enum VerificationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for VerificationStatus {
@@ -2132,12 +2782,45 @@ impl std::fmt::Display for VerificationStatus {
             VerificationStatus::Unverified => "UNVERIFIED",
             VerificationStatus::Verified => "VERIFIED",
             VerificationStatus::Noop => "",
-            VerificationStatus::FallthroughString => "*",
+            VerificationStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for VerificationStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "UNVERIFIED" => VerificationStatus::Unverified,
+            "VERIFIED" => VerificationStatus::Verified,
+            "" => VerificationStatus::Noop,
+            _ => VerificationStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for VerificationStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for VerificationStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for VerificationStatus {
     fn default() -> VerificationStatus {
         VerificationStatus::Noop
@@ -2199,14 +2882,16 @@ pub struct EventHook {
     pub verification_status: Option<VerificationStatus>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EventHookChannelType {
     #[serde(rename = "HTTP")]
     Http,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EventHookChannelType {
@@ -2214,12 +2899,44 @@ impl std::fmt::Display for EventHookChannelType {
         match self {
             EventHookChannelType::Http => "HTTP",
             EventHookChannelType::Noop => "",
-            EventHookChannelType::FallthroughString => "*",
+            EventHookChannelType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EventHookChannelType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "HTTP" => EventHookChannelType::Http,
+            "" => EventHookChannelType::Noop,
+            _ => EventHookChannelType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EventHookChannelType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EventHookChannelType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EventHookChannelType {
     fn default() -> EventHookChannelType {
         EventHookChannelType::Noop
@@ -2285,14 +3002,16 @@ pub struct EventHookChannelConfigAuthScheme {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EventHookChannelConfigAuthSchemeType {
     #[serde(rename = "HEADER")]
     Header,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EventHookChannelConfigAuthSchemeType {
@@ -2300,12 +3019,44 @@ impl std::fmt::Display for EventHookChannelConfigAuthSchemeType {
         match self {
             EventHookChannelConfigAuthSchemeType::Header => "HEADER",
             EventHookChannelConfigAuthSchemeType::Noop => "",
-            EventHookChannelConfigAuthSchemeType::FallthroughString => "*",
+            EventHookChannelConfigAuthSchemeType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EventHookChannelConfigAuthSchemeType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "HEADER" => EventHookChannelConfigAuthSchemeType::Header,
+            "" => EventHookChannelConfigAuthSchemeType::Noop,
+            _ => EventHookChannelConfigAuthSchemeType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EventHookChannelConfigAuthSchemeType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EventHookChannelConfigAuthSchemeType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EventHookChannelConfigAuthSchemeType {
     fn default() -> EventHookChannelConfigAuthSchemeType {
         EventHookChannelConfigAuthSchemeType::Noop
@@ -2333,7 +3084,8 @@ pub struct EventHookChannelConfigHeader {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EventSubscriptionsType {
     #[serde(rename = "EVENT_TYPE")]
     EventType,
@@ -2341,8 +3093,9 @@ pub enum EventSubscriptionsType {
     FlowEvent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EventSubscriptionsType {
@@ -2351,12 +3104,45 @@ impl std::fmt::Display for EventSubscriptionsType {
             EventSubscriptionsType::EventType => "EVENT_TYPE",
             EventSubscriptionsType::FlowEvent => "FLOW_EVENT",
             EventSubscriptionsType::Noop => "",
-            EventSubscriptionsType::FallthroughString => "*",
+            EventSubscriptionsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EventSubscriptionsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EVENT_TYPE" => EventSubscriptionsType::EventType,
+            "FLOW_EVENT" => EventSubscriptionsType::FlowEvent,
+            "" => EventSubscriptionsType::Noop,
+            _ => EventSubscriptionsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EventSubscriptionsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EventSubscriptionsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EventSubscriptionsType {
     fn default() -> EventSubscriptionsType {
         EventSubscriptionsType::Noop
@@ -2380,7 +3166,8 @@ pub struct EventSubscriptions {
     pub type_: Option<EventSubscriptionsType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FactorProvider {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -2400,8 +3187,9 @@ pub enum FactorProvider {
     Yubico,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FactorProvider {
@@ -2416,12 +3204,51 @@ impl std::fmt::Display for FactorProvider {
             FactorProvider::Symantec => "SYMANTEC",
             FactorProvider::Yubico => "YUBICO",
             FactorProvider::Noop => "",
-            FactorProvider::FallthroughString => "*",
+            FactorProvider::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FactorProvider {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CUSTOM" => FactorProvider::Custom,
+            "DUO" => FactorProvider::Duo,
+            "FIDO" => FactorProvider::Fido,
+            "GOOGLE" => FactorProvider::Google,
+            "OKTA" => FactorProvider::Okta,
+            "RSA" => FactorProvider::Rsa,
+            "SYMANTEC" => FactorProvider::Symantec,
+            "YUBICO" => FactorProvider::Yubico,
+            "" => FactorProvider::Noop,
+            _ => FactorProvider::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FactorProvider {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FactorProvider {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FactorProvider {
     fn default() -> FactorProvider {
         FactorProvider::Noop
@@ -2433,7 +3260,8 @@ impl FactorProvider {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FactorResultType {
     #[serde(rename = "CANCELLED")]
     Cancelled,
@@ -2457,8 +3285,9 @@ pub enum FactorResultType {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FactorResultType {
@@ -2475,13 +3304,54 @@ impl std::fmt::Display for FactorResultType {
             FactorResultType::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
             FactorResultType::Waiting => "WAITING",
             FactorResultType::Noop => "",
-            FactorResultType::FallthroughString => "*",
+            FactorResultType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
-impl Default for FactorResultType {
+impl std::str::FromStr for FactorResultType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CANCELLED" => FactorResultType::Cancelled,
+            "CHALLENGE" => FactorResultType::Challenge,
+            "ERROR" => FactorResultType::Error,
+            "FAILED" => FactorResultType::Failed,
+            "PASSCODE_REPLAYED" => FactorResultType::PasscodeReplayed,
+            "REJECTED" => FactorResultType::Rejected,
+            "SUCCESS" => FactorResultType::Success,
+            "TIMEOUT" => FactorResultType::Timeout,
+            "TIME_WINDOW_EXCEEDED" => FactorResultType::TimeWindowExceeded,
+            "WAITING" => FactorResultType::Waiting,
+            "" => FactorResultType::Noop,
+            _ => FactorResultType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FactorResultType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FactorResultType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl Default for FactorResultType {
     fn default() -> FactorResultType {
         FactorResultType::Noop
     }
@@ -2492,7 +3362,8 @@ impl FactorResultType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FactorStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -2510,8 +3381,9 @@ pub enum FactorStatus {
     PendingActivation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FactorStatus {
@@ -2525,12 +3397,50 @@ impl std::fmt::Display for FactorStatus {
             FactorStatus::NotSetup => "NOT_SETUP",
             FactorStatus::PendingActivation => "PENDING_ACTIVATION",
             FactorStatus::Noop => "",
-            FactorStatus::FallthroughString => "*",
+            FactorStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FactorStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => FactorStatus::Active,
+            "DISABLED" => FactorStatus::Disabled,
+            "ENROLLED" => FactorStatus::Enrolled,
+            "EXPIRED" => FactorStatus::Expired,
+            "INACTIVE" => FactorStatus::Inactive,
+            "NOT_SETUP" => FactorStatus::NotSetup,
+            "PENDING_ACTIVATION" => FactorStatus::PendingActivation,
+            "" => FactorStatus::Noop,
+            _ => FactorStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FactorStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FactorStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FactorStatus {
     fn default() -> FactorStatus {
         FactorStatus::Noop
@@ -2542,7 +3452,8 @@ impl FactorStatus {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FactorType {
     #[serde(rename = "call")]
     Call,
@@ -2572,8 +3483,9 @@ pub enum FactorType {
     Webauthn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FactorType {
@@ -2593,12 +3505,56 @@ impl std::fmt::Display for FactorType {
             FactorType::Web => "web",
             FactorType::Webauthn => "webauthn",
             FactorType::Noop => "",
-            FactorType::FallthroughString => "*",
+            FactorType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FactorType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "call" => FactorType::Call,
+            "email" => FactorType::Email,
+            "hotp" => FactorType::Hotp,
+            "push" => FactorType::Push,
+            "question" => FactorType::Question,
+            "sms" => FactorType::Sms,
+            "token" => FactorType::Token,
+            "token:hardware" => FactorType::TokenHardware,
+            "token:hotp" => FactorType::TokenHotp,
+            "token:software:totp" => FactorType::TokenSoftwareTotp,
+            "u2f" => FactorType::U2F,
+            "web" => FactorType::Web,
+            "webauthn" => FactorType::Webauthn,
+            "" => FactorType::Noop,
+            _ => FactorType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FactorType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FactorType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FactorType {
     fn default() -> FactorType {
         FactorType::Noop
@@ -2648,7 +3604,8 @@ pub struct FeatureStage {
     pub value: Option<FeatureStageValue>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FeatureStageState {
     #[serde(rename = "CLOSED")]
     Closed,
@@ -2656,8 +3613,9 @@ pub enum FeatureStageState {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FeatureStageState {
@@ -2666,12 +3624,45 @@ impl std::fmt::Display for FeatureStageState {
             FeatureStageState::Closed => "CLOSED",
             FeatureStageState::Open => "OPEN",
             FeatureStageState::Noop => "",
-            FeatureStageState::FallthroughString => "*",
+            FeatureStageState::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FeatureStageState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CLOSED" => FeatureStageState::Closed,
+            "OPEN" => FeatureStageState::Open,
+            "" => FeatureStageState::Noop,
+            _ => FeatureStageState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FeatureStageState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FeatureStageState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FeatureStageState {
     fn default() -> FeatureStageState {
         FeatureStageState::Noop
@@ -2683,7 +3674,8 @@ impl FeatureStageState {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FeatureStageValue {
     #[serde(rename = "BETA")]
     Beta,
@@ -2691,8 +3683,9 @@ pub enum FeatureStageValue {
     Ea,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FeatureStageValue {
@@ -2701,12 +3694,45 @@ impl std::fmt::Display for FeatureStageValue {
             FeatureStageValue::Beta => "BETA",
             FeatureStageValue::Ea => "EA",
             FeatureStageValue::Noop => "",
-            FeatureStageValue::FallthroughString => "*",
+            FeatureStageValue::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FeatureStageValue {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BETA" => FeatureStageValue::Beta,
+            "EA" => FeatureStageValue::Ea,
+            "" => FeatureStageValue::Noop,
+            _ => FeatureStageValue::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FeatureStageValue {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FeatureStageValue {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FeatureStageValue {
     fn default() -> FeatureStageValue {
         FeatureStageValue::Noop
@@ -2718,14 +3744,16 @@ impl FeatureStageValue {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FeatureType {
     #[serde(rename = "self-service")]
     SelfService,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FeatureType {
@@ -2733,12 +3761,44 @@ impl std::fmt::Display for FeatureType {
         match self {
             FeatureType::SelfService => "self-service",
             FeatureType::Noop => "",
-            FeatureType::FallthroughString => "*",
+            FeatureType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FeatureType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "self-service" => FeatureType::SelfService,
+            "" => FeatureType::Noop,
+            _ => FeatureType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FeatureType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FeatureType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FeatureType {
     fn default() -> FeatureType {
         FeatureType::Noop
@@ -2934,7 +3994,8 @@ pub struct GroupRulePeopleCondition {
     pub users: Option<GroupRuleCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GroupRuleStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -2944,8 +4005,9 @@ pub enum GroupRuleStatus {
     Invalid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GroupRuleStatus {
@@ -2955,12 +4017,46 @@ impl std::fmt::Display for GroupRuleStatus {
             GroupRuleStatus::Inactive => "INACTIVE",
             GroupRuleStatus::Invalid => "INVALID",
             GroupRuleStatus::Noop => "",
-            GroupRuleStatus::FallthroughString => "*",
+            GroupRuleStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GroupRuleStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => GroupRuleStatus::Active,
+            "INACTIVE" => GroupRuleStatus::Inactive,
+            "INVALID" => GroupRuleStatus::Invalid,
+            "" => GroupRuleStatus::Noop,
+            _ => GroupRuleStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GroupRuleStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GroupRuleStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GroupRuleStatus {
     fn default() -> GroupRuleStatus {
         GroupRuleStatus::Noop
@@ -2972,7 +4068,8 @@ impl GroupRuleStatus {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GroupType {
     #[serde(rename = "APP_GROUP")]
     AppGroup,
@@ -2982,8 +4079,9 @@ pub enum GroupType {
     OktaGroup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GroupType {
@@ -2993,12 +4091,46 @@ impl std::fmt::Display for GroupType {
             GroupType::BuiltIn => "BUILT_IN",
             GroupType::OktaGroup => "OKTA_GROUP",
             GroupType::Noop => "",
-            GroupType::FallthroughString => "*",
+            GroupType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GroupType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "APP_GROUP" => GroupType::AppGroup,
+            "BUILT_IN" => GroupType::BuiltIn,
+            "OKTA_GROUP" => GroupType::OktaGroup,
+            "" => GroupType::Noop,
+            _ => GroupType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GroupType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GroupType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GroupType {
     fn default() -> GroupType {
         GroupType::Noop
@@ -3027,7 +4159,8 @@ pub struct WebUserFactorProfile {
     pub credential_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IdentityProviderIssuerMode {
     #[serde(rename = "CUSTOM_URL_DOMAIN")]
     CustomUrlDomain,
@@ -3035,8 +4168,9 @@ pub enum IdentityProviderIssuerMode {
     OrgUrl,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IdentityProviderIssuerMode {
@@ -3045,12 +4179,45 @@ impl std::fmt::Display for IdentityProviderIssuerMode {
             IdentityProviderIssuerMode::CustomUrlDomain => "CUSTOM_URL_DOMAIN",
             IdentityProviderIssuerMode::OrgUrl => "ORG_URL",
             IdentityProviderIssuerMode::Noop => "",
-            IdentityProviderIssuerMode::FallthroughString => "*",
+            IdentityProviderIssuerMode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IdentityProviderIssuerMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CUSTOM_URL_DOMAIN" => IdentityProviderIssuerMode::CustomUrlDomain,
+            "ORG_URL" => IdentityProviderIssuerMode::OrgUrl,
+            "" => IdentityProviderIssuerMode::Noop,
+            _ => IdentityProviderIssuerMode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IdentityProviderIssuerMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IdentityProviderIssuerMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IdentityProviderIssuerMode {
     fn default() -> IdentityProviderIssuerMode {
         IdentityProviderIssuerMode::Noop
@@ -3062,7 +4229,8 @@ impl IdentityProviderIssuerMode {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IdentityProviderType {
     #[serde(rename = "AgentlessDSSO")]
     AgentlessDsso,
@@ -3086,8 +4254,9 @@ pub enum IdentityProviderType {
     X509,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IdentityProviderType {
@@ -3104,12 +4273,53 @@ impl std::fmt::Display for IdentityProviderType {
             IdentityProviderType::Saml2 => "SAML2",
             IdentityProviderType::X509 => "X509",
             IdentityProviderType::Noop => "",
-            IdentityProviderType::FallthroughString => "*",
+            IdentityProviderType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IdentityProviderType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AgentlessDSSO" => IdentityProviderType::AgentlessDsso,
+            "FACEBOOK" => IdentityProviderType::Facebook,
+            "GOOGLE" => IdentityProviderType::Google,
+            "IWA" => IdentityProviderType::Iwa,
+            "LINKEDIN" => IdentityProviderType::Linkedin,
+            "MICROSOFT" => IdentityProviderType::Microsoft,
+            "OIDC" => IdentityProviderType::Oidc,
+            "OKTA" => IdentityProviderType::Okta,
+            "SAML2" => IdentityProviderType::Saml2,
+            "X509" => IdentityProviderType::X509,
+            "" => IdentityProviderType::Noop,
+            _ => IdentityProviderType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IdentityProviderType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IdentityProviderType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IdentityProviderType {
     fn default() -> IdentityProviderType {
         IdentityProviderType::Noop
@@ -3238,7 +4448,8 @@ pub struct IdentityProviderCredentialsSigning {
     pub kid: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Revocation {
     #[serde(rename = "CRL")]
     Crl,
@@ -3248,8 +4459,9 @@ pub enum Revocation {
     Ocsp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Revocation {
@@ -3259,12 +4471,46 @@ impl std::fmt::Display for Revocation {
             Revocation::DeltaCrl => "DELTA_CRL",
             Revocation::Ocsp => "OCSP",
             Revocation::Noop => "",
-            Revocation::FallthroughString => "*",
+            Revocation::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Revocation {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CRL" => Revocation::Crl,
+            "DELTA_CRL" => Revocation::DeltaCrl,
+            "OCSP" => Revocation::Ocsp,
+            "" => Revocation::Noop,
+            _ => Revocation::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Revocation {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Revocation {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Revocation {
     fn default() -> Revocation {
         Revocation::Noop
@@ -3328,7 +4574,8 @@ pub struct IdentityProviderPolicy {
     pub subject: Option<PolicySubject>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Provider {
     #[serde(rename = "ANY")]
     Any,
@@ -3338,8 +4585,9 @@ pub enum Provider {
     SpecificIdp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Provider {
@@ -3349,12 +4597,46 @@ impl std::fmt::Display for Provider {
             Provider::Okta => "OKTA",
             Provider::SpecificIdp => "SPECIFIC_IDP",
             Provider::Noop => "",
-            Provider::FallthroughString => "*",
+            Provider::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Provider {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANY" => Provider::Any,
+            "OKTA" => Provider::Okta,
+            "SPECIFIC_IDP" => Provider::SpecificIdp,
+            "" => Provider::Noop,
+            _ => Provider::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Provider {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Provider {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Provider {
     fn default() -> Provider {
         Provider::Noop
@@ -3552,7 +4834,8 @@ pub struct InlineHookResponseCommands {
     pub value: Vec<InlineHookResponseCommandValue>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum InlineHookType {
     #[serde(rename = "com.okta.import.transform")]
     ComOktaImportTransform,
@@ -3566,8 +4849,9 @@ pub enum InlineHookType {
     ComOktaUserPreRegistration,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for InlineHookType {
@@ -3581,12 +4865,50 @@ impl std::fmt::Display for InlineHookType {
             }
             InlineHookType::ComOktaUserPreRegistration => "com.okta.user.pre-registration",
             InlineHookType::Noop => "",
-            InlineHookType::FallthroughString => "*",
+            InlineHookType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for InlineHookType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "com.okta.import.transform" => InlineHookType::ComOktaImportTransform,
+            "com.okta.oauth2.tokens.transform" => InlineHookType::ComOktaOauth2TokensTransform,
+            "com.okta.saml.tokens.transform" => InlineHookType::ComOktaSamlTokensTransform,
+            "com.okta.user.credential.password.import" => {
+                InlineHookType::ComOktaUserCredentialPasswordImport
+            }
+            "com.okta.user.pre-registration" => InlineHookType::ComOktaUserPreRegistration,
+            "" => InlineHookType::Noop,
+            _ => InlineHookType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for InlineHookType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for InlineHookType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for InlineHookType {
     fn default() -> InlineHookType {
         InlineHookType::Noop
@@ -3870,14 +5192,16 @@ pub struct LinkedObjectDetails {
     pub type_: Option<LinkedObjectDetailsType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LinkedObjectDetailsType {
     #[serde(rename = "USER")]
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LinkedObjectDetailsType {
@@ -3885,12 +5209,44 @@ impl std::fmt::Display for LinkedObjectDetailsType {
         match self {
             LinkedObjectDetailsType::User => "USER",
             LinkedObjectDetailsType::Noop => "",
-            LinkedObjectDetailsType::FallthroughString => "*",
+            LinkedObjectDetailsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LinkedObjectDetailsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "USER" => LinkedObjectDetailsType::User,
+            "" => LinkedObjectDetailsType::Noop,
+            _ => LinkedObjectDetailsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LinkedObjectDetailsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LinkedObjectDetailsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LinkedObjectDetailsType {
     fn default() -> LinkedObjectDetailsType {
         LinkedObjectDetailsType::Noop
@@ -3979,7 +5335,8 @@ pub struct LogAuthenticationContext {
     pub issuer: Option<LogIssuer>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LogAuthenticationProvider {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -3995,8 +5352,9 @@ pub enum LogAuthenticationProvider {
     Social,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LogAuthenticationProvider {
@@ -4009,12 +5367,49 @@ impl std::fmt::Display for LogAuthenticationProvider {
             LogAuthenticationProvider::OktaAuthenticationProvider => "OKTA_AUTHENTICATION_PROVIDER",
             LogAuthenticationProvider::Social => "SOCIAL",
             LogAuthenticationProvider::Noop => "",
-            LogAuthenticationProvider::FallthroughString => "*",
+            LogAuthenticationProvider::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LogAuthenticationProvider {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE_DIRECTORY" => LogAuthenticationProvider::ActiveDirectory,
+            "FACTOR_PROVIDER" => LogAuthenticationProvider::FactorProvider,
+            "FEDERATION" => LogAuthenticationProvider::Federation,
+            "LDAP" => LogAuthenticationProvider::Ldap,
+            "OKTA_AUTHENTICATION_PROVIDER" => LogAuthenticationProvider::OktaAuthenticationProvider,
+            "SOCIAL" => LogAuthenticationProvider::Social,
+            "" => LogAuthenticationProvider::Noop,
+            _ => LogAuthenticationProvider::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LogAuthenticationProvider {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LogAuthenticationProvider {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LogAuthenticationProvider {
     fn default() -> LogAuthenticationProvider {
         LogAuthenticationProvider::Noop
@@ -4063,7 +5458,8 @@ pub struct LogClient {
     pub zone: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LogCredentialProvider {
     #[serde(rename = "DUO")]
     Duo,
@@ -4081,8 +5477,9 @@ pub enum LogCredentialProvider {
     Yubikey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LogCredentialProvider {
@@ -4096,12 +5493,50 @@ impl std::fmt::Display for LogCredentialProvider {
             LogCredentialProvider::Symantec => "SYMANTEC",
             LogCredentialProvider::Yubikey => "YUBIKEY",
             LogCredentialProvider::Noop => "",
-            LogCredentialProvider::FallthroughString => "*",
+            LogCredentialProvider::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LogCredentialProvider {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DUO" => LogCredentialProvider::Duo,
+            "GOOGLE" => LogCredentialProvider::Google,
+            "OKTA_AUTHENTICATION_PROVIDER" => LogCredentialProvider::OktaAuthenticationProvider,
+            "OKTA_CREDENTIAL_PROVIDER" => LogCredentialProvider::OktaCredentialProvider,
+            "RSA" => LogCredentialProvider::Rsa,
+            "SYMANTEC" => LogCredentialProvider::Symantec,
+            "YUBIKEY" => LogCredentialProvider::Yubikey,
+            "" => LogCredentialProvider::Noop,
+            _ => LogCredentialProvider::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LogCredentialProvider {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LogCredentialProvider {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LogCredentialProvider {
     fn default() -> LogCredentialProvider {
         LogCredentialProvider::Noop
@@ -4113,7 +5548,8 @@ impl LogCredentialProvider {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LogCredentialType {
     #[serde(rename = "ASSERTION")]
     Assertion,
@@ -4133,8 +5569,9 @@ pub enum LogCredentialType {
     Sms,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LogCredentialType {
@@ -4149,12 +5586,51 @@ impl std::fmt::Display for LogCredentialType {
             LogCredentialType::Password => "PASSWORD",
             LogCredentialType::Sms => "SMS",
             LogCredentialType::Noop => "",
-            LogCredentialType::FallthroughString => "*",
+            LogCredentialType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LogCredentialType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ASSERTION" => LogCredentialType::Assertion,
+            "EMAIL" => LogCredentialType::Email,
+            "IWA" => LogCredentialType::Iwa,
+            "JWT" => LogCredentialType::Jwt,
+            "OAUTH2" => LogCredentialType::Oauth2,
+            "OTP" => LogCredentialType::Otp,
+            "PASSWORD" => LogCredentialType::Password,
+            "SMS" => LogCredentialType::Sms,
+            "" => LogCredentialType::Noop,
+            _ => LogCredentialType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LogCredentialType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LogCredentialType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LogCredentialType {
     fn default() -> LogCredentialType {
         LogCredentialType::Noop
@@ -4406,7 +5882,8 @@ pub struct LogSecurityContext {
     pub isp: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LogSeverity {
     #[serde(rename = "DEBUG")]
     Debug,
@@ -4418,8 +5895,9 @@ pub enum LogSeverity {
     Warn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LogSeverity {
@@ -4430,12 +5908,47 @@ impl std::fmt::Display for LogSeverity {
             LogSeverity::Info => "INFO",
             LogSeverity::Warn => "WARN",
             LogSeverity::Noop => "",
-            LogSeverity::FallthroughString => "*",
+            LogSeverity::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LogSeverity {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DEBUG" => LogSeverity::Debug,
+            "ERROR" => LogSeverity::Error,
+            "INFO" => LogSeverity::Info,
+            "WARN" => LogSeverity::Warn,
+            "" => LogSeverity::Noop,
+            _ => LogSeverity::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LogSeverity {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LogSeverity {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LogSeverity {
     fn default() -> LogSeverity {
         LogSeverity::Noop
@@ -4526,7 +6039,8 @@ pub struct LogUserAgent {
     pub raw_user_agent: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Enrollment {
     #[serde(rename = "ANY_OR_NONE")]
     AnyOrNone,
@@ -4534,8 +6048,9 @@ pub enum Enrollment {
     Omm,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Enrollment {
@@ -4544,12 +6059,45 @@ impl std::fmt::Display for Enrollment {
             Enrollment::AnyOrNone => "ANY_OR_NONE",
             Enrollment::Omm => "OMM",
             Enrollment::Noop => "",
-            Enrollment::FallthroughString => "*",
+            Enrollment::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Enrollment {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANY_OR_NONE" => Enrollment::AnyOrNone,
+            "OMM" => Enrollment::Omm,
+            "" => Enrollment::Noop,
+            _ => Enrollment::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Enrollment {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Enrollment {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Enrollment {
     fn default() -> Enrollment {
         Enrollment::Noop
@@ -4658,7 +6206,8 @@ pub struct NetworkZoneAddress {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum NetworkZoneAddressType {
     #[serde(rename = "CIDR")]
     Cidr,
@@ -4666,8 +6215,9 @@ pub enum NetworkZoneAddressType {
     Range,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for NetworkZoneAddressType {
@@ -4676,12 +6226,45 @@ impl std::fmt::Display for NetworkZoneAddressType {
             NetworkZoneAddressType::Cidr => "CIDR",
             NetworkZoneAddressType::Range => "RANGE",
             NetworkZoneAddressType::Noop => "",
-            NetworkZoneAddressType::FallthroughString => "*",
+            NetworkZoneAddressType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for NetworkZoneAddressType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CIDR" => NetworkZoneAddressType::Cidr,
+            "RANGE" => NetworkZoneAddressType::Range,
+            "" => NetworkZoneAddressType::Noop,
+            _ => NetworkZoneAddressType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NetworkZoneAddressType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for NetworkZoneAddressType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for NetworkZoneAddressType {
     fn default() -> NetworkZoneAddressType {
         NetworkZoneAddressType::Noop
@@ -4709,7 +6292,8 @@ pub struct NetworkZoneLocation {
     pub region: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum NetworkZoneType {
     #[serde(rename = "DYNAMIC")]
     Dynamic,
@@ -4717,8 +6301,9 @@ pub enum NetworkZoneType {
     Ip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for NetworkZoneType {
@@ -4727,12 +6312,45 @@ impl std::fmt::Display for NetworkZoneType {
             NetworkZoneType::Dynamic => "DYNAMIC",
             NetworkZoneType::Ip => "IP",
             NetworkZoneType::Noop => "",
-            NetworkZoneType::FallthroughString => "*",
+            NetworkZoneType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for NetworkZoneType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DYNAMIC" => NetworkZoneType::Dynamic,
+            "IP" => NetworkZoneType::Ip,
+            "" => NetworkZoneType::Noop,
+            _ => NetworkZoneType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NetworkZoneType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for NetworkZoneType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for NetworkZoneType {
     fn default() -> NetworkZoneType {
         NetworkZoneType::Noop
@@ -4744,7 +6362,8 @@ impl NetworkZoneType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum NetworkZoneUsage {
     #[serde(rename = "BLOCKLIST")]
     Blocklist,
@@ -4752,8 +6371,9 @@ pub enum NetworkZoneUsage {
     Policy,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for NetworkZoneUsage {
@@ -4762,12 +6382,45 @@ impl std::fmt::Display for NetworkZoneUsage {
             NetworkZoneUsage::Blocklist => "BLOCKLIST",
             NetworkZoneUsage::Policy => "POLICY",
             NetworkZoneUsage::Noop => "",
-            NetworkZoneUsage::FallthroughString => "*",
+            NetworkZoneUsage::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for NetworkZoneUsage {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BLOCKLIST" => NetworkZoneUsage::Blocklist,
+            "POLICY" => NetworkZoneUsage::Policy,
+            "" => NetworkZoneUsage::Noop,
+            _ => NetworkZoneUsage::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NetworkZoneUsage {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for NetworkZoneUsage {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for NetworkZoneUsage {
     fn default() -> NetworkZoneUsage {
         NetworkZoneUsage::Noop
@@ -4796,7 +6449,8 @@ pub struct OAuth2Actor {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ClaimType {
     #[serde(rename = "IDENTITY")]
     Identity,
@@ -4804,8 +6458,9 @@ pub enum ClaimType {
     Resource,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ClaimType {
@@ -4814,12 +6469,45 @@ impl std::fmt::Display for ClaimType {
             ClaimType::Identity => "IDENTITY",
             ClaimType::Resource => "RESOURCE",
             ClaimType::Noop => "",
-            ClaimType::FallthroughString => "*",
+            ClaimType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ClaimType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "IDENTITY" => ClaimType::Identity,
+            "RESOURCE" => ClaimType::Resource,
+            "" => ClaimType::Noop,
+            _ => ClaimType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ClaimType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ClaimType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ClaimType {
     fn default() -> ClaimType {
         ClaimType::Noop
@@ -4831,7 +6519,8 @@ impl ClaimType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GroupFilterType {
     #[serde(rename = "CONTAINS")]
     Contains,
@@ -4843,8 +6532,9 @@ pub enum GroupFilterType {
     StartsWith,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GroupFilterType {
@@ -4855,13 +6545,48 @@ impl std::fmt::Display for GroupFilterType {
             GroupFilterType::Regex => "REGEX",
             GroupFilterType::StartsWith => "STARTS_WITH",
             GroupFilterType::Noop => "",
-            GroupFilterType::FallthroughString => "*",
+            GroupFilterType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
-impl Default for GroupFilterType {
+impl std::str::FromStr for GroupFilterType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CONTAINS" => GroupFilterType::Contains,
+            "EQUALS" => GroupFilterType::Equals,
+            "REGEX" => GroupFilterType::Regex,
+            "STARTS_WITH" => GroupFilterType::StartsWith,
+            "" => GroupFilterType::Noop,
+            _ => GroupFilterType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GroupFilterType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GroupFilterType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl Default for GroupFilterType {
     fn default() -> GroupFilterType {
         GroupFilterType::Noop
     }
@@ -4872,7 +6597,8 @@ impl GroupFilterType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ValueType {
     #[serde(rename = "EXPRESSION")]
     Expression,
@@ -4882,8 +6608,9 @@ pub enum ValueType {
     System,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ValueType {
@@ -4893,12 +6620,46 @@ impl std::fmt::Display for ValueType {
             ValueType::Groups => "GROUPS",
             ValueType::System => "SYSTEM",
             ValueType::Noop => "",
-            ValueType::FallthroughString => "*",
+            ValueType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ValueType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EXPRESSION" => ValueType::Expression,
+            "GROUPS" => ValueType::Groups,
+            "SYSTEM" => ValueType::System,
+            "" => ValueType::Noop,
+            _ => ValueType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ValueType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ValueType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ValueType {
     fn default() -> ValueType {
         ValueType::Noop
@@ -4995,7 +6756,8 @@ pub struct OAuth2Client {
     pub logo_uri: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OAuth2RefreshTokenStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -5003,8 +6765,9 @@ pub enum OAuth2RefreshTokenStatus {
     Revoked,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OAuth2RefreshTokenStatus {
@@ -5013,12 +6776,45 @@ impl std::fmt::Display for OAuth2RefreshTokenStatus {
             OAuth2RefreshTokenStatus::Active => "ACTIVE",
             OAuth2RefreshTokenStatus::Revoked => "REVOKED",
             OAuth2RefreshTokenStatus::Noop => "",
-            OAuth2RefreshTokenStatus::FallthroughString => "*",
+            OAuth2RefreshTokenStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OAuth2RefreshTokenStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => OAuth2RefreshTokenStatus::Active,
+            "REVOKED" => OAuth2RefreshTokenStatus::Revoked,
+            "" => OAuth2RefreshTokenStatus::Noop,
+            _ => OAuth2RefreshTokenStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OAuth2RefreshTokenStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OAuth2RefreshTokenStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OAuth2RefreshTokenStatus {
     fn default() -> OAuth2RefreshTokenStatus {
         OAuth2RefreshTokenStatus::Noop
@@ -5094,7 +6890,8 @@ pub struct OAuth2RefreshToken {
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Consent {
     #[serde(rename = "ADMIN")]
     Admin,
@@ -5104,8 +6901,9 @@ pub enum Consent {
     Required,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Consent {
@@ -5115,12 +6913,46 @@ impl std::fmt::Display for Consent {
             Consent::Implicit => "IMPLICIT",
             Consent::Required => "REQUIRED",
             Consent::Noop => "",
-            Consent::FallthroughString => "*",
+            Consent::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Consent {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ADMIN" => Consent::Admin,
+            "IMPLICIT" => Consent::Implicit,
+            "REQUIRED" => Consent::Required,
+            "" => Consent::Noop,
+            _ => Consent::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Consent {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Consent {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Consent {
     fn default() -> Consent {
         Consent::Noop
@@ -5132,7 +6964,8 @@ impl Consent {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MetadataPublish {
     #[serde(rename = "ALL_CLIENTS")]
     AllClients,
@@ -5140,8 +6973,9 @@ pub enum MetadataPublish {
     NoClients,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MetadataPublish {
@@ -5150,12 +6984,45 @@ impl std::fmt::Display for MetadataPublish {
             MetadataPublish::AllClients => "ALL_CLIENTS",
             MetadataPublish::NoClients => "NO_CLIENTS",
             MetadataPublish::Noop => "",
-            MetadataPublish::FallthroughString => "*",
+            MetadataPublish::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MetadataPublish {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ALL_CLIENTS" => MetadataPublish::AllClients,
+            "NO_CLIENTS" => MetadataPublish::NoClients,
+            "" => MetadataPublish::Noop,
+            _ => MetadataPublish::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MetadataPublish {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MetadataPublish {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MetadataPublish {
     fn default() -> MetadataPublish {
         MetadataPublish::Noop
@@ -5274,7 +7141,8 @@ pub struct OAuth2ScopeConsentGrant {
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OAuth2ScopeConsentGrantSource {
     #[serde(rename = "ADMIN")]
     Admin,
@@ -5282,8 +7150,9 @@ pub enum OAuth2ScopeConsentGrantSource {
     EndUser,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OAuth2ScopeConsentGrantSource {
@@ -5292,12 +7161,45 @@ impl std::fmt::Display for OAuth2ScopeConsentGrantSource {
             OAuth2ScopeConsentGrantSource::Admin => "ADMIN",
             OAuth2ScopeConsentGrantSource::EndUser => "END_USER",
             OAuth2ScopeConsentGrantSource::Noop => "",
-            OAuth2ScopeConsentGrantSource::FallthroughString => "*",
+            OAuth2ScopeConsentGrantSource::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OAuth2ScopeConsentGrantSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ADMIN" => OAuth2ScopeConsentGrantSource::Admin,
+            "END_USER" => OAuth2ScopeConsentGrantSource::EndUser,
+            "" => OAuth2ScopeConsentGrantSource::Noop,
+            _ => OAuth2ScopeConsentGrantSource::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OAuth2ScopeConsentGrantSource {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OAuth2ScopeConsentGrantSource {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OAuth2ScopeConsentGrantSource {
     fn default() -> OAuth2ScopeConsentGrantSource {
         OAuth2ScopeConsentGrantSource::Noop
@@ -5391,7 +7293,8 @@ pub struct OAuthApplicationCredentials {
     pub oauth_client: Option<ApplicationCredentialsOAuthClient>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OAuthEndpointAuthenticationMethod {
     #[serde(rename = "client_secret_basic")]
     ClientSecretBasic,
@@ -5405,8 +7308,9 @@ pub enum OAuthEndpointAuthenticationMethod {
     PrivateKeyJwt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OAuthEndpointAuthenticationMethod {
@@ -5418,12 +7322,48 @@ impl std::fmt::Display for OAuthEndpointAuthenticationMethod {
             OAuthEndpointAuthenticationMethod::None => "none",
             OAuthEndpointAuthenticationMethod::PrivateKeyJwt => "private_key_jwt",
             OAuthEndpointAuthenticationMethod::Noop => "",
-            OAuthEndpointAuthenticationMethod::FallthroughString => "*",
+            OAuthEndpointAuthenticationMethod::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OAuthEndpointAuthenticationMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "client_secret_basic" => OAuthEndpointAuthenticationMethod::ClientSecretBasic,
+            "client_secret_jwt" => OAuthEndpointAuthenticationMethod::ClientSecretJwt,
+            "client_secret_post" => OAuthEndpointAuthenticationMethod::ClientSecretPost,
+            "none" => OAuthEndpointAuthenticationMethod::None,
+            "private_key_jwt" => OAuthEndpointAuthenticationMethod::PrivateKeyJwt,
+            "" => OAuthEndpointAuthenticationMethod::Noop,
+            _ => OAuthEndpointAuthenticationMethod::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OAuthEndpointAuthenticationMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OAuthEndpointAuthenticationMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OAuthEndpointAuthenticationMethod {
     fn default() -> OAuthEndpointAuthenticationMethod {
         OAuthEndpointAuthenticationMethod::Noop
@@ -5435,7 +7375,8 @@ impl OAuthEndpointAuthenticationMethod {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OAuthGrantType {
     #[serde(rename = "authorization_code")]
     AuthorizationCode,
@@ -5449,8 +7390,9 @@ pub enum OAuthGrantType {
     RefreshToken,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OAuthGrantType {
@@ -5462,12 +7404,48 @@ impl std::fmt::Display for OAuthGrantType {
             OAuthGrantType::Password => "password",
             OAuthGrantType::RefreshToken => "refresh_token",
             OAuthGrantType::Noop => "",
-            OAuthGrantType::FallthroughString => "*",
+            OAuthGrantType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OAuthGrantType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "authorization_code" => OAuthGrantType::AuthorizationCode,
+            "client_credentials" => OAuthGrantType::ClientCredentials,
+            "implicit" => OAuthGrantType::Implicit,
+            "password" => OAuthGrantType::Password,
+            "refresh_token" => OAuthGrantType::RefreshToken,
+            "" => OAuthGrantType::Noop,
+            _ => OAuthGrantType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OAuthGrantType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OAuthGrantType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OAuthGrantType {
     fn default() -> OAuthGrantType {
         OAuthGrantType::Noop
@@ -5479,7 +7457,8 @@ impl OAuthGrantType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OAuthResponseType {
     #[serde(rename = "code")]
     Code,
@@ -5489,8 +7468,9 @@ pub enum OAuthResponseType {
     Token,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OAuthResponseType {
@@ -5500,12 +7480,46 @@ impl std::fmt::Display for OAuthResponseType {
             OAuthResponseType::IdToken => "id_token",
             OAuthResponseType::Token => "token",
             OAuthResponseType::Noop => "",
-            OAuthResponseType::FallthroughString => "*",
+            OAuthResponseType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OAuthResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "code" => OAuthResponseType::Code,
+            "id_token" => OAuthResponseType::IdToken,
+            "token" => OAuthResponseType::Token,
+            "" => OAuthResponseType::Noop,
+            _ => OAuthResponseType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OAuthResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OAuthResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OAuthResponseType {
     fn default() -> OAuthResponseType {
         OAuthResponseType::Noop
@@ -5563,7 +7577,8 @@ pub struct OktaSignOnPolicyRuleConditions {
     pub people: Option<PolicyPeopleCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Access {
     #[serde(rename = "ALLOW")]
     Allow,
@@ -5571,8 +7586,9 @@ pub enum Access {
     Deny,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Access {
@@ -5581,12 +7597,45 @@ impl std::fmt::Display for Access {
             Access::Allow => "ALLOW",
             Access::Deny => "DENY",
             Access::Noop => "",
-            Access::FallthroughString => "*",
+            Access::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Access {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ALLOW" => Access::Allow,
+            "DENY" => Access::Deny,
+            "" => Access::Noop,
+            _ => Access::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Access {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Access {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Access {
     fn default() -> Access {
         Access::Noop
@@ -5598,7 +7647,8 @@ impl Access {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FactorPromptMode {
     #[serde(rename = "ALWAYS")]
     Always,
@@ -5608,8 +7658,9 @@ pub enum FactorPromptMode {
     Session,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FactorPromptMode {
@@ -5619,12 +7670,46 @@ impl std::fmt::Display for FactorPromptMode {
             FactorPromptMode::Device => "DEVICE",
             FactorPromptMode::Session => "SESSION",
             FactorPromptMode::Noop => "",
-            FactorPromptMode::FallthroughString => "*",
+            FactorPromptMode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FactorPromptMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ALWAYS" => FactorPromptMode::Always,
+            "DEVICE" => FactorPromptMode::Device,
+            "SESSION" => FactorPromptMode::Session,
+            "" => FactorPromptMode::Noop,
+            _ => FactorPromptMode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FactorPromptMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FactorPromptMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FactorPromptMode {
     fn default() -> FactorPromptMode {
         FactorPromptMode::Noop
@@ -5703,7 +7788,8 @@ pub struct OpenConnectApplication {
     pub settings: Option<OpenConnectApplicationSettings>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OpenConnectApplicationConsentMethod {
     #[serde(rename = "REQUIRED")]
     Required,
@@ -5711,8 +7797,9 @@ pub enum OpenConnectApplicationConsentMethod {
     Trusted,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OpenConnectApplicationConsentMethod {
@@ -5721,12 +7808,45 @@ impl std::fmt::Display for OpenConnectApplicationConsentMethod {
             OpenConnectApplicationConsentMethod::Required => "REQUIRED",
             OpenConnectApplicationConsentMethod::Trusted => "TRUSTED",
             OpenConnectApplicationConsentMethod::Noop => "",
-            OpenConnectApplicationConsentMethod::FallthroughString => "*",
+            OpenConnectApplicationConsentMethod::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OpenConnectApplicationConsentMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "REQUIRED" => OpenConnectApplicationConsentMethod::Required,
+            "TRUSTED" => OpenConnectApplicationConsentMethod::Trusted,
+            "" => OpenConnectApplicationConsentMethod::Noop,
+            _ => OpenConnectApplicationConsentMethod::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OpenConnectApplicationConsentMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OpenConnectApplicationConsentMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OpenConnectApplicationConsentMethod {
     fn default() -> OpenConnectApplicationConsentMethod {
         OpenConnectApplicationConsentMethod::Noop
@@ -5862,7 +7982,8 @@ pub struct OpenConnectApplicationSettingsRefreshToken {
     pub rotation_type: Option<OpenConnectRefreshTokenRotationType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OpenConnectApplicationType {
     #[serde(rename = "browser")]
     Browser,
@@ -5874,8 +7995,9 @@ pub enum OpenConnectApplicationType {
     Web,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OpenConnectApplicationType {
@@ -5886,12 +8008,47 @@ impl std::fmt::Display for OpenConnectApplicationType {
             OpenConnectApplicationType::Service => "service",
             OpenConnectApplicationType::Web => "web",
             OpenConnectApplicationType::Noop => "",
-            OpenConnectApplicationType::FallthroughString => "*",
+            OpenConnectApplicationType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OpenConnectApplicationType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "browser" => OpenConnectApplicationType::Browser,
+            "native" => OpenConnectApplicationType::Native,
+            "service" => OpenConnectApplicationType::Service,
+            "web" => OpenConnectApplicationType::Web,
+            "" => OpenConnectApplicationType::Noop,
+            _ => OpenConnectApplicationType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OpenConnectApplicationType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OpenConnectApplicationType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OpenConnectApplicationType {
     fn default() -> OpenConnectApplicationType {
         OpenConnectApplicationType::Noop
@@ -5903,7 +8060,8 @@ impl OpenConnectApplicationType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OpenConnectRefreshTokenRotationType {
     #[serde(rename = "rotate")]
     Rotate,
@@ -5911,8 +8069,9 @@ pub enum OpenConnectRefreshTokenRotationType {
     Static,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OpenConnectRefreshTokenRotationType {
@@ -5921,12 +8080,45 @@ impl std::fmt::Display for OpenConnectRefreshTokenRotationType {
             OpenConnectRefreshTokenRotationType::Rotate => "rotate",
             OpenConnectRefreshTokenRotationType::Static => "static",
             OpenConnectRefreshTokenRotationType::Noop => "",
-            OpenConnectRefreshTokenRotationType::FallthroughString => "*",
+            OpenConnectRefreshTokenRotationType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OpenConnectRefreshTokenRotationType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "rotate" => OpenConnectRefreshTokenRotationType::Rotate,
+            "static" => OpenConnectRefreshTokenRotationType::Static,
+            "" => OpenConnectRefreshTokenRotationType::Noop,
+            _ => OpenConnectRefreshTokenRotationType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OpenConnectRefreshTokenRotationType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OpenConnectRefreshTokenRotationType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OpenConnectRefreshTokenRotationType {
     fn default() -> OpenConnectRefreshTokenRotationType {
         OpenConnectRefreshTokenRotationType::Noop
@@ -5984,7 +8176,8 @@ pub struct PasswordCredentialHash {
     pub work_factor: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PasswordCredentialHashAlgorithm {
     #[serde(rename = "BCRYPT")]
     Bcrypt,
@@ -5998,8 +8191,9 @@ pub enum PasswordCredentialHashAlgorithm {
     Sha512,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PasswordCredentialHashAlgorithm {
@@ -6011,12 +8205,48 @@ impl std::fmt::Display for PasswordCredentialHashAlgorithm {
             PasswordCredentialHashAlgorithm::Sha256 => "SHA-256",
             PasswordCredentialHashAlgorithm::Sha512 => "SHA-512",
             PasswordCredentialHashAlgorithm::Noop => "",
-            PasswordCredentialHashAlgorithm::FallthroughString => "*",
+            PasswordCredentialHashAlgorithm::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PasswordCredentialHashAlgorithm {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BCRYPT" => PasswordCredentialHashAlgorithm::Bcrypt,
+            "MD5" => PasswordCredentialHashAlgorithm::Md5,
+            "SHA-1" => PasswordCredentialHashAlgorithm::ShAminusOne,
+            "SHA-256" => PasswordCredentialHashAlgorithm::Sha256,
+            "SHA-512" => PasswordCredentialHashAlgorithm::Sha512,
+            "" => PasswordCredentialHashAlgorithm::Noop,
+            _ => PasswordCredentialHashAlgorithm::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PasswordCredentialHashAlgorithm {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PasswordCredentialHashAlgorithm {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PasswordCredentialHashAlgorithm {
     fn default() -> PasswordCredentialHashAlgorithm {
         PasswordCredentialHashAlgorithm::Noop
@@ -6062,7 +8292,8 @@ pub struct PasswordPolicy {
     pub settings: Option<PasswordPolicySettingsData>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PasswordPolicyAuthenticationProviderCondition {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -6074,8 +8305,9 @@ pub enum PasswordPolicyAuthenticationProviderCondition {
     Okta,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PasswordPolicyAuthenticationProviderCondition {
@@ -6086,12 +8318,47 @@ impl std::fmt::Display for PasswordPolicyAuthenticationProviderCondition {
             PasswordPolicyAuthenticationProviderCondition::Ldap => "LDAP",
             PasswordPolicyAuthenticationProviderCondition::Okta => "OKTA",
             PasswordPolicyAuthenticationProviderCondition::Noop => "",
-            PasswordPolicyAuthenticationProviderCondition::FallthroughString => "*",
+            PasswordPolicyAuthenticationProviderCondition::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PasswordPolicyAuthenticationProviderCondition {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE_DIRECTORY" => PasswordPolicyAuthenticationProviderCondition::ActiveDirectory,
+            "ANY" => PasswordPolicyAuthenticationProviderCondition::Any,
+            "LDAP" => PasswordPolicyAuthenticationProviderCondition::Ldap,
+            "OKTA" => PasswordPolicyAuthenticationProviderCondition::Okta,
+            "" => PasswordPolicyAuthenticationProviderCondition::Noop,
+            _ => PasswordPolicyAuthenticationProviderCondition::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PasswordPolicyAuthenticationProviderCondition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PasswordPolicyAuthenticationProviderCondition {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PasswordPolicyAuthenticationProviderCondition {
     fn default() -> PasswordPolicyAuthenticationProviderCondition {
         PasswordPolicyAuthenticationProviderCondition::Noop
@@ -6408,7 +8675,8 @@ pub struct PasswordPolicySettingsData {
     pub recovery: Option<PasswordPolicyRecoverySettings>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PlatformConditionEvaluatorType {
     #[serde(rename = "ANY")]
     Any,
@@ -6420,8 +8688,9 @@ pub enum PlatformConditionEvaluatorType {
     Other,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PlatformConditionEvaluatorType {
@@ -6432,12 +8701,47 @@ impl std::fmt::Display for PlatformConditionEvaluatorType {
             PlatformConditionEvaluatorType::Mobile => "MOBILE",
             PlatformConditionEvaluatorType::Other => "OTHER",
             PlatformConditionEvaluatorType::Noop => "",
-            PlatformConditionEvaluatorType::FallthroughString => "*",
+            PlatformConditionEvaluatorType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PlatformConditionEvaluatorType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANY" => PlatformConditionEvaluatorType::Any,
+            "DESKTOP" => PlatformConditionEvaluatorType::Desktop,
+            "MOBILE" => PlatformConditionEvaluatorType::Mobile,
+            "OTHER" => PlatformConditionEvaluatorType::Other,
+            "" => PlatformConditionEvaluatorType::Noop,
+            _ => PlatformConditionEvaluatorType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PlatformConditionEvaluatorType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PlatformConditionEvaluatorType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PlatformConditionEvaluatorType {
     fn default() -> PlatformConditionEvaluatorType {
         PlatformConditionEvaluatorType::Noop
@@ -6457,7 +8761,8 @@ pub struct PlatformConditionEvaluator {
     pub type_: Option<PlatformConditionEvaluatorType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PlatformConditionEvaluatorOperatingSystemType {
     #[serde(rename = "ANDROID")]
     Android,
@@ -6473,8 +8778,9 @@ pub enum PlatformConditionEvaluatorOperatingSystemType {
     Windows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PlatformConditionEvaluatorOperatingSystemType {
@@ -6487,12 +8793,49 @@ impl std::fmt::Display for PlatformConditionEvaluatorOperatingSystemType {
             PlatformConditionEvaluatorOperatingSystemType::Other => "OTHER",
             PlatformConditionEvaluatorOperatingSystemType::Windows => "WINDOWS",
             PlatformConditionEvaluatorOperatingSystemType::Noop => "",
-            PlatformConditionEvaluatorOperatingSystemType::FallthroughString => "*",
+            PlatformConditionEvaluatorOperatingSystemType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PlatformConditionEvaluatorOperatingSystemType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANDROID" => PlatformConditionEvaluatorOperatingSystemType::Android,
+            "ANY" => PlatformConditionEvaluatorOperatingSystemType::Any,
+            "IOS" => PlatformConditionEvaluatorOperatingSystemType::Ios,
+            "OSX" => PlatformConditionEvaluatorOperatingSystemType::Osx,
+            "OTHER" => PlatformConditionEvaluatorOperatingSystemType::Other,
+            "WINDOWS" => PlatformConditionEvaluatorOperatingSystemType::Windows,
+            "" => PlatformConditionEvaluatorOperatingSystemType::Noop,
+            _ => PlatformConditionEvaluatorOperatingSystemType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PlatformConditionEvaluatorOperatingSystemType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PlatformConditionEvaluatorOperatingSystemType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PlatformConditionEvaluatorOperatingSystemType {
     fn default() -> PlatformConditionEvaluatorOperatingSystemType {
         PlatformConditionEvaluatorOperatingSystemType::Noop
@@ -6518,7 +8861,8 @@ pub struct PlatformConditionEvaluatorOperatingSystem {
     pub version: Option<PlatformConditionEvaluatorOperatingSystemVersion>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MatchType {
     #[serde(rename = "EXPRESSION")]
     Expression,
@@ -6526,8 +8870,9 @@ pub enum MatchType {
     Semver,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MatchType {
@@ -6536,12 +8881,45 @@ impl std::fmt::Display for MatchType {
             MatchType::Expression => "EXPRESSION",
             MatchType::Semver => "SEMVER",
             MatchType::Noop => "",
-            MatchType::FallthroughString => "*",
+            MatchType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MatchType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EXPRESSION" => MatchType::Expression,
+            "SEMVER" => MatchType::Semver,
+            "" => MatchType::Noop,
+            _ => MatchType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MatchType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MatchType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MatchType {
     fn default() -> MatchType {
         MatchType::Noop
@@ -6637,7 +9015,8 @@ pub struct Policy {
     pub type_: Option<PolicyType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Action {
     #[serde(rename = "AUTO")]
     Auto,
@@ -6645,8 +9024,9 @@ pub enum Action {
     Disabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Action {
@@ -6655,12 +9035,45 @@ impl std::fmt::Display for Action {
             Action::Auto => "AUTO",
             Action::Disabled => "DISABLED",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Action {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AUTO" => Action::Auto,
+            "DISABLED" => Action::Disabled,
+            "" => Action::Noop,
+            _ => Action::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Action {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Action {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Action {
     fn default() -> Action {
         Action::Noop
@@ -6686,7 +9099,8 @@ pub struct PolicyAccountLinkFilter {
     pub groups: Option<ClientPolicyCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Connection {
     #[serde(rename = "ANYWHERE")]
     Anywhere,
@@ -6694,8 +9108,9 @@ pub enum Connection {
     Zone,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Connection {
@@ -6704,12 +9119,45 @@ impl std::fmt::Display for Connection {
             Connection::Anywhere => "ANYWHERE",
             Connection::Zone => "ZONE",
             Connection::Noop => "",
-            Connection::FallthroughString => "*",
+            Connection::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Connection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANYWHERE" => Connection::Anywhere,
+            "ZONE" => Connection::Zone,
+            "" => Connection::Noop,
+            _ => Connection::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Connection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Connection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Connection {
     fn default() -> Connection {
         Connection::Noop
@@ -6747,7 +9195,8 @@ pub struct PolicyPeopleCondition {
     pub users: Option<UserCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PolicyRuleType {
     #[serde(rename = "PASSWORD")]
     Password,
@@ -6755,8 +9204,9 @@ pub enum PolicyRuleType {
     SignOn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PolicyRuleType {
@@ -6765,12 +9215,45 @@ impl std::fmt::Display for PolicyRuleType {
             PolicyRuleType::Password => "PASSWORD",
             PolicyRuleType::SignOn => "SIGN_ON",
             PolicyRuleType::Noop => "",
-            PolicyRuleType::FallthroughString => "*",
+            PolicyRuleType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PolicyRuleType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "PASSWORD" => PolicyRuleType::Password,
+            "SIGN_ON" => PolicyRuleType::SignOn,
+            "" => PolicyRuleType::Noop,
+            _ => PolicyRuleType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PolicyRuleType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PolicyRuleType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PolicyRuleType {
     fn default() -> PolicyRuleType {
         PolicyRuleType::Noop
@@ -6862,7 +9345,8 @@ pub struct PolicyRuleActionsEnroll {
     pub self_: Option<PolicyRuleActionsEnrollSelf>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PolicyRuleActionsEnrollSelf {
     #[serde(rename = "CHALLENGE")]
     Challenge,
@@ -6872,8 +9356,9 @@ pub enum PolicyRuleActionsEnrollSelf {
     Never,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PolicyRuleActionsEnrollSelf {
@@ -6883,12 +9368,46 @@ impl std::fmt::Display for PolicyRuleActionsEnrollSelf {
             PolicyRuleActionsEnrollSelf::Login => "LOGIN",
             PolicyRuleActionsEnrollSelf::Never => "NEVER",
             PolicyRuleActionsEnrollSelf::Noop => "",
-            PolicyRuleActionsEnrollSelf::FallthroughString => "*",
+            PolicyRuleActionsEnrollSelf::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PolicyRuleActionsEnrollSelf {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CHALLENGE" => PolicyRuleActionsEnrollSelf::Challenge,
+            "LOGIN" => PolicyRuleActionsEnrollSelf::Login,
+            "NEVER" => PolicyRuleActionsEnrollSelf::Never,
+            "" => PolicyRuleActionsEnrollSelf::Noop,
+            _ => PolicyRuleActionsEnrollSelf::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PolicyRuleActionsEnrollSelf {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PolicyRuleActionsEnrollSelf {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PolicyRuleActionsEnrollSelf {
     fn default() -> PolicyRuleActionsEnrollSelf {
         PolicyRuleActionsEnrollSelf::Noop
@@ -6900,7 +9419,8 @@ impl PolicyRuleActionsEnrollSelf {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AuthType {
     #[serde(rename = "ANY")]
     Any,
@@ -6908,8 +9428,9 @@ pub enum AuthType {
     Radius,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AuthType {
@@ -6918,12 +9439,45 @@ impl std::fmt::Display for AuthType {
             AuthType::Any => "ANY",
             AuthType::Radius => "RADIUS",
             AuthType::Noop => "",
-            AuthType::FallthroughString => "*",
+            AuthType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AuthType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANY" => AuthType::Any,
+            "RADIUS" => AuthType::Radius,
+            "" => AuthType::Noop,
+            _ => AuthType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AuthType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AuthType {
     fn default() -> AuthType {
         AuthType::Noop
@@ -7050,7 +9604,8 @@ pub struct PolicySubject {
     pub user_name_template: Option<PolicyUserNameTemplate>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PolicySubjectMatchType {
     #[serde(rename = "CUSTOM_ATTRIBUTE")]
     CustomAttribute,
@@ -7062,8 +9617,9 @@ pub enum PolicySubjectMatchType {
     UsernameOrEmail,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PolicySubjectMatchType {
@@ -7074,12 +9630,47 @@ impl std::fmt::Display for PolicySubjectMatchType {
             PolicySubjectMatchType::Username => "USERNAME",
             PolicySubjectMatchType::UsernameOrEmail => "USERNAME_OR_EMAIL",
             PolicySubjectMatchType::Noop => "",
-            PolicySubjectMatchType::FallthroughString => "*",
+            PolicySubjectMatchType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PolicySubjectMatchType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CUSTOM_ATTRIBUTE" => PolicySubjectMatchType::CustomAttribute,
+            "EMAIL" => PolicySubjectMatchType::Email,
+            "USERNAME" => PolicySubjectMatchType::Username,
+            "USERNAME_OR_EMAIL" => PolicySubjectMatchType::UsernameOrEmail,
+            "" => PolicySubjectMatchType::Noop,
+            _ => PolicySubjectMatchType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PolicySubjectMatchType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PolicySubjectMatchType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PolicySubjectMatchType {
     fn default() -> PolicySubjectMatchType {
         PolicySubjectMatchType::Noop
@@ -7091,7 +9682,8 @@ impl PolicySubjectMatchType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PolicyType {
     #[serde(rename = "IDP_DISCOVERY")]
     IdpDiscovery,
@@ -7103,8 +9695,9 @@ pub enum PolicyType {
     Password,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PolicyType {
@@ -7115,12 +9708,47 @@ impl std::fmt::Display for PolicyType {
             PolicyType::OktaSignOn => "OKTA_SIGN_ON",
             PolicyType::Password => "PASSWORD",
             PolicyType::Noop => "",
-            PolicyType::FallthroughString => "*",
+            PolicyType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PolicyType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "IDP_DISCOVERY" => PolicyType::IdpDiscovery,
+            "OAUTH_AUTHORIZATION_POLICY" => PolicyType::OauthAuthorizationPolicy,
+            "OKTA_SIGN_ON" => PolicyType::OktaSignOn,
+            "PASSWORD" => PolicyType::Password,
+            "" => PolicyType::Noop,
+            _ => PolicyType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PolicyType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PolicyType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PolicyType {
     fn default() -> PolicyType {
         PolicyType::Noop
@@ -7201,7 +9829,8 @@ pub struct ProfileMappingSource {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ProtocolType {
     #[serde(rename = "MTLS")]
     Mtls,
@@ -7213,8 +9842,9 @@ pub enum ProtocolType {
     Saml2,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ProtocolType {
@@ -7225,12 +9855,47 @@ impl std::fmt::Display for ProtocolType {
             ProtocolType::Oidc => "OIDC",
             ProtocolType::Saml2 => "SAML2",
             ProtocolType::Noop => "",
-            ProtocolType::FallthroughString => "*",
+            ProtocolType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ProtocolType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "MTLS" => ProtocolType::Mtls,
+            "OAUTH2" => ProtocolType::Oauth2,
+            "OIDC" => ProtocolType::Oidc,
+            "SAML2" => ProtocolType::Saml2,
+            "" => ProtocolType::Noop,
+            _ => ProtocolType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ProtocolType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProtocolType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ProtocolType {
     fn default() -> ProtocolType {
         ProtocolType::Noop
@@ -7276,7 +9941,8 @@ pub struct ProtocolAlgorithmType {
     pub signature: Option<ProtocolAlgorithmTypeSignature>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Scope {
     #[serde(rename = "ANY")]
     Any,
@@ -7290,8 +9956,9 @@ pub enum Scope {
     Token,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Scope {
@@ -7303,12 +9970,48 @@ impl std::fmt::Display for Scope {
             Scope::Response => "RESPONSE",
             Scope::Token => "TOKEN",
             Scope::Noop => "",
-            Scope::FallthroughString => "*",
+            Scope::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Scope {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANY" => Scope::Any,
+            "NONE" => Scope::None,
+            "REQUEST" => Scope::Request,
+            "RESPONSE" => Scope::Response,
+            "TOKEN" => Scope::Token,
+            "" => Scope::Noop,
+            _ => Scope::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Scope {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Scope {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Scope {
     fn default() -> Scope {
         Scope::Noop
@@ -7340,7 +10043,8 @@ pub struct ProtocolAlgorithms {
     pub response: Option<ProtocolAlgorithmType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Binding {
     #[serde(rename = "HTTP-POST")]
     HttpPost,
@@ -7348,8 +10052,9 @@ pub enum Binding {
     HttpRedirect,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Binding {
@@ -7358,12 +10063,45 @@ impl std::fmt::Display for Binding {
             Binding::HttpPost => "HTTP-POST",
             Binding::HttpRedirect => "HTTP-REDIRECT",
             Binding::Noop => "",
-            Binding::FallthroughString => "*",
+            Binding::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Binding {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "HTTP-POST" => Binding::HttpPost,
+            "HTTP-REDIRECT" => Binding::HttpRedirect,
+            "" => Binding::Noop,
+            _ => Binding::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Binding {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Binding {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Binding {
     fn default() -> Binding {
         Binding::Noop
@@ -7375,7 +10113,8 @@ impl Binding {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ProtocolEndpointType {
     #[serde(rename = "INSTANCE")]
     Instance,
@@ -7383,8 +10122,9 @@ pub enum ProtocolEndpointType {
     Org,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ProtocolEndpointType {
@@ -7393,12 +10133,45 @@ impl std::fmt::Display for ProtocolEndpointType {
             ProtocolEndpointType::Instance => "INSTANCE",
             ProtocolEndpointType::Org => "ORG",
             ProtocolEndpointType::Noop => "",
-            ProtocolEndpointType::FallthroughString => "*",
+            ProtocolEndpointType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ProtocolEndpointType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "INSTANCE" => ProtocolEndpointType::Instance,
+            "ORG" => ProtocolEndpointType::Org,
+            "" => ProtocolEndpointType::Noop,
+            _ => ProtocolEndpointType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ProtocolEndpointType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProtocolEndpointType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ProtocolEndpointType {
     fn default() -> ProtocolEndpointType {
         ProtocolEndpointType::Noop
@@ -7456,7 +10229,8 @@ pub struct ProtocolRelayState {
     pub format: Option<ProtocolRelayStateFormat>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ProtocolRelayStateFormat {
     #[serde(rename = "FROM_URL")]
     FromUrl,
@@ -7464,8 +10238,9 @@ pub enum ProtocolRelayStateFormat {
     Opaque,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ProtocolRelayStateFormat {
@@ -7474,12 +10249,45 @@ impl std::fmt::Display for ProtocolRelayStateFormat {
             ProtocolRelayStateFormat::FromUrl => "FROM_URL",
             ProtocolRelayStateFormat::Opaque => "OPAQUE",
             ProtocolRelayStateFormat::Noop => "",
-            ProtocolRelayStateFormat::FallthroughString => "*",
+            ProtocolRelayStateFormat::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ProtocolRelayStateFormat {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "FROM_URL" => ProtocolRelayStateFormat::FromUrl,
+            "OPAQUE" => ProtocolRelayStateFormat::Opaque,
+            "" => ProtocolRelayStateFormat::Noop,
+            _ => ProtocolRelayStateFormat::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ProtocolRelayStateFormat {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProtocolRelayStateFormat {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ProtocolRelayStateFormat {
     fn default() -> ProtocolRelayStateFormat {
         ProtocolRelayStateFormat::Noop
@@ -7502,7 +10310,8 @@ pub struct ProtocolSettings {
     pub name_format: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ProvisioningAction {
     #[serde(rename = "AUTO")]
     Auto,
@@ -7512,8 +10321,9 @@ pub enum ProvisioningAction {
     Disabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ProvisioningAction {
@@ -7523,12 +10333,46 @@ impl std::fmt::Display for ProvisioningAction {
             ProvisioningAction::Callout => "CALLOUT",
             ProvisioningAction::Disabled => "DISABLED",
             ProvisioningAction::Noop => "",
-            ProvisioningAction::FallthroughString => "*",
+            ProvisioningAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ProvisioningAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AUTO" => ProvisioningAction::Auto,
+            "CALLOUT" => ProvisioningAction::Callout,
+            "DISABLED" => ProvisioningAction::Disabled,
+            "" => ProvisioningAction::Noop,
+            _ => ProvisioningAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ProvisioningAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProvisioningAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ProvisioningAction {
     fn default() -> ProvisioningAction {
         ProvisioningAction::Noop
@@ -7564,7 +10408,8 @@ pub struct ProvisioningConditions {
     pub suspended: Option<ProvisioningSuspendedCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ProvisioningDeprovisionedConditionAction {
     #[serde(rename = "NONE")]
     None,
@@ -7572,8 +10417,9 @@ pub enum ProvisioningDeprovisionedConditionAction {
     Reactivate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ProvisioningDeprovisionedConditionAction {
@@ -7582,12 +10428,45 @@ impl std::fmt::Display for ProvisioningDeprovisionedConditionAction {
             ProvisioningDeprovisionedConditionAction::None => "NONE",
             ProvisioningDeprovisionedConditionAction::Reactivate => "REACTIVATE",
             ProvisioningDeprovisionedConditionAction::Noop => "",
-            ProvisioningDeprovisionedConditionAction::FallthroughString => "*",
+            ProvisioningDeprovisionedConditionAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ProvisioningDeprovisionedConditionAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "NONE" => ProvisioningDeprovisionedConditionAction::None,
+            "REACTIVATE" => ProvisioningDeprovisionedConditionAction::Reactivate,
+            "" => ProvisioningDeprovisionedConditionAction::Noop,
+            _ => ProvisioningDeprovisionedConditionAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ProvisioningDeprovisionedConditionAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProvisioningDeprovisionedConditionAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ProvisioningDeprovisionedConditionAction {
     fn default() -> ProvisioningDeprovisionedConditionAction {
         ProvisioningDeprovisionedConditionAction::Noop
@@ -7605,7 +10484,8 @@ pub struct ProvisioningDeprovisionedCondition {
     pub action: Option<ProvisioningDeprovisionedConditionAction>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ProvisioningGroupsAction {
     #[serde(rename = "APPEND")]
     Append,
@@ -7617,8 +10497,9 @@ pub enum ProvisioningGroupsAction {
     Sync,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ProvisioningGroupsAction {
@@ -7629,12 +10510,47 @@ impl std::fmt::Display for ProvisioningGroupsAction {
             ProvisioningGroupsAction::None => "NONE",
             ProvisioningGroupsAction::Sync => "SYNC",
             ProvisioningGroupsAction::Noop => "",
-            ProvisioningGroupsAction::FallthroughString => "*",
+            ProvisioningGroupsAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ProvisioningGroupsAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "APPEND" => ProvisioningGroupsAction::Append,
+            "ASSIGN" => ProvisioningGroupsAction::Assign,
+            "NONE" => ProvisioningGroupsAction::None,
+            "SYNC" => ProvisioningGroupsAction::Sync,
+            "" => ProvisioningGroupsAction::Noop,
+            _ => ProvisioningGroupsAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ProvisioningGroupsAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProvisioningGroupsAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ProvisioningGroupsAction {
     fn default() -> ProvisioningGroupsAction {
         ProvisioningGroupsAction::Noop
@@ -7671,7 +10587,8 @@ pub struct ProvisioningGroups {
     pub source_attribute_name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ProvisioningSuspendedConditionAction {
     #[serde(rename = "NONE")]
     None,
@@ -7679,8 +10596,9 @@ pub enum ProvisioningSuspendedConditionAction {
     Unsuspend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ProvisioningSuspendedConditionAction {
@@ -7689,12 +10607,45 @@ impl std::fmt::Display for ProvisioningSuspendedConditionAction {
             ProvisioningSuspendedConditionAction::None => "NONE",
             ProvisioningSuspendedConditionAction::Unsuspend => "UNSUSPEND",
             ProvisioningSuspendedConditionAction::Noop => "",
-            ProvisioningSuspendedConditionAction::FallthroughString => "*",
+            ProvisioningSuspendedConditionAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ProvisioningSuspendedConditionAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "NONE" => ProvisioningSuspendedConditionAction::None,
+            "UNSUSPEND" => ProvisioningSuspendedConditionAction::Unsuspend,
+            "" => ProvisioningSuspendedConditionAction::Noop,
+            _ => ProvisioningSuspendedConditionAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ProvisioningSuspendedConditionAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProvisioningSuspendedConditionAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ProvisioningSuspendedConditionAction {
     fn default() -> ProvisioningSuspendedConditionAction {
         ProvisioningSuspendedConditionAction::Noop
@@ -7859,7 +10810,8 @@ pub struct Role {
     pub type_: Option<RoleType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RoleAssignmentType {
     #[serde(rename = "GROUP")]
     Group,
@@ -7867,8 +10819,9 @@ pub enum RoleAssignmentType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RoleAssignmentType {
@@ -7877,12 +10830,45 @@ impl std::fmt::Display for RoleAssignmentType {
             RoleAssignmentType::Group => "GROUP",
             RoleAssignmentType::User => "USER",
             RoleAssignmentType::Noop => "",
-            RoleAssignmentType::FallthroughString => "*",
+            RoleAssignmentType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RoleAssignmentType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "GROUP" => RoleAssignmentType::Group,
+            "USER" => RoleAssignmentType::User,
+            "" => RoleAssignmentType::Noop,
+            _ => RoleAssignmentType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RoleAssignmentType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RoleAssignmentType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RoleAssignmentType {
     fn default() -> RoleAssignmentType {
         RoleAssignmentType::Noop
@@ -7894,7 +10880,8 @@ impl RoleAssignmentType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RoleType {
     #[serde(rename = "API_ACCESS_MANAGEMENT_ADMIN")]
     ApiAccessManagementAdmin,
@@ -7916,8 +10903,9 @@ pub enum RoleType {
     UserAdmin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RoleType {
@@ -7933,12 +10921,52 @@ impl std::fmt::Display for RoleType {
             RoleType::SuperAdmin => "SUPER_ADMIN",
             RoleType::UserAdmin => "USER_ADMIN",
             RoleType::Noop => "",
-            RoleType::FallthroughString => "*",
+            RoleType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RoleType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "API_ACCESS_MANAGEMENT_ADMIN" => RoleType::ApiAccessManagementAdmin,
+            "APP_ADMIN" => RoleType::AppAdmin,
+            "HELP_DESK_ADMIN" => RoleType::HelpDeskAdmin,
+            "MOBILE_ADMIN" => RoleType::MobileAdmin,
+            "ORG_ADMIN" => RoleType::OrgAdmin,
+            "READ_ONLY_ADMIN" => RoleType::ReadOnlyAdmin,
+            "REPORT_ADMIN" => RoleType::ReportAdmin,
+            "SUPER_ADMIN" => RoleType::SuperAdmin,
+            "USER_ADMIN" => RoleType::UserAdmin,
+            "" => RoleType::Noop,
+            _ => RoleType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RoleType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RoleType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RoleType {
     fn default() -> RoleType {
         RoleType::Noop
@@ -8177,7 +11205,8 @@ pub struct SamlAttributeStatement {
     pub values: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Value {
     #[serde(rename = "ACTIVATING")]
     Activating,
@@ -8197,8 +11226,9 @@ pub enum Value {
     Suspended,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Value {
@@ -8213,12 +11243,51 @@ impl std::fmt::Display for Value {
             Value::Pending => "PENDING",
             Value::Suspended => "SUSPENDED",
             Value::Noop => "",
-            Value::FallthroughString => "*",
+            Value::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Value {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVATING" => Value::Activating,
+            "ACTIVE" => Value::Active,
+            "DELETED" => Value::Deleted,
+            "DELETING" => Value::Deleting,
+            "EXPIRED_PASSWORD" => Value::ExpiredPassword,
+            "INACTIVE" => Value::Inactive,
+            "PENDING" => Value::Pending,
+            "SUSPENDED" => Value::Suspended,
+            "" => Value::Noop,
+            _ => Value::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Value {
     fn default() -> Value {
         Value::Noop
@@ -8272,7 +11341,8 @@ pub struct ScopeData {
     pub type_: Option<ScopeType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ScopeType {
     #[serde(rename = "CORS")]
     Cors,
@@ -8280,8 +11350,9 @@ pub enum ScopeType {
     Redirect,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ScopeType {
@@ -8290,12 +11361,45 @@ impl std::fmt::Display for ScopeType {
             ScopeType::Cors => "CORS",
             ScopeType::Redirect => "REDIRECT",
             ScopeType::Noop => "",
-            ScopeType::FallthroughString => "*",
+            ScopeType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ScopeType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CORS" => ScopeType::Cors,
+            "REDIRECT" => ScopeType::Redirect,
+            "" => ScopeType::Noop,
+            _ => ScopeType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ScopeType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScopeType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ScopeType {
     fn default() -> ScopeType {
         ScopeType::Noop
@@ -8481,7 +11585,8 @@ pub struct Session {
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SessionAuthenticationMethod {
     #[serde(rename = "fpt")]
     Fpt,
@@ -8505,8 +11610,9 @@ pub enum SessionAuthenticationMethod {
     Tel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SessionAuthenticationMethod {
@@ -8523,12 +11629,53 @@ impl std::fmt::Display for SessionAuthenticationMethod {
             SessionAuthenticationMethod::Swk => "swk",
             SessionAuthenticationMethod::Tel => "tel",
             SessionAuthenticationMethod::Noop => "",
-            SessionAuthenticationMethod::FallthroughString => "*",
+            SessionAuthenticationMethod::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SessionAuthenticationMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "fpt" => SessionAuthenticationMethod::Fpt,
+            "geo" => SessionAuthenticationMethod::Geo,
+            "hwk" => SessionAuthenticationMethod::Hwk,
+            "kba" => SessionAuthenticationMethod::Kba,
+            "mfa" => SessionAuthenticationMethod::Mfa,
+            "otp" => SessionAuthenticationMethod::Otp,
+            "pwd" => SessionAuthenticationMethod::Pwd,
+            "sms" => SessionAuthenticationMethod::Sms,
+            "swk" => SessionAuthenticationMethod::Swk,
+            "tel" => SessionAuthenticationMethod::Tel,
+            "" => SessionAuthenticationMethod::Noop,
+            _ => SessionAuthenticationMethod::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SessionAuthenticationMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SessionAuthenticationMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SessionAuthenticationMethod {
     fn default() -> SessionAuthenticationMethod {
         SessionAuthenticationMethod::Noop
@@ -8552,7 +11699,8 @@ pub struct SessionIdentityProvider {
     pub type_: Option<SessionIdentityProviderType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SessionIdentityProviderType {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -8566,8 +11714,9 @@ pub enum SessionIdentityProviderType {
     Social,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SessionIdentityProviderType {
@@ -8579,12 +11728,48 @@ impl std::fmt::Display for SessionIdentityProviderType {
             SessionIdentityProviderType::Okta => "OKTA",
             SessionIdentityProviderType::Social => "SOCIAL",
             SessionIdentityProviderType::Noop => "",
-            SessionIdentityProviderType::FallthroughString => "*",
+            SessionIdentityProviderType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SessionIdentityProviderType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE_DIRECTORY" => SessionIdentityProviderType::ActiveDirectory,
+            "FEDERATION" => SessionIdentityProviderType::Federation,
+            "LDAP" => SessionIdentityProviderType::Ldap,
+            "OKTA" => SessionIdentityProviderType::Okta,
+            "SOCIAL" => SessionIdentityProviderType::Social,
+            "" => SessionIdentityProviderType::Noop,
+            _ => SessionIdentityProviderType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SessionIdentityProviderType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SessionIdentityProviderType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SessionIdentityProviderType {
     fn default() -> SessionIdentityProviderType {
         SessionIdentityProviderType::Noop
@@ -8596,7 +11781,8 @@ impl SessionIdentityProviderType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SessionStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -8606,8 +11792,9 @@ pub enum SessionStatus {
     MfaRequired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SessionStatus {
@@ -8617,12 +11804,46 @@ impl std::fmt::Display for SessionStatus {
             SessionStatus::MfaEnroll => "MFA_ENROLL",
             SessionStatus::MfaRequired => "MFA_REQUIRED",
             SessionStatus::Noop => "",
-            SessionStatus::FallthroughString => "*",
+            SessionStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SessionStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => SessionStatus::Active,
+            "MFA_ENROLL" => SessionStatus::MfaEnroll,
+            "MFA_REQUIRED" => SessionStatus::MfaRequired,
+            "" => SessionStatus::Noop,
+            _ => SessionStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SessionStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SessionStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SessionStatus {
     fn default() -> SessionStatus {
         SessionStatus::Noop
@@ -8705,14 +11926,16 @@ pub struct SmsTemplate {
     pub type_: Option<SmsTemplateType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SmsTemplateType {
     #[serde(rename = "SMS_VERIFY_CODE")]
     SmsVerifyCode,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SmsTemplateType {
@@ -8720,12 +11943,44 @@ impl std::fmt::Display for SmsTemplateType {
         match self {
             SmsTemplateType::SmsVerifyCode => "SMS_VERIFY_CODE",
             SmsTemplateType::Noop => "",
-            SmsTemplateType::FallthroughString => "*",
+            SmsTemplateType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SmsTemplateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SMS_VERIFY_CODE" => SmsTemplateType::SmsVerifyCode,
+            "" => SmsTemplateType::Noop,
+            _ => SmsTemplateType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SmsTemplateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SmsTemplateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SmsTemplateType {
     fn default() -> SmsTemplateType {
         SmsTemplateType::Noop
@@ -8754,7 +12009,8 @@ pub struct SmsUserFactorProfile {
     pub phone_number: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TokenType {
     #[serde(rename = "ACCESS")]
     Access,
@@ -8762,8 +12018,9 @@ pub enum TokenType {
     Refresh,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TokenType {
@@ -8772,12 +12029,45 @@ impl std::fmt::Display for TokenType {
             TokenType::Access => "ACCESS",
             TokenType::Refresh => "REFRESH",
             TokenType::Noop => "",
-            TokenType::FallthroughString => "*",
+            TokenType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TokenType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACCESS" => TokenType::Access,
+            "REFRESH" => TokenType::Refresh,
+            "" => TokenType::Noop,
+            _ => TokenType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TokenType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TokenType {
     fn default() -> TokenType {
         TokenType::Noop
@@ -9257,7 +12547,8 @@ pub struct UserFactor {
     pub verify: Option<VerifyFactorRequest>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UserIdentifierConditionEvaluatorPatternMatchType {
     #[serde(rename = "CONTAINS")]
     Contains,
@@ -9271,8 +12562,9 @@ pub enum UserIdentifierConditionEvaluatorPatternMatchType {
     Suffix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UserIdentifierConditionEvaluatorPatternMatchType {
@@ -9284,12 +12576,48 @@ impl std::fmt::Display for UserIdentifierConditionEvaluatorPatternMatchType {
             UserIdentifierConditionEvaluatorPatternMatchType::StartsWith => "STARTS_WITH",
             UserIdentifierConditionEvaluatorPatternMatchType::Suffix => "SUFFIX",
             UserIdentifierConditionEvaluatorPatternMatchType::Noop => "",
-            UserIdentifierConditionEvaluatorPatternMatchType::FallthroughString => "*",
+            UserIdentifierConditionEvaluatorPatternMatchType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UserIdentifierConditionEvaluatorPatternMatchType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CONTAINS" => UserIdentifierConditionEvaluatorPatternMatchType::Contains,
+            "EQUALS" => UserIdentifierConditionEvaluatorPatternMatchType::Equals,
+            "EXPRESSION" => UserIdentifierConditionEvaluatorPatternMatchType::Expression,
+            "STARTS_WITH" => UserIdentifierConditionEvaluatorPatternMatchType::StartsWith,
+            "SUFFIX" => UserIdentifierConditionEvaluatorPatternMatchType::Suffix,
+            "" => UserIdentifierConditionEvaluatorPatternMatchType::Noop,
+            _ => UserIdentifierConditionEvaluatorPatternMatchType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UserIdentifierConditionEvaluatorPatternMatchType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserIdentifierConditionEvaluatorPatternMatchType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UserIdentifierConditionEvaluatorPatternMatchType {
     fn default() -> UserIdentifierConditionEvaluatorPatternMatchType {
         UserIdentifierConditionEvaluatorPatternMatchType::Noop
@@ -9313,7 +12641,8 @@ pub struct UserIdentifierConditionEvaluatorPattern {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UserIdentifierPolicyRuleConditionType {
     #[serde(rename = "ATTRIBUTE")]
     Attribute,
@@ -9321,8 +12650,9 @@ pub enum UserIdentifierPolicyRuleConditionType {
     Identifier,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UserIdentifierPolicyRuleConditionType {
@@ -9331,12 +12661,45 @@ impl std::fmt::Display for UserIdentifierPolicyRuleConditionType {
             UserIdentifierPolicyRuleConditionType::Attribute => "ATTRIBUTE",
             UserIdentifierPolicyRuleConditionType::Identifier => "IDENTIFIER",
             UserIdentifierPolicyRuleConditionType::Noop => "",
-            UserIdentifierPolicyRuleConditionType::FallthroughString => "*",
+            UserIdentifierPolicyRuleConditionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UserIdentifierPolicyRuleConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ATTRIBUTE" => UserIdentifierPolicyRuleConditionType::Attribute,
+            "IDENTIFIER" => UserIdentifierPolicyRuleConditionType::Identifier,
+            "" => UserIdentifierPolicyRuleConditionType::Noop,
+            _ => UserIdentifierPolicyRuleConditionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UserIdentifierPolicyRuleConditionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserIdentifierPolicyRuleConditionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UserIdentifierPolicyRuleConditionType {
     fn default() -> UserIdentifierPolicyRuleConditionType {
         UserIdentifierPolicyRuleConditionType::Noop
@@ -9395,14 +12758,16 @@ pub struct UserLifecycleAttributePolicyRuleCondition {
     pub matching_value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UserNextLogin {
     #[serde(rename = "changePassword")]
     ChangePassword,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UserNextLogin {
@@ -9410,12 +12775,44 @@ impl std::fmt::Display for UserNextLogin {
         match self {
             UserNextLogin::ChangePassword => "changePassword",
             UserNextLogin::Noop => "",
-            UserNextLogin::FallthroughString => "*",
+            UserNextLogin::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UserNextLogin {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "changePassword" => UserNextLogin::ChangePassword,
+            "" => UserNextLogin::Noop,
+            _ => UserNextLogin::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UserNextLogin {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserNextLogin {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UserNextLogin {
     fn default() -> UserNextLogin {
         UserNextLogin::Noop
@@ -9979,7 +13376,8 @@ pub struct UserSchemaAttributeMasterPriority {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UserSchemaAttributeMasterType {
     #[serde(rename = "OKTA")]
     Okta,
@@ -9989,8 +13387,9 @@ pub enum UserSchemaAttributeMasterType {
     ProfileMaster,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UserSchemaAttributeMasterType {
@@ -10000,12 +13399,46 @@ impl std::fmt::Display for UserSchemaAttributeMasterType {
             UserSchemaAttributeMasterType::Override => "OVERRIDE",
             UserSchemaAttributeMasterType::ProfileMaster => "PROFILE_MASTER",
             UserSchemaAttributeMasterType::Noop => "",
-            UserSchemaAttributeMasterType::FallthroughString => "*",
+            UserSchemaAttributeMasterType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UserSchemaAttributeMasterType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "OKTA" => UserSchemaAttributeMasterType::Okta,
+            "OVERRIDE" => UserSchemaAttributeMasterType::Override,
+            "PROFILE_MASTER" => UserSchemaAttributeMasterType::ProfileMaster,
+            "" => UserSchemaAttributeMasterType::Noop,
+            _ => UserSchemaAttributeMasterType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UserSchemaAttributeMasterType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserSchemaAttributeMasterType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UserSchemaAttributeMasterType {
     fn default() -> UserSchemaAttributeMasterType {
         UserSchemaAttributeMasterType::Noop
@@ -10033,7 +13466,8 @@ pub struct UserSchemaAttributePermission {
     pub principal: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UserSchemaAttributeType {
     #[serde(rename = "array")]
     Array,
@@ -10047,8 +13481,9 @@ pub enum UserSchemaAttributeType {
     String,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UserSchemaAttributeType {
@@ -10060,12 +13495,48 @@ impl std::fmt::Display for UserSchemaAttributeType {
             UserSchemaAttributeType::Number => "number",
             UserSchemaAttributeType::String => "string",
             UserSchemaAttributeType::Noop => "",
-            UserSchemaAttributeType::FallthroughString => "*",
+            UserSchemaAttributeType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UserSchemaAttributeType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "array" => UserSchemaAttributeType::Array,
+            "boolean" => UserSchemaAttributeType::Boolean,
+            "integer" => UserSchemaAttributeType::Integer,
+            "number" => UserSchemaAttributeType::Number,
+            "string" => UserSchemaAttributeType::String,
+            "" => UserSchemaAttributeType::Noop,
+            _ => UserSchemaAttributeType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UserSchemaAttributeType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserSchemaAttributeType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UserSchemaAttributeType {
     fn default() -> UserSchemaAttributeType {
         UserSchemaAttributeType::Noop
@@ -10285,7 +13756,8 @@ pub struct UserSchemaPublic {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UserStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -10305,8 +13777,9 @@ pub enum UserStatus {
     Suspended,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UserStatus {
@@ -10321,12 +13794,51 @@ impl std::fmt::Display for UserStatus {
             UserStatus::Staged => "STAGED",
             UserStatus::Suspended => "SUSPENDED",
             UserStatus::Noop => "",
-            UserStatus::FallthroughString => "*",
+            UserStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UserStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => UserStatus::Active,
+            "DEPROVISIONED" => UserStatus::Deprovisioned,
+            "LOCKED_OUT" => UserStatus::LockedOut,
+            "PASSWORD_EXPIRED" => UserStatus::PasswordExpired,
+            "PROVISIONED" => UserStatus::Provisioned,
+            "RECOVERY" => UserStatus::Recovery,
+            "STAGED" => UserStatus::Staged,
+            "SUSPENDED" => UserStatus::Suspended,
+            "" => UserStatus::Noop,
+            _ => UserStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UserStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UserStatus {
     fn default() -> UserStatus {
         UserStatus::Noop
@@ -10465,7 +13977,8 @@ pub struct VerifyFactorRequest {
     pub state_token: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FactorResult {
     #[serde(rename = "CHALLENGE")]
     Challenge,
@@ -10489,8 +14002,9 @@ pub enum FactorResult {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FactorResult {
@@ -10507,12 +14021,53 @@ impl std::fmt::Display for FactorResult {
             FactorResult::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
             FactorResult::Waiting => "WAITING",
             FactorResult::Noop => "",
-            FactorResult::FallthroughString => "*",
+            FactorResult::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FactorResult {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CHALLENGE" => FactorResult::Challenge,
+            "ERROR" => FactorResult::Error,
+            "EXPIRED" => FactorResult::Expired,
+            "FAILED" => FactorResult::Failed,
+            "PASSCODE_REPLAYED" => FactorResult::PasscodeReplayed,
+            "REJECTED" => FactorResult::Rejected,
+            "SUCCESS" => FactorResult::Success,
+            "TIMEOUT" => FactorResult::Timeout,
+            "TIME_WINDOW_EXCEEDED" => FactorResult::TimeWindowExceeded,
+            "WAITING" => FactorResult::Waiting,
+            "" => FactorResult::Noop,
+            _ => FactorResult::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FactorResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FactorResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FactorResult {
     fn default() -> FactorResult {
         FactorResult::Noop
@@ -51,7 +51,8 @@ pub struct DescriptionlessJobOptionsData {
     pub callback_url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Language {
     #[serde(rename = "ar")]
     Ar,
@@ -115,8 +116,9 @@ pub enum Language {
     Sv,
     #[serde(rename = "tr")]
     Tr,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Language {
@@ -153,12 +155,73 @@ impl std::fmt::Display for Language {
             Language::Sl => "sl",
             Language::Sv => "sv",
             Language::Tr => "tr",
-            Language::FallthroughString => "*",
+            Language::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Language {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ar" => Language::Ar,
+            "bg" => Language::Bg,
+            "ca" => Language::Ca,
+            "cmn" => Language::Cmn,
+            "cs" => Language::Cs,
+            "da" => Language::Da,
+            "de" => Language::De,
+            "el" => Language::El,
+            "en" => Language::En,
+            "es" => Language::Es,
+            "fi" => Language::Fi,
+            "fr" => Language::Fr,
+            "hi" => Language::Hi,
+            "hr" => Language::Hr,
+            "hu" => Language::Hu,
+            "it" => Language::It,
+            "ja" => Language::Ja,
+            "ko" => Language::Ko,
+            "lt" => Language::Lt,
+            "lv" => Language::Lv,
+            "ms" => Language::Ms,
+            "nl" => Language::Nl,
+            "no" => Language::No,
+            "pl" => Language::Pl,
+            "pt" => Language::Pt,
+            "ro" => Language::Ro,
+            "ru" => Language::Ru,
+            "sk" => Language::Sk,
+            "sl" => Language::Sl,
+            "sv" => Language::Sv,
+            "tr" => Language::Tr,
+            _ => Language::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Language {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Language {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Language {
     fn default() -> Language {
         Language::En
@@ -246,7 +309,8 @@ pub struct DescriptionlessJobOptionsAllOf {
 /**
 * Simple reason of why the transcription job failed. Check `failure_detail` for specific details and solutions
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Failure {
     #[serde(rename = "download_failure")]
     DownloadFailure,
@@ -268,8 +332,9 @@ pub enum Failure {
     Transcription,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Failure {
@@ -285,12 +350,52 @@ impl std::fmt::Display for Failure {
             Failure::InvoicingLimitExceeded => "invoicing_limit_exceeded",
             Failure::Transcription => "transcription",
             Failure::Noop => "",
-            Failure::FallthroughString => "*",
+            Failure::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Failure {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "download_failure" => Failure::DownloadFailure,
+            "duration_exceeded" => Failure::DurationExceeded,
+            "duration_too_short" => Failure::DurationTooShort,
+            "empty_media" => Failure::EmptyMedia,
+            "insufficient_balance" => Failure::InsufficientBalance,
+            "internal_processing" => Failure::InternalProcessing,
+            "invalid_media" => Failure::InvalidMedia,
+            "invoicing_limit_exceeded" => Failure::InvoicingLimitExceeded,
+            "transcription" => Failure::Transcription,
+            "" => Failure::Noop,
+            _ => Failure::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Failure {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Failure {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Failure {
     fn default() -> Failure {
         Failure::Noop
@@ -305,7 +410,8 @@ impl Failure {
 /**
 * Current status of the job
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Status {
     #[serde(rename = "failed")]
     Failed,
@@ -315,8 +421,9 @@ pub enum Status {
     Transcribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Status {
@@ -326,12 +433,46 @@ impl std::fmt::Display for Status {
             Status::InProgress => "in_progress",
             Status::Transcribed => "transcribed",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "failed" => Status::Failed,
+            "in_progress" => Status::InProgress,
+            "transcribed" => Status::Transcribed,
+            "" => Status::Noop,
+            _ => Status::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -346,14 +487,16 @@ impl Status {
 /**
 * Type of speech recognition performed. Currently the only supported values are 'async' for asynchronous jobs and `stream` for streaming jobs
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Type {
     #[serde(rename = "async")]
     Async,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Type {
@@ -361,12 +504,44 @@ impl std::fmt::Display for Type {
         match self {
             Type::Async => "async",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "async" => Type::Async,
+            "" => Type::Noop,
+            _ => Type::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -709,7 +884,8 @@ pub struct SubmitJobOptionsAllOf {
 /**
 * Type of transcript element. If Rev.ai was unable to determine the spoken word, the `type` will be `unknown`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TranscriptMonologuesElementsType {
     #[serde(rename = "punct")]
     Punct,
@@ -719,8 +895,9 @@ pub enum TranscriptMonologuesElementsType {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TranscriptMonologuesElementsType {
@@ -730,12 +907,46 @@ impl std::fmt::Display for TranscriptMonologuesElementsType {
             TranscriptMonologuesElementsType::Text => "text",
             TranscriptMonologuesElementsType::Unknown => "unknown",
             TranscriptMonologuesElementsType::Noop => "",
-            TranscriptMonologuesElementsType::FallthroughString => "*",
+            TranscriptMonologuesElementsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TranscriptMonologuesElementsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "punct" => TranscriptMonologuesElementsType::Punct,
+            "text" => TranscriptMonologuesElementsType::Text,
+            "unknown" => TranscriptMonologuesElementsType::Unknown,
+            "" => TranscriptMonologuesElementsType::Noop,
+            _ => TranscriptMonologuesElementsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TranscriptMonologuesElementsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TranscriptMonologuesElementsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TranscriptMonologuesElementsType {
     fn default() -> TranscriptMonologuesElementsType {
         TranscriptMonologuesElementsType::Noop
@@ -841,7 +1052,8 @@ pub struct Transcript {
 /**
 * MIME type specifying the caption output format
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Accept {
     #[serde(rename = "application/x-subrip")]
     ApplicationXSubrip,
@@ -849,8 +1061,9 @@ pub enum Accept {
     TextVtt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Accept {
@@ -859,12 +1072,45 @@ impl std::fmt::Display for Accept {
             Accept::ApplicationXSubrip => "application/x-subrip",
             Accept::TextVtt => "text/vtt",
             Accept::Noop => "",
-            Accept::FallthroughString => "*",
+            Accept::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Accept {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "application/x-subrip" => Accept::ApplicationXSubrip,
+            "text/vtt" => Accept::TextVtt,
+            "" => Accept::Noop,
+            _ => Accept::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Accept {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Accept {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Accept {
     fn default() -> Accept {
         Accept::Noop
@@ -879,7 +1125,8 @@ impl Accept {
 /**
 * MIME type specifying the transcription output format
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AcceptTranscript {
     #[serde(rename = "application/vnd.rev.transcript.v1.0+json")]
     ApplicationVndRevTranscript0Json,
@@ -887,8 +1134,9 @@ pub enum AcceptTranscript {
     TextPlain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AcceptTranscript {
@@ -899,12 +1147,47 @@ impl std::fmt::Display for AcceptTranscript {
             }
             AcceptTranscript::TextPlain => "text/plain",
             AcceptTranscript::Noop => "",
-            AcceptTranscript::FallthroughString => "*",
+            AcceptTranscript::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AcceptTranscript {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "application/vnd.rev.transcript.v1.0+json" => {
+                AcceptTranscript::ApplicationVndRevTranscript0Json
+            }
+            "text/plain" => AcceptTranscript::TextPlain,
+            "" => AcceptTranscript::Noop,
+            _ => AcceptTranscript::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AcceptTranscript {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AcceptTranscript {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AcceptTranscript {
     fn default() -> AcceptTranscript {
         AcceptTranscript::Noop
@@ -1 +1,83 @@
+/// Collect the body `attachment_stream` builds and parse it back.
+async fn send_body(
+    mail: &crate::types::PostMailSendRequest,
+    attachments: Vec<crate::traits::AttachmentReader>,
+) -> crate::types::PostMailSendRequest {
+    use futures::TryStreamExt;
 
+    let chunks: Vec<bytes::Bytes> = crate::traits::attachment_stream(mail, attachments)
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    serde_json::from_slice(&chunks.concat()).unwrap()
+}
+
+fn attachment(filename: &str, content: &str) -> crate::types::Attachments {
+    crate::types::Attachments {
+        content: content.to_string(),
+        content_id: String::new(),
+        disposition: None,
+        filename: filename.to_string(),
+        type_: "text/plain".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_attachment_body_round_trips() {
+    // Large enough to be encoded in several chunks.
+    let report = "x".repeat(100 * 1024);
+    let readers = || {
+        vec![
+            crate::traits::AttachmentReader::new("a.txt", "text/plain", &b"hello"[..]),
+            crate::traits::AttachmentReader::new(
+                "report.txt",
+                "text/plain",
+                std::io::Cursor::new(report.clone().into_bytes()),
+            )
+            .inline("report"),
+        ]
+    };
+    let read = vec![
+        attachment("a.txt", &base64::encode("hello")),
+        crate::types::Attachments {
+            disposition: Some(crate::types::Disposition::Inline),
+            content_id: "report".to_string(),
+            ..attachment("report.txt", &base64::encode(&report))
+        },
+    ];
+
+    // Without any other fields set.
+    let mail = crate::types::PostMailSendRequest::default();
+    let sent = send_body(&mail, readers()).await;
+    assert_eq!(sent.attachments, read);
+    assert_eq!(
+        sent,
+        crate::types::PostMailSendRequest {
+            attachments: read.clone(),
+            ..mail
+        }
+    );
+
+    // With other fields, and attachments already on the mail.
+    let mail = crate::types::PostMailSendRequest {
+        subject: "Report".to_string(),
+        categories: vec!["reports".to_string()],
+        attachments: vec![attachment("preset.txt", &base64::encode("preset"))],
+        ..Default::default()
+    };
+    let sent = send_body(&mail, readers()).await;
+    let mut all = mail.attachments.clone();
+    all.extend(read.clone());
+    assert_eq!(sent.attachments, all);
+    assert_eq!(sent.subject, "Report");
+    assert_eq!(sent.categories, vec!["reports".to_string()]);
+
+    // Attachments already on the mail are sent on their own too.
+    let sent = send_body(&mail, Vec::new()).await;
+    assert_eq!(sent, mail);
+
+    // And with nothing to attach, the mail is sent as it is.
+    let mail = crate::types::PostMailSendRequest::default();
+    assert_eq!(send_body(&mail, Vec::new()).await, mail);
+}
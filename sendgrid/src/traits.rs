@@ -15,6 +15,180 @@ pub trait MailOps {
         bcc: &[String],
         from: &str,
     ) -> Result<()>;
+
+    /// Send an email with attachments read from async readers.
+    ///
+    /// Attachments are base64-encoded while the request body is streamed, so
+    /// they never have to be held in memory. Any `attachments` already set on
+    /// `mail` are sent too. The request fails once the body grows past
+    /// `MAX_MAIL_SIZE`.
+    async fn send_with_attachments(
+        &self,
+        mail: crate::types::PostMailSendRequest,
+        attachments: Vec<AttachmentReader>,
+    ) -> Result<()>;
+}
+
+/// SendGrid rejects messages larger than 30MB, including attachments.
+pub const MAX_MAIL_SIZE: usize = 30 * 1024 * 1024;
+
+/// How many raw bytes are read from an attachment at a time.
+/// This is a multiple of 3 so every chunk encodes to base64 without padding.
+const ATTACHMENT_CHUNK_SIZE: usize = 3 * 16 * 1024;
+
+/// An attachment whose content is read from an async reader.
+pub struct AttachmentReader {
+    /// The attachment's metadata. Its `content` is ignored.
+    pub attachment: crate::types::Attachments,
+    pub reader: Box<dyn tokio::io::AsyncRead + Send + Sync + Unpin>,
+}
+
+impl AttachmentReader {
+    /// Create an attachment with the given filename and MIME type.
+    pub fn new<R>(filename: &str, type_: &str, reader: R) -> Self
+    where
+        R: tokio::io::AsyncRead + Send + Sync + Unpin + 'static,
+    {
+        AttachmentReader {
+            attachment: crate::types::Attachments {
+                content: String::new(),
+                content_id: String::new(),
+                disposition: None,
+                filename: filename.to_string(),
+                type_: type_.to_string(),
+            },
+            reader: Box::new(reader),
+        }
+    }
+
+    /// Display the attachment inline, referenced from the HTML content by `content_id`.
+    pub fn inline(mut self, content_id: &str) -> Self {
+        self.attachment.disposition = Some(crate::types::Disposition::Inline);
+        self.attachment.content_id = content_id.to_string();
+        self
+    }
+}
+
+/// Where the body stream is while sending attachments.
+enum BodyState {
+    /// The attachment at this index has not been started.
+    Start(usize),
+    /// The content of the attachment at this index is being encoded.
+    Content(usize),
+    Done,
+}
+
+/// Build the request body for `mail`, streaming each attachment's content as base64.
+fn attachment_body(
+    mail: &crate::types::PostMailSendRequest,
+    attachments: Vec<AttachmentReader>,
+) -> Result<reqwest::Body> {
+    let stream = attachment_stream(mail, attachments)?;
+    Ok(reqwest::Body::wrap_stream(stream))
+}
+
+type BodyResult<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+/// The chunks of the request body built by `attachment_body`.
+pub(crate) fn attachment_stream(
+    mail: &crate::types::PostMailSendRequest,
+    attachments: Vec<AttachmentReader>,
+) -> Result<impl futures::Stream<Item = BodyResult<bytes::Bytes>>> {
+    // Serialize everything but the attachments, and reopen the object so the
+    // attachments array can be appended. Attachments already set on `mail`
+    // come first, followed by the ones read from `attachments`.
+    let mut prefix = serde_json::to_vec(&crate::types::PostMailSendRequest {
+        attachments: Vec::new(),
+        ..mail.clone()
+    })?;
+    if prefix.pop() != Some(b'}') {
+        anyhow::bail!("mail did not serialize to a JSON object");
+    }
+    if prefix.len() > 1 {
+        prefix.push(b',');
+    }
+    prefix.extend_from_slice(b"\"attachments\":[");
+    for (i, a) in mail.attachments.iter().enumerate() {
+        if i > 0 {
+            prefix.push(b',');
+        }
+        serde_json::to_writer(&mut prefix, a)?;
+    }
+    if prefix.len() > MAX_MAIL_SIZE {
+        anyhow::bail!("mail exceeds the {} byte limit", MAX_MAIL_SIZE);
+    }
+
+    let preset = mail.attachments.len();
+    let state = (attachments, BodyState::Start(0), prefix.len(), Some(prefix));
+    Ok(futures::stream::try_unfold(
+        state,
+        move |(mut attachments, state, mut size, prefix)| async move {
+            let (chunk, next) = match state {
+                BodyState::Start(i) if i >= attachments.len() => (b"]}".to_vec(), BodyState::Done),
+                BodyState::Start(i) => {
+                    // Write the metadata first so the content can be closed with a quote.
+                    let metadata = serde_json::to_vec(&crate::types::Attachments {
+                        content: String::new(),
+                        ..attachments[i].attachment.clone()
+                    })?;
+                    let mut chunk = Vec::new();
+                    if preset + i > 0 {
+                        chunk.push(b',');
+                    }
+                    chunk.extend_from_slice(&metadata[..metadata.len() - 1]);
+                    if metadata.len() > 2 {
+                        chunk.push(b',');
+                    }
+                    chunk.extend_from_slice(b"\"content\":\"");
+                    (chunk, BodyState::Content(i))
+                }
+                BodyState::Content(i) => {
+                    let raw = read_chunk(&mut attachments[i].reader).await?;
+                    if raw.is_empty() {
+                        (b"\"}".to_vec(), BodyState::Start(i + 1))
+                    } else {
+                        (base64::encode(&raw).into_bytes(), BodyState::Content(i))
+                    }
+                }
+                BodyState::Done => return Ok(None),
+            };
+
+            size += chunk.len();
+            let chunk = match prefix {
+                Some(mut prefix) => {
+                    prefix.extend_from_slice(&chunk);
+                    prefix
+                }
+                None => chunk,
+            };
+            if size > MAX_MAIL_SIZE {
+                return Err(anyhow!("mail exceeds the {} byte limit", MAX_MAIL_SIZE).into());
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some((
+                bytes::Bytes::from(chunk),
+                (attachments, next, size, None),
+            )))
+        },
+    ))
+}
+
+/// Read up to `ATTACHMENT_CHUNK_SIZE` bytes, only returning less at the end of the reader.
+async fn read_chunk(
+    reader: &mut (dyn tokio::io::AsyncRead + Send + Sync + Unpin),
+) -> std::io::Result<Vec<u8>> {
+    use tokio::io::AsyncReadExt;
+
+    let mut buf = vec![0; ATTACHMENT_CHUNK_SIZE];
+    let mut len = 0;
+    while len < buf.len() {
+        match reader.read(&mut buf[len..]).await? {
+            0 => break,
+            n => len += n,
+        }
+    }
+    buf.truncate(len);
+    Ok(buf)
 }
 
 #[async_trait::async_trait]
@@ -77,4 +251,23 @@ impl MailOps for crate::mail_send::MailSend {
             s => Err(anyhow!("received response status: {:?}", s)),
         }
     }
+
+    /// Send an email with attachments read from async readers.
+    async fn send_with_attachments(
+        &self,
+        mail: crate::types::PostMailSendRequest,
+        attachments: Vec<AttachmentReader>,
+    ) -> Result<()> {
+        let body = attachment_body(&mail, attachments)?;
+
+        let resp = self
+            .client
+            .request_raw(reqwest::Method::POST, "/mail/send", Some(body))
+            .await?;
+
+        match resp.status() {
+            http::StatusCode::ACCEPTED => Ok(()),
+            s => Err(anyhow!("received response status: {:?}", s)),
+        }
+    }
 }
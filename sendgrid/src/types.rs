@@ -173,7 +173,8 @@ pub struct CampaignResponseAllOf {
     pub campaign_response: CampaignResponse,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Operator {
     #[serde(rename = "contains")]
     Contains,
@@ -187,8 +188,9 @@ pub enum Operator {
     Ne,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Operator {
@@ -200,12 +202,48 @@ impl std::fmt::Display for Operator {
             Operator::Lt => "lt",
             Operator::Ne => "ne",
             Operator::Noop => "",
-            Operator::FallthroughString => "*",
+            Operator::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Operator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "contains" => Operator::Contains,
+            "eq" => Operator::Eq,
+            "gt" => Operator::Gt,
+            "lt" => Operator::Lt,
+            "ne" => Operator::Ne,
+            "" => Operator::Noop,
+            _ => Operator::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Operator {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Operator {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Operator {
     fn default() -> Operator {
         Operator::Noop
@@ -217,7 +255,8 @@ impl Operator {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AndOr {
     #[serde(rename = "and")]
     And,
@@ -225,8 +264,9 @@ pub enum AndOr {
     Or,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AndOr {
@@ -235,12 +275,45 @@ impl std::fmt::Display for AndOr {
             AndOr::And => "and",
             AndOr::Or => "or",
             AndOr::Noop => "",
-            AndOr::FallthroughString => "*",
+            AndOr::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AndOr {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "and" => AndOr::And,
+            "or" => AndOr::Or,
+            "" => AndOr::Noop,
+            _ => AndOr::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AndOr {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AndOr {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AndOr {
     fn default() -> AndOr {
         AndOr::Noop
@@ -575,7 +648,8 @@ pub struct SenderAllOf {
 /**
 * The type of the field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Type {
     #[serde(rename = "date")]
     Date,
@@ -585,8 +659,9 @@ pub enum Type {
     Text,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Type {
@@ -596,12 +671,46 @@ impl std::fmt::Display for Type {
             Type::Number => "number",
             Type::Text => "text",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "date" => Type::Date,
+            "number" => Type::Number,
+            "text" => Type::Text,
+            "" => Type::Noop,
+            _ => Type::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -682,7 +791,8 @@ pub struct MailSettingsAddressWhitelabel {
 /**
 * The type of DNS record that was generated.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LinkBranding200ResponseDnsDomainCnameType {
     #[serde(rename = "cname")]
     Cname,
@@ -692,8 +802,9 @@ pub enum LinkBranding200ResponseDnsDomainCnameType {
     Txt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LinkBranding200ResponseDnsDomainCnameType {
@@ -703,12 +814,46 @@ impl std::fmt::Display for LinkBranding200ResponseDnsDomainCnameType {
             LinkBranding200ResponseDnsDomainCnameType::Mx => "mx",
             LinkBranding200ResponseDnsDomainCnameType::Txt => "txt",
             LinkBranding200ResponseDnsDomainCnameType::Noop => "",
-            LinkBranding200ResponseDnsDomainCnameType::FallthroughString => "*",
+            LinkBranding200ResponseDnsDomainCnameType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LinkBranding200ResponseDnsDomainCnameType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cname" => LinkBranding200ResponseDnsDomainCnameType::Cname,
+            "mx" => LinkBranding200ResponseDnsDomainCnameType::Mx,
+            "txt" => LinkBranding200ResponseDnsDomainCnameType::Txt,
+            "" => LinkBranding200ResponseDnsDomainCnameType::Noop,
+            _ => LinkBranding200ResponseDnsDomainCnameType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LinkBranding200ResponseDnsDomainCnameType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LinkBranding200ResponseDnsDomainCnameType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LinkBranding200ResponseDnsDomainCnameType {
     fn default() -> LinkBranding200ResponseDnsDomainCnameType {
         LinkBranding200ResponseDnsDomainCnameType::Noop
@@ -1847,7 +1992,8 @@ pub struct MailSettingsForwardSpam {
 /**
 * The editor used in the UI.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Editor {
     #[serde(rename = "code")]
     Code,
@@ -1855,8 +2001,9 @@ pub enum Editor {
     Design,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Editor {
@@ -1865,12 +2012,45 @@ impl std::fmt::Display for Editor {
             Editor::Code => "code",
             Editor::Design => "design",
             Editor::Noop => "",
-            Editor::FallthroughString => "*",
+            Editor::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Editor {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "code" => Editor::Code,
+            "design" => Editor::Design,
+            "" => Editor::Noop,
+            _ => Editor::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Editor {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Editor {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Editor {
     fn default() -> Editor {
         Editor::Noop
@@ -2125,7 +2305,8 @@ pub struct SubuserStatsData {
 /**
 * The status of the scheduled send.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Status {
     #[serde(rename = "cancel")]
     Cancel,
@@ -2133,8 +2314,9 @@ pub enum Status {
     Pause,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Status {
@@ -2143,12 +2325,45 @@ impl std::fmt::Display for Status {
             Status::Cancel => "cancel",
             Status::Pause => "pause",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cancel" => Status::Cancel,
+            "pause" => Status::Pause,
+            "" => Status::Noop,
+            _ => Status::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -2608,7 +2823,8 @@ pub struct TransactionalTemplatesVersionOutputLean {
 /**
 * Defines the generation of the template.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Generation {
     #[serde(rename = "dynamic")]
     Dynamic,
@@ -2616,8 +2832,9 @@ pub enum Generation {
     Legacy,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Generation {
@@ -2626,12 +2843,45 @@ impl std::fmt::Display for Generation {
             Generation::Dynamic => "dynamic",
             Generation::Legacy => "legacy",
             Generation::Noop => "",
-            Generation::FallthroughString => "*",
+            Generation::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Generation {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "dynamic" => Generation::Dynamic,
+            "legacy" => Generation::Legacy,
+            "" => Generation::Noop,
+            _ => Generation::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Generation {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Generation {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Generation {
     fn default() -> Generation {
         Generation::Noop
@@ -3020,7 +3270,8 @@ pub struct SingleContactRequest {
 /**
 * The export job's status. Allowed values: `pending`, `ready`, or `failure`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ContactExportStatus {
     #[serde(rename = "failure")]
     Failure,
@@ -3030,8 +3281,9 @@ pub enum ContactExportStatus {
     Ready,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ContactExportStatus {
@@ -3041,12 +3293,46 @@ impl std::fmt::Display for ContactExportStatus {
             ContactExportStatus::Pending => "pending",
             ContactExportStatus::Ready => "ready",
             ContactExportStatus::Noop => "",
-            ContactExportStatus::FallthroughString => "*",
+            ContactExportStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ContactExportStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "failure" => ContactExportStatus::Failure,
+            "pending" => ContactExportStatus::Pending,
+            "ready" => ContactExportStatus::Ready,
+            "" => ContactExportStatus::Noop,
+            _ => ContactExportStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ContactExportStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ContactExportStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ContactExportStatus {
     fn default() -> ContactExportStatus {
         ContactExportStatus::Noop
@@ -3525,7 +3811,8 @@ pub struct List {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FieldType {
     #[serde(rename = "Date")]
     Date,
@@ -3535,8 +3822,9 @@ pub enum FieldType {
     Text,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FieldType {
@@ -3546,12 +3834,46 @@ impl std::fmt::Display for FieldType {
             FieldType::Number => "Number",
             FieldType::Text => "Text",
             FieldType::Noop => "",
-            FieldType::FallthroughString => "*",
+            FieldType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FieldType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Date" => FieldType::Date,
+            "Number" => FieldType::Number,
+            "Text" => FieldType::Text,
+            "" => FieldType::Noop,
+            _ => FieldType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FieldType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FieldType {
     fn default() -> FieldType {
         FieldType::Noop
@@ -4450,7 +4772,8 @@ pub struct ErrorsDataType {
 /**
 * This is the A/B phase of the Single Send stat returned. If the `group_by` parameter doesn't include `ab_phase` in the request, then the value is "all".
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AbPhase {
     #[serde(rename = "all")]
     All,
@@ -4458,8 +4781,9 @@ pub enum AbPhase {
     Send,
     #[serde(rename = "test")]
     Test,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AbPhase {
@@ -4468,12 +4792,45 @@ impl std::fmt::Display for AbPhase {
             AbPhase::All => "all",
             AbPhase::Send => "send",
             AbPhase::Test => "test",
-            AbPhase::FallthroughString => "*",
+            AbPhase::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AbPhase {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => AbPhase::All,
+            "send" => AbPhase::Send,
+            "test" => AbPhase::Test,
+            _ => AbPhase::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AbPhase {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AbPhase {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AbPhase {
     fn default() -> AbPhase {
         AbPhase::All
@@ -4651,7 +5008,8 @@ pub struct MetricsData {
     pub unsubscribes: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SinglesendSearchStatus {
     #[serde(rename = "draft")]
     Draft,
@@ -4661,8 +5019,9 @@ pub enum SinglesendSearchStatus {
     Triggered,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SinglesendSearchStatus {
@@ -4672,12 +5031,46 @@ impl std::fmt::Display for SinglesendSearchStatus {
             SinglesendSearchStatus::Scheduled => "scheduled",
             SinglesendSearchStatus::Triggered => "triggered",
             SinglesendSearchStatus::Noop => "",
-            SinglesendSearchStatus::FallthroughString => "*",
+            SinglesendSearchStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SinglesendSearchStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "draft" => SinglesendSearchStatus::Draft,
+            "scheduled" => SinglesendSearchStatus::Scheduled,
+            "triggered" => SinglesendSearchStatus::Triggered,
+            "" => SinglesendSearchStatus::Noop,
+            _ => SinglesendSearchStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SinglesendSearchStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SinglesendSearchStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SinglesendSearchStatus {
     fn default() -> SinglesendSearchStatus {
         SinglesendSearchStatus::Noop
@@ -5318,7 +5711,8 @@ pub struct DomainAuthentication200ResponseAllOf {
 /**
 * What differs between the A/B tests
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AbTestSummaryType {
     #[serde(rename = "content")]
     Content,
@@ -5326,8 +5720,9 @@ pub enum AbTestSummaryType {
     Subject,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AbTestSummaryType {
@@ -5336,12 +5731,45 @@ impl std::fmt::Display for AbTestSummaryType {
             AbTestSummaryType::Content => "content",
             AbTestSummaryType::Subject => "subject",
             AbTestSummaryType::Noop => "",
-            AbTestSummaryType::FallthroughString => "*",
+            AbTestSummaryType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AbTestSummaryType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "content" => AbTestSummaryType::Content,
+            "subject" => AbTestSummaryType::Subject,
+            "" => AbTestSummaryType::Noop,
+            _ => AbTestSummaryType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AbTestSummaryType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AbTestSummaryType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AbTestSummaryType {
     fn default() -> AbTestSummaryType {
         AbTestSummaryType::Noop
@@ -5356,7 +5784,8 @@ impl AbTestSummaryType {
 /**
 * How the winner will be decided
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WinnerCriteria {
     #[serde(rename = "click")]
     Click,
@@ -5366,8 +5795,9 @@ pub enum WinnerCriteria {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WinnerCriteria {
@@ -5377,12 +5807,46 @@ impl std::fmt::Display for WinnerCriteria {
             WinnerCriteria::Manual => "manual",
             WinnerCriteria::Open => "open",
             WinnerCriteria::Noop => "",
-            WinnerCriteria::FallthroughString => "*",
+            WinnerCriteria::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WinnerCriteria {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "click" => WinnerCriteria::Click,
+            "manual" => WinnerCriteria::Manual,
+            "open" => WinnerCriteria::Open,
+            "" => WinnerCriteria::Noop,
+            _ => WinnerCriteria::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WinnerCriteria {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WinnerCriteria {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WinnerCriteria {
     fn default() -> WinnerCriteria {
         WinnerCriteria::Noop
@@ -6105,7 +6569,8 @@ pub struct InvalidEmail {
 /**
 * The message's status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EmailActivityResponseCommonFieldsStatus {
     #[serde(rename = "delivered")]
     Delivered,
@@ -6115,8 +6580,9 @@ pub enum EmailActivityResponseCommonFieldsStatus {
     Processed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EmailActivityResponseCommonFieldsStatus {
@@ -6126,12 +6592,46 @@ impl std::fmt::Display for EmailActivityResponseCommonFieldsStatus {
             EmailActivityResponseCommonFieldsStatus::NotDelivered => "not delivered",
             EmailActivityResponseCommonFieldsStatus::Processed => "processed",
             EmailActivityResponseCommonFieldsStatus::Noop => "",
-            EmailActivityResponseCommonFieldsStatus::FallthroughString => "*",
+            EmailActivityResponseCommonFieldsStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EmailActivityResponseCommonFieldsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "delivered" => EmailActivityResponseCommonFieldsStatus::Delivered,
+            "not delivered" => EmailActivityResponseCommonFieldsStatus::NotDelivered,
+            "processed" => EmailActivityResponseCommonFieldsStatus::Processed,
+            "" => EmailActivityResponseCommonFieldsStatus::Noop,
+            _ => EmailActivityResponseCommonFieldsStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EmailActivityResponseCommonFieldsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailActivityResponseCommonFieldsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EmailActivityResponseCommonFieldsStatus {
     fn default() -> EmailActivityResponseCommonFieldsStatus {
         EmailActivityResponseCommonFieldsStatus::Noop
@@ -6404,7 +6904,8 @@ pub struct SsoTeammateRequestAllOf {
 /**
 * A Teammate can be an “admin,” “owner,” or “teammate.” Each role is associated with the scope of the Teammate’s permissions.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UserType {
     #[serde(rename = "admin")]
     Admin,
@@ -6414,8 +6915,9 @@ pub enum UserType {
     Teammate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UserType {
@@ -6425,12 +6927,46 @@ impl std::fmt::Display for UserType {
             UserType::Owner => "owner",
             UserType::Teammate => "teammate",
             UserType::Noop => "",
-            UserType::FallthroughString => "*",
+            UserType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UserType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => UserType::Admin,
+            "owner" => UserType::Owner,
+            "teammate" => UserType::Teammate,
+            "" => UserType::Noop,
+            _ => UserType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UserType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UserType {
     fn default() -> UserType {
         UserType::Noop
@@ -7108,7 +7644,8 @@ pub struct SegmentUpdate {
     pub query_dsl: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AbbvMessageStatus {
     #[serde(rename = "delivered")]
     Delivered,
@@ -7118,8 +7655,9 @@ pub enum AbbvMessageStatus {
     Processed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AbbvMessageStatus {
@@ -7129,12 +7667,46 @@ impl std::fmt::Display for AbbvMessageStatus {
             AbbvMessageStatus::NotDelivered => "not_delivered",
             AbbvMessageStatus::Processed => "processed",
             AbbvMessageStatus::Noop => "",
-            AbbvMessageStatus::FallthroughString => "*",
+            AbbvMessageStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AbbvMessageStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "delivered" => AbbvMessageStatus::Delivered,
+            "not_delivered" => AbbvMessageStatus::NotDelivered,
+            "processed" => AbbvMessageStatus::Processed,
+            "" => AbbvMessageStatus::Noop,
+            _ => AbbvMessageStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AbbvMessageStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AbbvMessageStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AbbvMessageStatus {
     fn default() -> AbbvMessageStatus {
         AbbvMessageStatus::Noop
@@ -7212,7 +7784,8 @@ pub struct Messages {
 /**
 * Name of event
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EventName {
     #[serde(rename = "bounced")]
     Bounced,
@@ -7238,8 +7811,9 @@ pub enum EventName {
     Unsubscribe,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EventName {
@@ -7257,12 +7831,54 @@ impl std::fmt::Display for EventName {
             EventName::SpamReport => "spam_report",
             EventName::Unsubscribe => "unsubscribe",
             EventName::Noop => "",
-            EventName::FallthroughString => "*",
+            EventName::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EventName {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "bounced" => EventName::Bounced,
+            "clicked" => EventName::Clicked,
+            "deferred" => EventName::Deferred,
+            "delivered" => EventName::Delivered,
+            "dropped" => EventName::Dropped,
+            "group_resubscribe" => EventName::GroupResubscribe,
+            "group_unsubscribe" => EventName::GroupUnsubscribe,
+            "opened" => EventName::Opened,
+            "processed" => EventName::Processed,
+            "spam_report" => EventName::SpamReport,
+            "unsubscribe" => EventName::Unsubscribe,
+            "" => EventName::Noop,
+            _ => EventName::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EventName {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EventName {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EventName {
     fn default() -> EventName {
         EventName::Noop
@@ -7277,7 +7893,8 @@ impl EventName {
 /**
 * Use to distinguish between types of bounces
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BounceType {
     #[serde(rename = "blocked")]
     Blocked,
@@ -7287,8 +7904,9 @@ pub enum BounceType {
     Expired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BounceType {
@@ -7298,12 +7916,46 @@ impl std::fmt::Display for BounceType {
             BounceType::Bounced => "bounced",
             BounceType::Expired => "expired",
             BounceType::Noop => "",
-            BounceType::FallthroughString => "*",
+            BounceType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BounceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "blocked" => BounceType::Blocked,
+            "bounced" => BounceType::Bounced,
+            "expired" => BounceType::Expired,
+            "" => BounceType::Noop,
+            _ => BounceType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BounceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BounceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BounceType {
     fn default() -> BounceType {
         BounceType::Noop
@@ -7380,7 +8032,8 @@ pub struct Event {
 /**
 * Whether or not the outbound IP is dedicated vs shared
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OutboundIpType {
     #[serde(rename = "dedicated")]
     Dedicated,
@@ -7388,8 +8041,9 @@ pub enum OutboundIpType {
     Shared,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OutboundIpType {
@@ -7398,12 +8052,45 @@ impl std::fmt::Display for OutboundIpType {
             OutboundIpType::Dedicated => "dedicated",
             OutboundIpType::Shared => "shared",
             OutboundIpType::Noop => "",
-            OutboundIpType::FallthroughString => "*",
+            OutboundIpType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OutboundIpType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "dedicated" => OutboundIpType::Dedicated,
+            "shared" => OutboundIpType::Shared,
+            "" => OutboundIpType::Noop,
+            _ => OutboundIpType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OutboundIpType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OutboundIpType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OutboundIpType {
     fn default() -> OutboundIpType {
         OutboundIpType::Noop
@@ -7783,14 +8470,16 @@ pub struct AuthenticationDomain {
 /**
 * Dictates how the stats are time-sliced. Currently, `"total"` and `"day"` are supported.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AggregatedBy {
     #[serde(rename = "day")]
     Day,
     #[serde(rename = "total")]
     Total,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AggregatedBy {
@@ -7798,19 +8487,52 @@ impl std::fmt::Display for AggregatedBy {
         match self {
             AggregatedBy::Day => "day",
             AggregatedBy::Total => "total",
-            AggregatedBy::FallthroughString => "*",
+            AggregatedBy::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AggregatedBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "day" => AggregatedBy::Day,
+            "total" => AggregatedBy::Total,
+            _ => AggregatedBy::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AggregatedBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AggregatedBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AggregatedBy {
     fn default() -> AggregatedBy {
         AggregatedBy::Total
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GroupBy {
     #[serde(rename = "ab_phase")]
     AbPhase,
@@ -7818,8 +8540,9 @@ pub enum GroupBy {
     AbVariation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GroupBy {
@@ -7828,12 +8551,45 @@ impl std::fmt::Display for GroupBy {
             GroupBy::AbPhase => "ab_phase",
             GroupBy::AbVariation => "ab_variation",
             GroupBy::Noop => "",
-            GroupBy::FallthroughString => "*",
+            GroupBy::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GroupBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ab_phase" => GroupBy::AbPhase,
+            "ab_variation" => GroupBy::AbVariation,
+            "" => GroupBy::Noop,
+            _ => GroupBy::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GroupBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GroupBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GroupBy {
     fn default() -> GroupBy {
         GroupBy::Noop
@@ -7845,14 +8601,16 @@ impl GroupBy {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TraitAutomationQueryParamsGroupBy {
     #[serde(rename = "step_id")]
     StepId,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TraitAutomationQueryParamsGroupBy {
@@ -7860,12 +8618,44 @@ impl std::fmt::Display for TraitAutomationQueryParamsGroupBy {
         match self {
             TraitAutomationQueryParamsGroupBy::StepId => "step_id",
             TraitAutomationQueryParamsGroupBy::Noop => "",
-            TraitAutomationQueryParamsGroupBy::FallthroughString => "*",
+            TraitAutomationQueryParamsGroupBy::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TraitAutomationQueryParamsGroupBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "step_id" => TraitAutomationQueryParamsGroupBy::StepId,
+            "" => TraitAutomationQueryParamsGroupBy::Noop,
+            _ => TraitAutomationQueryParamsGroupBy::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TraitAutomationQueryParamsGroupBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TraitAutomationQueryParamsGroupBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TraitAutomationQueryParamsGroupBy {
     fn default() -> TraitAutomationQueryParamsGroupBy {
         TraitAutomationQueryParamsGroupBy::Noop
@@ -7877,7 +8667,8 @@ impl TraitAutomationQueryParamsGroupBy {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AbPhaseId {
     #[serde(rename = "send")]
     Send,
@@ -7885,8 +8676,9 @@ pub enum AbPhaseId {
     Test,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AbPhaseId {
@@ -7895,12 +8687,45 @@ impl std::fmt::Display for AbPhaseId {
             AbPhaseId::Send => "send",
             AbPhaseId::Test => "test",
             AbPhaseId::Noop => "",
-            AbPhaseId::FallthroughString => "*",
+            AbPhaseId::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AbPhaseId {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "send" => AbPhaseId::Send,
+            "test" => AbPhaseId::Test,
+            "" => AbPhaseId::Noop,
+            _ => AbPhaseId::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AbPhaseId {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AbPhaseId {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AbPhaseId {
     fn default() -> AbPhaseId {
         AbPhaseId::Noop
@@ -7915,7 +8740,8 @@ impl AbPhaseId {
 /**
 * How to group the statistics. Must be either "day", "week", or "month".
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TraitStatsAdvancedBaseQueryStringsAggregatedBy {
     #[serde(rename = "day")]
     Day,
@@ -7925,8 +8751,9 @@ pub enum TraitStatsAdvancedBaseQueryStringsAggregatedBy {
     Week,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
@@ -7936,12 +8763,46 @@ impl std::fmt::Display for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Month => "month",
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Week => "week",
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop => "",
-            TraitStatsAdvancedBaseQueryStringsAggregatedBy::FallthroughString => "*",
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "day" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Day,
+            "month" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Month,
+            "week" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Week,
+            "" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop,
+            _ => TraitStatsAdvancedBaseQueryStringsAggregatedBy::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
     fn default() -> TraitStatsAdvancedBaseQueryStringsAggregatedBy {
         TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop
@@ -8169,14 +9030,16 @@ pub struct Content {
 /**
 * The attachment's content-disposition, specifying how you would like the attachment to be displayed. For example, `“inline”` results in the attached file are displayed automatically within the message while `“attachment”` results in the attached file require some action to be taken before it is displayed, such as opening or downloading the file.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Disposition {
     #[serde(rename = "attachment")]
     Attachment,
     #[serde(rename = "inline")]
     Inline,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Disposition {
@@ -8184,12 +9047,44 @@ impl std::fmt::Display for Disposition {
         match self {
             Disposition::Attachment => "attachment",
             Disposition::Inline => "inline",
-            Disposition::FallthroughString => "*",
+            Disposition::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Disposition {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "attachment" => Disposition::Attachment,
+            "inline" => Disposition::Inline,
+            _ => Disposition::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Disposition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Disposition {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Disposition {
     fn default() -> Disposition {
         Disposition::Attachment
@@ -9598,7 +10493,8 @@ pub struct PatchScopesRequestsApproveResponse {
 /**
 * The type of alert.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetAlertsResponseType {
     #[serde(rename = "stats_notification")]
     StatsNotification,
@@ -9606,8 +10502,9 @@ pub enum GetAlertsResponseType {
     UsageLimit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetAlertsResponseType {
@@ -9616,12 +10513,45 @@ impl std::fmt::Display for GetAlertsResponseType {
             GetAlertsResponseType::StatsNotification => "stats_notification",
             GetAlertsResponseType::UsageLimit => "usage_limit",
             GetAlertsResponseType::Noop => "",
-            GetAlertsResponseType::FallthroughString => "*",
+            GetAlertsResponseType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetAlertsResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "stats_notification" => GetAlertsResponseType::StatsNotification,
+            "usage_limit" => GetAlertsResponseType::UsageLimit,
+            "" => GetAlertsResponseType::Noop,
+            _ => GetAlertsResponseType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetAlertsResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAlertsResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetAlertsResponseType {
     fn default() -> GetAlertsResponseType {
         GetAlertsResponseType::Noop
@@ -9781,7 +10711,8 @@ pub struct PostAlertsResponse {
 /**
 * The type of alert.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetAlertsAlertResponseType {
     #[serde(rename = "stats_notification")]
     StatsNotification,
@@ -9789,8 +10720,9 @@ pub enum GetAlertsAlertResponseType {
     UsageAlert,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetAlertsAlertResponseType {
@@ -9799,12 +10731,45 @@ impl std::fmt::Display for GetAlertsAlertResponseType {
             GetAlertsAlertResponseType::StatsNotification => "stats_notification",
             GetAlertsAlertResponseType::UsageAlert => "usage_alert",
             GetAlertsAlertResponseType::Noop => "",
-            GetAlertsAlertResponseType::FallthroughString => "*",
+            GetAlertsAlertResponseType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetAlertsAlertResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "stats_notification" => GetAlertsAlertResponseType::StatsNotification,
+            "usage_alert" => GetAlertsAlertResponseType::UsageAlert,
+            "" => GetAlertsAlertResponseType::Noop,
+            _ => GetAlertsAlertResponseType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetAlertsAlertResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAlertsAlertResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetAlertsAlertResponseType {
     fn default() -> GetAlertsAlertResponseType {
         GetAlertsAlertResponseType::Noop
@@ -9997,7 +10962,8 @@ pub struct GetUserProfileResponse {
 /**
 * The type of account for this user.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetUserAccountResponseType {
     #[serde(rename = "free")]
     Free,
@@ -10005,8 +10971,9 @@ pub enum GetUserAccountResponseType {
     Paid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetUserAccountResponseType {
@@ -10015,12 +10982,45 @@ impl std::fmt::Display for GetUserAccountResponseType {
             GetUserAccountResponseType::Free => "free",
             GetUserAccountResponseType::Paid => "paid",
             GetUserAccountResponseType::Noop => "",
-            GetUserAccountResponseType::FallthroughString => "*",
+            GetUserAccountResponseType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetUserAccountResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "free" => GetUserAccountResponseType::Free,
+            "paid" => GetUserAccountResponseType::Paid,
+            "" => GetUserAccountResponseType::Noop,
+            _ => GetUserAccountResponseType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetUserAccountResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetUserAccountResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetUserAccountResponseType {
     fn default() -> GetUserAccountResponseType {
         GetUserAccountResponseType::Noop
@@ -10261,14 +11261,16 @@ pub struct PutSubusersSubuserNameIpsResponse {
 /**
 * The direction you want to sort.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SortByDirection {
     #[serde(rename = "asc")]
     Asc,
     #[serde(rename = "desc")]
     Desc,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SortByDirection {
@@ -10276,12 +11278,44 @@ impl std::fmt::Display for SortByDirection {
         match self {
             SortByDirection::Asc => "asc",
             SortByDirection::Desc => "desc",
-            SortByDirection::FallthroughString => "*",
+            SortByDirection::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SortByDirection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "asc" => SortByDirection::Asc,
+            "desc" => SortByDirection::Desc,
+            _ => SortByDirection::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SortByDirection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SortByDirection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SortByDirection {
     fn default() -> SortByDirection {
         SortByDirection::Desc
@@ -10291,7 +11325,8 @@ impl Default for SortByDirection {
 /**
 * The metric that you want to sort by. Metrics that you can sort by are: `blocks`, `bounces`, `clicks`, `delivered`, `opens`, `requests`, `unique_clicks`, `unique_opens`, and `unsubscribes`.'
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SortByMetric {
     #[serde(rename = "blocks")]
     Blocks,
@@ -10311,8 +11346,9 @@ pub enum SortByMetric {
     UniqueOpens,
     #[serde(rename = "unsubscribes")]
     Unsubscribes,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SortByMetric {
@@ -10327,12 +11363,51 @@ impl std::fmt::Display for SortByMetric {
             SortByMetric::UniqueClicks => "unique_clicks",
             SortByMetric::UniqueOpens => "unique_opens",
             SortByMetric::Unsubscribes => "unsubscribes",
-            SortByMetric::FallthroughString => "*",
+            SortByMetric::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SortByMetric {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "blocks" => SortByMetric::Blocks,
+            "bounces" => SortByMetric::Bounces,
+            "clicks" => SortByMetric::Clicks,
+            "delivered" => SortByMetric::Delivered,
+            "opens" => SortByMetric::Opens,
+            "requests" => SortByMetric::Requests,
+            "unique_clicks" => SortByMetric::UniqueClicks,
+            "unique_opens" => SortByMetric::UniqueOpens,
+            "unsubscribes" => SortByMetric::Unsubscribes,
+            _ => SortByMetric::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SortByMetric {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SortByMetric {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SortByMetric {
     fn default() -> SortByMetric {
         SortByMetric::Delivered
@@ -10583,7 +11658,8 @@ pub struct PostValidationsEmailRequest {
 /**
 * A generic classification of whether or not the email address is valid.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Verdict {
     #[serde(rename = "Invalid")]
     Invalid,
@@ -10593,8 +11669,9 @@ pub enum Verdict {
     Valid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Verdict {
@@ -10604,12 +11681,46 @@ impl std::fmt::Display for Verdict {
             Verdict::Risky => "Risky",
             Verdict::Valid => "Valid",
             Verdict::Noop => "",
-            Verdict::FallthroughString => "*",
+            Verdict::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Verdict {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Invalid" => Verdict::Invalid,
+            "Risky" => Verdict::Risky,
+            "Valid" => Verdict::Valid,
+            "" => Verdict::Noop,
+            _ => Verdict::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Verdict {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Verdict {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Verdict {
     fn default() -> Verdict {
         Verdict::Noop
@@ -11866,14 +12977,16 @@ pub struct Notifications {
 /**
 * File type for export file. Choose from `json` or `csv`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FileType {
     #[serde(rename = "csv")]
     Csv,
     #[serde(rename = "json")]
     Json,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -11881,12 +12994,44 @@ impl std::fmt::Display for FileType {
         match self {
             FileType::Csv => "csv",
             FileType::Json => "json",
-            FileType::FallthroughString => "*",
+            FileType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FileType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "csv" => FileType::Csv,
+            "json" => FileType::Json,
+            _ => FileType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FileType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FileType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FileType {
     fn default() -> FileType {
         FileType::Csv
@@ -11980,14 +13125,16 @@ pub struct PostMcContactsSearchResponse {
 /**
 * Upload file type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PutMcContactsImportsRequestFileType {
     #[serde(rename = "csv")]
     Csv,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PutMcContactsImportsRequestFileType {
@@ -11995,12 +13142,44 @@ impl std::fmt::Display for PutMcContactsImportsRequestFileType {
         match self {
             PutMcContactsImportsRequestFileType::Csv => "csv",
             PutMcContactsImportsRequestFileType::Noop => "",
-            PutMcContactsImportsRequestFileType::FallthroughString => "*",
+            PutMcContactsImportsRequestFileType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PutMcContactsImportsRequestFileType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "csv" => PutMcContactsImportsRequestFileType::Csv,
+            "" => PutMcContactsImportsRequestFileType::Noop,
+            _ => PutMcContactsImportsRequestFileType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PutMcContactsImportsRequestFileType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PutMcContactsImportsRequestFileType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PutMcContactsImportsRequestFileType {
     fn default() -> PutMcContactsImportsRequestFileType {
         PutMcContactsImportsRequestFileType::Noop
@@ -12518,14 +13697,16 @@ pub struct PostMarketingSinglesendsRequest {
 /**
 * This is the ISO 8601 time at which to send the Single Send; must be in future, or the string "now"
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SendAt {
     #[serde(rename = "now")]
     Now,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SendAt {
@@ -12533,12 +13714,44 @@ impl std::fmt::Display for SendAt {
         match self {
             SendAt::Now => "now",
             SendAt::Noop => "",
-            SendAt::FallthroughString => "*",
+            SendAt::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SendAt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "now" => SendAt::Now,
+            "" => SendAt::Noop,
+            _ => SendAt::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SendAt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SendAt {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SendAt {
     fn default() -> SendAt {
         SendAt::Noop
@@ -12559,14 +13772,16 @@ pub struct PutMarketingSinglesendsScheduleRequest {
     pub send_at: SendAt,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PutMarketingSinglesendsScheduleResponseStatus {
     #[serde(rename = "scheduled")]
     Scheduled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PutMarketingSinglesendsScheduleResponseStatus {
@@ -12574,12 +13789,44 @@ impl std::fmt::Display for PutMarketingSinglesendsScheduleResponseStatus {
         match self {
             PutMarketingSinglesendsScheduleResponseStatus::Scheduled => "scheduled",
             PutMarketingSinglesendsScheduleResponseStatus::Noop => "",
-            PutMarketingSinglesendsScheduleResponseStatus::FallthroughString => "*",
+            PutMarketingSinglesendsScheduleResponseStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PutMarketingSinglesendsScheduleResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "scheduled" => PutMarketingSinglesendsScheduleResponseStatus::Scheduled,
+            "" => PutMarketingSinglesendsScheduleResponseStatus::Noop,
+            _ => PutMarketingSinglesendsScheduleResponseStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PutMarketingSinglesendsScheduleResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PutMarketingSinglesendsScheduleResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PutMarketingSinglesendsScheduleResponseStatus {
     fn default() -> PutMarketingSinglesendsScheduleResponseStatus {
         PutMarketingSinglesendsScheduleResponseStatus::Noop
@@ -13350,14 +14597,16 @@ pub struct ScheduleACampaignRequest {
 /**
 * The status of your campaign.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ScheduleACampaignResponseStatus {
     #[serde(rename = "Scheduled")]
     Scheduled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ScheduleACampaignResponseStatus {
@@ -13365,12 +14614,44 @@ impl std::fmt::Display for ScheduleACampaignResponseStatus {
         match self {
             ScheduleACampaignResponseStatus::Scheduled => "Scheduled",
             ScheduleACampaignResponseStatus::Noop => "",
-            ScheduleACampaignResponseStatus::FallthroughString => "*",
+            ScheduleACampaignResponseStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ScheduleACampaignResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Scheduled" => ScheduleACampaignResponseStatus::Scheduled,
+            "" => ScheduleACampaignResponseStatus::Noop,
+            _ => ScheduleACampaignResponseStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ScheduleACampaignResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScheduleACampaignResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ScheduleACampaignResponseStatus {
     fn default() -> ScheduleACampaignResponseStatus {
         ScheduleACampaignResponseStatus::Noop
@@ -13447,7 +14728,8 @@ pub struct SendATestCampaignRequest {
 /**
 * Comma-delimited list specifying which generations of templates to return. Options are `legacy`, `dynamic` or `legacy,dynamic`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Generations {
     #[serde(rename = "dynamic")]
     Dynamic,
@@ -13455,8 +14737,9 @@ pub enum Generations {
     Legacy,
     #[serde(rename = "legacy,dynamic")]
     LegacyDynamic,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Generations {
@@ -13465,12 +14748,45 @@ impl std::fmt::Display for Generations {
             Generations::Dynamic => "dynamic",
             Generations::Legacy => "legacy",
             Generations::LegacyDynamic => "legacy,dynamic",
-            Generations::FallthroughString => "*",
+            Generations::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Generations {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "dynamic" => Generations::Dynamic,
+            "legacy" => Generations::Legacy,
+            "legacy,dynamic" => Generations::LegacyDynamic,
+            _ => Generations::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Generations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Generations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Generations {
     fn default() -> Generations {
         Generations::Legacy
@@ -13708,14 +15024,16 @@ pub struct GetMessagesResponse {
     pub messages: Vec<Messages>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PostMessagesDownloadResponseStatus {
     #[serde(rename = "pending")]
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PostMessagesDownloadResponseStatus {
@@ -13723,12 +15041,44 @@ impl std::fmt::Display for PostMessagesDownloadResponseStatus {
         match self {
             PostMessagesDownloadResponseStatus::Pending => "pending",
             PostMessagesDownloadResponseStatus::Noop => "",
-            PostMessagesDownloadResponseStatus::FallthroughString => "*",
+            PostMessagesDownloadResponseStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PostMessagesDownloadResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "pending" => PostMessagesDownloadResponseStatus::Pending,
+            "" => PostMessagesDownloadResponseStatus::Noop,
+            _ => PostMessagesDownloadResponseStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PostMessagesDownloadResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PostMessagesDownloadResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PostMessagesDownloadResponseStatus {
     fn default() -> PostMessagesDownloadResponseStatus {
         PostMessagesDownloadResponseStatus::Noop
@@ -13866,7 +15216,8 @@ pub struct GetStatsResponseData {
 /**
 * The country you would like to see statistics for. Currently only supported for US and CA.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Country {
     #[serde(rename = "CA")]
     Ca,
@@ -13874,8 +15225,9 @@ pub enum Country {
     Us,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Country {
@@ -13884,12 +15236,45 @@ impl std::fmt::Display for Country {
             Country::Ca => "CA",
             Country::Us => "US",
             Country::Noop => "",
-            Country::FallthroughString => "*",
+            Country::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Country {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CA" => Country::Ca,
+            "US" => Country::Us,
+            "" => Country::Noop,
+            _ => Country::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Country {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Country {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Country {
     fn default() -> Country {
         Country::Noop
@@ -14001,7 +15386,8 @@ pub struct GetClientsStatsResponse {
 /**
 * Specifies the type of client to retrieve stats for. Must be either "phone", "tablet", "webmail", or "desktop".
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ClientType {
     #[serde(rename = "desktop")]
     Desktop,
@@ -14013,8 +15399,9 @@ pub enum ClientType {
     Webmail,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ClientType {
@@ -14025,12 +15412,47 @@ impl std::fmt::Display for ClientType {
             ClientType::Tablet => "tablet",
             ClientType::Webmail => "webmail",
             ClientType::Noop => "",
-            ClientType::FallthroughString => "*",
+            ClientType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ClientType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "desktop" => ClientType::Desktop,
+            "phone" => ClientType::Phone,
+            "tablet" => ClientType::Tablet,
+            "webmail" => ClientType::Webmail,
+            "" => ClientType::Noop,
+            _ => ClientType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ClientType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ClientType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ClientType {
     fn default() -> ClientType {
         ClientType::Noop
@@ -231,7 +231,8 @@ pub struct InventoryLotQuantity {
 /**
 * Attribute influencing the packaging requirements of this inventory item
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PackagingAttribute {
     #[serde(rename = "Book")]
     Book,
@@ -253,8 +254,9 @@ pub enum PackagingAttribute {
     Stackable,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PackagingAttribute {
@@ -270,12 +272,52 @@ impl std::fmt::Display for PackagingAttribute {
             PackagingAttribute::Poster => "Poster",
             PackagingAttribute::Stackable => "Stackable",
             PackagingAttribute::Noop => "",
-            PackagingAttribute::FallthroughString => "*",
+            PackagingAttribute::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PackagingAttribute {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Book" => PackagingAttribute::Book,
+            "CustomDunnage" => PackagingAttribute::CustomDunnage,
+            "CustomPackaging" => PackagingAttribute::CustomPackaging,
+            "Foldable" => PackagingAttribute::Foldable,
+            "Fragile" => PackagingAttribute::Fragile,
+            "MarketingInsert" => PackagingAttribute::MarketingInsert,
+            "None" => PackagingAttribute::None,
+            "Poster" => PackagingAttribute::Poster,
+            "Stackable" => PackagingAttribute::Stackable,
+            "" => PackagingAttribute::Noop,
+            _ => PackagingAttribute::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PackagingAttribute {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PackagingAttribute {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PackagingAttribute {
     fn default() -> PackagingAttribute {
         PackagingAttribute::Noop
@@ -657,7 +699,8 @@ pub struct OrdersChannelInfo {
 *   ShipFrom
 *   MarkFor
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Type {
     #[serde(rename = "MarkFor")]
     MarkFor,
@@ -665,8 +708,9 @@ pub enum Type {
     ShipFrom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Type {
@@ -675,12 +719,45 @@ impl std::fmt::Display for Type {
             Type::MarkFor => "MarkFor",
             Type::ShipFrom => "ShipFrom",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "MarkFor" => Type::MarkFor,
+            "ShipFrom" => Type::ShipFrom,
+            "" => Type::Noop,
+            _ => Type::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -1165,7 +1242,8 @@ pub struct OrdersMeasurements {
 /**
 * The shipment status
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Status {
     #[serde(rename = "Cancelled")]
     Cancelled,
@@ -1189,8 +1267,9 @@ pub enum Status {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1207,12 +1286,53 @@ impl std::fmt::Display for Status {
             Status::Pending => "Pending",
             Status::Processing => "Processing",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Cancelled" => Status::Cancelled,
+            "CleanSweeped" => Status::CleanSweeped,
+            "Completed" => Status::Completed,
+            "Exception" => Status::Exception,
+            "ImportReview" => Status::ImportReview,
+            "LabeledCreated" => Status::LabeledCreated,
+            "None" => Status::None,
+            "OnHold" => Status::OnHold,
+            "Pending" => Status::Pending,
+            "Processing" => Status::Processing,
+            "" => Status::Noop,
+            _ => Status::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -1227,7 +1347,8 @@ impl Status {
 /**
 * Container type for the shipment
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PackageMaterialType {
     #[serde(rename = "Bookfold")]
     Bookfold,
@@ -1251,8 +1372,9 @@ pub enum PackageMaterialType {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PackageMaterialType {
@@ -1269,12 +1391,53 @@ impl std::fmt::Display for PackageMaterialType {
             PackageMaterialType::Undefined => "Undefined",
             PackageMaterialType::Unknown => "Unknown",
             PackageMaterialType::Noop => "",
-            PackageMaterialType::FallthroughString => "*",
+            PackageMaterialType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PackageMaterialType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Bookfold" => PackageMaterialType::Bookfold,
+            "Box" => PackageMaterialType::Box,
+            "BubbleMailer" => PackageMaterialType::BubbleMailer,
+            "Custom" => PackageMaterialType::Custom,
+            "FragileBox" => PackageMaterialType::FragileBox,
+            "OwnContainer" => PackageMaterialType::OwnContainer,
+            "PolyMailer" => PackageMaterialType::PolyMailer,
+            "PosterTube" => PackageMaterialType::PosterTube,
+            "Undefined" => PackageMaterialType::Undefined,
+            "Unknown" => PackageMaterialType::Unknown,
+            "" => PackageMaterialType::Noop,
+            _ => PackageMaterialType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PackageMaterialType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PackageMaterialType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PackageMaterialType {
     fn default() -> PackageMaterialType {
         PackageMaterialType::Noop
@@ -1289,7 +1452,8 @@ impl PackageMaterialType {
 /**
 * Status of ShipBob’s completion of the fulfillment operation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EstimatedFulfillmentDateStatus {
     #[serde(rename = "AwaitingInventoryAllocation")]
     AwaitingInventoryAllocation,
@@ -1307,8 +1471,9 @@ pub enum EstimatedFulfillmentDateStatus {
     Unavailable,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EstimatedFulfillmentDateStatus {
@@ -1324,12 +1489,52 @@ impl std::fmt::Display for EstimatedFulfillmentDateStatus {
             EstimatedFulfillmentDateStatus::PendingOnTime => "PendingOnTime",
             EstimatedFulfillmentDateStatus::Unavailable => "Unavailable",
             EstimatedFulfillmentDateStatus::Noop => "",
-            EstimatedFulfillmentDateStatus::FallthroughString => "*",
+            EstimatedFulfillmentDateStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EstimatedFulfillmentDateStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AwaitingInventoryAllocation" => {
+                EstimatedFulfillmentDateStatus::AwaitingInventoryAllocation
+            }
+            "AwaitingReset" => EstimatedFulfillmentDateStatus::AwaitingReset,
+            "FulfilledLate" => EstimatedFulfillmentDateStatus::FulfilledLate,
+            "FulfilledOnTime" => EstimatedFulfillmentDateStatus::FulfilledOnTime,
+            "PendingLate" => EstimatedFulfillmentDateStatus::PendingLate,
+            "PendingOnTime" => EstimatedFulfillmentDateStatus::PendingOnTime,
+            "Unavailable" => EstimatedFulfillmentDateStatus::Unavailable,
+            "" => EstimatedFulfillmentDateStatus::Noop,
+            _ => EstimatedFulfillmentDateStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EstimatedFulfillmentDateStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EstimatedFulfillmentDateStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EstimatedFulfillmentDateStatus {
     fn default() -> EstimatedFulfillmentDateStatus {
         EstimatedFulfillmentDateStatus::Noop
@@ -1521,7 +1726,8 @@ pub struct OrdersShipment {
 *   
 *   Freight: Larger boxes, usually transported by truckload.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CarrierType {
     #[serde(rename = "Freight")]
     Freight,
@@ -1529,8 +1735,9 @@ pub enum CarrierType {
     Parcel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CarrierType {
@@ -1539,12 +1746,45 @@ impl std::fmt::Display for CarrierType {
             CarrierType::Freight => "Freight",
             CarrierType::Parcel => "Parcel",
             CarrierType::Noop => "",
-            CarrierType::FallthroughString => "*",
+            CarrierType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CarrierType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Freight" => CarrierType::Freight,
+            "Parcel" => CarrierType::Parcel,
+            "" => CarrierType::Noop,
+            _ => CarrierType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CarrierType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CarrierType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CarrierType {
     fn default() -> CarrierType {
         CarrierType::Noop
@@ -1565,7 +1805,8 @@ impl CarrierType {
 *   
 *   Prepaid: The shipper pays the shipping charges (Shipbob or merchant).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentTerm {
     #[serde(rename = "Collect")]
     Collect,
@@ -1575,8 +1816,9 @@ pub enum PaymentTerm {
     ThirdParty,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentTerm {
@@ -1586,12 +1828,46 @@ impl std::fmt::Display for PaymentTerm {
             PaymentTerm::Prepaid => "Prepaid",
             PaymentTerm::ThirdParty => "ThirdParty",
             PaymentTerm::Noop => "",
-            PaymentTerm::FallthroughString => "*",
+            PaymentTerm::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentTerm {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Collect" => PaymentTerm::Collect,
+            "Prepaid" => PaymentTerm::Prepaid,
+            "ThirdParty" => PaymentTerm::ThirdParty,
+            "" => PaymentTerm::Noop,
+            _ => PaymentTerm::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentTerm {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentTerm {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentTerm {
     fn default() -> PaymentTerm {
         PaymentTerm::Noop
@@ -1681,7 +1957,8 @@ pub struct OrdersRetailerProgramData {
 /**
 * The order status
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrderStatus {
     #[serde(rename = "Cancelled")]
     Cancelled,
@@ -1697,8 +1974,9 @@ pub enum OrderStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrderStatus {
@@ -1711,12 +1989,49 @@ impl std::fmt::Display for OrderStatus {
             OrderStatus::PartiallyFulfilled => "PartiallyFulfilled",
             OrderStatus::Processing => "Processing",
             OrderStatus::Noop => "",
-            OrderStatus::FallthroughString => "*",
+            OrderStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrderStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Cancelled" => OrderStatus::Cancelled,
+            "Exception" => OrderStatus::Exception,
+            "Fulfilled" => OrderStatus::Fulfilled,
+            "ImportReview" => OrderStatus::ImportReview,
+            "PartiallyFulfilled" => OrderStatus::PartiallyFulfilled,
+            "Processing" => OrderStatus::Processing,
+            "" => OrderStatus::Noop,
+            _ => OrderStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrderStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrderStatus {
     fn default() -> OrderStatus {
         OrderStatus::Noop
@@ -1731,7 +2046,8 @@ impl OrderStatus {
 /**
 * Shipment type of the order
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrderType {
     #[serde(rename = "B2B")]
     B2B,
@@ -1741,8 +2057,9 @@ pub enum OrderType {
     DropShip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrderType {
@@ -1752,12 +2069,46 @@ impl std::fmt::Display for OrderType {
             OrderType::Dtc => "DTC",
             OrderType::DropShip => "DropShip",
             OrderType::Noop => "",
-            OrderType::FallthroughString => "*",
+            OrderType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrderType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "B2B" => OrderType::B2B,
+            "DTC" => OrderType::Dtc,
+            "DropShip" => OrderType::DropShip,
+            "" => OrderType::Noop,
+            _ => OrderType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrderType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrderType {
     fn default() -> OrderType {
         OrderType::Noop
@@ -2074,14 +2425,16 @@ pub struct OrdersCreateOrderModel {
     pub type_: Option<OrderType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Action {
     #[serde(rename = "Cancel")]
     Cancel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Action {
@@ -2089,12 +2442,44 @@ impl std::fmt::Display for Action {
         match self {
             Action::Cancel => "Cancel",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Action {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Cancel" => Action::Cancel,
+            "" => Action::Noop,
+            _ => Action::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Action {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Action {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Action {
     fn default() -> Action {
         Action::Noop
@@ -2145,7 +2530,8 @@ pub struct OrdersCanceledShipment {
 /**
 * The overall result of canceling the shipments associated with the order
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrdersCanceledOrderStatus {
     #[serde(rename = "Failure")]
     Failure,
@@ -2155,8 +2541,9 @@ pub enum OrdersCanceledOrderStatus {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrdersCanceledOrderStatus {
@@ -2166,12 +2553,46 @@ impl std::fmt::Display for OrdersCanceledOrderStatus {
             OrdersCanceledOrderStatus::PartialSuccess => "PartialSuccess",
             OrdersCanceledOrderStatus::Success => "Success",
             OrdersCanceledOrderStatus::Noop => "",
-            OrdersCanceledOrderStatus::FallthroughString => "*",
+            OrdersCanceledOrderStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrdersCanceledOrderStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Failure" => OrdersCanceledOrderStatus::Failure,
+            "PartialSuccess" => OrdersCanceledOrderStatus::PartialSuccess,
+            "Success" => OrdersCanceledOrderStatus::Success,
+            "" => OrdersCanceledOrderStatus::Noop,
+            _ => OrdersCanceledOrderStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrdersCanceledOrderStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrdersCanceledOrderStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrdersCanceledOrderStatus {
     fn default() -> OrdersCanceledOrderStatus {
         OrdersCanceledOrderStatus::Noop
@@ -2369,7 +2790,8 @@ pub struct OrdersShipMethodDetail {
     pub service_level: Option<OrdersServiceLevelDetail>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ProductActiveStatus {
     #[serde(rename = "Active")]
     Active,
@@ -2379,8 +2801,9 @@ pub enum ProductActiveStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ProductActiveStatus {
@@ -2390,12 +2813,46 @@ impl std::fmt::Display for ProductActiveStatus {
             ProductActiveStatus::Any => "Any",
             ProductActiveStatus::Inactive => "Inactive",
             ProductActiveStatus::Noop => "",
-            ProductActiveStatus::FallthroughString => "*",
+            ProductActiveStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ProductActiveStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Active" => ProductActiveStatus::Active,
+            "Any" => ProductActiveStatus::Any,
+            "Inactive" => ProductActiveStatus::Inactive,
+            "" => ProductActiveStatus::Noop,
+            _ => ProductActiveStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ProductActiveStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProductActiveStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ProductActiveStatus {
     fn default() -> ProductActiveStatus {
         ProductActiveStatus::Noop
@@ -2407,7 +2864,8 @@ impl ProductActiveStatus {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ProductBundleStatus {
     #[serde(rename = "Any")]
     Any,
@@ -2417,8 +2875,9 @@ pub enum ProductBundleStatus {
     NotBundle,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ProductBundleStatus {
@@ -2428,12 +2887,46 @@ impl std::fmt::Display for ProductBundleStatus {
             ProductBundleStatus::Bundle => "Bundle",
             ProductBundleStatus::NotBundle => "NotBundle",
             ProductBundleStatus::Noop => "",
-            ProductBundleStatus::FallthroughString => "*",
+            ProductBundleStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ProductBundleStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Any" => ProductBundleStatus::Any,
+            "Bundle" => ProductBundleStatus::Bundle,
+            "NotBundle" => ProductBundleStatus::NotBundle,
+            "" => ProductBundleStatus::Noop,
+            _ => ProductBundleStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ProductBundleStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProductBundleStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ProductBundleStatus {
     fn default() -> ProductBundleStatus {
         ProductBundleStatus::Noop
@@ -2920,7 +3413,8 @@ pub struct ReceivingFulfillmentCenter {
     pub zip_code: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReceivingStatus {
     #[serde(rename = "Arrived")]
     Arrived,
@@ -2938,8 +3432,9 @@ pub enum ReceivingStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReceivingStatus {
@@ -2953,12 +3448,50 @@ impl std::fmt::Display for ReceivingStatus {
             ReceivingStatus::PartiallyArrived => "PartiallyArrived",
             ReceivingStatus::Processing => "Processing",
             ReceivingStatus::Noop => "",
-            ReceivingStatus::FallthroughString => "*",
+            ReceivingStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReceivingStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Arrived" => ReceivingStatus::Arrived,
+            "Awaiting" => ReceivingStatus::Awaiting,
+            "Cancelled" => ReceivingStatus::Cancelled,
+            "Completed" => ReceivingStatus::Completed,
+            "Incomplete" => ReceivingStatus::Incomplete,
+            "PartiallyArrived" => ReceivingStatus::PartiallyArrived,
+            "Processing" => ReceivingStatus::Processing,
+            "" => ReceivingStatus::Noop,
+            _ => ReceivingStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReceivingStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReceivingStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReceivingStatus {
     fn default() -> ReceivingStatus {
         ReceivingStatus::Noop
@@ -2970,7 +3503,8 @@ impl ReceivingStatus {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReceivingPackageType {
     #[serde(rename = "FloorLoadedContainer")]
     FloorLoadedContainer,
@@ -2980,8 +3514,9 @@ pub enum ReceivingPackageType {
     Pallet,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReceivingPackageType {
@@ -2991,12 +3526,46 @@ impl std::fmt::Display for ReceivingPackageType {
             ReceivingPackageType::Package => "Package",
             ReceivingPackageType::Pallet => "Pallet",
             ReceivingPackageType::Noop => "",
-            ReceivingPackageType::FallthroughString => "*",
+            ReceivingPackageType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReceivingPackageType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "FloorLoadedContainer" => ReceivingPackageType::FloorLoadedContainer,
+            "Package" => ReceivingPackageType::Package,
+            "Pallet" => ReceivingPackageType::Pallet,
+            "" => ReceivingPackageType::Noop,
+            _ => ReceivingPackageType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReceivingPackageType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReceivingPackageType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReceivingPackageType {
     fn default() -> ReceivingPackageType {
         ReceivingPackageType::Noop
@@ -3008,7 +3577,8 @@ impl ReceivingPackageType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReceivingPackingType {
     #[serde(rename = "EverythingInOneBox")]
     EverythingInOneBox,
@@ -3018,8 +3588,9 @@ pub enum ReceivingPackingType {
     OneSkuPerBox,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReceivingPackingType {
@@ -3029,12 +3600,46 @@ impl std::fmt::Display for ReceivingPackingType {
             ReceivingPackingType::MultipleSkuPerBox => "MultipleSkuPerBox",
             ReceivingPackingType::OneSkuPerBox => "OneSkuPerBox",
             ReceivingPackingType::Noop => "",
-            ReceivingPackingType::FallthroughString => "*",
+            ReceivingPackingType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReceivingPackingType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EverythingInOneBox" => ReceivingPackingType::EverythingInOneBox,
+            "MultipleSkuPerBox" => ReceivingPackingType::MultipleSkuPerBox,
+            "OneSkuPerBox" => ReceivingPackingType::OneSkuPerBox,
+            "" => ReceivingPackingType::Noop,
+            _ => ReceivingPackingType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReceivingPackingType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReceivingPackingType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReceivingPackingType {
     fn default() -> ReceivingPackingType {
         ReceivingPackingType::Noop
@@ -3046,7 +3651,8 @@ impl ReceivingPackingType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReceivingBoxStatus {
     #[serde(rename = "Arrived")]
     Arrived,
@@ -3062,8 +3668,9 @@ pub enum ReceivingBoxStatus {
     Stowing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReceivingBoxStatus {
@@ -3076,12 +3683,49 @@ impl std::fmt::Display for ReceivingBoxStatus {
             ReceivingBoxStatus::Counting => "Counting",
             ReceivingBoxStatus::Stowing => "Stowing",
             ReceivingBoxStatus::Noop => "",
-            ReceivingBoxStatus::FallthroughString => "*",
+            ReceivingBoxStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReceivingBoxStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Arrived" => ReceivingBoxStatus::Arrived,
+            "Awaiting" => ReceivingBoxStatus::Awaiting,
+            "Cancelled" => ReceivingBoxStatus::Cancelled,
+            "Completed" => ReceivingBoxStatus::Completed,
+            "Counting" => ReceivingBoxStatus::Counting,
+            "Stowing" => ReceivingBoxStatus::Stowing,
+            "" => ReceivingBoxStatus::Noop,
+            _ => ReceivingBoxStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReceivingBoxStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReceivingBoxStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReceivingBoxStatus {
     fn default() -> ReceivingBoxStatus {
         ReceivingBoxStatus::Noop
@@ -3457,7 +4101,8 @@ pub struct ReceivingCreateOrderModel {
     pub package_type: ReceivingPackageType,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReturnStatus {
     #[serde(rename = "Arrived")]
     Arrived,
@@ -3471,8 +4116,9 @@ pub enum ReturnStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReturnStatus {
@@ -3484,12 +4130,48 @@ impl std::fmt::Display for ReturnStatus {
             ReturnStatus::Completed => "Completed",
             ReturnStatus::Processing => "Processing",
             ReturnStatus::Noop => "",
-            ReturnStatus::FallthroughString => "*",
+            ReturnStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReturnStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Arrived" => ReturnStatus::Arrived,
+            "AwaitingArrival" => ReturnStatus::AwaitingArrival,
+            "Cancelled" => ReturnStatus::Cancelled,
+            "Completed" => ReturnStatus::Completed,
+            "Processing" => ReturnStatus::Processing,
+            "" => ReturnStatus::Noop,
+            _ => ReturnStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReturnStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReturnStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReturnStatus {
     fn default() -> ReturnStatus {
         ReturnStatus::Noop
@@ -3501,7 +4183,8 @@ impl ReturnStatus {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReturnsTransactionLogSource {
     #[serde(rename = "ReturnLabelInvoice")]
     ReturnLabelInvoice,
@@ -3511,8 +4194,9 @@ pub enum ReturnsTransactionLogSource {
     ReturnToSenderFee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReturnsTransactionLogSource {
@@ -3522,12 +4206,46 @@ impl std::fmt::Display for ReturnsTransactionLogSource {
             ReturnsTransactionLogSource::ReturnProcessingFee => "ReturnProcessingFee",
             ReturnsTransactionLogSource::ReturnToSenderFee => "ReturnToSenderFee",
             ReturnsTransactionLogSource::Noop => "",
-            ReturnsTransactionLogSource::FallthroughString => "*",
+            ReturnsTransactionLogSource::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReturnsTransactionLogSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ReturnLabelInvoice" => ReturnsTransactionLogSource::ReturnLabelInvoice,
+            "ReturnProcessingFee" => ReturnsTransactionLogSource::ReturnProcessingFee,
+            "ReturnToSenderFee" => ReturnsTransactionLogSource::ReturnToSenderFee,
+            "" => ReturnsTransactionLogSource::Noop,
+            _ => ReturnsTransactionLogSource::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReturnsTransactionLogSource {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReturnsTransactionLogSource {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReturnsTransactionLogSource {
     fn default() -> ReturnsTransactionLogSource {
         ReturnsTransactionLogSource::Noop
@@ -3577,7 +4295,8 @@ pub struct ReturnsFulfillmentCenter {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReturnAction {
     #[serde(rename = "Default")]
     Default,
@@ -3589,8 +4308,9 @@ pub enum ReturnAction {
     Restock,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReturnAction {
@@ -3601,12 +4321,47 @@ impl std::fmt::Display for ReturnAction {
             ReturnAction::Quarantine => "Quarantine",
             ReturnAction::Restock => "Restock",
             ReturnAction::Noop => "",
-            ReturnAction::FallthroughString => "*",
+            ReturnAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReturnAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Default" => ReturnAction::Default,
+            "Dispose" => ReturnAction::Dispose,
+            "Quarantine" => ReturnAction::Quarantine,
+            "Restock" => ReturnAction::Restock,
+            "" => ReturnAction::Noop,
+            _ => ReturnAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReturnAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReturnAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReturnAction {
     fn default() -> ReturnAction {
         ReturnAction::Noop
@@ -3618,7 +4373,8 @@ impl ReturnAction {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReturnActionSource {
     #[serde(rename = "InventoryDefault")]
     InventoryDefault,
@@ -3626,8 +4382,9 @@ pub enum ReturnActionSource {
     Override,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReturnActionSource {
@@ -3636,12 +4393,45 @@ impl std::fmt::Display for ReturnActionSource {
             ReturnActionSource::InventoryDefault => "InventoryDefault",
             ReturnActionSource::Override => "Override",
             ReturnActionSource::Noop => "",
-            ReturnActionSource::FallthroughString => "*",
+            ReturnActionSource::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReturnActionSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "InventoryDefault" => ReturnActionSource::InventoryDefault,
+            "Override" => ReturnActionSource::Override,
+            "" => ReturnActionSource::Noop,
+            _ => ReturnActionSource::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReturnActionSource {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReturnActionSource {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReturnActionSource {
     fn default() -> ReturnActionSource {
         ReturnActionSource::Noop
@@ -3736,7 +4526,8 @@ pub struct ReturnsInventoryItem {
     pub quantity: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReturnType {
     #[serde(rename = "Regular")]
     Regular,
@@ -3744,8 +4535,9 @@ pub enum ReturnType {
     ReturnToSender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReturnType {
@@ -3754,12 +4546,45 @@ impl std::fmt::Display for ReturnType {
             ReturnType::Regular => "Regular",
             ReturnType::ReturnToSender => "ReturnToSender",
             ReturnType::Noop => "",
-            ReturnType::FallthroughString => "*",
+            ReturnType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReturnType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Regular" => ReturnType::Regular,
+            "ReturnToSender" => ReturnType::ReturnToSender,
+            "" => ReturnType::Noop,
+            _ => ReturnType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReturnType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReturnType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReturnType {
     fn default() -> ReturnType {
         ReturnType::Noop
@@ -3933,7 +4758,8 @@ pub struct ReturnsCreateReturn {
 /**
 * Order to sort results in
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SortOrder {
     #[serde(rename = "Newest")]
     Newest,
@@ -3941,8 +4767,9 @@ pub enum SortOrder {
     Oldest,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -3951,12 +4778,45 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Newest => "Newest",
             SortOrder::Oldest => "Oldest",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SortOrder {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Newest" => SortOrder::Newest,
+            "Oldest" => SortOrder::Oldest,
+            "" => SortOrder::Noop,
+            _ => SortOrder::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SortOrder {
     fn default() -> SortOrder {
         SortOrder::Noop
@@ -3983,7 +4843,8 @@ pub struct ReturnOrderStatusHistory {
     pub time_stamp: Option<chrono::DateTime<chrono::Utc>>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WebhooksTopics {
     #[serde(rename = "order_shipped")]
     OrderShipped,
@@ -3995,8 +4856,9 @@ pub enum WebhooksTopics {
     ShipmentOnhold,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WebhooksTopics {
@@ -4007,12 +4869,47 @@ impl std::fmt::Display for WebhooksTopics {
             WebhooksTopics::ShipmentException => "shipment_exception",
             WebhooksTopics::ShipmentOnhold => "shipment_onhold",
             WebhooksTopics::Noop => "",
-            WebhooksTopics::FallthroughString => "*",
+            WebhooksTopics::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WebhooksTopics {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "order_shipped" => WebhooksTopics::OrderShipped,
+            "shipment_delivered" => WebhooksTopics::ShipmentDelivered,
+            "shipment_exception" => WebhooksTopics::ShipmentException,
+            "shipment_onhold" => WebhooksTopics::ShipmentOnhold,
+            "" => WebhooksTopics::Noop,
+            _ => WebhooksTopics::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WebhooksTopics {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WebhooksTopics {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WebhooksTopics {
     fn default() -> WebhooksTopics {
         WebhooksTopics::Noop
@@ -4131,7 +5028,8 @@ pub struct MicrosoftAspNetCoreMvcProblemDetails {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IntegrationsLocationServiceTypeEnum {
     #[serde(rename = "Receiving")]
     Receiving,
@@ -4139,8 +5037,9 @@ pub enum IntegrationsLocationServiceTypeEnum {
     Returns,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IntegrationsLocationServiceTypeEnum {
@@ -4149,12 +5048,45 @@ impl std::fmt::Display for IntegrationsLocationServiceTypeEnum {
             IntegrationsLocationServiceTypeEnum::Receiving => "Receiving",
             IntegrationsLocationServiceTypeEnum::Returns => "Returns",
             IntegrationsLocationServiceTypeEnum::Noop => "",
-            IntegrationsLocationServiceTypeEnum::FallthroughString => "*",
+            IntegrationsLocationServiceTypeEnum::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IntegrationsLocationServiceTypeEnum {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Receiving" => IntegrationsLocationServiceTypeEnum::Receiving,
+            "Returns" => IntegrationsLocationServiceTypeEnum::Returns,
+            "" => IntegrationsLocationServiceTypeEnum::Noop,
+            _ => IntegrationsLocationServiceTypeEnum::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IntegrationsLocationServiceTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IntegrationsLocationServiceTypeEnum {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IntegrationsLocationServiceTypeEnum {
     fn default() -> IntegrationsLocationServiceTypeEnum {
         IntegrationsLocationServiceTypeEnum::Noop
@@ -2129,14 +2129,16 @@ pub struct NewPagingStyle {
     pub next_cursor: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Warnings {
     #[serde(rename = "method_deprecated")]
     MethodDeprecated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Warnings {
@@ -2144,12 +2146,44 @@ impl std::fmt::Display for Warnings {
         match self {
             Warnings::MethodDeprecated => "method_deprecated",
             Warnings::Noop => "",
-            Warnings::FallthroughString => "*",
+            Warnings::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Warnings {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "method_deprecated" => Warnings::MethodDeprecated,
+            "" => Warnings::Noop,
+            _ => Warnings::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Warnings {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Warnings {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Warnings {
     fn default() -> Warnings {
         Warnings::Noop
@@ -2215,7 +2249,8 @@ pub struct ObjsResponseMetadataAnyOf {
     pub objs_response_metadata: ObjsResponseMetadata,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AutoType {
     #[serde(rename = "admin")]
     Admin,
@@ -2223,8 +2258,9 @@ pub enum AutoType {
     Owner,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AutoType {
@@ -2233,12 +2269,45 @@ impl std::fmt::Display for AutoType {
             AutoType::Admin => "admin",
             AutoType::Owner => "owner",
             AutoType::Noop => "",
-            AutoType::FallthroughString => "*",
+            AutoType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AutoType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => AutoType::Admin,
+            "owner" => AutoType::Owner,
+            "" => AutoType::Noop,
+            _ => AutoType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AutoType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AutoType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AutoType {
     fn default() -> AutoType {
         AutoType::Noop
@@ -2427,7 +2496,8 @@ pub struct TzAnyOf {
     pub value: serde_json::Value,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Plan {
     #[serde(rename = "compliance")]
     Compliance,
@@ -2439,8 +2509,9 @@ pub enum Plan {
     Std,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Plan {
@@ -2451,12 +2522,47 @@ impl std::fmt::Display for Plan {
             Plan::Plus => "plus",
             Plan::Std => "std",
             Plan::Noop => "",
-            Plan::FallthroughString => "*",
+            Plan::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Plan {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "compliance" => Plan::Compliance,
+            "enterprise" => Plan::Enterprise,
+            "plus" => Plan::Plus,
+            "std" => Plan::Std,
+            "" => Plan::Noop,
+            _ => Plan::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Plan {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Plan {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Plan {
     fn default() -> Plan {
         Plan::Noop
@@ -2655,7 +2761,8 @@ pub struct OptionsAnyOf {
     pub objs_team_profile_field_option: ObjsTeamProfileFieldOption,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Type {
     #[serde(rename = "date")]
     Date,
@@ -2671,8 +2778,9 @@ pub enum Type {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Type {
@@ -2685,12 +2793,49 @@ impl std::fmt::Display for Type {
             Type::Text => "text",
             Type::User => "user",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "date" => Type::Date,
+            "link" => Type::Link,
+            "mailto" => Type::Mailto,
+            "options_list" => Type::OptionsList,
+            "text" => Type::Text,
+            "user" => Type::User,
+            "" => Type::Noop,
+            _ => Type::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -4829,14 +4974,16 @@ pub struct MigrationExchangeSuccessSchema {
     pub user_id_map: Option<Fields>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ItemsType {
     #[serde(rename = "file")]
     File,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ItemsType {
@@ -4844,12 +4991,44 @@ impl std::fmt::Display for ItemsType {
         match self {
             ItemsType::File => "file",
             ItemsType::Noop => "",
-            ItemsType::FallthroughString => "*",
+            ItemsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ItemsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "file" => ItemsType::File,
+            "" => ItemsType::Noop,
+            _ => ItemsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ItemsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ItemsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ItemsType {
     fn default() -> ItemsType {
         ItemsType::Noop
@@ -4881,14 +5060,16 @@ pub struct Items {
     pub type_: Option<ItemsType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MessagePinType {
     #[serde(rename = "message")]
     Message,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MessagePinType {
@@ -4896,12 +5077,44 @@ impl std::fmt::Display for MessagePinType {
         match self {
             MessagePinType::Message => "message",
             MessagePinType::Noop => "",
-            MessagePinType::FallthroughString => "*",
+            MessagePinType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MessagePinType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "message" => MessagePinType::Message,
+            "" => MessagePinType::Noop,
+            _ => MessagePinType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MessagePinType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MessagePinType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MessagePinType {
     fn default() -> MessagePinType {
         MessagePinType::Noop
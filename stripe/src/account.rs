@@ -166,7 +166,7 @@ impl Account {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
+                    .get(&format!("{}?starting_after={}", url, page), None)
                     .await?;
             } else {
                 resp = self
@@ -294,7 +294,7 @@ impl Account {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
+                    .get(&format!("{}?starting_after={}", url, page), None)
                     .await?;
             } else {
                 resp = self
@@ -463,7 +463,7 @@ impl Account {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
+                    .get(&format!("{}?starting_after={}", url, page), None)
                     .await?;
             } else {
                 resp = self
@@ -615,7 +615,7 @@ impl Account {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
+                    .get(&format!("{}?starting_after={}", url, page), None)
                     .await?;
             } else {
                 resp = self
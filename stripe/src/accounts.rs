@@ -81,7 +81,7 @@ impl Accounts {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
+                    .get(&format!("{}?starting_after={}", url, page), None)
                     .await?;
             } else {
                 resp = self
@@ -319,7 +319,7 @@ impl Accounts {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
+                    .get(&format!("{}?starting_after={}", url, page), None)
                     .await?;
             } else {
                 resp = self
@@ -463,7 +463,7 @@ impl Accounts {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
+                    .get(&format!("{}?starting_after={}", url, page), None)
                     .await?;
             } else {
                 resp = self
@@ -677,7 +677,7 @@ impl Accounts {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
+                    .get(&format!("{}?starting_after={}", url, page), None)
                     .await?;
             } else {
                 resp = self
@@ -869,7 +869,7 @@ impl Accounts {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
+                    .get(&format!("{}?starting_after={}", url, page), None)
                     .await?;
             } else {
                 resp = self
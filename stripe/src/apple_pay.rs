@@ -93,7 +93,7 @@ impl ApplePay {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
+                    .get(&format!("{}?starting_after={}", url, page), None)
                     .await?;
             } else {
                 resp = self
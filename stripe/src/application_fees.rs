@@ -97,7 +97,7 @@ impl ApplicationFees {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
+                    .get(&format!("{}?starting_after={}", url, page), None)
                     .await?;
             } else {
                 resp = self
@@ -272,7 +272,7 @@ impl ApplicationFees {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
+                    .get(&format!("{}?starting_after={}", url, page), None)
                     .await?;
             } else {
                 resp = self
@@ -142,7 +142,7 @@ impl Balance {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
+                    .get(&format!("{}?starting_after={}", url, page), None)
                     .await?;
             } else {
                 resp = self
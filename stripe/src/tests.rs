@@ -1 +1,24 @@
+#[test]
+fn test_verify_webhook_signature() {
+    let header =
+        "t=1700000000,v1=0000,v1=c89214b5b5da833daed6f0b8c5bb6bd58cea9022bd80ccc78230f3942d632925";
+    let payload = br#"{"id":"evt_1"}"#;
+    let forever = std::time::Duration::from_secs(u64::MAX);
 
+    crate::webhooks::verify("whsec_test", payload, header, forever).unwrap();
+    assert!(crate::webhooks::verify("whsec_other", payload, header, forever).is_err());
+    assert!(crate::webhooks::verify("whsec_test", b"{}", header, forever).is_err());
+    // A zero tolerance skips the timestamp check.
+    crate::webhooks::verify("whsec_test", payload, header, std::time::Duration::ZERO).unwrap();
+    // A second timestamp is rejected rather than overriding the first.
+    let repeated = format!("{},t=1800000000", header);
+    assert!(crate::webhooks::verify("whsec_test", payload, &repeated, forever).is_err());
+    // The event was signed long ago.
+    assert!(crate::webhooks::verify(
+        "whsec_test",
+        payload,
+        header,
+        crate::webhooks::DEFAULT_TOLERANCE
+    )
+    .is_err());
+}
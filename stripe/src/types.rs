@@ -5,7 +5,8 @@ use serde::{Deserialize, Serialize};
 /**
 * The business type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BusinessType {
     #[serde(rename = "company")]
     Company,
@@ -17,8 +18,9 @@ pub enum BusinessType {
     NonProfit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BusinessType {
@@ -29,12 +31,47 @@ impl std::fmt::Display for BusinessType {
             BusinessType::Individual => "individual",
             BusinessType::NonProfit => "non_profit",
             BusinessType::Noop => "",
-            BusinessType::FallthroughString => "*",
+            BusinessType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BusinessType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "company" => BusinessType::Company,
+            "government_entity" => BusinessType::GovernmentEntity,
+            "individual" => BusinessType::Individual,
+            "non_profit" => BusinessType::NonProfit,
+            "" => BusinessType::Noop,
+            _ => BusinessType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BusinessType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BusinessType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BusinessType {
     fn default() -> BusinessType {
         BusinessType::Noop
@@ -95,14 +132,16 @@ impl DataAnyOf {
 /**
 * String representing the object's type. Objects of the same type share the same value. Always has the value `list`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Object {
     #[serde(rename = "list")]
     List,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Object {
@@ -110,12 +149,44 @@ impl std::fmt::Display for Object {
         match self {
             Object::List => "list",
             Object::Noop => "",
-            Object::FallthroughString => "*",
+            Object::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Object {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "list" => Object::List,
+            "" => Object::Noop,
+            _ => Object::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Object {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Object {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Object {
     fn default() -> Object {
         Object::Noop
@@ -162,14 +233,16 @@ pub struct ExternalAccounts {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AccountObject {
     #[serde(rename = "account")]
     Account,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AccountObject {
@@ -177,12 +250,44 @@ impl std::fmt::Display for AccountObject {
         match self {
             AccountObject::Account => "account",
             AccountObject::Noop => "",
-            AccountObject::FallthroughString => "*",
+            AccountObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AccountObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "account" => AccountObject::Account,
+            "" => AccountObject::Noop,
+            _ => AccountObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AccountObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AccountObject {
     fn default() -> AccountObject {
         AccountObject::Noop
@@ -197,7 +302,8 @@ impl AccountObject {
 /**
 * The Stripe account type. Can be `standard`, `express`, or `custom`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Type {
     #[serde(rename = "custom")]
     Custom,
@@ -207,8 +313,9 @@ pub enum Type {
     Standard,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Type {
@@ -218,12 +325,46 @@ impl std::fmt::Display for Type {
             Type::Express => "express",
             Type::Standard => "standard",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "custom" => Type::Custom,
+            "express" => Type::Express,
+            "standard" => Type::Standard,
+            "" => Type::Noop,
+            _ => Type::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -650,7 +791,8 @@ pub struct AccountBusinessProfile {
 /**
 * The status of the transfers capability of the account, or whether your platform can transfer funds to the account.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Transfers {
     #[serde(rename = "active")]
     Active,
@@ -660,8 +802,9 @@ pub enum Transfers {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Transfers {
@@ -671,12 +814,46 @@ impl std::fmt::Display for Transfers {
             Transfers::Inactive => "inactive",
             Transfers::Pending => "pending",
             Transfers::Noop => "",
-            Transfers::FallthroughString => "*",
+            Transfers::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Transfers {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => Transfers::Active,
+            "inactive" => Transfers::Inactive,
+            "pending" => Transfers::Pending,
+            "" => Transfers::Noop,
+            _ => Transfers::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Transfers {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Transfers {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Transfers {
     fn default() -> Transfers {
         Transfers::Noop
@@ -1144,14 +1321,16 @@ pub struct AccountFutureRequirements {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AccountLinkObject {
     #[serde(rename = "account_link")]
     AccountLink,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AccountLinkObject {
@@ -1159,12 +1338,44 @@ impl std::fmt::Display for AccountLinkObject {
         match self {
             AccountLinkObject::AccountLink => "account_link",
             AccountLinkObject::Noop => "",
-            AccountLinkObject::FallthroughString => "*",
+            AccountLinkObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AccountLinkObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "account_link" => AccountLinkObject::AccountLink,
+            "" => AccountLinkObject::Noop,
+            _ => AccountLinkObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AccountLinkObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountLinkObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AccountLinkObject {
     fn default() -> AccountLinkObject {
         AccountLinkObject::Noop
@@ -1378,7 +1589,8 @@ pub struct AccountRequirementsAlternative {
 /**
 * The code for the type of error.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Code {
     #[serde(rename = "invalid_address_city_state_postal_code")]
     InvalidAddressCityStatePostalCode,
@@ -1472,8 +1684,9 @@ pub enum Code {
     VerificationRequiresAdditionalMemorandumOfAssociations,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Code {
@@ -1535,12 +1748,98 @@ impl std::fmt::Display for Code {
                 "verification_requires_additional_memorandum_of_associations"
             }
             Code::Noop => "",
-            Code::FallthroughString => "*",
+            Code::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Code {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "invalid_address_city_state_postal_code" => Code::InvalidAddressCityStatePostalCode,
+            "invalid_street_address" => Code::InvalidStreetAddress,
+            "invalid_value_other" => Code::InvalidValueOther,
+            "verification_document_address_mismatch" => Code::VerificationDocumentAddressMismatch,
+            "verification_document_address_missing" => Code::VerificationDocumentAddressMissing,
+            "verification_document_corrupt" => Code::VerificationDocumentCorrupt,
+            "verification_document_country_not_supported" => {
+                Code::VerificationDocumentCountryNotSupported
+            }
+            "verification_document_dob_mismatch" => Code::VerificationDocumentDobMismatch,
+            "verification_document_duplicate_type" => Code::VerificationDocumentDuplicateType,
+            "verification_document_expired" => Code::VerificationDocumentExpired,
+            "verification_document_failed_copy" => Code::VerificationDocumentFailedCopy,
+            "verification_document_failed_greyscale" => Code::VerificationDocumentFailedGreyscale,
+            "verification_document_failed_other" => Code::VerificationDocumentFailedOther,
+            "verification_document_failed_test_mode" => Code::VerificationDocumentFailedTestMode,
+            "verification_document_fraudulent" => Code::VerificationDocumentFraudulent,
+            "verification_document_id_number_mismatch" => Code::VerificationDocumentNumberMismatch,
+            "verification_document_id_number_missing" => Code::VerificationDocumentNumberMissing,
+            "verification_document_incomplete" => Code::VerificationDocumentIncomplete,
+            "verification_document_invalid" => Code::VerificationDocumentInvalid,
+            "verification_document_issue_or_expiry_date_missing" => {
+                Code::VerificationDocumentIssueOrExpiryDateMissing
+            }
+            "verification_document_manipulated" => Code::VerificationDocumentManipulated,
+            "verification_document_missing_back" => Code::VerificationDocumentMissingBack,
+            "verification_document_missing_front" => Code::VerificationDocumentMissingFront,
+            "verification_document_name_mismatch" => Code::VerificationDocumentNameMismatch,
+            "verification_document_name_missing" => Code::VerificationDocumentNameMissing,
+            "verification_document_nationality_mismatch" => {
+                Code::VerificationDocumentNationalityMismatch
+            }
+            "verification_document_not_readable" => Code::VerificationDocumentNotReadable,
+            "verification_document_not_signed" => Code::VerificationDocumentNotSigned,
+            "verification_document_not_uploaded" => Code::VerificationDocumentNotUploaded,
+            "verification_document_photo_mismatch" => Code::VerificationDocumentPhotoMismatch,
+            "verification_document_too_large" => Code::VerificationDocumentTooLarge,
+            "verification_document_type_not_supported" => {
+                Code::VerificationDocumentTypeNotSupported
+            }
+            "verification_failed_address_match" => Code::VerificationFailedAddressMatch,
+            "verification_failed_business_iec_number" => Code::VerificationFailedBusinessIecNumber,
+            "verification_failed_document_match" => Code::VerificationFailedDocumentMatch,
+            "verification_failed_id_number_match" => Code::VerificationFailedNumberMatch,
+            "verification_failed_keyed_identity" => Code::VerificationFailedKeyedIdentity,
+            "verification_failed_keyed_match" => Code::VerificationFailedKeyedMatch,
+            "verification_failed_name_match" => Code::VerificationFailedNameMatch,
+            "verification_failed_other" => Code::VerificationFailedOther,
+            "verification_failed_tax_id_match" => Code::VerificationFailedTaxMatch,
+            "verification_failed_tax_id_not_issued" => Code::VerificationFailedTaxNotIssued,
+            "verification_missing_executives" => Code::VerificationMissingExecutives,
+            "verification_missing_owners" => Code::VerificationMissingOwners,
+            "verification_requires_additional_memorandum_of_associations" => {
+                Code::VerificationRequiresAdditionalMemorandumOfAssociations
+            }
+            "" => Code::Noop,
+            _ => Code::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Code {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Code {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Code {
     fn default() -> Code {
         Code::Noop
@@ -1683,7 +1982,8 @@ pub struct AccountTosAcceptance {
 /**
 * The controller type. Can be `application`, if a Connect application controls the account, or `account`, if the account controls itself.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AccountUnificationControllerType {
     #[serde(rename = "account")]
     Account,
@@ -1691,8 +1991,9 @@ pub enum AccountUnificationControllerType {
     Application,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AccountUnificationControllerType {
@@ -1701,12 +2002,45 @@ impl std::fmt::Display for AccountUnificationControllerType {
             AccountUnificationControllerType::Account => "account",
             AccountUnificationControllerType::Application => "application",
             AccountUnificationControllerType::Noop => "",
-            AccountUnificationControllerType::FallthroughString => "*",
+            AccountUnificationControllerType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AccountUnificationControllerType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "account" => AccountUnificationControllerType::Account,
+            "application" => AccountUnificationControllerType::Application,
+            "" => AccountUnificationControllerType::Noop,
+            _ => AccountUnificationControllerType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AccountUnificationControllerType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountUnificationControllerType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AccountUnificationControllerType {
     fn default() -> AccountUnificationControllerType {
         AccountUnificationControllerType::Noop
@@ -1866,14 +2200,16 @@ impl std::convert::From<CustomerAnyOf> for String {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AlipayAccountObject {
     #[serde(rename = "alipay_account")]
     AlipayAccount,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AlipayAccountObject {
@@ -1881,12 +2217,44 @@ impl std::fmt::Display for AlipayAccountObject {
         match self {
             AlipayAccountObject::AlipayAccount => "alipay_account",
             AlipayAccountObject::Noop => "",
-            AlipayAccountObject::FallthroughString => "*",
+            AlipayAccountObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AlipayAccountObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "alipay_account" => AlipayAccountObject::AlipayAccount,
+            "" => AlipayAccountObject::Noop,
+            _ => AlipayAccountObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AlipayAccountObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AlipayAccountObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AlipayAccountObject {
     fn default() -> AlipayAccountObject {
         AlipayAccountObject::Noop
@@ -2066,7 +2434,8 @@ impl SourceAnyOf {
 /**
 * The type of error returned. One of `api_error`, `card_error`, `idempotency_error`, or `invalid_request_error`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ApiErrorsType {
     #[serde(rename = "api_error")]
     ApiError,
@@ -2078,8 +2447,9 @@ pub enum ApiErrorsType {
     InvalidRequestError,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ApiErrorsType {
@@ -2090,12 +2460,47 @@ impl std::fmt::Display for ApiErrorsType {
             ApiErrorsType::IdempotencyError => "idempotency_error",
             ApiErrorsType::InvalidRequestError => "invalid_request_error",
             ApiErrorsType::Noop => "",
-            ApiErrorsType::FallthroughString => "*",
+            ApiErrorsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ApiErrorsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "api_error" => ApiErrorsType::ApiError,
+            "card_error" => ApiErrorsType::CardError,
+            "idempotency_error" => ApiErrorsType::IdempotencyError,
+            "invalid_request_error" => ApiErrorsType::InvalidRequestError,
+            "" => ApiErrorsType::Noop,
+            _ => ApiErrorsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ApiErrorsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ApiErrorsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ApiErrorsType {
     fn default() -> ApiErrorsType {
         ApiErrorsType::Noop
@@ -2207,14 +2612,16 @@ pub struct ApiErrors {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ApplePayDomainObject {
     #[serde(rename = "apple_pay_domain")]
     ApplePayDomain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ApplePayDomainObject {
@@ -2222,12 +2629,44 @@ impl std::fmt::Display for ApplePayDomainObject {
         match self {
             ApplePayDomainObject::ApplePayDomain => "apple_pay_domain",
             ApplePayDomainObject::Noop => "",
-            ApplePayDomainObject::FallthroughString => "*",
+            ApplePayDomainObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ApplePayDomainObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "apple_pay_domain" => ApplePayDomainObject::ApplePayDomain,
+            "" => ApplePayDomainObject::Noop,
+            _ => ApplePayDomainObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ApplePayDomainObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ApplePayDomainObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ApplePayDomainObject {
     fn default() -> ApplePayDomainObject {
         ApplePayDomainObject::Noop
@@ -2287,14 +2726,16 @@ pub struct ApplePayDomain {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ApplicationObject {
     #[serde(rename = "application")]
     Application,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ApplicationObject {
@@ -2302,12 +2743,44 @@ impl std::fmt::Display for ApplicationObject {
         match self {
             ApplicationObject::Application => "application",
             ApplicationObject::Noop => "",
-            ApplicationObject::FallthroughString => "*",
+            ApplicationObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ApplicationObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "application" => ApplicationObject::Application,
+            "" => ApplicationObject::Noop,
+            _ => ApplicationObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ApplicationObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ApplicationObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ApplicationObject {
     fn default() -> ApplicationObject {
         ApplicationObject::Noop
@@ -2554,14 +3027,16 @@ impl std::convert::From<ChargeAnyOf> for String {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PlatformFeeObject {
     #[serde(rename = "application_fee")]
     ApplicationFee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PlatformFeeObject {
@@ -2569,12 +3044,44 @@ impl std::fmt::Display for PlatformFeeObject {
         match self {
             PlatformFeeObject::ApplicationFee => "application_fee",
             PlatformFeeObject::Noop => "",
-            PlatformFeeObject::FallthroughString => "*",
+            PlatformFeeObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PlatformFeeObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "application_fee" => PlatformFeeObject::ApplicationFee,
+            "" => PlatformFeeObject::Noop,
+            _ => PlatformFeeObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PlatformFeeObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PlatformFeeObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PlatformFeeObject {
     fn default() -> PlatformFeeObject {
         PlatformFeeObject::Noop
@@ -2717,7 +3224,8 @@ pub struct PlatformFee {
 /**
 * The status of the most recent automated tax calculation for this invoice.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Status {
     #[serde(rename = "complete")]
     Complete,
@@ -2727,8 +3235,9 @@ pub enum Status {
     RequiresLocationInputs,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Status {
@@ -2738,12 +3247,46 @@ impl std::fmt::Display for Status {
             Status::Failed => "failed",
             Status::RequiresLocationInputs => "requires_location_inputs",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "complete" => Status::Complete,
+            "failed" => Status::Failed,
+            "requires_location_inputs" => Status::RequiresLocationInputs,
+            "" => Status::Noop,
+            _ => Status::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -2776,14 +3319,16 @@ pub struct AutomaticTax {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BalanceObject {
     #[serde(rename = "balance")]
     Balance,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BalanceObject {
@@ -2791,12 +3336,44 @@ impl std::fmt::Display for BalanceObject {
         match self {
             BalanceObject::Balance => "balance",
             BalanceObject::Noop => "",
-            BalanceObject::FallthroughString => "*",
+            BalanceObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BalanceObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "balance" => BalanceObject::Balance,
+            "" => BalanceObject::Noop,
+            _ => BalanceObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BalanceObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BalanceObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BalanceObject {
     fn default() -> BalanceObject {
         BalanceObject::Noop
@@ -2984,14 +3561,16 @@ pub struct BalanceDetail {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BalanceTransactionObject {
     #[serde(rename = "balance_transaction")]
     BalanceTransaction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BalanceTransactionObject {
@@ -2999,12 +3578,44 @@ impl std::fmt::Display for BalanceTransactionObject {
         match self {
             BalanceTransactionObject::BalanceTransaction => "balance_transaction",
             BalanceTransactionObject::Noop => "",
-            BalanceTransactionObject::FallthroughString => "*",
+            BalanceTransactionObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BalanceTransactionObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "balance_transaction" => BalanceTransactionObject::BalanceTransaction,
+            "" => BalanceTransactionObject::Noop,
+            _ => BalanceTransactionObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BalanceTransactionObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BalanceTransactionObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BalanceTransactionObject {
     fn default() -> BalanceTransactionObject {
         BalanceTransactionObject::Noop
@@ -3307,7 +3918,8 @@ impl std::convert::From<BalanceTransactionSourceAnyOf> for String {
 /**
 * Transaction type: `adjustment`, `advance`, `advance_funding`, `anticipation_repayment`, `application_fee`, `application_fee_refund`, `charge`, `connect_collection_transfer`, `contribution`, `issuing_authorization_hold`, `issuing_authorization_release`, `issuing_dispute`, `issuing_transaction`, `payment`, `payment_failure_refund`, `payment_refund`, `payout`, `payout_cancel`, `payout_failure`, `refund`, `refund_failure`, `reserve_transaction`, `reserved_funds`, `stripe_fee`, `stripe_fx_fee`, `tax_fee`, `topup`, `topup_reversal`, `transfer`, `transfer_cancel`, `transfer_failure`, or `transfer_refund`. [Learn more](https://stripe.com/docs/reports/balance-transaction-types) about balance transaction types and what they represent. If you are looking to classify transactions for accounting purposes, you might want to consider `reporting_category` instead.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BalanceTransactionType {
     #[serde(rename = "adjustment")]
     Adjustment,
@@ -3375,8 +3987,9 @@ pub enum BalanceTransactionType {
     TransferRefund,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BalanceTransactionType {
@@ -3415,9 +4028,72 @@ impl std::fmt::Display for BalanceTransactionType {
             BalanceTransactionType::TransferFailure => "transfer_failure",
             BalanceTransactionType::TransferRefund => "transfer_refund",
             BalanceTransactionType::Noop => "",
-            BalanceTransactionType::FallthroughString => "*",
-        }
-        .fmt(f)
+            BalanceTransactionType::UnknownValue(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl std::str::FromStr for BalanceTransactionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "adjustment" => BalanceTransactionType::Adjustment,
+            "advance" => BalanceTransactionType::Advance,
+            "advance_funding" => BalanceTransactionType::AdvanceFunding,
+            "anticipation_repayment" => BalanceTransactionType::AnticipationRepayment,
+            "application_fee" => BalanceTransactionType::ApplicationFee,
+            "application_fee_refund" => BalanceTransactionType::ApplicationFeeRefund,
+            "charge" => BalanceTransactionType::Charge,
+            "connect_collection_transfer" => BalanceTransactionType::ConnectCollectionTransfer,
+            "contribution" => BalanceTransactionType::Contribution,
+            "issuing_authorization_hold" => BalanceTransactionType::IssuingAuthorizationHold,
+            "issuing_authorization_release" => BalanceTransactionType::IssuingAuthorizationRelease,
+            "issuing_dispute" => BalanceTransactionType::IssuingDispute,
+            "issuing_transaction" => BalanceTransactionType::IssuingTransaction,
+            "payment" => BalanceTransactionType::Payment,
+            "payment_failure_refund" => BalanceTransactionType::PaymentFailureRefund,
+            "payment_refund" => BalanceTransactionType::PaymentRefund,
+            "payout" => BalanceTransactionType::Payout,
+            "payout_cancel" => BalanceTransactionType::PayoutCancel,
+            "payout_failure" => BalanceTransactionType::PayoutFailure,
+            "refund" => BalanceTransactionType::Refund,
+            "refund_failure" => BalanceTransactionType::RefundFailure,
+            "reserve_transaction" => BalanceTransactionType::ReserveTransaction,
+            "reserved_funds" => BalanceTransactionType::ReservedFunds,
+            "stripe_fee" => BalanceTransactionType::StripeFee,
+            "stripe_fx_fee" => BalanceTransactionType::StripeFxFee,
+            "tax_fee" => BalanceTransactionType::TaxFee,
+            "topup" => BalanceTransactionType::Topup,
+            "topup_reversal" => BalanceTransactionType::TopupReversal,
+            "transfer" => BalanceTransactionType::Transfer,
+            "transfer_cancel" => BalanceTransactionType::TransferCancel,
+            "transfer_failure" => BalanceTransactionType::TransferFailure,
+            "transfer_refund" => BalanceTransactionType::TransferRefund,
+            "" => BalanceTransactionType::Noop,
+            _ => BalanceTransactionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BalanceTransactionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BalanceTransactionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -3573,7 +4249,8 @@ pub struct BalanceTransaction {
     pub type_: BalanceTransactionType,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AvailablePayoutMethods {
     #[serde(rename = "instant")]
     Instant,
@@ -3581,8 +4258,9 @@ pub enum AvailablePayoutMethods {
     Standard,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AvailablePayoutMethods {
@@ -3591,12 +4269,45 @@ impl std::fmt::Display for AvailablePayoutMethods {
             AvailablePayoutMethods::Instant => "instant",
             AvailablePayoutMethods::Standard => "standard",
             AvailablePayoutMethods::Noop => "",
-            AvailablePayoutMethods::FallthroughString => "*",
+            AvailablePayoutMethods::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AvailablePayoutMethods {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "instant" => AvailablePayoutMethods::Instant,
+            "standard" => AvailablePayoutMethods::Standard,
+            "" => AvailablePayoutMethods::Noop,
+            _ => AvailablePayoutMethods::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AvailablePayoutMethods {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AvailablePayoutMethods {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AvailablePayoutMethods {
     fn default() -> AvailablePayoutMethods {
         AvailablePayoutMethods::Noop
@@ -3611,14 +4322,16 @@ impl AvailablePayoutMethods {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BankAccountObject {
     #[serde(rename = "bank_account")]
     BankAccount,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BankAccountObject {
@@ -3626,12 +4339,44 @@ impl std::fmt::Display for BankAccountObject {
         match self {
             BankAccountObject::BankAccount => "bank_account",
             BankAccountObject::Noop => "",
-            BankAccountObject::FallthroughString => "*",
+            BankAccountObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BankAccountObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "bank_account" => BankAccountObject::BankAccount,
+            "" => BankAccountObject::Noop,
+            _ => BankAccountObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BankAccountObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BankAccountObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BankAccountObject {
     fn default() -> BankAccountObject {
         BankAccountObject::Noop
@@ -3895,14 +4640,16 @@ pub struct BillingDetails {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PortalConfigurationObject {
     #[serde(rename = "billing_portal.configuration")]
     BillingPortalConfiguration,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PortalConfigurationObject {
@@ -3910,12 +4657,44 @@ impl std::fmt::Display for PortalConfigurationObject {
         match self {
             PortalConfigurationObject::BillingPortalConfiguration => "billing_portal.configuration",
             PortalConfigurationObject::Noop => "",
-            PortalConfigurationObject::FallthroughString => "*",
+            PortalConfigurationObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PortalConfigurationObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "billing_portal.configuration" => PortalConfigurationObject::BillingPortalConfiguration,
+            "" => PortalConfigurationObject::Noop,
+            _ => PortalConfigurationObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PortalConfigurationObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PortalConfigurationObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PortalConfigurationObject {
     fn default() -> PortalConfigurationObject {
         PortalConfigurationObject::Noop
@@ -4076,7 +4855,8 @@ impl std::convert::From<ConfigurationAnyOf> for String {
 /**
 * The IETF language tag of the locale Customer Portal is displayed in. If blank or auto, the customer’s `preferred_locales` or browser’s locale is used.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Locale {
     #[serde(rename = "auto")]
     Auto,
@@ -4174,8 +4954,9 @@ pub enum Locale {
     ZhTw,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Locale {
@@ -4229,9 +5010,87 @@ impl std::fmt::Display for Locale {
             Locale::ZhHk => "zh-HK",
             Locale::ZhTw => "zh-TW",
             Locale::Noop => "",
-            Locale::FallthroughString => "*",
-        }
-        .fmt(f)
+            Locale::UnknownValue(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl std::str::FromStr for Locale {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "auto" => Locale::Auto,
+            "bg" => Locale::Bg,
+            "cs" => Locale::Cs,
+            "da" => Locale::Da,
+            "de" => Locale::De,
+            "el" => Locale::El,
+            "en" => Locale::En,
+            "en-AU" => Locale::EnAu,
+            "en-CA" => Locale::EnCa,
+            "en-GB" => Locale::EnGb,
+            "en-IE" => Locale::EnIe,
+            "en-IN" => Locale::EnIn,
+            "en-NZ" => Locale::EnNz,
+            "en-SG" => Locale::EnSg,
+            "es" => Locale::Es,
+            "es-419" => Locale::Es419,
+            "et" => Locale::Et,
+            "fi" => Locale::Fi,
+            "fil" => Locale::Fil,
+            "fr" => Locale::Fr,
+            "fr-CA" => Locale::FrCa,
+            "hr" => Locale::Hr,
+            "hu" => Locale::Hu,
+            "id" => Locale::Id,
+            "it" => Locale::It,
+            "ja" => Locale::Ja,
+            "ko" => Locale::Ko,
+            "lt" => Locale::Lt,
+            "lv" => Locale::Lv,
+            "ms" => Locale::Ms,
+            "mt" => Locale::Mt,
+            "nb" => Locale::Nb,
+            "nl" => Locale::Nl,
+            "pl" => Locale::Pl,
+            "pt" => Locale::Pt,
+            "pt-BR" => Locale::PtBr,
+            "ro" => Locale::Ro,
+            "ru" => Locale::Ru,
+            "sk" => Locale::Sk,
+            "sl" => Locale::Sl,
+            "sv" => Locale::Sv,
+            "th" => Locale::Th,
+            "tr" => Locale::Tr,
+            "vi" => Locale::Vi,
+            "zh" => Locale::Zh,
+            "zh-HK" => Locale::ZhHk,
+            "zh-TW" => Locale::ZhTw,
+            "" => Locale::Noop,
+            _ => Locale::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -4249,14 +5108,16 @@ impl Locale {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PortalSessionObject {
     #[serde(rename = "billing_portal.session")]
     BillingPortalSession,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PortalSessionObject {
@@ -4264,12 +5125,44 @@ impl std::fmt::Display for PortalSessionObject {
         match self {
             PortalSessionObject::BillingPortalSession => "billing_portal.session",
             PortalSessionObject::Noop => "",
-            PortalSessionObject::FallthroughString => "*",
+            PortalSessionObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PortalSessionObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "billing_portal.session" => PortalSessionObject::BillingPortalSession,
+            "" => PortalSessionObject::Noop,
+            _ => PortalSessionObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PortalSessionObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PortalSessionObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PortalSessionObject {
     fn default() -> PortalSessionObject {
         PortalSessionObject::Noop
@@ -4389,14 +5282,16 @@ pub struct PortalSession {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BitcoinReceiverObject {
     #[serde(rename = "bitcoin_receiver")]
     BitcoinReceiver,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BitcoinReceiverObject {
@@ -4404,12 +5299,44 @@ impl std::fmt::Display for BitcoinReceiverObject {
         match self {
             BitcoinReceiverObject::BitcoinReceiver => "bitcoin_receiver",
             BitcoinReceiverObject::Noop => "",
-            BitcoinReceiverObject::FallthroughString => "*",
+            BitcoinReceiverObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BitcoinReceiverObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "bitcoin_receiver" => BitcoinReceiverObject::BitcoinReceiver,
+            "" => BitcoinReceiverObject::Noop,
+            _ => BitcoinReceiverObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BitcoinReceiverObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BitcoinReceiverObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BitcoinReceiverObject {
     fn default() -> BitcoinReceiverObject {
         BitcoinReceiverObject::Noop
@@ -4650,14 +5577,16 @@ pub struct BitcoinReceiver {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BitcoinTransactionObject {
     #[serde(rename = "bitcoin_transaction")]
     BitcoinTransaction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BitcoinTransactionObject {
@@ -4665,12 +5594,44 @@ impl std::fmt::Display for BitcoinTransactionObject {
         match self {
             BitcoinTransactionObject::BitcoinTransaction => "bitcoin_transaction",
             BitcoinTransactionObject::Noop => "",
-            BitcoinTransactionObject::FallthroughString => "*",
+            BitcoinTransactionObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BitcoinTransactionObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "bitcoin_transaction" => BitcoinTransactionObject::BitcoinTransaction,
+            "" => BitcoinTransactionObject::Noop,
+            _ => BitcoinTransactionObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BitcoinTransactionObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BitcoinTransactionObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BitcoinTransactionObject {
     fn default() -> BitcoinTransactionObject {
         BitcoinTransactionObject::Noop
@@ -4749,14 +5710,16 @@ pub struct BitcoinTransaction {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CapabilityObject {
     #[serde(rename = "capability")]
     Capability,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CapabilityObject {
@@ -4764,12 +5727,44 @@ impl std::fmt::Display for CapabilityObject {
         match self {
             CapabilityObject::Capability => "capability",
             CapabilityObject::Noop => "",
-            CapabilityObject::FallthroughString => "*",
+            CapabilityObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CapabilityObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "capability" => CapabilityObject::Capability,
+            "" => CapabilityObject::Noop,
+            _ => CapabilityObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CapabilityObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CapabilityObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CapabilityObject {
     fn default() -> CapabilityObject {
         CapabilityObject::Noop
@@ -4784,7 +5779,8 @@ impl CapabilityObject {
 /**
 * The status of the capability. Can be `active`, `inactive`, `pending`, or `unrequested`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CapabilityStatus {
     #[serde(rename = "active")]
     Active,
@@ -4798,8 +5794,9 @@ pub enum CapabilityStatus {
     Unrequested,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CapabilityStatus {
@@ -4811,12 +5808,48 @@ impl std::fmt::Display for CapabilityStatus {
             CapabilityStatus::Pending => "pending",
             CapabilityStatus::Unrequested => "unrequested",
             CapabilityStatus::Noop => "",
-            CapabilityStatus::FallthroughString => "*",
+            CapabilityStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CapabilityStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => CapabilityStatus::Active,
+            "disabled" => CapabilityStatus::Disabled,
+            "inactive" => CapabilityStatus::Inactive,
+            "pending" => CapabilityStatus::Pending,
+            "unrequested" => CapabilityStatus::Unrequested,
+            "" => CapabilityStatus::Noop,
+            _ => CapabilityStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CapabilityStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CapabilityStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CapabilityStatus {
     fn default() -> CapabilityStatus {
         CapabilityStatus::Noop
@@ -4892,14 +5925,16 @@ pub struct Capability {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CardObject {
     #[serde(rename = "card")]
     Card,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CardObject {
@@ -4907,12 +5942,44 @@ impl std::fmt::Display for CardObject {
         match self {
             CardObject::Card => "card",
             CardObject::Noop => "",
-            CardObject::FallthroughString => "*",
+            CardObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CardObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "card" => CardObject::Card,
+            "" => CardObject::Noop,
+            _ => CardObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CardObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CardObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CardObject {
     fn default() -> CardObject {
         CardObject::Noop
@@ -5509,14 +6576,16 @@ impl std::convert::From<InvoiceAnyOf> for String {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ChargeObject {
     #[serde(rename = "charge")]
     Charge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ChargeObject {
@@ -5524,12 +6593,44 @@ impl std::fmt::Display for ChargeObject {
         match self {
             ChargeObject::Charge => "charge",
             ChargeObject::Noop => "",
-            ChargeObject::FallthroughString => "*",
+            ChargeObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ChargeObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "charge" => ChargeObject::Charge,
+            "" => ChargeObject::Noop,
+            _ => ChargeObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ChargeObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChargeObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ChargeObject {
     fn default() -> ChargeObject {
         ChargeObject::Noop
@@ -5798,7 +6899,8 @@ impl std::convert::From<TransferAnyOf> for String {
 /**
 * The status of the payment is either `succeeded`, `pending`, or `failed`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ChargeStatus {
     #[serde(rename = "failed")]
     Failed,
@@ -5808,8 +6910,9 @@ pub enum ChargeStatus {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ChargeStatus {
@@ -5819,12 +6922,46 @@ impl std::fmt::Display for ChargeStatus {
             ChargeStatus::Pending => "pending",
             ChargeStatus::Succeeded => "succeeded",
             ChargeStatus::Noop => "",
-            ChargeStatus::FallthroughString => "*",
+            ChargeStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ChargeStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "failed" => ChargeStatus::Failed,
+            "pending" => ChargeStatus::Pending,
+            "succeeded" => ChargeStatus::Succeeded,
+            "" => ChargeStatus::Noop,
+            _ => ChargeStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ChargeStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChargeStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ChargeStatus {
     fn default() -> ChargeStatus {
         ChargeStatus::Noop
@@ -6412,7 +7549,8 @@ pub struct ChargeTransferData {
 /**
 * Describes whether Checkout should collect the customer's billing address.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BillingAddressCollection {
     #[serde(rename = "auto")]
     Auto,
@@ -6420,8 +7558,9 @@ pub enum BillingAddressCollection {
     Required,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BillingAddressCollection {
@@ -6430,12 +7569,45 @@ impl std::fmt::Display for BillingAddressCollection {
             BillingAddressCollection::Auto => "auto",
             BillingAddressCollection::Required => "required",
             BillingAddressCollection::Noop => "",
-            BillingAddressCollection::FallthroughString => "*",
+            BillingAddressCollection::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BillingAddressCollection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "auto" => BillingAddressCollection::Auto,
+            "required" => BillingAddressCollection::Required,
+            "" => BillingAddressCollection::Noop,
+            _ => BillingAddressCollection::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BillingAddressCollection {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BillingAddressCollection {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BillingAddressCollection {
     fn default() -> BillingAddressCollection {
         BillingAddressCollection::Noop
@@ -6450,7 +7622,8 @@ impl BillingAddressCollection {
 /**
 * Configure whether a Checkout Session creates a Customer when the Checkout Session completes.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CustomerCreation {
     #[serde(rename = "always")]
     Always,
@@ -6458,8 +7631,9 @@ pub enum CustomerCreation {
     IfRequired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CustomerCreation {
@@ -6468,12 +7642,45 @@ impl std::fmt::Display for CustomerCreation {
             CustomerCreation::Always => "always",
             CustomerCreation::IfRequired => "if_required",
             CustomerCreation::Noop => "",
-            CustomerCreation::FallthroughString => "*",
+            CustomerCreation::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CustomerCreation {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "always" => CustomerCreation::Always,
+            "if_required" => CustomerCreation::IfRequired,
+            "" => CustomerCreation::Noop,
+            _ => CustomerCreation::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CustomerCreation {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomerCreation {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CustomerCreation {
     fn default() -> CustomerCreation {
         CustomerCreation::Noop
@@ -6524,7 +7731,8 @@ pub struct LineItems {
 /**
 * The IETF language tag of the locale Checkout is displayed in. If blank or `auto`, the browser's locale is used.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SessionLocale {
     #[serde(rename = "auto")]
     Auto,
@@ -6610,8 +7818,9 @@ pub enum SessionLocale {
     ZhTw,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SessionLocale {
@@ -6659,9 +7868,81 @@ impl std::fmt::Display for SessionLocale {
             SessionLocale::ZhHk => "zh-HK",
             SessionLocale::ZhTw => "zh-TW",
             SessionLocale::Noop => "",
-            SessionLocale::FallthroughString => "*",
-        }
-        .fmt(f)
+            SessionLocale::UnknownValue(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl std::str::FromStr for SessionLocale {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "auto" => SessionLocale::Auto,
+            "bg" => SessionLocale::Bg,
+            "cs" => SessionLocale::Cs,
+            "da" => SessionLocale::Da,
+            "de" => SessionLocale::De,
+            "el" => SessionLocale::El,
+            "en" => SessionLocale::En,
+            "en-GB" => SessionLocale::EnGb,
+            "es" => SessionLocale::Es,
+            "es-419" => SessionLocale::Es419,
+            "et" => SessionLocale::Et,
+            "fi" => SessionLocale::Fi,
+            "fil" => SessionLocale::Fil,
+            "fr" => SessionLocale::Fr,
+            "fr-CA" => SessionLocale::FrCa,
+            "hr" => SessionLocale::Hr,
+            "hu" => SessionLocale::Hu,
+            "id" => SessionLocale::Id,
+            "it" => SessionLocale::It,
+            "ja" => SessionLocale::Ja,
+            "ko" => SessionLocale::Ko,
+            "lt" => SessionLocale::Lt,
+            "lv" => SessionLocale::Lv,
+            "ms" => SessionLocale::Ms,
+            "mt" => SessionLocale::Mt,
+            "nb" => SessionLocale::Nb,
+            "nl" => SessionLocale::Nl,
+            "pl" => SessionLocale::Pl,
+            "pt" => SessionLocale::Pt,
+            "pt-BR" => SessionLocale::PtBr,
+            "ro" => SessionLocale::Ro,
+            "ru" => SessionLocale::Ru,
+            "sk" => SessionLocale::Sk,
+            "sl" => SessionLocale::Sl,
+            "sv" => SessionLocale::Sv,
+            "th" => SessionLocale::Th,
+            "tr" => SessionLocale::Tr,
+            "vi" => SessionLocale::Vi,
+            "zh" => SessionLocale::Zh,
+            "zh-HK" => SessionLocale::ZhHk,
+            "zh-TW" => SessionLocale::ZhTw,
+            "" => SessionLocale::Noop,
+            _ => SessionLocale::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SessionLocale {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SessionLocale {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -6679,7 +7960,8 @@ impl SessionLocale {
 /**
 * The mode of the Checkout Session.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Mode {
     #[serde(rename = "payment")]
     Payment,
@@ -6689,8 +7971,9 @@ pub enum Mode {
     Subscription,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Mode {
@@ -6700,12 +7983,46 @@ impl std::fmt::Display for Mode {
             Mode::Setup => "setup",
             Mode::Subscription => "subscription",
             Mode::Noop => "",
-            Mode::FallthroughString => "*",
+            Mode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Mode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "payment" => Mode::Payment,
+            "setup" => Mode::Setup,
+            "subscription" => Mode::Subscription,
+            "" => Mode::Noop,
+            _ => Mode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Mode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Mode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Mode {
     fn default() -> Mode {
         Mode::Noop
@@ -6720,14 +8037,16 @@ impl Mode {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SessionObject {
     #[serde(rename = "checkout.session")]
     CheckoutSession,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SessionObject {
@@ -6735,12 +8054,44 @@ impl std::fmt::Display for SessionObject {
         match self {
             SessionObject::CheckoutSession => "checkout.session",
             SessionObject::Noop => "",
-            SessionObject::FallthroughString => "*",
+            SessionObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SessionObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "checkout.session" => SessionObject::CheckoutSession,
+            "" => SessionObject::Noop,
+            _ => SessionObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SessionObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SessionObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SessionObject {
     fn default() -> SessionObject {
         SessionObject::Noop
@@ -6808,7 +8159,8 @@ impl std::convert::From<PaymentLinkAnyOf> for String {
 * The payment status of the Checkout Session, one of `paid`, `unpaid`, or `no_payment_required`.
 *   You can use this value to decide when to fulfill your customer's order.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentStatus {
     #[serde(rename = "no_payment_required")]
     NoPaymentRequired,
@@ -6818,8 +8170,9 @@ pub enum PaymentStatus {
     Unpaid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentStatus {
@@ -6829,12 +8182,46 @@ impl std::fmt::Display for PaymentStatus {
             PaymentStatus::Paid => "paid",
             PaymentStatus::Unpaid => "unpaid",
             PaymentStatus::Noop => "",
-            PaymentStatus::FallthroughString => "*",
+            PaymentStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "no_payment_required" => PaymentStatus::NoPaymentRequired,
+            "paid" => PaymentStatus::Paid,
+            "unpaid" => PaymentStatus::Unpaid,
+            "" => PaymentStatus::Noop,
+            _ => PaymentStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentStatus {
     fn default() -> PaymentStatus {
         PaymentStatus::Noop
@@ -6967,7 +8354,8 @@ impl std::convert::From<ShippingRateAnyOf> for String {
 /**
 * The status of the Checkout Session, one of `open`, `complete`, or `expired`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SessionStatus {
     #[serde(rename = "complete")]
     Complete,
@@ -6977,8 +8365,9 @@ pub enum SessionStatus {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SessionStatus {
@@ -6988,12 +8377,46 @@ impl std::fmt::Display for SessionStatus {
             SessionStatus::Expired => "expired",
             SessionStatus::Open => "open",
             SessionStatus::Noop => "",
-            SessionStatus::FallthroughString => "*",
+            SessionStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SessionStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "complete" => SessionStatus::Complete,
+            "expired" => SessionStatus::Expired,
+            "open" => SessionStatus::Open,
+            "" => SessionStatus::Noop,
+            _ => SessionStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SessionStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SessionStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SessionStatus {
     fn default() -> SessionStatus {
         SessionStatus::Noop
@@ -7011,7 +8434,8 @@ impl SessionStatus {
 *   specified on Checkout Sessions in `payment` mode, but not Checkout Sessions
 *   in `subscription` or `setup` mode.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SubmitType {
     #[serde(rename = "auto")]
     Auto,
@@ -7023,8 +8447,9 @@ pub enum SubmitType {
     Pay,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SubmitType {
@@ -7035,12 +8460,47 @@ impl std::fmt::Display for SubmitType {
             SubmitType::Donate => "donate",
             SubmitType::Pay => "pay",
             SubmitType::Noop => "",
-            SubmitType::FallthroughString => "*",
+            SubmitType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SubmitType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "auto" => SubmitType::Auto,
+            "book" => SubmitType::Book,
+            "donate" => SubmitType::Donate,
+            "pay" => SubmitType::Pay,
+            "" => SubmitType::Noop,
+            _ => SubmitType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SubmitType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SubmitType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SubmitType {
     fn default() -> SubmitType {
         SubmitType::Noop
@@ -7630,7 +9090,8 @@ pub struct Session {
     pub url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DefaultFor {
     #[serde(rename = "invoice")]
     Invoice,
@@ -7638,8 +9099,9 @@ pub enum DefaultFor {
     Subscription,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DefaultFor {
@@ -7648,12 +9110,45 @@ impl std::fmt::Display for DefaultFor {
             DefaultFor::Invoice => "invoice",
             DefaultFor::Subscription => "subscription",
             DefaultFor::Noop => "",
-            DefaultFor::FallthroughString => "*",
+            DefaultFor::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DefaultFor {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "invoice" => DefaultFor::Invoice,
+            "subscription" => DefaultFor::Subscription,
+            "" => DefaultFor::Noop,
+            _ => DefaultFor::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DefaultFor {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DefaultFor {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DefaultFor {
     fn default() -> DefaultFor {
         DefaultFor::Noop
@@ -7668,7 +9163,8 @@ impl DefaultFor {
 /**
 * Payment schedule for the mandate.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentSchedule {
     #[serde(rename = "combined")]
     Combined,
@@ -7678,8 +9174,9 @@ pub enum PaymentSchedule {
     Sporadic,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentSchedule {
@@ -7689,12 +9186,46 @@ impl std::fmt::Display for PaymentSchedule {
             PaymentSchedule::Interval => "interval",
             PaymentSchedule::Sporadic => "sporadic",
             PaymentSchedule::Noop => "",
-            PaymentSchedule::FallthroughString => "*",
+            PaymentSchedule::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentSchedule {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "combined" => PaymentSchedule::Combined,
+            "interval" => PaymentSchedule::Interval,
+            "sporadic" => PaymentSchedule::Sporadic,
+            "" => PaymentSchedule::Noop,
+            _ => PaymentSchedule::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentSchedule {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentSchedule {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentSchedule {
     fn default() -> PaymentSchedule {
         PaymentSchedule::Noop
@@ -7709,7 +9240,8 @@ impl PaymentSchedule {
 /**
 * Transaction type of the mandate.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TransactionType {
     #[serde(rename = "business")]
     Business,
@@ -7717,8 +9249,9 @@ pub enum TransactionType {
     Personal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TransactionType {
@@ -7727,12 +9260,45 @@ impl std::fmt::Display for TransactionType {
             TransactionType::Business => "business",
             TransactionType::Personal => "personal",
             TransactionType::Noop => "",
-            TransactionType::FallthroughString => "*",
+            TransactionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TransactionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "business" => TransactionType::Business,
+            "personal" => TransactionType::Personal,
+            "" => TransactionType::Noop,
+            _ => TransactionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TransactionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TransactionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TransactionType {
     fn default() -> TransactionType {
         TransactionType::Noop
@@ -7789,7 +9355,8 @@ pub struct CheckoutAcssDebitMandateOptions {
 /**
 * Currency supported by the bank account. Returned when the Session is in `setup` mode.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Currency {
     #[serde(rename = "cad")]
     Cad,
@@ -7797,8 +9364,9 @@ pub enum Currency {
     Usd,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Currency {
@@ -7807,12 +9375,45 @@ impl std::fmt::Display for Currency {
             Currency::Cad => "cad",
             Currency::Usd => "usd",
             Currency::Noop => "",
-            Currency::FallthroughString => "*",
+            Currency::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Currency {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cad" => Currency::Cad,
+            "usd" => Currency::Usd,
+            "" => Currency::Noop,
+            _ => Currency::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Currency {
     fn default() -> Currency {
         Currency::Noop
@@ -7827,7 +9428,8 @@ impl Currency {
 /**
 * Bank account verification method.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum VerificationMethod {
     #[serde(rename = "automatic")]
     Automatic,
@@ -7837,8 +9439,9 @@ pub enum VerificationMethod {
     Microdeposits,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for VerificationMethod {
@@ -7848,12 +9451,46 @@ impl std::fmt::Display for VerificationMethod {
             VerificationMethod::Instant => "instant",
             VerificationMethod::Microdeposits => "microdeposits",
             VerificationMethod::Noop => "",
-            VerificationMethod::FallthroughString => "*",
+            VerificationMethod::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for VerificationMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "automatic" => VerificationMethod::Automatic,
+            "instant" => VerificationMethod::Instant,
+            "microdeposits" => VerificationMethod::Microdeposits,
+            "" => VerificationMethod::Noop,
+            _ => VerificationMethod::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for VerificationMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for VerificationMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for VerificationMethod {
     fn default() -> VerificationMethod {
         VerificationMethod::Noop
@@ -7946,7 +9583,8 @@ pub struct CheckoutSessionPaymentMethodOptions {
 /**
 * Bank account verification method.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CheckoutUsBankAccountPaymentMethodOptionsVerification {
     #[serde(rename = "automatic")]
     Automatic,
@@ -7954,8 +9592,9 @@ pub enum CheckoutUsBankAccountPaymentMethodOptionsVerification {
     Instant,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CheckoutUsBankAccountPaymentMethodOptionsVerification {
@@ -7964,12 +9603,45 @@ impl std::fmt::Display for CheckoutUsBankAccountPaymentMethodOptionsVerification
             CheckoutUsBankAccountPaymentMethodOptionsVerification::Automatic => "automatic",
             CheckoutUsBankAccountPaymentMethodOptionsVerification::Instant => "instant",
             CheckoutUsBankAccountPaymentMethodOptionsVerification::Noop => "",
-            CheckoutUsBankAccountPaymentMethodOptionsVerification::FallthroughString => "*",
+            CheckoutUsBankAccountPaymentMethodOptionsVerification::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CheckoutUsBankAccountPaymentMethodOptionsVerification {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "automatic" => CheckoutUsBankAccountPaymentMethodOptionsVerification::Automatic,
+            "instant" => CheckoutUsBankAccountPaymentMethodOptionsVerification::Instant,
+            "" => CheckoutUsBankAccountPaymentMethodOptionsVerification::Noop,
+            _ => CheckoutUsBankAccountPaymentMethodOptionsVerification::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CheckoutUsBankAccountPaymentMethodOptionsVerification {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CheckoutUsBankAccountPaymentMethodOptionsVerification {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CheckoutUsBankAccountPaymentMethodOptionsVerification {
     fn default() -> CheckoutUsBankAccountPaymentMethodOptionsVerification {
         CheckoutUsBankAccountPaymentMethodOptionsVerification::Noop
@@ -7997,14 +9669,16 @@ pub struct CheckoutUsBankAccountPaymentMethodOptions {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ConnectCollectionTransferObject {
     #[serde(rename = "connect_collection_transfer")]
     ConnectCollectionTransfer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ConnectCollectionTransferObject {
@@ -8014,12 +9688,46 @@ impl std::fmt::Display for ConnectCollectionTransferObject {
                 "connect_collection_transfer"
             }
             ConnectCollectionTransferObject::Noop => "",
-            ConnectCollectionTransferObject::FallthroughString => "*",
+            ConnectCollectionTransferObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ConnectCollectionTransferObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "connect_collection_transfer" => {
+                ConnectCollectionTransferObject::ConnectCollectionTransfer
+            }
+            "" => ConnectCollectionTransferObject::Noop,
+            _ => ConnectCollectionTransferObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ConnectCollectionTransferObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ConnectCollectionTransferObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ConnectCollectionTransferObject {
     fn default() -> ConnectCollectionTransferObject {
         ConnectCollectionTransferObject::Noop
@@ -8084,14 +9792,16 @@ pub struct ConnectCollectionTransfer {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CountrySpecObject {
     #[serde(rename = "country_spec")]
     CountrySpec,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CountrySpecObject {
@@ -8099,12 +9809,44 @@ impl std::fmt::Display for CountrySpecObject {
         match self {
             CountrySpecObject::CountrySpec => "country_spec",
             CountrySpecObject::Noop => "",
-            CountrySpecObject::FallthroughString => "*",
+            CountrySpecObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CountrySpecObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "country_spec" => CountrySpecObject::CountrySpec,
+            "" => CountrySpecObject::Noop,
+            _ => CountrySpecObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CountrySpecObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CountrySpecObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CountrySpecObject {
     fn default() -> CountrySpecObject {
         CountrySpecObject::Noop
@@ -8231,7 +9973,8 @@ pub struct CountrySpecVerificationFields {
 /**
 * One of `forever`, `once`, and `repeating`. Describes how long a customer who applies this coupon will get the discount.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Duration {
     #[serde(rename = "forever")]
     Forever,
@@ -8241,8 +9984,9 @@ pub enum Duration {
     Repeating,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Duration {
@@ -8252,12 +9996,46 @@ impl std::fmt::Display for Duration {
             Duration::Once => "once",
             Duration::Repeating => "repeating",
             Duration::Noop => "",
-            Duration::FallthroughString => "*",
+            Duration::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Duration {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "forever" => Duration::Forever,
+            "once" => Duration::Once,
+            "repeating" => Duration::Repeating,
+            "" => Duration::Noop,
+            _ => Duration::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Duration {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Duration {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Duration {
     fn default() -> Duration {
         Duration::Noop
@@ -8272,14 +10050,16 @@ impl Duration {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CouponObject {
     #[serde(rename = "coupon")]
     Coupon,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CouponObject {
@@ -8287,12 +10067,44 @@ impl std::fmt::Display for CouponObject {
         match self {
             CouponObject::Coupon => "coupon",
             CouponObject::Noop => "",
-            CouponObject::FallthroughString => "*",
+            CouponObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CouponObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "coupon" => CouponObject::Coupon,
+            "" => CouponObject::Noop,
+            _ => CouponObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CouponObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CouponObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CouponObject {
     fn default() -> CouponObject {
         CouponObject::Noop
@@ -8563,14 +10375,16 @@ pub struct Lines {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CreditNoteObject {
     #[serde(rename = "credit_note")]
     CreditNote,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CreditNoteObject {
@@ -8578,12 +10392,44 @@ impl std::fmt::Display for CreditNoteObject {
         match self {
             CreditNoteObject::CreditNote => "credit_note",
             CreditNoteObject::Noop => "",
-            CreditNoteObject::FallthroughString => "*",
+            CreditNoteObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CreditNoteObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "credit_note" => CreditNoteObject::CreditNote,
+            "" => CreditNoteObject::Noop,
+            _ => CreditNoteObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CreditNoteObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CreditNoteObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CreditNoteObject {
     fn default() -> CreditNoteObject {
         CreditNoteObject::Noop
@@ -8598,7 +10444,8 @@ impl CreditNoteObject {
 /**
 * Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Reason {
     #[serde(rename = "duplicate")]
     Duplicate,
@@ -8610,8 +10457,9 @@ pub enum Reason {
     ProductUnsatisfactory,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Reason {
@@ -8622,12 +10470,47 @@ impl std::fmt::Display for Reason {
             Reason::OrderChange => "order_change",
             Reason::ProductUnsatisfactory => "product_unsatisfactory",
             Reason::Noop => "",
-            Reason::FallthroughString => "*",
+            Reason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Reason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "duplicate" => Reason::Duplicate,
+            "fraudulent" => Reason::Fraudulent,
+            "order_change" => Reason::OrderChange,
+            "product_unsatisfactory" => Reason::ProductUnsatisfactory,
+            "" => Reason::Noop,
+            _ => Reason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Reason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Reason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Reason {
     fn default() -> Reason {
         Reason::Noop
@@ -8694,7 +10577,8 @@ impl std::convert::From<RefundAnyOf> for String {
 /**
 * Status of this credit note, one of `issued` or `void`. Learn more about [voiding credit notes](https://stripe.com/docs/billing/invoices/credit-notes#voiding).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CreditNoteStatus {
     #[serde(rename = "issued")]
     Issued,
@@ -8702,8 +10586,9 @@ pub enum CreditNoteStatus {
     Void,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CreditNoteStatus {
@@ -8712,12 +10597,45 @@ impl std::fmt::Display for CreditNoteStatus {
             CreditNoteStatus::Issued => "issued",
             CreditNoteStatus::Void => "void",
             CreditNoteStatus::Noop => "",
-            CreditNoteStatus::FallthroughString => "*",
+            CreditNoteStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CreditNoteStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "issued" => CreditNoteStatus::Issued,
+            "void" => CreditNoteStatus::Void,
+            "" => CreditNoteStatus::Noop,
+            _ => CreditNoteStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CreditNoteStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CreditNoteStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CreditNoteStatus {
     fn default() -> CreditNoteStatus {
         CreditNoteStatus::Noop
@@ -8732,7 +10650,8 @@ impl CreditNoteStatus {
 /**
 * Type of this credit note, one of `pre_payment` or `post_payment`. A `pre_payment` credit note means it was issued when the invoice was open. A `post_payment` credit note means it was issued when the invoice was paid.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CreditNoteType {
     #[serde(rename = "post_payment")]
     PostPayment,
@@ -8740,8 +10659,9 @@ pub enum CreditNoteType {
     PrePayment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CreditNoteType {
@@ -8750,12 +10670,45 @@ impl std::fmt::Display for CreditNoteType {
             CreditNoteType::PostPayment => "post_payment",
             CreditNoteType::PrePayment => "pre_payment",
             CreditNoteType::Noop => "",
-            CreditNoteType::FallthroughString => "*",
+            CreditNoteType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CreditNoteType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "post_payment" => CreditNoteType::PostPayment,
+            "pre_payment" => CreditNoteType::PrePayment,
+            "" => CreditNoteType::Noop,
+            _ => CreditNoteType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CreditNoteType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CreditNoteType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CreditNoteType {
     fn default() -> CreditNoteType {
         CreditNoteType::Noop
@@ -8975,14 +10928,16 @@ pub struct CreditNote {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CreditNoteLineItemObject {
     #[serde(rename = "credit_note_line_item")]
     CreditNoteLineItem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CreditNoteLineItemObject {
@@ -8990,12 +10945,44 @@ impl std::fmt::Display for CreditNoteLineItemObject {
         match self {
             CreditNoteLineItemObject::CreditNoteLineItem => "credit_note_line_item",
             CreditNoteLineItemObject::Noop => "",
-            CreditNoteLineItemObject::FallthroughString => "*",
+            CreditNoteLineItemObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CreditNoteLineItemObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "credit_note_line_item" => CreditNoteLineItemObject::CreditNoteLineItem,
+            "" => CreditNoteLineItemObject::Noop,
+            _ => CreditNoteLineItemObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CreditNoteLineItemObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CreditNoteLineItemObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CreditNoteLineItemObject {
     fn default() -> CreditNoteLineItemObject {
         CreditNoteLineItemObject::Noop
@@ -9010,7 +10997,8 @@ impl CreditNoteLineItemObject {
 /**
 * The type of the credit note line item, one of `invoice_line_item` or `custom_line_item`. When the type is `invoice_line_item` there is an additional `invoice_line_item` property on the resource the value of which is the id of the credited line item on the invoice.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CreditNoteLineItemType {
     #[serde(rename = "custom_line_item")]
     CustomLineItem,
@@ -9018,8 +11006,9 @@ pub enum CreditNoteLineItemType {
     InvoiceLineItem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CreditNoteLineItemType {
@@ -9028,12 +11017,45 @@ impl std::fmt::Display for CreditNoteLineItemType {
             CreditNoteLineItemType::CustomLineItem => "custom_line_item",
             CreditNoteLineItemType::InvoiceLineItem => "invoice_line_item",
             CreditNoteLineItemType::Noop => "",
-            CreditNoteLineItemType::FallthroughString => "*",
+            CreditNoteLineItemType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CreditNoteLineItemType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "custom_line_item" => CreditNoteLineItemType::CustomLineItem,
+            "invoice_line_item" => CreditNoteLineItemType::InvoiceLineItem,
+            "" => CreditNoteLineItemType::Noop,
+            _ => CreditNoteLineItemType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CreditNoteLineItemType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CreditNoteLineItemType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CreditNoteLineItemType {
     fn default() -> CreditNoteLineItemType {
         CreditNoteLineItemType::Noop
@@ -9359,14 +11381,16 @@ impl std::convert::From<DefaultSourceAnyOf> for String {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CustomerObject {
     #[serde(rename = "customer")]
     Customer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CustomerObject {
@@ -9374,12 +11398,44 @@ impl std::fmt::Display for CustomerObject {
         match self {
             CustomerObject::Customer => "customer",
             CustomerObject::Noop => "",
-            CustomerObject::FallthroughString => "*",
+            CustomerObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CustomerObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "customer" => CustomerObject::Customer,
+            "" => CustomerObject::Noop,
+            _ => CustomerObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CustomerObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomerObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CustomerObject {
     fn default() -> CustomerObject {
         CustomerObject::Noop
@@ -9549,7 +11605,8 @@ pub struct Subscriptions {
 /**
 * Describes the customer's tax exemption status. One of `none`, `exempt`, or `reverse`. When set to `reverse`, invoice and receipt PDFs include the text **"Reverse charge"**.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TaxExempt {
     #[serde(rename = "exempt")]
     Exempt,
@@ -9559,8 +11616,9 @@ pub enum TaxExempt {
     Reverse,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TaxExempt {
@@ -9570,12 +11628,46 @@ impl std::fmt::Display for TaxExempt {
             TaxExempt::None => "none",
             TaxExempt::Reverse => "reverse",
             TaxExempt::Noop => "",
-            TaxExempt::FallthroughString => "*",
+            TaxExempt::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TaxExempt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "exempt" => TaxExempt::Exempt,
+            "none" => TaxExempt::None,
+            "reverse" => TaxExempt::Reverse,
+            "" => TaxExempt::Noop,
+            _ => TaxExempt::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TaxExempt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TaxExempt {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TaxExempt {
     fn default() -> TaxExempt {
         TaxExempt::Noop
@@ -9903,7 +11995,8 @@ pub struct Customer {
 /**
 * The type of customer acceptance information included with the Mandate. One of `online` or `offline`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CustomerAcceptanceType {
     #[serde(rename = "offline")]
     Offline,
@@ -9911,8 +12004,9 @@ pub enum CustomerAcceptanceType {
     Online,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CustomerAcceptanceType {
@@ -9921,12 +12015,45 @@ impl std::fmt::Display for CustomerAcceptanceType {
             CustomerAcceptanceType::Offline => "offline",
             CustomerAcceptanceType::Online => "online",
             CustomerAcceptanceType::Noop => "",
-            CustomerAcceptanceType::FallthroughString => "*",
+            CustomerAcceptanceType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CustomerAcceptanceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "offline" => CustomerAcceptanceType::Offline,
+            "online" => CustomerAcceptanceType::Online,
+            "" => CustomerAcceptanceType::Noop,
+            _ => CustomerAcceptanceType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CustomerAcceptanceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomerAcceptanceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CustomerAcceptanceType {
     fn default() -> CustomerAcceptanceType {
         CustomerAcceptanceType::Noop
@@ -10074,14 +12201,16 @@ impl std::convert::From<CustomerAnyOfData> for String {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CustomerBalanceTransactionObject {
     #[serde(rename = "customer_balance_transaction")]
     CustomerBalanceTransaction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CustomerBalanceTransactionObject {
@@ -10091,12 +12220,46 @@ impl std::fmt::Display for CustomerBalanceTransactionObject {
                 "customer_balance_transaction"
             }
             CustomerBalanceTransactionObject::Noop => "",
-            CustomerBalanceTransactionObject::FallthroughString => "*",
+            CustomerBalanceTransactionObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CustomerBalanceTransactionObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "customer_balance_transaction" => {
+                CustomerBalanceTransactionObject::CustomerBalanceTransaction
+            }
+            "" => CustomerBalanceTransactionObject::Noop,
+            _ => CustomerBalanceTransactionObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CustomerBalanceTransactionObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomerBalanceTransactionObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CustomerBalanceTransactionObject {
     fn default() -> CustomerBalanceTransactionObject {
         CustomerBalanceTransactionObject::Noop
@@ -10111,7 +12274,8 @@ impl CustomerBalanceTransactionObject {
 /**
 * Transaction type: `adjustment`, `applied_to_invoice`, `credit_note`, `initial`, `invoice_too_large`, `invoice_too_small`, `unspent_receiver_credit`, or `unapplied_from_invoice`. See the [Customer Balance page](https://stripe.com/docs/billing/customer/balance#types) to learn more about transaction types.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CustomerBalanceTransactionType {
     #[serde(rename = "adjustment")]
     Adjustment,
@@ -10133,8 +12297,9 @@ pub enum CustomerBalanceTransactionType {
     UnspentReceiverCredit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CustomerBalanceTransactionType {
@@ -10150,12 +12315,52 @@ impl std::fmt::Display for CustomerBalanceTransactionType {
             CustomerBalanceTransactionType::UnappliedFromInvoice => "unapplied_from_invoice",
             CustomerBalanceTransactionType::UnspentReceiverCredit => "unspent_receiver_credit",
             CustomerBalanceTransactionType::Noop => "",
-            CustomerBalanceTransactionType::FallthroughString => "*",
+            CustomerBalanceTransactionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CustomerBalanceTransactionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "adjustment" => CustomerBalanceTransactionType::Adjustment,
+            "applied_to_invoice" => CustomerBalanceTransactionType::AppliedToInvoice,
+            "credit_note" => CustomerBalanceTransactionType::CreditNote,
+            "initial" => CustomerBalanceTransactionType::Initial,
+            "invoice_too_large" => CustomerBalanceTransactionType::InvoiceTooLarge,
+            "invoice_too_small" => CustomerBalanceTransactionType::InvoiceTooSmall,
+            "migration" => CustomerBalanceTransactionType::Migration,
+            "unapplied_from_invoice" => CustomerBalanceTransactionType::UnappliedFromInvoice,
+            "unspent_receiver_credit" => CustomerBalanceTransactionType::UnspentReceiverCredit,
+            "" => CustomerBalanceTransactionType::Noop,
+            _ => CustomerBalanceTransactionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CustomerBalanceTransactionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomerBalanceTransactionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CustomerBalanceTransactionType {
     fn default() -> CustomerBalanceTransactionType {
         CustomerBalanceTransactionType::Noop
@@ -10300,7 +12505,8 @@ pub struct CustomerBalanceTransaction {
 /**
 * Surfaces if automatic tax computation is possible given the current customer location information.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CustomerTaxAutomatic {
     #[serde(rename = "failed")]
     Failed,
@@ -10312,8 +12518,9 @@ pub enum CustomerTaxAutomatic {
     UnrecognizedLocation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CustomerTaxAutomatic {
@@ -10324,12 +12531,47 @@ impl std::fmt::Display for CustomerTaxAutomatic {
             CustomerTaxAutomatic::Supported => "supported",
             CustomerTaxAutomatic::UnrecognizedLocation => "unrecognized_location",
             CustomerTaxAutomatic::Noop => "",
-            CustomerTaxAutomatic::FallthroughString => "*",
+            CustomerTaxAutomatic::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CustomerTaxAutomatic {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "failed" => CustomerTaxAutomatic::Failed,
+            "not_collecting" => CustomerTaxAutomatic::NotCollecting,
+            "supported" => CustomerTaxAutomatic::Supported,
+            "unrecognized_location" => CustomerTaxAutomatic::UnrecognizedLocation,
+            "" => CustomerTaxAutomatic::Noop,
+            _ => CustomerTaxAutomatic::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CustomerTaxAutomatic {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomerTaxAutomatic {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CustomerTaxAutomatic {
     fn default() -> CustomerTaxAutomatic {
         CustomerTaxAutomatic::Noop
@@ -10368,7 +12610,8 @@ pub struct CustomerTax {
 /**
 * The data source used to infer the customer's location.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Source {
     #[serde(rename = "billing_address")]
     BillingAddress,
@@ -10380,8 +12623,9 @@ pub enum Source {
     ShippingDestination,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Source {
@@ -10392,12 +12636,47 @@ impl std::fmt::Display for Source {
             Source::PaymentMethod => "payment_method",
             Source::ShippingDestination => "shipping_destination",
             Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Source {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "billing_address" => Source::BillingAddress,
+            "ip_address" => Source::IpAddress,
+            "payment_method" => Source::PaymentMethod,
+            "shipping_destination" => Source::ShippingDestination,
+            "" => Source::Noop,
+            _ => Source::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Source {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Source {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Source {
     fn default() -> Source {
         Source::Noop
@@ -10674,14 +12953,16 @@ pub struct DeletedCustomer {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedDiscountObject {
     #[serde(rename = "discount")]
     Discount,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedDiscountObject {
@@ -10689,12 +12970,44 @@ impl std::fmt::Display for DeletedDiscountObject {
         match self {
             DeletedDiscountObject::Discount => "discount",
             DeletedDiscountObject::Noop => "",
-            DeletedDiscountObject::FallthroughString => "*",
+            DeletedDiscountObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedDiscountObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "discount" => DeletedDiscountObject::Discount,
+            "" => DeletedDiscountObject::Noop,
+            _ => DeletedDiscountObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedDiscountObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedDiscountObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedDiscountObject {
     fn default() -> DeletedDiscountObject {
         DeletedDiscountObject::Noop
@@ -10883,14 +13196,16 @@ impl DeletedExternalAccountAnyOf {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedInvoiceObject {
     #[serde(rename = "invoice")]
     Invoice,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedInvoiceObject {
@@ -10898,12 +13213,44 @@ impl std::fmt::Display for DeletedInvoiceObject {
         match self {
             DeletedInvoiceObject::Invoice => "invoice",
             DeletedInvoiceObject::Noop => "",
-            DeletedInvoiceObject::FallthroughString => "*",
+            DeletedInvoiceObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedInvoiceObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "invoice" => DeletedInvoiceObject::Invoice,
+            "" => DeletedInvoiceObject::Noop,
+            _ => DeletedInvoiceObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedInvoiceObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedInvoiceObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedInvoiceObject {
     fn default() -> DeletedInvoiceObject {
         DeletedInvoiceObject::Noop
@@ -10945,14 +13292,16 @@ pub struct DeletedInvoice {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedInvoiceItemObject {
     #[serde(rename = "invoiceitem")]
     Invoiceitem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedInvoiceItemObject {
@@ -10960,12 +13309,44 @@ impl std::fmt::Display for DeletedInvoiceItemObject {
         match self {
             DeletedInvoiceItemObject::Invoiceitem => "invoiceitem",
             DeletedInvoiceItemObject::Noop => "",
-            DeletedInvoiceItemObject::FallthroughString => "*",
+            DeletedInvoiceItemObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedInvoiceItemObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "invoiceitem" => DeletedInvoiceItemObject::Invoiceitem,
+            "" => DeletedInvoiceItemObject::Noop,
+            _ => DeletedInvoiceItemObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedInvoiceItemObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedInvoiceItemObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedInvoiceItemObject {
     fn default() -> DeletedInvoiceItemObject {
         DeletedInvoiceItemObject::Noop
@@ -11067,14 +13448,16 @@ impl DeletedPaymentSourceAnyOf {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedPersonObject {
     #[serde(rename = "person")]
     Person,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedPersonObject {
@@ -11082,12 +13465,44 @@ impl std::fmt::Display for DeletedPersonObject {
         match self {
             DeletedPersonObject::Person => "person",
             DeletedPersonObject::Noop => "",
-            DeletedPersonObject::FallthroughString => "*",
+            DeletedPersonObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedPersonObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "person" => DeletedPersonObject::Person,
+            "" => DeletedPersonObject::Noop,
+            _ => DeletedPersonObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedPersonObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedPersonObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedPersonObject {
     fn default() -> DeletedPersonObject {
         DeletedPersonObject::Noop
@@ -11129,14 +13544,16 @@ pub struct DeletedPerson {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedPlanObject {
     #[serde(rename = "plan")]
     Plan,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedPlanObject {
@@ -11144,12 +13561,44 @@ impl std::fmt::Display for DeletedPlanObject {
         match self {
             DeletedPlanObject::Plan => "plan",
             DeletedPlanObject::Noop => "",
-            DeletedPlanObject::FallthroughString => "*",
+            DeletedPlanObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedPlanObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "plan" => DeletedPlanObject::Plan,
+            "" => DeletedPlanObject::Noop,
+            _ => DeletedPlanObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedPlanObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedPlanObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedPlanObject {
     fn default() -> DeletedPlanObject {
         DeletedPlanObject::Noop
@@ -11191,14 +13640,16 @@ pub struct DeletedPlan {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedPriceObject {
     #[serde(rename = "price")]
     Price,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedPriceObject {
@@ -11206,12 +13657,44 @@ impl std::fmt::Display for DeletedPriceObject {
         match self {
             DeletedPriceObject::Price => "price",
             DeletedPriceObject::Noop => "",
-            DeletedPriceObject::FallthroughString => "*",
+            DeletedPriceObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedPriceObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "price" => DeletedPriceObject::Price,
+            "" => DeletedPriceObject::Noop,
+            _ => DeletedPriceObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedPriceObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedPriceObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedPriceObject {
     fn default() -> DeletedPriceObject {
         DeletedPriceObject::Noop
@@ -11253,14 +13736,16 @@ pub struct DeletedPrice {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedProductObject {
     #[serde(rename = "product")]
     Product,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedProductObject {
@@ -11268,12 +13753,44 @@ impl std::fmt::Display for DeletedProductObject {
         match self {
             DeletedProductObject::Product => "product",
             DeletedProductObject::Noop => "",
-            DeletedProductObject::FallthroughString => "*",
+            DeletedProductObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedProductObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "product" => DeletedProductObject::Product,
+            "" => DeletedProductObject::Noop,
+            _ => DeletedProductObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedProductObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedProductObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedProductObject {
     fn default() -> DeletedProductObject {
         DeletedProductObject::Noop
@@ -11315,14 +13832,16 @@ pub struct DeletedProduct {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RadarListDeletedObject {
     #[serde(rename = "radar.value_list")]
     RadarValueList,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RadarListDeletedObject {
@@ -11330,12 +13849,44 @@ impl std::fmt::Display for RadarListDeletedObject {
         match self {
             RadarListDeletedObject::RadarValueList => "radar.value_list",
             RadarListDeletedObject::Noop => "",
-            RadarListDeletedObject::FallthroughString => "*",
+            RadarListDeletedObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RadarListDeletedObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "radar.value_list" => RadarListDeletedObject::RadarValueList,
+            "" => RadarListDeletedObject::Noop,
+            _ => RadarListDeletedObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RadarListDeletedObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RadarListDeletedObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RadarListDeletedObject {
     fn default() -> RadarListDeletedObject {
         RadarListDeletedObject::Noop
@@ -11377,14 +13928,16 @@ pub struct RadarListDeleted {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RadarListDeletedItemObject {
     #[serde(rename = "radar.value_list_item")]
     RadarValueListItem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RadarListDeletedItemObject {
@@ -11392,12 +13945,44 @@ impl std::fmt::Display for RadarListDeletedItemObject {
         match self {
             RadarListDeletedItemObject::RadarValueListItem => "radar.value_list_item",
             RadarListDeletedItemObject::Noop => "",
-            RadarListDeletedItemObject::FallthroughString => "*",
+            RadarListDeletedItemObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RadarListDeletedItemObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "radar.value_list_item" => RadarListDeletedItemObject::RadarValueListItem,
+            "" => RadarListDeletedItemObject::Noop,
+            _ => RadarListDeletedItemObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RadarListDeletedItemObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RadarListDeletedItemObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RadarListDeletedItemObject {
     fn default() -> RadarListDeletedItemObject {
         RadarListDeletedItemObject::Noop
@@ -11439,14 +14024,16 @@ pub struct RadarListDeletedItem {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedRecipientObject {
     #[serde(rename = "recipient")]
     Recipient,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedRecipientObject {
@@ -11454,12 +14041,44 @@ impl std::fmt::Display for DeletedRecipientObject {
         match self {
             DeletedRecipientObject::Recipient => "recipient",
             DeletedRecipientObject::Noop => "",
-            DeletedRecipientObject::FallthroughString => "*",
+            DeletedRecipientObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedRecipientObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "recipient" => DeletedRecipientObject::Recipient,
+            "" => DeletedRecipientObject::Noop,
+            _ => DeletedRecipientObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedRecipientObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedRecipientObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedRecipientObject {
     fn default() -> DeletedRecipientObject {
         DeletedRecipientObject::Noop
@@ -11501,14 +14120,16 @@ pub struct DeletedRecipient {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedSkuObject {
     #[serde(rename = "sku")]
     Sku,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedSkuObject {
@@ -11516,12 +14137,44 @@ impl std::fmt::Display for DeletedSkuObject {
         match self {
             DeletedSkuObject::Sku => "sku",
             DeletedSkuObject::Noop => "",
-            DeletedSkuObject::FallthroughString => "*",
+            DeletedSkuObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedSkuObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "sku" => DeletedSkuObject::Sku,
+            "" => DeletedSkuObject::Noop,
+            _ => DeletedSkuObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedSkuObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedSkuObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedSkuObject {
     fn default() -> DeletedSkuObject {
         DeletedSkuObject::Noop
@@ -11563,14 +14216,16 @@ pub struct DeletedSku {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedSubscriptionItemObject {
     #[serde(rename = "subscription_item")]
     SubscriptionItem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedSubscriptionItemObject {
@@ -11578,12 +14233,44 @@ impl std::fmt::Display for DeletedSubscriptionItemObject {
         match self {
             DeletedSubscriptionItemObject::SubscriptionItem => "subscription_item",
             DeletedSubscriptionItemObject::Noop => "",
-            DeletedSubscriptionItemObject::FallthroughString => "*",
+            DeletedSubscriptionItemObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedSubscriptionItemObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "subscription_item" => DeletedSubscriptionItemObject::SubscriptionItem,
+            "" => DeletedSubscriptionItemObject::Noop,
+            _ => DeletedSubscriptionItemObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedSubscriptionItemObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedSubscriptionItemObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedSubscriptionItemObject {
     fn default() -> DeletedSubscriptionItemObject {
         DeletedSubscriptionItemObject::Noop
@@ -11628,14 +14315,16 @@ pub struct DeletedSubscriptionItem {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedTaxObject {
     #[serde(rename = "tax_id")]
     TaxId,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedTaxObject {
@@ -11643,12 +14332,44 @@ impl std::fmt::Display for DeletedTaxObject {
         match self {
             DeletedTaxObject::TaxId => "tax_id",
             DeletedTaxObject::Noop => "",
-            DeletedTaxObject::FallthroughString => "*",
+            DeletedTaxObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedTaxObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "tax_id" => DeletedTaxObject::TaxId,
+            "" => DeletedTaxObject::Noop,
+            _ => DeletedTaxObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedTaxObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedTaxObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedTaxObject {
     fn default() -> DeletedTaxObject {
         DeletedTaxObject::Noop
@@ -11690,14 +14411,16 @@ pub struct DeletedTaxId {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedTerminalLocationObject {
     #[serde(rename = "terminal.location")]
     TerminalLocation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedTerminalLocationObject {
@@ -11705,12 +14428,44 @@ impl std::fmt::Display for DeletedTerminalLocationObject {
         match self {
             DeletedTerminalLocationObject::TerminalLocation => "terminal.location",
             DeletedTerminalLocationObject::Noop => "",
-            DeletedTerminalLocationObject::FallthroughString => "*",
+            DeletedTerminalLocationObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedTerminalLocationObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "terminal.location" => DeletedTerminalLocationObject::TerminalLocation,
+            "" => DeletedTerminalLocationObject::Noop,
+            _ => DeletedTerminalLocationObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedTerminalLocationObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedTerminalLocationObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedTerminalLocationObject {
     fn default() -> DeletedTerminalLocationObject {
         DeletedTerminalLocationObject::Noop
@@ -11755,14 +14510,16 @@ pub struct DeletedTerminalLocation {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedTerminalReaderObject {
     #[serde(rename = "terminal.reader")]
     TerminalReader,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedTerminalReaderObject {
@@ -11770,12 +14527,44 @@ impl std::fmt::Display for DeletedTerminalReaderObject {
         match self {
             DeletedTerminalReaderObject::TerminalReader => "terminal.reader",
             DeletedTerminalReaderObject::Noop => "",
-            DeletedTerminalReaderObject::FallthroughString => "*",
+            DeletedTerminalReaderObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedTerminalReaderObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "terminal.reader" => DeletedTerminalReaderObject::TerminalReader,
+            "" => DeletedTerminalReaderObject::Noop,
+            _ => DeletedTerminalReaderObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedTerminalReaderObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedTerminalReaderObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedTerminalReaderObject {
     fn default() -> DeletedTerminalReaderObject {
         DeletedTerminalReaderObject::Noop
@@ -11817,14 +14606,16 @@ pub struct DeletedTerminalReader {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedTestClockObject {
     #[serde(rename = "test_helpers.test_clock")]
     TestHelpersClock,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedTestClockObject {
@@ -11832,12 +14623,44 @@ impl std::fmt::Display for DeletedTestClockObject {
         match self {
             DeletedTestClockObject::TestHelpersClock => "test_helpers.test_clock",
             DeletedTestClockObject::Noop => "",
-            DeletedTestClockObject::FallthroughString => "*",
+            DeletedTestClockObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedTestClockObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "test_helpers.test_clock" => DeletedTestClockObject::TestHelpersClock,
+            "" => DeletedTestClockObject::Noop,
+            _ => DeletedTestClockObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedTestClockObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedTestClockObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedTestClockObject {
     fn default() -> DeletedTestClockObject {
         DeletedTestClockObject::Noop
@@ -11879,14 +14702,16 @@ pub struct DeletedTestClock {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeletedWebhookEndpointObject {
     #[serde(rename = "webhook_endpoint")]
     WebhookEndpoint,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeletedWebhookEndpointObject {
@@ -11894,12 +14719,44 @@ impl std::fmt::Display for DeletedWebhookEndpointObject {
         match self {
             DeletedWebhookEndpointObject::WebhookEndpoint => "webhook_endpoint",
             DeletedWebhookEndpointObject::Noop => "",
-            DeletedWebhookEndpointObject::FallthroughString => "*",
+            DeletedWebhookEndpointObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeletedWebhookEndpointObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "webhook_endpoint" => DeletedWebhookEndpointObject::WebhookEndpoint,
+            "" => DeletedWebhookEndpointObject::Noop,
+            _ => DeletedWebhookEndpointObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeletedWebhookEndpointObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeletedWebhookEndpointObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeletedWebhookEndpointObject {
     fn default() -> DeletedWebhookEndpointObject {
         DeletedWebhookEndpointObject::Noop
@@ -12185,14 +15042,16 @@ pub struct DiscountsResourceDiscountAmount {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DisputeObject {
     #[serde(rename = "dispute")]
     Dispute,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DisputeObject {
@@ -12200,12 +15059,44 @@ impl std::fmt::Display for DisputeObject {
         match self {
             DisputeObject::Dispute => "dispute",
             DisputeObject::Noop => "",
-            DisputeObject::FallthroughString => "*",
+            DisputeObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DisputeObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "dispute" => DisputeObject::Dispute,
+            "" => DisputeObject::Noop,
+            _ => DisputeObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DisputeObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DisputeObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DisputeObject {
     fn default() -> DisputeObject {
         DisputeObject::Noop
@@ -12220,7 +15111,8 @@ impl DisputeObject {
 /**
 * Current status of dispute. Possible values are `warning_needs_response`, `warning_under_review`, `warning_closed`, `needs_response`, `under_review`, `charge_refunded`, `won`, or `lost`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DisputeStatus {
     #[serde(rename = "charge_refunded")]
     ChargeRefunded,
@@ -12240,8 +15132,9 @@ pub enum DisputeStatus {
     Won,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DisputeStatus {
@@ -12256,12 +15149,51 @@ impl std::fmt::Display for DisputeStatus {
             DisputeStatus::WarningUnderReview => "warning_under_review",
             DisputeStatus::Won => "won",
             DisputeStatus::Noop => "",
-            DisputeStatus::FallthroughString => "*",
+            DisputeStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DisputeStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "charge_refunded" => DisputeStatus::ChargeRefunded,
+            "lost" => DisputeStatus::Lost,
+            "needs_response" => DisputeStatus::NeedsResponse,
+            "under_review" => DisputeStatus::UnderReview,
+            "warning_closed" => DisputeStatus::WarningClosed,
+            "warning_needs_response" => DisputeStatus::WarningNeedsResponse,
+            "warning_under_review" => DisputeStatus::WarningUnderReview,
+            "won" => DisputeStatus::Won,
+            "" => DisputeStatus::Noop,
+            _ => DisputeStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DisputeStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DisputeStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DisputeStatus {
     fn default() -> DisputeStatus {
         DisputeStatus::Noop
@@ -12673,14 +15605,16 @@ pub struct EmailSent {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EphemeralKeyObject {
     #[serde(rename = "ephemeral_key")]
     EphemeralKey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EphemeralKeyObject {
@@ -12688,12 +15622,44 @@ impl std::fmt::Display for EphemeralKeyObject {
         match self {
             EphemeralKeyObject::EphemeralKey => "ephemeral_key",
             EphemeralKeyObject::Noop => "",
-            EphemeralKeyObject::FallthroughString => "*",
+            EphemeralKeyObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EphemeralKeyObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ephemeral_key" => EphemeralKeyObject::EphemeralKey,
+            "" => EphemeralKeyObject::Noop,
+            _ => EphemeralKeyObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EphemeralKeyObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EphemeralKeyObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EphemeralKeyObject {
     fn default() -> EphemeralKeyObject {
         EphemeralKeyObject::Noop
@@ -12772,14 +15738,16 @@ pub struct Error {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EventObject {
     #[serde(rename = "event")]
     Event,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EventObject {
@@ -12787,12 +15755,44 @@ impl std::fmt::Display for EventObject {
         match self {
             EventObject::Event => "event",
             EventObject::Noop => "",
-            EventObject::FallthroughString => "*",
+            EventObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EventObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "event" => EventObject::Event,
+            "" => EventObject::Noop,
+            _ => EventObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EventObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EventObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EventObject {
     fn default() -> EventObject {
         EventObject::Noop
@@ -12974,14 +15974,16 @@ pub struct Event {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ExchangeRateObject {
     #[serde(rename = "exchange_rate")]
     ExchangeRate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ExchangeRateObject {
@@ -12989,12 +15991,44 @@ impl std::fmt::Display for ExchangeRateObject {
         match self {
             ExchangeRateObject::ExchangeRate => "exchange_rate",
             ExchangeRateObject::Noop => "",
-            ExchangeRateObject::FallthroughString => "*",
+            ExchangeRateObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ExchangeRateObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "exchange_rate" => ExchangeRateObject::ExchangeRate,
+            "" => ExchangeRateObject::Noop,
+            _ => ExchangeRateObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ExchangeRateObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ExchangeRateObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ExchangeRateObject {
     fn default() -> ExchangeRateObject {
         ExchangeRateObject::Noop
@@ -13098,14 +16132,16 @@ pub struct Fee {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FeeRefundObject {
     #[serde(rename = "fee_refund")]
     FeeRefund,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FeeRefundObject {
@@ -13113,12 +16149,44 @@ impl std::fmt::Display for FeeRefundObject {
         match self {
             FeeRefundObject::FeeRefund => "fee_refund",
             FeeRefundObject::Noop => "",
-            FeeRefundObject::FallthroughString => "*",
+            FeeRefundObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FeeRefundObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "fee_refund" => FeeRefundObject::FeeRefund,
+            "" => FeeRefundObject::Noop,
+            _ => FeeRefundObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FeeRefundObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FeeRefundObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FeeRefundObject {
     fn default() -> FeeRefundObject {
         FeeRefundObject::Noop
@@ -13243,14 +16311,16 @@ pub struct Links {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FileObject {
     #[serde(rename = "file")]
     File,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FileObject {
@@ -13258,12 +16328,44 @@ impl std::fmt::Display for FileObject {
         match self {
             FileObject::File => "file",
             FileObject::Noop => "",
-            FileObject::FallthroughString => "*",
+            FileObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FileObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "file" => FileObject::File,
+            "" => FileObject::Noop,
+            _ => FileObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FileObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FileObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FileObject {
     fn default() -> FileObject {
         FileObject::Noop
@@ -13278,7 +16380,8 @@ impl FileObject {
 /**
 * The [purpose](https://stripe.com/docs/file-upload#uploading-a-file) of the uploaded file.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Purpose {
     #[serde(rename = "account_requirement")]
     AccountRequirement,
@@ -13310,8 +16413,9 @@ pub enum Purpose {
     TaxDocumentUserUpload,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Purpose {
@@ -13332,12 +16436,57 @@ impl std::fmt::Display for Purpose {
             Purpose::SigmaScheduledQuery => "sigma_scheduled_query",
             Purpose::TaxDocumentUserUpload => "tax_document_user_upload",
             Purpose::Noop => "",
-            Purpose::FallthroughString => "*",
+            Purpose::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Purpose {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "account_requirement" => Purpose::AccountRequirement,
+            "additional_verification" => Purpose::AdditionalVerification,
+            "business_icon" => Purpose::BusinessIcon,
+            "business_logo" => Purpose::BusinessLogo,
+            "customer_signature" => Purpose::CustomerSignature,
+            "dispute_evidence" => Purpose::DisputeEvidence,
+            "document_provider_identity_document" => Purpose::DocumentProviderIdentity,
+            "finance_report_run" => Purpose::FinanceReportRun,
+            "identity_document" => Purpose::IdentityDocument,
+            "identity_document_downloadable" => Purpose::IdentityDocumentDownloadable,
+            "pci_document" => Purpose::PciDocument,
+            "selfie" => Purpose::Selfie,
+            "sigma_scheduled_query" => Purpose::SigmaScheduledQuery,
+            "tax_document_user_upload" => Purpose::TaxDocumentUserUpload,
+            "" => Purpose::Noop,
+            _ => Purpose::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Purpose {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Purpose {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Purpose {
     fn default() -> Purpose {
         Purpose::Noop
@@ -13481,14 +16630,16 @@ pub struct File {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FileLinkObject {
     #[serde(rename = "file_link")]
     FileLink,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FileLinkObject {
@@ -13496,12 +16647,44 @@ impl std::fmt::Display for FileLinkObject {
         match self {
             FileLinkObject::FileLink => "file_link",
             FileLinkObject::Noop => "",
-            FileLinkObject::FallthroughString => "*",
+            FileLinkObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FileLinkObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "file_link" => FileLinkObject::FileLink,
+            "" => FileLinkObject::Noop,
+            _ => FileLinkObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FileLinkObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FileLinkObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FileLinkObject {
     fn default() -> FileLinkObject {
         FileLinkObject::Noop
@@ -13832,7 +17015,8 @@ pub struct GelatoDataVerifiedOutputsDate {
 /**
 * Status of this `document` check.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GelatoDocumentReportStatus {
     #[serde(rename = "unverified")]
     Unverified,
@@ -13840,8 +17024,9 @@ pub enum GelatoDocumentReportStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GelatoDocumentReportStatus {
@@ -13850,12 +17035,45 @@ impl std::fmt::Display for GelatoDocumentReportStatus {
             GelatoDocumentReportStatus::Unverified => "unverified",
             GelatoDocumentReportStatus::Verified => "verified",
             GelatoDocumentReportStatus::Noop => "",
-            GelatoDocumentReportStatus::FallthroughString => "*",
+            GelatoDocumentReportStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GelatoDocumentReportStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "unverified" => GelatoDocumentReportStatus::Unverified,
+            "verified" => GelatoDocumentReportStatus::Verified,
+            "" => GelatoDocumentReportStatus::Noop,
+            _ => GelatoDocumentReportStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GelatoDocumentReportStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GelatoDocumentReportStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GelatoDocumentReportStatus {
     fn default() -> GelatoDocumentReportStatus {
         GelatoDocumentReportStatus::Noop
@@ -13867,7 +17085,8 @@ impl GelatoDocumentReportStatus {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AllowedTypes {
     #[serde(rename = "driving_license")]
     DrivingLicense,
@@ -13877,8 +17096,9 @@ pub enum AllowedTypes {
     Passport,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AllowedTypes {
@@ -13888,12 +17108,46 @@ impl std::fmt::Display for AllowedTypes {
             AllowedTypes::IdCard => "id_card",
             AllowedTypes::Passport => "passport",
             AllowedTypes::Noop => "",
-            AllowedTypes::FallthroughString => "*",
+            AllowedTypes::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AllowedTypes {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "driving_license" => AllowedTypes::DrivingLicense,
+            "id_card" => AllowedTypes::IdCard,
+            "passport" => AllowedTypes::Passport,
+            "" => AllowedTypes::Noop,
+            _ => AllowedTypes::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AllowedTypes {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AllowedTypes {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AllowedTypes {
     fn default() -> AllowedTypes {
         AllowedTypes::Noop
@@ -13993,7 +17247,8 @@ pub struct GelatoDocumentReport {
 /**
 * A short machine-readable string giving the reason for the verification failure.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GelatoDocumentReportErrorCode {
     #[serde(rename = "document_expired")]
     DocumentExpired,
@@ -14003,8 +17258,9 @@ pub enum GelatoDocumentReportErrorCode {
     DocumentUnverifiedOther,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GelatoDocumentReportErrorCode {
@@ -14016,12 +17272,48 @@ impl std::fmt::Display for GelatoDocumentReportErrorCode {
             }
             GelatoDocumentReportErrorCode::DocumentUnverifiedOther => "document_unverified_other",
             GelatoDocumentReportErrorCode::Noop => "",
-            GelatoDocumentReportErrorCode::FallthroughString => "*",
+            GelatoDocumentReportErrorCode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GelatoDocumentReportErrorCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "document_expired" => GelatoDocumentReportErrorCode::DocumentExpired,
+            "document_type_not_supported" => {
+                GelatoDocumentReportErrorCode::DocumentTypeNotSupported
+            }
+            "document_unverified_other" => GelatoDocumentReportErrorCode::DocumentUnverifiedOther,
+            "" => GelatoDocumentReportErrorCode::Noop,
+            _ => GelatoDocumentReportErrorCode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GelatoDocumentReportErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GelatoDocumentReportErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GelatoDocumentReportErrorCode {
     fn default() -> GelatoDocumentReportErrorCode {
         GelatoDocumentReportErrorCode::Noop
@@ -14055,7 +17347,8 @@ pub struct GelatoDocumentReportErrorData {
 /**
 * Type of ID number.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IdNumberType {
     #[serde(rename = "br_cpf")]
     BrCpf,
@@ -14065,8 +17358,9 @@ pub enum IdNumberType {
     UsSsn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IdNumberType {
@@ -14076,12 +17370,46 @@ impl std::fmt::Display for IdNumberType {
             IdNumberType::SgNric => "sg_nric",
             IdNumberType::UsSsn => "us_ssn",
             IdNumberType::Noop => "",
-            IdNumberType::FallthroughString => "*",
+            IdNumberType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IdNumberType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "br_cpf" => IdNumberType::BrCpf,
+            "sg_nric" => IdNumberType::SgNric,
+            "us_ssn" => IdNumberType::UsSsn,
+            "" => IdNumberType::Noop,
+            _ => IdNumberType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IdNumberType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IdNumberType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IdNumberType {
     fn default() -> IdNumberType {
         IdNumberType::Noop
@@ -14148,7 +17476,8 @@ pub struct GelatoNumberReport {
 /**
 * A short machine-readable string giving the reason for the verification failure.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GelatoNumberReportErrorCode {
     #[serde(rename = "id_number_insufficient_document_data")]
     IdNumberInsufficientDocumentData,
@@ -14158,8 +17487,9 @@ pub enum GelatoNumberReportErrorCode {
     IdNumberUnverifiedOther,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GelatoNumberReportErrorCode {
@@ -14171,12 +17501,48 @@ impl std::fmt::Display for GelatoNumberReportErrorCode {
             GelatoNumberReportErrorCode::IdNumberMismatch => "id_number_mismatch",
             GelatoNumberReportErrorCode::IdNumberUnverifiedOther => "id_number_unverified_other",
             GelatoNumberReportErrorCode::Noop => "",
-            GelatoNumberReportErrorCode::FallthroughString => "*",
+            GelatoNumberReportErrorCode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GelatoNumberReportErrorCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "id_number_insufficient_document_data" => {
+                GelatoNumberReportErrorCode::IdNumberInsufficientDocumentData
+            }
+            "id_number_mismatch" => GelatoNumberReportErrorCode::IdNumberMismatch,
+            "id_number_unverified_other" => GelatoNumberReportErrorCode::IdNumberUnverifiedOther,
+            "" => GelatoNumberReportErrorCode::Noop,
+            _ => GelatoNumberReportErrorCode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GelatoNumberReportErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GelatoNumberReportErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GelatoNumberReportErrorCode {
     fn default() -> GelatoNumberReportErrorCode {
         GelatoNumberReportErrorCode::Noop
@@ -14281,7 +17647,8 @@ pub struct GelatoSelfieReport {
 /**
 * A short machine-readable string giving the reason for the verification failure.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GelatoSelfieReportErrorCode {
     #[serde(rename = "selfie_document_missing_photo")]
     SelfieDocumentMissingPhoto,
@@ -14293,8 +17660,9 @@ pub enum GelatoSelfieReportErrorCode {
     SelfieUnverifiedOther,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GelatoSelfieReportErrorCode {
@@ -14307,12 +17675,49 @@ impl std::fmt::Display for GelatoSelfieReportErrorCode {
             GelatoSelfieReportErrorCode::SelfieManipulated => "selfie_manipulated",
             GelatoSelfieReportErrorCode::SelfieUnverifiedOther => "selfie_unverified_other",
             GelatoSelfieReportErrorCode::Noop => "",
-            GelatoSelfieReportErrorCode::FallthroughString => "*",
+            GelatoSelfieReportErrorCode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GelatoSelfieReportErrorCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "selfie_document_missing_photo" => {
+                GelatoSelfieReportErrorCode::SelfieDocumentMissingPhoto
+            }
+            "selfie_face_mismatch" => GelatoSelfieReportErrorCode::SelfieFaceMismatch,
+            "selfie_manipulated" => GelatoSelfieReportErrorCode::SelfieManipulated,
+            "selfie_unverified_other" => GelatoSelfieReportErrorCode::SelfieUnverifiedOther,
+            "" => GelatoSelfieReportErrorCode::Noop,
+            _ => GelatoSelfieReportErrorCode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GelatoSelfieReportErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GelatoSelfieReportErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GelatoSelfieReportErrorCode {
     fn default() -> GelatoSelfieReportErrorCode {
         GelatoSelfieReportErrorCode::Noop
@@ -14384,7 +17789,8 @@ pub struct GelatoSessionDocumentOptions {
 /**
 * A short machine-readable string giving the reason for the verification or user-session failure.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GelatoSessionLastErrorCode {
     #[serde(rename = "abandoned")]
     Abandoned,
@@ -14418,8 +17824,9 @@ pub enum GelatoSessionLastErrorCode {
     UnderSupportedAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GelatoSessionLastErrorCode {
@@ -14445,12 +17852,62 @@ impl std::fmt::Display for GelatoSessionLastErrorCode {
             GelatoSessionLastErrorCode::SelfieUnverifiedOther => "selfie_unverified_other",
             GelatoSessionLastErrorCode::UnderSupportedAge => "under_supported_age",
             GelatoSessionLastErrorCode::Noop => "",
-            GelatoSessionLastErrorCode::FallthroughString => "*",
+            GelatoSessionLastErrorCode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GelatoSessionLastErrorCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "abandoned" => GelatoSessionLastErrorCode::Abandoned,
+            "consent_declined" => GelatoSessionLastErrorCode::ConsentDeclined,
+            "country_not_supported" => GelatoSessionLastErrorCode::CountryNotSupported,
+            "device_not_supported" => GelatoSessionLastErrorCode::DeviceNotSupported,
+            "document_expired" => GelatoSessionLastErrorCode::DocumentExpired,
+            "document_type_not_supported" => GelatoSessionLastErrorCode::DocumentTypeNotSupported,
+            "document_unverified_other" => GelatoSessionLastErrorCode::DocumentUnverifiedOther,
+            "id_number_insufficient_document_data" => {
+                GelatoSessionLastErrorCode::IdNumberInsufficientDocumentData
+            }
+            "id_number_mismatch" => GelatoSessionLastErrorCode::IdNumberMismatch,
+            "id_number_unverified_other" => GelatoSessionLastErrorCode::IdNumberUnverifiedOther,
+            "selfie_document_missing_photo" => {
+                GelatoSessionLastErrorCode::SelfieDocumentMissingPhoto
+            }
+            "selfie_face_mismatch" => GelatoSessionLastErrorCode::SelfieFaceMismatch,
+            "selfie_manipulated" => GelatoSessionLastErrorCode::SelfieManipulated,
+            "selfie_unverified_other" => GelatoSessionLastErrorCode::SelfieUnverifiedOther,
+            "under_supported_age" => GelatoSessionLastErrorCode::UnderSupportedAge,
+            "" => GelatoSessionLastErrorCode::Noop,
+            _ => GelatoSessionLastErrorCode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GelatoSessionLastErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GelatoSessionLastErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GelatoSessionLastErrorCode {
     fn default() -> GelatoSessionLastErrorCode {
         GelatoSessionLastErrorCode::Noop
@@ -14561,14 +18018,16 @@ pub struct GelatoVerifiedOutputs {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GelatoVerificationReportObject {
     #[serde(rename = "identity.verification_report")]
     IdentityVerificationReport,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GelatoVerificationReportObject {
@@ -14578,12 +18037,46 @@ impl std::fmt::Display for GelatoVerificationReportObject {
                 "identity.verification_report"
             }
             GelatoVerificationReportObject::Noop => "",
-            GelatoVerificationReportObject::FallthroughString => "*",
+            GelatoVerificationReportObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GelatoVerificationReportObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "identity.verification_report" => {
+                GelatoVerificationReportObject::IdentityVerificationReport
+            }
+            "" => GelatoVerificationReportObject::Noop,
+            _ => GelatoVerificationReportObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GelatoVerificationReportObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GelatoVerificationReportObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GelatoVerificationReportObject {
     fn default() -> GelatoVerificationReportObject {
         GelatoVerificationReportObject::Noop
@@ -14598,7 +18091,8 @@ impl GelatoVerificationReportObject {
 /**
 * Type of report.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GelatoVerificationReportType {
     #[serde(rename = "document")]
     Document,
@@ -14606,8 +18100,9 @@ pub enum GelatoVerificationReportType {
     IdNumber,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GelatoVerificationReportType {
@@ -14616,12 +18111,45 @@ impl std::fmt::Display for GelatoVerificationReportType {
             GelatoVerificationReportType::Document => "document",
             GelatoVerificationReportType::IdNumber => "id_number",
             GelatoVerificationReportType::Noop => "",
-            GelatoVerificationReportType::FallthroughString => "*",
+            GelatoVerificationReportType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GelatoVerificationReportType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "document" => GelatoVerificationReportType::Document,
+            "id_number" => GelatoVerificationReportType::IdNumber,
+            "" => GelatoVerificationReportType::Noop,
+            _ => GelatoVerificationReportType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GelatoVerificationReportType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GelatoVerificationReportType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GelatoVerificationReportType {
     fn default() -> GelatoVerificationReportType {
         GelatoVerificationReportType::Noop
@@ -14821,14 +18349,16 @@ impl std::convert::From<LastVerificationReportAnyOf> for String {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GelatoVerificationSessionObject {
     #[serde(rename = "identity.verification_session")]
     IdentityVerificationSession,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GelatoVerificationSessionObject {
@@ -14838,12 +18368,46 @@ impl std::fmt::Display for GelatoVerificationSessionObject {
                 "identity.verification_session"
             }
             GelatoVerificationSessionObject::Noop => "",
-            GelatoVerificationSessionObject::FallthroughString => "*",
+            GelatoVerificationSessionObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GelatoVerificationSessionObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "identity.verification_session" => {
+                GelatoVerificationSessionObject::IdentityVerificationSession
+            }
+            "" => GelatoVerificationSessionObject::Noop,
+            _ => GelatoVerificationSessionObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GelatoVerificationSessionObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GelatoVerificationSessionObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GelatoVerificationSessionObject {
     fn default() -> GelatoVerificationSessionObject {
         GelatoVerificationSessionObject::Noop
@@ -14858,7 +18422,8 @@ impl GelatoVerificationSessionObject {
 /**
 * Status of this VerificationSession. [Learn more about the lifecycle of sessions](https://stripe.com/docs/identity/how-sessions-work).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GelatoVerificationSessionStatus {
     #[serde(rename = "canceled")]
     Canceled,
@@ -14870,8 +18435,9 @@ pub enum GelatoVerificationSessionStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GelatoVerificationSessionStatus {
@@ -14882,12 +18448,47 @@ impl std::fmt::Display for GelatoVerificationSessionStatus {
             GelatoVerificationSessionStatus::RequiresInput => "requires_input",
             GelatoVerificationSessionStatus::Verified => "verified",
             GelatoVerificationSessionStatus::Noop => "",
-            GelatoVerificationSessionStatus::FallthroughString => "*",
+            GelatoVerificationSessionStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GelatoVerificationSessionStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "canceled" => GelatoVerificationSessionStatus::Canceled,
+            "processing" => GelatoVerificationSessionStatus::Processing,
+            "requires_input" => GelatoVerificationSessionStatus::RequiresInput,
+            "verified" => GelatoVerificationSessionStatus::Verified,
+            "" => GelatoVerificationSessionStatus::Noop,
+            _ => GelatoVerificationSessionStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GelatoVerificationSessionStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GelatoVerificationSessionStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GelatoVerificationSessionStatus {
     fn default() -> GelatoVerificationSessionStatus {
         GelatoVerificationSessionStatus::Noop
@@ -15113,7 +18714,8 @@ impl std::convert::From<AccountTaxIdsAnyOf> for String {
 /**
 * Indicates the reason why the invoice was created. `subscription_cycle` indicates an invoice created by a subscription advancing into a new period. `subscription_create` indicates an invoice created due to creating a subscription. `subscription_update` indicates an invoice created due to updating a subscription. `subscription` is set for all old invoices to indicate either a change to a subscription or a period advancement. `manual` is set for all invoices unrelated to a subscription (for example: created via the invoice editor). The `upcoming` value is reserved for simulated invoices per the upcoming invoice endpoint. `subscription_threshold` indicates an invoice created due to a billing threshold being reached.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BillingReason {
     #[serde(rename = "automatic_pending_invoice_item_invoice")]
     AutomaticPendingInvoiceItem,
@@ -15135,8 +18737,9 @@ pub enum BillingReason {
     Upcoming,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BillingReason {
@@ -15152,12 +18755,52 @@ impl std::fmt::Display for BillingReason {
             BillingReason::SubscriptionUpdate => "subscription_update",
             BillingReason::Upcoming => "upcoming",
             BillingReason::Noop => "",
-            BillingReason::FallthroughString => "*",
+            BillingReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BillingReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "automatic_pending_invoice_item_invoice" => BillingReason::AutomaticPendingInvoiceItem,
+            "manual" => BillingReason::Manual,
+            "quote_accept" => BillingReason::QuoteAccept,
+            "subscription" => BillingReason::Subscription,
+            "subscription_create" => BillingReason::SubscriptionCreate,
+            "subscription_cycle" => BillingReason::SubscriptionCycle,
+            "subscription_threshold" => BillingReason::SubscriptionThreshold,
+            "subscription_update" => BillingReason::SubscriptionUpdate,
+            "upcoming" => BillingReason::Upcoming,
+            "" => BillingReason::Noop,
+            _ => BillingReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BillingReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BillingReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BillingReason {
     fn default() -> BillingReason {
         BillingReason::Noop
@@ -15172,7 +18815,8 @@ impl BillingReason {
 /**
 * Either `charge_automatically`, or `send_invoice`. When charging automatically, Stripe will attempt to pay this invoice using the default source attached to the customer. When sending an invoice, Stripe will email this invoice to the customer with payment instructions.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CollectionMethod {
     #[serde(rename = "charge_automatically")]
     ChargeAutomatically,
@@ -15180,8 +18824,9 @@ pub enum CollectionMethod {
     SendInvoice,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CollectionMethod {
@@ -15190,12 +18835,45 @@ impl std::fmt::Display for CollectionMethod {
             CollectionMethod::ChargeAutomatically => "charge_automatically",
             CollectionMethod::SendInvoice => "send_invoice",
             CollectionMethod::Noop => "",
-            CollectionMethod::FallthroughString => "*",
+            CollectionMethod::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CollectionMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "charge_automatically" => CollectionMethod::ChargeAutomatically,
+            "send_invoice" => CollectionMethod::SendInvoice,
+            "" => CollectionMethod::Noop,
+            _ => CollectionMethod::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CollectionMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CollectionMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CollectionMethod {
     fn default() -> CollectionMethod {
         CollectionMethod::Noop
@@ -15347,7 +19025,8 @@ impl std::convert::From<QuoteAnyOf> for String {
 /**
 * The status of the invoice, one of `draft`, `open`, `paid`, `uncollectible`, or `void`. [Learn more](https://stripe.com/docs/billing/invoices/workflow#workflow-overview)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum InvoiceStatus {
     #[serde(rename = "deleted")]
     Deleted,
@@ -15363,8 +19042,9 @@ pub enum InvoiceStatus {
     Void,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for InvoiceStatus {
@@ -15377,12 +19057,49 @@ impl std::fmt::Display for InvoiceStatus {
             InvoiceStatus::Uncollectible => "uncollectible",
             InvoiceStatus::Void => "void",
             InvoiceStatus::Noop => "",
-            InvoiceStatus::FallthroughString => "*",
+            InvoiceStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for InvoiceStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "deleted" => InvoiceStatus::Deleted,
+            "draft" => InvoiceStatus::Draft,
+            "open" => InvoiceStatus::Open,
+            "paid" => InvoiceStatus::Paid,
+            "uncollectible" => InvoiceStatus::Uncollectible,
+            "void" => InvoiceStatus::Void,
+            "" => InvoiceStatus::Noop,
+            _ => InvoiceStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for InvoiceStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for InvoiceStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for InvoiceStatus {
     fn default() -> InvoiceStatus {
         InvoiceStatus::Noop
@@ -17023,7 +20740,8 @@ pub struct InvoiceLineItemPeriod {
 /**
 * One of `fixed` or `maximum`. If `fixed`, the `amount` param refers to the exact amount to be charged in future payments. If `maximum`, the amount charged can be up to the value passed for the `amount` param.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AmountType {
     #[serde(rename = "fixed")]
     Fixed,
@@ -17031,8 +20749,9 @@ pub enum AmountType {
     Maximum,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AmountType {
@@ -17041,12 +20760,45 @@ impl std::fmt::Display for AmountType {
             AmountType::Fixed => "fixed",
             AmountType::Maximum => "maximum",
             AmountType::Noop => "",
-            AmountType::FallthroughString => "*",
+            AmountType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AmountType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "fixed" => AmountType::Fixed,
+            "maximum" => AmountType::Maximum,
+            "" => AmountType::Noop,
+            _ => AmountType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AmountType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AmountType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AmountType {
     fn default() -> AmountType {
         AmountType::Noop
@@ -17114,7 +20866,8 @@ pub struct InvoicePaymentMethodOptionsAcssDebitMandate {
 /**
 * Preferred language of the Bancontact authorization page that the customer is redirected to.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PreferredLanguage {
     #[serde(rename = "de")]
     De,
@@ -17126,8 +20879,9 @@ pub enum PreferredLanguage {
     Nl,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PreferredLanguage {
@@ -17138,12 +20892,47 @@ impl std::fmt::Display for PreferredLanguage {
             PreferredLanguage::Fr => "fr",
             PreferredLanguage::Nl => "nl",
             PreferredLanguage::Noop => "",
-            PreferredLanguage::FallthroughString => "*",
+            PreferredLanguage::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PreferredLanguage {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "de" => PreferredLanguage::De,
+            "en" => PreferredLanguage::En,
+            "fr" => PreferredLanguage::Fr,
+            "nl" => PreferredLanguage::Nl,
+            "" => PreferredLanguage::Noop,
+            _ => PreferredLanguage::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PreferredLanguage {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PreferredLanguage {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PreferredLanguage {
     fn default() -> PreferredLanguage {
         PreferredLanguage::Noop
@@ -17168,7 +20957,8 @@ pub struct InvoicePaymentMethodOptionsBancontact {
 /**
 * We strongly recommend that you rely on our SCA Engine to automatically prompt your customers for authentication based on risk level and [other requirements](https://stripe.com/docs/strong-customer-authentication). However, if you wish to request 3D Secure based on logic from your own fraud engine, provide this option. Read our guide on [manually requesting 3D Secure](https://stripe.com/docs/payments/3d-secure#manual-three-ds) for more information on how this configuration interacts with Radar and our SCA Engine.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RequestThreeDSecure {
     #[serde(rename = "any")]
     Any,
@@ -17176,8 +20966,9 @@ pub enum RequestThreeDSecure {
     Automatic,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RequestThreeDSecure {
@@ -17186,12 +20977,45 @@ impl std::fmt::Display for RequestThreeDSecure {
             RequestThreeDSecure::Any => "any",
             RequestThreeDSecure::Automatic => "automatic",
             RequestThreeDSecure::Noop => "",
-            RequestThreeDSecure::FallthroughString => "*",
+            RequestThreeDSecure::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RequestThreeDSecure {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "any" => RequestThreeDSecure::Any,
+            "automatic" => RequestThreeDSecure::Automatic,
+            "" => RequestThreeDSecure::Noop,
+            _ => RequestThreeDSecure::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RequestThreeDSecure {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RequestThreeDSecure {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RequestThreeDSecure {
     fn default() -> RequestThreeDSecure {
         RequestThreeDSecure::Noop
@@ -17216,14 +21040,16 @@ pub struct InvoicePaymentMethodOptionsCard {
 /**
 * The funding method type to be used when there are not enough funds in the customer balance. Permitted values include: `bank_transfer`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FundingType {
     #[serde(rename = "bank_transfer")]
     BankTransfer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FundingType {
@@ -17231,12 +21057,44 @@ impl std::fmt::Display for FundingType {
         match self {
             FundingType::BankTransfer => "bank_transfer",
             FundingType::Noop => "",
-            FundingType::FallthroughString => "*",
+            FundingType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FundingType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "bank_transfer" => FundingType::BankTransfer,
+            "" => FundingType::Noop,
+            _ => FundingType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FundingType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FundingType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FundingType {
     fn default() -> FundingType {
         FundingType::Noop
@@ -17745,7 +21603,8 @@ pub struct InvoicesPaymentMethodOptions {
     pub us_bank_account: Option<InvoicePaymentMethodOptionsUsBankAccount>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodTypes {
     #[serde(rename = "ach_credit_transfer")]
     AchCreditTransfer,
@@ -17787,8 +21646,9 @@ pub enum PaymentMethodTypes {
     WechatPay,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodTypes {
@@ -17814,12 +21674,62 @@ impl std::fmt::Display for PaymentMethodTypes {
             PaymentMethodTypes::UsBankAccount => "us_bank_account",
             PaymentMethodTypes::WechatPay => "wechat_pay",
             PaymentMethodTypes::Noop => "",
-            PaymentMethodTypes::FallthroughString => "*",
+            PaymentMethodTypes::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodTypes {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ach_credit_transfer" => PaymentMethodTypes::AchCreditTransfer,
+            "ach_debit" => PaymentMethodTypes::AchDebit,
+            "acss_debit" => PaymentMethodTypes::AcssDebit,
+            "au_becs_debit" => PaymentMethodTypes::AuBecsDebit,
+            "bacs_debit" => PaymentMethodTypes::BacsDebit,
+            "bancontact" => PaymentMethodTypes::Bancontact,
+            "boleto" => PaymentMethodTypes::Boleto,
+            "card" => PaymentMethodTypes::Card,
+            "customer_balance" => PaymentMethodTypes::CustomerBalance,
+            "fpx" => PaymentMethodTypes::Fpx,
+            "giropay" => PaymentMethodTypes::Giropay,
+            "grabpay" => PaymentMethodTypes::Grabpay,
+            "ideal" => PaymentMethodTypes::Ideal,
+            "konbini" => PaymentMethodTypes::Konbini,
+            "paynow" => PaymentMethodTypes::Paynow,
+            "sepa_debit" => PaymentMethodTypes::SepaDebit,
+            "sofort" => PaymentMethodTypes::Sofort,
+            "us_bank_account" => PaymentMethodTypes::UsBankAccount,
+            "wechat_pay" => PaymentMethodTypes::WechatPay,
+            "" => PaymentMethodTypes::Noop,
+            _ => PaymentMethodTypes::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodTypes {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodTypes {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodTypes {
     fn default() -> PaymentMethodTypes {
         PaymentMethodTypes::Noop
@@ -17853,7 +21763,8 @@ pub struct InvoicesPaymentSettings {
 /**
 * The type of the tax ID, one of `eu_vat`, `br_cnpj`, `br_cpf`, `gb_vat`, `nz_gst`, `au_abn`, `au_arn`, `in_gst`, `no_vat`, `za_vat`, `ch_vat`, `mx_rfc`, `sg_uen`, `ru_inn`, `ru_kpp`, `ca_bn`, `hk_br`, `es_cif`, `tw_vat`, `th_vat`, `jp_cn`, `jp_rn`, `li_uid`, `my_itn`, `us_ein`, `kr_brn`, `ca_qst`, `ca_gst_hst`, `ca_pst_bc`, `ca_pst_mb`, `ca_pst_sk`, `my_sst`, `sg_gst`, `ae_trn`, `cl_tin`, `sa_vat`, `id_npwp`, `my_frp`, `il_vat`, `ge_vat`, `ua_vat`, `is_vat`, `bg_uic`, `hu_tin`, `si_tin`, or `unknown`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum InvoicesResourceInvoiceTaxType {
     #[serde(rename = "ae_trn")]
     AeTrn,
@@ -17949,8 +21860,9 @@ pub enum InvoicesResourceInvoiceTaxType {
     ZaVat,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for InvoicesResourceInvoiceTaxType {
@@ -18003,9 +21915,86 @@ impl std::fmt::Display for InvoicesResourceInvoiceTaxType {
             InvoicesResourceInvoiceTaxType::UsEin => "us_ein",
             InvoicesResourceInvoiceTaxType::ZaVat => "za_vat",
             InvoicesResourceInvoiceTaxType::Noop => "",
-            InvoicesResourceInvoiceTaxType::FallthroughString => "*",
-        }
-        .fmt(f)
+            InvoicesResourceInvoiceTaxType::UnknownValue(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl std::str::FromStr for InvoicesResourceInvoiceTaxType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ae_trn" => InvoicesResourceInvoiceTaxType::AeTrn,
+            "au_abn" => InvoicesResourceInvoiceTaxType::AuAbn,
+            "au_arn" => InvoicesResourceInvoiceTaxType::AuArn,
+            "bg_uic" => InvoicesResourceInvoiceTaxType::BgUic,
+            "br_cnpj" => InvoicesResourceInvoiceTaxType::BrCnpj,
+            "br_cpf" => InvoicesResourceInvoiceTaxType::BrCpf,
+            "ca_bn" => InvoicesResourceInvoiceTaxType::CaBn,
+            "ca_gst_hst" => InvoicesResourceInvoiceTaxType::CaGstHst,
+            "ca_pst_bc" => InvoicesResourceInvoiceTaxType::CaPstBc,
+            "ca_pst_mb" => InvoicesResourceInvoiceTaxType::CaPstMb,
+            "ca_pst_sk" => InvoicesResourceInvoiceTaxType::CaPstSk,
+            "ca_qst" => InvoicesResourceInvoiceTaxType::CaQst,
+            "ch_vat" => InvoicesResourceInvoiceTaxType::ChVat,
+            "cl_tin" => InvoicesResourceInvoiceTaxType::ClTin,
+            "es_cif" => InvoicesResourceInvoiceTaxType::EsCif,
+            "eu_vat" => InvoicesResourceInvoiceTaxType::EuVat,
+            "gb_vat" => InvoicesResourceInvoiceTaxType::GbVat,
+            "ge_vat" => InvoicesResourceInvoiceTaxType::GeVat,
+            "hk_br" => InvoicesResourceInvoiceTaxType::HkBr,
+            "hu_tin" => InvoicesResourceInvoiceTaxType::HuTin,
+            "id_npwp" => InvoicesResourceInvoiceTaxType::IdNpwp,
+            "il_vat" => InvoicesResourceInvoiceTaxType::IlVat,
+            "in_gst" => InvoicesResourceInvoiceTaxType::InGst,
+            "is_vat" => InvoicesResourceInvoiceTaxType::IsVat,
+            "jp_cn" => InvoicesResourceInvoiceTaxType::JpCn,
+            "jp_rn" => InvoicesResourceInvoiceTaxType::JpRn,
+            "kr_brn" => InvoicesResourceInvoiceTaxType::KrBrn,
+            "li_uid" => InvoicesResourceInvoiceTaxType::LiUid,
+            "mx_rfc" => InvoicesResourceInvoiceTaxType::MxRfc,
+            "my_frp" => InvoicesResourceInvoiceTaxType::MyFrp,
+            "my_itn" => InvoicesResourceInvoiceTaxType::MyItn,
+            "my_sst" => InvoicesResourceInvoiceTaxType::MySst,
+            "no_vat" => InvoicesResourceInvoiceTaxType::NoVat,
+            "nz_gst" => InvoicesResourceInvoiceTaxType::NzGst,
+            "ru_inn" => InvoicesResourceInvoiceTaxType::RuInn,
+            "ru_kpp" => InvoicesResourceInvoiceTaxType::RuKpp,
+            "sa_vat" => InvoicesResourceInvoiceTaxType::SaVat,
+            "sg_gst" => InvoicesResourceInvoiceTaxType::SgGst,
+            "sg_uen" => InvoicesResourceInvoiceTaxType::SgUen,
+            "si_tin" => InvoicesResourceInvoiceTaxType::SiTin,
+            "th_vat" => InvoicesResourceInvoiceTaxType::ThVat,
+            "tw_vat" => InvoicesResourceInvoiceTaxType::TwVat,
+            "ua_vat" => InvoicesResourceInvoiceTaxType::UaVat,
+            "unknown" => InvoicesResourceInvoiceTaxType::Unknown,
+            "us_ein" => InvoicesResourceInvoiceTaxType::UsEin,
+            "za_vat" => InvoicesResourceInvoiceTaxType::ZaVat,
+            "" => InvoicesResourceInvoiceTaxType::Noop,
+            _ => InvoicesResourceInvoiceTaxType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for InvoicesResourceInvoiceTaxType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for InvoicesResourceInvoiceTaxType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -18087,14 +22076,16 @@ pub struct InvoicesStatusTransitions {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuerFraudRecordObject {
     #[serde(rename = "issuer_fraud_record")]
     IssuerFraudRecord,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuerFraudRecordObject {
@@ -18102,12 +22093,44 @@ impl std::fmt::Display for IssuerFraudRecordObject {
         match self {
             IssuerFraudRecordObject::IssuerFraudRecord => "issuer_fraud_record",
             IssuerFraudRecordObject::Noop => "",
-            IssuerFraudRecordObject::FallthroughString => "*",
+            IssuerFraudRecordObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuerFraudRecordObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "issuer_fraud_record" => IssuerFraudRecordObject::IssuerFraudRecord,
+            "" => IssuerFraudRecordObject::Noop,
+            _ => IssuerFraudRecordObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuerFraudRecordObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuerFraudRecordObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuerFraudRecordObject {
     fn default() -> IssuerFraudRecordObject {
         IssuerFraudRecordObject::Noop
@@ -18196,7 +22219,8 @@ pub struct IssuerFraudRecord {
 /**
 * How the card details were provided.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AuthorizationMethod {
     #[serde(rename = "chip")]
     Chip,
@@ -18210,8 +22234,9 @@ pub enum AuthorizationMethod {
     Swipe,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AuthorizationMethod {
@@ -18223,12 +22248,48 @@ impl std::fmt::Display for AuthorizationMethod {
             AuthorizationMethod::Online => "online",
             AuthorizationMethod::Swipe => "swipe",
             AuthorizationMethod::Noop => "",
-            AuthorizationMethod::FallthroughString => "*",
+            AuthorizationMethod::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AuthorizationMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "chip" => AuthorizationMethod::Chip,
+            "contactless" => AuthorizationMethod::Contactless,
+            "keyed_in" => AuthorizationMethod::KeyedIn,
+            "online" => AuthorizationMethod::Online,
+            "swipe" => AuthorizationMethod::Swipe,
+            "" => AuthorizationMethod::Noop,
+            _ => AuthorizationMethod::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AuthorizationMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthorizationMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AuthorizationMethod {
     fn default() -> AuthorizationMethod {
         AuthorizationMethod::Noop
@@ -18293,14 +22354,16 @@ impl std::convert::From<CardholderAnyOf> for String {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingAuthorizationObject {
     #[serde(rename = "issuing.authorization")]
     IssuingAuthorization,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingAuthorizationObject {
@@ -18308,12 +22371,44 @@ impl std::fmt::Display for IssuingAuthorizationObject {
         match self {
             IssuingAuthorizationObject::IssuingAuthorization => "issuing.authorization",
             IssuingAuthorizationObject::Noop => "",
-            IssuingAuthorizationObject::FallthroughString => "*",
+            IssuingAuthorizationObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingAuthorizationObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "issuing.authorization" => IssuingAuthorizationObject::IssuingAuthorization,
+            "" => IssuingAuthorizationObject::Noop,
+            _ => IssuingAuthorizationObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingAuthorizationObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingAuthorizationObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingAuthorizationObject {
     fn default() -> IssuingAuthorizationObject {
         IssuingAuthorizationObject::Noop
@@ -18328,7 +22423,8 @@ impl IssuingAuthorizationObject {
 /**
 * The current status of the authorization in its lifecycle.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingAuthorizationStatus {
     #[serde(rename = "closed")]
     Closed,
@@ -18338,8 +22434,9 @@ pub enum IssuingAuthorizationStatus {
     Reversed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingAuthorizationStatus {
@@ -18349,12 +22446,46 @@ impl std::fmt::Display for IssuingAuthorizationStatus {
             IssuingAuthorizationStatus::Pending => "pending",
             IssuingAuthorizationStatus::Reversed => "reversed",
             IssuingAuthorizationStatus::Noop => "",
-            IssuingAuthorizationStatus::FallthroughString => "*",
+            IssuingAuthorizationStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingAuthorizationStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "closed" => IssuingAuthorizationStatus::Closed,
+            "pending" => IssuingAuthorizationStatus::Pending,
+            "reversed" => IssuingAuthorizationStatus::Reversed,
+            "" => IssuingAuthorizationStatus::Noop,
+            _ => IssuingAuthorizationStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingAuthorizationStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingAuthorizationStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingAuthorizationStatus {
     fn default() -> IssuingAuthorizationStatus {
         IssuingAuthorizationStatus::Noop
@@ -18546,7 +22677,8 @@ pub struct IssuingAuthorization {
 /**
 * The reason why the card was canceled.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CancellationReason {
     #[serde(rename = "lost")]
     Lost,
@@ -18554,8 +22686,9 @@ pub enum CancellationReason {
     Stolen,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CancellationReason {
@@ -18564,12 +22697,45 @@ impl std::fmt::Display for CancellationReason {
             CancellationReason::Lost => "lost",
             CancellationReason::Stolen => "stolen",
             CancellationReason::Noop => "",
-            CancellationReason::FallthroughString => "*",
+            CancellationReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CancellationReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "lost" => CancellationReason::Lost,
+            "stolen" => CancellationReason::Stolen,
+            "" => CancellationReason::Noop,
+            _ => CancellationReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CancellationReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CancellationReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CancellationReason {
     fn default() -> CancellationReason {
         CancellationReason::Noop
@@ -18584,14 +22750,16 @@ impl CancellationReason {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingCardObject {
     #[serde(rename = "issuing.card")]
     IssuingCard,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingCardObject {
@@ -18599,12 +22767,44 @@ impl std::fmt::Display for IssuingCardObject {
         match self {
             IssuingCardObject::IssuingCard => "issuing.card",
             IssuingCardObject::Noop => "",
-            IssuingCardObject::FallthroughString => "*",
+            IssuingCardObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingCardObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "issuing.card" => IssuingCardObject::IssuingCard,
+            "" => IssuingCardObject::Noop,
+            _ => IssuingCardObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingCardObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingCardObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingCardObject {
     fn default() -> IssuingCardObject {
         IssuingCardObject::Noop
@@ -18667,7 +22867,8 @@ impl std::convert::From<CardAnyOf> for String {
 /**
 * The reason why the previous card needed to be replaced.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReplacementReason {
     #[serde(rename = "damaged")]
     Damaged,
@@ -18679,8 +22880,9 @@ pub enum ReplacementReason {
     Stolen,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReplacementReason {
@@ -18691,12 +22893,47 @@ impl std::fmt::Display for ReplacementReason {
             ReplacementReason::Lost => "lost",
             ReplacementReason::Stolen => "stolen",
             ReplacementReason::Noop => "",
-            ReplacementReason::FallthroughString => "*",
+            ReplacementReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReplacementReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "damaged" => ReplacementReason::Damaged,
+            "expired" => ReplacementReason::Expired,
+            "lost" => ReplacementReason::Lost,
+            "stolen" => ReplacementReason::Stolen,
+            "" => ReplacementReason::Noop,
+            _ => ReplacementReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReplacementReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReplacementReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReplacementReason {
     fn default() -> ReplacementReason {
         ReplacementReason::Noop
@@ -18711,7 +22948,8 @@ impl ReplacementReason {
 /**
 * Whether authorizations can be approved on this card.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingCardStatus {
     #[serde(rename = "active")]
     Active,
@@ -18721,8 +22959,9 @@ pub enum IssuingCardStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingCardStatus {
@@ -18732,12 +22971,46 @@ impl std::fmt::Display for IssuingCardStatus {
             IssuingCardStatus::Canceled => "canceled",
             IssuingCardStatus::Inactive => "inactive",
             IssuingCardStatus::Noop => "",
-            IssuingCardStatus::FallthroughString => "*",
+            IssuingCardStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingCardStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => IssuingCardStatus::Active,
+            "canceled" => IssuingCardStatus::Canceled,
+            "inactive" => IssuingCardStatus::Inactive,
+            "" => IssuingCardStatus::Noop,
+            _ => IssuingCardStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingCardStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingCardStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingCardStatus {
     fn default() -> IssuingCardStatus {
         IssuingCardStatus::Noop
@@ -18752,7 +23025,8 @@ impl IssuingCardStatus {
 /**
 * The type of the card.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingCardType {
     #[serde(rename = "physical")]
     Physical,
@@ -18760,8 +23034,9 @@ pub enum IssuingCardType {
     Virtual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingCardType {
@@ -18770,12 +23045,45 @@ impl std::fmt::Display for IssuingCardType {
             IssuingCardType::Physical => "physical",
             IssuingCardType::Virtual => "virtual",
             IssuingCardType::Noop => "",
-            IssuingCardType::FallthroughString => "*",
+            IssuingCardType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingCardType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "physical" => IssuingCardType::Physical,
+            "virtual" => IssuingCardType::Virtual,
+            "" => IssuingCardType::Noop,
+            _ => IssuingCardType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingCardType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingCardType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingCardType {
     fn default() -> IssuingCardType {
         IssuingCardType::Noop
@@ -18955,14 +23263,16 @@ pub struct IssuingCard {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingCardholderObject {
     #[serde(rename = "issuing.cardholder")]
     IssuingCardholder,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingCardholderObject {
@@ -18970,12 +23280,44 @@ impl std::fmt::Display for IssuingCardholderObject {
         match self {
             IssuingCardholderObject::IssuingCardholder => "issuing.cardholder",
             IssuingCardholderObject::Noop => "",
-            IssuingCardholderObject::FallthroughString => "*",
+            IssuingCardholderObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingCardholderObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "issuing.cardholder" => IssuingCardholderObject::IssuingCardholder,
+            "" => IssuingCardholderObject::Noop,
+            _ => IssuingCardholderObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingCardholderObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingCardholderObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingCardholderObject {
     fn default() -> IssuingCardholderObject {
         IssuingCardholderObject::Noop
@@ -18990,7 +23332,8 @@ impl IssuingCardholderObject {
 /**
 * Specifies whether to permit authorizations on this cardholder's cards.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingCardholderStatus {
     #[serde(rename = "active")]
     Active,
@@ -19000,8 +23343,9 @@ pub enum IssuingCardholderStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingCardholderStatus {
@@ -19011,12 +23355,46 @@ impl std::fmt::Display for IssuingCardholderStatus {
             IssuingCardholderStatus::Blocked => "blocked",
             IssuingCardholderStatus::Inactive => "inactive",
             IssuingCardholderStatus::Noop => "",
-            IssuingCardholderStatus::FallthroughString => "*",
+            IssuingCardholderStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingCardholderStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => IssuingCardholderStatus::Active,
+            "blocked" => IssuingCardholderStatus::Blocked,
+            "inactive" => IssuingCardholderStatus::Inactive,
+            "" => IssuingCardholderStatus::Noop,
+            _ => IssuingCardholderStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingCardholderStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingCardholderStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingCardholderStatus {
     fn default() -> IssuingCardholderStatus {
         IssuingCardholderStatus::Noop
@@ -19031,7 +23409,8 @@ impl IssuingCardholderStatus {
 /**
 * Type of entity that holds the account. This can be either `individual` or `company`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AccountHolderType {
     #[serde(rename = "company")]
     Company,
@@ -19039,8 +23418,9 @@ pub enum AccountHolderType {
     Individual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AccountHolderType {
@@ -19049,12 +23429,45 @@ impl std::fmt::Display for AccountHolderType {
             AccountHolderType::Company => "company",
             AccountHolderType::Individual => "individual",
             AccountHolderType::Noop => "",
-            AccountHolderType::FallthroughString => "*",
+            AccountHolderType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AccountHolderType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "company" => AccountHolderType::Company,
+            "individual" => AccountHolderType::Individual,
+            "" => AccountHolderType::Noop,
+            _ => AccountHolderType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AccountHolderType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountHolderType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AccountHolderType {
     fn default() -> AccountHolderType {
         AccountHolderType::Noop
@@ -19186,14 +23599,16 @@ pub struct IssuingCardholder {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingDisputeObject {
     #[serde(rename = "issuing.dispute")]
     IssuingDispute,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingDisputeObject {
@@ -19201,12 +23616,44 @@ impl std::fmt::Display for IssuingDisputeObject {
         match self {
             IssuingDisputeObject::IssuingDispute => "issuing.dispute",
             IssuingDisputeObject::Noop => "",
-            IssuingDisputeObject::FallthroughString => "*",
+            IssuingDisputeObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingDisputeObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "issuing.dispute" => IssuingDisputeObject::IssuingDispute,
+            "" => IssuingDisputeObject::Noop,
+            _ => IssuingDisputeObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingDisputeObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingDisputeObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingDisputeObject {
     fn default() -> IssuingDisputeObject {
         IssuingDisputeObject::Noop
@@ -19221,7 +23668,8 @@ impl IssuingDisputeObject {
 /**
 * Current status of the dispute.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingDisputeStatus {
     #[serde(rename = "expired")]
     Expired,
@@ -19235,8 +23683,9 @@ pub enum IssuingDisputeStatus {
     Won,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingDisputeStatus {
@@ -19248,12 +23697,48 @@ impl std::fmt::Display for IssuingDisputeStatus {
             IssuingDisputeStatus::Unsubmitted => "unsubmitted",
             IssuingDisputeStatus::Won => "won",
             IssuingDisputeStatus::Noop => "",
-            IssuingDisputeStatus::FallthroughString => "*",
+            IssuingDisputeStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingDisputeStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "expired" => IssuingDisputeStatus::Expired,
+            "lost" => IssuingDisputeStatus::Lost,
+            "submitted" => IssuingDisputeStatus::Submitted,
+            "unsubmitted" => IssuingDisputeStatus::Unsubmitted,
+            "won" => IssuingDisputeStatus::Won,
+            "" => IssuingDisputeStatus::Noop,
+            _ => IssuingDisputeStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingDisputeStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingDisputeStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingDisputeStatus {
     fn default() -> IssuingDisputeStatus {
         IssuingDisputeStatus::Noop
@@ -19408,14 +23893,16 @@ pub struct IssuingDispute {
 /**
 * The card network for this settlement report. One of ["visa"]
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Network {
     #[serde(rename = "visa")]
     Visa,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Network {
@@ -19423,12 +23910,44 @@ impl std::fmt::Display for Network {
         match self {
             Network::Visa => "visa",
             Network::Noop => "",
-            Network::FallthroughString => "*",
+            Network::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Network {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "visa" => Network::Visa,
+            "" => Network::Noop,
+            _ => Network::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Network {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Network {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Network {
     fn default() -> Network {
         Network::Noop
@@ -19443,14 +23962,16 @@ impl Network {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingSettlementObject {
     #[serde(rename = "issuing.settlement")]
     IssuingSettlement,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingSettlementObject {
@@ -19458,12 +23979,44 @@ impl std::fmt::Display for IssuingSettlementObject {
         match self {
             IssuingSettlementObject::IssuingSettlement => "issuing.settlement",
             IssuingSettlementObject::Noop => "",
-            IssuingSettlementObject::FallthroughString => "*",
+            IssuingSettlementObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingSettlementObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "issuing.settlement" => IssuingSettlementObject::IssuingSettlement,
+            "" => IssuingSettlementObject::Noop,
+            _ => IssuingSettlementObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingSettlementObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingSettlementObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingSettlementObject {
     fn default() -> IssuingSettlementObject {
         IssuingSettlementObject::Noop
@@ -19720,14 +24273,16 @@ impl std::convert::From<DisputeAnyOf> for String {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingTransactionObject {
     #[serde(rename = "issuing.transaction")]
     IssuingTransaction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingTransactionObject {
@@ -19735,12 +24290,44 @@ impl std::fmt::Display for IssuingTransactionObject {
         match self {
             IssuingTransactionObject::IssuingTransaction => "issuing.transaction",
             IssuingTransactionObject::Noop => "",
-            IssuingTransactionObject::FallthroughString => "*",
+            IssuingTransactionObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingTransactionObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "issuing.transaction" => IssuingTransactionObject::IssuingTransaction,
+            "" => IssuingTransactionObject::Noop,
+            _ => IssuingTransactionObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingTransactionObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingTransactionObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingTransactionObject {
     fn default() -> IssuingTransactionObject {
         IssuingTransactionObject::Noop
@@ -19755,7 +24342,8 @@ impl IssuingTransactionObject {
 /**
 * The nature of the transaction.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingTransactionType {
     #[serde(rename = "capture")]
     Capture,
@@ -19763,8 +24351,9 @@ pub enum IssuingTransactionType {
     Refund,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingTransactionType {
@@ -19773,12 +24362,45 @@ impl std::fmt::Display for IssuingTransactionType {
             IssuingTransactionType::Capture => "capture",
             IssuingTransactionType::Refund => "refund",
             IssuingTransactionType::Noop => "",
-            IssuingTransactionType::FallthroughString => "*",
+            IssuingTransactionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingTransactionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "capture" => IssuingTransactionType::Capture,
+            "refund" => IssuingTransactionType::Refund,
+            "" => IssuingTransactionType::Noop,
+            _ => IssuingTransactionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingTransactionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingTransactionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingTransactionType {
     fn default() -> IssuingTransactionType {
         IssuingTransactionType::Noop
@@ -19793,7 +24415,8 @@ impl IssuingTransactionType {
 /**
 * The digital wallet used for this transaction. One of `apple_pay`, `google_pay`, or `samsung_pay`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Wallet {
     #[serde(rename = "apple_pay")]
     ApplePay,
@@ -19803,8 +24426,9 @@ pub enum Wallet {
     SamsungPay,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Wallet {
@@ -19814,12 +24438,46 @@ impl std::fmt::Display for Wallet {
             Wallet::GooglePay => "google_pay",
             Wallet::SamsungPay => "samsung_pay",
             Wallet::Noop => "",
-            Wallet::FallthroughString => "*",
+            Wallet::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Wallet {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "apple_pay" => Wallet::ApplePay,
+            "google_pay" => Wallet::GooglePay,
+            "samsung_pay" => Wallet::SamsungPay,
+            "" => Wallet::Noop,
+            _ => Wallet::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Wallet {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Wallet {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Wallet {
     fn default() -> Wallet {
         Wallet::Noop
@@ -20128,7 +24786,8 @@ pub struct IssuingAuthorizationPendingRequest {
 /**
 * The reason for the approval or decline.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingAuthorizationRequestReason {
     #[serde(rename = "account_disabled")]
     AccountDisabled,
@@ -20158,8 +24817,9 @@ pub enum IssuingAuthorizationRequestReason {
     WebhookTimeout,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingAuthorizationRequestReason {
@@ -20181,12 +24841,58 @@ impl std::fmt::Display for IssuingAuthorizationRequestReason {
             IssuingAuthorizationRequestReason::WebhookDeclined => "webhook_declined",
             IssuingAuthorizationRequestReason::WebhookTimeout => "webhook_timeout",
             IssuingAuthorizationRequestReason::Noop => "",
-            IssuingAuthorizationRequestReason::FallthroughString => "*",
+            IssuingAuthorizationRequestReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingAuthorizationRequestReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "account_disabled" => IssuingAuthorizationRequestReason::AccountDisabled,
+            "card_active" => IssuingAuthorizationRequestReason::CardActive,
+            "card_inactive" => IssuingAuthorizationRequestReason::CardInactive,
+            "cardholder_inactive" => IssuingAuthorizationRequestReason::CardholderInactive,
+            "cardholder_verification_required" => {
+                IssuingAuthorizationRequestReason::CardholderVerificationRequired
+            }
+            "insufficient_funds" => IssuingAuthorizationRequestReason::InsufficientFunds,
+            "not_allowed" => IssuingAuthorizationRequestReason::NotAllowed,
+            "spending_controls" => IssuingAuthorizationRequestReason::SpendingControls,
+            "suspected_fraud" => IssuingAuthorizationRequestReason::SuspectedFraud,
+            "verification_failed" => IssuingAuthorizationRequestReason::VerificationFailed,
+            "webhook_approved" => IssuingAuthorizationRequestReason::WebhookApproved,
+            "webhook_declined" => IssuingAuthorizationRequestReason::WebhookDeclined,
+            "webhook_timeout" => IssuingAuthorizationRequestReason::WebhookTimeout,
+            "" => IssuingAuthorizationRequestReason::Noop,
+            _ => IssuingAuthorizationRequestReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingAuthorizationRequestReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingAuthorizationRequestReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingAuthorizationRequestReason {
     fn default() -> IssuingAuthorizationRequestReason {
         IssuingAuthorizationRequestReason::Noop
@@ -20269,7 +24975,8 @@ pub struct IssuingAuthorizationRequest {
 /**
 * Whether the cardholder provided a CVC and if it matched Stripe’s record.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CvcCheck {
     #[serde(rename = "match")]
     Match,
@@ -20279,8 +24986,9 @@ pub enum CvcCheck {
     NotProvided,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CvcCheck {
@@ -20290,12 +24998,46 @@ impl std::fmt::Display for CvcCheck {
             CvcCheck::Mismatch => "mismatch",
             CvcCheck::NotProvided => "not_provided",
             CvcCheck::Noop => "",
-            CvcCheck::FallthroughString => "*",
+            CvcCheck::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CvcCheck {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "match" => CvcCheck::Match,
+            "mismatch" => CvcCheck::Mismatch,
+            "not_provided" => CvcCheck::NotProvided,
+            "" => CvcCheck::Noop,
+            _ => CvcCheck::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CvcCheck {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CvcCheck {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CvcCheck {
     fn default() -> CvcCheck {
         CvcCheck::Noop
@@ -20339,7 +25081,8 @@ pub struct IssuingAuthorizationVerificationData {
 /**
 * Reason the card is ineligible for Apple Pay
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IneligibleReason {
     #[serde(rename = "missing_agreement")]
     MissingAgreement,
@@ -20349,8 +25092,9 @@ pub enum IneligibleReason {
     UnsupportedRegion,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IneligibleReason {
@@ -20360,12 +25104,46 @@ impl std::fmt::Display for IneligibleReason {
             IneligibleReason::MissingCardholderContact => "missing_cardholder_contact",
             IneligibleReason::UnsupportedRegion => "unsupported_region",
             IneligibleReason::Noop => "",
-            IneligibleReason::FallthroughString => "*",
+            IneligibleReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IneligibleReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "missing_agreement" => IneligibleReason::MissingAgreement,
+            "missing_cardholder_contact" => IneligibleReason::MissingCardholderContact,
+            "unsupported_region" => IneligibleReason::UnsupportedRegion,
+            "" => IneligibleReason::Noop,
+            _ => IneligibleReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IneligibleReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IneligibleReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IneligibleReason {
     fn default() -> IneligibleReason {
         IneligibleReason::Noop
@@ -20395,7 +25173,8 @@ pub struct IssuingCardApplePay {
     pub ineligible_reason: Option<IneligibleReason>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Categories {
     #[serde(rename = "ac_refrigeration_repair")]
     AcRefrigerationRepair,
@@ -20975,8 +25754,9 @@ pub enum Categories {
     WreckingAndSalvageYards,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Categories {
@@ -21339,9 +26119,396 @@ impl std::fmt::Display for Categories {
             Categories::WomensReadyToWearStores => "womens_ready_to_wear_stores",
             Categories::WreckingAndSalvageYards => "wrecking_and_salvage_yards",
             Categories::Noop => "",
-            Categories::FallthroughString => "*",
-        }
-        .fmt(f)
+            Categories::UnknownValue(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl std::str::FromStr for Categories {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ac_refrigeration_repair" => Categories::AcRefrigerationRepair,
+            "accounting_bookkeeping_services" => Categories::AccountingBookkeepingServices,
+            "advertising_services" => Categories::AdvertisingServices,
+            "agricultural_cooperative" => Categories::AgriculturalCooperative,
+            "airlines_air_carriers" => Categories::AirlinesAirCarriers,
+            "airports_flying_fields" => Categories::AirportsFlyingFields,
+            "ambulance_services" => Categories::AmbulanceServices,
+            "amusement_parks_carnivals" => Categories::AmusementParksCarnivals,
+            "antique_reproductions" => Categories::AntiqueReproductions,
+            "antique_shops" => Categories::AntiqueShops,
+            "aquariums" => Categories::Aquariums,
+            "architectural_surveying_services" => Categories::ArchitecturalSurveyingServices,
+            "art_dealers_and_galleries" => Categories::ArtDealersAndGalleries,
+            "artists_supply_and_craft_shops" => Categories::ArtistsSupplyAndCraftShops,
+            "auto_and_home_supply_stores" => Categories::AutoAndHomeSupplyStores,
+            "auto_body_repair_shops" => Categories::AutoBodyRepairShops,
+            "auto_paint_shops" => Categories::AutoPaintShops,
+            "auto_service_shops" => Categories::AutoServiceShops,
+            "automated_cash_disburse" => Categories::AutomatedCashDisburse,
+            "automated_fuel_dispensers" => Categories::AutomatedFuelDispensers,
+            "automobile_associations" => Categories::AutomobileAssociations,
+            "automotive_parts_and_accessories_stores" => {
+                Categories::AutomotivePartsAndAccessoriesStores
+            }
+            "automotive_tire_stores" => Categories::AutomotiveTireStores,
+            "bail_and_bond_payments" => Categories::BailAndBondPayments,
+            "bakeries" => Categories::Bakeries,
+            "bands_orchestras" => Categories::BandsOrchestras,
+            "barber_and_beauty_shops" => Categories::BarberAndBeautyShops,
+            "betting_casino_gambling" => Categories::BettingCasinoGambling,
+            "bicycle_shops" => Categories::BicycleShops,
+            "billiard_pool_establishments" => Categories::BilliardPoolEstablishments,
+            "boat_dealers" => Categories::BoatDealers,
+            "boat_rentals_and_leases" => Categories::BoatRentalsAndLeases,
+            "book_stores" => Categories::BookStores,
+            "books_periodicals_and_newspapers" => Categories::BooksPeriodicalsAndNewspapers,
+            "bowling_alleys" => Categories::BowlingAlleys,
+            "bus_lines" => Categories::BusLines,
+            "business_secretarial_schools" => Categories::BusinessSecretarialSchools,
+            "buying_shopping_services" => Categories::BuyingShoppingServices,
+            "cable_satellite_and_other_pay_television_and_radio" => {
+                Categories::CableSatelliteAndOtherPayTelevisionRadio
+            }
+            "camera_and_photographic_supply_stores" => {
+                Categories::CameraAndPhotographicSupplyStores
+            }
+            "candy_nut_and_confectionery_stores" => Categories::CandyNutAndConfectioneryStores,
+            "car_and_truck_dealers_new_used" => Categories::CarAndTruckDealersNewUsed,
+            "car_and_truck_dealers_used_only" => Categories::CarAndTruckDealersUsedOnly,
+            "car_rental_agencies" => Categories::CarRentalAgencies,
+            "car_washes" => Categories::CarWashes,
+            "carpentry_services" => Categories::CarpentryServices,
+            "carpet_upholstery_cleaning" => Categories::CarpetUpholsteryCleaning,
+            "caterers" => Categories::Caterers,
+            "charitable_and_social_service_organizations_fundraising" => {
+                Categories::CharitableAndSocialServiceOrganizationsFundraising
+            }
+            "chemicals_and_allied_products" => Categories::ChemicalsAndAlliedProducts,
+            "child_care_services" => Categories::ChildCareServices,
+            "childrens_and_infants_wear_stores" => Categories::ChildrensAndInfantsWearStores,
+            "chiropodists_podiatrists" => Categories::ChiropodistsPodiatrists,
+            "chiropractors" => Categories::Chiropractors,
+            "cigar_stores_and_stands" => Categories::CigarStoresAndStands,
+            "civic_social_fraternal_associations" => Categories::CivicSocialFraternalAssociations,
+            "cleaning_and_maintenance" => Categories::CleaningAndMaintenance,
+            "clothing_rental" => Categories::ClothingRental,
+            "colleges_universities" => Categories::CollegesUniversities,
+            "commercial_equipment" => Categories::CommercialEquipment,
+            "commercial_footwear" => Categories::CommercialFootwear,
+            "commercial_photography_art_and_graphics" => {
+                Categories::CommercialPhotographyArtAndGraphics
+            }
+            "commuter_transport_and_ferries" => Categories::CommuterTransportAndFerries,
+            "computer_network_services" => Categories::ComputerNetworkServices,
+            "computer_programming" => Categories::ComputerProgramming,
+            "computer_repair" => Categories::ComputerRepair,
+            "computer_software_stores" => Categories::ComputerSoftwareStores,
+            "computers_peripherals_and_software" => Categories::ComputersPeripheralsAndSoftware,
+            "concrete_work_services" => Categories::ConcreteWorkServices,
+            "construction_materials" => Categories::ConstructionMaterials,
+            "consulting_public_relations" => Categories::ConsultingPublicRelations,
+            "correspondence_schools" => Categories::CorrespondenceSchools,
+            "cosmetic_stores" => Categories::CosmeticStores,
+            "counseling_services" => Categories::CounselingServices,
+            "country_clubs" => Categories::CountryClubs,
+            "courier_services" => Categories::CourierServices,
+            "court_costs" => Categories::CourtCosts,
+            "credit_reporting_agencies" => Categories::CreditReportingAgencies,
+            "cruise_lines" => Categories::CruiseLines,
+            "dairy_products_stores" => Categories::DairyProductsStores,
+            "dance_hall_studios_schools" => Categories::DanceHallStudiosSchools,
+            "dating_escort_services" => Categories::DatingEscortServices,
+            "dentists_orthodontists" => Categories::DentistsOrthodontists,
+            "department_stores" => Categories::DepartmentStores,
+            "detective_agencies" => Categories::DetectiveAgencies,
+            "digital_goods_applications" => Categories::DigitalGoodsApplications,
+            "digital_goods_games" => Categories::DigitalGoodsGames,
+            "digital_goods_large_volume" => Categories::DigitalGoodsLargeVolume,
+            "digital_goods_media" => Categories::DigitalGoodsMedia,
+            "direct_marketing_catalog_merchant" => Categories::DirectMarketingCatalogMerchant,
+            "direct_marketing_combination_catalog_and_retail_merchant" => {
+                Categories::DirectMarketingCombinationCatalogAndRetailMerchant
+            }
+            "direct_marketing_inbound_telemarketing" => {
+                Categories::DirectMarketingInboundTelemarketing
+            }
+            "direct_marketing_insurance_services" => Categories::DirectMarketingInsuranceServices,
+            "direct_marketing_other" => Categories::DirectMarketingOther,
+            "direct_marketing_outbound_telemarketing" => {
+                Categories::DirectMarketingOutboundTelemarketing
+            }
+            "direct_marketing_subscription" => Categories::DirectMarketingSubscription,
+            "direct_marketing_travel" => Categories::DirectMarketingTravel,
+            "discount_stores" => Categories::DiscountStores,
+            "doctors" => Categories::Doctors,
+            "door_to_door_sales" => Categories::DoorToSales,
+            "drapery_window_covering_and_upholstery_stores" => {
+                Categories::DraperyWindowCoveringAndUpholsteryStores
+            }
+            "drinking_places" => Categories::DrinkingPlaces,
+            "drug_stores_and_pharmacies" => Categories::DrugStoresAndPharmacies,
+            "drugs_drug_proprietaries_and_druggist_sundries" => {
+                Categories::DrugsDrugProprietariesAndDruggistSundries
+            }
+            "dry_cleaners" => Categories::DryCleaners,
+            "durable_goods" => Categories::DurableGoods,
+            "duty_free_stores" => Categories::DutyFreeStores,
+            "eating_places_restaurants" => Categories::EatingPlacesRestaurants,
+            "educational_services" => Categories::EducationalServices,
+            "electric_razor_stores" => Categories::ElectricRazorStores,
+            "electrical_parts_and_equipment" => Categories::ElectricalPartsAndEquipment,
+            "electrical_services" => Categories::ElectricalServices,
+            "electronics_repair_shops" => Categories::ElectronicsRepairShops,
+            "electronics_stores" => Categories::ElectronicsStores,
+            "elementary_secondary_schools" => Categories::ElementarySecondarySchools,
+            "employment_temp_agencies" => Categories::EmploymentTempAgencies,
+            "equipment_rental" => Categories::EquipmentRental,
+            "exterminating_services" => Categories::ExterminatingServices,
+            "family_clothing_stores" => Categories::FamilyClothingStores,
+            "fast_food_restaurants" => Categories::FastFoodRestaurants,
+            "financial_institutions" => Categories::FinancialInstitutions,
+            "fines_government_administrative_entities" => {
+                Categories::FinesGovernmentAdministrativeEntities
+            }
+            "fireplace_fireplace_screens_and_accessories_stores" => {
+                Categories::FireplaceScreensAndAccessoriesStores
+            }
+            "floor_covering_stores" => Categories::FloorCoveringStores,
+            "florists" => Categories::Florists,
+            "florists_supplies_nursery_stock_and_flowers" => {
+                Categories::FloristsSuppliesNurseryStockAndFlowers
+            }
+            "freezer_and_locker_meat_provisioners" => Categories::FreezerAndLockerMeatProvisioners,
+            "fuel_dealers_non_automotive" => Categories::FuelDealersNonAutomotive,
+            "funeral_services_crematories" => Categories::FuneralServicesCrematories,
+            "furniture_home_furnishings_and_equipment_stores_except_appliances" => {
+                Categories::FurnitureHomeFurnishingsAndEquipmentStoresExceptAppliances
+            }
+            "furniture_repair_refinishing" => Categories::FurnitureRepairRefinishing,
+            "furriers_and_fur_shops" => Categories::FurriersAndFurShops,
+            "general_services" => Categories::GeneralServices,
+            "gift_card_novelty_and_souvenir_shops" => Categories::GiftCardNoveltyAndSouvenirShops,
+            "glass_paint_and_wallpaper_stores" => Categories::GlassPaintAndWallpaperStores,
+            "glassware_crystal_stores" => Categories::GlasswareCrystalStores,
+            "golf_courses_public" => Categories::GolfCoursesPublic,
+            "government_services" => Categories::GovernmentServices,
+            "grocery_stores_supermarkets" => Categories::GroceryStoresSupermarkets,
+            "hardware_equipment_and_supplies" => Categories::HardwareEquipmentAndSupplies,
+            "hardware_stores" => Categories::HardwareStores,
+            "health_and_beauty_spas" => Categories::HealthAndBeautySpas,
+            "hearing_aids_sales_and_supplies" => Categories::HearingAidsSalesAndSupplies,
+            "heating_plumbing_a_c" => Categories::HeatingPlumbingAC,
+            "hobby_toy_and_game_shops" => Categories::HobbyToyAndGameShops,
+            "home_supply_warehouse_stores" => Categories::HomeSupplyWarehouseStores,
+            "hospitals" => Categories::Hospitals,
+            "hotels_motels_and_resorts" => Categories::HotelsMotelsAndResorts,
+            "household_appliance_stores" => Categories::HouseholdApplianceStores,
+            "industrial_supplies" => Categories::IndustrialSupplies,
+            "information_retrieval_services" => Categories::InformationRetrievalServices,
+            "insurance_default" => Categories::InsuranceDefault,
+            "insurance_underwriting_premiums" => Categories::InsuranceUnderwritingPremiums,
+            "intra_company_purchases" => Categories::IntraCompanyPurchases,
+            "jewelry_stores_watches_clocks_and_silverware_stores" => {
+                Categories::JewelryStoresWatchesClocksAndSilverware
+            }
+            "landscaping_services" => Categories::LandscapingServices,
+            "laundries" => Categories::Laundries,
+            "laundry_cleaning_services" => Categories::LaundryCleaningServices,
+            "legal_services_attorneys" => Categories::LegalServicesAttorneys,
+            "luggage_and_leather_goods_stores" => Categories::LuggageAndLeatherGoodsStores,
+            "lumber_building_materials_stores" => Categories::LumberBuildingMaterialsStores,
+            "manual_cash_disburse" => Categories::ManualCashDisburse,
+            "marinas_service_and_supplies" => Categories::MarinasServiceAndSupplies,
+            "masonry_stonework_and_plaster" => Categories::MasonryStoneworkAndPlaster,
+            "massage_parlors" => Categories::MassageParlors,
+            "medical_and_dental_labs" => Categories::MedicalAndDentalLabs,
+            "medical_dental_ophthalmic_and_hospital_equipment_and_supplies" => {
+                Categories::MedicalDentalOphthalmicAndHospitalEquipmentSupplies
+            }
+            "medical_services" => Categories::MedicalServices,
+            "membership_organizations" => Categories::MembershipOrganizations,
+            "mens_and_boys_clothing_and_accessories_stores" => {
+                Categories::MensAndBoysClothingAccessoriesStores
+            }
+            "mens_womens_clothing_stores" => Categories::MensWomensClothingStores,
+            "metal_service_centers" => Categories::MetalServiceCenters,
+            "miscellaneous" => Categories::Miscellaneous,
+            "miscellaneous_apparel_and_accessory_shops" => {
+                Categories::MiscellaneousApparelAndAccessoryShops
+            }
+            "miscellaneous_auto_dealers" => Categories::MiscellaneousAutoDealers,
+            "miscellaneous_business_services" => Categories::MiscellaneousBusinessServices,
+            "miscellaneous_food_stores" => Categories::MiscellaneousFoodStores,
+            "miscellaneous_general_merchandise" => Categories::MiscellaneousGeneralMerchandise,
+            "miscellaneous_general_services" => Categories::MiscellaneousGeneralServices,
+            "miscellaneous_home_furnishing_specialty_stores" => {
+                Categories::MiscellaneousHomeFurnishingSpecialtyStores
+            }
+            "miscellaneous_publishing_and_printing" => {
+                Categories::MiscellaneousPublishingAndPrinting
+            }
+            "miscellaneous_recreation_services" => Categories::MiscellaneousRecreationServices,
+            "miscellaneous_repair_shops" => Categories::MiscellaneousRepairShops,
+            "miscellaneous_specialty_retail" => Categories::MiscellaneousSpecialtyRetail,
+            "mobile_home_dealers" => Categories::MobileHomeDealers,
+            "motion_picture_theaters" => Categories::MotionPictureTheaters,
+            "motor_freight_carriers_and_trucking" => Categories::MotorFreightCarriersAndTrucking,
+            "motor_homes_dealers" => Categories::MotorHomesDealers,
+            "motor_vehicle_supplies_and_new_parts" => Categories::MotorVehicleSuppliesAndNewParts,
+            "motorcycle_shops_and_dealers" => Categories::MotorcycleShopsAndDealers,
+            "motorcycle_shops_dealers" => Categories::MotorcycleShopsDealers,
+            "music_stores_musical_instruments_pianos_and_sheet_music" => {
+                Categories::MusicStoresMusicalInstrumentsPianosAndSheet
+            }
+            "news_dealers_and_newsstands" => Categories::NewsDealersAndNewsstands,
+            "non_fi_money_orders" => Categories::NonFiMoneyOrders,
+            "non_fi_stored_value_card_purchase_load" => {
+                Categories::NonFiStoredValueCardPurchaseLoad
+            }
+            "nondurable_goods" => Categories::NondurableGoods,
+            "nurseries_lawn_and_garden_supply_stores" => {
+                Categories::NurseriesLawnAndGardenSupplyStores
+            }
+            "nursing_personal_care" => Categories::NursingPersonalCare,
+            "office_and_commercial_furniture" => Categories::OfficeAndCommercialFurniture,
+            "opticians_eyeglasses" => Categories::OpticiansEyeglasses,
+            "optometrists_ophthalmologist" => Categories::OptometristsOphthalmologist,
+            "orthopedic_goods_prosthetic_devices" => Categories::OrthopedicGoodsProstheticDevices,
+            "osteopaths" => Categories::Osteopaths,
+            "package_stores_beer_wine_and_liquor" => Categories::PackageStoresBeerWineAndLiquor,
+            "paints_varnishes_and_supplies" => Categories::PaintsVarnishesAndSupplies,
+            "parking_lots_garages" => Categories::ParkingLotsGarages,
+            "passenger_railways" => Categories::PassengerRailways,
+            "pawn_shops" => Categories::PawnShops,
+            "pet_shops_pet_food_and_supplies" => Categories::PetShopsFoodAndSupplies,
+            "petroleum_and_petroleum_products" => Categories::PetroleumAndProducts,
+            "photo_developing" => Categories::PhotoDeveloping,
+            "photographic_photocopy_microfilm_equipment_and_supplies" => {
+                Categories::PhotographicPhotocopyMicrofilmEquipmentAndSupplies
+            }
+            "photographic_studios" => Categories::PhotographicStudios,
+            "picture_video_production" => Categories::PictureVideoProduction,
+            "piece_goods_notions_and_other_dry_goods" => Categories::PieceGoodsNotionsAndOtherDry,
+            "plumbing_heating_equipment_and_supplies" => {
+                Categories::PlumbingHeatingEquipmentAndSupplies
+            }
+            "political_organizations" => Categories::PoliticalOrganizations,
+            "postal_services_government_only" => Categories::PostalServicesGovernmentOnly,
+            "precious_stones_and_metals_watches_and_jewelry" => {
+                Categories::PreciousStonesAndMetalsWatchesJewelry
+            }
+            "professional_services" => Categories::ProfessionalServices,
+            "public_warehousing_and_storage" => Categories::PublicWarehousingAndStorage,
+            "quick_copy_repro_and_blueprint" => Categories::QuickCopyReproAndBlueprint,
+            "railroads" => Categories::Railroads,
+            "real_estate_agents_and_managers_rentals" => {
+                Categories::RealEstateAgentsAndManagersRentals
+            }
+            "record_stores" => Categories::RecordStores,
+            "recreational_vehicle_rentals" => Categories::RecreationalVehicleRentals,
+            "religious_goods_stores" => Categories::ReligiousGoodsStores,
+            "religious_organizations" => Categories::ReligiousOrganizations,
+            "roofing_siding_sheet_metal" => Categories::RoofingSidingSheetMetal,
+            "secretarial_support_services" => Categories::SecretarialSupportServices,
+            "security_brokers_dealers" => Categories::SecurityBrokersDealers,
+            "service_stations" => Categories::ServiceStations,
+            "sewing_needlework_fabric_and_piece_goods_stores" => {
+                Categories::SewingNeedleworkFabricAndPieceGoodsStores
+            }
+            "shoe_repair_hat_cleaning" => Categories::ShoeRepairHatCleaning,
+            "shoe_stores" => Categories::ShoeStores,
+            "small_appliance_repair" => Categories::SmallApplianceRepair,
+            "snowmobile_dealers" => Categories::SnowmobileDealers,
+            "special_trade_services" => Categories::SpecialTradeServices,
+            "specialty_cleaning" => Categories::SpecialtyCleaning,
+            "sporting_goods_stores" => Categories::SportingGoodsStores,
+            "sporting_recreation_camps" => Categories::SportingRecreationCamps,
+            "sports_and_riding_apparel_stores" => Categories::SportsAndRidingApparelStores,
+            "sports_clubs_fields" => Categories::SportsClubsFields,
+            "stamp_and_coin_stores" => Categories::StampAndCoinStores,
+            "stationary_office_supplies_printing_and_writing_paper" => {
+                Categories::StationaryOfficeSuppliesPrintingAndWritingPaper
+            }
+            "stationery_stores_office_and_school_supply_stores" => {
+                Categories::StationeryStoresOfficeAndSchoolSupply
+            }
+            "swimming_pools_sales" => Categories::SwimmingPoolsSales,
+            "t_ui_travel_germany" => Categories::TUiTravelGermany,
+            "tailors_alterations" => Categories::TailorsAlterations,
+            "tax_payments_government_agencies" => Categories::TaxPaymentsGovernmentAgencies,
+            "tax_preparation_services" => Categories::TaxPreparationServices,
+            "taxicabs_limousines" => Categories::TaxicabsLimousines,
+            "telecommunication_equipment_and_telephone_sales" => {
+                Categories::TelecommunicationEquipmentAndTelephoneSales
+            }
+            "telecommunication_services" => Categories::TelecommunicationServices,
+            "telegraph_services" => Categories::TelegraphServices,
+            "tent_and_awning_shops" => Categories::TentAndAwningShops,
+            "testing_laboratories" => Categories::TestingLaboratories,
+            "theatrical_ticket_agencies" => Categories::TheatricalTicketAgencies,
+            "timeshares" => Categories::Timeshares,
+            "tire_retreading_and_repair" => Categories::TireRetreadingAndRepair,
+            "tolls_bridge_fees" => Categories::TollsBridgeFees,
+            "tourist_attractions_and_exhibits" => Categories::TouristAttractionsAndExhibits,
+            "towing_services" => Categories::TowingServices,
+            "trailer_parks_campgrounds" => Categories::TrailerParksCampgrounds,
+            "transportation_services" => Categories::TransportationServices,
+            "travel_agencies_tour_operators" => Categories::TravelAgenciesTourOperators,
+            "truck_stop_iteration" => Categories::TruckStopIteration,
+            "truck_utility_trailer_rentals" => Categories::TruckUtilityTrailerRentals,
+            "typesetting_plate_making_and_related_services" => {
+                Categories::TypesettingPlateMakingAndRelatedServices
+            }
+            "typewriter_stores" => Categories::TypewriterStores,
+            "u_s_federal_government_agencies_or_departments" => {
+                Categories::USFederalGovernmentAgenciesOrDepartments
+            }
+            "uniforms_commercial_clothing" => Categories::UniformsCommercialClothing,
+            "used_merchandise_and_secondhand_stores" => {
+                Categories::UsedMerchandiseAndSecondhandStores
+            }
+            "utilities" => Categories::Utilities,
+            "variety_stores" => Categories::VarietyStores,
+            "veterinary_services" => Categories::VeterinaryServices,
+            "video_amusement_game_supplies" => Categories::VideoAmusementGameSupplies,
+            "video_game_arcades" => Categories::VideoGameArcades,
+            "video_tape_rental_stores" => Categories::VideoTapeRentalStores,
+            "vocational_trade_schools" => Categories::VocationalTradeSchools,
+            "watch_jewelry_repair" => Categories::WatchJewelryRepair,
+            "welding_repair" => Categories::WeldingRepair,
+            "wholesale_clubs" => Categories::WholesaleClubs,
+            "wig_and_toupee_stores" => Categories::WigAndToupeeStores,
+            "wires_money_orders" => Categories::WiresMoneyOrders,
+            "womens_accessory_and_specialty_shops" => Categories::WomensAccessoryAndSpecialtyShops,
+            "womens_ready_to_wear_stores" => Categories::WomensReadyToWearStores,
+            "wrecking_and_salvage_yards" => Categories::WreckingAndSalvageYards,
+            "" => Categories::Noop,
+            _ => Categories::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Categories {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Categories {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -21400,7 +26567,8 @@ pub struct IssuingCardAuthorizationControls {
 /**
 * The delivery company that shipped a card.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Carrier {
     #[serde(rename = "dhl")]
     Dhl,
@@ -21412,8 +26580,9 @@ pub enum Carrier {
     Usps,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Carrier {
@@ -21424,12 +26593,47 @@ impl std::fmt::Display for Carrier {
             Carrier::RoyalMail => "royal_mail",
             Carrier::Usps => "usps",
             Carrier::Noop => "",
-            Carrier::FallthroughString => "*",
+            Carrier::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Carrier {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "dhl" => Carrier::Dhl,
+            "fedex" => Carrier::Fedex,
+            "royal_mail" => Carrier::RoyalMail,
+            "usps" => Carrier::Usps,
+            "" => Carrier::Noop,
+            _ => Carrier::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Carrier {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Carrier {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Carrier {
     fn default() -> Carrier {
         Carrier::Noop
@@ -21444,7 +26648,8 @@ impl Carrier {
 /**
 * Shipment service, such as `standard` or `express`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Service {
     #[serde(rename = "express")]
     Express,
@@ -21454,8 +26659,9 @@ pub enum Service {
     Standard,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Service {
@@ -21465,12 +26671,46 @@ impl std::fmt::Display for Service {
             Service::Priority => "priority",
             Service::Standard => "standard",
             Service::Noop => "",
-            Service::FallthroughString => "*",
+            Service::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Service {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "express" => Service::Express,
+            "priority" => Service::Priority,
+            "standard" => Service::Standard,
+            "" => Service::Noop,
+            _ => Service::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Service {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Service {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Service {
     fn default() -> Service {
         Service::Noop
@@ -21485,7 +26725,8 @@ impl Service {
 /**
 * The delivery status of the card.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingCardShippingStatus {
     #[serde(rename = "canceled")]
     Canceled,
@@ -21501,8 +26742,9 @@ pub enum IssuingCardShippingStatus {
     Shipped,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingCardShippingStatus {
@@ -21515,12 +26757,49 @@ impl std::fmt::Display for IssuingCardShippingStatus {
             IssuingCardShippingStatus::Returned => "returned",
             IssuingCardShippingStatus::Shipped => "shipped",
             IssuingCardShippingStatus::Noop => "",
-            IssuingCardShippingStatus::FallthroughString => "*",
+            IssuingCardShippingStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingCardShippingStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "canceled" => IssuingCardShippingStatus::Canceled,
+            "delivered" => IssuingCardShippingStatus::Delivered,
+            "failure" => IssuingCardShippingStatus::Failure,
+            "pending" => IssuingCardShippingStatus::Pending,
+            "returned" => IssuingCardShippingStatus::Returned,
+            "shipped" => IssuingCardShippingStatus::Shipped,
+            "" => IssuingCardShippingStatus::Noop,
+            _ => IssuingCardShippingStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingCardShippingStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingCardShippingStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingCardShippingStatus {
     fn default() -> IssuingCardShippingStatus {
         IssuingCardShippingStatus::Noop
@@ -21535,7 +26814,8 @@ impl IssuingCardShippingStatus {
 /**
 * Packaging options.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingCardShippingType {
     #[serde(rename = "bulk")]
     Bulk,
@@ -21543,8 +26823,9 @@ pub enum IssuingCardShippingType {
     Individual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingCardShippingType {
@@ -21553,12 +26834,45 @@ impl std::fmt::Display for IssuingCardShippingType {
             IssuingCardShippingType::Bulk => "bulk",
             IssuingCardShippingType::Individual => "individual",
             IssuingCardShippingType::Noop => "",
-            IssuingCardShippingType::FallthroughString => "*",
+            IssuingCardShippingType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingCardShippingType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "bulk" => IssuingCardShippingType::Bulk,
+            "individual" => IssuingCardShippingType::Individual,
+            "" => IssuingCardShippingType::Noop,
+            _ => IssuingCardShippingType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingCardShippingType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingCardShippingType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingCardShippingType {
     fn default() -> IssuingCardShippingType {
         IssuingCardShippingType::Noop
@@ -21643,7 +26957,8 @@ pub struct IssuingCardShippingData {
 /**
 * Interval (or event) to which the amount applies.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Interval {
     #[serde(rename = "all_time")]
     AllTime,
@@ -21659,8 +26974,9 @@ pub enum Interval {
     Yearly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Interval {
@@ -21673,12 +26989,49 @@ impl std::fmt::Display for Interval {
             Interval::Weekly => "weekly",
             Interval::Yearly => "yearly",
             Interval::Noop => "",
-            Interval::FallthroughString => "*",
+            Interval::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Interval {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all_time" => Interval::AllTime,
+            "daily" => Interval::Daily,
+            "monthly" => Interval::Monthly,
+            "per_authorization" => Interval::PerAuthorization,
+            "weekly" => Interval::Weekly,
+            "yearly" => Interval::Yearly,
+            "" => Interval::Noop,
+            _ => Interval::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Interval {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Interval {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Interval {
     fn default() -> Interval {
         Interval::Noop
@@ -21889,7 +27242,8 @@ pub struct IssuingCardholderIndividualDobData {
 /**
 * If `disabled_reason` is present, all cards will decline authorizations with `cardholder_verification_required` reason.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DisabledReason {
     #[serde(rename = "listed")]
     Listed,
@@ -21899,8 +27253,9 @@ pub enum DisabledReason {
     UnderReview,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DisabledReason {
@@ -21910,12 +27265,46 @@ impl std::fmt::Display for DisabledReason {
             DisabledReason::RejectedListed => "rejected.listed",
             DisabledReason::UnderReview => "under_review",
             DisabledReason::Noop => "",
-            DisabledReason::FallthroughString => "*",
+            DisabledReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DisabledReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "listed" => DisabledReason::Listed,
+            "rejected.listed" => DisabledReason::RejectedListed,
+            "under_review" => DisabledReason::UnderReview,
+            "" => DisabledReason::Noop,
+            _ => DisabledReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DisabledReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DisabledReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DisabledReason {
     fn default() -> DisabledReason {
         DisabledReason::Noop
@@ -21927,7 +27316,8 @@ impl DisabledReason {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PastDue {
     #[serde(rename = "company.tax_id")]
     CompanyTaxId,
@@ -21945,8 +27335,9 @@ pub enum PastDue {
     IndividualVerificationDocument,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PastDue {
@@ -21960,12 +27351,50 @@ impl std::fmt::Display for PastDue {
             PastDue::IndividualLastName => "individual.last_name",
             PastDue::IndividualVerificationDocument => "individual.verification.document",
             PastDue::Noop => "",
-            PastDue::FallthroughString => "*",
+            PastDue::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PastDue {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "company.tax_id" => PastDue::CompanyTaxId,
+            "individual.dob.day" => PastDue::IndividualDobDay,
+            "individual.dob.month" => PastDue::IndividualDobMonth,
+            "individual.dob.year" => PastDue::IndividualDobYear,
+            "individual.first_name" => PastDue::IndividualFirstName,
+            "individual.last_name" => PastDue::IndividualLastName,
+            "individual.verification.document" => PastDue::IndividualVerificationDocument,
+            "" => PastDue::Noop,
+            _ => PastDue::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PastDue {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PastDue {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PastDue {
     fn default() -> PastDue {
         PastDue::Noop
@@ -22009,7 +27438,8 @@ pub struct IssuingCardholderVerification {
 /**
 * Whether the product was a merchandise or service.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ProductType {
     #[serde(rename = "merchandise")]
     Merchandise,
@@ -22017,8 +27447,9 @@ pub enum ProductType {
     Service,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ProductType {
@@ -22027,12 +27458,45 @@ impl std::fmt::Display for ProductType {
             ProductType::Merchandise => "merchandise",
             ProductType::Service => "service",
             ProductType::Noop => "",
-            ProductType::FallthroughString => "*",
+            ProductType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ProductType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "merchandise" => ProductType::Merchandise,
+            "service" => ProductType::Service,
+            "" => ProductType::Noop,
+            _ => ProductType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ProductType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProductType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ProductType {
     fn default() -> ProductType {
         ProductType::Noop
@@ -22047,7 +27511,8 @@ impl ProductType {
 /**
 * Result of cardholder's attempt to return the product.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReturnStatus {
     #[serde(rename = "merchant_rejected")]
     MerchantRejected,
@@ -22055,8 +27520,9 @@ pub enum ReturnStatus {
     Successful,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReturnStatus {
@@ -22065,12 +27531,45 @@ impl std::fmt::Display for ReturnStatus {
             ReturnStatus::MerchantRejected => "merchant_rejected",
             ReturnStatus::Successful => "successful",
             ReturnStatus::Noop => "",
-            ReturnStatus::FallthroughString => "*",
+            ReturnStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReturnStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "merchant_rejected" => ReturnStatus::MerchantRejected,
+            "successful" => ReturnStatus::Successful,
+            "" => ReturnStatus::Noop,
+            _ => ReturnStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReturnStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReturnStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReturnStatus {
     fn default() -> ReturnStatus {
         ReturnStatus::Noop
@@ -22210,7 +27709,8 @@ pub struct IssuingDisputeDuplicateEvidence {
 /**
 * The reason for filing the dispute. Its value will match the field containing the evidence.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IssuingDisputeEvidenceReason {
     #[serde(rename = "canceled")]
     Canceled,
@@ -22228,8 +27728,9 @@ pub enum IssuingDisputeEvidenceReason {
     ServiceNotAsDescribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IssuingDisputeEvidenceReason {
@@ -22245,12 +27746,52 @@ impl std::fmt::Display for IssuingDisputeEvidenceReason {
             IssuingDisputeEvidenceReason::Other => "other",
             IssuingDisputeEvidenceReason::ServiceNotAsDescribed => "service_not_as_described",
             IssuingDisputeEvidenceReason::Noop => "",
-            IssuingDisputeEvidenceReason::FallthroughString => "*",
+            IssuingDisputeEvidenceReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IssuingDisputeEvidenceReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "canceled" => IssuingDisputeEvidenceReason::Canceled,
+            "duplicate" => IssuingDisputeEvidenceReason::Duplicate,
+            "fraudulent" => IssuingDisputeEvidenceReason::Fraudulent,
+            "merchandise_not_as_described" => {
+                IssuingDisputeEvidenceReason::MerchandiseNotAsDescribed
+            }
+            "not_received" => IssuingDisputeEvidenceReason::NotReceived,
+            "other" => IssuingDisputeEvidenceReason::Other,
+            "service_not_as_described" => IssuingDisputeEvidenceReason::ServiceNotAsDescribed,
+            "" => IssuingDisputeEvidenceReason::Noop,
+            _ => IssuingDisputeEvidenceReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IssuingDisputeEvidenceReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuingDisputeEvidenceReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IssuingDisputeEvidenceReason {
     fn default() -> IssuingDisputeEvidenceReason {
         IssuingDisputeEvidenceReason::Noop
@@ -22766,14 +28307,16 @@ pub struct IssuingTransactionReceiptData {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ItemObject {
     #[serde(rename = "item")]
     Item,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ItemObject {
@@ -22781,12 +28324,44 @@ impl std::fmt::Display for ItemObject {
         match self {
             ItemObject::Item => "item",
             ItemObject::Noop => "",
-            ItemObject::FallthroughString => "*",
+            ItemObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ItemObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "item" => ItemObject::Item,
+            "" => ItemObject::Noop,
+            _ => ItemObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ItemObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ItemObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ItemObject {
     fn default() -> ItemObject {
         ItemObject::Noop
@@ -22888,7 +28463,8 @@ pub struct Item {
 /**
 * The category identifying the legal structure of the company or legal entity. See [Business structure](https://stripe.com/docs/connect/identity-verification#business-structure) for more details.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Structure {
     #[serde(rename = "free_zone_establishment")]
     FreeZoneEstablishment,
@@ -22932,8 +28508,9 @@ pub enum Structure {
     UnincorporatedNonProfit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Structure {
@@ -22962,9 +28539,62 @@ impl std::fmt::Display for Structure {
             Structure::UnincorporatedAssociation => "unincorporated_association",
             Structure::UnincorporatedNonProfit => "unincorporated_non_profit",
             Structure::Noop => "",
-            Structure::FallthroughString => "*",
-        }
-        .fmt(f)
+            Structure::UnknownValue(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl std::str::FromStr for Structure {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "free_zone_establishment" => Structure::FreeZoneEstablishment,
+            "free_zone_llc" => Structure::FreeZoneLlc,
+            "government_instrumentality" => Structure::GovernmentInstrumentality,
+            "governmental_unit" => Structure::GovernmentalUnit,
+            "incorporated_non_profit" => Structure::IncorporatedNonProfit,
+            "limited_liability_partnership" => Structure::LimitedLiabilityPartnership,
+            "llc" => Structure::Llc,
+            "multi_member_llc" => Structure::MultiMemberLlc,
+            "private_company" => Structure::PrivateCompany,
+            "private_corporation" => Structure::PrivateCorporation,
+            "private_partnership" => Structure::PrivatePartnership,
+            "public_company" => Structure::PublicCompany,
+            "public_corporation" => Structure::PublicCorporation,
+            "public_partnership" => Structure::PublicPartnership,
+            "single_member_llc" => Structure::SingleMemberLlc,
+            "sole_establishment" => Structure::SoleEstablishment,
+            "sole_proprietorship" => Structure::SoleProprietorship,
+            "tax_exempt_government_instrumentality" => {
+                Structure::TaxExemptGovernmentInstrumentality
+            }
+            "unincorporated_association" => Structure::UnincorporatedAssociation,
+            "unincorporated_non_profit" => Structure::UnincorporatedNonProfit,
+            "" => Structure::Noop,
+            _ => Structure::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Structure {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Structure {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -23354,14 +28984,16 @@ pub struct LegalEntityUboDeclaration {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LineItemObject {
     #[serde(rename = "line_item")]
     LineItem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LineItemObject {
@@ -23369,12 +29001,44 @@ impl std::fmt::Display for LineItemObject {
         match self {
             LineItemObject::LineItem => "line_item",
             LineItemObject::Noop => "",
-            LineItemObject::FallthroughString => "*",
+            LineItemObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LineItemObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "line_item" => LineItemObject::LineItem,
+            "" => LineItemObject::Noop,
+            _ => LineItemObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LineItemObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LineItemObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LineItemObject {
     fn default() -> LineItemObject {
         LineItemObject::Noop
@@ -23389,7 +29053,8 @@ impl LineItemObject {
 /**
 * A string identifying the type of the source of this line item, either an `invoiceitem` or a `subscription`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LineItemType {
     #[serde(rename = "invoiceitem")]
     Invoiceitem,
@@ -23397,8 +29062,9 @@ pub enum LineItemType {
     Subscription,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LineItemType {
@@ -23407,12 +29073,45 @@ impl std::fmt::Display for LineItemType {
             LineItemType::Invoiceitem => "invoiceitem",
             LineItemType::Subscription => "subscription",
             LineItemType::Noop => "",
-            LineItemType::FallthroughString => "*",
+            LineItemType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LineItemType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "invoiceitem" => LineItemType::Invoiceitem,
+            "subscription" => LineItemType::Subscription,
+            "" => LineItemType::Noop,
+            _ => LineItemType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LineItemType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LineItemType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LineItemType {
     fn default() -> LineItemType {
         LineItemType::Noop
@@ -23642,14 +29341,16 @@ pub struct LineItemsTaxAmount {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LoginLinkObject {
     #[serde(rename = "login_link")]
     LoginLink,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LoginLinkObject {
@@ -23657,12 +29358,44 @@ impl std::fmt::Display for LoginLinkObject {
         match self {
             LoginLinkObject::LoginLink => "login_link",
             LoginLinkObject::Noop => "",
-            LoginLinkObject::FallthroughString => "*",
+            LoginLinkObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LoginLinkObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "login_link" => LoginLinkObject::LoginLink,
+            "" => LoginLinkObject::Noop,
+            _ => LoginLinkObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LoginLinkObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LoginLinkObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LoginLinkObject {
     fn default() -> LoginLinkObject {
         LoginLinkObject::Noop
@@ -23705,14 +29438,16 @@ pub struct LoginLink {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MandateObject {
     #[serde(rename = "mandate")]
     Mandate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MandateObject {
@@ -23720,12 +29455,44 @@ impl std::fmt::Display for MandateObject {
         match self {
             MandateObject::Mandate => "mandate",
             MandateObject::Noop => "",
-            MandateObject::FallthroughString => "*",
+            MandateObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MandateObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "mandate" => MandateObject::Mandate,
+            "" => MandateObject::Noop,
+            _ => MandateObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MandateObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MandateObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MandateObject {
     fn default() -> MandateObject {
         MandateObject::Noop
@@ -23740,7 +29507,8 @@ impl MandateObject {
 /**
 * The type of the mandate.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MandateType {
     #[serde(rename = "multi_use")]
     MultiUse,
@@ -23748,8 +29516,9 @@ pub enum MandateType {
     SingleUse,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MandateType {
@@ -23758,12 +29527,45 @@ impl std::fmt::Display for MandateType {
             MandateType::MultiUse => "multi_use",
             MandateType::SingleUse => "single_use",
             MandateType::Noop => "",
-            MandateType::FallthroughString => "*",
+            MandateType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MandateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "multi_use" => MandateType::MultiUse,
+            "single_use" => MandateType::SingleUse,
+            "" => MandateType::Noop,
+            _ => MandateType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MandateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MandateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MandateType {
     fn default() -> MandateType {
         MandateType::Noop
@@ -23884,7 +29686,8 @@ pub struct MandateAuBecsDebit {
 /**
 * The status of the mandate on the Bacs network. Can be one of `pending`, `revoked`, `refused`, or `accepted`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum NetworkStatus {
     #[serde(rename = "accepted")]
     Accepted,
@@ -23896,8 +29699,9 @@ pub enum NetworkStatus {
     Revoked,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for NetworkStatus {
@@ -23908,12 +29712,47 @@ impl std::fmt::Display for NetworkStatus {
             NetworkStatus::Refused => "refused",
             NetworkStatus::Revoked => "revoked",
             NetworkStatus::Noop => "",
-            NetworkStatus::FallthroughString => "*",
+            NetworkStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for NetworkStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "accepted" => NetworkStatus::Accepted,
+            "pending" => NetworkStatus::Pending,
+            "refused" => NetworkStatus::Refused,
+            "revoked" => NetworkStatus::Revoked,
+            "" => NetworkStatus::Noop,
+            _ => NetworkStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NetworkStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for NetworkStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for NetworkStatus {
     fn default() -> NetworkStatus {
         NetworkStatus::Noop
@@ -24131,14 +29970,16 @@ pub struct OnlineAcceptance {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrderObject {
     #[serde(rename = "order")]
     Order,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrderObject {
@@ -24146,12 +29987,44 @@ impl std::fmt::Display for OrderObject {
         match self {
             OrderObject::Order => "order",
             OrderObject::Noop => "",
-            OrderObject::FallthroughString => "*",
+            OrderObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrderObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "order" => OrderObject::Order,
+            "" => OrderObject::Noop,
+            _ => OrderObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrderObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrderObject {
     fn default() -> OrderObject {
         OrderObject::Noop
@@ -24437,14 +30310,16 @@ pub struct Order {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrderItemObject {
     #[serde(rename = "order_item")]
     OrderItem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrderItemObject {
@@ -24452,12 +30327,44 @@ impl std::fmt::Display for OrderItemObject {
         match self {
             OrderItemObject::OrderItem => "order_item",
             OrderItemObject::Noop => "",
-            OrderItemObject::FallthroughString => "*",
+            OrderItemObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrderItemObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "order_item" => OrderItemObject::OrderItem,
+            "" => OrderItemObject::Noop,
+            _ => OrderItemObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrderItemObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderItemObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrderItemObject {
     fn default() -> OrderItemObject {
         OrderItemObject::Noop
@@ -24595,14 +30502,16 @@ pub struct OrderItem {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OrderReturnObject {
     #[serde(rename = "order_return")]
     OrderReturn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OrderReturnObject {
@@ -24610,12 +30519,44 @@ impl std::fmt::Display for OrderReturnObject {
         match self {
             OrderReturnObject::OrderReturn => "order_return",
             OrderReturnObject::Noop => "",
-            OrderReturnObject::FallthroughString => "*",
+            OrderReturnObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OrderReturnObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "order_return" => OrderReturnObject::OrderReturn,
+            "" => OrderReturnObject::Noop,
+            _ => OrderReturnObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OrderReturnObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderReturnObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OrderReturnObject {
     fn default() -> OrderReturnObject {
         OrderReturnObject::Noop
@@ -24830,7 +30771,8 @@ pub struct PaymentFlowsPrivateMethodsKlarnaDob {
 /**
 * Reason for cancellation of this PaymentIntent, either user-provided (`duplicate`, `fraudulent`, `requested_by_customer`, or `abandoned`) or generated by Stripe internally (`failed_invoice`, `void_invoice`, or `automatic`).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentIntentCancellationReason {
     #[serde(rename = "abandoned")]
     Abandoned,
@@ -24848,8 +30790,9 @@ pub enum PaymentIntentCancellationReason {
     VoidInvoice,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentIntentCancellationReason {
@@ -24863,12 +30806,50 @@ impl std::fmt::Display for PaymentIntentCancellationReason {
             PaymentIntentCancellationReason::RequestedByCustomer => "requested_by_customer",
             PaymentIntentCancellationReason::VoidInvoice => "void_invoice",
             PaymentIntentCancellationReason::Noop => "",
-            PaymentIntentCancellationReason::FallthroughString => "*",
+            PaymentIntentCancellationReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentIntentCancellationReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "abandoned" => PaymentIntentCancellationReason::Abandoned,
+            "automatic" => PaymentIntentCancellationReason::Automatic,
+            "duplicate" => PaymentIntentCancellationReason::Duplicate,
+            "failed_invoice" => PaymentIntentCancellationReason::FailedInvoice,
+            "fraudulent" => PaymentIntentCancellationReason::Fraudulent,
+            "requested_by_customer" => PaymentIntentCancellationReason::RequestedByCustomer,
+            "void_invoice" => PaymentIntentCancellationReason::VoidInvoice,
+            "" => PaymentIntentCancellationReason::Noop,
+            _ => PaymentIntentCancellationReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentIntentCancellationReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentIntentCancellationReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentIntentCancellationReason {
     fn default() -> PaymentIntentCancellationReason {
         PaymentIntentCancellationReason::Noop
@@ -24883,7 +30864,8 @@ impl PaymentIntentCancellationReason {
 /**
 * Controls when the funds will be captured from the customer's account.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CaptureMethod {
     #[serde(rename = "automatic")]
     Automatic,
@@ -24891,8 +30873,9 @@ pub enum CaptureMethod {
     Manual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CaptureMethod {
@@ -24901,12 +30884,45 @@ impl std::fmt::Display for CaptureMethod {
             CaptureMethod::Automatic => "automatic",
             CaptureMethod::Manual => "manual",
             CaptureMethod::Noop => "",
-            CaptureMethod::FallthroughString => "*",
+            CaptureMethod::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CaptureMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "automatic" => CaptureMethod::Automatic,
+            "manual" => CaptureMethod::Manual,
+            "" => CaptureMethod::Noop,
+            _ => CaptureMethod::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CaptureMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CaptureMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CaptureMethod {
     fn default() -> CaptureMethod {
         CaptureMethod::Noop
@@ -24957,14 +30973,16 @@ pub struct Charges {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentIntentObject {
     #[serde(rename = "payment_intent")]
     PaymentIntent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentIntentObject {
@@ -24972,12 +30990,44 @@ impl std::fmt::Display for PaymentIntentObject {
         match self {
             PaymentIntentObject::PaymentIntent => "payment_intent",
             PaymentIntentObject::Noop => "",
-            PaymentIntentObject::FallthroughString => "*",
+            PaymentIntentObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentIntentObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "payment_intent" => PaymentIntentObject::PaymentIntent,
+            "" => PaymentIntentObject::Noop,
+            _ => PaymentIntentObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentIntentObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentIntentObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentIntentObject {
     fn default() -> PaymentIntentObject {
         PaymentIntentObject::Noop
@@ -24996,7 +31046,8 @@ impl PaymentIntentObject {
 *   
 *   When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SetupFutureUsage {
     #[serde(rename = "off_session")]
     OffSession,
@@ -25004,8 +31055,9 @@ pub enum SetupFutureUsage {
     OnSession,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SetupFutureUsage {
@@ -25014,12 +31066,45 @@ impl std::fmt::Display for SetupFutureUsage {
             SetupFutureUsage::OffSession => "off_session",
             SetupFutureUsage::OnSession => "on_session",
             SetupFutureUsage::Noop => "",
-            SetupFutureUsage::FallthroughString => "*",
+            SetupFutureUsage::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SetupFutureUsage {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "off_session" => SetupFutureUsage::OffSession,
+            "on_session" => SetupFutureUsage::OnSession,
+            "" => SetupFutureUsage::Noop,
+            _ => SetupFutureUsage::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SetupFutureUsage {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SetupFutureUsage {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SetupFutureUsage {
     fn default() -> SetupFutureUsage {
         SetupFutureUsage::Noop
@@ -25034,7 +31119,8 @@ impl SetupFutureUsage {
 /**
 * Status of this PaymentIntent, one of `requires_payment_method`, `requires_confirmation`, `requires_action`, `processing`, `requires_capture`, `canceled`, or `succeeded`. Read more about each PaymentIntent [status](https://stripe.com/docs/payments/intents#intent-statuses).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentIntentStatus {
     #[serde(rename = "canceled")]
     Canceled,
@@ -25052,8 +31138,9 @@ pub enum PaymentIntentStatus {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentIntentStatus {
@@ -25067,12 +31154,50 @@ impl std::fmt::Display for PaymentIntentStatus {
             PaymentIntentStatus::RequiresPaymentMethod => "requires_payment_method",
             PaymentIntentStatus::Succeeded => "succeeded",
             PaymentIntentStatus::Noop => "",
-            PaymentIntentStatus::FallthroughString => "*",
+            PaymentIntentStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentIntentStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "canceled" => PaymentIntentStatus::Canceled,
+            "processing" => PaymentIntentStatus::Processing,
+            "requires_action" => PaymentIntentStatus::RequiresAction,
+            "requires_capture" => PaymentIntentStatus::RequiresCapture,
+            "requires_confirmation" => PaymentIntentStatus::RequiresConfirmation,
+            "requires_payment_method" => PaymentIntentStatus::RequiresPaymentMethod,
+            "succeeded" => PaymentIntentStatus::Succeeded,
+            "" => PaymentIntentStatus::Noop,
+            _ => PaymentIntentStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentIntentStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentIntentStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentIntentStatus {
     fn default() -> PaymentIntentStatus {
         PaymentIntentStatus::Noop
@@ -25960,7 +32085,8 @@ pub struct PaymentIntentNextActionRedirectUrl {
 /**
 * The type of the microdeposit sent to the customer. Used to distinguish between different verification methods.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MicrodepositType {
     #[serde(rename = "amounts")]
     Amounts,
@@ -25968,8 +32094,9 @@ pub enum MicrodepositType {
     DescriptorCode,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MicrodepositType {
@@ -25978,12 +32105,45 @@ impl std::fmt::Display for MicrodepositType {
             MicrodepositType::Amounts => "amounts",
             MicrodepositType::DescriptorCode => "descriptor_code",
             MicrodepositType::Noop => "",
-            MicrodepositType::FallthroughString => "*",
+            MicrodepositType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MicrodepositType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "amounts" => MicrodepositType::Amounts,
+            "descriptor_code" => MicrodepositType::DescriptorCode,
+            "" => MicrodepositType::Noop,
+            _ => MicrodepositType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MicrodepositType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MicrodepositType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MicrodepositType {
     fn default() -> MicrodepositType {
         MicrodepositType::Noop
@@ -27203,7 +33363,8 @@ pub struct PaymentIntentMethodOptionsData {
 *   
 *   When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
     #[serde(rename = "none")]
     None,
@@ -27213,8 +33374,9 @@ pub enum PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
     OnSession,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
@@ -27224,12 +33386,46 @@ impl std::fmt::Display for PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
             PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::OffSession => "off_session",
             PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::OnSession => "on_session",
             PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::Noop => "",
-            PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::FallthroughString => "*",
+            PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "none" => PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::None,
+            "off_session" => PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::OffSession,
+            "on_session" => PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::OnSession,
+            "" => PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::Noop,
+            _ => PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
     fn default() -> PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
         PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::Noop
@@ -27277,14 +33473,16 @@ pub struct PaymentIntentMethodOptionsAuBecsDebit {
 /**
 * Controls when the funds will be captured from the customer's account.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentIntentMethodOptionsCardCapture {
     #[serde(rename = "manual")]
     Manual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsCardCapture {
@@ -27292,12 +33490,44 @@ impl std::fmt::Display for PaymentIntentMethodOptionsCardCapture {
         match self {
             PaymentIntentMethodOptionsCardCapture::Manual => "manual",
             PaymentIntentMethodOptionsCardCapture::Noop => "",
-            PaymentIntentMethodOptionsCardCapture::FallthroughString => "*",
+            PaymentIntentMethodOptionsCardCapture::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentIntentMethodOptionsCardCapture {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "manual" => PaymentIntentMethodOptionsCardCapture::Manual,
+            "" => PaymentIntentMethodOptionsCardCapture::Noop,
+            _ => PaymentIntentMethodOptionsCardCapture::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentIntentMethodOptionsCardCapture {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentIntentMethodOptionsCardCapture {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentIntentMethodOptionsCardCapture {
     fn default() -> PaymentIntentMethodOptionsCardCapture {
         PaymentIntentMethodOptionsCardCapture::Noop
@@ -27312,7 +33542,8 @@ impl PaymentIntentMethodOptionsCardCapture {
 /**
 * Selected network to process this payment intent on. Depends on the available networks of the card attached to the payment intent. Can be only set confirm-time.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentIntentMethodOptionsCardNetwork {
     #[serde(rename = "amex")]
     Amex,
@@ -27336,8 +33567,9 @@ pub enum PaymentIntentMethodOptionsCardNetwork {
     Visa,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsCardNetwork {
@@ -27354,12 +33586,53 @@ impl std::fmt::Display for PaymentIntentMethodOptionsCardNetwork {
             PaymentIntentMethodOptionsCardNetwork::Unknown => "unknown",
             PaymentIntentMethodOptionsCardNetwork::Visa => "visa",
             PaymentIntentMethodOptionsCardNetwork::Noop => "",
-            PaymentIntentMethodOptionsCardNetwork::FallthroughString => "*",
+            PaymentIntentMethodOptionsCardNetwork::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentIntentMethodOptionsCardNetwork {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "amex" => PaymentIntentMethodOptionsCardNetwork::Amex,
+            "cartes_bancaires" => PaymentIntentMethodOptionsCardNetwork::CartesBancaires,
+            "diners" => PaymentIntentMethodOptionsCardNetwork::Diners,
+            "discover" => PaymentIntentMethodOptionsCardNetwork::Discover,
+            "interac" => PaymentIntentMethodOptionsCardNetwork::Interac,
+            "jcb" => PaymentIntentMethodOptionsCardNetwork::Jcb,
+            "mastercard" => PaymentIntentMethodOptionsCardNetwork::Mastercard,
+            "unionpay" => PaymentIntentMethodOptionsCardNetwork::Unionpay,
+            "unknown" => PaymentIntentMethodOptionsCardNetwork::Unknown,
+            "visa" => PaymentIntentMethodOptionsCardNetwork::Visa,
+            "" => PaymentIntentMethodOptionsCardNetwork::Noop,
+            _ => PaymentIntentMethodOptionsCardNetwork::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentIntentMethodOptionsCardNetwork {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentIntentMethodOptionsCardNetwork {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentIntentMethodOptionsCardNetwork {
     fn default() -> PaymentIntentMethodOptionsCardNetwork {
         PaymentIntentMethodOptionsCardNetwork::Noop
@@ -27374,7 +33647,8 @@ impl PaymentIntentMethodOptionsCardNetwork {
 /**
 * We strongly recommend that you rely on our SCA Engine to automatically prompt your customers for authentication based on risk level and [other requirements](https://stripe.com/docs/strong-customer-authentication). However, if you wish to request 3D Secure based on logic from your own fraud engine, provide this option. Permitted values include: `automatic` or `any`. If not provided, defaults to `automatic`. Read our guide on [manually requesting 3D Secure](https://stripe.com/docs/payments/3d-secure#manual-three-ds) for more information on how this configuration interacts with Radar and our SCA Engine.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentIntentMethodOptionsCardRequestThreeDSecure {
     #[serde(rename = "any")]
     Any,
@@ -27384,8 +33658,9 @@ pub enum PaymentIntentMethodOptionsCardRequestThreeDSecure {
     ChallengeOnly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsCardRequestThreeDSecure {
@@ -27395,12 +33670,46 @@ impl std::fmt::Display for PaymentIntentMethodOptionsCardRequestThreeDSecure {
             PaymentIntentMethodOptionsCardRequestThreeDSecure::Automatic => "automatic",
             PaymentIntentMethodOptionsCardRequestThreeDSecure::ChallengeOnly => "challenge_only",
             PaymentIntentMethodOptionsCardRequestThreeDSecure::Noop => "",
-            PaymentIntentMethodOptionsCardRequestThreeDSecure::FallthroughString => "*",
+            PaymentIntentMethodOptionsCardRequestThreeDSecure::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentIntentMethodOptionsCardRequestThreeDSecure {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "any" => PaymentIntentMethodOptionsCardRequestThreeDSecure::Any,
+            "automatic" => PaymentIntentMethodOptionsCardRequestThreeDSecure::Automatic,
+            "challenge_only" => PaymentIntentMethodOptionsCardRequestThreeDSecure::ChallengeOnly,
+            "" => PaymentIntentMethodOptionsCardRequestThreeDSecure::Noop,
+            _ => PaymentIntentMethodOptionsCardRequestThreeDSecure::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentIntentMethodOptionsCardRequestThreeDSecure {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentIntentMethodOptionsCardRequestThreeDSecure {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentIntentMethodOptionsCardRequestThreeDSecure {
     fn default() -> PaymentIntentMethodOptionsCardRequestThreeDSecure {
         PaymentIntentMethodOptionsCardRequestThreeDSecure::Noop
@@ -27459,14 +33768,16 @@ pub struct PaymentIntentMethodOptionsCard {
 *   
 *   When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentIntentMethodOptionsEpsSetupFutureUsage {
     #[serde(rename = "none")]
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsEpsSetupFutureUsage {
@@ -27474,12 +33785,44 @@ impl std::fmt::Display for PaymentIntentMethodOptionsEpsSetupFutureUsage {
         match self {
             PaymentIntentMethodOptionsEpsSetupFutureUsage::None => "none",
             PaymentIntentMethodOptionsEpsSetupFutureUsage::Noop => "",
-            PaymentIntentMethodOptionsEpsSetupFutureUsage::FallthroughString => "*",
+            PaymentIntentMethodOptionsEpsSetupFutureUsage::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentIntentMethodOptionsEpsSetupFutureUsage {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "none" => PaymentIntentMethodOptionsEpsSetupFutureUsage::None,
+            "" => PaymentIntentMethodOptionsEpsSetupFutureUsage::Noop,
+            _ => PaymentIntentMethodOptionsEpsSetupFutureUsage::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentIntentMethodOptionsEpsSetupFutureUsage {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentIntentMethodOptionsEpsSetupFutureUsage {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentIntentMethodOptionsEpsSetupFutureUsage {
     fn default() -> PaymentIntentMethodOptionsEpsSetupFutureUsage {
         PaymentIntentMethodOptionsEpsSetupFutureUsage::Noop
@@ -27624,14 +33967,16 @@ pub struct PaymentIntentTypeSpecificMethodOptionsClient {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentLinkObject {
     #[serde(rename = "payment_link")]
     PaymentLink,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentLinkObject {
@@ -27639,12 +33984,44 @@ impl std::fmt::Display for PaymentLinkObject {
         match self {
             PaymentLinkObject::PaymentLink => "payment_link",
             PaymentLinkObject::Noop => "",
-            PaymentLinkObject::FallthroughString => "*",
+            PaymentLinkObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentLinkObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "payment_link" => PaymentLinkObject::PaymentLink,
+            "" => PaymentLinkObject::Noop,
+            _ => PaymentLinkObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentLinkObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentLinkObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentLinkObject {
     fn default() -> PaymentLinkObject {
         PaymentLinkObject::Noop
@@ -27808,7 +34185,8 @@ pub struct PaymentLink {
 /**
 * The specified behavior after the purchase is complete.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentLinksResourceAfterCompletionType {
     #[serde(rename = "hosted_confirmation")]
     HostedConfirmation,
@@ -27816,8 +34194,9 @@ pub enum PaymentLinksResourceAfterCompletionType {
     Redirect,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentLinksResourceAfterCompletionType {
@@ -27826,12 +34205,45 @@ impl std::fmt::Display for PaymentLinksResourceAfterCompletionType {
             PaymentLinksResourceAfterCompletionType::HostedConfirmation => "hosted_confirmation",
             PaymentLinksResourceAfterCompletionType::Redirect => "redirect",
             PaymentLinksResourceAfterCompletionType::Noop => "",
-            PaymentLinksResourceAfterCompletionType::FallthroughString => "*",
+            PaymentLinksResourceAfterCompletionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentLinksResourceAfterCompletionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "hosted_confirmation" => PaymentLinksResourceAfterCompletionType::HostedConfirmation,
+            "redirect" => PaymentLinksResourceAfterCompletionType::Redirect,
+            "" => PaymentLinksResourceAfterCompletionType::Noop,
+            _ => PaymentLinksResourceAfterCompletionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentLinksResourceAfterCompletionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentLinksResourceAfterCompletionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentLinksResourceAfterCompletionType {
     fn default() -> PaymentLinksResourceAfterCompletionType {
         PaymentLinksResourceAfterCompletionType::Noop
@@ -27881,7 +34293,8 @@ pub struct PaymentLinksResourceCompletionBehaviorConfirmationPage {
     pub custom_message: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AllowedCountries {
     #[serde(rename = "AC")]
     Ac,
@@ -28359,8 +34772,9 @@ pub enum AllowedCountries {
     Zz,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AllowedCountries {
@@ -28604,9 +35018,277 @@ impl std::fmt::Display for AllowedCountries {
             AllowedCountries::Zw => "ZW",
             AllowedCountries::Zz => "ZZ",
             AllowedCountries::Noop => "",
-            AllowedCountries::FallthroughString => "*",
-        }
-        .fmt(f)
+            AllowedCountries::UnknownValue(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl std::str::FromStr for AllowedCountries {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AC" => AllowedCountries::Ac,
+            "AD" => AllowedCountries::Ad,
+            "AE" => AllowedCountries::Ae,
+            "AF" => AllowedCountries::Af,
+            "AG" => AllowedCountries::Ag,
+            "AI" => AllowedCountries::Ai,
+            "AL" => AllowedCountries::Al,
+            "AM" => AllowedCountries::Am,
+            "AO" => AllowedCountries::Ao,
+            "AQ" => AllowedCountries::Aq,
+            "AR" => AllowedCountries::Ar,
+            "AT" => AllowedCountries::At,
+            "AU" => AllowedCountries::Au,
+            "AW" => AllowedCountries::Aw,
+            "AX" => AllowedCountries::Ax,
+            "AZ" => AllowedCountries::Az,
+            "BA" => AllowedCountries::Ba,
+            "BB" => AllowedCountries::Bb,
+            "BD" => AllowedCountries::Bd,
+            "BE" => AllowedCountries::Be,
+            "BF" => AllowedCountries::Bf,
+            "BG" => AllowedCountries::Bg,
+            "BH" => AllowedCountries::Bh,
+            "BI" => AllowedCountries::Bi,
+            "BJ" => AllowedCountries::Bj,
+            "BL" => AllowedCountries::Bl,
+            "BM" => AllowedCountries::Bm,
+            "BN" => AllowedCountries::Bn,
+            "BO" => AllowedCountries::Bo,
+            "BQ" => AllowedCountries::Bq,
+            "BR" => AllowedCountries::Br,
+            "BS" => AllowedCountries::Bs,
+            "BT" => AllowedCountries::Bt,
+            "BV" => AllowedCountries::Bv,
+            "BW" => AllowedCountries::Bw,
+            "BY" => AllowedCountries::By,
+            "BZ" => AllowedCountries::Bz,
+            "CA" => AllowedCountries::Ca,
+            "CD" => AllowedCountries::Cd,
+            "CF" => AllowedCountries::Cf,
+            "CG" => AllowedCountries::Cg,
+            "CH" => AllowedCountries::Ch,
+            "CI" => AllowedCountries::Ci,
+            "CK" => AllowedCountries::Ck,
+            "CL" => AllowedCountries::Cl,
+            "CM" => AllowedCountries::Cm,
+            "CN" => AllowedCountries::Cn,
+            "CO" => AllowedCountries::Co,
+            "CR" => AllowedCountries::Cr,
+            "CV" => AllowedCountries::Cv,
+            "CW" => AllowedCountries::Cw,
+            "CY" => AllowedCountries::Cy,
+            "CZ" => AllowedCountries::Cz,
+            "DE" => AllowedCountries::De,
+            "DJ" => AllowedCountries::Dj,
+            "DK" => AllowedCountries::Dk,
+            "DM" => AllowedCountries::Dm,
+            "DO" => AllowedCountries::Do,
+            "DZ" => AllowedCountries::Dz,
+            "EC" => AllowedCountries::Ec,
+            "EE" => AllowedCountries::Ee,
+            "EG" => AllowedCountries::Eg,
+            "EH" => AllowedCountries::Eh,
+            "ER" => AllowedCountries::Er,
+            "ES" => AllowedCountries::Es,
+            "ET" => AllowedCountries::Et,
+            "FI" => AllowedCountries::Fi,
+            "FJ" => AllowedCountries::Fj,
+            "FK" => AllowedCountries::Fk,
+            "FO" => AllowedCountries::Fo,
+            "FR" => AllowedCountries::Fr,
+            "GA" => AllowedCountries::Ga,
+            "GB" => AllowedCountries::Gb,
+            "GD" => AllowedCountries::Gd,
+            "GE" => AllowedCountries::Ge,
+            "GF" => AllowedCountries::Gf,
+            "GG" => AllowedCountries::Gg,
+            "GH" => AllowedCountries::Gh,
+            "GI" => AllowedCountries::Gi,
+            "GL" => AllowedCountries::Gl,
+            "GM" => AllowedCountries::Gm,
+            "GN" => AllowedCountries::Gn,
+            "GP" => AllowedCountries::Gp,
+            "GQ" => AllowedCountries::Gq,
+            "GR" => AllowedCountries::Gr,
+            "GS" => AllowedCountries::Gs,
+            "GT" => AllowedCountries::Gt,
+            "GU" => AllowedCountries::Gu,
+            "GW" => AllowedCountries::Gw,
+            "GY" => AllowedCountries::Gy,
+            "HK" => AllowedCountries::Hk,
+            "HN" => AllowedCountries::Hn,
+            "HR" => AllowedCountries::Hr,
+            "HT" => AllowedCountries::Ht,
+            "HU" => AllowedCountries::Hu,
+            "ID" => AllowedCountries::Id,
+            "IE" => AllowedCountries::Ie,
+            "IL" => AllowedCountries::Il,
+            "IM" => AllowedCountries::Im,
+            "IN" => AllowedCountries::In,
+            "IO" => AllowedCountries::Io,
+            "IQ" => AllowedCountries::Iq,
+            "IS" => AllowedCountries::Is,
+            "IT" => AllowedCountries::It,
+            "JE" => AllowedCountries::Je,
+            "JM" => AllowedCountries::Jm,
+            "JO" => AllowedCountries::Jo,
+            "JP" => AllowedCountries::Jp,
+            "KE" => AllowedCountries::Ke,
+            "KG" => AllowedCountries::Kg,
+            "KH" => AllowedCountries::Kh,
+            "KI" => AllowedCountries::Ki,
+            "KM" => AllowedCountries::Km,
+            "KN" => AllowedCountries::Kn,
+            "KR" => AllowedCountries::Kr,
+            "KW" => AllowedCountries::Kw,
+            "KY" => AllowedCountries::Ky,
+            "KZ" => AllowedCountries::Kz,
+            "LA" => AllowedCountries::La,
+            "LB" => AllowedCountries::Lb,
+            "LC" => AllowedCountries::Lc,
+            "LI" => AllowedCountries::Li,
+            "LK" => AllowedCountries::Lk,
+            "LR" => AllowedCountries::Lr,
+            "LS" => AllowedCountries::Ls,
+            "LT" => AllowedCountries::Lt,
+            "LU" => AllowedCountries::Lu,
+            "LV" => AllowedCountries::Lv,
+            "LY" => AllowedCountries::Ly,
+            "MA" => AllowedCountries::Ma,
+            "MC" => AllowedCountries::Mc,
+            "MD" => AllowedCountries::Md,
+            "ME" => AllowedCountries::Me,
+            "MF" => AllowedCountries::Mf,
+            "MG" => AllowedCountries::Mg,
+            "MK" => AllowedCountries::Mk,
+            "ML" => AllowedCountries::Ml,
+            "MM" => AllowedCountries::Mm,
+            "MN" => AllowedCountries::Mn,
+            "MO" => AllowedCountries::Mo,
+            "MQ" => AllowedCountries::Mq,
+            "MR" => AllowedCountries::Mr,
+            "MS" => AllowedCountries::Ms,
+            "MT" => AllowedCountries::Mt,
+            "MU" => AllowedCountries::Mu,
+            "MV" => AllowedCountries::Mv,
+            "MW" => AllowedCountries::Mw,
+            "MX" => AllowedCountries::Mx,
+            "MY" => AllowedCountries::My,
+            "MZ" => AllowedCountries::Mz,
+            "NA" => AllowedCountries::Na,
+            "NC" => AllowedCountries::Nc,
+            "NE" => AllowedCountries::Ne,
+            "NG" => AllowedCountries::Ng,
+            "NI" => AllowedCountries::Ni,
+            "NL" => AllowedCountries::Nl,
+            "NO" => AllowedCountries::No,
+            "NP" => AllowedCountries::Np,
+            "NR" => AllowedCountries::Nr,
+            "NU" => AllowedCountries::Nu,
+            "NZ" => AllowedCountries::Nz,
+            "OM" => AllowedCountries::Om,
+            "PA" => AllowedCountries::Pa,
+            "PE" => AllowedCountries::Pe,
+            "PF" => AllowedCountries::Pf,
+            "PG" => AllowedCountries::Pg,
+            "PH" => AllowedCountries::Ph,
+            "PK" => AllowedCountries::Pk,
+            "PL" => AllowedCountries::Pl,
+            "PM" => AllowedCountries::Pm,
+            "PN" => AllowedCountries::Pn,
+            "PR" => AllowedCountries::Pr,
+            "PS" => AllowedCountries::Ps,
+            "PT" => AllowedCountries::Pt,
+            "PY" => AllowedCountries::Py,
+            "QA" => AllowedCountries::Qa,
+            "RE" => AllowedCountries::Re,
+            "RO" => AllowedCountries::Ro,
+            "RS" => AllowedCountries::Rs,
+            "RU" => AllowedCountries::Ru,
+            "RW" => AllowedCountries::Rw,
+            "SA" => AllowedCountries::Sa,
+            "SB" => AllowedCountries::Sb,
+            "SC" => AllowedCountries::Sc,
+            "SE" => AllowedCountries::Se,
+            "SG" => AllowedCountries::Sg,
+            "SH" => AllowedCountries::Sh,
+            "SI" => AllowedCountries::Si,
+            "SJ" => AllowedCountries::Sj,
+            "SK" => AllowedCountries::Sk,
+            "SL" => AllowedCountries::Sl,
+            "SM" => AllowedCountries::Sm,
+            "SN" => AllowedCountries::Sn,
+            "SO" => AllowedCountries::So,
+            "SR" => AllowedCountries::Sr,
+            "SS" => AllowedCountries::Ss,
+            "ST" => AllowedCountries::St,
+            "SV" => AllowedCountries::Sv,
+            "SX" => AllowedCountries::Sx,
+            "SZ" => AllowedCountries::Sz,
+            "TA" => AllowedCountries::Ta,
+            "TC" => AllowedCountries::Tc,
+            "TD" => AllowedCountries::Td,
+            "TF" => AllowedCountries::Tf,
+            "TG" => AllowedCountries::Tg,
+            "TH" => AllowedCountries::Th,
+            "TJ" => AllowedCountries::Tj,
+            "TK" => AllowedCountries::Tk,
+            "TL" => AllowedCountries::Tl,
+            "TM" => AllowedCountries::Tm,
+            "TN" => AllowedCountries::Tn,
+            "TO" => AllowedCountries::To,
+            "TR" => AllowedCountries::Tr,
+            "TT" => AllowedCountries::Tt,
+            "TV" => AllowedCountries::Tv,
+            "TW" => AllowedCountries::Tw,
+            "TZ" => AllowedCountries::Tz,
+            "UA" => AllowedCountries::Ua,
+            "UG" => AllowedCountries::Ug,
+            "US" => AllowedCountries::Us,
+            "UY" => AllowedCountries::Uy,
+            "UZ" => AllowedCountries::Uz,
+            "VA" => AllowedCountries::Va,
+            "VC" => AllowedCountries::Vc,
+            "VE" => AllowedCountries::Ve,
+            "VG" => AllowedCountries::Vg,
+            "VN" => AllowedCountries::Vn,
+            "VU" => AllowedCountries::Vu,
+            "WF" => AllowedCountries::Wf,
+            "WS" => AllowedCountries::Ws,
+            "XK" => AllowedCountries::Xk,
+            "YE" => AllowedCountries::Ye,
+            "YT" => AllowedCountries::Yt,
+            "ZA" => AllowedCountries::Za,
+            "ZM" => AllowedCountries::Zm,
+            "ZW" => AllowedCountries::Zw,
+            "ZZ" => AllowedCountries::Zz,
+            "" => AllowedCountries::Noop,
+            _ => AllowedCountries::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AllowedCountries {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AllowedCountries {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -28668,14 +35350,16 @@ pub struct PaymentLinksResourceTransferData {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodObject {
     #[serde(rename = "payment_method")]
     PaymentMethod,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodObject {
@@ -28683,12 +35367,44 @@ impl std::fmt::Display for PaymentMethodObject {
         match self {
             PaymentMethodObject::PaymentMethod => "payment_method",
             PaymentMethodObject::Noop => "",
-            PaymentMethodObject::FallthroughString => "*",
+            PaymentMethodObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "payment_method" => PaymentMethodObject::PaymentMethod,
+            "" => PaymentMethodObject::Noop,
+            _ => PaymentMethodObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodObject {
     fn default() -> PaymentMethodObject {
         PaymentMethodObject::Noop
@@ -28703,7 +35419,8 @@ impl PaymentMethodObject {
 /**
 * The type of the PaymentMethod. An additional hash is included on the PaymentMethod with a name matching this value. It contains additional information specific to the PaymentMethod type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodType {
     #[serde(rename = "acss_debit")]
     AcssDebit,
@@ -28755,8 +35472,9 @@ pub enum PaymentMethodType {
     WechatPay,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodType {
@@ -28787,12 +35505,67 @@ impl std::fmt::Display for PaymentMethodType {
             PaymentMethodType::UsBankAccount => "us_bank_account",
             PaymentMethodType::WechatPay => "wechat_pay",
             PaymentMethodType::Noop => "",
-            PaymentMethodType::FallthroughString => "*",
+            PaymentMethodType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "acss_debit" => PaymentMethodType::AcssDebit,
+            "afterpay_clearpay" => PaymentMethodType::AfterpayClearpay,
+            "alipay" => PaymentMethodType::Alipay,
+            "au_becs_debit" => PaymentMethodType::AuBecsDebit,
+            "bacs_debit" => PaymentMethodType::BacsDebit,
+            "bancontact" => PaymentMethodType::Bancontact,
+            "boleto" => PaymentMethodType::Boleto,
+            "card" => PaymentMethodType::Card,
+            "card_present" => PaymentMethodType::CardPresent,
+            "eps" => PaymentMethodType::Eps,
+            "fpx" => PaymentMethodType::Fpx,
+            "giropay" => PaymentMethodType::Giropay,
+            "grabpay" => PaymentMethodType::Grabpay,
+            "ideal" => PaymentMethodType::Ideal,
+            "interac_present" => PaymentMethodType::InteracPresent,
+            "klarna" => PaymentMethodType::Klarna,
+            "konbini" => PaymentMethodType::Konbini,
+            "oxxo" => PaymentMethodType::Oxxo,
+            "p24" => PaymentMethodType::P24,
+            "paynow" => PaymentMethodType::Paynow,
+            "sepa_debit" => PaymentMethodType::SepaDebit,
+            "sofort" => PaymentMethodType::Sofort,
+            "us_bank_account" => PaymentMethodType::UsBankAccount,
+            "wechat_pay" => PaymentMethodType::WechatPay,
+            "" => PaymentMethodType::Noop,
+            _ => PaymentMethodType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodType {
     fn default() -> PaymentMethodType {
         PaymentMethodType::Noop
@@ -29432,7 +36205,8 @@ pub struct PaymentMethodCardGenerated {
 /**
 * The type of the card wallet, one of `amex_express_checkout`, `apple_pay`, `google_pay`, `masterpass`, `samsung_pay`, or `visa_checkout`. An additional hash is included on the Wallet subhash with a name matching this value. It contains additional information specific to the card wallet type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodCardWalletType {
     #[serde(rename = "amex_express_checkout")]
     AmexExpressCheckout,
@@ -29448,8 +36222,9 @@ pub enum PaymentMethodCardWalletType {
     VisaCheckout,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodCardWalletType {
@@ -29462,12 +36237,49 @@ impl std::fmt::Display for PaymentMethodCardWalletType {
             PaymentMethodCardWalletType::SamsungPay => "samsung_pay",
             PaymentMethodCardWalletType::VisaCheckout => "visa_checkout",
             PaymentMethodCardWalletType::Noop => "",
-            PaymentMethodCardWalletType::FallthroughString => "*",
+            PaymentMethodCardWalletType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodCardWalletType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "amex_express_checkout" => PaymentMethodCardWalletType::AmexExpressCheckout,
+            "apple_pay" => PaymentMethodCardWalletType::ApplePay,
+            "google_pay" => PaymentMethodCardWalletType::GooglePay,
+            "masterpass" => PaymentMethodCardWalletType::Masterpass,
+            "samsung_pay" => PaymentMethodCardWalletType::SamsungPay,
+            "visa_checkout" => PaymentMethodCardWalletType::VisaCheckout,
+            "" => PaymentMethodCardWalletType::Noop,
+            _ => PaymentMethodCardWalletType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodCardWalletType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodCardWalletType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodCardWalletType {
     fn default() -> PaymentMethodCardWalletType {
         PaymentMethodCardWalletType::Noop
@@ -30284,14 +37096,16 @@ pub struct PaymentMethodDetailsCardInstallmentsData {
 * For `fixed_count` installment plans, this is the interval between installment payments your customer will make to their credit card.
 *   One of `month`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodDetailsCardInstallmentsPlanInterval {
     #[serde(rename = "month")]
     Month,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsCardInstallmentsPlanInterval {
@@ -30299,12 +37113,44 @@ impl std::fmt::Display for PaymentMethodDetailsCardInstallmentsPlanInterval {
         match self {
             PaymentMethodDetailsCardInstallmentsPlanInterval::Month => "month",
             PaymentMethodDetailsCardInstallmentsPlanInterval::Noop => "",
-            PaymentMethodDetailsCardInstallmentsPlanInterval::FallthroughString => "*",
+            PaymentMethodDetailsCardInstallmentsPlanInterval::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodDetailsCardInstallmentsPlanInterval {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "month" => PaymentMethodDetailsCardInstallmentsPlanInterval::Month,
+            "" => PaymentMethodDetailsCardInstallmentsPlanInterval::Noop,
+            _ => PaymentMethodDetailsCardInstallmentsPlanInterval::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodDetailsCardInstallmentsPlanInterval {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodDetailsCardInstallmentsPlanInterval {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodDetailsCardInstallmentsPlanInterval {
     fn default() -> PaymentMethodDetailsCardInstallmentsPlanInterval {
         PaymentMethodDetailsCardInstallmentsPlanInterval::Noop
@@ -30319,14 +37165,16 @@ impl PaymentMethodDetailsCardInstallmentsPlanInterval {
 /**
 * Type of installment plan, one of `fixed_count`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodDetailsCardInstallmentsPlanType {
     #[serde(rename = "fixed_count")]
     FixedCount,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsCardInstallmentsPlanType {
@@ -30334,12 +37182,44 @@ impl std::fmt::Display for PaymentMethodDetailsCardInstallmentsPlanType {
         match self {
             PaymentMethodDetailsCardInstallmentsPlanType::FixedCount => "fixed_count",
             PaymentMethodDetailsCardInstallmentsPlanType::Noop => "",
-            PaymentMethodDetailsCardInstallmentsPlanType::FallthroughString => "*",
+            PaymentMethodDetailsCardInstallmentsPlanType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodDetailsCardInstallmentsPlanType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "fixed_count" => PaymentMethodDetailsCardInstallmentsPlanType::FixedCount,
+            "" => PaymentMethodDetailsCardInstallmentsPlanType::Noop,
+            _ => PaymentMethodDetailsCardInstallmentsPlanType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodDetailsCardInstallmentsPlanType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodDetailsCardInstallmentsPlanType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodDetailsCardInstallmentsPlanType {
     fn default() -> PaymentMethodDetailsCardInstallmentsPlanType {
         PaymentMethodDetailsCardInstallmentsPlanType::Noop
@@ -30383,7 +37263,8 @@ pub struct PaymentMethodDetailsCardInstallmentsPlan {
 /**
 * How card details were read in this transaction.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReadMethod {
     #[serde(rename = "contact_emv")]
     ContactEmv,
@@ -30397,8 +37278,9 @@ pub enum ReadMethod {
     MagneticStripeTrack2,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReadMethod {
@@ -30410,12 +37292,48 @@ impl std::fmt::Display for ReadMethod {
             ReadMethod::MagneticStripeFallback => "magnetic_stripe_fallback",
             ReadMethod::MagneticStripeTrack2 => "magnetic_stripe_track2",
             ReadMethod::Noop => "",
-            ReadMethod::FallthroughString => "*",
+            ReadMethod::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReadMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "contact_emv" => ReadMethod::ContactEmv,
+            "contactless_emv" => ReadMethod::ContactlessEmv,
+            "contactless_magstripe_mode" => ReadMethod::ContactlessMagstripeMode,
+            "magnetic_stripe_fallback" => ReadMethod::MagneticStripeFallback,
+            "magnetic_stripe_track2" => ReadMethod::MagneticStripeTrack2,
+            "" => ReadMethod::Noop,
+            _ => ReadMethod::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReadMethod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReadMethod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReadMethod {
     fn default() -> ReadMethod {
         ReadMethod::Noop
@@ -30571,7 +37489,8 @@ pub struct PaymentMethodDetailsCardPresent {
 /**
 * The type of account being debited or credited
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AccountType {
     #[serde(rename = "checking")]
     Checking,
@@ -30583,8 +37502,9 @@ pub enum AccountType {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AccountType {
@@ -30595,12 +37515,47 @@ impl std::fmt::Display for AccountType {
             AccountType::Prepaid => "prepaid",
             AccountType::Unknown => "unknown",
             AccountType::Noop => "",
-            AccountType::FallthroughString => "*",
+            AccountType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AccountType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "checking" => AccountType::Checking,
+            "credit" => AccountType::Credit,
+            "prepaid" => AccountType::Prepaid,
+            "unknown" => AccountType::Unknown,
+            "" => AccountType::Noop,
+            _ => AccountType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AccountType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AccountType {
     fn default() -> AccountType {
         AccountType::Noop
@@ -30817,7 +37772,8 @@ pub struct PaymentMethodDetailsCardWalletVisaCheckout {
 /**
 * The customer's bank. Should be one of `arzte_und_apotheker_bank`, `austrian_anadi_bank_ag`, `bank_austria`, `bankhaus_carl_spangler`, `bankhaus_schelhammer_und_schattera_ag`, `bawag_psk_ag`, `bks_bank_ag`, `brull_kallmus_bank_ag`, `btv_vier_lander_bank`, `capital_bank_grawe_gruppe_ag`, `dolomitenbank`, `easybank_ag`, `erste_bank_und_sparkassen`, `hypo_alpeadriabank_international_ag`, `hypo_noe_lb_fur_niederosterreich_u_wien`, `hypo_oberosterreich_salzburg_steiermark`, `hypo_tirol_bank_ag`, `hypo_vorarlberg_bank_ag`, `hypo_bank_burgenland_aktiengesellschaft`, `marchfelder_bank`, `oberbank_ag`, `raiffeisen_bankengruppe_osterreich`, `schoellerbank_ag`, `sparda_bank_wien`, `volksbank_gruppe`, `volkskreditbank_ag`, or `vr_bank_braunau`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Bank {
     #[serde(rename = "arzte_und_apotheker_bank")]
     ArzteUndApothekerBank,
@@ -30875,8 +37831,9 @@ pub enum Bank {
     VrBankBraunau,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Bank {
@@ -30910,9 +37867,67 @@ impl std::fmt::Display for Bank {
             Bank::VolkskreditbankAg => "volkskreditbank_ag",
             Bank::VrBankBraunau => "vr_bank_braunau",
             Bank::Noop => "",
-            Bank::FallthroughString => "*",
-        }
-        .fmt(f)
+            Bank::UnknownValue(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl std::str::FromStr for Bank {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "arzte_und_apotheker_bank" => Bank::ArzteUndApothekerBank,
+            "austrian_anadi_bank_ag" => Bank::AustrianAnadiBankAg,
+            "bank_austria" => Bank::BankAustria,
+            "bankhaus_carl_spangler" => Bank::BankhausCarlSpangler,
+            "bankhaus_schelhammer_und_schattera_ag" => Bank::BankhausSchelhammerUndSchatteraAg,
+            "bawag_psk_ag" => Bank::BawagPskAg,
+            "bks_bank_ag" => Bank::BksBankAg,
+            "brull_kallmus_bank_ag" => Bank::BrullKallmusBankAg,
+            "btv_vier_lander_bank" => Bank::BtvVierLanderBank,
+            "capital_bank_grawe_gruppe_ag" => Bank::CapitalBankGraweGruppeAg,
+            "dolomitenbank" => Bank::Dolomitenbank,
+            "easybank_ag" => Bank::EasybankAg,
+            "erste_bank_und_sparkassen" => Bank::ErsteBankUndSparkassen,
+            "hypo_alpeadriabank_international_ag" => Bank::HypoAlpeadriabankInternationalAg,
+            "hypo_bank_burgenland_aktiengesellschaft" => Bank::HypoBankBurgenlandAktiengesellschaft,
+            "hypo_noe_lb_fur_niederosterreich_u_wien" => Bank::HypoNoeLbFurNiederosterreichUWien,
+            "hypo_oberosterreich_salzburg_steiermark" => Bank::HypoOberosterreichSalzburgSteiermark,
+            "hypo_tirol_bank_ag" => Bank::HypoTirolBankAg,
+            "hypo_vorarlberg_bank_ag" => Bank::HypoVorarlbergBankAg,
+            "marchfelder_bank" => Bank::MarchfelderBank,
+            "oberbank_ag" => Bank::OberbankAg,
+            "raiffeisen_bankengruppe_osterreich" => Bank::RaiffeisenBankengruppeOsterreich,
+            "schoellerbank_ag" => Bank::SchoellerbankAg,
+            "sparda_bank_wien" => Bank::SpardaBankWien,
+            "volksbank_gruppe" => Bank::VolksbankGruppe,
+            "volkskreditbank_ag" => Bank::VolkskreditbankAg,
+            "vr_bank_braunau" => Bank::VrBankBraunau,
+            "" => Bank::Noop,
+            _ => Bank::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Bank {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bank {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -30949,7 +37964,8 @@ pub struct PaymentMethodDetailsEps {
 /**
 * The customer's bank. Can be one of `affin_bank`, `agrobank`, `alliance_bank`, `ambank`, `bank_islam`, `bank_muamalat`, `bank_rakyat`, `bsn`, `cimb`, `hong_leong_bank`, `hsbc`, `kfh`, `maybank2u`, `ocbc`, `public_bank`, `rhb`, `standard_chartered`, `uob`, `deutsche_bank`, `maybank2e`, or `pb_enterprise`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodDetailsFpxBank {
     #[serde(rename = "affin_bank")]
     AffinBank,
@@ -30995,8 +38011,9 @@ pub enum PaymentMethodDetailsFpxBank {
     Uob,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsFpxBank {
@@ -31024,12 +38041,64 @@ impl std::fmt::Display for PaymentMethodDetailsFpxBank {
             PaymentMethodDetailsFpxBank::StandardChartered => "standard_chartered",
             PaymentMethodDetailsFpxBank::Uob => "uob",
             PaymentMethodDetailsFpxBank::Noop => "",
-            PaymentMethodDetailsFpxBank::FallthroughString => "*",
+            PaymentMethodDetailsFpxBank::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodDetailsFpxBank {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "affin_bank" => PaymentMethodDetailsFpxBank::AffinBank,
+            "agrobank" => PaymentMethodDetailsFpxBank::Agrobank,
+            "alliance_bank" => PaymentMethodDetailsFpxBank::AllianceBank,
+            "ambank" => PaymentMethodDetailsFpxBank::Ambank,
+            "bank_islam" => PaymentMethodDetailsFpxBank::BankIslam,
+            "bank_muamalat" => PaymentMethodDetailsFpxBank::BankMuamalat,
+            "bank_rakyat" => PaymentMethodDetailsFpxBank::BankRakyat,
+            "bsn" => PaymentMethodDetailsFpxBank::Bsn,
+            "cimb" => PaymentMethodDetailsFpxBank::Cimb,
+            "deutsche_bank" => PaymentMethodDetailsFpxBank::DeutscheBank,
+            "hong_leong_bank" => PaymentMethodDetailsFpxBank::HongLeongBank,
+            "hsbc" => PaymentMethodDetailsFpxBank::Hsbc,
+            "kfh" => PaymentMethodDetailsFpxBank::Kfh,
+            "maybank2e" => PaymentMethodDetailsFpxBank::Maybank2E,
+            "maybank2u" => PaymentMethodDetailsFpxBank::Maybank2U,
+            "ocbc" => PaymentMethodDetailsFpxBank::Ocbc,
+            "pb_enterprise" => PaymentMethodDetailsFpxBank::PbEnterprise,
+            "public_bank" => PaymentMethodDetailsFpxBank::PublicBank,
+            "rhb" => PaymentMethodDetailsFpxBank::Rhb,
+            "standard_chartered" => PaymentMethodDetailsFpxBank::StandardChartered,
+            "uob" => PaymentMethodDetailsFpxBank::Uob,
+            "" => PaymentMethodDetailsFpxBank::Noop,
+            _ => PaymentMethodDetailsFpxBank::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodDetailsFpxBank {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodDetailsFpxBank {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodDetailsFpxBank {
     fn default() -> PaymentMethodDetailsFpxBank {
         PaymentMethodDetailsFpxBank::Noop
@@ -31118,7 +38187,8 @@ pub struct PaymentMethodDetailsGrabpay {
 /**
 * The customer's bank. Can be one of `abn_amro`, `asn_bank`, `bunq`, `handelsbanken`, `ing`, `knab`, `moneyou`, `rabobank`, `regiobank`, `revolut`, `sns_bank`, `triodos_bank`, or `van_lanschot`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodDetailsIdealBank {
     #[serde(rename = "abn_amro")]
     AbnAmro,
@@ -31148,8 +38218,9 @@ pub enum PaymentMethodDetailsIdealBank {
     VanLanschot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsIdealBank {
@@ -31169,12 +38240,56 @@ impl std::fmt::Display for PaymentMethodDetailsIdealBank {
             PaymentMethodDetailsIdealBank::TriodosBank => "triodos_bank",
             PaymentMethodDetailsIdealBank::VanLanschot => "van_lanschot",
             PaymentMethodDetailsIdealBank::Noop => "",
-            PaymentMethodDetailsIdealBank::FallthroughString => "*",
+            PaymentMethodDetailsIdealBank::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodDetailsIdealBank {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "abn_amro" => PaymentMethodDetailsIdealBank::AbnAmro,
+            "asn_bank" => PaymentMethodDetailsIdealBank::AsnBank,
+            "bunq" => PaymentMethodDetailsIdealBank::Bunq,
+            "handelsbanken" => PaymentMethodDetailsIdealBank::Handelsbanken,
+            "ing" => PaymentMethodDetailsIdealBank::Ing,
+            "knab" => PaymentMethodDetailsIdealBank::Knab,
+            "moneyou" => PaymentMethodDetailsIdealBank::Moneyou,
+            "rabobank" => PaymentMethodDetailsIdealBank::Rabobank,
+            "regiobank" => PaymentMethodDetailsIdealBank::Regiobank,
+            "revolut" => PaymentMethodDetailsIdealBank::Revolut,
+            "sns_bank" => PaymentMethodDetailsIdealBank::SnsBank,
+            "triodos_bank" => PaymentMethodDetailsIdealBank::TriodosBank,
+            "van_lanschot" => PaymentMethodDetailsIdealBank::VanLanschot,
+            "" => PaymentMethodDetailsIdealBank::Noop,
+            _ => PaymentMethodDetailsIdealBank::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodDetailsIdealBank {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodDetailsIdealBank {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodDetailsIdealBank {
     fn default() -> PaymentMethodDetailsIdealBank {
         PaymentMethodDetailsIdealBank::Noop
@@ -31189,7 +38304,8 @@ impl PaymentMethodDetailsIdealBank {
 /**
 * The Bank Identifier Code of the customer's bank.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Bic {
     #[serde(rename = "ABNANL2A")]
     Abnanl2A,
@@ -31219,8 +38335,9 @@ pub enum Bic {
     Trionl2U,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Bic {
@@ -31240,12 +38357,56 @@ impl std::fmt::Display for Bic {
             Bic::Snsbnl2A => "SNSBNL2A",
             Bic::Trionl2U => "TRIONL2U",
             Bic::Noop => "",
-            Bic::FallthroughString => "*",
+            Bic::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Bic {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ABNANL2A" => Bic::Abnanl2A,
+            "ASNBNL21" => Bic::Asnbnl21,
+            "BUNQNL2A" => Bic::Bunqnl2A,
+            "FVLBNL22" => Bic::Fvlbnl22,
+            "HANDNL2A" => Bic::Handnl2A,
+            "INGBNL2A" => Bic::Ingbnl2A,
+            "KNABNL2H" => Bic::Knabnl2H,
+            "MOYONL21" => Bic::Moyonl21,
+            "RABONL2U" => Bic::Rabonl2U,
+            "RBRBNL21" => Bic::Rbrbnl21,
+            "REVOLT21" => Bic::Revolt21,
+            "SNSBNL2A" => Bic::Snsbnl2A,
+            "TRIONL2U" => Bic::Trionl2U,
+            "" => Bic::Noop,
+            _ => Bic::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Bic {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bic {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Bic {
     fn default() -> Bic {
         Bic::Noop
@@ -31428,7 +38589,8 @@ pub struct PaymentMethodDetailsInteracPresent {
 /**
 * The type of account being debited or credited
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodDetailsInteracPresentReceiptAccountType {
     #[serde(rename = "checking")]
     Checking,
@@ -31438,8 +38600,9 @@ pub enum PaymentMethodDetailsInteracPresentReceiptAccountType {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsInteracPresentReceiptAccountType {
@@ -31449,12 +38612,46 @@ impl std::fmt::Display for PaymentMethodDetailsInteracPresentReceiptAccountType
             PaymentMethodDetailsInteracPresentReceiptAccountType::Savings => "savings",
             PaymentMethodDetailsInteracPresentReceiptAccountType::Unknown => "unknown",
             PaymentMethodDetailsInteracPresentReceiptAccountType::Noop => "",
-            PaymentMethodDetailsInteracPresentReceiptAccountType::FallthroughString => "*",
+            PaymentMethodDetailsInteracPresentReceiptAccountType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodDetailsInteracPresentReceiptAccountType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "checking" => PaymentMethodDetailsInteracPresentReceiptAccountType::Checking,
+            "savings" => PaymentMethodDetailsInteracPresentReceiptAccountType::Savings,
+            "unknown" => PaymentMethodDetailsInteracPresentReceiptAccountType::Unknown,
+            "" => PaymentMethodDetailsInteracPresentReceiptAccountType::Noop,
+            _ => PaymentMethodDetailsInteracPresentReceiptAccountType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodDetailsInteracPresentReceiptAccountType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodDetailsInteracPresentReceiptAccountType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodDetailsInteracPresentReceiptAccountType {
     fn default() -> PaymentMethodDetailsInteracPresentReceiptAccountType {
         PaymentMethodDetailsInteracPresentReceiptAccountType::Noop
@@ -31587,7 +38784,8 @@ pub struct PaymentMethodDetailsKonbini {
 /**
 * The name of the convenience store chain where the payment was completed.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Chain {
     #[serde(rename = "familymart")]
     Familymart,
@@ -31599,8 +38797,9 @@ pub enum Chain {
     Seicomart,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Chain {
@@ -31611,12 +38810,47 @@ impl std::fmt::Display for Chain {
             Chain::Ministop => "ministop",
             Chain::Seicomart => "seicomart",
             Chain::Noop => "",
-            Chain::FallthroughString => "*",
+            Chain::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Chain {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "familymart" => Chain::Familymart,
+            "lawson" => Chain::Lawson,
+            "ministop" => Chain::Ministop,
+            "seicomart" => Chain::Seicomart,
+            "" => Chain::Noop,
+            _ => Chain::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Chain {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Chain {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Chain {
     fn default() -> Chain {
         Chain::Noop
@@ -31678,7 +38912,8 @@ pub struct PaymentMethodDetailsOxxo {
 /**
 * The customer's bank. Can be one of `ing`, `citi_handlowy`, `tmobile_usbugi_bankowe`, `plus_bank`, `etransfer_pocztowy24`, `banki_spbdzielcze`, `bank_nowy_bfg_sa`, `getin_bank`, `blik`, `noble_pay`, `ideabank`, `envelobank`, `santander_przelew24`, `nest_przelew`, `mbank_mtransfer`, `inteligo`, `pbac_z_ipko`, `bnp_paribas`, `credit_agricole`, `toyota_bank`, `bank_pekao_sa`, `volkswagen_bank`, `bank_millennium`, `alior_bank`, or `boz`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodDetailsP24Bank {
     #[serde(rename = "alior_bank")]
     AliorBank,
@@ -31732,8 +38967,9 @@ pub enum PaymentMethodDetailsP24Bank {
     VolkswagenBank,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsP24Bank {
@@ -31765,12 +39001,68 @@ impl std::fmt::Display for PaymentMethodDetailsP24Bank {
             PaymentMethodDetailsP24Bank::ToyotaBank => "toyota_bank",
             PaymentMethodDetailsP24Bank::VolkswagenBank => "volkswagen_bank",
             PaymentMethodDetailsP24Bank::Noop => "",
-            PaymentMethodDetailsP24Bank::FallthroughString => "*",
+            PaymentMethodDetailsP24Bank::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodDetailsP24Bank {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "alior_bank" => PaymentMethodDetailsP24Bank::AliorBank,
+            "bank_millennium" => PaymentMethodDetailsP24Bank::BankMillennium,
+            "bank_nowy_bfg_sa" => PaymentMethodDetailsP24Bank::BankNowyBfgSa,
+            "bank_pekao_sa" => PaymentMethodDetailsP24Bank::BankPekaoSa,
+            "banki_spbdzielcze" => PaymentMethodDetailsP24Bank::BankiSpbdzielcze,
+            "blik" => PaymentMethodDetailsP24Bank::Blik,
+            "bnp_paribas" => PaymentMethodDetailsP24Bank::BnpParibas,
+            "boz" => PaymentMethodDetailsP24Bank::Boz,
+            "citi_handlowy" => PaymentMethodDetailsP24Bank::CitiHandlowy,
+            "credit_agricole" => PaymentMethodDetailsP24Bank::CreditAgricole,
+            "envelobank" => PaymentMethodDetailsP24Bank::Envelobank,
+            "etransfer_pocztowy24" => PaymentMethodDetailsP24Bank::EtransferPocztowy24,
+            "getin_bank" => PaymentMethodDetailsP24Bank::GetinBank,
+            "ideabank" => PaymentMethodDetailsP24Bank::Ideabank,
+            "ing" => PaymentMethodDetailsP24Bank::Ing,
+            "inteligo" => PaymentMethodDetailsP24Bank::Inteligo,
+            "mbank_mtransfer" => PaymentMethodDetailsP24Bank::MbankMtransfer,
+            "nest_przelew" => PaymentMethodDetailsP24Bank::NestPrzelew,
+            "noble_pay" => PaymentMethodDetailsP24Bank::NoblePay,
+            "pbac_z_ipko" => PaymentMethodDetailsP24Bank::PbacZIpko,
+            "plus_bank" => PaymentMethodDetailsP24Bank::PlusBank,
+            "santander_przelew24" => PaymentMethodDetailsP24Bank::SantanderPrzelew24,
+            "tmobile_usbugi_bankowe" => PaymentMethodDetailsP24Bank::TmobileUsbugiBankowe,
+            "toyota_bank" => PaymentMethodDetailsP24Bank::ToyotaBank,
+            "volkswagen_bank" => PaymentMethodDetailsP24Bank::VolkswagenBank,
+            "" => PaymentMethodDetailsP24Bank::Noop,
+            _ => PaymentMethodDetailsP24Bank::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodDetailsP24Bank {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodDetailsP24Bank {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodDetailsP24Bank {
     fn default() -> PaymentMethodDetailsP24Bank {
         PaymentMethodDetailsP24Bank::Noop
@@ -31888,7 +39180,8 @@ pub struct PaymentMethodDetailsSepaDebit {
 * Preferred language of the SOFORT authorization page that the customer is redirected to.
 *   Can be one of `de`, `en`, `es`, `fr`, `it`, `nl`, or `pl`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodDetailsSofortPreferredLanguage {
     #[serde(rename = "de")]
     De,
@@ -31906,8 +39199,9 @@ pub enum PaymentMethodDetailsSofortPreferredLanguage {
     Pl,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsSofortPreferredLanguage {
@@ -31921,12 +39215,50 @@ impl std::fmt::Display for PaymentMethodDetailsSofortPreferredLanguage {
             PaymentMethodDetailsSofortPreferredLanguage::Nl => "nl",
             PaymentMethodDetailsSofortPreferredLanguage::Pl => "pl",
             PaymentMethodDetailsSofortPreferredLanguage::Noop => "",
-            PaymentMethodDetailsSofortPreferredLanguage::FallthroughString => "*",
+            PaymentMethodDetailsSofortPreferredLanguage::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodDetailsSofortPreferredLanguage {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "de" => PaymentMethodDetailsSofortPreferredLanguage::De,
+            "en" => PaymentMethodDetailsSofortPreferredLanguage::En,
+            "es" => PaymentMethodDetailsSofortPreferredLanguage::Es,
+            "fr" => PaymentMethodDetailsSofortPreferredLanguage::Fr,
+            "it" => PaymentMethodDetailsSofortPreferredLanguage::It,
+            "nl" => PaymentMethodDetailsSofortPreferredLanguage::Nl,
+            "pl" => PaymentMethodDetailsSofortPreferredLanguage::Pl,
+            "" => PaymentMethodDetailsSofortPreferredLanguage::Noop,
+            _ => PaymentMethodDetailsSofortPreferredLanguage::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodDetailsSofortPreferredLanguage {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodDetailsSofortPreferredLanguage {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodDetailsSofortPreferredLanguage {
     fn default() -> PaymentMethodDetailsSofortPreferredLanguage {
         PaymentMethodDetailsSofortPreferredLanguage::Noop
@@ -32017,7 +39349,8 @@ pub struct PaymentMethodDetailsSofort {
 /**
 * Account type: checkings or savings. Defaults to checking if omitted.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodDetailsUsBankAccountType {
     #[serde(rename = "checking")]
     Checking,
@@ -32025,8 +39358,9 @@ pub enum PaymentMethodDetailsUsBankAccountType {
     Savings,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsUsBankAccountType {
@@ -32035,12 +39369,45 @@ impl std::fmt::Display for PaymentMethodDetailsUsBankAccountType {
             PaymentMethodDetailsUsBankAccountType::Checking => "checking",
             PaymentMethodDetailsUsBankAccountType::Savings => "savings",
             PaymentMethodDetailsUsBankAccountType::Noop => "",
-            PaymentMethodDetailsUsBankAccountType::FallthroughString => "*",
+            PaymentMethodDetailsUsBankAccountType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodDetailsUsBankAccountType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "checking" => PaymentMethodDetailsUsBankAccountType::Checking,
+            "savings" => PaymentMethodDetailsUsBankAccountType::Savings,
+            "" => PaymentMethodDetailsUsBankAccountType::Noop,
+            _ => PaymentMethodDetailsUsBankAccountType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodDetailsUsBankAccountType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodDetailsUsBankAccountType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodDetailsUsBankAccountType {
     fn default() -> PaymentMethodDetailsUsBankAccountType {
         PaymentMethodDetailsUsBankAccountType::Noop
@@ -32203,7 +39570,8 @@ pub struct PaymentMethodOptionsAfterpayClearpay {
 *   
 *   When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodOptionsAlipaySetupFutureUsage {
     #[serde(rename = "none")]
     None,
@@ -32211,8 +39579,9 @@ pub enum PaymentMethodOptionsAlipaySetupFutureUsage {
     OffSession,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodOptionsAlipaySetupFutureUsage {
@@ -32221,12 +39590,45 @@ impl std::fmt::Display for PaymentMethodOptionsAlipaySetupFutureUsage {
             PaymentMethodOptionsAlipaySetupFutureUsage::None => "none",
             PaymentMethodOptionsAlipaySetupFutureUsage::OffSession => "off_session",
             PaymentMethodOptionsAlipaySetupFutureUsage::Noop => "",
-            PaymentMethodOptionsAlipaySetupFutureUsage::FallthroughString => "*",
+            PaymentMethodOptionsAlipaySetupFutureUsage::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodOptionsAlipaySetupFutureUsage {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "none" => PaymentMethodOptionsAlipaySetupFutureUsage::None,
+            "off_session" => PaymentMethodOptionsAlipaySetupFutureUsage::OffSession,
+            "" => PaymentMethodOptionsAlipaySetupFutureUsage::Noop,
+            _ => PaymentMethodOptionsAlipaySetupFutureUsage::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodOptionsAlipaySetupFutureUsage {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodOptionsAlipaySetupFutureUsage {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodOptionsAlipaySetupFutureUsage {
     fn default() -> PaymentMethodOptionsAlipaySetupFutureUsage {
         PaymentMethodOptionsAlipaySetupFutureUsage::Noop
@@ -32322,7 +39724,8 @@ pub struct PaymentMethodOptionsCardInstallments {
 /**
 * Specifies payment frequency. One of `day`, `week`, `month`, `year`, or `sporadic`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentMethodOptionsCardMandateInterval {
     #[serde(rename = "day")]
     Day,
@@ -32336,8 +39739,9 @@ pub enum PaymentMethodOptionsCardMandateInterval {
     Year,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentMethodOptionsCardMandateInterval {
@@ -32349,12 +39753,48 @@ impl std::fmt::Display for PaymentMethodOptionsCardMandateInterval {
             PaymentMethodOptionsCardMandateInterval::Week => "week",
             PaymentMethodOptionsCardMandateInterval::Year => "year",
             PaymentMethodOptionsCardMandateInterval::Noop => "",
-            PaymentMethodOptionsCardMandateInterval::FallthroughString => "*",
+            PaymentMethodOptionsCardMandateInterval::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentMethodOptionsCardMandateInterval {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "day" => PaymentMethodOptionsCardMandateInterval::Day,
+            "month" => PaymentMethodOptionsCardMandateInterval::Month,
+            "sporadic" => PaymentMethodOptionsCardMandateInterval::Sporadic,
+            "week" => PaymentMethodOptionsCardMandateInterval::Week,
+            "year" => PaymentMethodOptionsCardMandateInterval::Year,
+            "" => PaymentMethodOptionsCardMandateInterval::Noop,
+            _ => PaymentMethodOptionsCardMandateInterval::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentMethodOptionsCardMandateInterval {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodOptionsCardMandateInterval {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentMethodOptionsCardMandateInterval {
     fn default() -> PaymentMethodOptionsCardMandateInterval {
         PaymentMethodOptionsCardMandateInterval::Noop
@@ -32366,14 +39806,16 @@ impl PaymentMethodOptionsCardMandateInterval {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SupportedTypes {
     #[serde(rename = "india")]
     India,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SupportedTypes {
@@ -32381,12 +39823,44 @@ impl std::fmt::Display for SupportedTypes {
         match self {
             SupportedTypes::India => "india",
             SupportedTypes::Noop => "",
-            SupportedTypes::FallthroughString => "*",
+            SupportedTypes::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SupportedTypes {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "india" => SupportedTypes::India,
+            "" => SupportedTypes::Noop,
+            _ => SupportedTypes::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SupportedTypes {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SupportedTypes {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SupportedTypes {
     fn default() -> SupportedTypes {
         SupportedTypes::Noop
@@ -32660,7 +40134,8 @@ pub struct PaymentMethodOptionsSofort {
 /**
 * The client type that the end customer will pay from
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Client {
     #[serde(rename = "android")]
     Android,
@@ -32670,8 +40145,9 @@ pub enum Client {
     Web,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Client {
@@ -32681,12 +40157,46 @@ impl std::fmt::Display for Client {
             Client::Ios => "ios",
             Client::Web => "web",
             Client::Noop => "",
-            Client::FallthroughString => "*",
+            Client::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Client {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "android" => Client::Android,
+            "ios" => Client::Ios,
+            "web" => Client::Web,
+            "" => Client::Noop,
+            _ => Client::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Client {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Client {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Client {
     fn default() -> Client {
         Client::Noop
@@ -32907,7 +40417,8 @@ pub struct PaymentPagesCheckoutSessionAfterExpirationRecovery {
 * If `opt_in`, the customer consents to receiving promotional communications
 *   from the merchant about this Checkout Session.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Promotions {
     #[serde(rename = "opt_in")]
     OptIn,
@@ -32915,8 +40426,9 @@ pub enum Promotions {
     OptOut,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Promotions {
@@ -32925,12 +40437,45 @@ impl std::fmt::Display for Promotions {
             Promotions::OptIn => "opt_in",
             Promotions::OptOut => "opt_out",
             Promotions::Noop => "",
-            Promotions::FallthroughString => "*",
+            Promotions::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Promotions {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "opt_in" => Promotions::OptIn,
+            "opt_out" => Promotions::OptOut,
+            "" => Promotions::Noop,
+            _ => Promotions::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Promotions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Promotions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Promotions {
     fn default() -> Promotions {
         Promotions::Noop
@@ -32958,14 +40503,16 @@ pub struct PaymentPagesCheckoutSessionConsent {
 *   Session will determine whether to display an option to opt into promotional communication
 *   from the merchant depending on the customer's locale. Only available to US merchants.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentPagesCheckoutSessionConsentCollectionPromotions {
     #[serde(rename = "auto")]
     Auto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentPagesCheckoutSessionConsentCollectionPromotions {
@@ -32973,12 +40520,46 @@ impl std::fmt::Display for PaymentPagesCheckoutSessionConsentCollectionPromotion
         match self {
             PaymentPagesCheckoutSessionConsentCollectionPromotions::Auto => "auto",
             PaymentPagesCheckoutSessionConsentCollectionPromotions::Noop => "",
-            PaymentPagesCheckoutSessionConsentCollectionPromotions::FallthroughString => "*",
+            PaymentPagesCheckoutSessionConsentCollectionPromotions::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentPagesCheckoutSessionConsentCollectionPromotions {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "auto" => PaymentPagesCheckoutSessionConsentCollectionPromotions::Auto,
+            "" => PaymentPagesCheckoutSessionConsentCollectionPromotions::Noop,
+            _ => {
+                PaymentPagesCheckoutSessionConsentCollectionPromotions::UnknownValue(s.to_string())
+            }
+        })
+    }
+}
+
+impl Serialize for PaymentPagesCheckoutSessionConsentCollectionPromotions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentPagesCheckoutSessionConsentCollectionPromotions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentPagesCheckoutSessionConsentCollectionPromotions {
     fn default() -> PaymentPagesCheckoutSessionConsentCollectionPromotions {
         PaymentPagesCheckoutSessionConsentCollectionPromotions::Noop
@@ -33356,14 +40937,16 @@ impl std::convert::From<DestinationAnyOf> for String {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PayoutObject {
     #[serde(rename = "payout")]
     Payout,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PayoutObject {
@@ -33371,12 +40954,44 @@ impl std::fmt::Display for PayoutObject {
         match self {
             PayoutObject::Payout => "payout",
             PayoutObject::Noop => "",
-            PayoutObject::FallthroughString => "*",
+            PayoutObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PayoutObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "payout" => PayoutObject::Payout,
+            "" => PayoutObject::Noop,
+            _ => PayoutObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PayoutObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PayoutObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PayoutObject {
     fn default() -> PayoutObject {
         PayoutObject::Noop
@@ -33446,7 +41061,8 @@ impl std::convert::From<ReversedByAnyOf> for String {
 /**
 * Can be `bank_account` or `card`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PayoutType {
     #[serde(rename = "bank_account")]
     BankAccount,
@@ -33454,8 +41070,9 @@ pub enum PayoutType {
     Card,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PayoutType {
@@ -33464,12 +41081,45 @@ impl std::fmt::Display for PayoutType {
             PayoutType::BankAccount => "bank_account",
             PayoutType::Card => "card",
             PayoutType::Noop => "",
-            PayoutType::FallthroughString => "*",
+            PayoutType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PayoutType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "bank_account" => PayoutType::BankAccount,
+            "card" => PayoutType::Card,
+            "" => PayoutType::Noop,
+            _ => PayoutType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PayoutType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PayoutType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PayoutType {
     fn default() -> PayoutType {
         PayoutType::Noop
@@ -33757,7 +41407,8 @@ pub struct Period {
 /**
 * Indicates if the person or any of their representatives, family members, or other closely related persons, declares that they hold or have held an important public job or function, in any jurisdiction.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PoliticalExposure {
     #[serde(rename = "existing")]
     Existing,
@@ -33765,8 +41416,9 @@ pub enum PoliticalExposure {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PoliticalExposure {
@@ -33775,12 +41427,45 @@ impl std::fmt::Display for PoliticalExposure {
             PoliticalExposure::Existing => "existing",
             PoliticalExposure::None => "none",
             PoliticalExposure::Noop => "",
-            PoliticalExposure::FallthroughString => "*",
+            PoliticalExposure::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PoliticalExposure {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "existing" => PoliticalExposure::Existing,
+            "none" => PoliticalExposure::None,
+            "" => PoliticalExposure::Noop,
+            _ => PoliticalExposure::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PoliticalExposure {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PoliticalExposure {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PoliticalExposure {
     fn default() -> PoliticalExposure {
         PoliticalExposure::Noop
@@ -34223,7 +41908,8 @@ pub struct PersonRelationship {
 /**
 * Specifies a usage aggregation strategy for plans of `usage_type=metered`. Allowed values are `sum` for summing up all usage during a period, `last_during_period` for using the last usage record reported within a period, `last_ever` for using the last usage record ever (across period bounds) or `max` which uses the usage record with the maximum reported usage during a period. Defaults to `sum`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AggregateUsage {
     #[serde(rename = "last_during_period")]
     LastDuringPeriod,
@@ -34235,8 +41921,9 @@ pub enum AggregateUsage {
     Sum,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AggregateUsage {
@@ -34247,12 +41934,47 @@ impl std::fmt::Display for AggregateUsage {
             AggregateUsage::Max => "max",
             AggregateUsage::Sum => "sum",
             AggregateUsage::Noop => "",
-            AggregateUsage::FallthroughString => "*",
+            AggregateUsage::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AggregateUsage {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "last_during_period" => AggregateUsage::LastDuringPeriod,
+            "last_ever" => AggregateUsage::LastEver,
+            "max" => AggregateUsage::Max,
+            "sum" => AggregateUsage::Sum,
+            "" => AggregateUsage::Noop,
+            _ => AggregateUsage::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AggregateUsage {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AggregateUsage {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AggregateUsage {
     fn default() -> AggregateUsage {
         AggregateUsage::Noop
@@ -34267,7 +41989,8 @@ impl AggregateUsage {
 /**
 * Describes how to compute the price per period. Either `per_unit` or `tiered`. `per_unit` indicates that the fixed amount (specified in `amount`) will be charged per unit in `quantity` (for plans with `usage_type=licensed`), or per unit of total usage (for plans with `usage_type=metered`). `tiered` indicates that the unit pricing will be computed using a tiering strategy as defined using the `tiers` and `tiers_mode` attributes.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BillingScheme {
     #[serde(rename = "per_unit")]
     PerUnit,
@@ -34275,8 +41998,9 @@ pub enum BillingScheme {
     Tiered,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BillingScheme {
@@ -34285,12 +42009,45 @@ impl std::fmt::Display for BillingScheme {
             BillingScheme::PerUnit => "per_unit",
             BillingScheme::Tiered => "tiered",
             BillingScheme::Noop => "",
-            BillingScheme::FallthroughString => "*",
+            BillingScheme::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BillingScheme {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "per_unit" => BillingScheme::PerUnit,
+            "tiered" => BillingScheme::Tiered,
+            "" => BillingScheme::Noop,
+            _ => BillingScheme::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BillingScheme {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BillingScheme {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BillingScheme {
     fn default() -> BillingScheme {
         BillingScheme::Noop
@@ -34305,7 +42062,8 @@ impl BillingScheme {
 /**
 * The frequency at which a subscription is billed. One of `day`, `week`, `month` or `year`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PlanInterval {
     #[serde(rename = "day")]
     Day,
@@ -34317,8 +42075,9 @@ pub enum PlanInterval {
     Year,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PlanInterval {
@@ -34329,12 +42088,47 @@ impl std::fmt::Display for PlanInterval {
             PlanInterval::Week => "week",
             PlanInterval::Year => "year",
             PlanInterval::Noop => "",
-            PlanInterval::FallthroughString => "*",
+            PlanInterval::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PlanInterval {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "day" => PlanInterval::Day,
+            "month" => PlanInterval::Month,
+            "week" => PlanInterval::Week,
+            "year" => PlanInterval::Year,
+            "" => PlanInterval::Noop,
+            _ => PlanInterval::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PlanInterval {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PlanInterval {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PlanInterval {
     fn default() -> PlanInterval {
         PlanInterval::Noop
@@ -34416,7 +42210,8 @@ impl std::convert::From<ProductAnyOf> for String {
 /**
 * Defines if the tiering price should be `graduated` or `volume` based. In `volume`-based tiering, the maximum quantity within a period determines the per unit price. In `graduated` tiering, pricing can change as the quantity grows.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TiersMode {
     #[serde(rename = "graduated")]
     Graduated,
@@ -34424,8 +42219,9 @@ pub enum TiersMode {
     Volume,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TiersMode {
@@ -34434,12 +42230,45 @@ impl std::fmt::Display for TiersMode {
             TiersMode::Graduated => "graduated",
             TiersMode::Volume => "volume",
             TiersMode::Noop => "",
-            TiersMode::FallthroughString => "*",
+            TiersMode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TiersMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "graduated" => TiersMode::Graduated,
+            "volume" => TiersMode::Volume,
+            "" => TiersMode::Noop,
+            _ => TiersMode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TiersMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TiersMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TiersMode {
     fn default() -> TiersMode {
         TiersMode::Noop
@@ -34454,7 +42283,8 @@ impl TiersMode {
 /**
 * Configures how the quantity per period should be determined. Can be either `metered` or `licensed`. `licensed` automatically bills the `quantity` set when adding it to a subscription. `metered` aggregates the total usage based on usage records. Defaults to `licensed`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UsageType {
     #[serde(rename = "licensed")]
     Licensed,
@@ -34462,8 +42292,9 @@ pub enum UsageType {
     Metered,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UsageType {
@@ -34472,12 +42303,45 @@ impl std::fmt::Display for UsageType {
             UsageType::Licensed => "licensed",
             UsageType::Metered => "metered",
             UsageType::Noop => "",
-            UsageType::FallthroughString => "*",
+            UsageType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UsageType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "licensed" => UsageType::Licensed,
+            "metered" => UsageType::Metered,
+            "" => UsageType::Noop,
+            _ => UsageType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UsageType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UsageType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UsageType {
     fn default() -> UsageType {
         UsageType::Noop
@@ -34742,14 +42606,16 @@ pub struct PlanTier {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PlatformTaxObject {
     #[serde(rename = "platform_tax_fee")]
     PlatformTaxFee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PlatformTaxObject {
@@ -34757,12 +42623,44 @@ impl std::fmt::Display for PlatformTaxObject {
         match self {
             PlatformTaxObject::PlatformTaxFee => "platform_tax_fee",
             PlatformTaxObject::Noop => "",
-            PlatformTaxObject::FallthroughString => "*",
+            PlatformTaxObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PlatformTaxObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "platform_tax_fee" => PlatformTaxObject::PlatformTaxFee,
+            "" => PlatformTaxObject::Noop,
+            _ => PlatformTaxObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PlatformTaxObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PlatformTaxObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PlatformTaxObject {
     fn default() -> PlatformTaxObject {
         PlatformTaxObject::Noop
@@ -34853,7 +42751,8 @@ pub struct PortalBusinessProfile {
     pub terms_of_service_url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AllowedUpdates {
     #[serde(rename = "address")]
     Address,
@@ -34867,8 +42766,9 @@ pub enum AllowedUpdates {
     TaxId,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AllowedUpdates {
@@ -34880,12 +42780,48 @@ impl std::fmt::Display for AllowedUpdates {
             AllowedUpdates::Shipping => "shipping",
             AllowedUpdates::TaxId => "tax_id",
             AllowedUpdates::Noop => "",
-            AllowedUpdates::FallthroughString => "*",
+            AllowedUpdates::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AllowedUpdates {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "address" => AllowedUpdates::Address,
+            "email" => AllowedUpdates::Email,
+            "phone" => AllowedUpdates::Phone,
+            "shipping" => AllowedUpdates::Shipping,
+            "tax_id" => AllowedUpdates::TaxId,
+            "" => AllowedUpdates::Noop,
+            _ => AllowedUpdates::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AllowedUpdates {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AllowedUpdates {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AllowedUpdates {
     fn default() -> AllowedUpdates {
         AllowedUpdates::Noop
@@ -34957,7 +42893,8 @@ pub struct PortalFeatures {
 /**
 * Whether to cancel subscriptions immediately or at the end of the billing period.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PortalSubscriptionCancelMode {
     #[serde(rename = "at_period_end")]
     AtPeriodEnd,
@@ -34965,8 +42902,9 @@ pub enum PortalSubscriptionCancelMode {
     Immediately,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PortalSubscriptionCancelMode {
@@ -34975,12 +42913,45 @@ impl std::fmt::Display for PortalSubscriptionCancelMode {
             PortalSubscriptionCancelMode::AtPeriodEnd => "at_period_end",
             PortalSubscriptionCancelMode::Immediately => "immediately",
             PortalSubscriptionCancelMode::Noop => "",
-            PortalSubscriptionCancelMode::FallthroughString => "*",
+            PortalSubscriptionCancelMode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PortalSubscriptionCancelMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "at_period_end" => PortalSubscriptionCancelMode::AtPeriodEnd,
+            "immediately" => PortalSubscriptionCancelMode::Immediately,
+            "" => PortalSubscriptionCancelMode::Noop,
+            _ => PortalSubscriptionCancelMode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PortalSubscriptionCancelMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PortalSubscriptionCancelMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PortalSubscriptionCancelMode {
     fn default() -> PortalSubscriptionCancelMode {
         PortalSubscriptionCancelMode::Noop
@@ -34995,7 +42966,8 @@ impl PortalSubscriptionCancelMode {
 /**
 * Whether to create prorations when canceling subscriptions. Possible values are `none` and `create_prorations`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ProrationBehavior {
     #[serde(rename = "always_invoice")]
     AlwaysInvoice,
@@ -35005,8 +42977,9 @@ pub enum ProrationBehavior {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ProrationBehavior {
@@ -35016,12 +42989,46 @@ impl std::fmt::Display for ProrationBehavior {
             ProrationBehavior::CreateProrations => "create_prorations",
             ProrationBehavior::None => "none",
             ProrationBehavior::Noop => "",
-            ProrationBehavior::FallthroughString => "*",
+            ProrationBehavior::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ProrationBehavior {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "always_invoice" => ProrationBehavior::AlwaysInvoice,
+            "create_prorations" => ProrationBehavior::CreateProrations,
+            "none" => ProrationBehavior::None,
+            "" => ProrationBehavior::Noop,
+            _ => ProrationBehavior::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ProrationBehavior {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProrationBehavior {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ProrationBehavior {
     fn default() -> ProrationBehavior {
         ProrationBehavior::Noop
@@ -35061,7 +43068,8 @@ pub struct PortalSubscriptionCancel {
     pub proration_behavior: ProrationBehavior,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Options {
     #[serde(rename = "customer_service")]
     CustomerService,
@@ -35081,8 +43089,9 @@ pub enum Options {
     Unused,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Options {
@@ -35097,12 +43106,51 @@ impl std::fmt::Display for Options {
             Options::TooExpensive => "too_expensive",
             Options::Unused => "unused",
             Options::Noop => "",
-            Options::FallthroughString => "*",
+            Options::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Options {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "customer_service" => Options::CustomerService,
+            "low_quality" => Options::LowQuality,
+            "missing_features" => Options::MissingFeatures,
+            "other" => Options::Other,
+            "switched_service" => Options::SwitchedService,
+            "too_complex" => Options::TooComplex,
+            "too_expensive" => Options::TooExpensive,
+            "unused" => Options::Unused,
+            "" => Options::Noop,
+            _ => Options::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Options {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Options {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Options {
     fn default() -> Options {
         Options::Noop
@@ -35136,7 +43184,8 @@ pub struct PortalSubscriptionCancellationReason {
     pub options: Vec<Options>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DefaultAllowedUpdates {
     #[serde(rename = "price")]
     Price,
@@ -35146,8 +43195,9 @@ pub enum DefaultAllowedUpdates {
     Quantity,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DefaultAllowedUpdates {
@@ -35157,12 +43207,46 @@ impl std::fmt::Display for DefaultAllowedUpdates {
             DefaultAllowedUpdates::PromotionCode => "promotion_code",
             DefaultAllowedUpdates::Quantity => "quantity",
             DefaultAllowedUpdates::Noop => "",
-            DefaultAllowedUpdates::FallthroughString => "*",
+            DefaultAllowedUpdates::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DefaultAllowedUpdates {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "price" => DefaultAllowedUpdates::Price,
+            "promotion_code" => DefaultAllowedUpdates::PromotionCode,
+            "quantity" => DefaultAllowedUpdates::Quantity,
+            "" => DefaultAllowedUpdates::Noop,
+            _ => DefaultAllowedUpdates::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DefaultAllowedUpdates {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DefaultAllowedUpdates {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DefaultAllowedUpdates {
     fn default() -> DefaultAllowedUpdates {
         DefaultAllowedUpdates::Noop
@@ -35236,7 +43320,8 @@ pub struct PortalSubscriptionUpdateProduct {
 /**
 * Specifies whether the price is considered inclusive of taxes or exclusive of taxes. One of `inclusive`, `exclusive`, or `unspecified`. Once specified as either `inclusive` or `exclusive`, it cannot be changed.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TaxBehavior {
     #[serde(rename = "exclusive")]
     Exclusive,
@@ -35246,8 +43331,9 @@ pub enum TaxBehavior {
     Unspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TaxBehavior {
@@ -35257,12 +43343,46 @@ impl std::fmt::Display for TaxBehavior {
             TaxBehavior::Inclusive => "inclusive",
             TaxBehavior::Unspecified => "unspecified",
             TaxBehavior::Noop => "",
-            TaxBehavior::FallthroughString => "*",
+            TaxBehavior::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TaxBehavior {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "exclusive" => TaxBehavior::Exclusive,
+            "inclusive" => TaxBehavior::Inclusive,
+            "unspecified" => TaxBehavior::Unspecified,
+            "" => TaxBehavior::Noop,
+            _ => TaxBehavior::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TaxBehavior {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TaxBehavior {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TaxBehavior {
     fn default() -> TaxBehavior {
         TaxBehavior::Noop
@@ -35277,7 +43397,8 @@ impl TaxBehavior {
 /**
 * One of `one_time` or `recurring` depending on whether the price is for a one-time purchase or a recurring (subscription) purchase.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PriceType {
     #[serde(rename = "one_time")]
     OneTime,
@@ -35285,8 +43406,9 @@ pub enum PriceType {
     Recurring,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PriceType {
@@ -35295,12 +43417,45 @@ impl std::fmt::Display for PriceType {
             PriceType::OneTime => "one_time",
             PriceType::Recurring => "recurring",
             PriceType::Noop => "",
-            PriceType::FallthroughString => "*",
+            PriceType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PriceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "one_time" => PriceType::OneTime,
+            "recurring" => PriceType::Recurring,
+            "" => PriceType::Noop,
+            _ => PriceType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PriceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PriceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PriceType {
     fn default() -> PriceType {
         PriceType::Noop
@@ -35760,14 +43915,16 @@ pub struct Product {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PromotionCodeObject {
     #[serde(rename = "promotion_code")]
     PromotionCode,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PromotionCodeObject {
@@ -35775,12 +43932,44 @@ impl std::fmt::Display for PromotionCodeObject {
         match self {
             PromotionCodeObject::PromotionCode => "promotion_code",
             PromotionCodeObject::Noop => "",
-            PromotionCodeObject::FallthroughString => "*",
+            PromotionCodeObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PromotionCodeObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "promotion_code" => PromotionCodeObject::PromotionCode,
+            "" => PromotionCodeObject::Noop,
+            _ => PromotionCodeObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PromotionCodeObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PromotionCodeObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PromotionCodeObject {
     fn default() -> PromotionCodeObject {
         PromotionCodeObject::Noop
@@ -36028,14 +44217,16 @@ impl std::convert::From<QuoteInvoiceAnyOf> for String {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum QuoteObject {
     #[serde(rename = "quote")]
     Quote,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for QuoteObject {
@@ -36043,12 +44234,44 @@ impl std::fmt::Display for QuoteObject {
         match self {
             QuoteObject::Quote => "quote",
             QuoteObject::Noop => "",
-            QuoteObject::FallthroughString => "*",
+            QuoteObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for QuoteObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "quote" => QuoteObject::Quote,
+            "" => QuoteObject::Noop,
+            _ => QuoteObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for QuoteObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for QuoteObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for QuoteObject {
     fn default() -> QuoteObject {
         QuoteObject::Noop
@@ -36063,7 +44286,8 @@ impl QuoteObject {
 /**
 * The status of the quote.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum QuoteStatus {
     #[serde(rename = "accepted")]
     Accepted,
@@ -36075,8 +44299,9 @@ pub enum QuoteStatus {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for QuoteStatus {
@@ -36087,12 +44312,47 @@ impl std::fmt::Display for QuoteStatus {
             QuoteStatus::Draft => "draft",
             QuoteStatus::Open => "open",
             QuoteStatus::Noop => "",
-            QuoteStatus::FallthroughString => "*",
+            QuoteStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for QuoteStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "accepted" => QuoteStatus::Accepted,
+            "canceled" => QuoteStatus::Canceled,
+            "draft" => QuoteStatus::Draft,
+            "open" => QuoteStatus::Open,
+            "" => QuoteStatus::Noop,
+            _ => QuoteStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for QuoteStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for QuoteStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for QuoteStatus {
     fn default() -> QuoteStatus {
         QuoteStatus::Noop
@@ -36624,14 +44884,16 @@ pub struct QuotesResourceUpfront {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RadarEarlyFraudWarningObject {
     #[serde(rename = "radar.early_fraud_warning")]
     RadarEarlyFraudWarning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RadarEarlyFraudWarningObject {
@@ -36639,12 +44901,44 @@ impl std::fmt::Display for RadarEarlyFraudWarningObject {
         match self {
             RadarEarlyFraudWarningObject::RadarEarlyFraudWarning => "radar.early_fraud_warning",
             RadarEarlyFraudWarningObject::Noop => "",
-            RadarEarlyFraudWarningObject::FallthroughString => "*",
+            RadarEarlyFraudWarningObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RadarEarlyFraudWarningObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "radar.early_fraud_warning" => RadarEarlyFraudWarningObject::RadarEarlyFraudWarning,
+            "" => RadarEarlyFraudWarningObject::Noop,
+            _ => RadarEarlyFraudWarningObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RadarEarlyFraudWarningObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RadarEarlyFraudWarningObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RadarEarlyFraudWarningObject {
     fn default() -> RadarEarlyFraudWarningObject {
         RadarEarlyFraudWarningObject::Noop
@@ -36725,7 +45019,8 @@ pub struct RadarEarlyFraudWarning {
 /**
 * The type of items in the value list. One of `card_fingerprint`, `card_bin`, `email`, `ip_address`, `country`, `string`, `case_sensitive_string`, or `customer_id`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ItemType {
     #[serde(rename = "card_bin")]
     CardBin,
@@ -36745,8 +45040,9 @@ pub enum ItemType {
     String,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ItemType {
@@ -36761,12 +45057,51 @@ impl std::fmt::Display for ItemType {
             ItemType::IpAddress => "ip_address",
             ItemType::String => "string",
             ItemType::Noop => "",
-            ItemType::FallthroughString => "*",
+            ItemType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ItemType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "card_bin" => ItemType::CardBin,
+            "card_fingerprint" => ItemType::CardFingerprint,
+            "case_sensitive_string" => ItemType::CaseSensitiveString,
+            "country" => ItemType::Country,
+            "customer_id" => ItemType::CustomerId,
+            "email" => ItemType::Email,
+            "ip_address" => ItemType::IpAddress,
+            "string" => ItemType::String,
+            "" => ItemType::Noop,
+            _ => ItemType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ItemType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ItemType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ItemType {
     fn default() -> ItemType {
         ItemType::Noop
@@ -37341,14 +45676,16 @@ pub struct RecurringData {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RefundObject {
     #[serde(rename = "refund")]
     Refund,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RefundObject {
@@ -37356,12 +45693,44 @@ impl std::fmt::Display for RefundObject {
         match self {
             RefundObject::Refund => "refund",
             RefundObject::Noop => "",
-            RefundObject::FallthroughString => "*",
+            RefundObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RefundObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "refund" => RefundObject::Refund,
+            "" => RefundObject::Noop,
+            _ => RefundObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RefundObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RefundObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RefundObject {
     fn default() -> RefundObject {
         RefundObject::Noop
@@ -37376,7 +45745,8 @@ impl RefundObject {
 /**
 * Reason for the refund, either user-provided (`duplicate`, `fraudulent`, or `requested_by_customer`) or generated by Stripe internally (`expired_uncaptured_charge`).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RefundReason {
     #[serde(rename = "duplicate")]
     Duplicate,
@@ -37388,8 +45758,9 @@ pub enum RefundReason {
     RequestedByCustomer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RefundReason {
@@ -37400,12 +45771,47 @@ impl std::fmt::Display for RefundReason {
             RefundReason::Fraudulent => "fraudulent",
             RefundReason::RequestedByCustomer => "requested_by_customer",
             RefundReason::Noop => "",
-            RefundReason::FallthroughString => "*",
+            RefundReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RefundReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "duplicate" => RefundReason::Duplicate,
+            "expired_uncaptured_charge" => RefundReason::ExpiredUncapturedCharge,
+            "fraudulent" => RefundReason::Fraudulent,
+            "requested_by_customer" => RefundReason::RequestedByCustomer,
+            "" => RefundReason::Noop,
+            _ => RefundReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RefundReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RefundReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RefundReason {
     fn default() -> RefundReason {
         RefundReason::Noop
@@ -37702,14 +46108,16 @@ pub struct RefundNextActionDisplayDetails {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReportingReportRunObject {
     #[serde(rename = "reporting.report_run")]
     ReportingReportRun,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReportingReportRunObject {
@@ -37717,12 +46125,44 @@ impl std::fmt::Display for ReportingReportRunObject {
         match self {
             ReportingReportRunObject::ReportingReportRun => "reporting.report_run",
             ReportingReportRunObject::Noop => "",
-            ReportingReportRunObject::FallthroughString => "*",
+            ReportingReportRunObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReportingReportRunObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "reporting.report_run" => ReportingReportRunObject::ReportingReportRun,
+            "" => ReportingReportRunObject::Noop,
+            _ => ReportingReportRunObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReportingReportRunObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReportingReportRunObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReportingReportRunObject {
     fn default() -> ReportingReportRunObject {
         ReportingReportRunObject::Noop
@@ -37841,14 +46281,16 @@ pub struct ReportingReportRun {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReportingReportTypeObject {
     #[serde(rename = "reporting.report_type")]
     ReportingReportType,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReportingReportTypeObject {
@@ -37856,12 +46298,44 @@ impl std::fmt::Display for ReportingReportTypeObject {
         match self {
             ReportingReportTypeObject::ReportingReportType => "reporting.report_type",
             ReportingReportTypeObject::Noop => "",
-            ReportingReportTypeObject::FallthroughString => "*",
+            ReportingReportTypeObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReportingReportTypeObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "reporting.report_type" => ReportingReportTypeObject::ReportingReportType,
+            "" => ReportingReportTypeObject::Noop,
+            _ => ReportingReportTypeObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReportingReportTypeObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReportingReportTypeObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReportingReportTypeObject {
     fn default() -> ReportingReportTypeObject {
         ReportingReportTypeObject::Noop
@@ -37971,14 +46445,16 @@ pub struct ReportingReportType {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReserveTransactionObject {
     #[serde(rename = "reserve_transaction")]
     ReserveTransaction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReserveTransactionObject {
@@ -37986,12 +46462,44 @@ impl std::fmt::Display for ReserveTransactionObject {
         match self {
             ReserveTransactionObject::ReserveTransaction => "reserve_transaction",
             ReserveTransactionObject::Noop => "",
-            ReserveTransactionObject::FallthroughString => "*",
+            ReserveTransactionObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReserveTransactionObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "reserve_transaction" => ReserveTransactionObject::ReserveTransaction,
+            "" => ReserveTransactionObject::Noop,
+            _ => ReserveTransactionObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReserveTransactionObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReserveTransactionObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReserveTransactionObject {
     fn default() -> ReserveTransactionObject {
         ReserveTransactionObject::Noop
@@ -38052,7 +46560,8 @@ pub struct ReserveTransaction {
 /**
 * The reason the review was closed, or null if it has not yet been closed. One of `approved`, `refunded`, `refunded_as_fraud`, `disputed`, or `redacted`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ClosedReason {
     #[serde(rename = "approved")]
     Approved,
@@ -38066,8 +46575,9 @@ pub enum ClosedReason {
     RefundedAsFraud,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ClosedReason {
@@ -38079,12 +46589,48 @@ impl std::fmt::Display for ClosedReason {
             ClosedReason::Refunded => "refunded",
             ClosedReason::RefundedAsFraud => "refunded_as_fraud",
             ClosedReason::Noop => "",
-            ClosedReason::FallthroughString => "*",
+            ClosedReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ClosedReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approved" => ClosedReason::Approved,
+            "disputed" => ClosedReason::Disputed,
+            "redacted" => ClosedReason::Redacted,
+            "refunded" => ClosedReason::Refunded,
+            "refunded_as_fraud" => ClosedReason::RefundedAsFraud,
+            "" => ClosedReason::Noop,
+            _ => ClosedReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ClosedReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ClosedReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ClosedReason {
     fn default() -> ClosedReason {
         ClosedReason::Noop
@@ -38099,14 +46645,16 @@ impl ClosedReason {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReviewObject {
     #[serde(rename = "review")]
     Review,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReviewObject {
@@ -38114,12 +46662,44 @@ impl std::fmt::Display for ReviewObject {
         match self {
             ReviewObject::Review => "review",
             ReviewObject::Noop => "",
-            ReviewObject::FallthroughString => "*",
+            ReviewObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReviewObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "review" => ReviewObject::Review,
+            "" => ReviewObject::Noop,
+            _ => ReviewObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReviewObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReviewObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReviewObject {
     fn default() -> ReviewObject {
         ReviewObject::Noop
@@ -38134,7 +46714,8 @@ impl ReviewObject {
 /**
 * The reason the review was opened. One of `rule` or `manual`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OpenedReason {
     #[serde(rename = "manual")]
     Manual,
@@ -38142,8 +46723,9 @@ pub enum OpenedReason {
     Rule,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OpenedReason {
@@ -38152,12 +46734,45 @@ impl std::fmt::Display for OpenedReason {
             OpenedReason::Manual => "manual",
             OpenedReason::Rule => "rule",
             OpenedReason::Noop => "",
-            OpenedReason::FallthroughString => "*",
+            OpenedReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OpenedReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "manual" => OpenedReason::Manual,
+            "rule" => OpenedReason::Rule,
+            "" => OpenedReason::Noop,
+            _ => OpenedReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OpenedReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OpenedReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OpenedReason {
     fn default() -> OpenedReason {
         OpenedReason::Noop
@@ -38320,14 +46935,16 @@ pub struct Rule {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ScheduledQueryRunObject {
     #[serde(rename = "scheduled_query_run")]
     ScheduledQueryRun,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ScheduledQueryRunObject {
@@ -38335,12 +46952,44 @@ impl std::fmt::Display for ScheduledQueryRunObject {
         match self {
             ScheduledQueryRunObject::ScheduledQueryRun => "scheduled_query_run",
             ScheduledQueryRunObject::Noop => "",
-            ScheduledQueryRunObject::FallthroughString => "*",
+            ScheduledQueryRunObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ScheduledQueryRunObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "scheduled_query_run" => ScheduledQueryRunObject::ScheduledQueryRun,
+            "" => ScheduledQueryRunObject::Noop,
+            _ => ScheduledQueryRunObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ScheduledQueryRunObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScheduledQueryRunObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ScheduledQueryRunObject {
     fn default() -> ScheduledQueryRunObject {
         ScheduledQueryRunObject::Noop
@@ -38468,14 +47117,16 @@ pub struct SepaDebitGeneratedFrom {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SetupAttemptObject {
     #[serde(rename = "setup_attempt")]
     SetupAttempt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SetupAttemptObject {
@@ -38483,12 +47134,44 @@ impl std::fmt::Display for SetupAttemptObject {
         match self {
             SetupAttemptObject::SetupAttempt => "setup_attempt",
             SetupAttemptObject::Noop => "",
-            SetupAttemptObject::FallthroughString => "*",
+            SetupAttemptObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SetupAttemptObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "setup_attempt" => SetupAttemptObject::SetupAttempt,
+            "" => SetupAttemptObject::Noop,
+            _ => SetupAttemptObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SetupAttemptObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SetupAttemptObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SetupAttemptObject {
     fn default() -> SetupAttemptObject {
         SetupAttemptObject::Noop
@@ -38864,7 +47547,8 @@ pub struct SetupAttemptPaymentMethodDetailsSofort {
 /**
 * Reason for cancellation of this SetupIntent, one of `abandoned`, `requested_by_customer`, or `duplicate`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SetupIntentCancellationReason {
     #[serde(rename = "abandoned")]
     Abandoned,
@@ -38874,8 +47558,9 @@ pub enum SetupIntentCancellationReason {
     RequestedByCustomer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SetupIntentCancellationReason {
@@ -38885,12 +47570,46 @@ impl std::fmt::Display for SetupIntentCancellationReason {
             SetupIntentCancellationReason::Duplicate => "duplicate",
             SetupIntentCancellationReason::RequestedByCustomer => "requested_by_customer",
             SetupIntentCancellationReason::Noop => "",
-            SetupIntentCancellationReason::FallthroughString => "*",
+            SetupIntentCancellationReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SetupIntentCancellationReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "abandoned" => SetupIntentCancellationReason::Abandoned,
+            "duplicate" => SetupIntentCancellationReason::Duplicate,
+            "requested_by_customer" => SetupIntentCancellationReason::RequestedByCustomer,
+            "" => SetupIntentCancellationReason::Noop,
+            _ => SetupIntentCancellationReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SetupIntentCancellationReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SetupIntentCancellationReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SetupIntentCancellationReason {
     fn default() -> SetupIntentCancellationReason {
         SetupIntentCancellationReason::Noop
@@ -38905,14 +47624,16 @@ impl SetupIntentCancellationReason {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SetupIntentObject {
     #[serde(rename = "setup_intent")]
     SetupIntent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SetupIntentObject {
@@ -38920,12 +47641,44 @@ impl std::fmt::Display for SetupIntentObject {
         match self {
             SetupIntentObject::SetupIntent => "setup_intent",
             SetupIntentObject::Noop => "",
-            SetupIntentObject::FallthroughString => "*",
+            SetupIntentObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SetupIntentObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "setup_intent" => SetupIntentObject::SetupIntent,
+            "" => SetupIntentObject::Noop,
+            _ => SetupIntentObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SetupIntentObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SetupIntentObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SetupIntentObject {
     fn default() -> SetupIntentObject {
         SetupIntentObject::Noop
@@ -38940,7 +47693,8 @@ impl SetupIntentObject {
 /**
 * [Status](https://stripe.com/docs/payments/intents#intent-statuses) of this SetupIntent, one of `requires_payment_method`, `requires_confirmation`, `requires_action`, `processing`, `canceled`, or `succeeded`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SetupIntentStatus {
     #[serde(rename = "canceled")]
     Canceled,
@@ -38956,8 +47710,9 @@ pub enum SetupIntentStatus {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SetupIntentStatus {
@@ -38970,12 +47725,49 @@ impl std::fmt::Display for SetupIntentStatus {
             SetupIntentStatus::RequiresPaymentMethod => "requires_payment_method",
             SetupIntentStatus::Succeeded => "succeeded",
             SetupIntentStatus::Noop => "",
-            SetupIntentStatus::FallthroughString => "*",
+            SetupIntentStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SetupIntentStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "canceled" => SetupIntentStatus::Canceled,
+            "processing" => SetupIntentStatus::Processing,
+            "requires_action" => SetupIntentStatus::RequiresAction,
+            "requires_confirmation" => SetupIntentStatus::RequiresConfirmation,
+            "requires_payment_method" => SetupIntentStatus::RequiresPaymentMethod,
+            "succeeded" => SetupIntentStatus::Succeeded,
+            "" => SetupIntentStatus::Noop,
+            _ => SetupIntentStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SetupIntentStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SetupIntentStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SetupIntentStatus {
     fn default() -> SetupIntentStatus {
         SetupIntentStatus::Noop
@@ -39855,14 +48647,16 @@ pub struct ShippingMethod {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ShippingRateObject {
     #[serde(rename = "shipping_rate")]
     ShippingRate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ShippingRateObject {
@@ -39870,12 +48664,44 @@ impl std::fmt::Display for ShippingRateObject {
         match self {
             ShippingRateObject::ShippingRate => "shipping_rate",
             ShippingRateObject::Noop => "",
-            ShippingRateObject::FallthroughString => "*",
+            ShippingRateObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ShippingRateObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "shipping_rate" => ShippingRateObject::ShippingRate,
+            "" => ShippingRateObject::Noop,
+            _ => ShippingRateObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ShippingRateObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ShippingRateObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ShippingRateObject {
     fn default() -> ShippingRateObject {
         ShippingRateObject::Noop
@@ -39890,14 +48716,16 @@ impl ShippingRateObject {
 /**
 * The type of calculation to use on the shipping rate. Can only be `fixed_amount` for now.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ShippingRateType {
     #[serde(rename = "fixed_amount")]
     FixedAmount,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ShippingRateType {
@@ -39905,12 +48733,44 @@ impl std::fmt::Display for ShippingRateType {
         match self {
             ShippingRateType::FixedAmount => "fixed_amount",
             ShippingRateType::Noop => "",
-            ShippingRateType::FallthroughString => "*",
+            ShippingRateType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ShippingRateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "fixed_amount" => ShippingRateType::FixedAmount,
+            "" => ShippingRateType::Noop,
+            _ => ShippingRateType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ShippingRateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ShippingRateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ShippingRateType {
     fn default() -> ShippingRateType {
         ShippingRateType::Noop
@@ -40035,7 +48895,8 @@ pub struct ShippingRateDeliveryEstimateData {
 /**
 * A unit of time.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Unit {
     #[serde(rename = "business_day")]
     BusinessDay,
@@ -40049,8 +48910,9 @@ pub enum Unit {
     Week,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Unit {
@@ -40062,12 +48924,48 @@ impl std::fmt::Display for Unit {
             Unit::Month => "month",
             Unit::Week => "week",
             Unit::Noop => "",
-            Unit::FallthroughString => "*",
+            Unit::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Unit {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "business_day" => Unit::BusinessDay,
+            "day" => Unit::Day,
+            "hour" => Unit::Hour,
+            "month" => Unit::Month,
+            "week" => Unit::Week,
+            "" => Unit::Noop,
+            _ => Unit::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Unit {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Unit {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Unit {
     fn default() -> Unit {
         Unit::Noop
@@ -40323,14 +49221,16 @@ pub struct SkuInventory {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SourceObject {
     #[serde(rename = "source")]
     Source,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SourceObject {
@@ -40338,12 +49238,44 @@ impl std::fmt::Display for SourceObject {
         match self {
             SourceObject::Source => "source",
             SourceObject::Noop => "",
-            SourceObject::FallthroughString => "*",
+            SourceObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SourceObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "source" => SourceObject::Source,
+            "" => SourceObject::Noop,
+            _ => SourceObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SourceObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SourceObject {
     fn default() -> SourceObject {
         SourceObject::Noop
@@ -40358,7 +49290,8 @@ impl SourceObject {
 /**
 * The `type` of the source. The `type` is a payment method, one of `ach_credit_transfer`, `ach_debit`, `alipay`, `bancontact`, `card`, `card_present`, `eps`, `giropay`, `ideal`, `multibanco`, `klarna`, `p24`, `sepa_debit`, `sofort`, `three_d_secure`, or `wechat`. An additional hash is included on the source with a name matching this value. It contains additional information specific to the [payment method](https://stripe.com/docs/sources) used.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SourceType {
     #[serde(rename = "ach_credit_transfer")]
     AchCreditTransfer,
@@ -40398,8 +49331,9 @@ pub enum SourceType {
     Wechat,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SourceType {
@@ -40424,12 +49358,61 @@ impl std::fmt::Display for SourceType {
             SourceType::ThreeDSecure => "three_d_secure",
             SourceType::Wechat => "wechat",
             SourceType::Noop => "",
-            SourceType::FallthroughString => "*",
+            SourceType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SourceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ach_credit_transfer" => SourceType::AchCreditTransfer,
+            "ach_debit" => SourceType::AchDebit,
+            "acss_debit" => SourceType::AcssDebit,
+            "alipay" => SourceType::Alipay,
+            "au_becs_debit" => SourceType::AuBecsDebit,
+            "bancontact" => SourceType::Bancontact,
+            "card" => SourceType::Card,
+            "card_present" => SourceType::CardPresent,
+            "eps" => SourceType::Eps,
+            "giropay" => SourceType::Giropay,
+            "ideal" => SourceType::Ideal,
+            "klarna" => SourceType::Klarna,
+            "multibanco" => SourceType::Multibanco,
+            "p24" => SourceType::P24,
+            "sepa_debit" => SourceType::SepaDebit,
+            "sofort" => SourceType::Sofort,
+            "three_d_secure" => SourceType::ThreeDSecure,
+            "wechat" => SourceType::Wechat,
+            "" => SourceType::Noop,
+            _ => SourceType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SourceType {
     fn default() -> SourceType {
         SourceType::Noop
@@ -40849,14 +49832,16 @@ pub struct SourceCodeVerificationFlow {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SourceMandateNotificationObject {
     #[serde(rename = "source_mandate_notification")]
     SourceMandateNotification,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SourceMandateNotificationObject {
@@ -40866,12 +49851,46 @@ impl std::fmt::Display for SourceMandateNotificationObject {
                 "source_mandate_notification"
             }
             SourceMandateNotificationObject::Noop => "",
-            SourceMandateNotificationObject::FallthroughString => "*",
+            SourceMandateNotificationObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SourceMandateNotificationObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "source_mandate_notification" => {
+                SourceMandateNotificationObject::SourceMandateNotification
+            }
+            "" => SourceMandateNotificationObject::Noop,
+            _ => SourceMandateNotificationObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SourceMandateNotificationObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceMandateNotificationObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SourceMandateNotificationObject {
     fn default() -> SourceMandateNotificationObject {
         SourceMandateNotificationObject::Noop
@@ -41334,14 +50353,16 @@ pub struct SourceRedirectFlow {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SourceTransactionObject {
     #[serde(rename = "source_transaction")]
     SourceTransaction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SourceTransactionObject {
@@ -41349,12 +50370,44 @@ impl std::fmt::Display for SourceTransactionObject {
         match self {
             SourceTransactionObject::SourceTransaction => "source_transaction",
             SourceTransactionObject::Noop => "",
-            SourceTransactionObject::FallthroughString => "*",
+            SourceTransactionObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SourceTransactionObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "source_transaction" => SourceTransactionObject::SourceTransaction,
+            "" => SourceTransactionObject::Noop,
+            _ => SourceTransactionObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SourceTransactionObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceTransactionObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SourceTransactionObject {
     fn default() -> SourceTransactionObject {
         SourceTransactionObject::Noop
@@ -41369,7 +50422,8 @@ impl SourceTransactionObject {
 /**
 * The type of source this transaction is attached to.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SourceTransactionType {
     #[serde(rename = "ach_credit_transfer")]
     AchCreditTransfer,
@@ -41405,8 +50459,9 @@ pub enum SourceTransactionType {
     Wechat,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SourceTransactionType {
@@ -41429,12 +50484,59 @@ impl std::fmt::Display for SourceTransactionType {
             SourceTransactionType::ThreeDSecure => "three_d_secure",
             SourceTransactionType::Wechat => "wechat",
             SourceTransactionType::Noop => "",
-            SourceTransactionType::FallthroughString => "*",
+            SourceTransactionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SourceTransactionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ach_credit_transfer" => SourceTransactionType::AchCreditTransfer,
+            "ach_debit" => SourceTransactionType::AchDebit,
+            "alipay" => SourceTransactionType::Alipay,
+            "bancontact" => SourceTransactionType::Bancontact,
+            "card" => SourceTransactionType::Card,
+            "card_present" => SourceTransactionType::CardPresent,
+            "eps" => SourceTransactionType::Eps,
+            "giropay" => SourceTransactionType::Giropay,
+            "ideal" => SourceTransactionType::Ideal,
+            "klarna" => SourceTransactionType::Klarna,
+            "multibanco" => SourceTransactionType::Multibanco,
+            "p24" => SourceTransactionType::P24,
+            "sepa_debit" => SourceTransactionType::SepaDebit,
+            "sofort" => SourceTransactionType::Sofort,
+            "three_d_secure" => SourceTransactionType::ThreeDSecure,
+            "wechat" => SourceTransactionType::Wechat,
+            "" => SourceTransactionType::Noop,
+            _ => SourceTransactionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SourceTransactionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceTransactionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SourceTransactionType {
     fn default() -> SourceTransactionType {
         SourceTransactionType::Noop
@@ -43341,14 +52443,16 @@ pub struct Items {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SubscriptionObject {
     #[serde(rename = "subscription")]
     Subscription,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SubscriptionObject {
@@ -43356,12 +52460,44 @@ impl std::fmt::Display for SubscriptionObject {
         match self {
             SubscriptionObject::Subscription => "subscription",
             SubscriptionObject::Noop => "",
-            SubscriptionObject::FallthroughString => "*",
+            SubscriptionObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SubscriptionObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "subscription" => SubscriptionObject::Subscription,
+            "" => SubscriptionObject::Noop,
+            _ => SubscriptionObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SubscriptionObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SubscriptionObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SubscriptionObject {
     fn default() -> SubscriptionObject {
         SubscriptionObject::Noop
@@ -43384,7 +52520,8 @@ impl SubscriptionObject {
 *   
 *   If subscription `collection_method=send_invoice` it becomes `past_due` when its invoice is not paid by the due date, and `canceled` or `unpaid` if it is still not paid by an additional deadline after that. Note that when a subscription has a status of `unpaid`, no subsequent invoices will be attempted (invoices will be created, but then immediately automatically closed). After receiving updated payment information from a customer, you may choose to reopen and pay their closed invoices.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SubscriptionStatus {
     #[serde(rename = "active")]
     Active,
@@ -43402,8 +52539,9 @@ pub enum SubscriptionStatus {
     Unpaid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SubscriptionStatus {
@@ -43417,12 +52555,50 @@ impl std::fmt::Display for SubscriptionStatus {
             SubscriptionStatus::Trialing => "trialing",
             SubscriptionStatus::Unpaid => "unpaid",
             SubscriptionStatus::Noop => "",
-            SubscriptionStatus::FallthroughString => "*",
+            SubscriptionStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SubscriptionStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => SubscriptionStatus::Active,
+            "canceled" => SubscriptionStatus::Canceled,
+            "incomplete" => SubscriptionStatus::Incomplete,
+            "incomplete_expired" => SubscriptionStatus::IncompleteExpired,
+            "past_due" => SubscriptionStatus::PastDue,
+            "trialing" => SubscriptionStatus::Trialing,
+            "unpaid" => SubscriptionStatus::Unpaid,
+            "" => SubscriptionStatus::Noop,
+            _ => SubscriptionStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SubscriptionStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SubscriptionStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SubscriptionStatus {
     fn default() -> SubscriptionStatus {
         SubscriptionStatus::Noop
@@ -43887,7 +53063,8 @@ pub struct SubscriptionPendingInvoiceItemInterval {
 /**
 * Behavior of the subscription schedule and underlying subscription when it ends. Possible values are `release` and `cancel`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EndBehavior {
     #[serde(rename = "cancel")]
     Cancel,
@@ -43899,8 +53076,9 @@ pub enum EndBehavior {
     Renew,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EndBehavior {
@@ -43911,12 +53089,47 @@ impl std::fmt::Display for EndBehavior {
             EndBehavior::Release => "release",
             EndBehavior::Renew => "renew",
             EndBehavior::Noop => "",
-            EndBehavior::FallthroughString => "*",
+            EndBehavior::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EndBehavior {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cancel" => EndBehavior::Cancel,
+            "none" => EndBehavior::None,
+            "release" => EndBehavior::Release,
+            "renew" => EndBehavior::Renew,
+            "" => EndBehavior::Noop,
+            _ => EndBehavior::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EndBehavior {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EndBehavior {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EndBehavior {
     fn default() -> EndBehavior {
         EndBehavior::Noop
@@ -43931,14 +53144,16 @@ impl EndBehavior {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SubscriptionScheduleObject {
     #[serde(rename = "subscription_schedule")]
     SubscriptionSchedule,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SubscriptionScheduleObject {
@@ -43946,12 +53161,44 @@ impl std::fmt::Display for SubscriptionScheduleObject {
         match self {
             SubscriptionScheduleObject::SubscriptionSchedule => "subscription_schedule",
             SubscriptionScheduleObject::Noop => "",
-            SubscriptionScheduleObject::FallthroughString => "*",
+            SubscriptionScheduleObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SubscriptionScheduleObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "subscription_schedule" => SubscriptionScheduleObject::SubscriptionSchedule,
+            "" => SubscriptionScheduleObject::Noop,
+            _ => SubscriptionScheduleObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SubscriptionScheduleObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SubscriptionScheduleObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SubscriptionScheduleObject {
     fn default() -> SubscriptionScheduleObject {
         SubscriptionScheduleObject::Noop
@@ -43966,7 +53213,8 @@ impl SubscriptionScheduleObject {
 /**
 * The present status of the subscription schedule. Possible values are `not_started`, `active`, `completed`, `released`, and `canceled`. You can read more about the different states in our [behavior guide](https://stripe.com/docs/billing/subscriptions/subscription-schedules).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SubscriptionScheduleStatus {
     #[serde(rename = "active")]
     Active,
@@ -43980,8 +53228,9 @@ pub enum SubscriptionScheduleStatus {
     Released,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SubscriptionScheduleStatus {
@@ -43993,12 +53242,48 @@ impl std::fmt::Display for SubscriptionScheduleStatus {
             SubscriptionScheduleStatus::NotStarted => "not_started",
             SubscriptionScheduleStatus::Released => "released",
             SubscriptionScheduleStatus::Noop => "",
-            SubscriptionScheduleStatus::FallthroughString => "*",
+            SubscriptionScheduleStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SubscriptionScheduleStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => SubscriptionScheduleStatus::Active,
+            "canceled" => SubscriptionScheduleStatus::Canceled,
+            "completed" => SubscriptionScheduleStatus::Completed,
+            "not_started" => SubscriptionScheduleStatus::NotStarted,
+            "released" => SubscriptionScheduleStatus::Released,
+            "" => SubscriptionScheduleStatus::Noop,
+            _ => SubscriptionScheduleStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SubscriptionScheduleStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SubscriptionScheduleStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SubscriptionScheduleStatus {
     fn default() -> SubscriptionScheduleStatus {
         SubscriptionScheduleStatus::Noop
@@ -44294,7 +53579,8 @@ pub struct SubscriptionScheduleCurrentPhase {
 /**
 * Possible values are `phase_start` or `automatic`. If `phase_start` then billing cycle anchor of the subscription is set to the start of the phase when entering the phase. If `automatic` then the billing cycle anchor is automatically modified as needed when entering the phase. For more information, see the billing cycle [documentation](https://stripe.com/docs/billing/subscriptions/billing-cycle).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BillingCycleAnchor {
     #[serde(rename = "automatic")]
     Automatic,
@@ -44302,8 +53588,9 @@ pub enum BillingCycleAnchor {
     PhaseStart,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BillingCycleAnchor {
@@ -44312,12 +53599,45 @@ impl std::fmt::Display for BillingCycleAnchor {
             BillingCycleAnchor::Automatic => "automatic",
             BillingCycleAnchor::PhaseStart => "phase_start",
             BillingCycleAnchor::Noop => "",
-            BillingCycleAnchor::FallthroughString => "*",
+            BillingCycleAnchor::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BillingCycleAnchor {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "automatic" => BillingCycleAnchor::Automatic,
+            "phase_start" => BillingCycleAnchor::PhaseStart,
+            "" => BillingCycleAnchor::Noop,
+            _ => BillingCycleAnchor::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BillingCycleAnchor {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BillingCycleAnchor {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BillingCycleAnchor {
     fn default() -> BillingCycleAnchor {
         BillingCycleAnchor::Noop
@@ -44572,7 +53892,8 @@ pub struct SubscriptionTransferDataType {
 /**
 * The payment collection behavior for this subscription while paused. One of `keep_as_draft`, `mark_uncollectible`, or `void`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Behavior {
     #[serde(rename = "keep_as_draft")]
     KeepAsDraft,
@@ -44582,8 +53903,9 @@ pub enum Behavior {
     Void,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Behavior {
@@ -44593,12 +53915,46 @@ impl std::fmt::Display for Behavior {
             Behavior::MarkUncollectible => "mark_uncollectible",
             Behavior::Void => "void",
             Behavior::Noop => "",
-            Behavior::FallthroughString => "*",
+            Behavior::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Behavior {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "keep_as_draft" => Behavior::KeepAsDraft,
+            "mark_uncollectible" => Behavior::MarkUncollectible,
+            "void" => Behavior::Void,
+            "" => Behavior::Noop,
+            _ => Behavior::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Behavior {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Behavior {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Behavior {
     fn default() -> Behavior {
         Behavior::Noop
@@ -44742,14 +54098,16 @@ pub struct SubscriptionsResourcePendingUpdate {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TaxCodeObject {
     #[serde(rename = "tax_code")]
     TaxCode,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TaxCodeObject {
@@ -44757,12 +54115,44 @@ impl std::fmt::Display for TaxCodeObject {
         match self {
             TaxCodeObject::TaxCode => "tax_code",
             TaxCodeObject::Noop => "",
-            TaxCodeObject::FallthroughString => "*",
+            TaxCodeObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TaxCodeObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "tax_code" => TaxCodeObject::TaxCode,
+            "" => TaxCodeObject::Noop,
+            _ => TaxCodeObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TaxCodeObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TaxCodeObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TaxCodeObject {
     fn default() -> TaxCodeObject {
         TaxCodeObject::Noop
@@ -44814,14 +54204,16 @@ pub struct TaxCode {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TaxDeductedAtSourceObject {
     #[serde(rename = "tax_deducted_at_source")]
     TaxDeductedAtSource,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TaxDeductedAtSourceObject {
@@ -44829,12 +54221,44 @@ impl std::fmt::Display for TaxDeductedAtSourceObject {
         match self {
             TaxDeductedAtSourceObject::TaxDeductedAtSource => "tax_deducted_at_source",
             TaxDeductedAtSourceObject::Noop => "",
-            TaxDeductedAtSourceObject::FallthroughString => "*",
+            TaxDeductedAtSourceObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TaxDeductedAtSourceObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "tax_deducted_at_source" => TaxDeductedAtSourceObject::TaxDeductedAtSource,
+            "" => TaxDeductedAtSourceObject::Noop,
+            _ => TaxDeductedAtSourceObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TaxDeductedAtSourceObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TaxDeductedAtSourceObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TaxDeductedAtSourceObject {
     fn default() -> TaxDeductedAtSourceObject {
         TaxDeductedAtSourceObject::Noop
@@ -44977,7 +54401,8 @@ pub struct TaxId {
 /**
 * Verification status, one of `pending`, `verified`, `unverified`, or `unavailable`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TaxVerificationStatus {
     #[serde(rename = "pending")]
     Pending,
@@ -44989,8 +54414,9 @@ pub enum TaxVerificationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TaxVerificationStatus {
@@ -45001,12 +54427,47 @@ impl std::fmt::Display for TaxVerificationStatus {
             TaxVerificationStatus::Unverified => "unverified",
             TaxVerificationStatus::Verified => "verified",
             TaxVerificationStatus::Noop => "",
-            TaxVerificationStatus::FallthroughString => "*",
+            TaxVerificationStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TaxVerificationStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "pending" => TaxVerificationStatus::Pending,
+            "unavailable" => TaxVerificationStatus::Unavailable,
+            "unverified" => TaxVerificationStatus::Unverified,
+            "verified" => TaxVerificationStatus::Verified,
+            "" => TaxVerificationStatus::Noop,
+            _ => TaxVerificationStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TaxVerificationStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TaxVerificationStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TaxVerificationStatus {
     fn default() -> TaxVerificationStatus {
         TaxVerificationStatus::Noop
@@ -45049,14 +54510,16 @@ pub struct TaxVerificationData {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TaxRateObject {
     #[serde(rename = "tax_rate")]
     TaxRate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TaxRateObject {
@@ -45064,12 +54527,44 @@ impl std::fmt::Display for TaxRateObject {
         match self {
             TaxRateObject::TaxRate => "tax_rate",
             TaxRateObject::Noop => "",
-            TaxRateObject::FallthroughString => "*",
+            TaxRateObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TaxRateObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "tax_rate" => TaxRateObject::TaxRate,
+            "" => TaxRateObject::Noop,
+            _ => TaxRateObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TaxRateObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TaxRateObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TaxRateObject {
     fn default() -> TaxRateObject {
         TaxRateObject::Noop
@@ -45084,7 +54579,8 @@ impl TaxRateObject {
 /**
 * The high-level tax type, such as `vat` or `sales_tax`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TaxType {
     #[serde(rename = "gst")]
     Gst,
@@ -45104,8 +54600,9 @@ pub enum TaxType {
     Vat,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TaxType {
@@ -45120,12 +54617,51 @@ impl std::fmt::Display for TaxType {
             TaxType::SalesTax => "sales_tax",
             TaxType::Vat => "vat",
             TaxType::Noop => "",
-            TaxType::FallthroughString => "*",
+            TaxType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TaxType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "gst" => TaxType::Gst,
+            "hst" => TaxType::Hst,
+            "jct" => TaxType::Jct,
+            "pst" => TaxType::Pst,
+            "qst" => TaxType::Qst,
+            "rst" => TaxType::Rst,
+            "sales_tax" => TaxType::SalesTax,
+            "vat" => TaxType::Vat,
+            "" => TaxType::Noop,
+            _ => TaxType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TaxType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TaxType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TaxType {
     fn default() -> TaxType {
         TaxType::Noop
@@ -45272,14 +54808,16 @@ pub struct TaxRate {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TerminalConnectionTokenObject {
     #[serde(rename = "terminal.connection_token")]
     TerminalConnectionToken,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TerminalConnectionTokenObject {
@@ -45287,12 +54825,44 @@ impl std::fmt::Display for TerminalConnectionTokenObject {
         match self {
             TerminalConnectionTokenObject::TerminalConnectionToken => "terminal.connection_token",
             TerminalConnectionTokenObject::Noop => "",
-            TerminalConnectionTokenObject::FallthroughString => "*",
+            TerminalConnectionTokenObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TerminalConnectionTokenObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "terminal.connection_token" => TerminalConnectionTokenObject::TerminalConnectionToken,
+            "" => TerminalConnectionTokenObject::Noop,
+            _ => TerminalConnectionTokenObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TerminalConnectionTokenObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TerminalConnectionTokenObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TerminalConnectionTokenObject {
     fn default() -> TerminalConnectionTokenObject {
         TerminalConnectionTokenObject::Noop
@@ -45397,7 +54967,8 @@ pub struct TerminalLocation {
 /**
 * Type of reader, one of `bbpos_wisepad3`, `stripe_m2`, `bbpos_chipper2x`, `bbpos_wisepos_e`, or `verifone_P400`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeviceType {
     #[serde(rename = "bbpos_chipper2x")]
     BbposChipper2X,
@@ -45411,8 +54982,9 @@ pub enum DeviceType {
     VerifoneP400,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeviceType {
@@ -45424,12 +54996,48 @@ impl std::fmt::Display for DeviceType {
             DeviceType::StripeM2 => "stripe_m2",
             DeviceType::VerifoneP400 => "verifone_P400",
             DeviceType::Noop => "",
-            DeviceType::FallthroughString => "*",
+            DeviceType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeviceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "bbpos_chipper2x" => DeviceType::BbposChipper2X,
+            "bbpos_wisepad3" => DeviceType::BbposWisepad3,
+            "bbpos_wisepos_e" => DeviceType::BbposWiseposE,
+            "stripe_m2" => DeviceType::StripeM2,
+            "verifone_P400" => DeviceType::VerifoneP400,
+            "" => DeviceType::Noop,
+            _ => DeviceType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeviceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeviceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeviceType {
     fn default() -> DeviceType {
         DeviceType::Noop
@@ -45696,7 +55304,8 @@ pub struct TerminalReaderResourceProcessSetupIntentAction {
 /**
 * Status of the action performed by the reader.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TerminalReaderResourceActionStatus {
     #[serde(rename = "failed")]
     Failed,
@@ -45706,8 +55315,9 @@ pub enum TerminalReaderResourceActionStatus {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TerminalReaderResourceActionStatus {
@@ -45717,12 +55327,46 @@ impl std::fmt::Display for TerminalReaderResourceActionStatus {
             TerminalReaderResourceActionStatus::InProgress => "in_progress",
             TerminalReaderResourceActionStatus::Succeeded => "succeeded",
             TerminalReaderResourceActionStatus::Noop => "",
-            TerminalReaderResourceActionStatus::FallthroughString => "*",
+            TerminalReaderResourceActionStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TerminalReaderResourceActionStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "failed" => TerminalReaderResourceActionStatus::Failed,
+            "in_progress" => TerminalReaderResourceActionStatus::InProgress,
+            "succeeded" => TerminalReaderResourceActionStatus::Succeeded,
+            "" => TerminalReaderResourceActionStatus::Noop,
+            _ => TerminalReaderResourceActionStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TerminalReaderResourceActionStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TerminalReaderResourceActionStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TerminalReaderResourceActionStatus {
     fn default() -> TerminalReaderResourceActionStatus {
         TerminalReaderResourceActionStatus::Noop
@@ -45737,7 +55381,8 @@ impl TerminalReaderResourceActionStatus {
 /**
 * Type of action performed by the reader.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TerminalReaderResourceActionType {
     #[serde(rename = "process_payment_intent")]
     ProcessPaymentIntent,
@@ -45747,8 +55392,9 @@ pub enum TerminalReaderResourceActionType {
     SetReaderDisplay,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TerminalReaderResourceActionType {
@@ -45758,12 +55404,46 @@ impl std::fmt::Display for TerminalReaderResourceActionType {
             TerminalReaderResourceActionType::ProcessSetupIntent => "process_setup_intent",
             TerminalReaderResourceActionType::SetReaderDisplay => "set_reader_display",
             TerminalReaderResourceActionType::Noop => "",
-            TerminalReaderResourceActionType::FallthroughString => "*",
+            TerminalReaderResourceActionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TerminalReaderResourceActionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "process_payment_intent" => TerminalReaderResourceActionType::ProcessPaymentIntent,
+            "process_setup_intent" => TerminalReaderResourceActionType::ProcessSetupIntent,
+            "set_reader_display" => TerminalReaderResourceActionType::SetReaderDisplay,
+            "" => TerminalReaderResourceActionType::Noop,
+            _ => TerminalReaderResourceActionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TerminalReaderResourceActionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TerminalReaderResourceActionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TerminalReaderResourceActionType {
     fn default() -> TerminalReaderResourceActionType {
         TerminalReaderResourceActionType::Noop
@@ -45833,14 +55513,16 @@ pub struct TerminalReaderResourceAction {
 /**
 * Type of information to be displayed by the reader.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TerminalReaderResourceSetDisplayActionType {
     #[serde(rename = "cart")]
     Cart,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TerminalReaderResourceSetDisplayActionType {
@@ -45848,12 +55530,44 @@ impl std::fmt::Display for TerminalReaderResourceSetDisplayActionType {
         match self {
             TerminalReaderResourceSetDisplayActionType::Cart => "cart",
             TerminalReaderResourceSetDisplayActionType::Noop => "",
-            TerminalReaderResourceSetDisplayActionType::FallthroughString => "*",
+            TerminalReaderResourceSetDisplayActionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TerminalReaderResourceSetDisplayActionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cart" => TerminalReaderResourceSetDisplayActionType::Cart,
+            "" => TerminalReaderResourceSetDisplayActionType::Noop,
+            _ => TerminalReaderResourceSetDisplayActionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TerminalReaderResourceSetDisplayActionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TerminalReaderResourceSetDisplayActionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TerminalReaderResourceSetDisplayActionType {
     fn default() -> TerminalReaderResourceSetDisplayActionType {
         TerminalReaderResourceSetDisplayActionType::Noop
@@ -45887,7 +55601,8 @@ pub struct TerminalReaderResourceSetDisplayAction {
 /**
 * The status of the Test Clock.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TestClockStatus {
     #[serde(rename = "advancing")]
     Advancing,
@@ -45897,8 +55612,9 @@ pub enum TestClockStatus {
     Ready,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TestClockStatus {
@@ -45908,12 +55624,46 @@ impl std::fmt::Display for TestClockStatus {
             TestClockStatus::InternalFailure => "internal_failure",
             TestClockStatus::Ready => "ready",
             TestClockStatus::Noop => "",
-            TestClockStatus::FallthroughString => "*",
+            TestClockStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TestClockStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "advancing" => TestClockStatus::Advancing,
+            "internal_failure" => TestClockStatus::InternalFailure,
+            "ready" => TestClockStatus::Ready,
+            "" => TestClockStatus::Noop,
+            _ => TestClockStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TestClockStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TestClockStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TestClockStatus {
     fn default() -> TestClockStatus {
         TestClockStatus::Noop
@@ -46000,14 +55750,16 @@ pub struct TestClock {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ThreeDSecureObject {
     #[serde(rename = "three_d_secure")]
     ThreeDSecure,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ThreeDSecureObject {
@@ -46015,12 +55767,44 @@ impl std::fmt::Display for ThreeDSecureObject {
         match self {
             ThreeDSecureObject::ThreeDSecure => "three_d_secure",
             ThreeDSecureObject::Noop => "",
-            ThreeDSecureObject::FallthroughString => "*",
+            ThreeDSecureObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ThreeDSecureObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "three_d_secure" => ThreeDSecureObject::ThreeDSecure,
+            "" => ThreeDSecureObject::Noop,
+            _ => ThreeDSecureObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ThreeDSecureObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ThreeDSecureObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ThreeDSecureObject {
     fn default() -> ThreeDSecureObject {
         ThreeDSecureObject::Noop
@@ -46129,7 +55913,8 @@ pub struct ThreeDSecure {
 * For authenticated transactions: how the customer was authenticated by
 *   the issuing bank.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AuthenticationFlow {
     #[serde(rename = "challenge")]
     Challenge,
@@ -46137,8 +55922,9 @@ pub enum AuthenticationFlow {
     Frictionless,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AuthenticationFlow {
@@ -46147,12 +55933,45 @@ impl std::fmt::Display for AuthenticationFlow {
             AuthenticationFlow::Challenge => "challenge",
             AuthenticationFlow::Frictionless => "frictionless",
             AuthenticationFlow::Noop => "",
-            AuthenticationFlow::FallthroughString => "*",
+            AuthenticationFlow::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AuthenticationFlow {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "challenge" => AuthenticationFlow::Challenge,
+            "frictionless" => AuthenticationFlow::Frictionless,
+            "" => AuthenticationFlow::Noop,
+            _ => AuthenticationFlow::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AuthenticationFlow {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthenticationFlow {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AuthenticationFlow {
     fn default() -> AuthenticationFlow {
         AuthenticationFlow::Noop
@@ -46167,7 +55986,8 @@ impl AuthenticationFlow {
 /**
 * Indicates the outcome of 3D Secure authentication.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ThreeDSecureDetailsResult {
     #[serde(rename = "attempt_acknowledged")]
     AttemptAcknowledged,
@@ -46181,8 +56001,9 @@ pub enum ThreeDSecureDetailsResult {
     ProcessingError,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ThreeDSecureDetailsResult {
@@ -46194,12 +56015,48 @@ impl std::fmt::Display for ThreeDSecureDetailsResult {
             ThreeDSecureDetailsResult::NotSupported => "not_supported",
             ThreeDSecureDetailsResult::ProcessingError => "processing_error",
             ThreeDSecureDetailsResult::Noop => "",
-            ThreeDSecureDetailsResult::FallthroughString => "*",
+            ThreeDSecureDetailsResult::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ThreeDSecureDetailsResult {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "attempt_acknowledged" => ThreeDSecureDetailsResult::AttemptAcknowledged,
+            "authenticated" => ThreeDSecureDetailsResult::Authenticated,
+            "failed" => ThreeDSecureDetailsResult::Failed,
+            "not_supported" => ThreeDSecureDetailsResult::NotSupported,
+            "processing_error" => ThreeDSecureDetailsResult::ProcessingError,
+            "" => ThreeDSecureDetailsResult::Noop,
+            _ => ThreeDSecureDetailsResult::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ThreeDSecureDetailsResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ThreeDSecureDetailsResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ThreeDSecureDetailsResult {
     fn default() -> ThreeDSecureDetailsResult {
         ThreeDSecureDetailsResult::Noop
@@ -46215,7 +56072,8 @@ impl ThreeDSecureDetailsResult {
 * Additional information about why 3D Secure succeeded or failed based
 *   on the `result`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ResultReason {
     #[serde(rename = "abandoned")]
     Abandoned,
@@ -46233,8 +56091,9 @@ pub enum ResultReason {
     Rejected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ResultReason {
@@ -46248,12 +56107,50 @@ impl std::fmt::Display for ResultReason {
             ResultReason::ProtocolError => "protocol_error",
             ResultReason::Rejected => "rejected",
             ResultReason::Noop => "",
-            ResultReason::FallthroughString => "*",
+            ResultReason::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ResultReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "abandoned" => ResultReason::Abandoned,
+            "bypassed" => ResultReason::Bypassed,
+            "canceled" => ResultReason::Canceled,
+            "card_not_enrolled" => ResultReason::CardNotEnrolled,
+            "network_not_supported" => ResultReason::NetworkNotSupported,
+            "protocol_error" => ResultReason::ProtocolError,
+            "rejected" => ResultReason::Rejected,
+            "" => ResultReason::Noop,
+            _ => ResultReason::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ResultReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ResultReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ResultReason {
     fn default() -> ResultReason {
         ResultReason::Noop
@@ -46268,7 +56165,8 @@ impl ResultReason {
 /**
 * The version of 3D Secure that was used.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Version {
     #[serde(rename = "1.0.2")]
     OneHundredAndTwo,
@@ -46278,8 +56176,9 @@ pub enum Version {
     Twenty,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Version {
@@ -46289,12 +56188,46 @@ impl std::fmt::Display for Version {
             Version::TwoHundredAndTen => "2.1.0",
             Version::Twenty => "2.2.0",
             Version::Noop => "",
-            Version::FallthroughString => "*",
+            Version::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Version {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "1.0.2" => Version::OneHundredAndTwo,
+            "2.1.0" => Version::TwoHundredAndTen,
+            "2.2.0" => Version::Twenty,
+            "" => Version::Noop,
+            _ => Version::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Version {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Version {
     fn default() -> Version {
         Version::Noop
@@ -46349,14 +56282,16 @@ pub struct ThreeDSecureUsage {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TokenObject {
     #[serde(rename = "token")]
     Token,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TokenObject {
@@ -46364,12 +56299,44 @@ impl std::fmt::Display for TokenObject {
         match self {
             TokenObject::Token => "token",
             TokenObject::Noop => "",
-            TokenObject::FallthroughString => "*",
+            TokenObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TokenObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "token" => TokenObject::Token,
+            "" => TokenObject::Noop,
+            _ => TokenObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TokenObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TokenObject {
     fn default() -> TokenObject {
         TokenObject::Noop
@@ -46541,14 +56508,16 @@ pub struct Token {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TopupObject {
     #[serde(rename = "topup")]
     Topup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TopupObject {
@@ -46556,12 +56525,44 @@ impl std::fmt::Display for TopupObject {
         match self {
             TopupObject::Topup => "topup",
             TopupObject::Noop => "",
-            TopupObject::FallthroughString => "*",
+            TopupObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TopupObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "topup" => TopupObject::Topup,
+            "" => TopupObject::Noop,
+            _ => TopupObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TopupObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TopupObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TopupObject {
     fn default() -> TopupObject {
         TopupObject::Noop
@@ -46576,7 +56577,8 @@ impl TopupObject {
 /**
 * The status of the top-up is either `canceled`, `failed`, `pending`, `reversed`, or `succeeded`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TopupStatus {
     #[serde(rename = "canceled")]
     Canceled,
@@ -46590,8 +56592,9 @@ pub enum TopupStatus {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TopupStatus {
@@ -46603,12 +56606,48 @@ impl std::fmt::Display for TopupStatus {
             TopupStatus::Reversed => "reversed",
             TopupStatus::Succeeded => "succeeded",
             TopupStatus::Noop => "",
-            TopupStatus::FallthroughString => "*",
+            TopupStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TopupStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "canceled" => TopupStatus::Canceled,
+            "failed" => TopupStatus::Failed,
+            "pending" => TopupStatus::Pending,
+            "reversed" => TopupStatus::Reversed,
+            "succeeded" => TopupStatus::Succeeded,
+            "" => TopupStatus::Noop,
+            _ => TopupStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TopupStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TopupStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TopupStatus {
     fn default() -> TopupStatus {
         TopupStatus::Noop
@@ -46792,14 +56831,16 @@ pub struct Topup {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TransferObject {
     #[serde(rename = "transfer")]
     Transfer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TransferObject {
@@ -46807,12 +56848,44 @@ impl std::fmt::Display for TransferObject {
         match self {
             TransferObject::Transfer => "transfer",
             TransferObject::Noop => "",
-            TransferObject::FallthroughString => "*",
+            TransferObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TransferObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "transfer" => TransferObject::Transfer,
+            "" => TransferObject::Noop,
+            _ => TransferObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TransferObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TransferObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TransferObject {
     fn default() -> TransferObject {
         TransferObject::Noop
@@ -47083,14 +57156,16 @@ pub struct TransferData {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TransferReversalObject {
     #[serde(rename = "transfer_reversal")]
     TransferReversal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TransferReversalObject {
@@ -47098,12 +57173,44 @@ impl std::fmt::Display for TransferReversalObject {
         match self {
             TransferReversalObject::TransferReversal => "transfer_reversal",
             TransferReversalObject::Noop => "",
-            TransferReversalObject::FallthroughString => "*",
+            TransferReversalObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TransferReversalObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "transfer_reversal" => TransferReversalObject::TransferReversal,
+            "" => TransferReversalObject::Noop,
+            _ => TransferReversalObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TransferReversalObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TransferReversalObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TransferReversalObject {
     fn default() -> TransferReversalObject {
         TransferReversalObject::Noop
@@ -47291,7 +57398,8 @@ pub struct TransferSchedule {
 /**
 * After division, either round the result `up` or `down`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Round {
     #[serde(rename = "down")]
     Down,
@@ -47299,8 +57407,9 @@ pub enum Round {
     Up,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Round {
@@ -47309,12 +57418,45 @@ impl std::fmt::Display for Round {
             Round::Down => "down",
             Round::Up => "up",
             Round::Noop => "",
-            Round::FallthroughString => "*",
+            Round::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Round {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "down" => Round::Down,
+            "up" => Round::Up,
+            "" => Round::Noop,
+            _ => Round::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Round {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Round {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Round {
     fn default() -> Round {
         Round::Noop
@@ -47348,14 +57490,16 @@ pub struct TransformUsage {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UsageRecordObject {
     #[serde(rename = "usage_record")]
     UsageRecord,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UsageRecordObject {
@@ -47363,12 +57507,44 @@ impl std::fmt::Display for UsageRecordObject {
         match self {
             UsageRecordObject::UsageRecord => "usage_record",
             UsageRecordObject::Noop => "",
-            UsageRecordObject::FallthroughString => "*",
+            UsageRecordObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UsageRecordObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "usage_record" => UsageRecordObject::UsageRecord,
+            "" => UsageRecordObject::Noop,
+            _ => UsageRecordObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UsageRecordObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UsageRecordObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UsageRecordObject {
     fn default() -> UsageRecordObject {
         UsageRecordObject::Noop
@@ -47440,14 +57616,16 @@ pub struct UsageRecord {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UsageRecordSummaryObject {
     #[serde(rename = "usage_record_summary")]
     UsageRecordSummary,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UsageRecordSummaryObject {
@@ -47455,12 +57633,44 @@ impl std::fmt::Display for UsageRecordSummaryObject {
         match self {
             UsageRecordSummaryObject::UsageRecordSummary => "usage_record_summary",
             UsageRecordSummaryObject::Noop => "",
-            UsageRecordSummaryObject::FallthroughString => "*",
+            UsageRecordSummaryObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UsageRecordSummaryObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "usage_record_summary" => UsageRecordSummaryObject::UsageRecordSummary,
+            "" => UsageRecordSummaryObject::Noop,
+            _ => UsageRecordSummaryObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UsageRecordSummaryObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UsageRecordSummaryObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UsageRecordSummaryObject {
     fn default() -> UsageRecordSummaryObject {
         UsageRecordSummaryObject::Noop
@@ -47534,7 +57744,8 @@ pub struct UsageRecordSummary {
 /**
 * Indicates whether this object and its related objects have been redacted or not.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum VerificationSessionRedactionStatus {
     #[serde(rename = "processing")]
     Processing,
@@ -47542,8 +57753,9 @@ pub enum VerificationSessionRedactionStatus {
     Redacted,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for VerificationSessionRedactionStatus {
@@ -47552,12 +57764,45 @@ impl std::fmt::Display for VerificationSessionRedactionStatus {
             VerificationSessionRedactionStatus::Processing => "processing",
             VerificationSessionRedactionStatus::Redacted => "redacted",
             VerificationSessionRedactionStatus::Noop => "",
-            VerificationSessionRedactionStatus::FallthroughString => "*",
+            VerificationSessionRedactionStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for VerificationSessionRedactionStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "processing" => VerificationSessionRedactionStatus::Processing,
+            "redacted" => VerificationSessionRedactionStatus::Redacted,
+            "" => VerificationSessionRedactionStatus::Noop,
+            _ => VerificationSessionRedactionStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for VerificationSessionRedactionStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for VerificationSessionRedactionStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for VerificationSessionRedactionStatus {
     fn default() -> VerificationSessionRedactionStatus {
         VerificationSessionRedactionStatus::Noop
@@ -48100,14 +58345,16 @@ pub struct GetBitcoinReceiversResponse {
 /**
 * String representing the object's type. Objects of the same type share the same value.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SearchResultObject {
     #[serde(rename = "search_result")]
     SearchResult,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SearchResultObject {
@@ -48115,12 +58362,44 @@ impl std::fmt::Display for SearchResultObject {
         match self {
             SearchResultObject::SearchResult => "search_result",
             SearchResultObject::Noop => "",
-            SearchResultObject::FallthroughString => "*",
+            SearchResultObject::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SearchResultObject {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "search_result" => SearchResultObject::SearchResult,
+            "" => SearchResultObject::Noop,
+            _ => SearchResultObject::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SearchResultObject {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchResultObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SearchResultObject {
     fn default() -> SearchResultObject {
         SearchResultObject::Noop
@@ -48318,24 +58597,57 @@ pub struct CreditNotesList {
     pub url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Shipping {
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Shipping {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Shipping::Noop => "",
-            Shipping::FallthroughString => "*",
+            Shipping::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Shipping {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "" => Shipping::Noop,
+            _ => Shipping::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Shipping {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Shipping {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Shipping {
     fn default() -> Shipping {
         Shipping::Noop
@@ -48622,7 +58934,8 @@ impl DeleteCustomersCustomerCardsResponseAnyOf {
 /**
 * A required filter on the list, based on the object `type` field.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetCustomersCustomerPaymentMethodsType {
     #[serde(rename = "acss_debit")]
     AcssDebit,
@@ -48670,8 +58983,9 @@ pub enum GetCustomersCustomerPaymentMethodsType {
     WechatPay,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetCustomersCustomerPaymentMethodsType {
@@ -48700,12 +59014,65 @@ impl std::fmt::Display for GetCustomersCustomerPaymentMethodsType {
             GetCustomersCustomerPaymentMethodsType::UsBankAccount => "us_bank_account",
             GetCustomersCustomerPaymentMethodsType::WechatPay => "wechat_pay",
             GetCustomersCustomerPaymentMethodsType::Noop => "",
-            GetCustomersCustomerPaymentMethodsType::FallthroughString => "*",
+            GetCustomersCustomerPaymentMethodsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetCustomersCustomerPaymentMethodsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "acss_debit" => GetCustomersCustomerPaymentMethodsType::AcssDebit,
+            "afterpay_clearpay" => GetCustomersCustomerPaymentMethodsType::AfterpayClearpay,
+            "alipay" => GetCustomersCustomerPaymentMethodsType::Alipay,
+            "au_becs_debit" => GetCustomersCustomerPaymentMethodsType::AuBecsDebit,
+            "bacs_debit" => GetCustomersCustomerPaymentMethodsType::BacsDebit,
+            "bancontact" => GetCustomersCustomerPaymentMethodsType::Bancontact,
+            "boleto" => GetCustomersCustomerPaymentMethodsType::Boleto,
+            "card" => GetCustomersCustomerPaymentMethodsType::Card,
+            "eps" => GetCustomersCustomerPaymentMethodsType::Eps,
+            "fpx" => GetCustomersCustomerPaymentMethodsType::Fpx,
+            "giropay" => GetCustomersCustomerPaymentMethodsType::Giropay,
+            "grabpay" => GetCustomersCustomerPaymentMethodsType::Grabpay,
+            "ideal" => GetCustomersCustomerPaymentMethodsType::Ideal,
+            "klarna" => GetCustomersCustomerPaymentMethodsType::Klarna,
+            "konbini" => GetCustomersCustomerPaymentMethodsType::Konbini,
+            "oxxo" => GetCustomersCustomerPaymentMethodsType::Oxxo,
+            "p24" => GetCustomersCustomerPaymentMethodsType::P24,
+            "paynow" => GetCustomersCustomerPaymentMethodsType::Paynow,
+            "sepa_debit" => GetCustomersCustomerPaymentMethodsType::SepaDebit,
+            "sofort" => GetCustomersCustomerPaymentMethodsType::Sofort,
+            "us_bank_account" => GetCustomersCustomerPaymentMethodsType::UsBankAccount,
+            "wechat_pay" => GetCustomersCustomerPaymentMethodsType::WechatPay,
+            "" => GetCustomersCustomerPaymentMethodsType::Noop,
+            _ => GetCustomersCustomerPaymentMethodsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetCustomersCustomerPaymentMethodsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetCustomersCustomerPaymentMethodsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetCustomersCustomerPaymentMethodsType {
     fn default() -> GetCustomersCustomerPaymentMethodsType {
         GetCustomersCustomerPaymentMethodsType::Noop
@@ -48984,7 +59351,8 @@ pub struct GetInvoiceitemsResponse {
 /**
 * The status of the invoice, one of `draft`, `open`, `paid`, `uncollectible`, or `void`. [Learn more](https://stripe.com/docs/billing/invoices/workflow#workflow-overview)
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetInvoicesStatus {
     #[serde(rename = "draft")]
     Draft,
@@ -48998,8 +59366,9 @@ pub enum GetInvoicesStatus {
     Void,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetInvoicesStatus {
@@ -49011,12 +59380,48 @@ impl std::fmt::Display for GetInvoicesStatus {
             GetInvoicesStatus::Uncollectible => "uncollectible",
             GetInvoicesStatus::Void => "void",
             GetInvoicesStatus::Noop => "",
-            GetInvoicesStatus::FallthroughString => "*",
+            GetInvoicesStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetInvoicesStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "draft" => GetInvoicesStatus::Draft,
+            "open" => GetInvoicesStatus::Open,
+            "paid" => GetInvoicesStatus::Paid,
+            "uncollectible" => GetInvoicesStatus::Uncollectible,
+            "void" => GetInvoicesStatus::Void,
+            "" => GetInvoicesStatus::Noop,
+            _ => GetInvoicesStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetInvoicesStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetInvoicesStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetInvoicesStatus {
     fn default() -> GetInvoicesStatus {
         GetInvoicesStatus::Noop
@@ -49235,7 +59640,8 @@ pub struct Tax {
     pub ip_address: Box<Option<IpAddressAnyOf>>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetInvoicesUpcomingCustomerDetailsTaxExempt {
     #[serde(rename = "exempt")]
     Exempt,
@@ -49245,8 +59651,9 @@ pub enum GetInvoicesUpcomingCustomerDetailsTaxExempt {
     Reverse,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetInvoicesUpcomingCustomerDetailsTaxExempt {
@@ -49256,12 +59663,46 @@ impl std::fmt::Display for GetInvoicesUpcomingCustomerDetailsTaxExempt {
             GetInvoicesUpcomingCustomerDetailsTaxExempt::None => "none",
             GetInvoicesUpcomingCustomerDetailsTaxExempt::Reverse => "reverse",
             GetInvoicesUpcomingCustomerDetailsTaxExempt::Noop => "",
-            GetInvoicesUpcomingCustomerDetailsTaxExempt::FallthroughString => "*",
+            GetInvoicesUpcomingCustomerDetailsTaxExempt::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetInvoicesUpcomingCustomerDetailsTaxExempt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "exempt" => GetInvoicesUpcomingCustomerDetailsTaxExempt::Exempt,
+            "none" => GetInvoicesUpcomingCustomerDetailsTaxExempt::None,
+            "reverse" => GetInvoicesUpcomingCustomerDetailsTaxExempt::Reverse,
+            "" => GetInvoicesUpcomingCustomerDetailsTaxExempt::Noop,
+            _ => GetInvoicesUpcomingCustomerDetailsTaxExempt::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetInvoicesUpcomingCustomerDetailsTaxExempt {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetInvoicesUpcomingCustomerDetailsTaxExempt {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetInvoicesUpcomingCustomerDetailsTaxExempt {
     fn default() -> GetInvoicesUpcomingCustomerDetailsTaxExempt {
         GetInvoicesUpcomingCustomerDetailsTaxExempt::Noop
@@ -49273,7 +59714,8 @@ impl GetInvoicesUpcomingCustomerDetailsTaxExempt {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetInvoicesUpcomingCustomerDetailsDataParamsType {
     #[serde(rename = "ae_trn")]
     AeTrn,
@@ -49367,8 +59809,9 @@ pub enum GetInvoicesUpcomingCustomerDetailsDataParamsType {
     ZaVat,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetInvoicesUpcomingCustomerDetailsDataParamsType {
@@ -49420,9 +59863,85 @@ impl std::fmt::Display for GetInvoicesUpcomingCustomerDetailsDataParamsType {
             GetInvoicesUpcomingCustomerDetailsDataParamsType::UsEin => "us_ein",
             GetInvoicesUpcomingCustomerDetailsDataParamsType::ZaVat => "za_vat",
             GetInvoicesUpcomingCustomerDetailsDataParamsType::Noop => "",
-            GetInvoicesUpcomingCustomerDetailsDataParamsType::FallthroughString => "*",
-        }
-        .fmt(f)
+            GetInvoicesUpcomingCustomerDetailsDataParamsType::UnknownValue(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetInvoicesUpcomingCustomerDetailsDataParamsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ae_trn" => GetInvoicesUpcomingCustomerDetailsDataParamsType::AeTrn,
+            "au_abn" => GetInvoicesUpcomingCustomerDetailsDataParamsType::AuAbn,
+            "au_arn" => GetInvoicesUpcomingCustomerDetailsDataParamsType::AuArn,
+            "bg_uic" => GetInvoicesUpcomingCustomerDetailsDataParamsType::BgUic,
+            "br_cnpj" => GetInvoicesUpcomingCustomerDetailsDataParamsType::BrCnpj,
+            "br_cpf" => GetInvoicesUpcomingCustomerDetailsDataParamsType::BrCpf,
+            "ca_bn" => GetInvoicesUpcomingCustomerDetailsDataParamsType::CaBn,
+            "ca_gst_hst" => GetInvoicesUpcomingCustomerDetailsDataParamsType::CaGstHst,
+            "ca_pst_bc" => GetInvoicesUpcomingCustomerDetailsDataParamsType::CaPstBc,
+            "ca_pst_mb" => GetInvoicesUpcomingCustomerDetailsDataParamsType::CaPstMb,
+            "ca_pst_sk" => GetInvoicesUpcomingCustomerDetailsDataParamsType::CaPstSk,
+            "ca_qst" => GetInvoicesUpcomingCustomerDetailsDataParamsType::CaQst,
+            "ch_vat" => GetInvoicesUpcomingCustomerDetailsDataParamsType::ChVat,
+            "cl_tin" => GetInvoicesUpcomingCustomerDetailsDataParamsType::ClTin,
+            "es_cif" => GetInvoicesUpcomingCustomerDetailsDataParamsType::EsCif,
+            "eu_vat" => GetInvoicesUpcomingCustomerDetailsDataParamsType::EuVat,
+            "gb_vat" => GetInvoicesUpcomingCustomerDetailsDataParamsType::GbVat,
+            "ge_vat" => GetInvoicesUpcomingCustomerDetailsDataParamsType::GeVat,
+            "hk_br" => GetInvoicesUpcomingCustomerDetailsDataParamsType::HkBr,
+            "hu_tin" => GetInvoicesUpcomingCustomerDetailsDataParamsType::HuTin,
+            "id_npwp" => GetInvoicesUpcomingCustomerDetailsDataParamsType::IdNpwp,
+            "il_vat" => GetInvoicesUpcomingCustomerDetailsDataParamsType::IlVat,
+            "in_gst" => GetInvoicesUpcomingCustomerDetailsDataParamsType::InGst,
+            "is_vat" => GetInvoicesUpcomingCustomerDetailsDataParamsType::IsVat,
+            "jp_cn" => GetInvoicesUpcomingCustomerDetailsDataParamsType::JpCn,
+            "jp_rn" => GetInvoicesUpcomingCustomerDetailsDataParamsType::JpRn,
+            "kr_brn" => GetInvoicesUpcomingCustomerDetailsDataParamsType::KrBrn,
+            "li_uid" => GetInvoicesUpcomingCustomerDetailsDataParamsType::LiUid,
+            "mx_rfc" => GetInvoicesUpcomingCustomerDetailsDataParamsType::MxRfc,
+            "my_frp" => GetInvoicesUpcomingCustomerDetailsDataParamsType::MyFrp,
+            "my_itn" => GetInvoicesUpcomingCustomerDetailsDataParamsType::MyItn,
+            "my_sst" => GetInvoicesUpcomingCustomerDetailsDataParamsType::MySst,
+            "no_vat" => GetInvoicesUpcomingCustomerDetailsDataParamsType::NoVat,
+            "nz_gst" => GetInvoicesUpcomingCustomerDetailsDataParamsType::NzGst,
+            "ru_inn" => GetInvoicesUpcomingCustomerDetailsDataParamsType::RuInn,
+            "ru_kpp" => GetInvoicesUpcomingCustomerDetailsDataParamsType::RuKpp,
+            "sa_vat" => GetInvoicesUpcomingCustomerDetailsDataParamsType::SaVat,
+            "sg_gst" => GetInvoicesUpcomingCustomerDetailsDataParamsType::SgGst,
+            "sg_uen" => GetInvoicesUpcomingCustomerDetailsDataParamsType::SgUen,
+            "si_tin" => GetInvoicesUpcomingCustomerDetailsDataParamsType::SiTin,
+            "th_vat" => GetInvoicesUpcomingCustomerDetailsDataParamsType::ThVat,
+            "tw_vat" => GetInvoicesUpcomingCustomerDetailsDataParamsType::TwVat,
+            "ua_vat" => GetInvoicesUpcomingCustomerDetailsDataParamsType::UaVat,
+            "us_ein" => GetInvoicesUpcomingCustomerDetailsDataParamsType::UsEin,
+            "za_vat" => GetInvoicesUpcomingCustomerDetailsDataParamsType::ZaVat,
+            "" => GetInvoicesUpcomingCustomerDetailsDataParamsType::Noop,
+            _ => GetInvoicesUpcomingCustomerDetailsDataParamsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetInvoicesUpcomingCustomerDetailsDataParamsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetInvoicesUpcomingCustomerDetailsDataParamsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -49711,7 +60230,8 @@ pub struct InvoiceItems {
 /**
 * For new subscriptions, a future timestamp to anchor the subscription's [billing cycle](https://stripe.com/docs/subscriptions/billing-cycle). This is used to determine the date of the first full invoice, and, for plans with `month` or `year` intervals, the day of the month for subsequent invoices. For existing subscriptions, the value can only be set to `now` or `unchanged`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SubscriptionBillingCycleAnchor {
     #[serde(rename = "now")]
     Now,
@@ -49719,8 +60239,9 @@ pub enum SubscriptionBillingCycleAnchor {
     Unchanged,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SubscriptionBillingCycleAnchor {
@@ -49729,12 +60250,45 @@ impl std::fmt::Display for SubscriptionBillingCycleAnchor {
             SubscriptionBillingCycleAnchor::Now => "now",
             SubscriptionBillingCycleAnchor::Unchanged => "unchanged",
             SubscriptionBillingCycleAnchor::Noop => "",
-            SubscriptionBillingCycleAnchor::FallthroughString => "*",
+            SubscriptionBillingCycleAnchor::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SubscriptionBillingCycleAnchor {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "now" => SubscriptionBillingCycleAnchor::Now,
+            "unchanged" => SubscriptionBillingCycleAnchor::Unchanged,
+            "" => SubscriptionBillingCycleAnchor::Noop,
+            _ => SubscriptionBillingCycleAnchor::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SubscriptionBillingCycleAnchor {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SubscriptionBillingCycleAnchor {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SubscriptionBillingCycleAnchor {
     fn default() -> SubscriptionBillingCycleAnchor {
         SubscriptionBillingCycleAnchor::Noop
@@ -49984,14 +60538,16 @@ pub struct SubscriptionItems {
 /**
 * If provided, the invoice returned will preview updating or creating a subscription with that trial end. If set, one of `subscription_items` or `subscription` is required.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SubscriptionTrialEnd {
     #[serde(rename = "now")]
     Now,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SubscriptionTrialEnd {
@@ -49999,12 +60555,44 @@ impl std::fmt::Display for SubscriptionTrialEnd {
         match self {
             SubscriptionTrialEnd::Now => "now",
             SubscriptionTrialEnd::Noop => "",
-            SubscriptionTrialEnd::FallthroughString => "*",
+            SubscriptionTrialEnd::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SubscriptionTrialEnd {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "now" => SubscriptionTrialEnd::Now,
+            "" => SubscriptionTrialEnd::Noop,
+            _ => SubscriptionTrialEnd::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SubscriptionTrialEnd {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SubscriptionTrialEnd {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SubscriptionTrialEnd {
     fn default() -> SubscriptionTrialEnd {
         SubscriptionTrialEnd::Noop
@@ -50710,7 +61298,8 @@ pub struct GetRadarValueListsResponse {
     pub url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetRecipientsType {
     #[serde(rename = "corporation")]
     Corporation,
@@ -50718,8 +61307,9 @@ pub enum GetRecipientsType {
     Individual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetRecipientsType {
@@ -50728,12 +61318,45 @@ impl std::fmt::Display for GetRecipientsType {
             GetRecipientsType::Corporation => "corporation",
             GetRecipientsType::Individual => "individual",
             GetRecipientsType::Noop => "",
-            GetRecipientsType::FallthroughString => "*",
+            GetRecipientsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetRecipientsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "corporation" => GetRecipientsType::Corporation,
+            "individual" => GetRecipientsType::Individual,
+            "" => GetRecipientsType::Noop,
+            _ => GetRecipientsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetRecipientsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetRecipientsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetRecipientsType {
     fn default() -> GetRecipientsType {
         GetRecipientsType::Noop
@@ -51230,7 +61853,8 @@ pub struct GetSubscriptionSchedulesResponse {
 /**
 * The status of the subscriptions to retrieve. Passing in a value of `canceled` will return all canceled subscriptions, including those belonging to deleted customers. Pass `ended` to find subscriptions that are canceled and subscriptions that are expired due to [incomplete payment](https://stripe.com/docs/billing/subscriptions/overview#subscription-statuses). Passing in a value of `all` will return subscriptions of all statuses. If no value is supplied, all subscriptions that have not been canceled are returned.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetSubscriptionsStatus {
     #[serde(rename = "active")]
     Active,
@@ -51252,8 +61876,9 @@ pub enum GetSubscriptionsStatus {
     Unpaid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetSubscriptionsStatus {
@@ -51269,12 +61894,52 @@ impl std::fmt::Display for GetSubscriptionsStatus {
             GetSubscriptionsStatus::Trialing => "trialing",
             GetSubscriptionsStatus::Unpaid => "unpaid",
             GetSubscriptionsStatus::Noop => "",
-            GetSubscriptionsStatus::FallthroughString => "*",
+            GetSubscriptionsStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetSubscriptionsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => GetSubscriptionsStatus::Active,
+            "all" => GetSubscriptionsStatus::All,
+            "canceled" => GetSubscriptionsStatus::Canceled,
+            "ended" => GetSubscriptionsStatus::Ended,
+            "incomplete" => GetSubscriptionsStatus::Incomplete,
+            "incomplete_expired" => GetSubscriptionsStatus::IncompleteExpired,
+            "past_due" => GetSubscriptionsStatus::PastDue,
+            "trialing" => GetSubscriptionsStatus::Trialing,
+            "unpaid" => GetSubscriptionsStatus::Unpaid,
+            "" => GetSubscriptionsStatus::Noop,
+            _ => GetSubscriptionsStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetSubscriptionsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetSubscriptionsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetSubscriptionsStatus {
     fn default() -> GetSubscriptionsStatus {
         GetSubscriptionsStatus::Noop
@@ -51536,7 +62201,8 @@ pub struct GetTestHelpersClocksResponse {
 /**
 * Only return top-ups that have the given status. One of `canceled`, `failed`, `pending` or `succeeded`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetTopupsStatus {
     #[serde(rename = "canceled")]
     Canceled,
@@ -51548,8 +62214,9 @@ pub enum GetTopupsStatus {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetTopupsStatus {
@@ -51560,12 +62227,47 @@ impl std::fmt::Display for GetTopupsStatus {
             GetTopupsStatus::Pending => "pending",
             GetTopupsStatus::Succeeded => "succeeded",
             GetTopupsStatus::Noop => "",
-            GetTopupsStatus::FallthroughString => "*",
+            GetTopupsStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetTopupsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "canceled" => GetTopupsStatus::Canceled,
+            "failed" => GetTopupsStatus::Failed,
+            "pending" => GetTopupsStatus::Pending,
+            "succeeded" => GetTopupsStatus::Succeeded,
+            "" => GetTopupsStatus::Noop,
+            _ => GetTopupsStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetTopupsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetTopupsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetTopupsStatus {
     fn default() -> GetTopupsStatus {
         GetTopupsStatus::Noop
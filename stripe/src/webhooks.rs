@@ -0,0 +1,74 @@
+//! For verifying the signatures Stripe sends with webhook events.
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// The header Stripe sends the signature of an event in.
+pub const SIGNATURE_HEADER: &str = "stripe-signature";
+
+/// How far the signed timestamp may be from now, matching Stripe's own libraries.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
+
+/// Verify the `Stripe-Signature` header of a webhook event.
+///
+/// `header` is the header value, for example `t=1492774577,v1=5257a869...`,
+/// `payload` the raw request body and `secret` the endpoint's signing secret
+/// (`whsec_...`). Any of the `v1` signatures may match, so events stay valid
+/// while a secret is being rolled. Events signed more than `tolerance` ago
+/// are rejected to limit replays, and a `tolerance` of zero skips the check.
+/// A header with more than one timestamp is rejected. Comparisons are
+/// constant-time.
+pub fn verify(secret: &str, payload: &[u8], header: &str, tolerance: Duration) -> Result<()> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", t)) => {
+                if timestamp.replace(t).is_some() {
+                    return Err(anyhow!("webhook signature has more than one timestamp"));
+                }
+            }
+            Some(("v1", signature)) => signatures.push(signature),
+            _ => {}
+        }
+    }
+    let timestamp = timestamp.ok_or_else(|| anyhow!("webhook signature has no timestamp"))?;
+    if signatures.is_empty() {
+        return Err(anyhow!("webhook signature has no v1 signature"));
+    }
+
+    let signed_at = timestamp.parse::<u64>()?;
+    if !tolerance.is_zero() {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        if now > signed_at.saturating_add(tolerance.as_secs()) {
+            return Err(anyhow!("webhook timestamp is outside the tolerance"));
+        }
+    }
+
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(payload);
+    let matched = signatures
+        .iter()
+        .filter_map(|signature| hex::decode(signature).ok())
+        .any(|signature| mac.clone().verify_slice(&signature).is_ok());
+    if !matched {
+        return Err(anyhow!("webhook signature does not match"));
+    }
+
+    Ok(())
+}
+
+/// Verify a webhook event using the signature in its headers and
+/// `DEFAULT_TOLERANCE`.
+pub fn verify_headers(secret: &str, payload: &[u8], headers: &http::HeaderMap) -> Result<()> {
+    let header = headers
+        .get(SIGNATURE_HEADER)
+        .ok_or_else(|| anyhow!("missing `{}` header", SIGNATURE_HEADER))?
+        .to_str()?;
+    verify(secret, payload, header, DEFAULT_TOLERANCE)
+}
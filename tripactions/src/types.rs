@@ -2,7 +2,8 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TravelerType {
     #[serde(rename = "GUEST")]
     Guest,
@@ -10,8 +11,9 @@ pub enum TravelerType {
     Passenger,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TravelerType {
@@ -20,12 +22,45 @@ impl std::fmt::Display for TravelerType {
             TravelerType::Guest => "GUEST",
             TravelerType::Passenger => "PASSENGER",
             TravelerType::Noop => "",
-            TravelerType::FallthroughString => "*",
+            TravelerType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TravelerType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "GUEST" => TravelerType::Guest,
+            "PASSENGER" => TravelerType::Passenger,
+            "" => TravelerType::Noop,
+            _ => TravelerType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TravelerType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TravelerType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TravelerType {
     fn default() -> TravelerType {
         TravelerType::Noop
@@ -37,7 +72,8 @@ impl TravelerType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BookingStatus {
     #[serde(rename = "ACCEPTED")]
     Accepted,
@@ -51,8 +87,9 @@ pub enum BookingStatus {
     Voided,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BookingStatus {
@@ -64,12 +101,48 @@ impl std::fmt::Display for BookingStatus {
             BookingStatus::Ticketed => "TICKETED",
             BookingStatus::Voided => "VOIDED",
             BookingStatus::Noop => "",
-            BookingStatus::FallthroughString => "*",
+            BookingStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BookingStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACCEPTED" => BookingStatus::Accepted,
+            "CANCELED" => BookingStatus::Canceled,
+            "CONFIRMED" => BookingStatus::Confirmed,
+            "TICKETED" => BookingStatus::Ticketed,
+            "VOIDED" => BookingStatus::Voided,
+            "" => BookingStatus::Noop,
+            _ => BookingStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BookingStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BookingStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BookingStatus {
     fn default() -> BookingStatus {
         BookingStatus::Noop
@@ -81,7 +154,8 @@ impl BookingStatus {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum BookingType {
     #[serde(rename = "BLACK_CAR")]
     BlackCar,
@@ -97,8 +171,9 @@ pub enum BookingType {
     Transportation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for BookingType {
@@ -111,12 +186,49 @@ impl std::fmt::Display for BookingType {
             BookingType::Rail => "RAIL",
             BookingType::Transportation => "TRANSPORTATION",
             BookingType::Noop => "",
-            BookingType::FallthroughString => "*",
+            BookingType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for BookingType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BLACK_CAR" => BookingType::BlackCar,
+            "CAR" => BookingType::Car,
+            "FLIGHT" => BookingType::Flight,
+            "HOTEL" => BookingType::Hotel,
+            "RAIL" => BookingType::Rail,
+            "TRANSPORTATION" => BookingType::Transportation,
+            "" => BookingType::Noop,
+            _ => BookingType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for BookingType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BookingType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for BookingType {
     fn default() -> BookingType {
         BookingType::Noop
@@ -128,7 +240,8 @@ impl BookingType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PaymentSchedule {
     #[serde(rename = "LATER")]
     Later,
@@ -140,8 +253,9 @@ pub enum PaymentSchedule {
     Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PaymentSchedule {
@@ -152,12 +266,47 @@ impl std::fmt::Display for PaymentSchedule {
             PaymentSchedule::Partial => "PARTIAL",
             PaymentSchedule::Unknown => "UNKNOWN",
             PaymentSchedule::Noop => "",
-            PaymentSchedule::FallthroughString => "*",
+            PaymentSchedule::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PaymentSchedule {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "LATER" => PaymentSchedule::Later,
+            "NOW" => PaymentSchedule::Now,
+            "PARTIAL" => PaymentSchedule::Partial,
+            "UNKNOWN" => PaymentSchedule::Unknown,
+            "" => PaymentSchedule::Noop,
+            _ => PaymentSchedule::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PaymentSchedule {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentSchedule {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PaymentSchedule {
     fn default() -> PaymentSchedule {
         PaymentSchedule::Noop
@@ -169,7 +318,8 @@ impl PaymentSchedule {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PassengerStatus {
     #[serde(rename = "DISABLED")]
     Disabled,
@@ -177,8 +327,9 @@ pub enum PassengerStatus {
     Enabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PassengerStatus {
@@ -187,12 +338,45 @@ impl std::fmt::Display for PassengerStatus {
             PassengerStatus::Disabled => "DISABLED",
             PassengerStatus::Enabled => "ENABLED",
             PassengerStatus::Noop => "",
-            PassengerStatus::FallthroughString => "*",
+            PassengerStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PassengerStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DISABLED" => PassengerStatus::Disabled,
+            "ENABLED" => PassengerStatus::Enabled,
+            "" => PassengerStatus::Noop,
+            _ => PassengerStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PassengerStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PassengerStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PassengerStatus {
     fn default() -> PassengerStatus {
         PassengerStatus::Noop
@@ -83,3 +83,24 @@ fn test_deserialize_recorded_meetings() {
         deserialized.from.unwrap()
     );
 }
+
+const SERVER_TO_SERVER_TOKEN: &str = r#"{
+  "access_token": "eyJhbGciOiJIUzUxMiIsInYiOiIyLjAiLCJraWQiOiI",
+  "token_type": "bearer",
+  "expires_in": 3599,
+  "scope": "meeting:read:admin user:read:admin"
+}"#;
+
+#[test]
+fn test_deserialize_server_to_server_token() {
+    let deserialized: crate::AccessToken = serde_json::from_str(SERVER_TO_SERVER_TOKEN).unwrap();
+
+    assert_eq!(deserialized.expires_in, 3599);
+    assert!(deserialized.refresh_token.is_empty());
+    assert_eq!(
+        deserialized.scopes(),
+        vec!["meeting:read:admin", "user:read:admin"]
+    );
+    assert!(deserialized.has_scope("user:read:admin"));
+    assert!(!deserialized.has_scope("user:write:admin"));
+}
@@ -5,14 +5,16 @@ use serde::{Deserialize, Serialize};
 /**
 * Payee:<br>`master` - master account holder pays.<br>`sub` - Sub account holder pays.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PayMode {
     #[serde(rename = "master")]
     Master,
     #[serde(rename = "sub")]
     Sub,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PayMode {
@@ -20,12 +22,44 @@ impl std::fmt::Display for PayMode {
         match self {
             PayMode::Master => "master",
             PayMode::Sub => "sub",
-            PayMode::FallthroughString => "*",
+            PayMode::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PayMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "master" => PayMode::Master,
+            "sub" => PayMode::Sub,
+            _ => PayMode::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PayMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PayMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PayMode {
     fn default() -> PayMode {
         PayMode::Master
@@ -475,7 +509,8 @@ pub struct AccountPlans {
 /**
 * Determine how participants can join the audio portion of the meeting.<br>`both` - Telephony and VoIP.<br>`telephony` - Audio PSTN telephony only.<br>`voip` - VoIP only.<br>`thirdParty` - 3rd party audio conference.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AudioType {
     #[serde(rename = "both")]
     Both,
@@ -485,8 +520,9 @@ pub enum AudioType {
     ThirdParty,
     #[serde(rename = "voip")]
     Voip,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AudioType {
@@ -496,12 +532,46 @@ impl std::fmt::Display for AudioType {
             AudioType::Telephony => "telephony",
             AudioType::ThirdParty => "thirdParty",
             AudioType::Voip => "voip",
-            AudioType::FallthroughString => "*",
+            AudioType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AudioType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "both" => AudioType::Both,
+            "telephony" => AudioType::Telephony,
+            "thirdParty" => AudioType::ThirdParty,
+            "voip" => AudioType::Voip,
+            _ => AudioType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AudioType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AudioType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AudioType {
     fn default() -> AudioType {
         AudioType::Both
@@ -512,7 +582,8 @@ impl Default for AudioType {
 * Require a password for a meeting held using Personal Meeting ID (PMI) This setting is always enabled for free accounts and Pro accounts with a single host and cannot be modified for these accounts.
 *   
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RequirePasswordPmiMeetings {
     #[serde(rename = "all")]
     All,
@@ -522,8 +593,9 @@ pub enum RequirePasswordPmiMeetings {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RequirePasswordPmiMeetings {
@@ -533,12 +605,46 @@ impl std::fmt::Display for RequirePasswordPmiMeetings {
             RequirePasswordPmiMeetings::JbhOnly => "jbh_only",
             RequirePasswordPmiMeetings::None => "none",
             RequirePasswordPmiMeetings::Noop => "",
-            RequirePasswordPmiMeetings::FallthroughString => "*",
+            RequirePasswordPmiMeetings::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RequirePasswordPmiMeetings {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => RequirePasswordPmiMeetings::All,
+            "jbh_only" => RequirePasswordPmiMeetings::JbhOnly,
+            "none" => RequirePasswordPmiMeetings::None,
+            "" => RequirePasswordPmiMeetings::Noop,
+            _ => RequirePasswordPmiMeetings::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RequirePasswordPmiMeetings {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RequirePasswordPmiMeetings {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RequirePasswordPmiMeetings {
     fn default() -> RequirePasswordPmiMeetings {
         RequirePasswordPmiMeetings::Noop
@@ -766,7 +872,8 @@ pub struct ScheduleMeeting {
 /**
 * Play sound when participants join or leave.<br>`host` - Heard by host only.<br>`all` - Heard by host and all attendees.<br>`none` - Disable.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EntryExitChime {
     #[serde(rename = "all")]
     All,
@@ -776,8 +883,9 @@ pub enum EntryExitChime {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EntryExitChime {
@@ -787,12 +895,46 @@ impl std::fmt::Display for EntryExitChime {
             EntryExitChime::Host => "host",
             EntryExitChime::None => "none",
             EntryExitChime::Noop => "",
-            EntryExitChime::FallthroughString => "*",
+            EntryExitChime::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EntryExitChime {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => EntryExitChime::All,
+            "host" => EntryExitChime::Host,
+            "none" => EntryExitChime::None,
+            "" => EntryExitChime::Noop,
+            _ => EntryExitChime::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EntryExitChime {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EntryExitChime {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EntryExitChime {
     fn default() -> EntryExitChime {
         EntryExitChime::Noop
@@ -897,7 +1039,8 @@ pub struct VirtualBackgroundSettings {
 *   `all`: Both hosts and attendees can share their screen during meetings. For Webinar, the hosts and panelists can start screen sharing, but not the attendees.
 *   
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WhoCanShareScreen {
     #[serde(rename = "all")]
     All,
@@ -905,8 +1048,9 @@ pub enum WhoCanShareScreen {
     Host,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WhoCanShareScreen {
@@ -915,12 +1059,45 @@ impl std::fmt::Display for WhoCanShareScreen {
             WhoCanShareScreen::All => "all",
             WhoCanShareScreen::Host => "host",
             WhoCanShareScreen::Noop => "",
-            WhoCanShareScreen::FallthroughString => "*",
+            WhoCanShareScreen::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WhoCanShareScreen {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => WhoCanShareScreen::All,
+            "host" => WhoCanShareScreen::Host,
+            "" => WhoCanShareScreen::Noop,
+            _ => WhoCanShareScreen::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WhoCanShareScreen {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WhoCanShareScreen {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WhoCanShareScreen {
     fn default() -> WhoCanShareScreen {
         WhoCanShareScreen::Noop
@@ -1543,7 +1720,8 @@ pub struct PasswordRequirement {
 *   `sign_in_with_two_factor_auth_roles` field.
 *   
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SignInWithTwoFactorAuth {
     #[serde(rename = "all")]
     All,
@@ -1555,8 +1733,9 @@ pub enum SignInWithTwoFactorAuth {
     Role,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SignInWithTwoFactorAuth {
@@ -1567,12 +1746,47 @@ impl std::fmt::Display for SignInWithTwoFactorAuth {
             SignInWithTwoFactorAuth::None => "none",
             SignInWithTwoFactorAuth::Role => "role",
             SignInWithTwoFactorAuth::Noop => "",
-            SignInWithTwoFactorAuth::FallthroughString => "*",
+            SignInWithTwoFactorAuth::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SignInWithTwoFactorAuth {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => SignInWithTwoFactorAuth::All,
+            "group" => SignInWithTwoFactorAuth::Group,
+            "none" => SignInWithTwoFactorAuth::None,
+            "role" => SignInWithTwoFactorAuth::Role,
+            "" => SignInWithTwoFactorAuth::Noop,
+            _ => SignInWithTwoFactorAuth::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SignInWithTwoFactorAuth {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SignInWithTwoFactorAuth {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SignInWithTwoFactorAuth {
     fn default() -> SignInWithTwoFactorAuth {
         SignInWithTwoFactorAuth::Noop
@@ -1662,7 +1876,8 @@ pub struct Security {
 /**
 * Automatic recording:<br>`local` - Record on local.<br>`cloud` -  Record on cloud.<br>`none` - Disabled.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AutoRecording {
     #[serde(rename = "cloud")]
     Cloud,
@@ -1672,8 +1887,9 @@ pub enum AutoRecording {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AutoRecording {
@@ -1683,12 +1899,46 @@ impl std::fmt::Display for AutoRecording {
             AutoRecording::Local => "local",
             AutoRecording::None => "none",
             AutoRecording::Noop => "",
-            AutoRecording::FallthroughString => "*",
+            AutoRecording::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AutoRecording {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cloud" => AutoRecording::Cloud,
+            "local" => AutoRecording::Local,
+            "none" => AutoRecording::None,
+            "" => AutoRecording::Noop,
+            _ => AutoRecording::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AutoRecording {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AutoRecording {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AutoRecording {
     fn default() -> AutoRecording {
         AutoRecording::Noop
@@ -2563,7 +2813,8 @@ pub struct DateTime {
 /**
 * Device protocol:<br>`H.323` - H.323.<br>`SIP` - SIP.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Protocol {
     #[serde(rename = "H.323")]
     H323,
@@ -2571,8 +2822,9 @@ pub enum Protocol {
     Sip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Protocol {
@@ -2581,12 +2833,45 @@ impl std::fmt::Display for Protocol {
             Protocol::H323 => "H.323",
             Protocol::Sip => "SIP",
             Protocol::Noop => "",
-            Protocol::FallthroughString => "*",
+            Protocol::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Protocol {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "H.323" => Protocol::H323,
+            "SIP" => Protocol::Sip,
+            "" => Protocol::Noop,
+            _ => Protocol::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Protocol {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Protocol {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Protocol {
     fn default() -> Protocol {
         Protocol::Noop
@@ -2601,7 +2886,8 @@ impl Protocol {
 /**
 * Device encryption:<br>`auto` - auto.<br>`yes` - yes.<br>`no` - no.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Encryption {
     #[serde(rename = "auto")]
     Auto,
@@ -2611,8 +2897,9 @@ pub enum Encryption {
     Yes,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Encryption {
@@ -2622,12 +2909,46 @@ impl std::fmt::Display for Encryption {
             Encryption::No => "no",
             Encryption::Yes => "yes",
             Encryption::Noop => "",
-            Encryption::FallthroughString => "*",
+            Encryption::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Encryption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "auto" => Encryption::Auto,
+            "no" => Encryption::No,
+            "yes" => Encryption::Yes,
+            "" => Encryption::Noop,
+            _ => Encryption::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Encryption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Encryption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Encryption {
     fn default() -> Encryption {
         Encryption::Noop
@@ -2872,7 +3193,8 @@ pub struct Occurrence {
 /**
 * Determine how participants can join the audio portion of the meeting.<br>`both` - Both Telephony and VoIP.<br>`telephony` - Telephony only.<br>`voip` - VoIP only.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Audio {
     #[serde(rename = "both")]
     Both,
@@ -2880,8 +3202,9 @@ pub enum Audio {
     Telephony,
     #[serde(rename = "voip")]
     Voip,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Audio {
@@ -2890,12 +3213,45 @@ impl std::fmt::Display for Audio {
             Audio::Both => "both",
             Audio::Telephony => "telephony",
             Audio::Voip => "voip",
-            Audio::FallthroughString => "*",
+            Audio::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Audio {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "both" => Audio::Both,
+            "telephony" => Audio::Telephony,
+            "voip" => Audio::Voip,
+            _ => Audio::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Audio {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Audio {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Audio {
     fn default() -> Audio {
         Audio::Both
@@ -2905,7 +3261,8 @@ impl Default for Audio {
 /**
 * Type of number.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Type {
     #[serde(rename = "toll")]
     Toll,
@@ -2913,8 +3270,9 @@ pub enum Type {
     Tollfree,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Type {
@@ -2923,12 +3281,45 @@ impl std::fmt::Display for Type {
             Type::Toll => "toll",
             Type::Tollfree => "tollfree",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "toll" => Type::Toll,
+            "tollfree" => Type::Tollfree,
+            "" => Type::Noop,
+            _ => Type::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -2991,7 +3382,8 @@ pub struct GlobalDialInNumbers {
 *   
 *   `e2ee`: [End-to-end encryption](https://support.zoom.us/hc/en-us/articles/360048660871). The encryption key is stored in your local device and can not be obtained by anyone else. Enabling this setting also **disables** the following features: join before host, cloud recording, streaming, live transcription, breakout rooms, polling, 1:1 private chat, and meeting reactions.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum EncryptionType {
     #[serde(rename = "e2ee")]
     E2Ee,
@@ -2999,8 +3391,9 @@ pub enum EncryptionType {
     EnhancedEncryption,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for EncryptionType {
@@ -3009,12 +3402,45 @@ impl std::fmt::Display for EncryptionType {
             EncryptionType::E2Ee => "e2ee",
             EncryptionType::EnhancedEncryption => "enhanced_encryption",
             EncryptionType::Noop => "",
-            EncryptionType::FallthroughString => "*",
+            EncryptionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for EncryptionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "e2ee" => EncryptionType::E2Ee,
+            "enhanced_encryption" => EncryptionType::EnhancedEncryption,
+            "" => EncryptionType::Noop,
+            _ => EncryptionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for EncryptionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EncryptionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for EncryptionType {
     fn default() -> EncryptionType {
         EncryptionType::Noop
@@ -3031,7 +3457,8 @@ impl EncryptionType {
 *   `approve`: Allow users from specific regions/countries to join this meeting. If this setting is selected, the approved regions/countries must be included in the `approved_list`.<br><br>
 *   `deny`: Block users from specific regions/countries from joining this meeting. If this setting is selected, the approved regions/countries must be included in the `denied_list`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Method {
     #[serde(rename = "approve")]
     Approve,
@@ -3039,8 +3466,9 @@ pub enum Method {
     Deny,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Method {
@@ -3049,12 +3477,45 @@ impl std::fmt::Display for Method {
             Method::Approve => "approve",
             Method::Deny => "deny",
             Method::Noop => "",
-            Method::FallthroughString => "*",
+            Method::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Method {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approve" => Method::Approve,
+            "deny" => Method::Deny,
+            "" => Method::Noop,
+            _ => Method::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Method {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Method {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Method {
     fn default() -> Method {
         Method::Noop
@@ -3543,7 +4004,8 @@ pub struct MeetingSettings {
 *   
 *    <br>`1`  - Sunday. <br>`2` - Monday.<br>`3` - Tuesday.<br>`4` -  Wednesday.<br>`5` -  Thursday.<br>`6` - Friday.<br>`7` - Saturday.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WeeklyDays {
     #[serde(rename = "1")]
     One,
@@ -3559,8 +4021,9 @@ pub enum WeeklyDays {
     Six,
     #[serde(rename = "7")]
     Seven,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WeeklyDays {
@@ -3573,12 +4036,49 @@ impl std::fmt::Display for WeeklyDays {
             WeeklyDays::Five => "5",
             WeeklyDays::Six => "6",
             WeeklyDays::Seven => "7",
-            WeeklyDays::FallthroughString => "*",
+            WeeklyDays::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WeeklyDays {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "1" => WeeklyDays::One,
+            "2" => WeeklyDays::Two,
+            "3" => WeeklyDays::Three,
+            "4" => WeeklyDays::Four,
+            "5" => WeeklyDays::Five,
+            "6" => WeeklyDays::Six,
+            "7" => WeeklyDays::Seven,
+            _ => WeeklyDays::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WeeklyDays {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WeeklyDays {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WeeklyDays {
     fn default() -> WeeklyDays {
         WeeklyDays::One
@@ -3813,7 +4313,8 @@ pub struct MeetingInfo {
 /**
 * Meeting status
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Status {
     #[serde(rename = "started")]
     Started,
@@ -3821,8 +4322,9 @@ pub enum Status {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Status {
@@ -3831,12 +4333,45 @@ impl std::fmt::Display for Status {
             Status::Started => "started",
             Status::Waiting => "waiting",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "started" => Status::Started,
+            "waiting" => Status::Waiting,
+            "" => Status::Noop,
+            _ => Status::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -4244,7 +4779,8 @@ pub struct MeetingLiveStream {
 *   `start`: Start a live stream. <br>
 *   `stop`: Stop an ongoing live stream.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Action {
     #[serde(rename = "start")]
     Start,
@@ -4252,8 +4788,9 @@ pub enum Action {
     Stop,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Action {
@@ -4262,12 +4799,45 @@ impl std::fmt::Display for Action {
             Action::Start => "start",
             Action::Stop => "stop",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Action {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "start" => Action::Start,
+            "stop" => Action::Stop,
+            "" => Action::Noop,
+            _ => Action::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Action {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Action {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Action {
     fn default() -> Action {
         Action::Noop
@@ -4598,14 +5168,16 @@ pub struct QosAudio {
 /**
 * The recording status.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RecordingStatus {
     #[serde(rename = "completed")]
     Completed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RecordingStatus {
@@ -4613,12 +5185,44 @@ impl std::fmt::Display for RecordingStatus {
         match self {
             RecordingStatus::Completed => "completed",
             RecordingStatus::Noop => "",
-            RecordingStatus::FallthroughString => "*",
+            RecordingStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RecordingStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "completed" => RecordingStatus::Completed,
+            "" => RecordingStatus::Noop,
+            _ => RecordingStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RecordingStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RecordingStatus {
     fn default() -> RecordingStatus {
         RecordingStatus::Noop
@@ -4815,7 +5419,8 @@ pub struct RecordingSeparateAudio {
 /**
 * Determine how the meeting recording is shared.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ShareRecording {
     #[serde(rename = "internally")]
     Internally,
@@ -4825,8 +5430,9 @@ pub enum ShareRecording {
     Publicly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ShareRecording {
@@ -4836,12 +5442,46 @@ impl std::fmt::Display for ShareRecording {
             ShareRecording::None => "none",
             ShareRecording::Publicly => "publicly",
             ShareRecording::Noop => "",
-            ShareRecording::FallthroughString => "*",
+            ShareRecording::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ShareRecording {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "internally" => ShareRecording::Internally,
+            "none" => ShareRecording::None,
+            "publicly" => ShareRecording::Publicly,
+            "" => ShareRecording::Noop,
+            _ => ShareRecording::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ShareRecording {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ShareRecording {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ShareRecording {
     fn default() -> ShareRecording {
         ShareRecording::Noop
@@ -5096,7 +5736,8 @@ pub struct Registrant {
 /**
 * Registrant Status:<br>`approve` - Approve registrant.<br>`cancel` - Cancel previously approved registrant's registration.<br>`deny` - Deny registrant.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RegistrantStatusAction {
     #[serde(rename = "approve")]
     Approve,
@@ -5106,8 +5747,9 @@ pub enum RegistrantStatusAction {
     Deny,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RegistrantStatusAction {
@@ -5117,12 +5759,46 @@ impl std::fmt::Display for RegistrantStatusAction {
             RegistrantStatusAction::Cancel => "cancel",
             RegistrantStatusAction::Deny => "deny",
             RegistrantStatusAction::Noop => "",
-            RegistrantStatusAction::FallthroughString => "*",
+            RegistrantStatusAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RegistrantStatusAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approve" => RegistrantStatusAction::Approve,
+            "cancel" => RegistrantStatusAction::Cancel,
+            "deny" => RegistrantStatusAction::Deny,
+            "" => RegistrantStatusAction::Noop,
+            _ => RegistrantStatusAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RegistrantStatusAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RegistrantStatusAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RegistrantStatusAction {
     fn default() -> RegistrantStatusAction {
         RegistrantStatusAction::Noop
@@ -5575,7 +6251,8 @@ pub struct RecurrenceWebinar {
 *   
 *   *  `all`: Attendees are able to view all questions submitted in the Q&A.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AnswerQuestions {
     #[serde(rename = "all")]
     All,
@@ -5583,8 +6260,9 @@ pub enum AnswerQuestions {
     Only,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AnswerQuestions {
@@ -5593,12 +6271,45 @@ impl std::fmt::Display for AnswerQuestions {
             AnswerQuestions::All => "all",
             AnswerQuestions::Only => "only",
             AnswerQuestions::Noop => "",
-            AnswerQuestions::FallthroughString => "*",
+            AnswerQuestions::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AnswerQuestions {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => AnswerQuestions::All,
+            "only" => AnswerQuestions::Only,
+            "" => AnswerQuestions::Noop,
+            _ => AnswerQuestions::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AnswerQuestions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AnswerQuestions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AnswerQuestions {
     fn default() -> AnswerQuestions {
         AnswerQuestions::Noop
@@ -6190,7 +6901,8 @@ pub struct SessionWebinarUpdate {
 * Dial-in number types:<br>`toll` - Toll number.<br>`tollfree` -Toll free number.<br>
 *   `media_link` - Media link.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TspAccountsListDialInNumbersType {
     #[serde(rename = "media_link")]
     MediaLink,
@@ -6200,8 +6912,9 @@ pub enum TspAccountsListDialInNumbersType {
     Tollfree,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TspAccountsListDialInNumbersType {
@@ -6211,12 +6924,46 @@ impl std::fmt::Display for TspAccountsListDialInNumbersType {
             TspAccountsListDialInNumbersType::Toll => "toll",
             TspAccountsListDialInNumbersType::Tollfree => "tollfree",
             TspAccountsListDialInNumbersType::Noop => "",
-            TspAccountsListDialInNumbersType::FallthroughString => "*",
+            TspAccountsListDialInNumbersType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TspAccountsListDialInNumbersType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "media_link" => TspAccountsListDialInNumbersType::MediaLink,
+            "toll" => TspAccountsListDialInNumbersType::Toll,
+            "tollfree" => TspAccountsListDialInNumbersType::Tollfree,
+            "" => TspAccountsListDialInNumbersType::Noop,
+            _ => TspAccountsListDialInNumbersType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TspAccountsListDialInNumbersType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TspAccountsListDialInNumbersType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TspAccountsListDialInNumbersType {
     fn default() -> TspAccountsListDialInNumbersType {
         TspAccountsListDialInNumbersType::Noop
@@ -6268,7 +7015,8 @@ pub struct DialInNumbers {
 /**
 * Telephony bridge
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TspBridge {
     #[serde(rename = "EU_TSP_TB")]
     EuTspTb,
@@ -6276,8 +7024,9 @@ pub enum TspBridge {
     UsTspTb,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TspBridge {
@@ -6286,12 +7035,45 @@ impl std::fmt::Display for TspBridge {
             TspBridge::EuTspTb => "EU_TSP_TB",
             TspBridge::UsTspTb => "US_TSP_TB",
             TspBridge::Noop => "",
-            TspBridge::FallthroughString => "*",
+            TspBridge::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TspBridge {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EU_TSP_TB" => TspBridge::EuTspTb,
+            "US_TSP_TB" => TspBridge::UsTspTb,
+            "" => TspBridge::Noop,
+            _ => TspBridge::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TspBridge {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TspBridge {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TspBridge {
     fn default() -> TspBridge {
         TspBridge::Noop
@@ -6565,7 +7347,8 @@ pub struct CustomAttributes {
 *   * `Home`
 *   * `Fax`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Label {
     #[serde(rename = "Fax")]
     Fax,
@@ -6577,8 +7360,9 @@ pub enum Label {
     Office,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Label {
@@ -6589,12 +7373,47 @@ impl std::fmt::Display for Label {
             Label::Mobile => "Mobile",
             Label::Office => "Office",
             Label::Noop => "",
-            Label::FallthroughString => "*",
+            Label::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Label {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Fax" => Label::Fax,
+            "Home" => Label::Home,
+            "Mobile" => Label::Mobile,
+            "Office" => Label::Office,
+            "" => Label::Noop,
+            _ => Label::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Label {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Label {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Label {
     fn default() -> Label {
         Label::Noop
@@ -8335,7 +9154,8 @@ pub struct ZoomRoom {
 /**
 * Poll Question & Answer type:<br>`single` - Single choice<br>`mutliple` - Multiple choice
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PollQuestionsType {
     #[serde(rename = "multiple")]
     Multiple,
@@ -8343,8 +9163,9 @@ pub enum PollQuestionsType {
     Single,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PollQuestionsType {
@@ -8353,12 +9174,45 @@ impl std::fmt::Display for PollQuestionsType {
             PollQuestionsType::Multiple => "multiple",
             PollQuestionsType::Single => "single",
             PollQuestionsType::Noop => "",
-            PollQuestionsType::FallthroughString => "*",
+            PollQuestionsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PollQuestionsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "multiple" => PollQuestionsType::Multiple,
+            "single" => PollQuestionsType::Single,
+            "" => PollQuestionsType::Noop,
+            _ => PollQuestionsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PollQuestionsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PollQuestionsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PollQuestionsType {
     fn default() -> PollQuestionsType {
         PollQuestionsType::Noop
@@ -8426,7 +9280,8 @@ pub struct Poll {
 /**
 * Field name of the question.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FieldName {
     #[serde(rename = "address")]
     Address,
@@ -8456,8 +9311,9 @@ pub enum FieldName {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FieldName {
@@ -8477,12 +9333,56 @@ impl std::fmt::Display for FieldName {
             FieldName::State => "state",
             FieldName::Zip => "zip",
             FieldName::Noop => "",
-            FieldName::FallthroughString => "*",
+            FieldName::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FieldName {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "address" => FieldName::Address,
+            "city" => FieldName::City,
+            "comments" => FieldName::Comments,
+            "country" => FieldName::Country,
+            "industry" => FieldName::Industry,
+            "job_title" => FieldName::JobTitle,
+            "no_of_employees" => FieldName::NoOfEmployees,
+            "org" => FieldName::Org,
+            "phone" => FieldName::Phone,
+            "purchasing_time_frame" => FieldName::PurchasingTimeFrame,
+            "role_in_purchase_process" => FieldName::RoleInPurchaseProcess,
+            "state" => FieldName::State,
+            "zip" => FieldName::Zip,
+            "" => FieldName::Noop,
+            _ => FieldName::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FieldName {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldName {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FieldName {
     fn default() -> FieldName {
         FieldName::Noop
@@ -8514,7 +9414,8 @@ pub struct MeetingRegistrantQuestions {
 /**
 * Type of the question being asked.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MeetingRegistrantQuestionsCustomType {
     #[serde(rename = "short")]
     Short,
@@ -8522,8 +9423,9 @@ pub enum MeetingRegistrantQuestionsCustomType {
     Single,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MeetingRegistrantQuestionsCustomType {
@@ -8532,12 +9434,45 @@ impl std::fmt::Display for MeetingRegistrantQuestionsCustomType {
             MeetingRegistrantQuestionsCustomType::Short => "short",
             MeetingRegistrantQuestionsCustomType::Single => "single",
             MeetingRegistrantQuestionsCustomType::Noop => "",
-            MeetingRegistrantQuestionsCustomType::FallthroughString => "*",
+            MeetingRegistrantQuestionsCustomType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MeetingRegistrantQuestionsCustomType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "short" => MeetingRegistrantQuestionsCustomType::Short,
+            "single" => MeetingRegistrantQuestionsCustomType::Single,
+            "" => MeetingRegistrantQuestionsCustomType::Noop,
+            _ => MeetingRegistrantQuestionsCustomType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MeetingRegistrantQuestionsCustomType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MeetingRegistrantQuestionsCustomType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MeetingRegistrantQuestionsCustomType {
     fn default() -> MeetingRegistrantQuestionsCustomType {
         MeetingRegistrantQuestionsCustomType::Noop
@@ -8636,7 +9571,8 @@ pub struct WebinarRegistrantQuestions {
 /**
 * Field name.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RecordingRegistrantQuestionsFieldName {
     #[serde(rename = "address")]
     Address,
@@ -8668,8 +9604,9 @@ pub enum RecordingRegistrantQuestionsFieldName {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RecordingRegistrantQuestionsFieldName {
@@ -8692,12 +9629,59 @@ impl std::fmt::Display for RecordingRegistrantQuestionsFieldName {
             RecordingRegistrantQuestionsFieldName::State => "state",
             RecordingRegistrantQuestionsFieldName::Zip => "zip",
             RecordingRegistrantQuestionsFieldName::Noop => "",
-            RecordingRegistrantQuestionsFieldName::FallthroughString => "*",
+            RecordingRegistrantQuestionsFieldName::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RecordingRegistrantQuestionsFieldName {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "address" => RecordingRegistrantQuestionsFieldName::Address,
+            "city" => RecordingRegistrantQuestionsFieldName::City,
+            "comments" => RecordingRegistrantQuestionsFieldName::Comments,
+            "country" => RecordingRegistrantQuestionsFieldName::Country,
+            "industry" => RecordingRegistrantQuestionsFieldName::Industry,
+            "job_title" => RecordingRegistrantQuestionsFieldName::JobTitle,
+            "last_name" => RecordingRegistrantQuestionsFieldName::LastName,
+            "no_of_employees" => RecordingRegistrantQuestionsFieldName::NoOfEmployees,
+            "org" => RecordingRegistrantQuestionsFieldName::Org,
+            "phone" => RecordingRegistrantQuestionsFieldName::Phone,
+            "purchasing_time_frame" => RecordingRegistrantQuestionsFieldName::PurchasingTimeFrame,
+            "role_in_purchase_process" => {
+                RecordingRegistrantQuestionsFieldName::RoleInPurchaseProcess
+            }
+            "state" => RecordingRegistrantQuestionsFieldName::State,
+            "zip" => RecordingRegistrantQuestionsFieldName::Zip,
+            "" => RecordingRegistrantQuestionsFieldName::Noop,
+            _ => RecordingRegistrantQuestionsFieldName::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RecordingRegistrantQuestionsFieldName {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingRegistrantQuestionsFieldName {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RecordingRegistrantQuestionsFieldName {
     fn default() -> RecordingRegistrantQuestionsFieldName {
         RecordingRegistrantQuestionsFieldName::Noop
@@ -8729,7 +9713,8 @@ pub struct RecordingRegistrantQuestions {
 /**
 * The type of registration question and answers.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RecordingRegistrantQuestionsCustomType {
     #[serde(rename = "multiple")]
     Multiple,
@@ -8739,8 +9724,9 @@ pub enum RecordingRegistrantQuestionsCustomType {
     Single,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RecordingRegistrantQuestionsCustomType {
@@ -8750,12 +9736,46 @@ impl std::fmt::Display for RecordingRegistrantQuestionsCustomType {
             RecordingRegistrantQuestionsCustomType::Short => "short",
             RecordingRegistrantQuestionsCustomType::Single => "single",
             RecordingRegistrantQuestionsCustomType::Noop => "",
-            RecordingRegistrantQuestionsCustomType::FallthroughString => "*",
+            RecordingRegistrantQuestionsCustomType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RecordingRegistrantQuestionsCustomType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "multiple" => RecordingRegistrantQuestionsCustomType::Multiple,
+            "short" => RecordingRegistrantQuestionsCustomType::Short,
+            "single" => RecordingRegistrantQuestionsCustomType::Single,
+            "" => RecordingRegistrantQuestionsCustomType::Noop,
+            _ => RecordingRegistrantQuestionsCustomType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RecordingRegistrantQuestionsCustomType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingRegistrantQuestionsCustomType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RecordingRegistrantQuestionsCustomType {
     fn default() -> RecordingRegistrantQuestionsCustomType {
         RecordingRegistrantQuestionsCustomType::Noop
@@ -10247,7 +11267,8 @@ pub struct ParticipantQos {
     pub version: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AccountSettingsRecordingAuthenticationUpdateOptionType {
     #[serde(rename = "enforce_login")]
     EnforceLogin,
@@ -10257,8 +11278,9 @@ pub enum AccountSettingsRecordingAuthenticationUpdateOptionType {
     Internally,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionType {
@@ -10270,12 +11292,50 @@ impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionTyp
             }
             AccountSettingsRecordingAuthenticationUpdateOptionType::Internally => "internally",
             AccountSettingsRecordingAuthenticationUpdateOptionType::Noop => "",
-            AccountSettingsRecordingAuthenticationUpdateOptionType::FallthroughString => "*",
+            AccountSettingsRecordingAuthenticationUpdateOptionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AccountSettingsRecordingAuthenticationUpdateOptionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "enforce_login" => AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLogin,
+            "enforce_login_with_domains" => {
+                AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLoginWithDomains
+            }
+            "internally" => AccountSettingsRecordingAuthenticationUpdateOptionType::Internally,
+            "" => AccountSettingsRecordingAuthenticationUpdateOptionType::Noop,
+            _ => {
+                AccountSettingsRecordingAuthenticationUpdateOptionType::UnknownValue(s.to_string())
+            }
+        })
+    }
+}
+
+impl Serialize for AccountSettingsRecordingAuthenticationUpdateOptionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountSettingsRecordingAuthenticationUpdateOptionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AccountSettingsRecordingAuthenticationUpdateOptionType {
     fn default() -> AccountSettingsRecordingAuthenticationUpdateOptionType {
         AccountSettingsRecordingAuthenticationUpdateOptionType::Noop
@@ -10290,7 +11350,8 @@ impl AccountSettingsRecordingAuthenticationUpdateOptionType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AccountSettingsRecordingAuthenticationUpdateOptionAction {
     #[serde(rename = "add")]
     Add,
@@ -10300,8 +11361,9 @@ pub enum AccountSettingsRecordingAuthenticationUpdateOptionAction {
     Update,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionAction {
@@ -10311,12 +11373,48 @@ impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionAct
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Delete => "delete",
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Update => "update",
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop => "",
-            AccountSettingsRecordingAuthenticationUpdateOptionAction::FallthroughString => "*",
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AccountSettingsRecordingAuthenticationUpdateOptionAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "add" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Add,
+            "delete" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Delete,
+            "update" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Update,
+            "" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop,
+            _ => AccountSettingsRecordingAuthenticationUpdateOptionAction::UnknownValue(
+                s.to_string(),
+            ),
+        })
+    }
+}
+
+impl Serialize for AccountSettingsRecordingAuthenticationUpdateOptionAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountSettingsRecordingAuthenticationUpdateOptionAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AccountSettingsRecordingAuthenticationUpdateOptionAction {
     fn default() -> AccountSettingsRecordingAuthenticationUpdateOptionAction {
         AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop
@@ -10883,7 +11981,8 @@ pub struct Metrics {
 *   
 *   For more information, read our [Managing and sharing cloud recordings](https://support.zoom.us/hc/en-us/articles/205347605-Managing-and-sharing-cloud-recordings#h_9898497b-e736-4980-a749-d55608f10773) documentation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum FileType {
     #[serde(rename = "CC")]
     Cc,
@@ -10899,8 +11998,9 @@ pub enum FileType {
     Transcript,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -10913,12 +12013,49 @@ impl std::fmt::Display for FileType {
             FileType::Mp4 => "MP4",
             FileType::Transcript => "TRANSCRIPT",
             FileType::Noop => "",
-            FileType::FallthroughString => "*",
+            FileType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for FileType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CC" => FileType::Cc,
+            "CHAT" => FileType::Chat,
+            "CSV" => FileType::Csv,
+            "M4A" => FileType::M4A,
+            "MP4" => FileType::Mp4,
+            "TRANSCRIPT" => FileType::Transcript,
+            "" => FileType::Noop,
+            _ => FileType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for FileType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FileType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for FileType {
     fn default() -> FileType {
         FileType::Noop
@@ -10946,7 +12083,8 @@ impl FileType {
 *   
 *   For more information, read our [Managing and sharing cloud recordings](https://support.zoom.us/hc/en-us/articles/205347605-Managing-and-sharing-cloud-recordings#h_9898497b-e736-4980-a749-d55608f10773) documentation.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RecordingType {
     #[serde(rename = "active_speaker")]
     ActiveSpeaker,
@@ -10972,8 +12110,9 @@ pub enum RecordingType {
     SpeakerView,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RecordingType {
@@ -10991,12 +12130,54 @@ impl std::fmt::Display for RecordingType {
             RecordingType::SharedScreenWithSpeakerViewCc => "shared_screen_with_speaker_view(CC)",
             RecordingType::SpeakerView => "speaker_view",
             RecordingType::Noop => "",
-            RecordingType::FallthroughString => "*",
+            RecordingType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RecordingType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active_speaker" => RecordingType::ActiveSpeaker,
+            "audio_only" => RecordingType::AudioOnly,
+            "audio_transcript" => RecordingType::AudioTranscript,
+            "chat_file" => RecordingType::ChatFile,
+            "gallery_view" => RecordingType::GalleryView,
+            "host_video" => RecordingType::HostVideo,
+            "shared_screen" => RecordingType::SharedScreen,
+            "shared_screen_with_gallery_view" => RecordingType::SharedScreenWithGalleryView,
+            "shared_screen_with_speaker_view" => RecordingType::SharedScreenWithSpeakerView,
+            "shared_screen_with_speaker_view(CC)" => RecordingType::SharedScreenWithSpeakerViewCc,
+            "speaker_view" => RecordingType::SpeakerView,
+            "" => RecordingType::Noop,
+            _ => RecordingType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RecordingType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RecordingType {
     fn default() -> RecordingType {
         RecordingType::Noop
@@ -11264,14 +12445,16 @@ pub struct InviteLinks {
     pub attendees: Vec<InviteLinksAttendees>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RecordingStatusUpdateBodyRequestAction {
     #[serde(rename = "recover")]
     Recover,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RecordingStatusUpdateBodyRequestAction {
@@ -11279,12 +12462,44 @@ impl std::fmt::Display for RecordingStatusUpdateBodyRequestAction {
         match self {
             RecordingStatusUpdateBodyRequestAction::Recover => "recover",
             RecordingStatusUpdateBodyRequestAction::Noop => "",
-            RecordingStatusUpdateBodyRequestAction::FallthroughString => "*",
+            RecordingStatusUpdateBodyRequestAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RecordingStatusUpdateBodyRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "recover" => RecordingStatusUpdateBodyRequestAction::Recover,
+            "" => RecordingStatusUpdateBodyRequestAction::Noop,
+            _ => RecordingStatusUpdateBodyRequestAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RecordingStatusUpdateBodyRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingStatusUpdateBodyRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RecordingStatusUpdateBodyRequestAction {
     fn default() -> RecordingStatusUpdateBodyRequestAction {
         RecordingStatusUpdateBodyRequestAction::Noop
@@ -11401,7 +12616,8 @@ pub struct SetUpAccountRequest {
 *   `all`: Include both assigned and unassigned numbers in the response.<br>
 *   `byoc`: Include Bring Your Own Carrier (BYOC) numbers only in the response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ListAccountPhoneNumbersType {
     #[serde(rename = "all")]
     All,
@@ -11411,8 +12627,9 @@ pub enum ListAccountPhoneNumbersType {
     Unassigned,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ListAccountPhoneNumbersType {
@@ -11422,12 +12639,46 @@ impl std::fmt::Display for ListAccountPhoneNumbersType {
             ListAccountPhoneNumbersType::Assigned => "assigned",
             ListAccountPhoneNumbersType::Unassigned => "unassigned",
             ListAccountPhoneNumbersType::Noop => "",
-            ListAccountPhoneNumbersType::FallthroughString => "*",
+            ListAccountPhoneNumbersType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ListAccountPhoneNumbersType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ListAccountPhoneNumbersType::All,
+            "assigned" => ListAccountPhoneNumbersType::Assigned,
+            "unassigned" => ListAccountPhoneNumbersType::Unassigned,
+            "" => ListAccountPhoneNumbersType::Noop,
+            _ => ListAccountPhoneNumbersType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ListAccountPhoneNumbersType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListAccountPhoneNumbersType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ListAccountPhoneNumbersType {
     fn default() -> ListAccountPhoneNumbersType {
         ListAccountPhoneNumbersType::Noop
@@ -11444,7 +12695,8 @@ impl ListAccountPhoneNumbersType {
 *   `user`<br> `callQueue`<br> `autoReceptionist`<br>
 *   `commonAreaPhone`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ExtensionType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -11456,8 +12708,9 @@ pub enum ExtensionType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ExtensionType {
@@ -11468,12 +12721,47 @@ impl std::fmt::Display for ExtensionType {
             ExtensionType::CommonAreaPhone => "commonAreaPhone",
             ExtensionType::User => "user",
             ExtensionType::Noop => "",
-            ExtensionType::FallthroughString => "*",
+            ExtensionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ExtensionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "autoReceptionist" => ExtensionType::AutoReceptionist,
+            "callQueue" => ExtensionType::CallQueue,
+            "commonAreaPhone" => ExtensionType::CommonAreaPhone,
+            "user" => ExtensionType::User,
+            "" => ExtensionType::Noop,
+            _ => ExtensionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ExtensionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ExtensionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ExtensionType {
     fn default() -> ExtensionType {
         ExtensionType::Noop
@@ -11488,7 +12776,8 @@ impl ExtensionType {
 /**
 * Source of phone number.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Source {
     #[serde(rename = "external")]
     External,
@@ -11496,8 +12785,9 @@ pub enum Source {
     Internal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Source {
@@ -11506,12 +12796,45 @@ impl std::fmt::Display for Source {
             Source::External => "external",
             Source::Internal => "internal",
             Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Source {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "external" => Source::External,
+            "internal" => Source::Internal,
+            "" => Source::Noop,
+            _ => Source::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Source {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Source {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Source {
     fn default() -> Source {
         Source::Noop
@@ -11526,7 +12849,8 @@ impl Source {
 /**
 * Status of the number.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ListAccountPhoneNumbersResponseStatus {
     #[serde(rename = "available")]
     Available,
@@ -11534,8 +12858,9 @@ pub enum ListAccountPhoneNumbersResponseStatus {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ListAccountPhoneNumbersResponseStatus {
@@ -11544,12 +12869,45 @@ impl std::fmt::Display for ListAccountPhoneNumbersResponseStatus {
             ListAccountPhoneNumbersResponseStatus::Available => "available",
             ListAccountPhoneNumbersResponseStatus::Pending => "pending",
             ListAccountPhoneNumbersResponseStatus::Noop => "",
-            ListAccountPhoneNumbersResponseStatus::FallthroughString => "*",
+            ListAccountPhoneNumbersResponseStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ListAccountPhoneNumbersResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "available" => ListAccountPhoneNumbersResponseStatus::Available,
+            "pending" => ListAccountPhoneNumbersResponseStatus::Pending,
+            "" => ListAccountPhoneNumbersResponseStatus::Noop,
+            _ => ListAccountPhoneNumbersResponseStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ListAccountPhoneNumbersResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListAccountPhoneNumbersResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ListAccountPhoneNumbersResponseStatus {
     fn default() -> ListAccountPhoneNumbersResponseStatus {
         ListAccountPhoneNumbersResponseStatus::Noop
@@ -11788,7 +13146,8 @@ pub struct ListAccountPhoneNumbersResponseData {
 /**
 * Protocols supported by the SIP provider.<br> The value must be either `UDP`, `TCP`, `TLS`, `AUTO`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TransportProtocol {
     #[serde(rename = "AUTO")]
     Auto,
@@ -11800,8 +13159,9 @@ pub enum TransportProtocol {
     Udp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TransportProtocol {
@@ -11812,12 +13172,47 @@ impl std::fmt::Display for TransportProtocol {
             TransportProtocol::Tls => "TLS",
             TransportProtocol::Udp => "UDP",
             TransportProtocol::Noop => "",
-            TransportProtocol::FallthroughString => "*",
+            TransportProtocol::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TransportProtocol {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AUTO" => TransportProtocol::Auto,
+            "TCP" => TransportProtocol::Tcp,
+            "TLS" => TransportProtocol::Tls,
+            "UDP" => TransportProtocol::Udp,
+            "" => TransportProtocol::Noop,
+            _ => TransportProtocol::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TransportProtocol {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TransportProtocol {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TransportProtocol {
     fn default() -> TransportProtocol {
         TransportProtocol::Noop
@@ -12334,7 +13729,8 @@ pub struct UpdateSipPhoneRequest {
 /**
 * The status of the Zoom Room.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ListZoomRoomsStatus {
     #[serde(rename = "Available")]
     Available,
@@ -12346,8 +13742,9 @@ pub enum ListZoomRoomsStatus {
     UnderConstruction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ListZoomRoomsStatus {
@@ -12358,12 +13755,47 @@ impl std::fmt::Display for ListZoomRoomsStatus {
             ListZoomRoomsStatus::Offline => "Offline",
             ListZoomRoomsStatus::UnderConstruction => "UnderConstruction",
             ListZoomRoomsStatus::Noop => "",
-            ListZoomRoomsStatus::FallthroughString => "*",
+            ListZoomRoomsStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ListZoomRoomsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Available" => ListZoomRoomsStatus::Available,
+            "InMeeting" => ListZoomRoomsStatus::InMeeting,
+            "Offline" => ListZoomRoomsStatus::Offline,
+            "UnderConstruction" => ListZoomRoomsStatus::UnderConstruction,
+            "" => ListZoomRoomsStatus::Noop,
+            _ => ListZoomRoomsStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ListZoomRoomsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListZoomRoomsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ListZoomRoomsStatus {
     fn default() -> ListZoomRoomsStatus {
         ListZoomRoomsStatus::Noop
@@ -12378,7 +13810,8 @@ impl ListZoomRoomsStatus {
 /**
 * Type of the Zoom Rooms.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ListZoomRoomsType {
     #[serde(rename = "DigitalSignageOnly")]
     DigitalSignageOnly,
@@ -12388,8 +13821,9 @@ pub enum ListZoomRoomsType {
     ZoomRoom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ListZoomRoomsType {
@@ -12399,12 +13833,46 @@ impl std::fmt::Display for ListZoomRoomsType {
             ListZoomRoomsType::SchedulingDisplayOnly => "SchedulingDisplayOnly",
             ListZoomRoomsType::ZoomRoom => "ZoomRoom",
             ListZoomRoomsType::Noop => "",
-            ListZoomRoomsType::FallthroughString => "*",
+            ListZoomRoomsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ListZoomRoomsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DigitalSignageOnly" => ListZoomRoomsType::DigitalSignageOnly,
+            "SchedulingDisplayOnly" => ListZoomRoomsType::SchedulingDisplayOnly,
+            "ZoomRoom" => ListZoomRoomsType::ZoomRoom,
+            "" => ListZoomRoomsType::Noop,
+            _ => ListZoomRoomsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ListZoomRoomsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListZoomRoomsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ListZoomRoomsType {
     fn default() -> ListZoomRoomsType {
         ListZoomRoomsType::Noop
@@ -12947,7 +14415,8 @@ pub struct UpdateSiteDetailsRequest {
 * Presence status of the contact in Zoom Client. The value of this field can be one of the following:
 *   `Do_Not_Disturb`<br> `away`<br> `Available`<br> `Offline`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PresenceStatus {
     #[serde(rename = "Available")]
     Available,
@@ -12959,8 +14428,9 @@ pub enum PresenceStatus {
     Offline,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PresenceStatus {
@@ -12971,12 +14441,47 @@ impl std::fmt::Display for PresenceStatus {
             PresenceStatus::DoNotDisturb => "Do_Not_Disturb",
             PresenceStatus::Offline => "Offline",
             PresenceStatus::Noop => "",
-            PresenceStatus::FallthroughString => "*",
+            PresenceStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PresenceStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Available" => PresenceStatus::Available,
+            "Away" => PresenceStatus::Away,
+            "Do_Not_Disturb" => PresenceStatus::DoNotDisturb,
+            "Offline" => PresenceStatus::Offline,
+            "" => PresenceStatus::Noop,
+            _ => PresenceStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PresenceStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PresenceStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PresenceStatus {
     fn default() -> PresenceStatus {
         PresenceStatus::Noop
@@ -13151,7 +14656,8 @@ pub struct SearchCompanyContactsResponse {
 *   
 *   This field is only returned if you set the value of `for include_deleted_and_edited_message` query parameter to `true`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetChatMessagesResponseStatus {
     #[serde(rename = "Deleted")]
     Deleted,
@@ -13161,8 +14667,9 @@ pub enum GetChatMessagesResponseStatus {
     Normal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetChatMessagesResponseStatus {
@@ -13172,12 +14679,46 @@ impl std::fmt::Display for GetChatMessagesResponseStatus {
             GetChatMessagesResponseStatus::Edited => "Edited",
             GetChatMessagesResponseStatus::Normal => "Normal",
             GetChatMessagesResponseStatus::Noop => "",
-            GetChatMessagesResponseStatus::FallthroughString => "*",
+            GetChatMessagesResponseStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetChatMessagesResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Deleted" => GetChatMessagesResponseStatus::Deleted,
+            "Edited" => GetChatMessagesResponseStatus::Edited,
+            "Normal" => GetChatMessagesResponseStatus::Normal,
+            "" => GetChatMessagesResponseStatus::Noop,
+            _ => GetChatMessagesResponseStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetChatMessagesResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetChatMessagesResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetChatMessagesResponseStatus {
     fn default() -> GetChatMessagesResponseStatus {
         GetChatMessagesResponseStatus::Noop
@@ -13467,7 +15008,8 @@ pub struct MarkMessageRequest {
 *   * `add` — Add an emoji reaction.
 *   * `remove` — Remove an emoji reaction.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReactMessageRequestAction {
     #[serde(rename = "add")]
     Add,
@@ -13475,8 +15017,9 @@ pub enum ReactMessageRequestAction {
     Remove,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReactMessageRequestAction {
@@ -13485,12 +15028,45 @@ impl std::fmt::Display for ReactMessageRequestAction {
             ReactMessageRequestAction::Add => "add",
             ReactMessageRequestAction::Remove => "remove",
             ReactMessageRequestAction::Noop => "",
-            ReactMessageRequestAction::FallthroughString => "*",
+            ReactMessageRequestAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReactMessageRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "add" => ReactMessageRequestAction::Add,
+            "remove" => ReactMessageRequestAction::Remove,
+            "" => ReactMessageRequestAction::Noop,
+            _ => ReactMessageRequestAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReactMessageRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReactMessageRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReactMessageRequestAction {
     fn default() -> ReactMessageRequestAction {
         ReactMessageRequestAction::Noop
@@ -14021,7 +15597,8 @@ pub struct GetUserContactResponse {
 /**
 * The role of the member. The value can be one of these: `owner`, `admin`, `member`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Role {
     #[serde(rename = "admin")]
     Admin,
@@ -14031,8 +15608,9 @@ pub enum Role {
     Owner,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Role {
@@ -14042,12 +15620,46 @@ impl std::fmt::Display for Role {
             Role::Member => "member",
             Role::Owner => "owner",
             Role::Noop => "",
-            Role::FallthroughString => "*",
+            Role::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Role {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => Role::Admin,
+            "member" => Role::Member,
+            "owner" => Role::Owner,
+            "" => Role::Noop,
+            _ => Role::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Role {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Role {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Role {
     fn default() -> Role {
         Role::Noop
@@ -14369,7 +15981,8 @@ pub struct AccountResponse {
 *   * `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
 *   * `meeting_security` — View the account's meeting security settings.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum OptionData {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -14377,8 +15990,9 @@ pub enum OptionData {
     RecordingAuthentication,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for OptionData {
@@ -14387,12 +16001,45 @@ impl std::fmt::Display for OptionData {
             OptionData::MeetingAuthentication => "meeting_authentication",
             OptionData::RecordingAuthentication => "recording_authentication",
             OptionData::Noop => "",
-            OptionData::FallthroughString => "*",
+            OptionData::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for OptionData {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "meeting_authentication" => OptionData::MeetingAuthentication,
+            "recording_authentication" => OptionData::RecordingAuthentication,
+            "" => OptionData::Noop,
+            _ => OptionData::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for OptionData {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OptionData {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for OptionData {
     fn default() -> OptionData {
         OptionData::Noop
@@ -14494,7 +16141,8 @@ impl AccountSettingsUpdateRequestOneOf {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AccountSettingsUpdateOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -14506,8 +16154,9 @@ pub enum AccountSettingsUpdateOption {
     Security,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AccountSettingsUpdateOption {
@@ -14518,12 +16167,47 @@ impl std::fmt::Display for AccountSettingsUpdateOption {
             AccountSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
             AccountSettingsUpdateOption::Security => "security",
             AccountSettingsUpdateOption::Noop => "",
-            AccountSettingsUpdateOption::FallthroughString => "*",
+            AccountSettingsUpdateOption::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AccountSettingsUpdateOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "meeting_authentication" => AccountSettingsUpdateOption::MeetingAuthentication,
+            "meeting_security" => AccountSettingsUpdateOption::MeetingSecurity,
+            "recording_authentication" => AccountSettingsUpdateOption::RecordingAuthentication,
+            "security" => AccountSettingsUpdateOption::Security,
+            "" => AccountSettingsUpdateOption::Noop,
+            _ => AccountSettingsUpdateOption::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AccountSettingsUpdateOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountSettingsUpdateOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AccountSettingsUpdateOption {
     fn default() -> AccountSettingsUpdateOption {
         AccountSettingsUpdateOption::Noop
@@ -14554,7 +16238,8 @@ pub struct AccountTrustedDomainResponse {
 /**
 * Status of the plan.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AccountPlansResponsePlanBaseStatus {
     #[serde(rename = "active")]
     Active,
@@ -14564,8 +16249,9 @@ pub enum AccountPlansResponsePlanBaseStatus {
     Expired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AccountPlansResponsePlanBaseStatus {
@@ -14575,12 +16261,46 @@ impl std::fmt::Display for AccountPlansResponsePlanBaseStatus {
             AccountPlansResponsePlanBaseStatus::Cancel => "cancel",
             AccountPlansResponsePlanBaseStatus::Expired => "expired",
             AccountPlansResponsePlanBaseStatus::Noop => "",
-            AccountPlansResponsePlanBaseStatus::FallthroughString => "*",
+            AccountPlansResponsePlanBaseStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AccountPlansResponsePlanBaseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => AccountPlansResponsePlanBaseStatus::Active,
+            "cancel" => AccountPlansResponsePlanBaseStatus::Cancel,
+            "expired" => AccountPlansResponsePlanBaseStatus::Expired,
+            "" => AccountPlansResponsePlanBaseStatus::Noop,
+            _ => AccountPlansResponsePlanBaseStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AccountPlansResponsePlanBaseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountPlansResponsePlanBaseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AccountPlansResponsePlanBaseStatus {
     fn default() -> AccountPlansResponsePlanBaseStatus {
         AccountPlansResponsePlanBaseStatus::Noop
@@ -14688,7 +16408,8 @@ pub struct PlanWebinar {
 /**
 * Status of additional Cloud Recording plan.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PlanRecordingStatus {
     #[serde(rename = "Active")]
     Active,
@@ -14696,8 +16417,9 @@ pub enum PlanRecordingStatus {
     Cancelled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PlanRecordingStatus {
@@ -14706,12 +16428,45 @@ impl std::fmt::Display for PlanRecordingStatus {
             PlanRecordingStatus::Active => "Active",
             PlanRecordingStatus::Cancelled => "Cancelled",
             PlanRecordingStatus::Noop => "",
-            PlanRecordingStatus::FallthroughString => "*",
+            PlanRecordingStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PlanRecordingStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Active" => PlanRecordingStatus::Active,
+            "Cancelled" => PlanRecordingStatus::Cancelled,
+            "" => PlanRecordingStatus::Noop,
+            _ => PlanRecordingStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PlanRecordingStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PlanRecordingStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PlanRecordingStatus {
     fn default() -> PlanRecordingStatus {
         PlanRecordingStatus::Noop
@@ -15206,7 +16961,8 @@ pub struct GroupMembersCreateResponse {
 *   * `move` — Remove the group member from one group and move them to a different group.
 *   * `set_primary` — Set the user's primary group.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UpdateGroupMemberRequestAction {
     #[serde(rename = "move")]
     Move,
@@ -15214,8 +16970,9 @@ pub enum UpdateGroupMemberRequestAction {
     SetPrimary,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UpdateGroupMemberRequestAction {
@@ -15224,12 +16981,45 @@ impl std::fmt::Display for UpdateGroupMemberRequestAction {
             UpdateGroupMemberRequestAction::Move => "move",
             UpdateGroupMemberRequestAction::SetPrimary => "set_primary",
             UpdateGroupMemberRequestAction::Noop => "",
-            UpdateGroupMemberRequestAction::FallthroughString => "*",
+            UpdateGroupMemberRequestAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UpdateGroupMemberRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "move" => UpdateGroupMemberRequestAction::Move,
+            "set_primary" => UpdateGroupMemberRequestAction::SetPrimary,
+            "" => UpdateGroupMemberRequestAction::Noop,
+            _ => UpdateGroupMemberRequestAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UpdateGroupMemberRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UpdateGroupMemberRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UpdateGroupMemberRequestAction {
     fn default() -> UpdateGroupMemberRequestAction {
         UpdateGroupMemberRequestAction::Noop
@@ -15299,7 +17089,8 @@ pub struct TrackingfieldGetResponseAllOf {
 /**
 * IM Group types:<br>`normal` - Only members can see automatically see the other members of this group. Other people can search for members within this group. <br>`shared` - Everyone under an account can see the group members automatically.<br>`restricted` - Nobody can see the group or search for members except the members in the group.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ImGroupCreateRequestType {
     #[serde(rename = "normal")]
     Normal,
@@ -15307,8 +17098,9 @@ pub enum ImGroupCreateRequestType {
     Restricted,
     #[serde(rename = "shared")]
     Shared,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ImGroupCreateRequestType {
@@ -15317,12 +17109,45 @@ impl std::fmt::Display for ImGroupCreateRequestType {
             ImGroupCreateRequestType::Normal => "normal",
             ImGroupCreateRequestType::Restricted => "restricted",
             ImGroupCreateRequestType::Shared => "shared",
-            ImGroupCreateRequestType::FallthroughString => "*",
+            ImGroupCreateRequestType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ImGroupCreateRequestType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "normal" => ImGroupCreateRequestType::Normal,
+            "restricted" => ImGroupCreateRequestType::Restricted,
+            "shared" => ImGroupCreateRequestType::Shared,
+            _ => ImGroupCreateRequestType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ImGroupCreateRequestType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ImGroupCreateRequestType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ImGroupCreateRequestType {
     fn default() -> ImGroupCreateRequestType {
         ImGroupCreateRequestType::Normal
@@ -15455,7 +17280,8 @@ pub struct DashboardImResponse {
 /**
 * IM chat session type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ImChatSessionsResponseType {
     #[serde(rename = "1:1")]
     One,
@@ -15463,8 +17289,9 @@ pub enum ImChatSessionsResponseType {
     Group,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ImChatSessionsResponseType {
@@ -15473,12 +17300,45 @@ impl std::fmt::Display for ImChatSessionsResponseType {
             ImChatSessionsResponseType::One => "1:1",
             ImChatSessionsResponseType::Group => "Group",
             ImChatSessionsResponseType::Noop => "",
-            ImChatSessionsResponseType::FallthroughString => "*",
+            ImChatSessionsResponseType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ImChatSessionsResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "1:1" => ImChatSessionsResponseType::One,
+            "Group" => ImChatSessionsResponseType::Group,
+            "" => ImChatSessionsResponseType::Noop,
+            _ => ImChatSessionsResponseType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ImChatSessionsResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ImChatSessionsResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ImChatSessionsResponseType {
     fn default() -> ImChatSessionsResponseType {
         ImChatSessionsResponseType::Noop
@@ -15673,7 +17533,8 @@ pub struct ImChatMessagesResponseAllOf {
 /**
 * The meeting types: <br>`scheduled` - This includes all valid past meetings (unexpired), live meetings and upcoming scheduled meetings. It is equivalent to the combined list of "Previous Meetings" and "Upcoming Meetings" displayed in the user's [Meetings page](https://zoom.us/meeting) on the Zoom Web Portal.<br>`live` - All the ongoing meetings.<br>`upcoming` - All upcoming meetings including live meetings.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MeetingsType {
     #[serde(rename = "live")]
     Live,
@@ -15681,8 +17542,9 @@ pub enum MeetingsType {
     Scheduled,
     #[serde(rename = "upcoming")]
     Upcoming,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MeetingsType {
@@ -15691,12 +17553,45 @@ impl std::fmt::Display for MeetingsType {
             MeetingsType::Live => "live",
             MeetingsType::Scheduled => "scheduled",
             MeetingsType::Upcoming => "upcoming",
-            MeetingsType::FallthroughString => "*",
+            MeetingsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MeetingsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "live" => MeetingsType::Live,
+            "scheduled" => MeetingsType::Scheduled,
+            "upcoming" => MeetingsType::Upcoming,
+            _ => MeetingsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MeetingsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MeetingsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MeetingsType {
     fn default() -> MeetingsType {
         MeetingsType::Live
@@ -15855,7 +17750,8 @@ pub struct MeetingUpdateRequestAllOf {
 *   `recover` - [Recover](https://support.zoom.us/hc/en-us/articles/360038297111-Recover-a-deleted-meeting) a deleted meeting.
 *   
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MeetingStatusRequestAction {
     #[serde(rename = "end")]
     End,
@@ -15863,8 +17759,9 @@ pub enum MeetingStatusRequestAction {
     Recover,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MeetingStatusRequestAction {
@@ -15873,12 +17770,45 @@ impl std::fmt::Display for MeetingStatusRequestAction {
             MeetingStatusRequestAction::End => "end",
             MeetingStatusRequestAction::Recover => "recover",
             MeetingStatusRequestAction::Noop => "",
-            MeetingStatusRequestAction::FallthroughString => "*",
+            MeetingStatusRequestAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MeetingStatusRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "end" => MeetingStatusRequestAction::End,
+            "recover" => MeetingStatusRequestAction::Recover,
+            "" => MeetingStatusRequestAction::Noop,
+            _ => MeetingStatusRequestAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MeetingStatusRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MeetingStatusRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MeetingStatusRequestAction {
     fn default() -> MeetingStatusRequestAction {
         MeetingStatusRequestAction::Noop
@@ -15904,7 +17834,8 @@ pub struct MeetingStatusRequest {
 /**
 * The registrant status:<br>`pending` - Registrant's status is pending.<br>`approved` - Registrant's status is approved.<br>`denied` - Registrant's status is denied.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MeetingRegistrantsStatus {
     #[serde(rename = "approved")]
     Approved,
@@ -15912,8 +17843,9 @@ pub enum MeetingRegistrantsStatus {
     Denied,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MeetingRegistrantsStatus {
@@ -15922,12 +17854,45 @@ impl std::fmt::Display for MeetingRegistrantsStatus {
             MeetingRegistrantsStatus::Approved => "approved",
             MeetingRegistrantsStatus::Denied => "denied",
             MeetingRegistrantsStatus::Pending => "pending",
-            MeetingRegistrantsStatus::FallthroughString => "*",
+            MeetingRegistrantsStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MeetingRegistrantsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approved" => MeetingRegistrantsStatus::Approved,
+            "denied" => MeetingRegistrantsStatus::Denied,
+            "pending" => MeetingRegistrantsStatus::Pending,
+            _ => MeetingRegistrantsStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MeetingRegistrantsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MeetingRegistrantsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MeetingRegistrantsStatus {
     fn default() -> MeetingRegistrantsStatus {
         MeetingRegistrantsStatus::Approved
@@ -16159,7 +18124,8 @@ pub struct PastMeetingParticipantsResponseAllOf {
 /**
 * Status of the Meeting Poll:<br>`notstart` - Poll not started<br>`started` - Poll started<br>`ended` - Poll ended<br>`sharing` - Sharing poll results
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MeetingPollCreateResponseStatus {
     #[serde(rename = "ended")]
     Ended,
@@ -16171,8 +18137,9 @@ pub enum MeetingPollCreateResponseStatus {
     Started,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MeetingPollCreateResponseStatus {
@@ -16183,12 +18150,47 @@ impl std::fmt::Display for MeetingPollCreateResponseStatus {
             MeetingPollCreateResponseStatus::Sharing => "sharing",
             MeetingPollCreateResponseStatus::Started => "started",
             MeetingPollCreateResponseStatus::Noop => "",
-            MeetingPollCreateResponseStatus::FallthroughString => "*",
+            MeetingPollCreateResponseStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MeetingPollCreateResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ended" => MeetingPollCreateResponseStatus::Ended,
+            "notstart" => MeetingPollCreateResponseStatus::Notstart,
+            "sharing" => MeetingPollCreateResponseStatus::Sharing,
+            "started" => MeetingPollCreateResponseStatus::Started,
+            "" => MeetingPollCreateResponseStatus::Noop,
+            _ => MeetingPollCreateResponseStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MeetingPollCreateResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MeetingPollCreateResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MeetingPollCreateResponseStatus {
     fn default() -> MeetingPollCreateResponseStatus {
         MeetingPollCreateResponseStatus::Noop
@@ -16272,14 +18274,16 @@ pub struct RecordingGetResponseAllOf {
 /**
 * The recording delete actions:<br>`trash` - Move recording to trash.<br>`delete` - Delete recording permanently.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RecordingDeleteAction {
     #[serde(rename = "delete")]
     Delete,
     #[serde(rename = "trash")]
     Trash,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RecordingDeleteAction {
@@ -16287,12 +18291,44 @@ impl std::fmt::Display for RecordingDeleteAction {
         match self {
             RecordingDeleteAction::Delete => "delete",
             RecordingDeleteAction::Trash => "trash",
-            RecordingDeleteAction::FallthroughString => "*",
+            RecordingDeleteAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RecordingDeleteAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "delete" => RecordingDeleteAction::Delete,
+            "trash" => RecordingDeleteAction::Trash,
+            _ => RecordingDeleteAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RecordingDeleteAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingDeleteAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RecordingDeleteAction {
     fn default() -> RecordingDeleteAction {
         RecordingDeleteAction::Trash
@@ -16344,7 +18380,8 @@ pub struct MeetingRecordingRegistrantCreateResponse {
 *   
 *   If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DashboardMeetingsType {
     #[serde(rename = "live")]
     Live,
@@ -16352,8 +18389,9 @@ pub enum DashboardMeetingsType {
     Past,
     #[serde(rename = "pastOne")]
     PastOne,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DashboardMeetingsType {
@@ -16362,12 +18400,45 @@ impl std::fmt::Display for DashboardMeetingsType {
             DashboardMeetingsType::Live => "live",
             DashboardMeetingsType::Past => "past",
             DashboardMeetingsType::PastOne => "pastOne",
-            DashboardMeetingsType::FallthroughString => "*",
+            DashboardMeetingsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DashboardMeetingsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "live" => DashboardMeetingsType::Live,
+            "past" => DashboardMeetingsType::Past,
+            "pastOne" => DashboardMeetingsType::PastOne,
+            _ => DashboardMeetingsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DashboardMeetingsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DashboardMeetingsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DashboardMeetingsType {
     fn default() -> DashboardMeetingsType {
         DashboardMeetingsType::Live
@@ -16377,14 +18448,16 @@ impl Default for DashboardMeetingsType {
 /**
 * Set the value of this field to "tracking_fields" if you would like to include tracking fields of each meeting in the response.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum IncludeFields {
     #[serde(rename = "tracking_fields")]
     TrackingFields,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for IncludeFields {
@@ -16392,12 +18465,44 @@ impl std::fmt::Display for IncludeFields {
         match self {
             IncludeFields::TrackingFields => "tracking_fields",
             IncludeFields::Noop => "",
-            IncludeFields::FallthroughString => "*",
+            IncludeFields::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for IncludeFields {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "tracking_fields" => IncludeFields::TrackingFields,
+            "" => IncludeFields::Noop,
+            _ => IncludeFields::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for IncludeFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IncludeFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for IncludeFields {
     fn default() -> IncludeFields {
         IncludeFields::Noop
@@ -16444,14 +18549,16 @@ pub struct DashboardMeetingsResponseAllOf {
 /**
 * Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DashboardMeetingParticipantsIncludeFields {
     #[serde(rename = "registrant_id")]
     RegistrantId,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsIncludeFields {
@@ -16459,12 +18566,44 @@ impl std::fmt::Display for DashboardMeetingParticipantsIncludeFields {
         match self {
             DashboardMeetingParticipantsIncludeFields::RegistrantId => "registrant_id",
             DashboardMeetingParticipantsIncludeFields::Noop => "",
-            DashboardMeetingParticipantsIncludeFields::FallthroughString => "*",
+            DashboardMeetingParticipantsIncludeFields::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DashboardMeetingParticipantsIncludeFields {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "registrant_id" => DashboardMeetingParticipantsIncludeFields::RegistrantId,
+            "" => DashboardMeetingParticipantsIncludeFields::Noop,
+            _ => DashboardMeetingParticipantsIncludeFields::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DashboardMeetingParticipantsIncludeFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DashboardMeetingParticipantsIncludeFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DashboardMeetingParticipantsIncludeFields {
     fn default() -> DashboardMeetingParticipantsIncludeFields {
         DashboardMeetingParticipantsIncludeFields::Noop
@@ -16487,7 +18626,8 @@ impl DashboardMeetingParticipantsIncludeFields {
 *   
 *   **Note:** This response returns an empty string (`““`) value for any users who are **not** a part of the host’s account (external users).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DashboardMeetingParticipantsResponseDevice {
     #[serde(rename = "Android")]
     Android,
@@ -16503,8 +18643,9 @@ pub enum DashboardMeetingParticipantsResponseDevice {
     IOs,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsResponseDevice {
@@ -16517,12 +18658,49 @@ impl std::fmt::Display for DashboardMeetingParticipantsResponseDevice {
             DashboardMeetingParticipantsResponseDevice::Windows => "Windows",
             DashboardMeetingParticipantsResponseDevice::IOs => "iOS",
             DashboardMeetingParticipantsResponseDevice::Noop => "",
-            DashboardMeetingParticipantsResponseDevice::FallthroughString => "*",
+            DashboardMeetingParticipantsResponseDevice::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DashboardMeetingParticipantsResponseDevice {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Android" => DashboardMeetingParticipantsResponseDevice::Android,
+            "H.323/SIP" => DashboardMeetingParticipantsResponseDevice::H323Sip,
+            "Mac" => DashboardMeetingParticipantsResponseDevice::Mac,
+            "Phone" => DashboardMeetingParticipantsResponseDevice::Phone,
+            "Windows" => DashboardMeetingParticipantsResponseDevice::Windows,
+            "iOS" => DashboardMeetingParticipantsResponseDevice::IOs,
+            "" => DashboardMeetingParticipantsResponseDevice::Noop,
+            _ => DashboardMeetingParticipantsResponseDevice::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DashboardMeetingParticipantsResponseDevice {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DashboardMeetingParticipantsResponseDevice {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DashboardMeetingParticipantsResponseDevice {
     fn default() -> DashboardMeetingParticipantsResponseDevice {
         DashboardMeetingParticipantsResponseDevice::Noop
@@ -16543,7 +18721,8 @@ impl DashboardMeetingParticipantsResponseDevice {
 *   * `Cellular` — 3G, 4G, and 5G cellular.
 *   * `Others` — An unknown device.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum NetworkType {
     #[serde(rename = "Cellular")]
     Cellular,
@@ -16557,8 +18736,9 @@ pub enum NetworkType {
     Wired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for NetworkType {
@@ -16570,12 +18750,48 @@ impl std::fmt::Display for NetworkType {
             NetworkType::Wifi => "Wifi",
             NetworkType::Wired => "Wired",
             NetworkType::Noop => "",
-            NetworkType::FallthroughString => "*",
+            NetworkType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for NetworkType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Cellular" => NetworkType::Cellular,
+            "Others" => NetworkType::Others,
+            "PPP" => NetworkType::Ppp,
+            "Wifi" => NetworkType::Wifi,
+            "Wired" => NetworkType::Wired,
+            "" => NetworkType::Noop,
+            _ => NetworkType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NetworkType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for NetworkType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for NetworkType {
     fn default() -> NetworkType {
         NetworkType::Noop
@@ -16590,7 +18806,8 @@ impl NetworkType {
 /**
 * Audio quality of the participant.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AudioQuality {
     #[serde(rename = "bad")]
     Bad,
@@ -16602,8 +18819,9 @@ pub enum AudioQuality {
     Poor,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AudioQuality {
@@ -16614,12 +18832,47 @@ impl std::fmt::Display for AudioQuality {
             AudioQuality::Good => "good",
             AudioQuality::Poor => "poor",
             AudioQuality::Noop => "",
-            AudioQuality::FallthroughString => "*",
+            AudioQuality::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AudioQuality {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "bad" => AudioQuality::Bad,
+            "fair" => AudioQuality::Fair,
+            "good" => AudioQuality::Good,
+            "poor" => AudioQuality::Poor,
+            "" => AudioQuality::Noop,
+            _ => AudioQuality::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AudioQuality {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AudioQuality {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AudioQuality {
     fn default() -> AudioQuality {
         AudioQuality::Noop
@@ -16636,7 +18889,8 @@ impl AudioQuality {
 *   
 *   The value of this field can be `in_meeting` or `in_waiting_room`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DashboardMeetingParticipantsResponseStatus {
     #[serde(rename = "in_meeting")]
     InMeeting,
@@ -16644,8 +18898,9 @@ pub enum DashboardMeetingParticipantsResponseStatus {
     InWaitingRoom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsResponseStatus {
@@ -16654,12 +18909,45 @@ impl std::fmt::Display for DashboardMeetingParticipantsResponseStatus {
             DashboardMeetingParticipantsResponseStatus::InMeeting => "in_meeting",
             DashboardMeetingParticipantsResponseStatus::InWaitingRoom => "in_waiting_room",
             DashboardMeetingParticipantsResponseStatus::Noop => "",
-            DashboardMeetingParticipantsResponseStatus::FallthroughString => "*",
+            DashboardMeetingParticipantsResponseStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DashboardMeetingParticipantsResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "in_meeting" => DashboardMeetingParticipantsResponseStatus::InMeeting,
+            "in_waiting_room" => DashboardMeetingParticipantsResponseStatus::InWaitingRoom,
+            "" => DashboardMeetingParticipantsResponseStatus::Noop,
+            _ => DashboardMeetingParticipantsResponseStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DashboardMeetingParticipantsResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DashboardMeetingParticipantsResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DashboardMeetingParticipantsResponseStatus {
     fn default() -> DashboardMeetingParticipantsResponseStatus {
         DashboardMeetingParticipantsResponseStatus::Noop
@@ -17085,14 +19373,16 @@ pub struct DashboardMeetingParticipantShareResponseAllOf {
 /**
 * The webinar type.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DashboardWebinarsType {
     #[serde(rename = "live")]
     Live,
     #[serde(rename = "past")]
     Past,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DashboardWebinarsType {
@@ -17100,12 +19390,44 @@ impl std::fmt::Display for DashboardWebinarsType {
         match self {
             DashboardWebinarsType::Live => "live",
             DashboardWebinarsType::Past => "past",
-            DashboardWebinarsType::FallthroughString => "*",
+            DashboardWebinarsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DashboardWebinarsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "live" => DashboardWebinarsType::Live,
+            "past" => DashboardWebinarsType::Past,
+            _ => DashboardWebinarsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DashboardWebinarsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DashboardWebinarsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DashboardWebinarsType {
     fn default() -> DashboardWebinarsType {
         DashboardWebinarsType::Live
@@ -18079,7 +20401,8 @@ pub struct ReportDailyResponse {
 /**
 * Active or inactive hosts.<br>`active` - Active hosts. <br>`inactive` - Inactive hosts.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReportUsersType {
     #[serde(rename = "active")]
     Active,
@@ -18087,8 +20410,9 @@ pub enum ReportUsersType {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReportUsersType {
@@ -18097,12 +20421,45 @@ impl std::fmt::Display for ReportUsersType {
             ReportUsersType::Active => "active",
             ReportUsersType::Inactive => "inactive",
             ReportUsersType::Noop => "",
-            ReportUsersType::FallthroughString => "*",
+            ReportUsersType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReportUsersType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => ReportUsersType::Active,
+            "inactive" => ReportUsersType::Inactive,
+            "" => ReportUsersType::Noop,
+            _ => ReportUsersType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReportUsersType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReportUsersType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReportUsersType {
     fn default() -> ReportUsersType {
         ReportUsersType::Noop
@@ -18117,14 +20474,16 @@ impl ReportUsersType {
 /**
 * The meeting types: <br>`past` - Past meetings.<br>`pastOne` - Past one user meetings.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ReportMeetingsType {
     #[serde(rename = "past")]
     Past,
     #[serde(rename = "pastOne")]
     PastOne,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ReportMeetingsType {
@@ -18132,12 +20491,44 @@ impl std::fmt::Display for ReportMeetingsType {
         match self {
             ReportMeetingsType::Past => "past",
             ReportMeetingsType::PastOne => "pastOne",
-            ReportMeetingsType::FallthroughString => "*",
+            ReportMeetingsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ReportMeetingsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "past" => ReportMeetingsType::Past,
+            "pastOne" => ReportMeetingsType::PastOne,
+            _ => ReportMeetingsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ReportMeetingsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReportMeetingsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ReportMeetingsType {
     fn default() -> ReportMeetingsType {
         ReportMeetingsType::Past
@@ -18899,14 +21290,16 @@ pub struct ReportWebinarQaResponse {
 /**
 * Date types:<br>`start_time` - Query by call start time.<br>`end_time` - Query by call end time.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum QueryDateType {
     #[serde(rename = "end_time")]
     EndTime,
     #[serde(rename = "start_time")]
     StartTime,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for QueryDateType {
@@ -18914,12 +21307,44 @@ impl std::fmt::Display for QueryDateType {
         match self {
             QueryDateType::EndTime => "end_time",
             QueryDateType::StartTime => "start_time",
-            QueryDateType::FallthroughString => "*",
+            QueryDateType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for QueryDateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "end_time" => QueryDateType::EndTime,
+            "start_time" => QueryDateType::StartTime,
+            _ => QueryDateType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for QueryDateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for QueryDateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for QueryDateType {
     fn default() -> QueryDateType {
         QueryDateType::StartTime
@@ -18960,7 +21385,8 @@ pub struct ReportCloudRecordingResponseAllOf {
 *   Filter your response by a category type to see reports for a specific category.
 *   The value for this field can be one of the following:<br> `all`<br>`user`<br>`user_settings`<br>`account`<br>`billing`<br>`im`<br>`recording`<br>`phone_contacts`<br>`webinar`<br>`sub_account`<br>`role`<br>`zoom_rooms`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CategoryType {
     #[serde(rename = "account")]
     Account,
@@ -18988,8 +21414,9 @@ pub enum CategoryType {
     ZoomRooms,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CategoryType {
@@ -19008,12 +21435,55 @@ impl std::fmt::Display for CategoryType {
             CategoryType::Webinar => "webinar",
             CategoryType::ZoomRooms => "zoom_rooms",
             CategoryType::Noop => "",
-            CategoryType::FallthroughString => "*",
+            CategoryType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CategoryType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "account" => CategoryType::Account,
+            "all" => CategoryType::All,
+            "billing" => CategoryType::Billing,
+            "im" => CategoryType::Im,
+            "phone_contacts" => CategoryType::PhoneContacts,
+            "recording" => CategoryType::Recording,
+            "role" => CategoryType::Role,
+            "sub_account" => CategoryType::SubAccount,
+            "user" => CategoryType::User,
+            "user_settings" => CategoryType::UserSettings,
+            "webinar" => CategoryType::Webinar,
+            "zoom_rooms" => CategoryType::ZoomRooms,
+            "" => CategoryType::Noop,
+            _ => CategoryType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CategoryType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CategoryType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CategoryType {
     fn default() -> CategoryType {
         CategoryType::Noop
@@ -19358,7 +21828,8 @@ pub struct TspUpdateRequest {
 *   
 *   This value defaults to `active`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UsersStatus {
     #[serde(rename = "active")]
     Active,
@@ -19366,8 +21837,9 @@ pub enum UsersStatus {
     Inactive,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UsersStatus {
@@ -19376,12 +21848,45 @@ impl std::fmt::Display for UsersStatus {
             UsersStatus::Active => "active",
             UsersStatus::Inactive => "inactive",
             UsersStatus::Pending => "pending",
-            UsersStatus::FallthroughString => "*",
+            UsersStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UsersStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => UsersStatus::Active,
+            "inactive" => UsersStatus::Inactive,
+            "pending" => UsersStatus::Pending,
+            _ => UsersStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UsersStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UsersStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UsersStatus {
     fn default() -> UsersStatus {
         UsersStatus::Active
@@ -19393,7 +21898,8 @@ impl Default for UsersStatus {
 *   * `custom_attributes` — Return the user's custom attributes.
 *   * `host_key` — Return the user's [host key](https://support.zoom.us/hc/en-us/articles/205172555-Using-your-host-key).
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UsersIncludeFields {
     #[serde(rename = "custom_attributes")]
     CustomAttributes,
@@ -19401,8 +21907,9 @@ pub enum UsersIncludeFields {
     HostKey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UsersIncludeFields {
@@ -19411,12 +21918,45 @@ impl std::fmt::Display for UsersIncludeFields {
             UsersIncludeFields::CustomAttributes => "custom_attributes",
             UsersIncludeFields::HostKey => "host_key",
             UsersIncludeFields::Noop => "",
-            UsersIncludeFields::FallthroughString => "*",
+            UsersIncludeFields::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UsersIncludeFields {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "custom_attributes" => UsersIncludeFields::CustomAttributes,
+            "host_key" => UsersIncludeFields::HostKey,
+            "" => UsersIncludeFields::Noop,
+            _ => UsersIncludeFields::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UsersIncludeFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UsersIncludeFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UsersIncludeFields {
     fn default() -> UsersIncludeFields {
         UsersIncludeFields::Noop
@@ -19705,7 +22245,8 @@ pub struct UsersResponseData {
 /**
 * Specify how to create the new user: <br>`create` - User will get an email sent from Zoom. There is a confirmation link in this email. The user will then need to use the link to activate their Zoom account. The user can then set or change their password.<br>`autoCreate` - This action is provided for the enterprise customer who has a managed domain. This feature is disabled by default because of the security risk involved in creating a user who does not belong to your domain.<br>`custCreate` - Users created via this option do not have passwords and will not have the ability to log into the Zoom Web Portal or the Zoom Client. These users can still host and join meetings using the `start_url` and `join_url` respectively. To use this option, you must contact the ISV Platform Sales team at isv@zoom.us.<br>`ssoCreate` - This action is provided for the enabled “Pre-provisioning SSO User” option. A user created in this way has no password. If not a basic user, a personal vanity URL using the user name (no domain) of the provisioning email will be generated. If the user name or PMI is invalid or occupied, it will use a random number or random personal vanity URL.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UserCreateRequestAction {
     #[serde(rename = "autoCreate")]
     AutoCreate,
@@ -19717,8 +22258,9 @@ pub enum UserCreateRequestAction {
     SsoCreate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UserCreateRequestAction {
@@ -19729,12 +22271,47 @@ impl std::fmt::Display for UserCreateRequestAction {
             UserCreateRequestAction::CustCreate => "custCreate",
             UserCreateRequestAction::SsoCreate => "ssoCreate",
             UserCreateRequestAction::Noop => "",
-            UserCreateRequestAction::FallthroughString => "*",
+            UserCreateRequestAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UserCreateRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "autoCreate" => UserCreateRequestAction::AutoCreate,
+            "create" => UserCreateRequestAction::Create,
+            "custCreate" => UserCreateRequestAction::CustCreate,
+            "ssoCreate" => UserCreateRequestAction::SsoCreate,
+            "" => UserCreateRequestAction::Noop,
+            _ => UserCreateRequestAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UserCreateRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserCreateRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UserCreateRequestAction {
     fn default() -> UserCreateRequestAction {
         UserCreateRequestAction::Noop
@@ -19816,7 +22393,8 @@ pub struct UserCreateRequest {
 *   
 *   `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum LoginType {
     #[serde(rename = "0")]
     Zero,
@@ -19844,8 +22422,9 @@ pub enum LoginType {
     NinetyNine,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for LoginType {
@@ -19864,12 +22443,55 @@ impl std::fmt::Display for LoginType {
             LoginType::NinetyEight => "98",
             LoginType::NinetyNine => "99",
             LoginType::Noop => "",
-            LoginType::FallthroughString => "*",
+            LoginType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for LoginType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "0" => LoginType::Zero,
+            "1" => LoginType::One,
+            "100" => LoginType::OneHundred,
+            "101" => LoginType::OneHundredAndOne,
+            "11" => LoginType::Eleven,
+            "21" => LoginType::TwentyOne,
+            "23" => LoginType::TwentyThree,
+            "24" => LoginType::TwentyFour,
+            "27" => LoginType::TwentySeven,
+            "97" => LoginType::NinetySeven,
+            "98" => LoginType::NinetyEight,
+            "99" => LoginType::NinetyNine,
+            "" => LoginType::Noop,
+            _ => LoginType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for LoginType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LoginType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for LoginType {
     fn default() -> LoginType {
         LoginType::Noop
@@ -20208,14 +22830,16 @@ pub struct UserResponseAllOf {
 /**
 * Delete action options:<br>`disassociate` - Disassociate a user.<br>`delete`-  Permanently delete a user.<br>Note: To delete pending user in the account, use `disassociate`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UserDeleteAction {
     #[serde(rename = "delete")]
     Delete,
     #[serde(rename = "disassociate")]
     Disassociate,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UserDeleteAction {
@@ -20223,12 +22847,44 @@ impl std::fmt::Display for UserDeleteAction {
         match self {
             UserDeleteAction::Delete => "delete",
             UserDeleteAction::Disassociate => "disassociate",
-            UserDeleteAction::FallthroughString => "*",
+            UserDeleteAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UserDeleteAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "delete" => UserDeleteAction::Delete,
+            "disassociate" => UserDeleteAction::Disassociate,
+            _ => UserDeleteAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UserDeleteAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserDeleteAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UserDeleteAction {
     fn default() -> UserDeleteAction {
         UserDeleteAction::Disassociate
@@ -20326,7 +22982,8 @@ impl UserSettingsUpdateRequestOneOf {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UserSettingsUpdateOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -20336,8 +22993,9 @@ pub enum UserSettingsUpdateOption {
     RecordingAuthentication,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UserSettingsUpdateOption {
@@ -20347,12 +23005,46 @@ impl std::fmt::Display for UserSettingsUpdateOption {
             UserSettingsUpdateOption::MeetingSecuirty => "meeting_secuirty",
             UserSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
             UserSettingsUpdateOption::Noop => "",
-            UserSettingsUpdateOption::FallthroughString => "*",
+            UserSettingsUpdateOption::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UserSettingsUpdateOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "meeting_authentication" => UserSettingsUpdateOption::MeetingAuthentication,
+            "meeting_secuirty" => UserSettingsUpdateOption::MeetingSecuirty,
+            "recording_authentication" => UserSettingsUpdateOption::RecordingAuthentication,
+            "" => UserSettingsUpdateOption::Noop,
+            _ => UserSettingsUpdateOption::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UserSettingsUpdateOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserSettingsUpdateOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UserSettingsUpdateOption {
     fn default() -> UserSettingsUpdateOption {
         UserSettingsUpdateOption::Noop
@@ -20367,7 +23059,8 @@ impl UserSettingsUpdateOption {
 /**
 * The action types:<br>`activate` - Activate a deactivated user.<br>`deactivate` - Deactivate a user.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UserStatusRequestAction {
     #[serde(rename = "activate")]
     Activate,
@@ -20375,8 +23068,9 @@ pub enum UserStatusRequestAction {
     Deactivate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UserStatusRequestAction {
@@ -20385,12 +23079,45 @@ impl std::fmt::Display for UserStatusRequestAction {
             UserStatusRequestAction::Activate => "activate",
             UserStatusRequestAction::Deactivate => "deactivate",
             UserStatusRequestAction::Noop => "",
-            UserStatusRequestAction::FallthroughString => "*",
+            UserStatusRequestAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UserStatusRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "activate" => UserStatusRequestAction::Activate,
+            "deactivate" => UserStatusRequestAction::Deactivate,
+            "" => UserStatusRequestAction::Noop,
+            _ => UserStatusRequestAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UserStatusRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserStatusRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UserStatusRequestAction {
     fn default() -> UserStatusRequestAction {
         UserStatusRequestAction::Noop
@@ -20626,7 +23353,8 @@ pub struct TspAccountData {
 /**
 * User token types:<br>`token` - Used for starting meetings with the client SDK. This token expires in 14 days and a new token will be returned after the expiry.<br>`zak` - Used for generating the start meeting URL. The token expiration time is two hours. For API users, the expiration time is 90 days.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UserTokenType {
     #[serde(rename = "token")]
     Token,
@@ -20634,8 +23362,9 @@ pub enum UserTokenType {
     Zak,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UserTokenType {
@@ -20644,12 +23373,45 @@ impl std::fmt::Display for UserTokenType {
             UserTokenType::Token => "token",
             UserTokenType::Zak => "zak",
             UserTokenType::Noop => "",
-            UserTokenType::FallthroughString => "*",
+            UserTokenType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UserTokenType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "token" => UserTokenType::Token,
+            "zak" => UserTokenType::Zak,
+            "" => UserTokenType::Noop,
+            _ => UserTokenType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UserTokenType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserTokenType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UserTokenType {
     fn default() -> UserTokenType {
         UserTokenType::Noop
@@ -20863,14 +23625,16 @@ pub struct ListWebinarParticipantsResponse {
     pub total_records: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum WebinarStatusRequestAction {
     #[serde(rename = "end")]
     End,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for WebinarStatusRequestAction {
@@ -20878,12 +23642,44 @@ impl std::fmt::Display for WebinarStatusRequestAction {
         match self {
             WebinarStatusRequestAction::End => "end",
             WebinarStatusRequestAction::Noop => "",
-            WebinarStatusRequestAction::FallthroughString => "*",
+            WebinarStatusRequestAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for WebinarStatusRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "end" => WebinarStatusRequestAction::End,
+            "" => WebinarStatusRequestAction::Noop,
+            _ => WebinarStatusRequestAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for WebinarStatusRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WebinarStatusRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for WebinarStatusRequestAction {
     fn default() -> WebinarStatusRequestAction {
         WebinarStatusRequestAction::Noop
@@ -22001,7 +24797,8 @@ pub struct PhoneUserSettingsResponse {
 *   * `zr`<br>
 *   * `interop`<br>
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ListSettingTemplatesResponseType {
     #[serde(rename = "autReceptionist")]
     AutReceptionist,
@@ -22017,8 +24814,9 @@ pub enum ListSettingTemplatesResponseType {
     Zr,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ListSettingTemplatesResponseType {
@@ -22031,12 +24829,49 @@ impl std::fmt::Display for ListSettingTemplatesResponseType {
             ListSettingTemplatesResponseType::User => "user",
             ListSettingTemplatesResponseType::Zr => "zr",
             ListSettingTemplatesResponseType::Noop => "",
-            ListSettingTemplatesResponseType::FallthroughString => "*",
+            ListSettingTemplatesResponseType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ListSettingTemplatesResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "autReceptionist" => ListSettingTemplatesResponseType::AutReceptionist,
+            "commonArea" => ListSettingTemplatesResponseType::CommonArea,
+            "group" => ListSettingTemplatesResponseType::Group,
+            "interop" => ListSettingTemplatesResponseType::Interop,
+            "user" => ListSettingTemplatesResponseType::User,
+            "zr" => ListSettingTemplatesResponseType::Zr,
+            "" => ListSettingTemplatesResponseType::Noop,
+            _ => ListSettingTemplatesResponseType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ListSettingTemplatesResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListSettingTemplatesResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ListSettingTemplatesResponseType {
     fn default() -> ListSettingTemplatesResponseType {
         ListSettingTemplatesResponseType::Noop
@@ -22131,7 +24966,8 @@ pub struct ListSettingTemplatesResponse {
 /**
 * The type of template. Values include `user`, `group`, `autoReceptionist`, `commonarea`, or `interop`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AddSettingTemplateRequestType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -22145,8 +24981,9 @@ pub enum AddSettingTemplateRequestType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AddSettingTemplateRequestType {
@@ -22158,12 +24995,48 @@ impl std::fmt::Display for AddSettingTemplateRequestType {
             AddSettingTemplateRequestType::Interop => "interop",
             AddSettingTemplateRequestType::User => "user",
             AddSettingTemplateRequestType::Noop => "",
-            AddSettingTemplateRequestType::FallthroughString => "*",
+            AddSettingTemplateRequestType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AddSettingTemplateRequestType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "autoReceptionist" => AddSettingTemplateRequestType::AutoReceptionist,
+            "commonarea" => AddSettingTemplateRequestType::Commonarea,
+            "group" => AddSettingTemplateRequestType::Group,
+            "interop" => AddSettingTemplateRequestType::Interop,
+            "user" => AddSettingTemplateRequestType::User,
+            "" => AddSettingTemplateRequestType::Noop,
+            _ => AddSettingTemplateRequestType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AddSettingTemplateRequestType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AddSettingTemplateRequestType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AddSettingTemplateRequestType {
     fn default() -> AddSettingTemplateRequestType {
         AddSettingTemplateRequestType::Noop
@@ -23169,7 +26042,8 @@ pub struct ListSipGroupsResponse {
 /**
 * The type of template being queried. Values: `user`, `group`, `auto receptionist` `common area`,`zr`, `interop`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetSettingTemplateResponseType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23185,8 +26059,9 @@ pub enum GetSettingTemplateResponseType {
     Zr,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetSettingTemplateResponseType {
@@ -23199,12 +26074,49 @@ impl std::fmt::Display for GetSettingTemplateResponseType {
             GetSettingTemplateResponseType::User => "user",
             GetSettingTemplateResponseType::Zr => "zr",
             GetSettingTemplateResponseType::Noop => "",
-            GetSettingTemplateResponseType::FallthroughString => "*",
+            GetSettingTemplateResponseType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetSettingTemplateResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "autoReceptionist" => GetSettingTemplateResponseType::AutoReceptionist,
+            "commonArea" => GetSettingTemplateResponseType::CommonArea,
+            "group" => GetSettingTemplateResponseType::Group,
+            "interop" => GetSettingTemplateResponseType::Interop,
+            "user" => GetSettingTemplateResponseType::User,
+            "zr" => GetSettingTemplateResponseType::Zr,
+            "" => GetSettingTemplateResponseType::Noop,
+            _ => GetSettingTemplateResponseType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetSettingTemplateResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetSettingTemplateResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetSettingTemplateResponseType {
     fn default() -> GetSettingTemplateResponseType {
         GetSettingTemplateResponseType::Noop
@@ -23399,7 +26311,8 @@ pub struct CustomHours {
 *   5-zoomRoom, <br>
 *   7-sharedLineGroup
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23415,8 +26328,9 @@ pub enum GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectO
     ZoomRoom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display
@@ -23431,12 +26345,55 @@ GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorTy
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::User => "user",
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::ZoomRoom => "zoomRoom",
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop => "",
-GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::FallthroughString => "*",
+GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::UnknownValue(s) => s.as_str(),
 }
 .fmt(f)
     }
 }
 
+impl std::str::FromStr
+    for GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType
+{
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+"autoReceptionist" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::AutoReceptionist,
+"callQueue" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CallQueue,
+"commonAreaPhone" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CommonAreaPhone,
+"sharedLineGroup" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::SharedLineGroup,
+"user" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::User,
+"zoomRoom" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::ZoomRoom,
+"" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop,
+_ => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::UnknownValue(s.to_string()),
+})
+    }
+}
+
+impl Serialize
+    for GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType
+{
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de>
+    for GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType
+{
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default
     for GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType
 {
@@ -23577,7 +26534,8 @@ pub struct GetSettingTemplateResponseUserSettingsDeskPhone {
 *   
 *   * `disable`: This means that the hold music is disabled.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum HoldMusic {
     #[serde(rename = "default")]
     Default,
@@ -23585,8 +26543,9 @@ pub enum HoldMusic {
     Disable,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for HoldMusic {
@@ -23595,12 +26554,45 @@ impl std::fmt::Display for HoldMusic {
             HoldMusic::Default => "default",
             HoldMusic::Disable => "disable",
             HoldMusic::Noop => "",
-            HoldMusic::FallthroughString => "*",
+            HoldMusic::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for HoldMusic {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "default" => HoldMusic::Default,
+            "disable" => HoldMusic::Disable,
+            "" => HoldMusic::Noop,
+            _ => HoldMusic::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for HoldMusic {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for HoldMusic {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for HoldMusic {
     fn default() -> HoldMusic {
         HoldMusic::Noop
@@ -23691,7 +26683,8 @@ pub struct GetSettingTemplateResponse {
 /**
 * Values: inbound, outbound, both.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum RecordingCalls {
     #[serde(rename = "both")]
     Both,
@@ -23701,8 +26694,9 @@ pub enum RecordingCalls {
     Outbound,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for RecordingCalls {
@@ -23712,12 +26706,46 @@ impl std::fmt::Display for RecordingCalls {
             RecordingCalls::Inbound => "inbound",
             RecordingCalls::Outbound => "outbound",
             RecordingCalls::Noop => "",
-            RecordingCalls::FallthroughString => "*",
+            RecordingCalls::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for RecordingCalls {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "both" => RecordingCalls::Both,
+            "inbound" => RecordingCalls::Inbound,
+            "outbound" => RecordingCalls::Outbound,
+            "" => RecordingCalls::Noop,
+            _ => RecordingCalls::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for RecordingCalls {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordingCalls {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for RecordingCalls {
     fn default() -> RecordingCalls {
         RecordingCalls::Noop
@@ -23802,7 +26830,8 @@ pub struct UpdateSettingTemplateRequest {
     pub user_settings: Option<GetSettingTemplateResponseUserSettings>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PhoneUserCallLogsType {
     #[serde(rename = "all")]
     All,
@@ -23810,8 +26839,9 @@ pub enum PhoneUserCallLogsType {
     Missed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PhoneUserCallLogsType {
@@ -23820,12 +26850,45 @@ impl std::fmt::Display for PhoneUserCallLogsType {
             PhoneUserCallLogsType::All => "all",
             PhoneUserCallLogsType::Missed => "missed",
             PhoneUserCallLogsType::Noop => "",
-            PhoneUserCallLogsType::FallthroughString => "*",
+            PhoneUserCallLogsType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PhoneUserCallLogsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => PhoneUserCallLogsType::All,
+            "missed" => PhoneUserCallLogsType::Missed,
+            "" => PhoneUserCallLogsType::Noop,
+            _ => PhoneUserCallLogsType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PhoneUserCallLogsType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PhoneUserCallLogsType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PhoneUserCallLogsType {
     fn default() -> PhoneUserCallLogsType {
         PhoneUserCallLogsType::Noop
@@ -23840,14 +26903,16 @@ impl PhoneUserCallLogsType {
 /**
 * Enables you to sort call logs by start or end time. Choose the sort time value. Values include `startTime` or `endTime`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum TimeType {
     #[serde(rename = "endTime")]
     EndTime,
     #[serde(rename = "startTime")]
     StartTime,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for TimeType {
@@ -23855,12 +26920,44 @@ impl std::fmt::Display for TimeType {
         match self {
             TimeType::EndTime => "endTime",
             TimeType::StartTime => "startTime",
-            TimeType::FallthroughString => "*",
+            TimeType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for TimeType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "endTime" => TimeType::EndTime,
+            "startTime" => TimeType::StartTime,
+            _ => TimeType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for TimeType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for TimeType {
     fn default() -> TimeType {
         TimeType::StartTime
@@ -23917,7 +27014,8 @@ pub struct ForwardedTo {
     pub phone_number: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PhoneUserCallLogsResponseForwardedByExtensionType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23931,8 +27029,9 @@ pub enum PhoneUserCallLogsResponseForwardedByExtensionType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PhoneUserCallLogsResponseForwardedByExtensionType {
@@ -23946,12 +27045,50 @@ impl std::fmt::Display for PhoneUserCallLogsResponseForwardedByExtensionType {
             PhoneUserCallLogsResponseForwardedByExtensionType::SharedLineGroup => "sharedLineGroup",
             PhoneUserCallLogsResponseForwardedByExtensionType::User => "user",
             PhoneUserCallLogsResponseForwardedByExtensionType::Noop => "",
-            PhoneUserCallLogsResponseForwardedByExtensionType::FallthroughString => "*",
+            PhoneUserCallLogsResponseForwardedByExtensionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PhoneUserCallLogsResponseForwardedByExtensionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "autoReceptionist" => {
+                PhoneUserCallLogsResponseForwardedByExtensionType::AutoReceptionist
+            }
+            "callQueue" => PhoneUserCallLogsResponseForwardedByExtensionType::CallQueue,
+            "commonAreaPhone" => PhoneUserCallLogsResponseForwardedByExtensionType::CommonAreaPhone,
+            "sharedLineGroup" => PhoneUserCallLogsResponseForwardedByExtensionType::SharedLineGroup,
+            "user" => PhoneUserCallLogsResponseForwardedByExtensionType::User,
+            "" => PhoneUserCallLogsResponseForwardedByExtensionType::Noop,
+            _ => PhoneUserCallLogsResponseForwardedByExtensionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PhoneUserCallLogsResponseForwardedByExtensionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PhoneUserCallLogsResponseForwardedByExtensionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PhoneUserCallLogsResponseForwardedByExtensionType {
     fn default() -> PhoneUserCallLogsResponseForwardedByExtensionType {
         PhoneUserCallLogsResponseForwardedByExtensionType::Noop
@@ -24605,7 +27742,8 @@ pub struct PhoneUserRecordingsResponse {
 /**
 * Status of the voice mail
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PhoneUserVoiceMailsStatus {
     #[serde(rename = "all")]
     All,
@@ -24613,8 +27751,9 @@ pub enum PhoneUserVoiceMailsStatus {
     Read,
     #[serde(rename = "unread")]
     Unread,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PhoneUserVoiceMailsStatus {
@@ -24623,12 +27762,45 @@ impl std::fmt::Display for PhoneUserVoiceMailsStatus {
             PhoneUserVoiceMailsStatus::All => "all",
             PhoneUserVoiceMailsStatus::Read => "read",
             PhoneUserVoiceMailsStatus::Unread => "unread",
-            PhoneUserVoiceMailsStatus::FallthroughString => "*",
+            PhoneUserVoiceMailsStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PhoneUserVoiceMailsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => PhoneUserVoiceMailsStatus::All,
+            "read" => PhoneUserVoiceMailsStatus::Read,
+            "unread" => PhoneUserVoiceMailsStatus::Unread,
+            _ => PhoneUserVoiceMailsStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PhoneUserVoiceMailsStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PhoneUserVoiceMailsStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PhoneUserVoiceMailsStatus {
     fn default() -> PhoneUserVoiceMailsStatus {
         PhoneUserVoiceMailsStatus::All
@@ -24638,7 +27810,8 @@ impl Default for PhoneUserVoiceMailsStatus {
 /**
 * Status of the voice mail. Can be either 'read' or 'unread'
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum PhoneUserVoiceMailsResponseStatus {
     #[serde(rename = "read")]
     Read,
@@ -24646,8 +27819,9 @@ pub enum PhoneUserVoiceMailsResponseStatus {
     Unread,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for PhoneUserVoiceMailsResponseStatus {
@@ -24656,12 +27830,45 @@ impl std::fmt::Display for PhoneUserVoiceMailsResponseStatus {
             PhoneUserVoiceMailsResponseStatus::Read => "read",
             PhoneUserVoiceMailsResponseStatus::Unread => "unread",
             PhoneUserVoiceMailsResponseStatus::Noop => "",
-            PhoneUserVoiceMailsResponseStatus::FallthroughString => "*",
+            PhoneUserVoiceMailsResponseStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for PhoneUserVoiceMailsResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "read" => PhoneUserVoiceMailsResponseStatus::Read,
+            "unread" => PhoneUserVoiceMailsResponseStatus::Unread,
+            "" => PhoneUserVoiceMailsResponseStatus::Noop,
+            _ => PhoneUserVoiceMailsResponseStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for PhoneUserVoiceMailsResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PhoneUserVoiceMailsResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for PhoneUserVoiceMailsResponseStatus {
     fn default() -> PhoneUserVoiceMailsResponseStatus {
         PhoneUserVoiceMailsResponseStatus::Noop
@@ -24979,7 +28186,8 @@ pub struct UpdateUserSettingRequest {
 *   *`international`
 *   *`contactCenter`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum CallType {
     #[serde(rename = "contactCenter")]
     ContactCenter,
@@ -24993,8 +28201,9 @@ pub enum CallType {
     Voip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for CallType {
@@ -25006,12 +28215,48 @@ impl std::fmt::Display for CallType {
             CallType::Tollfree => "tollfree",
             CallType::Voip => "voip",
             CallType::Noop => "",
-            CallType::FallthroughString => "*",
+            CallType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for CallType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "contactCenter" => CallType::ContactCenter,
+            "international" => CallType::International,
+            "pstn" => CallType::Pstn,
+            "tollfree" => CallType::Tollfree,
+            "voip" => CallType::Voip,
+            "" => CallType::Noop,
+            _ => CallType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CallType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CallType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for CallType {
     fn default() -> CallType {
         CallType::Noop
@@ -25380,7 +28625,8 @@ pub struct AssignCallingPlanRequest {
 /**
 * The owner type: `user` or `call queue`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetPhoneRecordingsResponseOwnerType {
     #[serde(rename = "call queue")]
     CallQueue,
@@ -25388,8 +28634,9 @@ pub enum GetPhoneRecordingsResponseOwnerType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetPhoneRecordingsResponseOwnerType {
@@ -25398,12 +28645,45 @@ impl std::fmt::Display for GetPhoneRecordingsResponseOwnerType {
             GetPhoneRecordingsResponseOwnerType::CallQueue => "call queue",
             GetPhoneRecordingsResponseOwnerType::User => "user",
             GetPhoneRecordingsResponseOwnerType::Noop => "",
-            GetPhoneRecordingsResponseOwnerType::FallthroughString => "*",
+            GetPhoneRecordingsResponseOwnerType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetPhoneRecordingsResponseOwnerType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "call queue" => GetPhoneRecordingsResponseOwnerType::CallQueue,
+            "user" => GetPhoneRecordingsResponseOwnerType::User,
+            "" => GetPhoneRecordingsResponseOwnerType::Noop,
+            _ => GetPhoneRecordingsResponseOwnerType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetPhoneRecordingsResponseOwnerType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetPhoneRecordingsResponseOwnerType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetPhoneRecordingsResponseOwnerType {
     fn default() -> GetPhoneRecordingsResponseOwnerType {
         GetPhoneRecordingsResponseOwnerType::Noop
@@ -25455,7 +28735,8 @@ pub struct GetPhoneRecordingsResponseOwner {
 /**
 * The direction of the call. Values: `inbound` or `outbound`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Direction {
     #[serde(rename = "inbound")]
     Inbound,
@@ -25463,8 +28744,9 @@ pub enum Direction {
     Outbound,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Direction {
@@ -25473,12 +28755,45 @@ impl std::fmt::Display for Direction {
             Direction::Inbound => "inbound",
             Direction::Outbound => "outbound",
             Direction::Noop => "",
-            Direction::FallthroughString => "*",
+            Direction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Direction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "inbound" => Direction::Inbound,
+            "outbound" => Direction::Outbound,
+            "" => Direction::Noop,
+            _ => Direction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Direction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Direction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Direction {
     fn default() -> Direction {
         Direction::Noop
@@ -25849,14 +29164,16 @@ pub struct AccountPlanBaseDeleteRequest {
 /**
 * The action that needs to be taken for this sub account. Value must be set to "cancel".
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum AccountPlanAddonCancelRequestAction {
     #[serde(rename = "cancel")]
     Cancel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for AccountPlanAddonCancelRequestAction {
@@ -25864,12 +29181,44 @@ impl std::fmt::Display for AccountPlanAddonCancelRequestAction {
         match self {
             AccountPlanAddonCancelRequestAction::Cancel => "cancel",
             AccountPlanAddonCancelRequestAction::Noop => "",
-            AccountPlanAddonCancelRequestAction::FallthroughString => "*",
+            AccountPlanAddonCancelRequestAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for AccountPlanAddonCancelRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cancel" => AccountPlanAddonCancelRequestAction::Cancel,
+            "" => AccountPlanAddonCancelRequestAction::Noop,
+            _ => AccountPlanAddonCancelRequestAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for AccountPlanAddonCancelRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountPlanAddonCancelRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for AccountPlanAddonCancelRequestAction {
     fn default() -> AccountPlanAddonCancelRequestAction {
         AccountPlanAddonCancelRequestAction::Noop
@@ -27352,7 +30701,8 @@ impl UpdateGroupSettingsRequestOneOf {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UpdateGroupSettingsOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -27362,8 +30712,9 @@ pub enum UpdateGroupSettingsOption {
     RecordingAuthentication,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UpdateGroupSettingsOption {
@@ -27373,12 +30724,46 @@ impl std::fmt::Display for UpdateGroupSettingsOption {
             UpdateGroupSettingsOption::MeetingSecurity => "meeting_security",
             UpdateGroupSettingsOption::RecordingAuthentication => "recording_authentication",
             UpdateGroupSettingsOption::Noop => "",
-            UpdateGroupSettingsOption::FallthroughString => "*",
+            UpdateGroupSettingsOption::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UpdateGroupSettingsOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "meeting_authentication" => UpdateGroupSettingsOption::MeetingAuthentication,
+            "meeting_security" => UpdateGroupSettingsOption::MeetingSecurity,
+            "recording_authentication" => UpdateGroupSettingsOption::RecordingAuthentication,
+            "" => UpdateGroupSettingsOption::Noop,
+            _ => UpdateGroupSettingsOption::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UpdateGroupSettingsOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UpdateGroupSettingsOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UpdateGroupSettingsOption {
     fn default() -> UpdateGroupSettingsOption {
         UpdateGroupSettingsOption::Noop
@@ -28519,7 +31904,8 @@ pub struct SwitchUserAccountRequest {
 *   A recording file object with file type of either `CC` or `TB` **does not have** the following properties:<br>
 *   	`id`, `status`, `file_size`, `recording_type`, and `play_url`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesFileType {
     #[serde(rename = "CC")]
     Cc,
@@ -28537,8 +31923,9 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesFileType {
     Transcript,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesFileType {
@@ -28552,12 +31939,50 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesFileType
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Tb => "TB",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Transcript => "TRANSCRIPT",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesFileType::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetAccountCloudRecordingResponseMeetingsFilesFileType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CC" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Cc,
+            "CHAT" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Chat,
+            "CSV" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Csv,
+            "M4A" => GetAccountCloudRecordingResponseMeetingsFilesFileType::M4A,
+            "MP4" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Mp4,
+            "TB" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Tb,
+            "TRANSCRIPT" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Transcript,
+            "" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop,
+            _ => GetAccountCloudRecordingResponseMeetingsFilesFileType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetAccountCloudRecordingResponseMeetingsFilesFileType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAccountCloudRecordingResponseMeetingsFilesFileType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetAccountCloudRecordingResponseMeetingsFilesFileType {
     fn default() -> GetAccountCloudRecordingResponseMeetingsFilesFileType {
         GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop
@@ -28575,7 +32000,8 @@ impl GetAccountCloudRecordingResponseMeetingsFilesFileType {
 /**
 * The status of the recording, either `completed` or `processing`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesStatus {
     #[serde(rename = "completed")]
     Completed,
@@ -28583,8 +32009,9 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesStatus {
@@ -28593,12 +32020,45 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesStatus {
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Completed => "completed",
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Processing => "processing",
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesStatus::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetAccountCloudRecordingResponseMeetingsFilesStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "completed" => GetAccountCloudRecordingResponseMeetingsFilesStatus::Completed,
+            "processing" => GetAccountCloudRecordingResponseMeetingsFilesStatus::Processing,
+            "" => GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop,
+            _ => GetAccountCloudRecordingResponseMeetingsFilesStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetAccountCloudRecordingResponseMeetingsFilesStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAccountCloudRecordingResponseMeetingsFilesStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetAccountCloudRecordingResponseMeetingsFilesStatus {
     fn default() -> GetAccountCloudRecordingResponseMeetingsFilesStatus {
         GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop
@@ -28631,7 +32091,8 @@ impl GetAccountCloudRecordingResponseMeetingsFilesStatus {
 *   * `thumbnail`
 *   
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesType {
     #[serde(rename = "active_speaker")]
     ActiveSpeaker,
@@ -28663,8 +32124,9 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesType {
     Timeline,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesType {
@@ -28693,12 +32155,65 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesType {
             GetAccountCloudRecordingResponseMeetingsFilesType::Thumbnail => "thumbnail",
             GetAccountCloudRecordingResponseMeetingsFilesType::Timeline => "timeline",
             GetAccountCloudRecordingResponseMeetingsFilesType::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesType::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetAccountCloudRecordingResponseMeetingsFilesType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active_speaker" => GetAccountCloudRecordingResponseMeetingsFilesType::ActiveSpeaker,
+            "audio_only" => GetAccountCloudRecordingResponseMeetingsFilesType::AudioOnly,
+            "audio_transcript" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::AudioTranscript
+            }
+            "chat_file" => GetAccountCloudRecordingResponseMeetingsFilesType::ChatFile,
+            "closed_caption" => GetAccountCloudRecordingResponseMeetingsFilesType::ClosedCaption,
+            "gallery_view" => GetAccountCloudRecordingResponseMeetingsFilesType::GalleryView,
+            "host_video" => GetAccountCloudRecordingResponseMeetingsFilesType::HostVideo,
+            "poll" => GetAccountCloudRecordingResponseMeetingsFilesType::Poll,
+            "shared_screen" => GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreen,
+            "shared_screen_with_gallery_view" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithGalleryView
+            }
+            "shared_screen_with_speaker_view" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithSpeakerView
+            }
+            "shared_screen_with_speaker_view(CC)" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithSpeakerViewCc
+            }
+            "thumbnail" => GetAccountCloudRecordingResponseMeetingsFilesType::Thumbnail,
+            "timeline" => GetAccountCloudRecordingResponseMeetingsFilesType::Timeline,
+            "" => GetAccountCloudRecordingResponseMeetingsFilesType::Noop,
+            _ => GetAccountCloudRecordingResponseMeetingsFilesType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetAccountCloudRecordingResponseMeetingsFilesType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAccountCloudRecordingResponseMeetingsFilesType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetAccountCloudRecordingResponseMeetingsFilesType {
     fn default() -> GetAccountCloudRecordingResponseMeetingsFilesType {
         GetAccountCloudRecordingResponseMeetingsFilesType::Noop
@@ -29084,14 +32599,16 @@ pub struct UpdateZrAccProfileRequest {
 *   `meeting`: Meeting settings of the Zoom Rooms Account. <br>
 *   `signage`: View digital signage settings of the Zoom Rooms Account.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum SettingType {
     #[serde(rename = "alert")]
     Alert,
     #[serde(rename = "meeting")]
     Meeting,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for SettingType {
@@ -29099,12 +32616,44 @@ impl std::fmt::Display for SettingType {
         match self {
             SettingType::Alert => "alert",
             SettingType::Meeting => "meeting",
-            SettingType::FallthroughString => "*",
+            SettingType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for SettingType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "alert" => SettingType::Alert,
+            "meeting" => SettingType::Meeting,
+            _ => SettingType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for SettingType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SettingType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for SettingType {
     fn default() -> SettingType {
         SettingType::Meeting
@@ -29115,7 +32664,8 @@ impl Default for SettingType {
 * The type of location. The value can be one of the following:
 *   `country`, `states`, `city`, `campus`, `building`, `floor`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ListZrLocationsResponseType {
     #[serde(rename = "building")]
     Building,
@@ -29131,8 +32681,9 @@ pub enum ListZrLocationsResponseType {
     States,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ListZrLocationsResponseType {
@@ -29145,12 +32696,49 @@ impl std::fmt::Display for ListZrLocationsResponseType {
             ListZrLocationsResponseType::Floor => "floor",
             ListZrLocationsResponseType::States => "states",
             ListZrLocationsResponseType::Noop => "",
-            ListZrLocationsResponseType::FallthroughString => "*",
+            ListZrLocationsResponseType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ListZrLocationsResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "building" => ListZrLocationsResponseType::Building,
+            "campus" => ListZrLocationsResponseType::Campus,
+            "city" => ListZrLocationsResponseType::City,
+            "country" => ListZrLocationsResponseType::Country,
+            "floor" => ListZrLocationsResponseType::Floor,
+            "states" => ListZrLocationsResponseType::States,
+            "" => ListZrLocationsResponseType::Noop,
+            _ => ListZrLocationsResponseType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ListZrLocationsResponseType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListZrLocationsResponseType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ListZrLocationsResponseType {
     fn default() -> ListZrLocationsResponseType {
         ListZrLocationsResponseType::Noop
@@ -29483,7 +33071,8 @@ pub struct UpdateRoomProfileRequest {
 /**
 * Type of the device. The value of this field can be one of the following:<br>`Zoom Rooms Computer`,<br> `Controller`, `Scheduling Display`, `Zoom Rooms Control System`, `Companion Whiteboard`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum DeviceType {
     #[serde(rename = "Companion Whiteboard")]
     CompanionWhiteboard,
@@ -29497,8 +33086,9 @@ pub enum DeviceType {
     ZoomRoomsControlSystem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for DeviceType {
@@ -29510,12 +33100,48 @@ impl std::fmt::Display for DeviceType {
             DeviceType::ZoomRoomsComputer => "Zoom Rooms Computer",
             DeviceType::ZoomRoomsControlSystem => "Zoom Rooms Control System",
             DeviceType::Noop => "",
-            DeviceType::FallthroughString => "*",
+            DeviceType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for DeviceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Companion Whiteboard" => DeviceType::CompanionWhiteboard,
+            "Controller" => DeviceType::Controller,
+            "Scheduling Display" => DeviceType::SchedulingDisplay,
+            "Zoom Rooms Computer" => DeviceType::ZoomRoomsComputer,
+            "Zoom Rooms Control System" => DeviceType::ZoomRoomsControlSystem,
+            "" => DeviceType::Noop,
+            _ => DeviceType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for DeviceType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeviceType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for DeviceType {
     fn default() -> DeviceType {
         DeviceType::Noop
@@ -29530,7 +33156,8 @@ impl DeviceType {
 /**
 * Status of the device. The value can be either `Online` or `Offline`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ListZrDevicesResponseStatus {
     #[serde(rename = "Offline")]
     Offline,
@@ -29538,8 +33165,9 @@ pub enum ListZrDevicesResponseStatus {
     Online,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ListZrDevicesResponseStatus {
@@ -29548,12 +33176,45 @@ impl std::fmt::Display for ListZrDevicesResponseStatus {
             ListZrDevicesResponseStatus::Offline => "Offline",
             ListZrDevicesResponseStatus::Online => "Online",
             ListZrDevicesResponseStatus::Noop => "",
-            ListZrDevicesResponseStatus::FallthroughString => "*",
+            ListZrDevicesResponseStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ListZrDevicesResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Offline" => ListZrDevicesResponseStatus::Offline,
+            "Online" => ListZrDevicesResponseStatus::Online,
+            "" => ListZrDevicesResponseStatus::Noop,
+            _ => ListZrDevicesResponseStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ListZrDevicesResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListZrDevicesResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ListZrDevicesResponseStatus {
     fn default() -> ListZrDevicesResponseStatus {
         ListZrDevicesResponseStatus::Noop
@@ -29834,7 +33495,8 @@ pub struct ListMeetingSatisfactionResponse {
 /**
 * State of the device. The value should be either `assigned` to list devices that have been assigned to user(s) or `unassigned` to list devices that have not yet been assigned to any user in the Zoom account.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ListPhoneDevicesType {
     #[serde(rename = "assigned")]
     Assigned,
@@ -29842,8 +33504,9 @@ pub enum ListPhoneDevicesType {
     Unassigned,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ListPhoneDevicesType {
@@ -29852,12 +33515,45 @@ impl std::fmt::Display for ListPhoneDevicesType {
             ListPhoneDevicesType::Assigned => "assigned",
             ListPhoneDevicesType::Unassigned => "unassigned",
             ListPhoneDevicesType::Noop => "",
-            ListPhoneDevicesType::FallthroughString => "*",
+            ListPhoneDevicesType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ListPhoneDevicesType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "assigned" => ListPhoneDevicesType::Assigned,
+            "unassigned" => ListPhoneDevicesType::Unassigned,
+            "" => ListPhoneDevicesType::Noop,
+            _ => ListPhoneDevicesType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ListPhoneDevicesType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListPhoneDevicesType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ListPhoneDevicesType {
     fn default() -> ListPhoneDevicesType {
         ListPhoneDevicesType::Noop
@@ -29903,7 +33599,8 @@ pub struct AddAutoReceptionistResponse {
 /**
 * Status of the device. The value is either `online` or `offline`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ListPhoneDevicesResponseStatus {
     #[serde(rename = "offline")]
     Offline,
@@ -29911,8 +33608,9 @@ pub enum ListPhoneDevicesResponseStatus {
     Online,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ListPhoneDevicesResponseStatus {
@@ -29921,12 +33619,45 @@ impl std::fmt::Display for ListPhoneDevicesResponseStatus {
             ListPhoneDevicesResponseStatus::Offline => "offline",
             ListPhoneDevicesResponseStatus::Online => "online",
             ListPhoneDevicesResponseStatus::Noop => "",
-            ListPhoneDevicesResponseStatus::FallthroughString => "*",
+            ListPhoneDevicesResponseStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ListPhoneDevicesResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "offline" => ListPhoneDevicesResponseStatus::Offline,
+            "online" => ListPhoneDevicesResponseStatus::Online,
+            "" => ListPhoneDevicesResponseStatus::Noop,
+            _ => ListPhoneDevicesResponseStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ListPhoneDevicesResponseStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListPhoneDevicesResponseStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ListPhoneDevicesResponseStatus {
     fn default() -> ListPhoneDevicesResponseStatus {
         ListPhoneDevicesResponseStatus::Noop
@@ -30115,7 +33846,8 @@ pub struct GetDeviceResponseAssignee {
 *   
 *   
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetDeviceResponseProvisionType {
     #[serde(rename = "assisted")]
     Assisted,
@@ -30125,8 +33857,9 @@ pub enum GetDeviceResponseProvisionType {
     Ztp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetDeviceResponseProvisionType {
@@ -30136,12 +33869,46 @@ impl std::fmt::Display for GetDeviceResponseProvisionType {
             GetDeviceResponseProvisionType::Manual => "manual",
             GetDeviceResponseProvisionType::Ztp => "ztp",
             GetDeviceResponseProvisionType::Noop => "",
-            GetDeviceResponseProvisionType::FallthroughString => "*",
+            GetDeviceResponseProvisionType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetDeviceResponseProvisionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "assisted" => GetDeviceResponseProvisionType::Assisted,
+            "manual" => GetDeviceResponseProvisionType::Manual,
+            "ztp" => GetDeviceResponseProvisionType::Ztp,
+            "" => GetDeviceResponseProvisionType::Noop,
+            _ => GetDeviceResponseProvisionType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetDeviceResponseProvisionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetDeviceResponseProvisionType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetDeviceResponseProvisionType {
     fn default() -> GetDeviceResponseProvisionType {
         GetDeviceResponseProvisionType::Noop
@@ -30883,7 +34650,8 @@ pub struct CreateCallQueueResponse {
 *   `manager`: A call queue manager has the privilege to change call queue settings, policy settings and manage recordings and voicemail inbox. There can only be one manager for each call queue.<br><br>
 *   `user`: Regular user without the privileges of a manager.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Level {
     #[serde(rename = "manager")]
     Manager,
@@ -30891,8 +34659,9 @@ pub enum Level {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Level {
@@ -30901,12 +34670,45 @@ impl std::fmt::Display for Level {
             Level::Manager => "manager",
             Level::User => "user",
             Level::Noop => "",
-            Level::FallthroughString => "*",
+            Level::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Level {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "manager" => Level::Manager,
+            "user" => Level::User,
+            "" => Level::Noop,
+            _ => Level::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Level {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Level {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Level {
     fn default() -> Level {
         Level::Noop
@@ -31850,7 +35652,8 @@ pub struct InternationalCall {
 /**
 * Allow Hot Desking feature to current device.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     #[serde(rename = "off")]
     Off,
@@ -31860,8 +35663,9 @@ pub enum GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     Unsupported,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
@@ -31871,12 +35675,46 @@ impl std::fmt::Display for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::On => "on",
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unsupported => "unsupported",
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop => "",
-            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::FallthroughString => "*",
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "off" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Off,
+            "on" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::On,
+            "unsupported" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unsupported,
+            "" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop,
+            _ => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     fn default() -> GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
         GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop
@@ -32157,7 +35995,8 @@ pub struct AssignCallingPlansCommonAreaPhoneResponseData {
 *   `phoneNumber`: Indicates that only a specific phone number that is shown in the `phone_number` field is blocked.<br><br>
 *   `prefix`: Indicates that all numbers starting with prefix that is shown in the `phone_number` field are blocked.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum MatchType {
     #[serde(rename = "phoneNumber")]
     PhoneNumber,
@@ -32165,8 +36004,9 @@ pub enum MatchType {
     Prefix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for MatchType {
@@ -32175,12 +36015,45 @@ impl std::fmt::Display for MatchType {
             MatchType::PhoneNumber => "phoneNumber",
             MatchType::Prefix => "prefix",
             MatchType::Noop => "",
-            MatchType::FallthroughString => "*",
+            MatchType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for MatchType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "phoneNumber" => MatchType::PhoneNumber,
+            "prefix" => MatchType::Prefix,
+            "" => MatchType::Noop,
+            _ => MatchType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for MatchType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MatchType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for MatchType {
     fn default() -> MatchType {
         MatchType::Noop
@@ -33360,7 +37233,8 @@ pub struct AddCalloutCountriesResponse {
 /**
 * Specify the language in which this phone number should be displayed. Currently, the only supported languages are `en-GB`, `en-US`, `de-DE`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Languages {
     #[serde(rename = "de-DE")]
     De,
@@ -33370,8 +37244,9 @@ pub enum Languages {
     EnUs,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Languages {
@@ -33381,12 +37256,46 @@ impl std::fmt::Display for Languages {
             Languages::EnGb => "en-GB",
             Languages::EnUs => "en-US",
             Languages::Noop => "",
-            Languages::FallthroughString => "*",
+            Languages::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Languages {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "de-DE" => Languages::De,
+            "en-GB" => Languages::EnGb,
+            "en-US" => Languages::EnUs,
+            "" => Languages::Noop,
+            _ => Languages::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Languages {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Languages {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Languages {
     fn default() -> Languages {
         Languages::Noop
@@ -33629,7 +37538,8 @@ pub struct AddInternalNumbersResponseData {
 *   `downgrade`: Downgrade the Zoom Rooms App Version.<br>
 *   `cancel`: Cancel an ongoing upgrade or downgrade process.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ChangeZoomRoomsAppVersionRequestAction {
     #[serde(rename = "cancel")]
     Cancel,
@@ -33639,8 +37549,9 @@ pub enum ChangeZoomRoomsAppVersionRequestAction {
     Upgrade,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ChangeZoomRoomsAppVersionRequestAction {
@@ -33650,12 +37561,46 @@ impl std::fmt::Display for ChangeZoomRoomsAppVersionRequestAction {
             ChangeZoomRoomsAppVersionRequestAction::Downgrade => "downgrade",
             ChangeZoomRoomsAppVersionRequestAction::Upgrade => "upgrade",
             ChangeZoomRoomsAppVersionRequestAction::Noop => "",
-            ChangeZoomRoomsAppVersionRequestAction::FallthroughString => "*",
+            ChangeZoomRoomsAppVersionRequestAction::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ChangeZoomRoomsAppVersionRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cancel" => ChangeZoomRoomsAppVersionRequestAction::Cancel,
+            "downgrade" => ChangeZoomRoomsAppVersionRequestAction::Downgrade,
+            "upgrade" => ChangeZoomRoomsAppVersionRequestAction::Upgrade,
+            "" => ChangeZoomRoomsAppVersionRequestAction::Noop,
+            _ => ChangeZoomRoomsAppVersionRequestAction::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ChangeZoomRoomsAppVersionRequestAction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChangeZoomRoomsAppVersionRequestAction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ChangeZoomRoomsAppVersionRequestAction {
     fn default() -> ChangeZoomRoomsAppVersionRequestAction {
         ChangeZoomRoomsAppVersionRequestAction::Noop
@@ -33747,7 +37692,8 @@ pub struct ListWebinarTemplatesResponse {
 *   * `Available` to `Away`
 *   * `Away` to `Available`
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum UpdatePresenceStatusRequest {
     #[serde(rename = "Available")]
     Available,
@@ -33765,8 +37711,9 @@ pub enum UpdatePresenceStatusRequest {
     Presenting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for UpdatePresenceStatusRequest {
@@ -33780,12 +37727,50 @@ impl std::fmt::Display for UpdatePresenceStatusRequest {
             UpdatePresenceStatusRequest::OnACall => "On_A_Call",
             UpdatePresenceStatusRequest::Presenting => "Presenting",
             UpdatePresenceStatusRequest::Noop => "",
-            UpdatePresenceStatusRequest::FallthroughString => "*",
+            UpdatePresenceStatusRequest::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for UpdatePresenceStatusRequest {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Available" => UpdatePresenceStatusRequest::Available,
+            "Away" => UpdatePresenceStatusRequest::Away,
+            "Do_No_Disturb" => UpdatePresenceStatusRequest::DoNoDisturb,
+            "In_A_Zoom_Meeting" => UpdatePresenceStatusRequest::InAZoomMeeting,
+            "In_Calendar_Event" => UpdatePresenceStatusRequest::InCalendarEvent,
+            "On_A_Call" => UpdatePresenceStatusRequest::OnACall,
+            "Presenting" => UpdatePresenceStatusRequest::Presenting,
+            "" => UpdatePresenceStatusRequest::Noop,
+            _ => UpdatePresenceStatusRequest::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for UpdatePresenceStatusRequest {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UpdatePresenceStatusRequest {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for UpdatePresenceStatusRequest {
     fn default() -> UpdatePresenceStatusRequest {
         UpdatePresenceStatusRequest::Noop
@@ -34680,7 +38665,8 @@ pub struct GetAccountBillingInvoiceResponse {
 *   * `GOOD`: Thumbs up.
 *   * `NOT GOOD`: Thumbs down.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Quality {
     #[serde(rename = "GOOD")]
     Good,
@@ -34688,8 +38674,9 @@ pub enum Quality {
     NotGood,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Quality {
@@ -34698,12 +38685,45 @@ impl std::fmt::Display for Quality {
             Quality::Good => "GOOD",
             Quality::NotGood => "NOT GOOD",
             Quality::Noop => "",
-            Quality::FallthroughString => "*",
+            Quality::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Quality {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "GOOD" => Quality::Good,
+            "NOT GOOD" => Quality::NotGood,
+            "" => Quality::Noop,
+            _ => Quality::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Quality {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Quality {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Quality {
     fn default() -> Quality {
         Quality::Noop
@@ -34785,7 +38805,8 @@ pub struct ParticipantFeedbackResponse {
 /**
 * Result of the call: `call_connected` | `recorded` | `no_answer`.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum Result {
     #[serde(rename = "call_connected")]
     CallConnected,
@@ -34795,8 +38816,9 @@ pub enum Result {
     Recorded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for Result {
@@ -34806,12 +38828,46 @@ impl std::fmt::Display for Result {
             Result::NoAnswer => "no_answer",
             Result::Recorded => "recorded",
             Result::Noop => "",
-            Result::FallthroughString => "*",
+            Result::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for Result {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "call_connected" => Result::CallConnected,
+            "no_answer" => Result::NoAnswer,
+            "recorded" => Result::Recorded,
+            "" => Result::Noop,
+            _ => Result::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for Result {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Result {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Result {
     fn default() -> Result {
         Result::Noop
@@ -35191,14 +39247,16 @@ pub struct ListMeetingTemplatesResponseData {
 /**
 * The query date type for the `from` and `to` parameters.
 */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[non_exhaustive]
 pub enum ListArchivedFilesQueryDateType {
     #[serde(rename = "archive_complete_time")]
     ArchiveCompleteTime,
     #[serde(rename = "meeting_start_time")]
     MeetingStartTime,
-    #[serde(other)]
-    FallthroughString,
+    /// A value that was not known when this client was generated.
+    #[serde(skip)]
+    UnknownValue(String),
 }
 
 impl std::fmt::Display for ListArchivedFilesQueryDateType {
@@ -35206,12 +39264,44 @@ impl std::fmt::Display for ListArchivedFilesQueryDateType {
         match self {
             ListArchivedFilesQueryDateType::ArchiveCompleteTime => "archive_complete_time",
             ListArchivedFilesQueryDateType::MeetingStartTime => "meeting_start_time",
-            ListArchivedFilesQueryDateType::FallthroughString => "*",
+            ListArchivedFilesQueryDateType::UnknownValue(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::str::FromStr for ListArchivedFilesQueryDateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "archive_complete_time" => ListArchivedFilesQueryDateType::ArchiveCompleteTime,
+            "meeting_start_time" => ListArchivedFilesQueryDateType::MeetingStartTime,
+            _ => ListArchivedFilesQueryDateType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for ListArchivedFilesQueryDateType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListArchivedFilesQueryDateType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for ListArchivedFilesQueryDateType {
     fn default() -> ListArchivedFilesQueryDateType {
         ListArchivedFilesQueryDateType::MeetingStartTime